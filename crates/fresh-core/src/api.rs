@@ -635,6 +635,42 @@ pub struct OverlayOptions {
     pub url: Option<String>,
 }
 
+/// Where plugin virtual text is drawn, relative to a byte offset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+#[ts(export)]
+pub enum VirtualTextAnchor {
+    /// Inline, before the character at `position`
+    Before { position: usize },
+    /// Inline, after the character at `position`
+    After { position: usize },
+    /// After the content of the line containing `position`
+    EndOfLine { position: usize },
+}
+
+/// Styling for plugin virtual text. Colors are RGB arrays or theme keys,
+/// resolved at render time like overlay colors.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, TS)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+#[ts(export, rename_all = "camelCase")]
+pub struct VirtualTextStyle {
+    /// Foreground color - RGB array or theme key string
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fg: Option<OverlayColorSpec>,
+
+    /// Background color - RGB array or theme key string
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bg: Option<OverlayColorSpec>,
+
+    /// Whether to render in bold
+    #[serde(default)]
+    pub bold: bool,
+
+    /// Whether to render in italic
+    #[serde(default)]
+    pub italic: bool,
+}
+
 // ============================================================================
 // Composite Buffer Configuration (for multi-buffer single-tab views)
 // ============================================================================
@@ -1238,6 +1274,17 @@ pub enum PluginCommand {
         before: bool,
     },
 
+    /// Add or replace the virtual text with ID `id`. Unlike
+    /// `AddVirtualTextStyled` the anchor can also be the end of a line,
+    /// and the text is reflowed with the line when it wraps.
+    SetVirtualText {
+        buffer_id: BufferId,
+        anchor: VirtualTextAnchor,
+        text: String,
+        style: VirtualTextStyle,
+        id: String,
+    },
+
     /// Remove a virtual text by ID
     RemoveVirtualText {
         buffer_id: BufferId,
@@ -1614,6 +1661,25 @@ pub enum PluginCommand {
     /// Get text properties at the cursor position in a buffer
     GetTextPropertiesAtCursor { buffer_id: BufferId },
 
+    /// Open a scratch buffer: an editable buffer with no file behind it,
+    /// shown as a new tab in the active split. Closing it never prompts
+    /// to save.
+    OpenScratchBuffer {
+        /// Display name (e.g., "*Build Output*")
+        name: String,
+        /// Initial content
+        content: String,
+        /// Language for syntax highlighting (e.g., "rust"); when `None`
+        /// it is detected from `name`
+        language: Option<String>,
+        /// Optional request ID for async response (if set, editor will send back buffer ID)
+        request_id: Option<u64>,
+    },
+
+    /// Append text to the end of a buffer, e.g. to stream output into a
+    /// scratch buffer
+    AppendToBuffer { buffer_id: BufferId, text: String },
+
     /// Create a buffer group: multiple panels appearing as one tab.
     /// Each panel is a real buffer with its own scrollbar and viewport.
     CreateBufferGroup {
@@ -1855,9 +1921,10 @@ pub enum PluginCommand {
         actions: Vec<ActionSpec>,
     },
 
-    /// Get text from a buffer range (for yank operations)
+    /// Get text from a buffer range (for yank operations). The range must
+    /// lie within the buffer and is widened to UTF-8 character boundaries.
     GetBufferText {
-        /// Buffer ID
+        /// Buffer ID (0 = active buffer)
         buffer_id: BufferId,
         /// Start byte offset
         start: usize,
@@ -1867,6 +1934,21 @@ pub enum PluginCommand {
         request_id: u64,
     },
 
+    /// Replace a byte range with new text as a single undoable edit
+    /// (async, resolves once applied). Range rules match `GetBufferText`.
+    ReplaceRange {
+        /// Buffer ID (0 = active buffer)
+        buffer_id: BufferId,
+        /// Start byte offset
+        start: usize,
+        /// End byte offset
+        end: usize,
+        /// Replacement text
+        text: String,
+        /// Request ID for async response
+        request_id: u64,
+    },
+
     /// Get byte offset of the start of a line (async)
     /// Line is 0-indexed (0 = first line)
     GetLineStartPosition {
@@ -22,6 +22,12 @@ pub struct PluginConfig {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[schemars(extend("readOnly" = true))]
     pub path: Option<PathBuf>,
+
+    /// Permissions granted to this plugin (default: none)
+    /// A plugin whose manifest requests a permission that isn't granted
+    /// here is not loaded. Plugins without a manifest are unaffected.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub permissions: Vec<PluginPermission>,
 }
 
 impl Default for PluginConfig {
@@ -29,6 +35,7 @@ impl Default for PluginConfig {
         Self {
             enabled: true,
             path: None,
+            permissions: Vec::new(),
         }
     }
 }
@@ -38,10 +45,43 @@ impl PluginConfig {
         Self {
             enabled: true,
             path: Some(path),
+            permissions: Vec::new(),
         }
     }
 }
 
+/// A capability a plugin declares in its manifest and the user grants in
+/// the plugin's config
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum PluginPermission {
+    /// Read, write and list files and directories directly
+    Filesystem,
+    /// Spawn processes and terminals
+    Process,
+    /// Reach the network (reserved: no editor API needs it yet)
+    Network,
+}
+
+impl PluginPermission {
+    /// Every permission, in declaration order
+    pub const ALL: [Self; 3] = [Self::Filesystem, Self::Process, Self::Network];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Filesystem => "filesystem",
+            Self::Process => "process",
+            Self::Network => "network",
+        }
+    }
+}
+
+impl std::fmt::Display for PluginPermission {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -14,14 +14,20 @@ use crate::{BufferId, CursorId, SplitId};
 #[derive(Debug, Clone, serde::Serialize)]
 #[serde(untagged)]
 pub enum HookArgs {
-    /// Before a file is opened
+    /// Before a file is opened; a handler returning `false` cancels it
     BeforeFileOpen { path: PathBuf },
 
     /// After a file is successfully opened
     AfterFileOpen { buffer_id: BufferId, path: PathBuf },
 
-    /// Before a buffer is saved to disk
-    BeforeFileSave { buffer_id: BufferId, path: PathBuf },
+    /// Before a buffer is saved to disk; a handler returning `false`
+    /// cancels the save. `text` is the content about to be written, or
+    /// `None` when the buffer isn't fully loaded (large files).
+    BeforeFileSave {
+        buffer_id: BufferId,
+        path: PathBuf,
+        text: Option<String>,
+    },
 
     /// After a buffer is successfully saved
     AfterFileSave { buffer_id: BufferId, path: PathBuf },
@@ -192,10 +198,12 @@ pub enum HookArgs {
         split_id: SplitId,
         /// Byte offset of the viewport start
         viewport_start: usize,
-        /// Byte offset of the viewport end
+        /// Byte offset just past the last visible source byte
         viewport_end: usize,
         /// Base tokens (Text, Newline, Space) from the source
         tokens: Vec<ViewTokenWire>,
+        /// The same tokens grouped by source line
+        lines: Vec<ViewLineTokens>,
         /// Byte positions of all cursors in this buffer
         cursor_positions: Vec<usize>,
     },
@@ -351,6 +359,15 @@ pub struct LineInfo {
     pub content: String,
 }
 
+/// Tokens of one visible source line for the ViewTransformRequest hook
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ViewLineTokens {
+    /// Byte offset where the line starts in the buffer
+    pub line_start: usize,
+    /// The line's tokens, including its trailing Newline
+    pub tokens: Vec<ViewTokenWire>,
+}
+
 /// Location information for LSP references
 #[derive(Debug, Clone, serde::Serialize)]
 pub struct LspLocation {
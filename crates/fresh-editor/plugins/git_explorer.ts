@@ -28,6 +28,9 @@ const PRIORITY = {
 };
 
 let refreshInFlight = false;
+// Set when a refresh is requested while one is running, so the state that
+// triggered it (e.g. a save) is picked up once the current run finishes.
+let refreshQueued = false;
 
 function statusToDecoration(status: string, staged: boolean) {
   switch (status) {
@@ -101,6 +104,7 @@ function parseStatusOutput(output: string, repoRoot: string) {
 
 async function refreshGitExplorerDecorations() {
   if (refreshInFlight) {
+    refreshQueued = true;
     return;
   }
   refreshInFlight = true;
@@ -138,17 +142,13 @@ async function refreshGitExplorerDecorations() {
     throw err;
   } finally {
     refreshInFlight = false;
+    if (refreshQueued) {
+      refreshQueued = false;
+      refreshGitExplorerDecorations();
+    }
   }
 }
 
-
-
-
-
-
-
-
-
 editor.on("after_file_open", () => {
   refreshGitExplorerDecorations();
 });
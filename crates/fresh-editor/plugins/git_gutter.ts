@@ -56,6 +56,11 @@ interface BufferGitState {
   hunks: DiffHunk[];
   /** Whether we're currently updating */
   updating: boolean;
+  /**
+   * Set when an update is requested while one is running, so the change that
+   * triggered it (e.g. a save) is picked up once the current run finishes
+   */
+  queued: boolean;
 }
 
 // =============================================================================
@@ -234,7 +239,11 @@ async function getGitDiff(filePath: string): Promise<string> {
  */
 async function updateGitGutter(bufferId: number): Promise<void> {
   const state = bufferStates.get(bufferId);
-  if (!state || state.updating) return;
+  if (!state) return;
+  if (state.updating) {
+    state.queued = true;
+    return;
+  }
 
   state.updating = true;
 
@@ -311,6 +320,10 @@ async function updateGitGutter(bufferId: number): Promise<void> {
     editor.setViewState(bufferId, "git_gutter_hunks", hunks);
   } finally {
     state.updating = false;
+    if (state.queued && bufferStates.get(bufferId) === state) {
+      state.queued = false;
+      updateGitGutter(bufferId);
+    }
   }
 }
 
@@ -365,6 +378,7 @@ function git_gutter_refresh() : void {
       filePath,
       hunks: [],
       updating: false,
+      queued: false,
     });
   }
 
@@ -397,6 +411,7 @@ editor.on("after_file_open", (args) => {
     filePath,
     hunks: [],
     updating: false,
+    queued: false,
   });
 
   // Update immediately (no debounce for file open)
@@ -415,12 +430,15 @@ editor.on("buffer_activated", (args) => {
         filePath,
         hunks: [],
         updating: false,
+        queued: false,
       });
-      updateGitGutter(bufferId);
     }
   }
-  // If we already have state, the indicators should be current
-  // (they update on file open and save)
+  // Refresh on focus too: the file may have been committed, staged or
+  // edited outside the editor since the last update
+  if (bufferStates.has(bufferId)) {
+    updateGitGutter(bufferId);
+  }
 
   return true;
 });
@@ -436,6 +454,7 @@ editor.on("after_file_save", (args) => {
       filePath: args.path,
       hunks: [],
       updating: false,
+      queued: false,
     });
   }
 
@@ -465,6 +484,7 @@ if (initPath && initPath !== "") {
     filePath: initPath,
     hunks: [],
     updating: false,
+    queued: false,
   });
   updateGitGutter(initBufferId);
 }
@@ -1648,6 +1648,15 @@ interface EditorAPI {
 	*/
 	addVirtualTextStyled(bufferId: number, virtualTextId: string, position: number, text: string, options: Record<string, unknown>, before: boolean): boolean;
 	/**
+	* Add or replace the virtual text `virtualTextId`. `options.anchor`
+	* places it relative to `position`: `"before"` or `"after"` the
+	* character there, or `"endOfLine"` (the default) after the content
+	* of its line. `fg`/`bg`/`bold`/`italic` style it as in
+	* `addVirtualTextStyled`. The text reflows with its line when the
+	* line wraps. Remove it with `removeVirtualText`.
+	*/
+	setVirtualText(bufferId: number, virtualTextId: string, position: number, text: string, options?: Record<string, unknown>): boolean;
+	/**
 	* Remove virtual texts whose ID starts with the given prefix
 	*/
 	removeVirtualTextsByPrefix(bufferId: number, prefix: string): boolean;
@@ -1916,6 +1925,18 @@ interface EditorAPI {
 	*/
 	setVirtualBufferContent(bufferId: number, entriesArr: Record<string, unknown>[]): boolean;
 	/**
+	* Open an editable scratch buffer (no file, closed without a save
+	* prompt) as a new tab and switch to it (async, returns the buffer
+	* ID). `language` sets the highlighting; it defaults to detection
+	* from `name`.
+	*/
+	openScratchBuffer(name: string, content: string, language?: string): Promise<number>;
+	/**
+	* Append text to the end of a buffer (e.g. streaming output into a
+	* scratch buffer)
+	*/
+	appendToBuffer(bufferId: number, text: string): boolean;
+	/**
 	* Get text properties at cursor position (returns JS array)
 	*/
 	getTextPropertiesAtCursor(bufferId: number): TextPropertiesAtCursor;
@@ -1982,6 +2003,20 @@ interface EditorAPI {
 	*/
 	getBufferText(bufferId: number, start: number, end: number): Promise<string>;
 	/**
+	* Shorthand for `getBufferText` with the buffer last (async).
+	* `bufferId` defaults to the active buffer. Rejects if the range lies
+	* outside the buffer; offsets inside a character are widened to
+	* include the whole character.
+	*/
+	getText(start: number, end: number, bufferId?: number): Promise<string>;
+	/**
+	* Replace the text between byte offsets `start` and `end` with `text`
+	* as one undoable edit (async, resolves to true once applied).
+	* `bufferId` defaults to the active buffer; range rules match
+	* `getText`.
+	*/
+	replaceText(start: number, end: number, text: string, bufferId?: number): Promise<boolean>;
+	/**
 	* Delay/sleep (async, returns request_id)
 	*/
 	delay(durationMs: number): Promise<void>;
@@ -2084,6 +2119,9 @@ interface EditorAPI {
 * `action` in `pre_command`/`post_command` is the serde JSON of the `Action`
 * enum: unit variants serialize as a plain string (e.g. `"MoveLeft"`),
 * tuple variants as a single-key object (e.g. `{"InsertChar": "a"}`).
+*
+* A `before_file_open` or `before_file_save` handler that returns `false`
+* (synchronously) cancels the open or save.
 */
 interface HookEventMap {
 	// ── lifecycle ────────────────────────────────────────────────────────────
@@ -2115,6 +2153,7 @@ interface HookEventMap {
 	before_file_save: {
 		path: string;
 		buffer_id: number;
+		text: string | null;
 	};
 	after_file_save: {
 		path: string;
@@ -2195,6 +2234,10 @@ interface HookEventMap {
 		viewport_start: number;
 		viewport_end: number;
 		tokens: ViewTokenWire[];
+		lines: {
+			line_start: number;
+			tokens: ViewTokenWire[];
+		}[];
 		cursor_positions: number[];
 	};
 	// ── commands ─────────────────────────────────────────────────────────────
@@ -288,6 +288,9 @@ function loadThemeSections(): ThemeSection[] {
       });
     }
 
+    // Free-form maps (e.g. scope overrides) have no fixed fields to edit
+    if (fields.length === 0) continue;
+
     // Sort fields alphabetically (use simple comparison to avoid ICU issues in QuickJS)
     fields.sort((a, b) => (a.key < b.key ? -1 : a.key > b.key ? 1 : 0));
 
@@ -804,6 +804,21 @@ function vi_toggle_case() : void {
 }
 registerHandler("vi_toggle_case", vi_toggle_case);
 
+// Ctrl-A / Ctrl-X — add/subtract count to the number under or after the cursor
+function vi_increment() : void {
+  const count = consumeCount();
+  state.lastChange = { type: "simple", action: "increment_number", count };
+  executeWithCount("increment_number", count);
+}
+registerHandler("vi_increment", vi_increment);
+
+function vi_decrement() : void {
+  const count = consumeCount();
+  state.lastChange = { type: "simple", action: "decrement_number", count };
+  executeWithCount("decrement_number", count);
+}
+registerHandler("vi_decrement", vi_decrement);
+
 // Search
 function vi_search_forward() : void {
   editor.executeAction("search");
@@ -1761,6 +1776,8 @@ editor.defineMode("vi-normal", [
   // Other
   ["J", "vi_join"],
   ["~", "vi_toggle_case"],
+  ["C-a", "vi_increment"],
+  ["C-x", "vi_decrement"],
 
   // Command mode
   [":", "vi_command_mode"],
@@ -17,7 +17,6 @@ impl Editor {
     /// Convert an action into a list of events to apply to the active buffer
     /// Returns None for actions that don't generate events (like Quit)
     pub fn action_to_events(&mut self, action: Action) -> Option<Vec<Event>> {
-        let auto_indent = self.config.editor.auto_indent;
         let estimated_line_length = self.config.editor.estimated_line_length;
 
         // Use the *effective* active split: when the user is focused on an
@@ -62,7 +61,9 @@ impl Editor {
         let state = self.buffers.get_mut(&buffer_id).unwrap();
 
         // Use per-buffer settings which respect language overrides and user changes
-        let tab_size = state.buffer_settings.tab_size;
+        let tab_size = state.buffer_settings.indent_size();
+        // The global flag gates smart indent; the language can opt out per buffer.
+        let auto_indent = self.config.editor.auto_indent && state.buffer_settings.auto_indent;
         let auto_close = state.buffer_settings.auto_close;
         let auto_surround = state.buffer_settings.auto_surround;
 
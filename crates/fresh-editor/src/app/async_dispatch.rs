@@ -172,6 +172,18 @@ impl Editor {
                         tracing::error!("Error handling references response: {}", e);
                     }
                 }
+                AsyncMessage::LspDocumentSymbols {
+                    request_id,
+                    response,
+                } => {
+                    self.handle_document_symbols_response(request_id, response);
+                }
+                AsyncMessage::LspWorkspaceSymbols {
+                    request_id,
+                    response,
+                } => {
+                    self.handle_workspace_symbols_response(request_id, response);
+                }
                 AsyncMessage::LspSignatureHelp {
                     request_id,
                     signature_help,
@@ -212,23 +224,18 @@ impl Editor {
                         self.set_status_message(format!("Code action resolve failed: {e}"));
                     }
                 },
-                AsyncMessage::LspCompletionResolved {
-                    request_id: _,
-                    item,
-                } => {
+                AsyncMessage::LspCompletionResolved { request_id, item } => {
                     if let Ok(resolved) = item {
-                        self.handle_completion_resolved(resolved);
+                        self.handle_completion_resolved(request_id, resolved);
                     }
                 }
                 AsyncMessage::LspFormatting {
-                    request_id: _,
-                    uri,
+                    request_id,
+                    uri: _,
                     edits,
                 } => {
-                    if !edits.is_empty() {
-                        if let Err(e) = self.apply_formatting_edits(&uri, edits) {
-                            tracing::error!("Failed to apply formatting: {}", e);
-                        }
+                    if let Err(e) = self.handle_formatting_response(request_id, edits) {
+                        tracing::error!("Failed to apply formatting: {}", e);
                     }
                 }
                 AsyncMessage::LspPrepareRename {
@@ -243,8 +250,15 @@ impl Editor {
                     result_id,
                     diagnostics,
                     unchanged,
+                    server_name,
                 } => {
-                    self.handle_lsp_pulled_diagnostics(uri, result_id, diagnostics, unchanged);
+                    self.handle_lsp_pulled_diagnostics(
+                        uri,
+                        result_id,
+                        diagnostics,
+                        unchanged,
+                        server_name,
+                    );
                 }
                 AsyncMessage::LspInlayHints {
                     request_id,
@@ -292,6 +306,9 @@ impl Editor {
                 AsyncMessage::FileExplorerExpandedToPath(view) => {
                     self.handle_file_explorer_expanded_to_path(view);
                 }
+                AsyncMessage::FileExplorerFilterLoaded(listings) => {
+                    self.handle_file_explorer_filter_loaded(listings);
+                }
                 AsyncMessage::Plugin(plugin_msg) => {
                     use fresh_core::api::{JsCallbackId, PluginAsyncMessage};
                     match plugin_msg {
@@ -418,8 +435,12 @@ impl Editor {
                 } => {
                     self.handle_lsp_status_update(language, server_name, status);
                 }
-                AsyncMessage::FileOpenDirectoryLoaded(result) => {
-                    self.handle_file_open_directory_loaded(result);
+                AsyncMessage::FileOpenDirectoryLoaded {
+                    path,
+                    typed,
+                    result,
+                } => {
+                    self.handle_file_open_directory_loaded(path, typed, result);
                 }
                 AsyncMessage::FileOpenShortcutsLoaded(shortcuts) => {
                     self.handle_file_open_shortcuts_loaded(shortcuts);
@@ -450,6 +471,21 @@ impl Editor {
                         }
                     }
                 }
+                AsyncMessage::CustomCommandOutput {
+                    buffer_id,
+                    run_id,
+                    text,
+                } => {
+                    self.append_custom_command_output(buffer_id, run_id, text);
+                }
+                AsyncMessage::CustomCommandExited {
+                    buffer_id,
+                    run_id,
+                    name,
+                    exit_code,
+                } => {
+                    self.finish_custom_command(buffer_id, run_id, &name, exit_code);
+                }
                 AsyncMessage::TerminalExited { terminal_id } => {
                     tracing::info!("Terminal {:?} exited", terminal_id);
                     // Find the buffer associated with this terminal
@@ -700,8 +736,14 @@ impl Editor {
             let _s = tracing::info_span!("poll_file_tree_changes").entered();
             self.poll_file_tree_changes()
         };
+        let theme_changes = self.poll_theme_file_changes();
 
         // Trigger render if any async messages, plugin commands were processed, or plugin requested render
-        needs_render || processed_any_commands || plugin_render || file_changes || tree_changes
+        needs_render
+            || processed_any_commands
+            || plugin_render
+            || file_changes
+            || tree_changes
+            || theme_changes
     }
 }
@@ -9,6 +9,7 @@
 
 use crate::model::buffer::Buffer;
 use crate::model::event::BufferId;
+use crate::model::filesystem::DirEntry;
 use crate::services::async_bridge::{
     LspMessageType, LspProgressValue, LspSemanticTokensResponse, LspServerStatus,
 };
@@ -21,6 +22,7 @@ use lsp_types::{
 };
 use rust_i18n::t;
 use serde_json::Value;
+use std::collections::HashSet;
 use std::path::PathBuf;
 use std::time::{Duration, Instant};
 
@@ -106,16 +108,37 @@ impl Editor {
 impl Editor {
     /// Merge push + pull diagnostics for a URI and apply the combined set
     fn merge_and_apply_diagnostics(&mut self, uri: &str) {
-        // Merge diagnostics from all servers (push model) and pull model
-        let mut merged = Vec::new();
-        if let Some(server_map) = self.stored_push_diagnostics.get(uri) {
-            for diagnostics in server_map.values() {
-                merged.extend(diagnostics.iter().cloned());
+        // Merge diagnostics from all servers, push model first, then pull model.
+        // Servers are visited in name order so the merged list is stable, and
+        // a diagnostic without a `source` is tagged with the server that
+        // reported it. Servers reporting the same problem (same range,
+        // severity and message) show it once.
+        let mut merged: Vec<Diagnostic> = Vec::new();
+        let mut seen = HashSet::new();
+        for stored in [&self.stored_push_diagnostics, &self.stored_pull_diagnostics] {
+            let Some(server_map) = stored.get(uri) else {
+                continue;
+            };
+            let mut servers: Vec<_> = server_map.iter().collect();
+            servers.sort_by(|(a, _), (b, _)| a.cmp(b));
+            for (server_name, diagnostics) in servers {
+                for diagnostic in diagnostics {
+                    let range = diagnostic.range;
+                    let key = (
+                        (range.start.line, range.start.character),
+                        (range.end.line, range.end.character),
+                        crate::services::lsp::diagnostics::severity_value(diagnostic.severity),
+                        diagnostic.message.as_str(),
+                    );
+                    if !seen.insert(key) {
+                        continue;
+                    }
+                    let mut diagnostic = diagnostic.clone();
+                    diagnostic.source.get_or_insert_with(|| server_name.clone());
+                    merged.push(diagnostic);
+                }
             }
         }
-        if let Some(pull) = self.stored_pull_diagnostics.get(uri) {
-            merged.extend(pull.iter().cloned());
-        }
 
         // Update the merged view
         if merged.is_empty() {
@@ -203,7 +226,21 @@ impl Editor {
         result_id: Option<String>,
         diagnostics: Vec<Diagnostic>,
         unchanged: bool,
+        server_name: String,
     ) {
+        // Discard responses from servers that have been shut down, as for
+        // push diagnostics.
+        if let Some(lsp) = &self.lsp {
+            if !lsp.has_server_named(&server_name) {
+                tracing::debug!(
+                    "Dropping pulled diagnostics from stopped server '{}' for {}",
+                    server_name,
+                    uri
+                );
+                return;
+            }
+        }
+
         if unchanged {
             tracing::debug!(
                 "Diagnostics unchanged for {} (result_id: {:?})",
@@ -214,8 +251,9 @@ impl Editor {
         }
 
         tracing::debug!(
-            "Processing {} pulled diagnostics for {} (result_id: {:?})",
+            "Processing {} pulled diagnostics from '{}' for {} (result_id: {:?})",
             diagnostics.len(),
+            server_name,
             uri,
             result_id
         );
@@ -225,11 +263,15 @@ impl Editor {
             self.diagnostic_result_ids.insert(uri.clone(), result_id);
         }
 
+        let server_map = self.stored_pull_diagnostics.entry(uri.clone()).or_default();
         if diagnostics.is_empty() {
-            self.stored_pull_diagnostics.remove(&uri);
+            server_map.remove(&server_name);
+            // Clean up empty outer entry
+            if server_map.is_empty() {
+                self.stored_pull_diagnostics.remove(&uri);
+            }
         } else {
-            self.stored_pull_diagnostics
-                .insert(uri.clone(), diagnostics);
+            server_map.insert(server_name, diagnostics);
         }
 
         self.merge_and_apply_diagnostics(&uri);
@@ -237,14 +279,16 @@ impl Editor {
 
     /// Clear all diagnostics originating from a specific server.
     ///
-    /// Removes the server's entries from `stored_push_diagnostics`, then
-    /// re-merges and re-applies diagnostics for every affected URI so that
-    /// overlays on screen are updated immediately.
+    /// Removes the server's entries from `stored_push_diagnostics` and
+    /// `stored_pull_diagnostics`, then re-merges and re-applies diagnostics
+    /// for every affected URI so that overlays on screen are updated
+    /// immediately.
     pub(crate) fn clear_diagnostics_for_server(&mut self, server_name: &str) {
         // Collect URIs that have diagnostics from this server.
-        let affected_uris: Vec<String> = self
+        let mut affected_uris: Vec<String> = self
             .stored_push_diagnostics
             .iter()
+            .chain(self.stored_pull_diagnostics.iter())
             .filter_map(|(uri, server_map)| {
                 if server_map.contains_key(server_name) {
                     Some(uri.clone())
@@ -253,6 +297,8 @@ impl Editor {
                 }
             })
             .collect();
+        affected_uris.sort();
+        affected_uris.dedup();
 
         if affected_uris.is_empty() {
             return;
@@ -265,10 +311,15 @@ impl Editor {
         );
 
         for uri in &affected_uris {
-            if let Some(server_map) = self.stored_push_diagnostics.get_mut(uri) {
-                server_map.remove(server_name);
-                if server_map.is_empty() {
-                    self.stored_push_diagnostics.remove(uri);
+            for stored in [
+                &mut self.stored_push_diagnostics,
+                &mut self.stored_pull_diagnostics,
+            ] {
+                if let Some(server_map) = stored.get_mut(uri) {
+                    server_map.remove(server_name);
+                    if server_map.is_empty() {
+                        stored.remove(uri);
+                    }
                 }
             }
 
@@ -1261,6 +1312,16 @@ impl Editor {
         self.file_explorer = Some(view);
         self.file_explorer_sync_in_progress = false;
     }
+
+    /// Handle file explorer subtree listed for an active filter
+    pub(super) fn handle_file_explorer_filter_loaded(
+        &mut self,
+        listings: Vec<(PathBuf, Vec<DirEntry>)>,
+    ) {
+        if let Some(explorer) = &mut self.file_explorer {
+            explorer.apply_filter_listings(listings);
+        }
+    }
 }
 
 // =============================================================================
@@ -3,10 +3,15 @@
 //! Cross-cutting effects — cursor movement, status messages, lazy
 //! cleanup of bookmarks pointing at closed buffers — for the bookmark
 //! subsystem. Plain data state lives in `super::bookmarks::BookmarkState`.
+//! Anonymous bookmarks are toggled on the cursor's line and cycled with
+//! `next_bookmark` / `prev_bookmark` within the active buffer.
 
 use rust_i18n::t;
 
+use crate::input::commands::Suggestion;
 use crate::model::event::Event;
+use crate::model::marker::MarkerId;
+use crate::view::prompt::{Prompt, PromptType};
 
 use super::Editor;
 
@@ -42,11 +47,17 @@ impl Editor {
             }
         }
 
-        // Move cursor to bookmark position
+        self.move_cursor_to_bookmark(bookmark.position);
+        self.set_status_message(t!("bookmark.jumped", key = key).to_string());
+    }
+
+    /// Move the primary cursor to a bookmarked position in the active
+    /// buffer and scroll it into view.
+    fn move_cursor_to_bookmark(&mut self, position: usize) {
         let cursor = *self.active_cursors().primary();
         let cursor_id = self.active_cursors().primary_id();
         let state = self.active_state_mut();
-        let new_pos = bookmark.position.min(state.buffer.len());
+        let new_pos = position.min(state.buffer.len());
 
         let event = Event::MoveCursor {
             cursor_id,
@@ -64,7 +75,112 @@ impl Editor {
         // to follow the jump even when the bookmark target is in the same
         // buffer that's already visible (#1689).
         self.ensure_active_cursor_visible_for_navigation(true);
-        self.set_status_message(t!("bookmark.jumped", key = key).to_string());
+    }
+
+    /// Add an anonymous bookmark on the cursor's line, or remove the one
+    /// already there.
+    pub(super) fn toggle_bookmark(&mut self) {
+        let buffer_id = self.active_buffer();
+        let cursor_pos = self.active_cursors().primary().position;
+        let markers = self.bookmarks.anonymous(buffer_id).to_vec();
+        let state = self.active_state_mut();
+        let line = state.buffer.get_line_number(cursor_pos);
+        let line_start = state.buffer.line_start_offset(line).unwrap_or(cursor_pos);
+        let line_end = state
+            .buffer
+            .line_start_offset(line + 1)
+            .unwrap_or(state.buffer.len() + 1);
+
+        let existing: Vec<MarkerId> = markers
+            .into_iter()
+            .filter(|marker| {
+                state
+                    .marker_list
+                    .get_position(*marker)
+                    .is_some_and(|pos| pos >= line_start && pos < line_end)
+            })
+            .collect();
+
+        if existing.is_empty() {
+            let marker = state.marker_list.create(line_start, true);
+            self.bookmarks.add_anonymous(buffer_id, marker);
+            self.set_status_message(t!("bookmark.toggled_on", line = line + 1).to_string());
+        } else {
+            for marker in &existing {
+                state.marker_list.delete(*marker);
+            }
+            for marker in existing {
+                self.bookmarks.remove_anonymous(buffer_id, marker);
+            }
+            self.set_status_message(t!("bookmark.toggled_off", line = line + 1).to_string());
+        }
+    }
+
+    /// Move to the next anonymous bookmark in the active buffer, wrapping
+    /// to the first.
+    pub(super) fn next_bookmark(&mut self) {
+        let cursor_pos = self.active_cursors().primary().position;
+        let Some(positions) = self.anonymous_bookmark_positions() else {
+            return;
+        };
+
+        let next_pos = positions
+            .iter()
+            .find(|&&pos| pos > cursor_pos)
+            .or_else(|| positions.first()) // Wrap around
+            .copied();
+
+        if let Some(new_pos) = next_pos {
+            self.move_cursor_to_bookmark(new_pos);
+        }
+    }
+
+    /// Move to the previous anonymous bookmark in the active buffer,
+    /// wrapping to the last.
+    pub(super) fn prev_bookmark(&mut self) {
+        let cursor_pos = self.active_cursors().primary().position;
+        // Compare against the line start so a cursor in the middle of a
+        // bookmarked line moves past that line's own bookmark
+        let state = self.active_state();
+        let line = state.buffer.get_line_number(cursor_pos);
+        let line_start = state.buffer.line_start_offset(line).unwrap_or(cursor_pos);
+        let Some(positions) = self.anonymous_bookmark_positions() else {
+            return;
+        };
+
+        let prev_pos = positions
+            .iter()
+            .rev()
+            .find(|&&pos| pos < line_start)
+            .or_else(|| positions.last()) // Wrap around
+            .copied();
+
+        if let Some(new_pos) = prev_pos {
+            self.move_cursor_to_bookmark(new_pos);
+        }
+    }
+
+    /// Sorted, deduplicated positions of the active buffer's anonymous
+    /// bookmarks. Shows a status message and returns `None` when there are
+    /// none.
+    fn anonymous_bookmark_positions(&mut self) -> Option<Vec<usize>> {
+        let buffer_id = self.active_buffer();
+        let state = self.active_state();
+        let mut positions: Vec<usize> = self
+            .bookmarks
+            .anonymous(buffer_id)
+            .iter()
+            .filter_map(|marker| state.marker_list.get_position(*marker))
+            .collect();
+
+        if positions.is_empty() {
+            self.set_status_message(t!("bookmark.none_in_buffer").to_string());
+            return None;
+        }
+
+        positions.sort_unstable();
+        positions.dedup();
+        Some(positions)
     }
 
     /// Clear a bookmark
@@ -76,7 +192,8 @@ impl Editor {
         }
     }
 
-    /// List all bookmarks
+    /// Open a prompt listing all bookmarks with a preview of each
+    /// bookmarked line. Enter jumps to the selection; Delete removes it.
     pub(super) fn list_bookmarks(&mut self) {
         if self.bookmarks.is_empty() {
             self.set_status_message(t!("bookmark.none_set").to_string());
@@ -87,19 +204,86 @@ impl Editor {
             self.bookmarks.iter().collect();
         bookmark_list.sort_by_key(|(k, _)| *k);
 
-        let list_str: String = bookmark_list
-            .iter()
-            .map(|(k, bm)| {
-                let buffer_name = self
-                    .buffer_metadata
-                    .get(&bm.buffer_id)
-                    .map(|m| m.display_name.as_str())
-                    .unwrap_or("unknown");
-                format!("'{}': {} @ {}", k, buffer_name, bm.position)
-            })
-            .collect::<Vec<_>>()
-            .join(", ");
+        let suggestions = bookmark_list
+            .into_iter()
+            .map(|(key, bm)| self.bookmark_suggestion(key, bm))
+            .collect();
+
+        self.prompt = Some(Prompt::with_suggestions(
+            t!("bookmark.list_prompt").to_string(),
+            PromptType::ListBookmarks,
+            suggestions,
+        ));
+    }
+
+    /// Build the list entry for one bookmark: `'key'  file:line` with the
+    /// line's text as the description.
+    fn bookmark_suggestion(&self, key: char, bm: super::bookmarks::Bookmark) -> Suggestion {
+        let buffer_name = self
+            .buffer_metadata
+            .get(&bm.buffer_id)
+            .map(|m| m.display_name.as_str())
+            .unwrap_or("unknown");
+
+        let (line, preview) = match self.buffers.get(&bm.buffer_id) {
+            Some(state) => {
+                let position = bm.position.min(state.buffer.len());
+                let line = state.buffer.get_line_number(position);
+                let preview = state
+                    .buffer
+                    .get_line(line)
+                    .map(|bytes| String::from_utf8_lossy(&bytes).trim().to_string())
+                    .filter(|text| !text.is_empty());
+                (Some(line + 1), preview)
+            }
+            None => (None, None),
+        };
 
-        self.set_status_message(t!("bookmark.list", list = list_str).to_string());
+        let text = match line {
+            Some(line) => format!("'{}'  {}:{}", key, buffer_name, line),
+            None => format!("'{}'  {}", key, buffer_name),
+        };
+
+        Suggestion {
+            text,
+            description: preview,
+            value: Some(key.to_string()),
+            disabled: false,
+            keybinding: None,
+            source: None,
+            match_positions: Vec::new(),
+        }
+    }
+
+    /// Remove the bookmark selected in the bookmark list prompt and drop
+    /// it from the list, closing the prompt once none remain.
+    pub(super) fn delete_selected_bookmark(&mut self) {
+        let Some(prompt) = self.prompt.as_mut() else {
+            return;
+        };
+        let Some(key) = prompt
+            .selected_suggestion
+            .and_then(|idx| prompt.suggestions.get(idx))
+            .and_then(|s| s.get_value().chars().next())
+        else {
+            return;
+        };
+
+        let value = key.to_string();
+        prompt.suggestions.retain(|s| s.get_value() != value);
+        if let Some(original) = prompt.original_suggestions.as_mut() {
+            original.retain(|s| s.get_value() != value);
+        }
+        prompt.selected_suggestion = match prompt.selected_suggestion {
+            _ if prompt.suggestions.is_empty() => None,
+            Some(idx) => Some(idx.min(prompt.suggestions.len() - 1)),
+            None => None,
+        };
+
+        self.bookmarks.remove(key);
+        if self.bookmarks.is_empty() {
+            self.cancel_prompt();
+        }
+        self.set_status_message(t!("bookmark.cleared", key = key).to_string());
     }
 }
@@ -1,13 +1,17 @@
 //! Self-contained bookmark storage.
 //!
 //! A bookmark remembers a `(buffer_id, byte_position)` under a single-char
-//! register. The state is deliberately minimal: lookup, insert, remove,
-//! iterate. Cross-cutting work — checking that a bookmark's target buffer
-//! still exists, jumping the cursor, rendering a list — lives on `Editor`.
+//! register. Anonymous bookmarks have no register: they are toggled per
+//! line and stored as markers in the owning buffer's `MarkerList`, so they
+//! shift with edits. The state is deliberately minimal: lookup, insert,
+//! remove, iterate. Cross-cutting work — checking that a bookmark's target
+//! buffer still exists, jumping the cursor, rendering a list — lives on
+//! `Editor`.
 
 use std::collections::HashMap;
 
 use crate::model::event::BufferId;
+use crate::model::marker::MarkerId;
 
 /// A bookmark: a position within a specific buffer.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -16,10 +20,12 @@ pub(crate) struct Bookmark {
     pub position: usize,
 }
 
-/// Owner of the register -> bookmark map.
+/// Owner of the register -> bookmark map and the per-buffer anonymous
+/// bookmark markers.
 #[derive(Debug, Default)]
 pub(crate) struct BookmarkState {
     bookmarks: HashMap<char, Bookmark>,
+    anonymous: HashMap<BufferId, Vec<MarkerId>>,
 }
 
 impl BookmarkState {
@@ -48,6 +54,42 @@ impl BookmarkState {
     pub(crate) fn iter(&self) -> impl Iterator<Item = (char, Bookmark)> + '_ {
         self.bookmarks.iter().map(|(k, v)| (*k, *v))
     }
+
+    /// Markers of the anonymous bookmarks in `buffer_id`, in insertion order.
+    pub(crate) fn anonymous(&self, buffer_id: BufferId) -> &[MarkerId] {
+        self.anonymous
+            .get(&buffer_id)
+            .map(Vec::as_slice)
+            .unwrap_or(&[])
+    }
+
+    /// Record an anonymous bookmark anchored at `marker` in `buffer_id`.
+    pub(crate) fn add_anonymous(&mut self, buffer_id: BufferId, marker: MarkerId) {
+        self.anonymous.entry(buffer_id).or_default().push(marker);
+    }
+
+    /// Forget the anonymous bookmark anchored at `marker`. Returns `true`
+    /// if it existed.
+    pub(crate) fn remove_anonymous(&mut self, buffer_id: BufferId, marker: MarkerId) -> bool {
+        let Some(markers) = self.anonymous.get_mut(&buffer_id) else {
+            return false;
+        };
+        let before = markers.len();
+        markers.retain(|m| *m != marker);
+        let removed = markers.len() != before;
+        if markers.is_empty() {
+            self.anonymous.remove(&buffer_id);
+        }
+        removed
+    }
+
+    /// Iterate over every buffer's anonymous bookmark markers — used by
+    /// workspace serialization.
+    pub(crate) fn iter_anonymous(&self) -> impl Iterator<Item = (BufferId, &[MarkerId])> + '_ {
+        self.anonymous
+            .iter()
+            .map(|(buffer_id, markers)| (*buffer_id, markers.as_slice()))
+    }
 }
 
 #[cfg(test)]
@@ -107,4 +149,20 @@ mod tests {
         seen.sort_by_key(|(k, _)| *k);
         assert_eq!(seen, vec![('a', bm(1, 10)), ('b', bm(2, 20))]);
     }
+
+    #[test]
+    fn anonymous_bookmarks_are_tracked_per_buffer() {
+        let mut s = BookmarkState::default();
+        s.add_anonymous(BufferId(1), MarkerId(10));
+        s.add_anonymous(BufferId(1), MarkerId(11));
+        s.add_anonymous(BufferId(2), MarkerId(20));
+        assert_eq!(s.anonymous(BufferId(1)), &[MarkerId(10), MarkerId(11)]);
+        assert_eq!(s.anonymous(BufferId(2)), &[MarkerId(20)]);
+
+        assert!(s.remove_anonymous(BufferId(1), MarkerId(10)));
+        assert!(!s.remove_anonymous(BufferId(1), MarkerId(10)));
+        assert_eq!(s.anonymous(BufferId(1)), &[MarkerId(11)]);
+        // Named bookmarks are unaffected
+        assert!(s.is_empty());
+    }
 }
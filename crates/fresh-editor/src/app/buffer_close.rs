@@ -21,7 +21,7 @@ impl Editor {
     pub fn close_buffer(&mut self, id: BufferId) -> anyhow::Result<()> {
         // Check for unsaved changes
         if let Some(state) = self.buffers.get(&id) {
-            if state.buffer.is_modified() {
+            if state.buffer.is_modified() && !self.is_scratch_buffer(id) {
                 return Err(anyhow::anyhow!("Buffer has unsaved changes"));
             }
         }
@@ -259,6 +259,9 @@ impl Editor {
         self.semantic_tokens_range_last_request.remove(&id);
         self.semantic_tokens_range_applied.remove(&id);
         self.semantic_tokens_full_debounce.remove(&id);
+        self.inlay_hints_coverage.remove(&id);
+        self.inlay_hints_viewport.remove(&id);
+        self.scheduled_inlay_hints_requests.remove(&id);
 
         // Remove buffer from panel_ids mapping if it was a panel buffer
         // This prevents stale entries when the same panel_id is reused later
@@ -375,7 +378,7 @@ impl Editor {
         if is_last_viewport {
             // Last viewport of this buffer - need to close buffer entirely
             if let Some(state) = self.buffers.get(&buffer_id) {
-                if state.buffer.is_modified() {
+                if state.buffer.is_modified() && !self.is_scratch_buffer(buffer_id) {
                     // Buffer has unsaved changes - prompt for confirmation
                     let name = self.get_buffer_display_name(buffer_id);
                     let save_key = t!("prompt.key.save").to_string();
@@ -450,7 +453,108 @@ impl Editor {
         true
     }
 
-    /// Close all other tabs in a split, keeping only the specified buffer
+    /// Close every tab in the active split except the current one, asking
+    /// once first if any of them has unsaved changes
+    pub fn close_other_tabs(&mut self) {
+        let split_id = self.split_manager.active_split();
+        self.close_other_tabs_in_split(self.active_buffer(), split_id);
+    }
+
+    /// Close the tabs after the current one in the active split, asking
+    /// once first if any of them has unsaved changes
+    pub fn close_tabs_to_right(&mut self) {
+        let split_id = self.split_manager.active_split();
+        self.close_tabs_to_right_in_split(self.active_buffer(), split_id);
+    }
+
+    /// Close `buffer_ids` in a split, or prompt once to save or discard
+    /// them first when closing would lose unsaved changes
+    fn confirm_close_tabs(
+        &mut self,
+        split_id: LeafId,
+        buffer_ids: Vec<BufferId>,
+        keep_buffer_id: BufferId,
+    ) {
+        let modified = buffer_ids
+            .iter()
+            .filter(|&&id| self.tab_close_loses_changes(id, split_id))
+            .count();
+        if modified == 0 {
+            self.close_tabs_in_split(buffer_ids, split_id, keep_buffer_id, false);
+            return;
+        }
+
+        let save_key = t!("prompt.key.save").to_string();
+        let discard_key = t!("prompt.key.discard").to_string();
+        let cancel_key = t!("prompt.key.cancel").to_string();
+        self.start_prompt(
+            t!(
+                "prompt.tabs_modified",
+                count = modified,
+                save_key = save_key,
+                discard_key = discard_key,
+                cancel_key = cancel_key
+            )
+            .to_string(),
+            PromptType::ConfirmCloseTabs {
+                split_id,
+                buffer_ids,
+                keep_buffer_id,
+            },
+        );
+    }
+
+    /// Whether closing `buffer_id`'s tab in `split_id` would close a
+    /// modified buffer, i.e. no other split shows it
+    pub(super) fn tab_close_loses_changes(&self, buffer_id: BufferId, split_id: LeafId) -> bool {
+        let in_other_splits = self
+            .split_view_states
+            .iter()
+            .any(|(&sid, view_state)| sid != split_id && view_state.has_buffer(buffer_id));
+        !in_other_splits
+            && !self.is_scratch_buffer(buffer_id)
+            && self
+                .buffers
+                .get(&buffer_id)
+                .is_some_and(|state| state.buffer.is_modified())
+    }
+
+    /// Close `buffer_ids` in a split, then show `keep_buffer_id`. Modified
+    /// buffers are discarded when `discard_modified` is set, otherwise
+    /// skipped.
+    pub(super) fn close_tabs_in_split(
+        &mut self,
+        buffer_ids: Vec<BufferId>,
+        split_id: LeafId,
+        keep_buffer_id: BufferId,
+        discard_modified: bool,
+    ) {
+        let mut closed = 0;
+        let mut skipped_modified = 0;
+        for buffer_id in buffer_ids {
+            if discard_modified && self.tab_close_loses_changes(buffer_id, split_id) {
+                match self.force_close_buffer(buffer_id) {
+                    Ok(()) => closed += 1,
+                    Err(e) => {
+                        tracing::warn!("Failed to close buffer: {}", e);
+                        skipped_modified += 1;
+                    }
+                }
+            } else if self.close_tab_in_split_silent(buffer_id, split_id) {
+                closed += 1;
+            } else {
+                skipped_modified += 1;
+            }
+        }
+
+        // Make sure the kept buffer is active
+        self.set_pane_buffer(split_id, keep_buffer_id);
+
+        self.set_batch_close_status_message(closed, skipped_modified);
+    }
+
+    /// Close all other tabs in a split, keeping only the specified buffer,
+    /// asking once first if any of them has unsaved changes
     pub fn close_other_tabs_in_split(&mut self, keep_buffer_id: BufferId, split_id: LeafId) {
         // Get the split's open buffers
         let split_tabs = self
@@ -466,24 +570,11 @@ impl Editor {
             .copied()
             .collect();
 
-        let mut closed = 0;
-        let mut skipped_modified = 0;
-        for buffer_id in tabs_to_close {
-            if self.close_tab_in_split_silent(buffer_id, split_id) {
-                closed += 1;
-            } else {
-                skipped_modified += 1;
-            }
-        }
-
-        // Make sure the kept buffer is active
-        self.split_manager
-            .set_split_buffer(split_id, keep_buffer_id);
-
-        self.set_batch_close_status_message(closed, skipped_modified);
+        self.confirm_close_tabs(split_id, tabs_to_close, keep_buffer_id);
     }
 
-    /// Close tabs to the right of the specified buffer in a split
+    /// Close tabs to the right of the specified buffer in a split, which
+    /// stays shown, asking once first if any of them has unsaved changes
     pub fn close_tabs_to_right_in_split(&mut self, buffer_id: BufferId, split_id: LeafId) {
         // Get the split's open buffers
         let split_tabs = self
@@ -500,17 +591,7 @@ impl Editor {
         // Close all tabs after the target
         let tabs_to_close: Vec<_> = split_tabs.iter().skip(target_idx + 1).copied().collect();
 
-        let mut closed = 0;
-        let mut skipped_modified = 0;
-        for buf_id in tabs_to_close {
-            if self.close_tab_in_split_silent(buf_id, split_id) {
-                closed += 1;
-            } else {
-                skipped_modified += 1;
-            }
-        }
-
-        self.set_batch_close_status_message(closed, skipped_modified);
+        self.confirm_close_tabs(split_id, tabs_to_close, buffer_id);
     }
 
     /// Close tabs to the left of the specified buffer in a split
@@ -608,7 +689,7 @@ impl Editor {
             // Last viewport of this buffer - need to close buffer entirely
             // Skip modified buffers to avoid prompting during batch operations
             if let Some(state) = self.buffers.get(&buffer_id) {
-                if state.buffer.is_modified() {
+                if state.buffer.is_modified() && !self.is_scratch_buffer(buffer_id) {
                     // Skip modified buffers - don't close them
                     return false;
                 }
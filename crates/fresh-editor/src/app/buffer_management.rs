@@ -530,7 +530,7 @@ impl Editor {
             .set_default_line_ending(self.config.editor.default_line_ending.to_line_ending());
         self.buffers.insert(buffer_id, state);
         self.event_logs
-            .insert(buffer_id, crate::model::event::EventLog::new());
+            .insert(buffer_id, self.new_event_log(buffer_id));
         self.buffer_metadata
             .insert(buffer_id, crate::app::types::BufferMetadata::new());
 
@@ -548,6 +548,8 @@ impl Editor {
                 self.config.editor.highlight_current_line,
                 line_wrap,
                 self.config.editor.wrap_indent,
+                self.config.editor.wrap_mode,
+                self.config.editor.wrap_indent_extra,
                 wrap_column,
                 self.config.editor.rulers.clone(),
             );
@@ -1,6 +1,7 @@
 //! Click and scroll-position helpers on `Editor`.
 //!
-//! - `move_cursor_to_visible_area` and `calculate_max_scroll_position`:
+//! - `move_cursor_to_visible_area`, `move_cursor_to_visible_columns` and
+//!   `calculate_max_scroll_position`:
 //!   small helpers that fix up cursor position after scroll-driven moves
 //!   so the user keeps a visible cursor.
 //! - `fold_toggle_line_at_screen_position`: maps a click in the gutter to
@@ -67,6 +68,51 @@ impl Editor {
         }
     }
 
+    /// Move the cursor into the visible columns of the current viewport
+    /// This is called after horizontal scrolling so the cursor stays on its
+    /// line but doesn't end up off-screen
+    pub(super) fn move_cursor_to_visible_columns(&mut self, split_id: LeafId, buffer_id: BufferId) {
+        let (Some(view_state), Some(state)) = (
+            self.split_view_states.get_mut(&split_id),
+            self.buffers.get(&buffer_id),
+        ) else {
+            return;
+        };
+        let buffer = &state.buffer;
+        let viewport = &view_state.viewport;
+
+        let scrollbar_width = 1;
+        let visible_width = (viewport.width as usize)
+            .saturating_sub(viewport.gutter_width(buffer))
+            .saturating_sub(scrollbar_width);
+        if visible_width == 0 {
+            return;
+        }
+
+        let tab_size = state.buffer_settings.tab_size;
+        let cursor_pos = view_state.cursors.primary().position;
+        let (line, column) = buffer.position_to_line_col(cursor_pos);
+        let Some(bytes) = buffer.get_line(line) else {
+            return;
+        };
+
+        // Offsets are taken on the raw bytes so invalid UTF-8 can't shift them
+        let cursor_col = super::prompt_actions::byte_to_visual_column(&bytes, column, tab_size);
+        let first_col = viewport.left_column;
+        let last_col = first_col + visible_width - 1;
+        if (first_col..=last_col).contains(&cursor_col) {
+            return;
+        }
+
+        // On a line shorter than the scroll, this lands on the line end
+        let target_col = cursor_col.clamp(first_col, last_col);
+        let line_start = cursor_pos.saturating_sub(column);
+        let cursor = view_state.cursors.primary_mut();
+        cursor.position =
+            line_start + super::prompt_actions::visual_column_to_byte(&bytes, target_col, tab_size);
+        // Keep the existing sticky_column value so vertical navigation preserves column
+    }
+
     /// Calculate the maximum allowed scroll position
     /// Ensures the last line is always at the bottom unless the buffer is smaller than viewport
     pub(super) fn calculate_max_scroll_position(
@@ -0,0 +1,110 @@
+//! View state of a session client that dropped its connection.
+//!
+//! Every client attached to a session shares one editor, so while a client
+//! is away (an SSH blip) the others can move focus and scroll. The server
+//! captures what the client was looking at when it dropped and restores it
+//! if the client reconnects in time.
+
+use super::navigation::reconcile_restored_buffer_view;
+use super::Editor;
+use crate::model::cursor::Cursor;
+use crate::model::event::{BufferId, LeafId};
+
+/// Focused split and per-split cursor and scroll position, as a client
+/// last saw them.
+#[derive(Debug, Clone)]
+pub struct ClientView {
+    active_split: LeafId,
+    splits: Vec<SplitView>,
+}
+
+/// What one split showed
+#[derive(Debug, Clone)]
+struct SplitView {
+    split: LeafId,
+    buffer: BufferId,
+    cursor: Cursor,
+    top_byte: usize,
+    top_view_line_offset: usize,
+    left_column: usize,
+}
+
+impl Editor {
+    /// Capture the focused split and what every split shows.
+    pub fn capture_client_view(&self) -> ClientView {
+        let splits = self
+            .split_view_states
+            .iter()
+            .map(|(&split, view_state)| SplitView {
+                split,
+                buffer: view_state.active_buffer,
+                cursor: view_state.cursors.primary().clone(),
+                top_byte: view_state.viewport.top_byte,
+                top_view_line_offset: view_state.viewport.top_view_line_offset,
+                left_column: view_state.viewport.left_column,
+            })
+            .collect();
+        ClientView {
+            active_split: self.split_manager.active_split(),
+            splits,
+        }
+    }
+
+    /// Restore a view captured by [`Editor::capture_client_view`].
+    ///
+    /// Splits and buffers closed in the meantime are skipped, and positions
+    /// past the end of a buffer that shrank are clamped.
+    pub fn restore_client_view(&mut self, view: &ClientView) {
+        for saved in &view.splits {
+            if !self.is_open_in_split(saved.split, saved.buffer) {
+                continue;
+            }
+            self.set_pane_buffer(saved.split, saved.buffer);
+
+            let (Some(view_state), Some(state)) = (
+                self.split_view_states.get_mut(&saved.split),
+                self.buffers.get_mut(&saved.buffer),
+            ) else {
+                continue;
+            };
+            let Some(buf_state) = view_state.buffer_state_mut(saved.buffer) else {
+                continue;
+            };
+            let max_pos = state.buffer.len();
+            let cursor = buf_state.cursors.primary_mut();
+            cursor.position = saved.cursor.position.min(max_pos);
+            cursor.anchor = saved.cursor.anchor.map(|a| a.min(max_pos));
+            cursor.sticky_column = saved.cursor.sticky_column;
+
+            buf_state.viewport.top_byte = saved.top_byte.min(max_pos);
+            buf_state.viewport.top_view_line_offset = saved.top_view_line_offset;
+            buf_state.viewport.left_column = saved.left_column;
+            buf_state.viewport.set_skip_resize_sync();
+            reconcile_restored_buffer_view(buf_state, &mut state.buffer);
+        }
+
+        let in_tree = self
+            .split_manager
+            .root()
+            .leaf_split_ids()
+            .contains(&view.active_split);
+        let active_buffer = view
+            .splits
+            .iter()
+            .find(|saved| saved.split == view.active_split)
+            .map(|saved| saved.buffer)
+            .filter(|&buffer| self.is_open_in_split(view.active_split, buffer));
+        if let (true, Some(buffer)) = (in_tree, active_buffer) {
+            self.focus_split(view.active_split, buffer);
+        }
+    }
+
+    /// Whether `buffer` still exists and is one of `split`'s tabs
+    fn is_open_in_split(&self, split: LeafId, buffer: BufferId) -> bool {
+        self.buffers.contains_key(&buffer)
+            && self
+                .split_view_states
+                .get(&split)
+                .is_some_and(|view_state| view_state.has_buffer(buffer))
+    }
+}
@@ -35,6 +35,30 @@ impl Editor {
     /// If no selection exists, copies the entire current line (like VSCode/Rider/Zed).
     /// For block selections, copies only the rectangular region.
     pub fn copy_selection(&mut self) {
+        self.copy_selection_into(None);
+    }
+
+    /// Copy the current selection (or line) into a named register, leaving
+    /// the clipboard untouched.
+    pub fn copy_selection_to_register(&mut self, register: char) {
+        if self.copy_selection_into(Some(register)) {
+            self.status_message = Some(t!("register.copied", key = register).to_string());
+        }
+    }
+
+    /// Store copied text in `register`, or in the clipboard when `None`.
+    /// Either way the text also enters the numbered yank ring.
+    fn store_copied_text(&mut self, register: Option<char>, text: String) {
+        self.registers.record_yank(&text);
+        match register {
+            Some(key) => self.registers.set(key, text),
+            None => self.clipboard.copy(text),
+        }
+    }
+
+    /// Shared body of `copy_selection` and `copy_selection_to_register`.
+    /// Returns whether any text was copied.
+    fn copy_selection_into(&mut self, register: Option<char>) -> bool {
         // Check if any cursor has a block selection (takes priority)
         let has_block_selection = self
             .active_cursors()
@@ -44,11 +68,12 @@ impl Editor {
         if has_block_selection {
             // Block selection: copy rectangular region
             let text = self.copy_block_selection_text();
-            if !text.is_empty() {
-                self.clipboard.copy(text);
-                self.status_message = Some(t!("clipboard.copied").to_string());
+            if text.is_empty() {
+                return false;
             }
-            return;
+            self.store_copied_text(register, text);
+            self.status_message = Some(t!("clipboard.copied").to_string());
+            return true;
         }
 
         // Check if any cursor has a normal selection
@@ -75,10 +100,12 @@ impl Editor {
                 text.push_str(&range_text);
             }
 
-            if !text.is_empty() {
-                self.clipboard.copy(text);
-                self.status_message = Some(t!("clipboard.copied").to_string());
+            if text.is_empty() {
+                return false;
             }
+            self.store_copied_text(register, text);
+            self.status_message = Some(t!("clipboard.copied").to_string());
+            true
         } else {
             // No selection: copy entire line(s) for each cursor
             let estimated_line_length = 80;
@@ -102,10 +129,12 @@ impl Editor {
                 }
             }
 
-            if !text.is_empty() {
-                self.clipboard.copy(text);
-                self.status_message = Some(t!("clipboard.copied_line").to_string());
+            if text.is_empty() {
+                return false;
             }
+            self.store_copied_text(register, text);
+            self.status_message = Some(t!("clipboard.copied_line").to_string());
+            true
         }
     }
 
@@ -364,6 +393,7 @@ impl Editor {
                     disabled: false,
                     keybinding: None,
                     source: None,
+                    match_positions: Vec::new(),
                 }
             })
             .collect();
@@ -387,6 +417,20 @@ impl Editor {
     ///
     /// If no selection exists, cuts the entire current line (like VSCode/Rider/Zed).
     pub fn cut_selection(&mut self) {
+        self.cut_selection_into(None);
+    }
+
+    /// Cut the current selection (or line) into a named register, leaving
+    /// the clipboard untouched.
+    pub fn cut_selection_to_register(&mut self, register: char) {
+        if self.cut_selection_into(Some(register)) {
+            self.status_message = Some(t!("register.cut", key = register).to_string());
+        }
+    }
+
+    /// Shared body of `cut_selection` and `cut_selection_to_register`.
+    /// Returns whether any text was cut.
+    fn cut_selection_into(&mut self, register: Option<char>) -> bool {
         // Check if any cursor has a selection
         let has_selection = self
             .active_cursors()
@@ -394,7 +438,7 @@ impl Editor {
             .any(|(_, cursor)| cursor.selection_range().is_some());
 
         // Copy first (this handles both selection and whole-line cases)
-        self.copy_selection();
+        self.copy_selection_into(register);
 
         if has_selection {
             // Delete selected text from all cursors
@@ -435,6 +479,7 @@ impl Editor {
             if !deletions.is_empty() {
                 self.status_message = Some(t!("clipboard.cut").to_string());
             }
+            !deletions.is_empty()
         } else {
             // No selection: delete entire line(s) for each cursor
             let estimated_line_length = 80;
@@ -493,6 +538,7 @@ impl Editor {
             if !deletions.is_empty() {
                 self.status_message = Some(t!("clipboard.cut_line").to_string());
             }
+            !deletions.is_empty()
         }
     }
 
@@ -514,6 +560,61 @@ impl Editor {
         self.paste_text(text);
     }
 
+    /// Paste the text held by `register` at all cursor positions.
+    ///
+    /// `"` is the clipboard (same as a plain paste), `1`-`9` the most recent
+    /// copies, anything else a named register.
+    pub fn paste_from_register(&mut self, register: char) {
+        if register == super::registers::UNNAMED_REGISTER {
+            self.paste();
+            return;
+        }
+        match self.registers.get(register) {
+            Some(text) => {
+                let text = text.to_string();
+                self.paste_text(text);
+            }
+            None => {
+                self.status_message = Some(t!("register.empty", key = register).to_string());
+            }
+        }
+    }
+
+    /// Open a picker listing every non-empty register with a preview of its
+    /// first line. Enter pastes the selected register.
+    pub fn start_paste_from_register_prompt(&mut self) {
+        use super::registers::UNNAMED_REGISTER;
+        use crate::input::commands::Suggestion;
+        use crate::view::prompt::{Prompt, PromptType};
+
+        let clipboard = self.clipboard.get_internal();
+        let unnamed = (!clipboard.is_empty()).then_some((UNNAMED_REGISTER, clipboard));
+        let suggestions: Vec<Suggestion> = unnamed
+            .into_iter()
+            .chain(self.registers.entries())
+            .map(|(key, text)| Suggestion {
+                text: format!("'{}'", key),
+                description: Some(register_preview(text)),
+                value: Some(key.to_string()),
+                disabled: false,
+                keybinding: None,
+                source: None,
+                match_positions: Vec::new(),
+            })
+            .collect();
+
+        if suggestions.is_empty() {
+            self.set_status_message(t!("register.none_stored").to_string());
+            return;
+        }
+
+        self.prompt = Some(Prompt::with_suggestions(
+            t!("register.paste_prompt").to_string(),
+            PromptType::PasteFromRegister,
+            suggestions,
+        ));
+    }
+
     /// Paste text directly into the editor
     ///
     /// Handles:
@@ -546,14 +647,84 @@ impl Editor {
             return;
         }
 
-        // Convert to buffer's line ending format
-        let buffer_line_ending = self.active_state().buffer.line_ending();
-        let paste_text = match buffer_line_ending {
+        let paste_text = self.with_buffer_line_ending(normalized);
+        let events = self.paste_events(&paste_text);
+        self.apply_paste_events(events.clone());
+
+        // Remember the paste so Cycle Paste can swap in another yank
+        let cycle = super::registers::PasteCycle {
+            buffer_id: self.active_buffer(),
+            log_seq: self.active_event_log().change_seq(),
+            events,
+            ring_index: self.registers.yank_index_of(&paste_text),
+        };
+        self.registers.set_paste_cycle(cycle);
+
+        self.status_message = Some(t!("clipboard.pasted").to_string());
+    }
+
+    /// Replace the text just pasted with the next (`forward`) or previous
+    /// entry of the yank ring, like Emacs's yank-pop.
+    ///
+    /// The earlier paste is undone before the new text goes in, so however
+    /// many times the paste is cycled a single undo removes it. Any other
+    /// edit or cursor movement after the paste ends the cycle.
+    pub fn cycle_paste(&mut self, forward: bool) {
+        let buffer_id = self.active_buffer();
+        let log_seq = self.active_event_log().change_seq();
+        let Some(cycle) = self.registers.take_paste_cycle(buffer_id, log_seq) else {
+            self.set_status_message(t!("register.no_paste_to_cycle").to_string());
+            return;
+        };
+        let Some((ring_index, text)) = self.registers.next_yank(cycle.ring_index, forward) else {
+            self.set_status_message(t!("register.yank_ring_empty").to_string());
+            return;
+        };
+        let normalized = text.replace("\r\n", "\n").replace('\r', "\n");
+        let text = self.with_buffer_line_ending(normalized);
+
+        let events: Vec<Event> = cycle
+            .events
+            .into_iter()
+            .map(|event| match event {
+                Event::Insert {
+                    position,
+                    cursor_id,
+                    ..
+                } => Event::Insert {
+                    position,
+                    text: text.clone(),
+                    cursor_id,
+                },
+                other => other,
+            })
+            .collect();
+
+        self.handle_undo();
+        self.apply_paste_events(events.clone());
+        self.registers
+            .set_paste_cycle(super::registers::PasteCycle {
+                buffer_id,
+                log_seq: self.active_event_log().change_seq(),
+                events,
+                ring_index: Some(ring_index),
+            });
+
+        self.set_status_message(t!("register.paste_cycled", index = ring_index + 1).to_string());
+    }
+
+    /// Convert LF-normalized text to the active buffer's line endings.
+    fn with_buffer_line_ending(&self, normalized: String) -> String {
+        match self.active_state().buffer.line_ending() {
             crate::model::buffer::LineEnding::LF => normalized,
             crate::model::buffer::LineEnding::CRLF => normalized.replace('\n', "\r\n"),
             crate::model::buffer::LineEnding::CR => normalized.replace('\n', "\r"),
-        };
+        }
+    }
 
+    /// Build the events that paste `paste_text` at every cursor, replacing
+    /// selections.
+    fn paste_events(&mut self, paste_text: &str) -> Vec<Event> {
         let mut events = Vec::new();
 
         // Collect cursor info sorted in reverse order by position
@@ -596,11 +767,15 @@ impl Editor {
             }
             events.push(Event::Insert {
                 position: insert_position,
-                text: paste_text.clone(),
+                text: paste_text.to_string(),
                 cursor_id,
             });
         }
+        events
+    }
 
+    /// Apply paste events as a single undo step.
+    fn apply_paste_events(&mut self, events: Vec<Event>) {
         // Apply events with atomic undo using bulk edit for O(n) performance
         if events.len() > 1 {
             // Use optimized bulk edit for multi-cursor paste
@@ -610,8 +785,6 @@ impl Editor {
         } else if let Some(event) = events.into_iter().next() {
             self.log_and_apply_event(&event);
         }
-
-        self.status_message = Some(t!("clipboard.pasted").to_string());
     }
 
     /// Set clipboard content for testing purposes
@@ -758,6 +931,42 @@ impl Editor {
         }
     }
 
+    /// Move the most recently added selection to the next occurrence of its
+    /// text, leaving the current one unselected (VS Code's Ctrl+K Ctrl+D).
+    ///
+    /// The search starts after the last added selection and wraps, skipping
+    /// occurrences that already have a cursor. With no selection, the word at
+    /// the cursor is selected, as with [`Self::add_cursor_at_next_match`].
+    pub fn skip_last_selection_match(&mut self) {
+        let cursors = self.active_cursors().clone();
+        let primary_id = cursors.primary_id();
+        let primary = *cursors.primary();
+        let state = self.active_state_mut();
+        let (new_position, new_anchor) = match add_cursor_at_next_match(state, &cursors) {
+            AddCursorResult::Success { cursor, .. } => (cursor.position, cursor.anchor),
+            AddCursorResult::WordSelected {
+                word_start,
+                word_end,
+            } => (word_end, Some(word_start)),
+            AddCursorResult::Failed { message } => {
+                self.status_message = Some(message);
+                return;
+            }
+        };
+
+        let event = Event::MoveCursor {
+            cursor_id: primary_id,
+            old_position: primary.position,
+            new_position,
+            old_anchor: primary.anchor,
+            new_anchor,
+            old_sticky_column: primary.sticky_column,
+            new_sticky_column: 0,
+        };
+        self.active_event_log_mut().append(event.clone());
+        self.apply_event_to_active_buffer(&event);
+    }
+
     /// Add a cursor above the primary cursor at the same column
     pub fn add_cursor_above(&mut self) {
         let cursors = self.active_cursors().clone();
@@ -820,6 +1029,28 @@ impl Editor {
         }
     }
 
+    /// Make the next cursor in buffer order the primary one, wrapping
+    /// around, and scroll it into view.
+    pub fn rotate_primary_cursor(&mut self) {
+        let cursors = self.active_cursors();
+        let count = cursors.count();
+        if count < 2 {
+            return;
+        }
+        let mut ids: Vec<_> = cursors.iter().map(|(id, c)| (c.position, id)).collect();
+        ids.sort_by_key(|&(position, _)| position);
+        let primary_id = cursors.primary_id();
+        let current = ids
+            .iter()
+            .position(|&(_, id)| id == primary_id)
+            .unwrap_or(0);
+        let next = (current + 1) % count;
+        self.active_cursors_mut().set_primary(ids[next].1);
+        self.ensure_active_cursor_visible_for_navigation(false);
+        self.status_message =
+            Some(t!("clipboard.primary_cursor", index = next + 1, count = count).to_string());
+    }
+
     // =========================================================================
     // Vi-style yank operations (copy range without requiring selection)
     // =========================================================================
@@ -1044,3 +1275,18 @@ impl Editor {
         }
     }
 }
+
+/// One-line preview of a register's text for the register picker.
+fn register_preview(text: &str) -> String {
+    const MAX_CHARS: usize = 60;
+    let first_line = text.lines().next().unwrap_or("").trim();
+    let mut preview: String = first_line.chars().take(MAX_CHARS).collect();
+    if first_line.chars().count() > MAX_CHARS {
+        preview.push('…');
+    }
+    let extra_lines = text.lines().count().saturating_sub(1);
+    if extra_lines > 0 {
+        preview.push_str(&format!("  (+{} lines)", extra_lines));
+    }
+    preview
+}
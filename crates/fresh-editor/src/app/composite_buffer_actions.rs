@@ -243,7 +243,7 @@ impl Editor {
 
         // Create an event log entry (required for many editor operations)
         self.event_logs
-            .insert(buffer_id, crate::model::event::EventLog::new());
+            .insert(buffer_id, self.new_event_log(buffer_id));
 
         // Register with the active split so it appears in tabs
         let split_id = self.split_manager.active_split();
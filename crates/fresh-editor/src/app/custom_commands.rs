@@ -0,0 +1,407 @@
+//! Custom commands defined in the `commands` config section.
+//!
+//! Each entry is registered in the command palette under its configured
+//! name. Running one spawns the shell command through the active
+//! authority's long-running spawner (so `ProcessLimits` apply) and streams
+//! stdout/stderr line by line into a read-only `*<name>*` buffer via
+//! `AsyncMessage::CustomCommandOutput`. Running it again stops a run that
+//! is still going, and each run's output is a single undo step.
+//!
+//! "Run Current File" goes through the same path, with the command taken
+//! from the active language's `run_command` template.
+
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+
+use rust_i18n::t;
+use tokio::io::{AsyncBufReadExt, AsyncRead, BufReader};
+
+use super::Editor;
+use crate::input::commands::{Command, CommandSource};
+use crate::input::keybindings::Action;
+use crate::model::cursor::Cursors;
+use crate::model::event::{BufferId, CursorId, Event};
+use crate::services::async_bridge::AsyncMessage;
+use crate::services::process_limits::ProcessLimits;
+use crate::view::prompt::PromptType;
+
+/// Command source recorded for config-defined commands. Plugin sources are
+/// file names, which can't contain `/`, so no plugin can claim this one.
+const CUSTOM_COMMAND_SOURCE: &str = "config/commands";
+
+/// A command streaming its output into a buffer
+pub(crate) struct CustomCommandRun {
+    /// Tags the run's messages, so output of a run that was replaced is
+    /// dropped
+    id: u64,
+    /// Stops the task running the command, killing the command with it
+    abort: Option<tokio::task::AbortHandle>,
+    /// Edits made to the output buffer, logged as one undo step when the
+    /// run ends
+    events: Vec<Event>,
+}
+
+impl Editor {
+    /// Register the config's custom commands in the command palette,
+    /// replacing any registered from a previous config.
+    pub(crate) fn register_custom_commands(&self) {
+        let registry = self.command_registry.read().unwrap();
+        registry.unregister_by_plugin(CUSTOM_COMMAND_SOURCE);
+        for (name, custom) in &self.config.commands {
+            registry.register(Command {
+                name: name.clone(),
+                description: custom.command.clone(),
+                action: Action::RunCustomCommand(name.clone()),
+                contexts: Vec::new(),
+                custom_contexts: Vec::new(),
+                source: CommandSource::Plugin(CUSTOM_COMMAND_SOURCE.to_string()),
+            });
+        }
+    }
+
+    /// Run a config-defined command, streaming its output into a
+    /// read-only buffer named after it. Re-running reuses that buffer.
+    pub(super) fn run_custom_command(&mut self, name: &str) {
+        let Some(custom) = self.config.commands.get(name).cloned() else {
+            self.set_status_message(t!("shell.custom_command_not_found", name = name).to_string());
+            return;
+        };
+        let cwd = match custom.cwd {
+            Some(ref dir) => self.working_dir.join(dir),
+            None => self.working_dir.clone(),
+        };
+        self.spawn_command_output(name, custom.command, cwd, custom.process_limits);
+    }
+
+    /// Run the active file with its language's `run_command`, saving it
+    /// first if it has unsaved changes. Output goes to a `*Run: <file>*`
+    /// buffer and the exit code to the status bar.
+    pub(super) fn run_current_file(&mut self) {
+        let Some(path) = self
+            .active_state()
+            .buffer
+            .file_path()
+            .map(|p| p.to_path_buf())
+        else {
+            self.set_status_message(t!("shell.run_file_no_path").to_string());
+            return;
+        };
+        let language = self.active_state().language.clone();
+        let Some(template) = self
+            .config
+            .languages
+            .get(&language)
+            .and_then(|lang| lang.run_command.clone())
+        else {
+            self.set_status_message(
+                t!("shell.run_file_no_command", language = &language).to_string(),
+            );
+            return;
+        };
+
+        let file_name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_else(|| path.display().to_string());
+
+        if self.active_state().buffer.is_modified() {
+            if self.check_save_conflict().is_some() {
+                self.start_prompt(
+                    t!("file.file_changed_prompt").to_string(),
+                    PromptType::ConfirmSaveConflict,
+                );
+                return;
+            }
+            if let Err(e) = self.save() {
+                self.set_status_message(t!("file.save_failed", error = e.to_string()).to_string());
+                return;
+            }
+            // A plugin vetoed the save or it is waiting on a prompt
+            if self.active_state().buffer.is_modified() {
+                self.set_status_message(
+                    t!("shell.run_file_not_saved", file = &file_name).to_string(),
+                );
+                return;
+            }
+        }
+
+        let command = template.replace("{file}", &quote_path(&path));
+        let cwd = path
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_else(|| self.working_dir.clone());
+        self.spawn_command_output(
+            &format!("Run: {}", file_name),
+            command,
+            cwd,
+            self.config.editor.process_limits.clone(),
+        );
+    }
+
+    /// Spawn `command` through the shell and stream its output into the
+    /// `*<name>*` buffer, reporting the exit code when it finishes.
+    fn spawn_command_output(
+        &mut self,
+        name: &str,
+        command: String,
+        cwd: PathBuf,
+        process_limits: ProcessLimits,
+    ) {
+        let Some(runtime) = self.tokio_runtime.as_ref().map(|rt| rt.handle().clone()) else {
+            return;
+        };
+        let Some(sender) = self.async_bridge.as_ref().map(|bridge| bridge.sender()) else {
+            return;
+        };
+
+        let buffer_id = self.custom_command_buffer(name);
+        // A previous run still writing to the buffer is stopped first
+        self.end_custom_command_run(buffer_id);
+        let run_id = self.next_custom_command_run_id;
+        self.next_custom_command_run_id += 1;
+        self.custom_command_runs.insert(
+            buffer_id,
+            CustomCommandRun {
+                id: run_id,
+                abort: None,
+                events: Vec::new(),
+            },
+        );
+        let state = self.buffers.get_mut(&buffer_id).unwrap();
+        let len = state.buffer.len();
+        if len > 0 {
+            let deleted_text = state.get_text_range(0, len);
+            self.apply_output_edit(
+                buffer_id,
+                Event::Delete {
+                    range: 0..len,
+                    deleted_text,
+                    cursor_id: CursorId(0),
+                },
+            );
+        }
+        self.set_active_buffer(buffer_id);
+        self.set_status_message(t!("shell.custom_command_running", name = name).to_string());
+
+        let spawner = self.authority.long_running_spawner.clone();
+        let name = name.to_string();
+
+        let task = runtime.spawn(async move {
+            let (shell, flag) = if cfg!(windows) {
+                ("cmd", "/C")
+            } else {
+                ("sh", "-c")
+            };
+            let args = [flag.to_string(), command];
+            let spawned = spawner
+                .spawn_stdio(shell, &args, Vec::new(), Some(&cwd), Some(&process_limits))
+                .await;
+            let mut child = match spawned {
+                Ok(child) => child,
+                Err(e) => {
+                    #[allow(clippy::let_underscore_must_use)]
+                    let _ = sender.send(AsyncMessage::CustomCommandOutput {
+                        buffer_id,
+                        run_id,
+                        text: format!("{}\n", t!("shell.spawn_failed", error = e.to_string())),
+                    });
+                    #[allow(clippy::let_underscore_must_use)]
+                    let _ = sender.send(AsyncMessage::CustomCommandExited {
+                        buffer_id,
+                        run_id,
+                        name,
+                        exit_code: -1,
+                    });
+                    return;
+                }
+            };
+            // Nothing reads stdin; close it so commands waiting on it exit
+            drop(child.take_stdin());
+
+            let stdout = child.take_stdout();
+            let stderr = child.take_stderr();
+            tokio::join!(
+                forward_lines(stdout, &sender, buffer_id, run_id),
+                forward_lines(stderr, &sender, buffer_id, run_id),
+            );
+            let exit_code = child
+                .wait()
+                .await
+                .map(|status| status.code().unwrap_or(-1))
+                .unwrap_or(-1);
+
+            #[allow(clippy::let_underscore_must_use)]
+            let _ = sender.send(AsyncMessage::CustomCommandExited {
+                buffer_id,
+                run_id,
+                name,
+                exit_code,
+            });
+        });
+        if let Some(run) = self.custom_command_runs.get_mut(&buffer_id) {
+            run.abort = Some(task.abort_handle());
+        }
+    }
+
+    /// Find or create the output buffer for a custom command.
+    fn custom_command_buffer(&mut self, name: &str) -> BufferId {
+        let buffer_name = format!("*{}*", name);
+        let existing = self
+            .buffer_metadata
+            .iter()
+            .find(|(id, m)| m.display_name == buffer_name && self.buffers.contains_key(id))
+            .map(|(id, _)| *id);
+
+        existing
+            .unwrap_or_else(|| self.create_virtual_buffer(buffer_name, "special".to_string(), true))
+    }
+
+    /// Stop the run writing to `buffer_id`, if any, and log its edits as
+    /// one undo step.
+    fn end_custom_command_run(&mut self, buffer_id: BufferId) {
+        let Some(run) = self.custom_command_runs.remove(&buffer_id) else {
+            return;
+        };
+        if let Some(abort) = run.abort {
+            abort.abort();
+        }
+        if run.events.is_empty() {
+            return;
+        }
+        if let Some(log) = self.event_logs.get_mut(&buffer_id) {
+            log.append(Event::Batch {
+                events: run.events,
+                description: "Command output".to_string(),
+            });
+        }
+    }
+
+    /// Whether `run_id` is the run currently writing to `buffer_id`
+    fn is_current_custom_command_run(&self, buffer_id: BufferId, run_id: u64) -> bool {
+        self.custom_command_runs
+            .get(&buffer_id)
+            .is_some_and(|run| run.id == run_id)
+    }
+
+    /// Apply an edit to a command's output buffer, recording it in the
+    /// run instead of the event log.
+    fn apply_output_edit(&mut self, buffer_id: BufferId, event: Event) {
+        let (position, deleted, inserted) = match &event {
+            Event::Insert { position, text, .. } => (*position, 0, text.len()),
+            Event::Delete { range, .. } => (range.start, range.len(), 0),
+            _ => return,
+        };
+        let Some(state) = self.buffers.get_mut(&buffer_id) else {
+            return;
+        };
+        // Apply to buffer with dummy cursors (real cursors adjusted below)
+        state.apply(&mut Cursors::default(), &event);
+        state.buffer.clear_modified();
+        for leaf_id in self.split_manager.splits_for_buffer(buffer_id) {
+            if let Some(view_state) = self.split_view_states.get_mut(&leaf_id) {
+                view_state
+                    .cursors
+                    .adjust_for_edit(position, deleted, inserted);
+            }
+        }
+        if let Some(run) = self.custom_command_runs.get_mut(&buffer_id) {
+            run.events.push(event);
+        }
+    }
+
+    /// Append a chunk of custom command output to its buffer.
+    pub(super) fn append_custom_command_output(
+        &mut self,
+        buffer_id: BufferId,
+        run_id: u64,
+        text: String,
+    ) {
+        if !self.is_current_custom_command_run(buffer_id, run_id) {
+            return;
+        }
+        let Some(end) = self.buffers.get(&buffer_id).map(|s| s.buffer.len()) else {
+            return;
+        };
+        self.apply_output_edit(
+            buffer_id,
+            Event::Insert {
+                position: end,
+                text,
+                cursor_id: CursorId(0),
+            },
+        );
+    }
+
+    /// Report that a custom command finished.
+    pub(super) fn finish_custom_command(
+        &mut self,
+        buffer_id: BufferId,
+        run_id: u64,
+        name: &str,
+        exit_code: i32,
+    ) {
+        // A run that was replaced reports nothing
+        if !self.is_current_custom_command_run(buffer_id, run_id) {
+            return;
+        }
+        self.end_custom_command_run(buffer_id);
+        self.set_status_message(
+            t!(
+                "shell.custom_command_finished",
+                name = name,
+                code = exit_code
+            )
+            .to_string(),
+        );
+    }
+}
+
+/// Send each line read from `reader` to the editor as command output.
+async fn forward_lines<R: AsyncRead + Unpin>(
+    reader: Option<R>,
+    sender: &mpsc::Sender<AsyncMessage>,
+    buffer_id: BufferId,
+    run_id: u64,
+) {
+    let Some(reader) = reader else {
+        return;
+    };
+    let mut lines = BufReader::new(reader).lines();
+    while let Ok(Some(line)) = lines.next_line().await {
+        let text = format!("{}\n", line);
+        if sender
+            .send(AsyncMessage::CustomCommandOutput {
+                buffer_id,
+                run_id,
+                text,
+            })
+            .is_err()
+        {
+            break;
+        }
+    }
+}
+
+/// Quote `path` as a single argument for the shell the command runs in.
+fn quote_path(path: &Path) -> String {
+    let path = path.to_string_lossy();
+    if cfg!(windows) {
+        format!("\"{}\"", path)
+    } else {
+        // POSIX single quotes, with embedded quotes as `'\''`
+        format!("'{}'", path.replace('\'', "'\\''"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::quote_path;
+    use std::path::Path;
+
+    #[test]
+    #[cfg(unix)]
+    fn quote_path_survives_spaces_and_quotes() {
+        assert_eq!(quote_path(Path::new("/tmp/a b.sh")), "'/tmp/a b.sh'");
+        assert_eq!(quote_path(Path::new("/tmp/it's.py")), "'/tmp/it'\\''s.py'");
+    }
+}
@@ -42,6 +42,21 @@ impl Editor {
     /// the pointer move and will reserialize on the next refresh.
     pub fn set_config(&mut self, new_config: Config) {
         self.config = Arc::new(new_config);
+        self.configure_plugin_hooks();
+    }
+
+    /// Hand the plugin hook debounce and timeout settings to the plugin
+    /// manager.
+    pub(super) fn configure_plugin_hooks(&self) {
+        self.plugin_manager.configure_hook_debounce(
+            std::time::Duration::from_millis(self.config.editor.plugin_hook_debounce_ms),
+            &self.config.editor.plugin_debounced_hooks,
+            self.time_source.clone(),
+        );
+        self.plugin_manager.configure_handler_limits(
+            std::time::Duration::from_millis(self.config.editor.plugin_handler_timeout_ms),
+            self.config.editor.plugin_disable_after_timeouts,
+        );
     }
 
     /// Replace the cached raw user config. Like `set_config`, constructs
@@ -391,6 +406,19 @@ impl Editor {
             .unwrap_or(0)
     }
 
+    /// Return the process ids of the spawned LSP servers for a given language.
+    pub fn lsp_server_process_ids(&self, language: &str) -> Vec<u32> {
+        self.lsp
+            .as_ref()
+            .map(|lsp| {
+                lsp.get_handles(language)
+                    .iter()
+                    .filter_map(|sh| sh.handle.process_id())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
     /// Shutdown an LSP server by language (marks it as disabled until manual restart)
     ///
     /// Returns true if the server was found and shutdown, false otherwise
@@ -403,19 +431,34 @@ impl Editor {
     }
 
     /// Enable event log streaming to a file
+    ///
+    /// Every buffer's events, each handled action and each keystroke are
+    /// written to `path` in the structured format described in
+    /// [`crate::model::event_stream`].
     pub fn enable_event_streaming<P: AsRef<Path>>(&mut self, path: P) -> AnyhowResult<()> {
-        // Enable streaming for all existing event logs
-        for event_log in self.event_logs.values_mut() {
-            event_log.enable_streaming(&path)?;
+        let writer = Arc::new(Mutex::new(EventStreamWriter::create(path.as_ref())?));
+        for (buffer_id, event_log) in self.event_logs.iter_mut() {
+            event_log.set_stream(Some(EventStreamSink::new(writer.clone(), *buffer_id)));
+        }
+
+        // Name the files already open so the stream can be replayed
+        for (buffer_id, state) in &self.buffers {
+            if let Some(path) = state.buffer.file_path() {
+                EventStreamSink::new(writer.clone(), *buffer_id).record(RecordKind::OpenFile {
+                    path: path.to_path_buf(),
+                });
+            }
         }
+        self.event_stream = Some(writer);
         Ok(())
     }
 
     /// Log keystroke for debugging
     pub fn log_keystroke(&mut self, key_code: &str, modifiers: &str) {
-        if let Some(event_log) = self.event_logs.get_mut(&self.active_buffer()) {
-            event_log.log_keystroke(key_code, modifiers);
-        }
+        self.record_to_event_stream(RecordKind::Keystroke {
+            key: key_code.to_string(),
+            modifiers: modifiers.to_string(),
+        });
     }
 
     /// Set up warning log monitoring
@@ -486,7 +529,7 @@ impl Editor {
     /// of the empty string the temporary `Authority::local()` carried
     /// during construction.
     pub fn set_boot_authority(&mut self, authority: crate::services::authority::Authority) {
-        self.authority = authority;
+        self.authority = authority.with_process_limits(&self.config.editor.process_limits);
         // Propagate the authority's long-running spawner into the LSP
         // manager so `force_spawn` can route server processes through
         // the right backend. The editor rebuilds on every authority
@@ -494,6 +537,8 @@ impl Editor {
         // single wiring point — no need for a hot-swap API. Path
         // translation rides along for the same reason — LSP URIs need
         // to be host↔container-translated under the new authority.
+        // The local process spawner picks up `editor.process_limits` the
+        // same way.
         if let Some(lsp) = self.lsp.as_mut() {
             lsp.set_long_running_spawner(self.authority.long_running_spawner.clone());
             lsp.set_path_translation(self.authority.path_translation.clone());
@@ -802,6 +847,45 @@ impl Editor {
         false // no immediate redraw needed; diagnostics arrive asynchronously
     }
 
+    /// Check which buffers' inlay hints timers have expired and re-request
+    /// hints for them.
+    ///
+    /// A buffer's timer is armed after edits settle and when its viewport
+    /// scrolls past the lines covered by the last request.
+    pub fn check_inlay_hints_timer(&mut self) -> bool {
+        let now = Instant::now();
+        let due: Vec<BufferId> = self
+            .scheduled_inlay_hints_requests
+            .iter()
+            .filter(|(_, &trigger_time)| now >= trigger_time)
+            .map(|(&buffer_id, _)| buffer_id)
+            .collect();
+
+        for buffer_id in due {
+            self.scheduled_inlay_hints_requests.remove(&buffer_id);
+            self.request_inlay_hints_for_buffer(buffer_id);
+        }
+
+        false // no immediate redraw needed; hints arrive asynchronously
+    }
+
+    /// Check if the workspace symbol search timer has expired and send the
+    /// query currently typed in the Workspace Symbols prompt.
+    pub fn check_workspace_symbols_timer(&mut self) -> bool {
+        let Some(trigger_time) = self.scheduled_workspace_symbols_request else {
+            return false;
+        };
+
+        if Instant::now() < trigger_time {
+            return false;
+        }
+
+        self.scheduled_workspace_symbols_request = None;
+        self.request_workspace_symbols();
+
+        false // no immediate redraw needed; results arrive asynchronously
+    }
+
     /// Check if completion trigger timer has expired and trigger completion if so
     ///
     /// This implements debounced completion - we wait for quick_suggestions_delay_ms
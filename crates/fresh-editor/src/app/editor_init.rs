@@ -286,12 +286,21 @@ impl Editor {
         state
             .margins
             .configure_for_line_numbers(config.editor.line_numbers);
-        state.buffer_settings.tab_size = config.editor.tab_size;
+        state.buffer_settings.tab_size = config.editor.effective_tab_size();
         state.buffer_settings.auto_close = config.editor.auto_close;
+        state.buffer_settings.backspace_unindent = config.editor.backspace_unindent;
+        state.buffer_settings.indent_guides = config.editor.indent_guides;
+        state.buffer_settings.highlight_all_cursor_lines = config.editor.highlight_all_cursor_lines;
         // Note: line_wrap_enabled is now stored in SplitViewState.viewport
         tracing::info!("EditorState created for buffer {:?}", buffer_id);
         buffers.insert(buffer_id, state);
-        event_logs.insert(buffer_id, EventLog::new());
+        event_logs.insert(
+            buffer_id,
+            EventLog::with_limits(
+                config.editor.undo_history_max_groups,
+                config.editor.undo_history_max_bytes,
+            ),
+        );
 
         // Create metadata for the initial empty buffer
         let mut buffer_metadata = HashMap::new();
@@ -366,6 +375,8 @@ impl Editor {
             config.editor.highlight_current_line,
             config.editor.line_wrap,
             config.editor.wrap_indent,
+            config.editor.wrap_mode,
+            config.editor.wrap_indent_extra,
             config.editor.wrap_column,
             config.editor.rulers.clone(),
         );
@@ -385,7 +396,8 @@ impl Editor {
         let authority = crate::services::authority::Authority {
             filesystem: Arc::clone(&filesystem),
             ..crate::services::authority::Authority::local()
-        };
+        }
+        .with_process_limits(&config.editor.process_limits);
         let process_spawner = Arc::clone(&authority.process_spawner);
 
         // Initialize Quick Open registry with all providers
@@ -735,6 +747,7 @@ impl Editor {
             status_message: None,
             plugin_status_message: None,
             last_window_title: None,
+            status_bar_format: None,
             plugin_errors: Vec::new(),
             prompt: None,
             terminal_width: width,
@@ -772,6 +785,7 @@ impl Editor {
             status_bar_visible: show_status_bar,
             prompt_line_visible: show_prompt_line,
             mouse_enabled: true,
+            workspace_theme: None,
             same_buffer_scroll_sync: false,
             mouse_cursor_position: None,
             gpm_active: false,
@@ -783,19 +797,27 @@ impl Editor {
             in_navigation: false,
             next_lsp_request_id: 0,
             pending_completion_requests: HashSet::new(),
+            pending_completion_server_names: HashMap::new(),
             completion_items: None,
+            pending_completion_preview: None,
+            pending_completion_accept: None,
+            pending_rename_edit: None,
             scheduled_completion_trigger: None,
             completion_service: crate::services::completion::CompletionService::new(),
             dabbrev_state: None,
+            pending_indent_trim: None,
             pending_goto_definition_request: None,
             hover: hover::HoverState::default(),
             pending_references_request: None,
+            pending_document_symbols_request: None,
+            pending_workspace_symbols_request: None,
             pending_references_symbol: String::new(),
             pending_signature_help_request: None,
             pending_code_actions_requests: HashSet::new(),
             pending_code_actions_server_names: HashMap::new(),
             pending_code_actions: None,
             pending_inlay_hints_requests: HashMap::new(),
+            pending_formatting_requests: HashMap::new(),
             pending_folding_range_requests: HashMap::new(),
             folding_ranges_in_flight: HashMap::new(),
             folding_ranges_debounce: HashMap::new(),
@@ -814,6 +836,7 @@ impl Editor {
                 "lsp-diagnostic".to_string(),
             ),
             pending_search_range: None,
+            search_preview_origin: None,
             interactive_replace_state: None,
             mouse_state: MouseState::default(),
             tab_context_menu: None,
@@ -833,6 +856,8 @@ impl Editor {
             next_buffer_group_id: 0,
             grouped_subtrees: HashMap::new(),
             background_process_handles: HashMap::new(),
+            custom_command_runs: HashMap::new(),
+            next_custom_command_run_id: 0,
             host_process_handles: HashMap::new(),
             prompt_histories: {
                 // Load prompt histories from disk if available
@@ -853,13 +878,17 @@ impl Editor {
             key_capture_active: false,
             pending_key_capture_buffer: std::collections::VecDeque::new(),
             goto_line_preview: None,
+            last_palette_command: None,
             lsp_progress: std::collections::HashMap::new(),
             lsp_server_statuses: std::collections::HashMap::new(),
             lsp_window_messages: Vec::new(),
             lsp_log_messages: Vec::new(),
             diagnostic_result_ids: HashMap::new(),
             scheduled_diagnostic_pull: None,
-            scheduled_inlay_hints_request: None,
+            scheduled_inlay_hints_requests: HashMap::new(),
+            scheduled_workspace_symbols_request: None,
+            inlay_hints_coverage: HashMap::new(),
+            inlay_hints_viewport: HashMap::new(),
             stored_push_diagnostics: HashMap::new(),
             stored_pull_diagnostics: HashMap::new(),
             stored_diagnostics: Arc::new(HashMap::new()),
@@ -871,6 +900,7 @@ impl Editor {
             search_use_regex: false,
             search_confirm_each: false,
             macros: macros::MacroState::default(),
+            registers: registers::RegisterState::default(),
             #[cfg(feature = "plugins")]
             pending_plugin_actions: Vec::new(),
             #[cfg(feature = "plugins")]
@@ -881,6 +911,8 @@ impl Editor {
             auto_revert_enabled: true,
             last_auto_revert_poll: time_source.now(),
             last_file_tree_poll: time_source.now(),
+            last_theme_poll: time_source.now(),
+            theme_file_mod_times: None,
             git_index_resolved: false,
             file_mod_times: HashMap::new(),
             dir_mod_times: HashMap::new(),
@@ -888,6 +920,7 @@ impl Editor {
             pending_dir_poll_rx: None,
             file_rapid_change_counts: HashMap::new(),
             file_open_state: None,
+            file_open_listing_task: None,
             file_browser_layout: None,
             recovery_service: {
                 let recovery_config = RecoveryConfig {
@@ -910,7 +943,9 @@ impl Editor {
             suspend_requested: false,
             time_source: time_source.clone(),
             last_auto_recovery_save: time_source.now(),
+            last_buffer_edit: time_source.now(),
             last_persistent_auto_save: time_source.now(),
+            persistent_auto_save_failures: 0,
             active_custom_contexts: HashSet::new(),
             plugin_global_state: HashMap::new(),
             editor_mode: None,
@@ -940,6 +975,8 @@ impl Editor {
             color_capability,
             pending_file_opens: Vec::new(),
             pending_hot_exit_recovery: false,
+            pending_event_replay: None,
+            event_stream: None,
             wait_tracking: HashMap::new(),
             completed_waits: Vec::new(),
             stdin_stream: stdin_stream::StdinStream::default(),
@@ -959,6 +996,11 @@ impl Editor {
         // Apply clipboard configuration
         editor.clipboard.apply_config(&editor.config.clipboard);
 
+        // Add the config's custom commands to the command palette
+        editor.register_custom_commands();
+
+        editor.configure_plugin_hooks();
+
         #[cfg(feature = "plugins")]
         {
             editor.update_plugin_state_snapshot();
@@ -19,6 +19,7 @@
 use lsp_types::TextDocumentContentChangeEvent;
 
 use crate::model::event::{BufferId, Event, LeafId};
+use crate::view::viewport::CursorPlacement;
 
 use super::types::EventLineInfo;
 use super::Editor;
@@ -71,6 +72,7 @@ impl Editor {
         // `InsertChar` (single-character typing).
         if event.modifies_buffer() {
             self.promote_active_buffer_from_preview();
+            self.last_buffer_edit = self.time_source.now();
         }
 
         // IMPORTANT: Calculate LSP changes and line info BEFORE applying to buffer!
@@ -708,7 +710,7 @@ impl Editor {
             } else {
                 continue;
             };
-            let tab_size = self.config.editor.tab_size;
+            let tab_size = self.config.editor.effective_tab_size();
 
             // Get view_transform tokens from SplitViewState (if any)
             let view_transform_tokens = self
@@ -831,6 +833,12 @@ impl Editor {
 
     /// Handle Recenter event using SplitViewState's viewport
     fn handle_recenter_event(&mut self) {
+        self.place_cursor_in_view(CursorPlacement::Center);
+    }
+
+    /// Scroll the active split (and any splits in its scroll-sync group) so
+    /// the primary cursor sits at the top, middle, or bottom of the view.
+    pub(super) fn place_cursor_in_view(&mut self, placement: CursorPlacement) {
         let active_split = self.split_manager.active_split();
 
         // Find other splits in the same sync group if any
@@ -838,14 +846,14 @@ impl Editor {
             .split_view_states
             .get(&active_split)
             .and_then(|vs| vs.sync_group);
-        let splits_to_recenter = if let Some(group_id) = sync_group {
+        let splits_to_scroll = if let Some(group_id) = sync_group {
             self.split_manager
                 .get_splits_in_group(group_id, &self.split_view_states)
         } else {
             vec![active_split]
         };
 
-        for split_id in splits_to_recenter {
+        for split_id in splits_to_scroll {
             let buffer_id = if let Some(id) = self.split_manager.buffer_for_split(split_id) {
                 id
             } else {
@@ -854,23 +862,9 @@ impl Editor {
 
             if let Some(state) = self.buffers.get_mut(&buffer_id) {
                 let buffer = &mut state.buffer;
-                let view_state = self.split_view_states.get_mut(&split_id);
-
-                if let Some(view_state) = view_state {
-                    // Recenter viewport on cursor
+                if let Some(view_state) = self.split_view_states.get_mut(&split_id) {
                     let cursor = *view_state.cursors.primary();
-                    let viewport_height = view_state.viewport.visible_line_count();
-                    let target_rows_from_top = viewport_height / 2;
-
-                    // Move backwards from cursor position target_rows_from_top lines
-                    let mut iter = buffer.line_iterator(cursor.position, 80);
-                    for _ in 0..target_rows_from_top {
-                        if iter.prev().is_none() {
-                            break;
-                        }
-                    }
-                    let new_top_byte = iter.current_position();
-                    view_state.viewport.top_byte = new_top_byte;
+                    view_state.viewport.place_cursor(buffer, &cursor, placement);
                     // Mark to skip ensure_visible on next render so the scroll isn't undone
                     view_state.viewport.set_skip_ensure_visible();
                 }
@@ -0,0 +1,258 @@
+//! Exporting and replaying event logs.
+//!
+//! Both use the event stream format from [`crate::model::event_stream`]:
+//! an export is an `open_file` record naming the buffer's file followed by
+//! one `event` record per event since the file was last saved, and a stream written by `--event-log` replays the same way. Keystroke,
+//! action and render records are skipped. Events without a buffer id apply
+//! to the active buffer, so a log without an `open_file` record replays
+//! into whatever buffer is open at the time.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result as AnyhowResult};
+use rust_i18n::t;
+
+use super::Editor;
+use crate::model::event::{BufferId, Event};
+use crate::model::event_stream::{EventStreamReader, EventStreamWriter, RecordKind};
+use crate::state::EditorState;
+
+/// Outcome of replaying an event log.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReplaySummary {
+    /// Events applied to a buffer
+    pub applied: usize,
+    /// Events not applied: their file could not be opened, their content
+    /// isn't recorded (bulk edits), or they don't match the buffer
+    pub skipped: usize,
+    /// Files named by the log that could not be opened
+    pub missing_files: Vec<PathBuf>,
+}
+
+impl Editor {
+    /// Queue an event log to replay once the pending file opens have run.
+    pub fn queue_event_replay(&mut self, path: PathBuf) {
+        self.pending_event_replay = Some(path);
+    }
+
+    /// Replay the queued event log (called from the event loop).
+    /// Returns true if a replay ran.
+    pub fn process_pending_event_replay(&mut self) -> bool {
+        if !self.pending_file_opens.is_empty() {
+            return false;
+        }
+        let Some(path) = self.pending_event_replay.take() else {
+            return false;
+        };
+        match self.replay_event_log(&path) {
+            Ok(summary) => {
+                self.set_status_message(
+                    t!(
+                        "event_replay.finished",
+                        applied = summary.applied,
+                        skipped = summary.skipped
+                    )
+                    .to_string(),
+                );
+            }
+            Err(e) => {
+                tracing::warn!("Failed to replay event log {}: {:#}", path.display(), e);
+                self.set_status_message(
+                    t!("event_replay.failed", error = format!("{:#}", e)).to_string(),
+                );
+            }
+        }
+        true
+    }
+
+    /// Apply the events recorded in `path`, opening the files it names.
+    ///
+    /// Events go to the buffer their file was opened into, or to the active
+    /// buffer when the log doesn't say. A file that can't be opened is
+    /// reported in the summary and the events recorded for it are skipped
+    /// rather than applied to another buffer. Events that don't match the
+    /// buffer they target (a deletion of text that isn't there, a position
+    /// past the end) are skipped too.
+    pub fn replay_event_log(&mut self, path: &Path) -> AnyhowResult<ReplaySummary> {
+        let reader = EventStreamReader::open(path)
+            .with_context(|| format!("cannot open {}", path.display()))?;
+        let mut summary = ReplaySummary::default();
+        // Recorded buffer id -> buffer it was replayed into (None: missing)
+        let mut buffers: HashMap<BufferId, Option<BufferId>> = HashMap::new();
+        // Target of records without a buffer id: the last file opened
+        let mut target_missing = false;
+
+        for record in reader {
+            let record = record?;
+            match record.kind {
+                RecordKind::OpenFile { path: file_path } => {
+                    let opened = if file_path.is_file() {
+                        self.open_file(&file_path).ok()
+                    } else {
+                        None
+                    };
+                    target_missing = opened.is_none();
+                    if let Some(recorded) = record.buffer_id {
+                        buffers.insert(recorded, opened);
+                    }
+                    if target_missing {
+                        tracing::warn!(
+                            "Event replay: cannot open {}, skipping its events",
+                            file_path.display()
+                        );
+                        summary.missing_files.push(file_path);
+                    }
+                }
+                RecordKind::Event { event } => {
+                    let target = match record.buffer_id.and_then(|id| buffers.get(&id)) {
+                        Some(target) => *target,
+                        None if target_missing => None,
+                        None => Some(self.active_buffer()),
+                    };
+                    let Some(target) = target.filter(|id| self.buffers.contains_key(id)) else {
+                        summary.skipped += 1;
+                        continue;
+                    };
+                    if target != self.active_buffer() {
+                        self.set_active_buffer(target);
+                    }
+                    if !self.event_fits_active_buffer(&event) {
+                        summary.skipped += 1;
+                        continue;
+                    }
+                    self.log_and_apply_event(&event);
+                    summary.applied += 1;
+                }
+                // Actions, keystrokes and renders are recorded for analysis;
+                // the events they produced are what gets replayed
+                _ => {}
+            }
+        }
+
+        Ok(summary)
+    }
+
+    /// Write the events behind the active buffer's current state to `path`
+    /// as an event stream `replay_event_log` reads. Returns the number of
+    /// events written.
+    ///
+    /// A file-backed buffer exports the events since it was last saved, so
+    /// replaying them on the file as it is on disk reproduces the buffer.
+    /// Fails if the history no longer leads from the saved state to the
+    /// current one (it was undone past, or trimmed away).
+    pub fn export_event_log(&mut self, path: &Path) -> AnyhowResult<usize> {
+        let buffer_id = Some(self.active_buffer());
+        let file_path = self
+            .active_state()
+            .buffer
+            .file_path()
+            .map(Path::to_path_buf);
+        let log = self.active_event_log();
+        let start = if file_path.is_some() {
+            log.saved_at_index()
+                .filter(|&saved| saved <= log.current_index())
+                .context("the undo history no longer reaches the saved file")?
+        } else {
+            0
+        };
+        let entries = &log.entries()[start..log.current_index()];
+
+        let mut writer = EventStreamWriter::create(path)
+            .with_context(|| format!("cannot create {}", path.display()))?;
+        if let Some(file_path) = file_path {
+            writer.write(buffer_id, RecordKind::OpenFile { path: file_path })?;
+        }
+        for entry in entries {
+            writer.write(
+                buffer_id,
+                RecordKind::Event {
+                    event: entry.event.clone(),
+                },
+            )?;
+        }
+        Ok(entries.len())
+    }
+
+    /// Handle the Export Event Log prompt confirmation.
+    pub(super) fn handle_export_event_log(&mut self, input: &str) {
+        let trimmed = input.trim();
+        if trimmed.is_empty() {
+            return;
+        }
+        let path = self.working_dir.join(trimmed);
+        match self.export_event_log(&path) {
+            Ok(count) => {
+                self.set_status_message(
+                    t!(
+                        "event_replay.exported",
+                        count = count,
+                        path = path.display().to_string()
+                    )
+                    .to_string(),
+                );
+            }
+            Err(e) => {
+                self.set_status_message(
+                    t!("event_replay.export_failed", error = format!("{:#}", e)).to_string(),
+                );
+            }
+        }
+    }
+
+    /// Whether a recorded event can be reproduced on the active buffer as
+    /// it stands now.
+    fn event_fits_active_buffer(&mut self, event: &Event) -> bool {
+        let state = self.active_state_mut();
+        let mut len = state.buffer.len();
+        let mut untouched_below = len;
+        event_fits(state, event, &mut len, &mut untouched_below)
+    }
+}
+
+/// Check `event` against a buffer that has grown or shrunk to `len` by the
+/// events before it in the same batch. Text below `untouched_below` is
+/// still as it is in `state`, so deletions there are checked against it;
+/// deletions above it can only be checked against the length.
+fn event_fits(
+    state: &mut EditorState,
+    event: &Event,
+    len: &mut usize,
+    untouched_below: &mut usize,
+) -> bool {
+    match event {
+        // Bulk edits keep their content in snapshots that aren't recorded
+        Event::BulkEdit { .. } => false,
+        Event::Insert { position, text, .. } => {
+            if *position > *len {
+                return false;
+            }
+            *len += text.len();
+            *untouched_below = (*untouched_below).min(*position);
+            true
+        }
+        Event::Delete {
+            range,
+            deleted_text,
+            ..
+        } => {
+            if range.start > range.end || range.end > *len {
+                return false;
+            }
+            if range.end <= *untouched_below
+                && state.get_text_range(range.start, range.end) != *deleted_text
+            {
+                return false;
+            }
+            *len -= range.end - range.start;
+            *untouched_below = (*untouched_below).min(range.start);
+            true
+        }
+        Event::MoveCursor { new_position, .. } => *new_position <= *len,
+        Event::AddCursor { position, .. } => *position <= *len,
+        Event::Batch { events, .. } => events
+            .iter()
+            .all(|e| event_fits(state, e, len, untouched_below)),
+        _ => true,
+    }
+}
@@ -165,6 +165,36 @@ impl Editor {
         }
     }
 
+    /// Reveal the active buffer's file in the file explorer: open and focus
+    /// the explorer, expand the tree down to the file, select it and scroll
+    /// it into view.
+    pub fn reveal_in_explorer(&mut self) {
+        let Some(path) = self
+            .buffer_metadata
+            .get(&self.active_buffer())
+            .and_then(|metadata| metadata.file_path())
+            .cloned()
+        else {
+            self.set_status_message(t!("explorer.reveal_no_file").to_string());
+            return;
+        };
+
+        // Files outside the working directory (restored as external files)
+        // have no node in the tree to reveal.
+        if !path.starts_with(&self.working_dir) {
+            let name = path
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_default();
+            self.set_status_message(t!("explorer.reveal_outside_root", name = &name).to_string());
+            return;
+        }
+
+        // Both paths end in `sync_file_explorer_to_active_file`, which
+        // expands to the file and scrolls the selection into view.
+        self.focus_file_explorer();
+    }
+
     pub fn focus_editor(&mut self) {
         self.key_context = KeyContext::Normal;
         self.set_status_message(t!("editor.focused").to_string());
@@ -640,6 +670,21 @@ impl Editor {
             return;
         }
 
+        // Deleting force-closes the buffers backed by these paths, so call
+        // out unsaved edits before the user confirms.
+        let has_unsaved = paths.iter().any(|(path, _)| {
+            self.buffer_ids_under_path(path).into_iter().any(|id| {
+                self.buffers
+                    .get(&id)
+                    .is_some_and(|state| state.buffer.is_modified())
+            })
+        });
+        let warning = if has_unsaved {
+            t!("explorer.delete_unsaved_warning").to_string()
+        } else {
+            String::new()
+        };
+
         if paths.len() == 1 {
             let (path, is_dir) = paths.into_iter().next().unwrap();
             let name = path
@@ -649,7 +694,11 @@ impl Editor {
                 .to_string();
             let type_str = if is_dir { "directory" } else { "file" };
             self.start_prompt(
-                t!("explorer.delete_confirm", "type" = type_str, name = &name).to_string(),
+                format!(
+                    "{}{}",
+                    warning,
+                    t!("explorer.delete_confirm", "type" = type_str, name = &name)
+                ),
                 PromptType::ConfirmDeleteFile { path, is_dir },
             );
         } else {
@@ -660,12 +709,15 @@ impl Editor {
             // fit in the minibuffer budget.
             let names = format_path_preview_for_prompt(&all_paths, 3);
             self.start_prompt(
-                t!(
-                    "explorer.delete_multi_confirm",
-                    count = count,
-                    names = &names
-                )
-                .to_string(),
+                format!(
+                    "{}{}",
+                    warning,
+                    t!(
+                        "explorer.delete_multi_confirm",
+                        count = count,
+                        names = &names
+                    )
+                ),
                 PromptType::ConfirmMultiDelete { paths: all_paths },
             );
         }
@@ -973,6 +1025,8 @@ impl Editor {
                 explorer.clear_multi_selection();
             } else if explorer.is_search_active() {
                 explorer.search_clear();
+            } else if explorer.is_filter_active() {
+                explorer.clear_filter();
             } else {
                 self.focus_editor();
             }
@@ -1004,21 +1058,67 @@ impl Editor {
     }
 
     /// Add a character to the file explorer search
+    ///
+    /// `/` with no search in progress enters filter mode instead; while
+    /// filtering, typed characters extend the filter query.
     pub fn file_explorer_search_push_char(&mut self, c: char) {
-        if let Some(explorer) = &mut self.file_explorer {
+        let Some(explorer) = &mut self.file_explorer else {
+            return;
+        };
+        if explorer.is_filter_active() {
+            let query = format!("{}{}", explorer.filter_query(), c);
+            self.file_explorer_set_filter(&query);
+        } else if c == '/' && !explorer.is_search_active() {
+            self.file_explorer_set_filter("");
+        } else {
             explorer.search_push_char(c);
             explorer.update_scroll_for_selection();
         }
     }
 
     /// Remove a character from the file explorer search (backspace)
+    ///
+    /// Backspace on an empty filter query leaves filter mode.
     pub fn file_explorer_search_pop_char(&mut self) {
-        if let Some(explorer) = &mut self.file_explorer {
+        let Some(explorer) = &mut self.file_explorer else {
+            return;
+        };
+        if explorer.is_filter_active() {
+            let mut query = explorer.filter_query().to_string();
+            if query.pop().is_some() {
+                self.file_explorer_set_filter(&query);
+            } else {
+                explorer.clear_filter();
+            }
+        } else {
             explorer.search_pop_char();
             explorer.update_scroll_for_selection();
         }
     }
 
+    /// Narrow the file explorer to paths fuzzy-matching `query`.
+    ///
+    /// Loaded nodes are matched right away; the rest of the tree is listed
+    /// in the background and matched when it arrives.
+    fn file_explorer_set_filter(&mut self, query: &str) {
+        let Some(explorer) = &mut self.file_explorer else {
+            return;
+        };
+        explorer.set_filter(query);
+
+        if let (Some(runtime), Some(bridge)) = (&self.tokio_runtime, &self.async_bridge) {
+            if let Some(load) = explorer.begin_filter_load() {
+                let sender = bridge.sender();
+                runtime.spawn(async move {
+                    let listings = load.await;
+                    // Receiver may have been dropped during shutdown.
+                    #[allow(clippy::let_underscore_must_use)]
+                    let _ = sender.send(AsyncMessage::FileExplorerFilterLoaded(listings));
+                });
+            }
+        }
+    }
+
     pub fn handle_set_file_explorer_decorations(
         &mut self,
         namespace: String,
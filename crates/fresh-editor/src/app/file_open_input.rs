@@ -142,6 +142,9 @@ impl Editor {
             Action::PromptCancel => {
                 self.cancel_prompt();
                 self.file_open_state = None;
+                if let Some(task) = self.file_open_listing_task.take() {
+                    task.abort();
+                }
                 true
             }
 
@@ -417,6 +420,7 @@ impl Editor {
                     disabled: false,
                     keybinding: None,
                     source: None,
+                    match_positions: Vec::new(),
                 }
             })
             .collect();
@@ -508,60 +512,102 @@ impl Editor {
             .map(|p| p.input.clone())
             .unwrap_or_default();
 
-        // Check if user typed/pasted a path containing directory separators
-        // Navigate to the parent directory of the path (so the file appears in the list)
-        if filter.contains('/') {
-            let current_dir = self
-                .file_open_state
-                .as_ref()
-                .map(|s| s.current_dir.clone())
-                .unwrap_or_else(|| std::env::current_dir().unwrap_or_default());
+        // If the user typed/pasted a path into another directory, list that
+        // directory in the background. Whether it exists is only known once
+        // the listing returns, so nothing here touches the filesystem.
+        if let Some((target_dir, _)) = self.typed_file_open_target() {
+            let current_dir = self.file_open_state.as_ref().map(|s| &s.current_dir);
+            if current_dir != Some(&target_dir) {
+                self.spawn_file_open_listing(target_dir, true);
+            }
+        }
 
-            // Build the full path
-            // Expand tilde and resolve path
-            let tilde_expanded = expand_tilde(&filter);
-            let full_path = if tilde_expanded.is_absolute() {
-                tilde_expanded
-            } else {
-                current_dir.join(&filter)
-            };
+        if let Some(state) = &mut self.file_open_state {
+            state.apply_filter(&filter);
+        }
+    }
 
-            // Get the parent directory and filename
-            let (target_dir, filename) = if filter.ends_with('/') {
-                // Path ends with /, treat the whole thing as a directory
-                (full_path.clone(), String::new())
-            } else {
-                // Get parent directory so the file will be in the listing
-                let parent = full_path
-                    .parent()
-                    .map(|p| p.to_path_buf())
-                    .unwrap_or(full_path.clone());
-                let name = full_path
-                    .file_name()
-                    .map(|n| n.to_string_lossy().to_string())
-                    .unwrap_or_default();
-                (parent, name)
-            };
+    /// Directory and filename the prompt input points at, when the input
+    /// contains a directory separator (e.g. `src/ma`, `~/`, `/tmp/x`).
+    fn typed_file_open_target(&self) -> Option<(std::path::PathBuf, String)> {
+        let filter = &self.prompt.as_ref()?.input;
+        if !filter.contains('/') {
+            return None;
+        }
+        let current_dir = self
+            .file_open_state
+            .as_ref()
+            .map(|s| s.current_dir.clone())
+            .unwrap_or_else(|| std::env::current_dir().unwrap_or_default());
 
-            // Navigate to target directory if it exists and is different from current
-            if target_dir.is_dir() && target_dir != current_dir {
-                // Update prompt to only show the filename (directory is shown separately)
-                if let Some(prompt) = &mut self.prompt {
-                    prompt.input = filename.clone();
-                    prompt.cursor_pos = prompt.input.len();
-                }
-                self.load_file_open_directory(target_dir);
+        // Build the full path
+        // Expand tilde and resolve path
+        let tilde_expanded = expand_tilde(filter);
+        let full_path = if tilde_expanded.is_absolute() {
+            tilde_expanded
+        } else {
+            current_dir.join(filter)
+        };
 
-                // Apply filter with the filename only
-                if let Some(state) = &mut self.file_open_state {
-                    state.apply_filter(&filename);
+        if filter.ends_with('/') {
+            // Path ends with /, treat the whole thing as a directory
+            return Some((full_path, String::new()));
+        }
+        // Get parent directory so the file will be in the listing
+        let parent = full_path
+            .parent()
+            .map(|p| p.to_path_buf())
+            .unwrap_or(full_path.clone());
+        let name = full_path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+        Some((parent, name))
+    }
+
+    /// Navigate into a directory typed into the prompt once its listing
+    /// arrives, provided the prompt still points into it.
+    pub(super) fn handle_typed_directory_loaded(
+        &mut self,
+        path: std::path::PathBuf,
+        result: std::io::Result<Vec<crate::services::fs::DirEntry>>,
+    ) {
+        if !self.is_file_open_active() {
+            return;
+        }
+        let entries = match result {
+            Ok(entries) => entries,
+            Err(_) => {
+                // Not a directory (yet) — keep browsing where we are. If this
+                // listing superseded the initial one, restart that.
+                if let Some(state) = &self.file_open_state {
+                    if state.loading {
+                        let current_dir = state.current_dir.clone();
+                        self.load_file_open_directory(current_dir);
+                    }
                 }
                 return;
             }
-        }
+        };
+        let Some(filename) = self
+            .typed_file_open_target()
+            .and_then(|(dir, name)| (dir == path).then_some(name))
+        else {
+            return;
+        };
 
+        // Update prompt to only show the filename (directory is shown separately)
+        if let Some(prompt) = &mut self.prompt {
+            prompt.input = filename.clone();
+            prompt.cursor_pos = prompt.input.len();
+        }
         if let Some(state) = &mut self.file_open_state {
-            state.apply_filter(&filter);
+            state.current_dir = path;
+            state.error = None;
+            state.update_shortcuts();
+            state.set_entries(entries);
+            // Apply filter with the filename only
+            state.apply_filter(&filename);
         }
     }
 
@@ -17,6 +17,7 @@ use std::sync::Arc;
 use rust_i18n::t;
 
 use crate::model::event::{BufferId, LeafId};
+use crate::model::event_stream::RecordKind;
 use crate::state::EditorState;
 
 use super::Editor;
@@ -58,6 +59,13 @@ impl Editor {
 
         self.set_active_buffer(buffer_id);
 
+        if self.event_stream.is_some() {
+            if let Some(file_path) = self.active_state().buffer.file_path() {
+                let path = file_path.to_path_buf();
+                self.record_to_event_stream(RecordKind::OpenFile { path });
+            }
+        }
+
         // If the initial empty buffer was replaced in-place with file content,
         // set_active_buffer is a no-op (same buffer ID). Fire buffer_activated
         // explicitly so plugins see the newly loaded file.
@@ -215,6 +223,17 @@ impl Editor {
             return Ok(id);
         }
 
+        if self.plugin_manager.has_hook_handlers("before_file_open")
+            && !self.plugin_manager.run_veto_hook(
+                "before_file_open",
+                crate::services::plugins::hooks::HookArgs::BeforeFileOpen {
+                    path: path.to_path_buf(),
+                },
+            )
+        {
+            anyhow::bail!(t!("file.open_blocked", path = path.display().to_string()));
+        }
+
         // If the current buffer is empty and unmodified, replace it instead of creating a new one
         // Note: Don't replace composite buffers (they appear empty but are special views).
         // Suppressed when `allow_replace_empty` is false — see
@@ -287,13 +306,20 @@ impl Editor {
             crate::config::WhitespaceVisibility::from_editor_config(&self.config.editor);
         state.buffer_settings.auto_close = self.config.editor.auto_close;
         state.buffer_settings.auto_surround = self.config.editor.auto_surround;
+        state.buffer_settings.backspace_unindent = self.config.editor.backspace_unindent;
+        state.buffer_settings.indent_guides = self.config.editor.indent_guides;
+        state.buffer_settings.highlight_all_cursor_lines =
+            self.config.editor.highlight_all_cursor_lines;
         if let Some(lang_config) = self.config.languages.get(&state.language) {
             whitespace = whitespace.with_language_tab_override(lang_config.show_whitespace_tabs);
-            state.buffer_settings.use_tabs =
-                lang_config.use_tabs.unwrap_or(self.config.editor.use_tabs);
+            state.buffer_settings.use_tabs = lang_config
+                .use_tabs_override()
+                .unwrap_or(self.config.editor.effective_use_tabs());
             // Use language-specific tab_size if set, otherwise fall back to global
-            state.buffer_settings.tab_size =
-                lang_config.tab_size.unwrap_or(self.config.editor.tab_size);
+            state.buffer_settings.tab_size = lang_config
+                .tab_size_override()
+                .unwrap_or(self.config.editor.effective_tab_size());
+            state.buffer_settings.auto_indent = lang_config.auto_indent;
             // Auto close: language override (only if globally enabled)
             if state.buffer_settings.auto_close {
                 if let Some(lang_auto_close) = lang_config.auto_close {
@@ -307,11 +333,26 @@ impl Editor {
                 }
             }
         } else {
-            state.buffer_settings.tab_size = self.config.editor.tab_size;
-            state.buffer_settings.use_tabs = self.config.editor.use_tabs;
+            state.buffer_settings.tab_size = self.config.editor.effective_tab_size();
+            state.buffer_settings.use_tabs = self.config.editor.effective_use_tabs();
         }
         state.buffer_settings.whitespace = whitespace;
 
+        // Indentation found in the file wins over the configured defaults
+        let detected_indent = if file_exists && !is_binary && self.config.editor.detect_indentation
+        {
+            super::indent_detection::detect_buffer_indent(&mut state.buffer)
+        } else {
+            None
+        };
+        if let Some(indent) = detected_indent {
+            super::indent_detection::apply_detected_indent(
+                &mut state.buffer_settings,
+                indent,
+                self.config.languages.get(&state.language),
+            );
+        }
+
         // Apply line_numbers default from config
         state
             .margins
@@ -319,7 +360,7 @@ impl Editor {
 
         self.buffers.insert(buffer_id, state);
         self.event_logs
-            .insert(buffer_id, crate::model::event::EventLog::new());
+            .insert(buffer_id, self.new_event_log(buffer_id));
 
         // Create metadata for this buffer
         let mut metadata = super::types::BufferMetadata::with_file(
@@ -329,6 +370,8 @@ impl Editor {
             self.authority.path_translation.as_ref(),
         );
 
+        metadata.detected_indent = detected_indent;
+
         // Mark binary files in metadata and disable LSP
         if is_binary {
             metadata.binary = true;
@@ -371,6 +414,8 @@ impl Editor {
                 self.config.editor.highlight_current_line,
                 line_wrap,
                 self.config.editor.wrap_indent,
+                self.config.editor.wrap_mode,
+                self.config.editor.wrap_indent_extra,
                 wrap_column,
                 self.config.editor.rulers.clone(),
             );
@@ -466,7 +511,7 @@ impl Editor {
 
         self.buffers.insert(buffer_id, state);
         self.event_logs
-            .insert(buffer_id, crate::model::event::EventLog::new());
+            .insert(buffer_id, self.new_event_log(buffer_id));
 
         // Create metadata
         let metadata = super::types::BufferMetadata::with_file(
@@ -489,6 +534,8 @@ impl Editor {
                 self.config.editor.highlight_current_line,
                 line_wrap,
                 self.config.editor.wrap_indent,
+                self.config.editor.wrap_mode,
+                self.config.editor.wrap_indent_extra,
                 wrap_column,
                 self.config.editor.rulers.clone(),
             );
@@ -580,7 +627,7 @@ impl Editor {
 
         self.buffers.insert(buffer_id, state);
         self.event_logs
-            .insert(buffer_id, crate::model::event::EventLog::new());
+            .insert(buffer_id, self.new_event_log(buffer_id));
 
         let metadata = super::types::BufferMetadata::with_file(
             path.to_path_buf(),
@@ -602,6 +649,8 @@ impl Editor {
                 self.config.editor.highlight_current_line,
                 line_wrap,
                 self.config.editor.wrap_indent,
+                self.config.editor.wrap_mode,
+                self.config.editor.wrap_indent_extra,
                 wrap_column,
                 self.config.editor.rulers.clone(),
             );
@@ -729,7 +778,7 @@ impl Editor {
 
         self.buffers.insert(buffer_id, state);
         self.event_logs
-            .insert(buffer_id, crate::model::event::EventLog::new());
+            .insert(buffer_id, self.new_event_log(buffer_id));
 
         let metadata = super::types::BufferMetadata::with_file(
             path.to_path_buf(),
@@ -751,6 +800,8 @@ impl Editor {
                 self.config.editor.highlight_current_line,
                 line_wrap,
                 self.config.editor.wrap_indent,
+                self.config.editor.wrap_mode,
+                self.config.editor.wrap_indent_extra,
                 wrap_column,
                 self.config.editor.rulers.clone(),
             );
@@ -1063,13 +1114,15 @@ impl Editor {
             crate::config::WhitespaceVisibility::from_editor_config(&self.config.editor);
         if let Some(lang_config) = self.config.languages.get(&state.language) {
             whitespace = whitespace.with_language_tab_override(lang_config.show_whitespace_tabs);
-            state.buffer_settings.use_tabs =
-                lang_config.use_tabs.unwrap_or(self.config.editor.use_tabs);
-            state.buffer_settings.tab_size =
-                lang_config.tab_size.unwrap_or(self.config.editor.tab_size);
+            state.buffer_settings.use_tabs = lang_config
+                .use_tabs_override()
+                .unwrap_or(self.config.editor.effective_use_tabs());
+            state.buffer_settings.tab_size = lang_config
+                .tab_size_override()
+                .unwrap_or(self.config.editor.effective_tab_size());
         } else {
-            state.buffer_settings.tab_size = self.config.editor.tab_size;
-            state.buffer_settings.use_tabs = self.config.editor.use_tabs;
+            state.buffer_settings.tab_size = self.config.editor.effective_tab_size();
+            state.buffer_settings.use_tabs = self.config.editor.effective_use_tabs();
         }
         state.buffer_settings.whitespace = whitespace;
         state
@@ -1080,7 +1133,7 @@ impl Editor {
         self.next_buffer_id += 1;
         self.buffers.insert(buffer_id, state);
         self.event_logs
-            .insert(buffer_id, crate::model::event::EventLog::new());
+            .insert(buffer_id, self.new_event_log(buffer_id));
 
         let mut metadata =
             super::types::BufferMetadata::with_container_file(container_path.clone(), uri);
@@ -1105,6 +1158,8 @@ impl Editor {
                 self.config.editor.highlight_current_line,
                 line_wrap,
                 self.config.editor.wrap_indent,
+                self.config.editor.wrap_mode,
+                self.config.editor.wrap_indent_extra,
                 wrap_column,
                 self.config.editor.rulers.clone(),
             );
@@ -17,10 +17,11 @@ use std::path::{Path, PathBuf};
 use lsp_types::TextDocumentContentChangeEvent;
 use rust_i18n::t;
 
-use crate::model::event::{BufferId, EventLog};
+use crate::model::event::BufferId;
 use crate::services::lsp::manager::LspSpawnResult;
 use crate::state::EditorState;
 
+use super::on_save_actions::OnSaveReport;
 use super::{BufferMetadata, Editor};
 
 impl Editor {
@@ -43,8 +44,20 @@ impl Editor {
             .file_path()
             .map(|p| p.to_path_buf());
 
-        match self.active_state_mut().buffer.save() {
-            Ok(()) => self.finalize_save(path),
+        let buffer_id = self.active_buffer();
+        let written = match path {
+            Some(ref p) => self.write_buffer_with_pre_save_actions(buffer_id, p),
+            None => self
+                .active_state_mut()
+                .buffer
+                .save()
+                .map(|()| Some(Ok(OnSaveReport::default()))),
+        };
+
+        match written {
+            // A plugin vetoed the save
+            Ok(None) => Ok(()),
+            Ok(Some(pre_save)) => self.finalize_save_after_actions(buffer_id, path, Some(pre_save)),
             Err(e) => {
                 if let Some(sudo_info) = e.downcast_ref::<SudoSaveRequired>() {
                     let info = sudo_info.clone();
@@ -83,6 +96,115 @@ impl Editor {
         }
     }
 
+    /// Write `buffer_id` to `path` once `before_file_save` handlers allow
+    /// it. Every save of a buffer to disk goes through here (or
+    /// `write_buffers` or `write_buffer_with_pre_save_actions`) so plugins
+    /// can't be bypassed.
+    ///
+    /// Returns Ok(false), with a status message, when a plugin vetoed the
+    /// save.
+    pub(crate) fn write_buffer(
+        &mut self,
+        buffer_id: BufferId,
+        path: &Path,
+    ) -> anyhow::Result<bool> {
+        match self
+            .write_buffers(vec![(buffer_id, path.to_path_buf())])
+            .pop()
+        {
+            Some((_, _, result)) => result.map(|()| true),
+            None => Ok(false),
+        }
+    }
+
+    /// Save `buffer_id` to `path` as the user asked: the `before_file_save`
+    /// handlers are asked first, so a vetoed save leaves the buffer
+    /// untouched, then the pre-save actions clean up and format the active
+    /// buffer and it is written once.
+    ///
+    /// Returns Ok(None), with a status message, when a plugin vetoed the
+    /// save, else the outcome of the pre-save actions. A failed action
+    /// doesn't stop the write.
+    pub(crate) fn write_buffer_with_pre_save_actions(
+        &mut self,
+        buffer_id: BufferId,
+        path: &Path,
+    ) -> anyhow::Result<Option<Result<OnSaveReport, String>>> {
+        if self
+            .plugins_allow_saves(vec![(buffer_id, path.to_path_buf())])
+            .is_empty()
+        {
+            return Ok(None);
+        }
+        // These actions work on the active buffer
+        let pre_save = if buffer_id == self.active_buffer() {
+            self.run_pre_save_actions()
+        } else {
+            Ok(OnSaveReport::default())
+        };
+        let state = self
+            .buffers
+            .get_mut(&buffer_id)
+            .ok_or_else(|| anyhow::anyhow!("Buffer {:?} not found", buffer_id))?;
+        state.buffer.save_to_file(path)?;
+        Ok(Some(pre_save))
+    }
+
+    /// Write several buffers, each to its paired path, asking the
+    /// `before_file_save` handlers about all of them at once so the wait
+    /// for plugins doesn't grow with the number of buffers.
+    ///
+    /// Vetoed saves are left out of the result; the others carry the
+    /// outcome of the write.
+    pub(crate) fn write_buffers(
+        &mut self,
+        saves: Vec<(BufferId, PathBuf)>,
+    ) -> Vec<(BufferId, PathBuf, anyhow::Result<()>)> {
+        self.plugins_allow_saves(saves)
+            .into_iter()
+            .filter_map(|(buffer_id, path)| {
+                let state = self.buffers.get_mut(&buffer_id)?;
+                let result = state.buffer.save_to_file(&path);
+                Some((buffer_id, path, result))
+            })
+            .collect()
+    }
+
+    /// Ask `before_file_save` handlers about several saves, waiting at most
+    /// one veto-hook timeout for all of them. Returns the saves plugins
+    /// allow and shows a status message for a vetoed one.
+    fn plugins_allow_saves(&mut self, saves: Vec<(BufferId, PathBuf)>) -> Vec<(BufferId, PathBuf)> {
+        if saves.is_empty() || !self.plugin_manager.has_hook_handlers("before_file_save") {
+            return saves;
+        }
+        let args = saves
+            .iter()
+            .map(
+                |(buffer_id, path)| crate::services::plugins::hooks::HookArgs::BeforeFileSave {
+                    buffer_id: *buffer_id,
+                    path: path.clone(),
+                    text: self
+                        .buffers
+                        .get(buffer_id)
+                        .and_then(|state| state.buffer.to_string()),
+                },
+            )
+            .collect();
+        let verdicts = self.plugin_manager.run_veto_hooks("before_file_save", args);
+
+        let mut allowed = Vec::with_capacity(saves.len());
+        for ((buffer_id, path), allow) in saves.into_iter().zip(verdicts) {
+            if allow {
+                allowed.push((buffer_id, path));
+            } else {
+                self.set_status_message(
+                    t!("file.save_blocked", path = path.display().to_string()).to_string(),
+                );
+            }
+        }
+        allowed
+    }
+
     /// Internal helper to finalize save state (mark as saved, notify LSP, etc.)
     pub(crate) fn finalize_save(&mut self, path: Option<PathBuf>) -> anyhow::Result<()> {
         let buffer_id = self.active_buffer();
@@ -95,6 +217,20 @@ impl Editor {
         buffer_id: BufferId,
         path: Option<PathBuf>,
         silent: bool,
+    ) -> anyhow::Result<()> {
+        let pre_save = (!silent).then(|| Ok(OnSaveReport::default()));
+        self.finalize_save_after_actions(buffer_id, path, pre_save)
+    }
+
+    /// Finalize the save of `buffer_id`. `pre_save` is the outcome of the
+    /// pre-save actions of a save the user asked for; the on-save actions
+    /// then run and the status message reports both. A silent save passes
+    /// None and skips the on-save actions and the status message.
+    pub(crate) fn finalize_save_after_actions(
+        &mut self,
+        buffer_id: BufferId,
+        path: Option<PathBuf>,
+        pre_save: Option<Result<OnSaveReport, String>>,
     ) -> anyhow::Result<()> {
         // Auto-detect language if it's currently "text" and we have a path
         if let Some(ref p) = path {
@@ -113,10 +249,6 @@ impl Editor {
             }
         }
 
-        if !silent {
-            self.status_message = Some(t!("status.file_saved").to_string());
-        }
-
         // Mark the event log position as saved (for undo modified tracking)
         if let Some(event_log) = self.event_logs.get_mut(&buffer_id) {
             event_log.mark_saved();
@@ -179,44 +311,50 @@ impl Editor {
         // We might need to refactor it too if we want auto-save to trigger formatters.
         // For now, let's just do it for active buffer or skip for silent auto-saves.
 
-        if !silent {
-            match self.run_on_save_actions() {
-                Ok(true) => {
-                    // Actions ran successfully - if status_message was set by run_on_save_actions
-                    // (e.g., for missing optional formatters), keep it. Otherwise update status.
-                    if self.status_message.as_deref() == Some(&t!("status.file_saved")) {
-                        self.status_message =
-                            Some(t!("status.file_saved_with_actions").to_string());
-                    }
-                    // else: keep the message set by run_on_save_actions (e.g., missing formatter)
-                }
-                Ok(false) => {
-                    // No actions configured, keep original status
-                }
-                Err(e) => {
-                    // Action failed, show error but don't fail the save
-                    self.status_message = Some(e);
-                }
-            }
+        if let Some(pre_save) = pre_save {
+            let on_save = self.run_on_save_actions();
+            let report = pre_save.and_then(|pre| on_save.map(|post| pre.merge(post)));
+            self.status_message = Some(match report {
+                // e.g. a missing optional formatter
+                Ok(OnSaveReport {
+                    notice: Some(notice),
+                    ..
+                }) => notice,
+                Ok(report) if report.ran_action => t!("status.file_saved_with_actions").to_string(),
+                Ok(_) => t!("status.file_saved").to_string(),
+                // A failed action shows its error but doesn't fail the save
+                Err(e) => e,
+            });
         }
 
         Ok(())
     }
 
-    /// Auto-save all modified buffers to their original files on disk
+    /// Auto-save all modified buffers to their original files on disk once
+    /// editing has been idle for `auto_save_interval_secs`.
+    /// Attempts are spaced at least that far apart, and further apart after
+    /// failed writes (see `auto_save_retry_delay`).
     /// Returns the number of buffers saved
     pub fn auto_save_persistent_buffers(&mut self) -> anyhow::Result<usize> {
         if !self.config.editor.auto_save_enabled {
             return Ok(0);
         }
 
-        // Check if enough time has passed since last auto-save
+        // Debounce: every edit pushes the save back, so files aren't written
+        // mid-burst while the user is still typing
         let interval =
             std::time::Duration::from_secs(self.config.editor.auto_save_interval_secs as u64);
+        if self.time_source.elapsed_since(self.last_buffer_edit) < interval {
+            return Ok(0);
+        }
+
+        // Rate limit: this runs on every tick, so don't retry faster than
+        // the interval, backing off further while writes keep failing
+        let delay = Self::auto_save_retry_delay(interval, self.persistent_auto_save_failures);
         if self
             .time_source
             .elapsed_since(self.last_persistent_auto_save)
-            < interval
+            < delay
         {
             return Ok(0);
         }
@@ -234,31 +372,47 @@ impl Editor {
         }
 
         let mut count = 0;
-        for (id, path) in to_save {
-            if let Some(state) = self.buffers.get_mut(&id) {
-                match state.buffer.save() {
-                    Ok(()) => {
-                        self.finalize_save_buffer(id, Some(path), true)?;
-                        count += 1;
-                    }
-                    Err(e) => {
-                        // Skip if sudo is required (auto-save can't handle prompts)
-                        if e.downcast_ref::<SudoSaveRequired>().is_some() {
-                            tracing::debug!(
-                                "Auto-save skipped for {:?} (sudo required)",
-                                path.display()
-                            );
-                        } else {
-                            tracing::warn!("Auto-save failed for {:?}: {}", path.display(), e);
-                        }
+        let mut failed = false;
+        for (id, path, result) in self.write_buffers(to_save) {
+            match result {
+                Ok(()) => {
+                    self.finalize_save_buffer(id, Some(path), true)?;
+                    count += 1;
+                }
+                Err(e) => {
+                    failed = true;
+                    // Skip if sudo is required (auto-save can't handle prompts)
+                    if e.downcast_ref::<SudoSaveRequired>().is_some() {
+                        tracing::debug!(
+                            "Auto-save skipped for {:?} (sudo required)",
+                            path.display()
+                        );
+                    } else {
+                        tracing::warn!("Auto-save failed for {:?}: {}", path.display(), e);
                     }
                 }
             }
         }
 
+        if failed {
+            self.persistent_auto_save_failures =
+                self.persistent_auto_save_failures.saturating_add(1);
+        } else {
+            self.persistent_auto_save_failures = 0;
+        }
+
         Ok(count)
     }
 
+    /// Minimum time between persistent auto-save attempts: the configured
+    /// interval, doubled for each consecutive failed round (up to 64x).
+    fn auto_save_retry_delay(interval: std::time::Duration, failures: u32) -> std::time::Duration {
+        if failures == 0 {
+            return interval;
+        }
+        interval.max(std::time::Duration::from_secs(1)) * (1 << failures.min(6))
+    }
+
     /// Save all modified file-backed buffers to disk (called on exit when auto_save is enabled).
     /// Unlike `auto_save_persistent_buffers`, this skips the interval check and only saves
     /// named file-backed buffers (not unnamed buffers).
@@ -275,26 +429,20 @@ impl Editor {
         }
 
         let mut count = 0;
-        for (id, path) in to_save {
-            if let Some(state) = self.buffers.get_mut(&id) {
-                match state.buffer.save() {
-                    Ok(()) => {
-                        self.finalize_save_buffer(id, Some(path), true)?;
-                        count += 1;
-                    }
-                    Err(e) => {
-                        if e.downcast_ref::<SudoSaveRequired>().is_some() {
-                            tracing::debug!(
-                                "Auto-save on exit skipped for {} (sudo required)",
-                                path.display()
-                            );
-                        } else {
-                            tracing::warn!(
-                                "Auto-save on exit failed for {}: {}",
-                                path.display(),
-                                e
-                            );
-                        }
+        for (id, path, result) in self.write_buffers(to_save) {
+            match result {
+                Ok(()) => {
+                    self.finalize_save_buffer(id, Some(path), true)?;
+                    count += 1;
+                }
+                Err(e) => {
+                    if e.downcast_ref::<SudoSaveRequired>().is_some() {
+                        tracing::debug!(
+                            "Auto-save on exit skipped for {} (sudo required)",
+                            path.display()
+                        );
+                    } else {
+                        tracing::warn!("Auto-save on exit failed for {}: {}", path.display(), e);
                     }
                 }
             }
@@ -377,8 +525,9 @@ impl Editor {
         }
 
         // Clear the undo/redo history for this buffer
+        let fresh_log = self.new_event_log(buffer_id);
         if let Some(event_log) = self.event_logs.get_mut(&buffer_id) {
-            *event_log = EventLog::new();
+            *event_log = fresh_log;
         }
 
         // Clear seen_byte_ranges so plugins get notified of all visible lines
@@ -1178,8 +1327,9 @@ impl Editor {
         }
 
         // Clear the undo/redo history for this buffer
+        let fresh_log = self.new_event_log(buffer_id);
         if let Some(event_log) = self.event_logs.get_mut(&buffer_id) {
-            *event_log = EventLog::new();
+            *event_log = fresh_log;
         }
 
         // Clear seen_byte_ranges so plugins get notified of all visible lines
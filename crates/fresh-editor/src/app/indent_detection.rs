@@ -0,0 +1,96 @@
+//! Indentation detected from a file's content when it is opened.
+//!
+//! The result is kept on `BufferMetadata` so that re-resolving a buffer's
+//! settings from config (a settings change, Reset Buffer Settings) keeps
+//! the detected style and width instead of reverting to the defaults.
+
+use rust_i18n::t;
+
+use super::Editor;
+use crate::config::LanguageConfig;
+use crate::model::buffer::Buffer;
+use crate::model::event::BufferId;
+use crate::primitives::indent_detection::{detect_indent, DetectedIndent};
+use crate::state::BufferSettings;
+
+/// Bytes read from the start of a file to detect its indentation.
+const SAMPLE_BYTES: usize = 64 * 1024;
+
+/// Detect the indentation of the start of `buffer`.
+pub(super) fn detect_buffer_indent(buffer: &mut Buffer) -> Option<DetectedIndent> {
+    let len = buffer.len().min(SAMPLE_BYTES);
+    let bytes = buffer.get_text_range_mut(0, len).ok()?;
+    detect_indent(String::from_utf8_lossy(&bytes).lines())
+}
+
+/// Override the configured indentation in `settings` with `indent`.
+///
+/// A language that sets its indent style or width explicitly (a Makefile
+/// requires tabs) keeps that setting. The detected width only changes the
+/// indent step; tab characters keep their configured display width.
+pub(super) fn apply_detected_indent(
+    settings: &mut BufferSettings,
+    indent: DetectedIndent,
+    lang_config: Option<&LanguageConfig>,
+) {
+    if lang_config.is_none_or(|lang| lang.use_tabs_override().is_none()) {
+        settings.use_tabs = indent.use_tabs;
+    }
+    if !settings.use_tabs && lang_config.is_none_or(|lang| lang.tab_size_override().is_none()) {
+        settings.indent_width = indent.width;
+    }
+}
+
+impl Editor {
+    /// Re-apply a buffer's detected indentation after its settings were
+    /// resolved from config again.
+    pub(super) fn reapply_detected_indent(&mut self, buffer_id: BufferId) {
+        if !self.config.editor.detect_indentation {
+            return;
+        }
+        let Some(indent) = self
+            .buffer_metadata
+            .get(&buffer_id)
+            .and_then(|m| m.detected_indent)
+        else {
+            return;
+        };
+        if let Some(state) = self.buffers.get_mut(&buffer_id) {
+            let lang_config = self.config.languages.get(&state.language);
+            apply_detected_indent(&mut state.buffer_settings, indent, lang_config);
+        }
+    }
+
+    /// Show the active buffer's indentation in the status bar, and whether
+    /// it was detected from the file.
+    pub(super) fn show_indentation(&mut self) {
+        let settings = &self.active_state().buffer_settings;
+        let (use_tabs, width) = (settings.use_tabs, settings.indent_size());
+        let style = if use_tabs {
+            t!("whitespace.style_tabs")
+        } else {
+            t!("whitespace.style_spaces")
+        };
+        let detected = self
+            .buffer_metadata
+            .get(&self.active_buffer())
+            .and_then(|m| m.detected_indent)
+            .is_some_and(|indent| {
+                indent.use_tabs == use_tabs && indent.width.is_none_or(|w| w == width)
+            });
+        let msg = if detected {
+            t!(
+                "whitespace.indentation_detected",
+                style = style,
+                width = width
+            )
+        } else {
+            t!(
+                "whitespace.indentation_configured",
+                style = style,
+                width = width
+            )
+        };
+        self.set_status_message(msg.to_string());
+    }
+}
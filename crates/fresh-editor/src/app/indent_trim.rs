@@ -0,0 +1,147 @@
+//! Deferred trimming of auto-inserted blank indentation.
+//!
+//! When Enter leaves the cursor on a new line holding nothing but the
+//! smart-indent whitespace, that line is remembered. If the cursor later
+//! leaves it without anything having been typed, the whitespace is
+//! removed so blank lines don't accumulate trailing spaces. Indentation
+//! the user typed themselves is never tracked and so never trimmed.
+//! The removal joins the undo step of the edit before it, so undoing
+//! that edit never leaves the trimmed whitespace behind on its own.
+//! A line in a buffer that is no longer active is left as it is.
+
+use crate::model::event::{BufferId, CursorId, Event};
+use crate::model::marker::MarkerId;
+
+use super::Editor;
+
+/// A blank line whose only content is indentation inserted by Enter.
+#[derive(Debug, Clone)]
+pub(crate) struct PendingIndentTrim {
+    pub buffer_id: BufferId,
+    /// Marker at the start of the tracked line.
+    pub line_start: MarkerId,
+    /// The whitespace that was auto-inserted.
+    pub indent: String,
+}
+
+impl Editor {
+    /// Trim or keep tracking the pending auto-indented line after an
+    /// action, then start tracking the cursor's line if the action was an
+    /// Enter that left it holding only indentation.
+    pub(super) fn update_pending_indent_trim(&mut self, inserted_newline: bool) {
+        if let Some(pending) = self.pending_indent_trim.take() {
+            self.resolve_pending_indent_trim(pending);
+        }
+        if inserted_newline {
+            self.track_auto_indented_line();
+        }
+    }
+
+    fn resolve_pending_indent_trim(&mut self, pending: PendingIndentTrim) {
+        let estimated_line_length = self.config.editor.estimated_line_length;
+        let is_active = pending.buffer_id == self.active_buffer();
+        let cursor_positions: Vec<usize> = self
+            .active_cursors()
+            .iter()
+            .map(|(_, cursor)| cursor.position)
+            .collect();
+        let Some(state) = self.buffers.get_mut(&pending.buffer_id) else {
+            return;
+        };
+        let Some(line_start) = state.marker_list.get_position(pending.line_start) else {
+            return;
+        };
+        state.marker_list.delete(pending.line_start);
+        if !is_active {
+            return;
+        }
+
+        // The line must still start where it did and hold exactly the
+        // auto-inserted indentation; anything else means the user edited it
+        if line_start > 0 && state.get_text_range(line_start - 1, line_start) != "\n" {
+            return;
+        }
+        let line = line_content(state, line_start, estimated_line_length);
+        if line != pending.indent {
+            return;
+        }
+        let line_end = line_start + line.len();
+
+        let still_on_line = cursor_positions
+            .iter()
+            .any(|&pos| pos >= line_start && pos <= line_end);
+        if still_on_line {
+            let marker = state.marker_list.create(line_start, true);
+            self.pending_indent_trim = Some(PendingIndentTrim {
+                line_start: marker,
+                ..pending
+            });
+            return;
+        }
+
+        self.trim_auto_indent(line_start..line_end, pending.indent);
+    }
+
+    /// Delete the auto-inserted indentation from the active buffer,
+    /// folding the deletion into its latest undo step.
+    fn trim_auto_indent(&mut self, range: std::ops::Range<usize>, indent: String) {
+        let event = Event::Delete {
+            range,
+            deleted_text: indent,
+            cursor_id: CursorId::UNDO_SENTINEL,
+        };
+        if self
+            .active_event_log_mut()
+            .fold_into_last_write(event.clone())
+        {
+            self.apply_event_to_active_buffer(&event);
+        } else {
+            self.log_and_apply_event(&event);
+        }
+    }
+
+    fn track_auto_indented_line(&mut self) {
+        if self.active_cursors().count() != 1 {
+            return;
+        }
+        let estimated_line_length = self.config.editor.estimated_line_length;
+        let buffer_id = self.active_buffer();
+        let position = self.active_cursors().primary().position;
+        let Some(state) = self.buffers.get_mut(&buffer_id) else {
+            return;
+        };
+        let line_start = state
+            .buffer
+            .line_iterator(position, estimated_line_length)
+            .current_position();
+        let line = line_content(state, line_start, estimated_line_length);
+        if line.is_empty()
+            || line_start + line.len() != position
+            || !line.chars().all(|c| c == ' ' || c == '\t')
+        {
+            return;
+        }
+
+        let marker = state.marker_list.create(line_start, true);
+        self.pending_indent_trim = Some(PendingIndentTrim {
+            buffer_id,
+            line_start: marker,
+            indent: line,
+        });
+    }
+}
+
+/// Text of the line starting at `line_start`, without its line ending.
+fn line_content(
+    state: &mut crate::state::EditorState,
+    line_start: usize,
+    estimated_line_length: usize,
+) -> String {
+    let mut iter = state
+        .buffer
+        .line_iterator(line_start, estimated_line_length);
+    let Some((_, line)) = iter.next_line() else {
+        return String::new();
+    };
+    line.trim_end_matches(['\n', '\r']).to_string()
+}
@@ -158,6 +158,8 @@ impl Editor {
             let highlight_current_line = self.config.editor.highlight_current_line;
             let line_wrap = self.resolve_line_wrap_for_buffer(buffer_id);
             let wrap_indent = self.config.editor.wrap_indent;
+            let wrap_mode = self.config.editor.wrap_mode;
+            let wrap_indent_extra = self.config.editor.wrap_indent_extra;
             let wrap_column = self.resolve_wrap_column_for_buffer(buffer_id);
             let rulers = self.config.editor.rulers.clone();
             if let Some(view_state) = self.split_view_states.get_mut(&dock_leaf) {
@@ -167,6 +169,8 @@ impl Editor {
                     highlight_current_line,
                     line_wrap,
                     wrap_indent,
+                    wrap_mode,
+                    wrap_indent_extra,
                     wrap_column,
                     rulers,
                 );
@@ -192,6 +196,8 @@ impl Editor {
                         self.config.editor.highlight_current_line,
                         self.resolve_line_wrap_for_buffer(buffer_id),
                         self.config.editor.wrap_indent,
+                        self.config.editor.wrap_mode,
+                        self.config.editor.wrap_indent_extra,
                         self.resolve_wrap_column_for_buffer(buffer_id),
                         self.config.editor.rulers.clone(),
                     );
@@ -396,6 +402,10 @@ impl Editor {
             modifiers
         );
 
+        if self.event_stream.is_some() {
+            self.log_keystroke(&format!("{:?}", code), &format!("{:?}", modifiers));
+        }
+
         // Create key event for dispatch methods
         let key_event = crossterm::event::KeyEvent::new(code, modifiers);
 
@@ -464,7 +474,7 @@ impl Editor {
             // Check if the current popup is transient (hover, signature help).
             // Editor-level popups always take precedence over buffer popups
             // when both are visible — they're effectively modal overlays.
-            let (is_transient_popup, has_selection) = {
+            let (is_transient_popup, has_selection, is_signature_help) = {
                 let popup = self
                     .global_popups
                     .top()
@@ -472,6 +482,9 @@ impl Editor {
                 (
                     popup.is_some_and(|p| p.transient),
                     popup.is_some_and(|p| p.has_selection()),
+                    popup.is_some_and(|p| {
+                        matches!(p.resolver, crate::view::popup::PopupResolver::SignatureHelp)
+                    }),
                 )
             };
 
@@ -495,7 +508,23 @@ impl Editor {
                 Some(crate::input::keybindings::Action::PopupFocus)
             );
 
-            if is_transient_popup && !(has_selection && is_copy_key) && !is_focus_popup_key {
+            // Typing (or erasing) the call's arguments keeps signature help
+            // open; the edit handlers refresh or close it.
+            let edits_call_arguments = is_signature_help
+                && match key_event.code {
+                    crossterm::event::KeyCode::Char(_) => {
+                        key_event.modifiers.is_empty()
+                            || key_event.modifiers == crossterm::event::KeyModifiers::SHIFT
+                    }
+                    crossterm::event::KeyCode::Backspace => key_event.modifiers.is_empty(),
+                    _ => false,
+                };
+
+            if is_transient_popup
+                && !(has_selection && is_copy_key)
+                && !is_focus_popup_key
+                && !edits_call_arguments
+            {
                 // Dismiss the popup on any key press (except Ctrl+C with selection)
                 self.hide_popup();
                 tracing::debug!("Dismissed transient popup on key press");
@@ -696,6 +725,13 @@ impl Editor {
         // Record action to macro if recording
         self.record_macro_action(&action);
 
+        if self.event_stream.is_some() {
+            match serde_json::to_value(&action) {
+                Ok(action) => self.record_to_event_stream(RecordKind::Action { action }),
+                Err(e) => tracing::trace!("Cannot record action to event stream: {e}"),
+            }
+        }
+
         // Reset dabbrev cycling session on any non-dabbrev action.
         if !matches!(action, Action::DabbrevExpand) {
             self.reset_dabbrev_state();
@@ -792,6 +828,8 @@ impl Editor {
                 // - Properly closing the buffer
                 self.close_tab();
             }
+            Action::CloseOtherTabs => self.close_other_tabs(),
+            Action::CloseTabsToRight => self.close_tabs_to_right(),
             Action::Revert => {
                 // Check if buffer has unsaved changes - prompt for confirmation
                 if self.active_state().buffer.is_modified() {
@@ -838,6 +876,22 @@ impl Editor {
                     );
                 }
             },
+            Action::Retab => match self.retab_buffer() {
+                Ok(true) => {
+                    let msg = if self.active_state().buffer_settings.use_tabs {
+                        t!("whitespace.retabbed_tabs")
+                    } else {
+                        t!("whitespace.retabbed_spaces")
+                    };
+                    self.set_status_message(msg.to_string());
+                }
+                Ok(false) => {
+                    self.set_status_message(t!("whitespace.no_retab").to_string());
+                }
+                Err(e) => {
+                    self.set_status_message(t!("error.retab_failed", error = e).to_string());
+                }
+            },
             Action::EnsureFinalNewline => match self.ensure_final_newline() {
                 Ok(true) => {
                     self.set_status_message(t!("whitespace.newline_added").to_string());
@@ -851,6 +905,27 @@ impl Editor {
                     );
                 }
             },
+            Action::NormalizeLineEndings => {
+                let value = self.active_state().buffer.line_ending().display_name();
+                match self.normalize_line_endings() {
+                    Ok(true) => {
+                        self.set_status_message(
+                            t!("settings.line_endings_normalized", value = value).to_string(),
+                        );
+                    }
+                    Ok(false) => {
+                        self.set_status_message(
+                            t!("settings.line_endings_already_normalized", value = value)
+                                .to_string(),
+                        );
+                    }
+                    Err(e) => {
+                        self.set_status_message(
+                            t!("error.normalize_line_endings_failed", error = e).to_string(),
+                        );
+                    }
+                }
+            }
             Action::Copy => {
                 // Editor-level popups take precedence over everything, including the file explorer.
                 let popup = self
@@ -904,6 +979,60 @@ impl Editor {
                 }
                 self.paste()
             }
+            Action::CopyToRegister(key) => {
+                if super::registers::RegisterState::is_named_key(key) {
+                    self.copy_selection_to_register(key);
+                } else {
+                    self.set_status_message(t!("register.read_only", key = key).to_string());
+                }
+            }
+            Action::CutToRegister(key) => {
+                if self.is_editing_disabled() {
+                    self.set_status_message(t!("buffer.editing_disabled").to_string());
+                    return Ok(());
+                }
+                if super::registers::RegisterState::is_named_key(key) {
+                    self.cut_selection_to_register(key);
+                } else {
+                    self.set_status_message(t!("register.read_only", key = key).to_string());
+                }
+            }
+            Action::PasteFromRegister(key) => {
+                if self.is_editing_disabled() {
+                    self.set_status_message(t!("buffer.editing_disabled").to_string());
+                    return Ok(());
+                }
+                self.paste_from_register(key);
+            }
+            Action::CyclePasteForward => {
+                if self.is_editing_disabled() {
+                    self.set_status_message(t!("buffer.editing_disabled").to_string());
+                    return Ok(());
+                }
+                self.cycle_paste(true);
+            }
+            Action::CyclePasteBackward => {
+                if self.is_editing_disabled() {
+                    self.set_status_message(t!("buffer.editing_disabled").to_string());
+                    return Ok(());
+                }
+                self.cycle_paste(false);
+            }
+            Action::PromptCopyToRegister => {
+                self.start_prompt(
+                    t!("register.copy_prompt").to_string(),
+                    PromptType::CopyToRegister,
+                );
+            }
+            Action::PromptCutToRegister => {
+                self.start_prompt(
+                    t!("register.cut_prompt").to_string(),
+                    PromptType::CutToRegister,
+                );
+            }
+            Action::PromptPasteFromRegister => {
+                self.start_paste_from_register_prompt();
+            }
             Action::YankWordForward => self.yank_word_forward(),
             Action::YankWordBackward => self.yank_word_backward(),
             Action::YankToLineEnd => self.yank_to_line_end(),
@@ -947,6 +1076,9 @@ impl Editor {
                 }
                 self.start_quick_open();
             }
+            Action::RepeatLastCommand => {
+                return self.repeat_last_command();
+            }
             Action::QuickOpen => {
                 // Toggle Quick Open: close if already open, otherwise open it
                 if let Some(prompt) = &self.prompt {
@@ -1221,6 +1353,8 @@ impl Editor {
                                 self.config.editor.highlight_current_line,
                                 self.resolve_line_wrap_for_buffer(buffer_id),
                                 self.config.editor.wrap_indent,
+                                self.config.editor.wrap_mode,
+                                self.config.editor.wrap_indent_extra,
                                 self.resolve_wrap_column_for_buffer(buffer_id),
                                 self.config.editor.rulers.clone(),
                             );
@@ -1274,6 +1408,12 @@ impl Editor {
                     }
                 }
             }
+            Action::CursorToTop => {
+                self.place_cursor_in_view(crate::view::viewport::CursorPlacement::Top);
+            }
+            Action::CursorToBottom => {
+                self.place_cursor_in_view(crate::view::viewport::CursorPlacement::Bottom);
+            }
             Action::ToggleLineWrap => {
                 let new_value = !self.config.editor.line_wrap;
                 self.config_mut().editor.line_wrap = new_value;
@@ -1291,6 +1431,9 @@ impl Editor {
                     if let Some(view_state) = self.split_view_states.get_mut(&leaf_id) {
                         view_state.viewport.line_wrap_enabled = effective_wrap;
                         view_state.viewport.wrap_indent = self.config.editor.wrap_indent;
+                        view_state.viewport.wrap_mode = self.config.editor.wrap_mode;
+                        view_state.viewport.wrap_indent_extra =
+                            self.config.editor.wrap_indent_extra;
                         view_state.viewport.wrap_column = wrap_column;
                     }
                 }
@@ -1356,6 +1499,26 @@ impl Editor {
                     current,
                 );
             }
+            Action::SetComposeGuides => {
+                let active_split = self.split_manager.active_split();
+                let current = self
+                    .split_view_states
+                    .get(&active_split)
+                    .and_then(|v| v.compose_column_guides.as_ref())
+                    .map(|guides| {
+                        guides
+                            .iter()
+                            .map(|col| col.to_string())
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    })
+                    .unwrap_or_default();
+                self.start_prompt_with_initial_text(
+                    "Compose guides (comma-separated columns, empty = none): ".to_string(),
+                    PromptType::SetComposeGuides,
+                    current,
+                );
+            }
             Action::SetBackground => {
                 let default_path = self
                     .ansi_background_path
@@ -1405,6 +1568,15 @@ impl Editor {
             Action::LspCodeActions => {
                 self.request_code_actions()?;
             }
+            Action::LspFormat => {
+                self.request_formatting(false);
+            }
+            Action::LspDocumentSymbols => {
+                self.request_document_symbols();
+            }
+            Action::LspWorkspaceSymbols => {
+                self.start_workspace_symbols_prompt();
+            }
             Action::LspRestart => {
                 self.handle_lsp_restart();
             }
@@ -1496,8 +1668,11 @@ impl Editor {
                 self.find_selection_previous();
             }
             Action::AddCursorNextMatch => self.add_cursor_at_next_match(),
+            Action::SkipLastSelectionMatch => self.skip_last_selection_match(),
+            Action::SelectAllMatches => self.select_all_matches(),
             Action::AddCursorAbove => self.add_cursor_above(),
             Action::AddCursorBelow => self.add_cursor_below(),
+            Action::RotatePrimaryCursor => self.rotate_primary_cursor(),
             Action::NextBuffer => self.next_buffer(),
             Action::PrevBuffer => self.prev_buffer(),
             Action::SwitchToPreviousTab => self.switch_to_previous_tab(),
@@ -1528,6 +1703,10 @@ impl Editor {
             Action::IncreaseSplitSize => self.adjust_split_size(0.05),
             Action::DecreaseSplitSize => self.adjust_split_size(-0.05),
             Action::ToggleMaximizeSplit => self.toggle_maximize_split(),
+            Action::UnzoomSplit => self.unzoom_split(),
+            Action::EqualizeSplits => self.equalize_splits(),
+            Action::MoveBufferToNextSplit => self.move_buffer_to_next_split(),
+            Action::SwapSplits => self.swap_splits(),
             Action::ToggleFileExplorer => self.toggle_file_explorer(),
             Action::ToggleMenuBar => self.toggle_menu_bar(),
             Action::ToggleTabBar => self.toggle_tab_bar(),
@@ -1583,6 +1762,7 @@ impl Editor {
                     self.set_status_message(status.to_string());
                 }
             }
+            Action::ShowIndentation => self.show_indentation(),
             Action::ToggleTabIndicators | Action::ToggleWhitespaceIndicators => {
                 if let Some(state) = self.buffers.get_mut(&self.active_buffer()) {
                     state.buffer_settings.whitespace.toggle_all();
@@ -1596,6 +1776,7 @@ impl Editor {
             }
             Action::ResetBufferSettings => self.reset_buffer_settings(),
             Action::FocusFileExplorer => self.focus_file_explorer(),
+            Action::RevealInExplorer => self.reveal_in_explorer(),
             Action::FocusEditor => self.focus_editor(),
             Action::FileExplorerUp => self.file_explorer_navigate_up(),
             Action::FileExplorerDown => self.file_explorer_navigate_down(),
@@ -1620,13 +1801,18 @@ impl Editor {
             Action::FileExplorerExtendSelectionDown => self.file_explorer_extend_selection_down(),
             Action::FileExplorerToggleSelect => self.file_explorer_toggle_select(),
             Action::FileExplorerSelectAll => self.file_explorer_select_all(),
-            Action::RemoveSecondaryCursors => {
+            Action::RemoveSecondaryCursors | Action::CollapseToSinglePrimary => {
+                let description = if action == Action::RemoveSecondaryCursors {
+                    "Remove secondary cursors"
+                } else {
+                    "Collapse to primary cursor"
+                };
                 // Convert action to events and apply them
-                if let Some(events) = self.action_to_events(Action::RemoveSecondaryCursors) {
+                if let Some(events) = self.action_to_events(action) {
                     // Wrap in batch for atomic undo
                     let batch = Event::Batch {
                         events: events.clone(),
-                        description: "Remove secondary cursors".to_string(),
+                        description: description.to_string(),
                     };
                     self.active_event_log_mut().append(batch.clone());
                     self.apply_event_to_active_buffer(&batch);
@@ -1734,6 +1920,15 @@ impl Editor {
             Action::ListBookmarks => {
                 self.list_bookmarks();
             }
+            Action::ToggleBookmark => {
+                self.toggle_bookmark();
+            }
+            Action::NextBookmark => {
+                self.next_bookmark();
+            }
+            Action::PrevBookmark => {
+                self.prev_bookmark();
+            }
             Action::ToggleSearchCaseSensitive => {
                 self.search_case_sensitive = !self.search_case_sensitive;
                 let state = if self.search_case_sensitive {
@@ -1755,6 +1950,7 @@ impl Editor {
                     ) {
                         let query = prompt.input.clone();
                         self.update_search_highlights(&query);
+                        self.preview_search_match(&query);
                     }
                 } else if let Some(search_state) = &self.search_state {
                     let query = search_state.query.clone();
@@ -1780,6 +1976,7 @@ impl Editor {
                     ) {
                         let query = prompt.input.clone();
                         self.update_search_highlights(&query);
+                        self.preview_search_match(&query);
                     }
                 } else if let Some(search_state) = &self.search_state {
                     let query = search_state.query.clone();
@@ -1805,6 +2002,7 @@ impl Editor {
                     ) {
                         let query = prompt.input.clone();
                         self.update_search_highlights(&query);
+                        self.preview_search_match(&query);
                     }
                 } else if let Some(search_state) = &self.search_state {
                     let query = search_state.query.clone();
@@ -1897,6 +2095,7 @@ impl Editor {
                         }
                     }
                 }
+                self.refresh_signature_help();
             }
             Action::PluginAction(action_name) => {
                 tracing::debug!("handle_action: PluginAction('{}')", action_name);
@@ -2098,6 +2297,21 @@ impl Editor {
                 // Run shell command on buffer/selection, replace content
                 self.start_shell_command_prompt(true);
             }
+            Action::RunCustomCommand(name) => {
+                self.run_custom_command(&name);
+            }
+            Action::RunCurrentFile => {
+                self.run_current_file();
+            }
+            Action::Surround(delimiter) => {
+                self.surround_selection(&delimiter);
+            }
+            Action::AlignCursors(delimiter) => {
+                self.align_cursors(&delimiter);
+            }
+            Action::PromptAlignCursors => {
+                self.start_prompt(t!("align.prompt").to_string(), PromptType::AlignCursors);
+            }
             Action::OpenSettings => {
                 self.open_settings();
             }
@@ -2159,6 +2373,21 @@ impl Editor {
             Action::EventDebug => {
                 self.open_event_debug();
             }
+            Action::ExportEventLog => {
+                self.start_prompt_with_initial_text(
+                    "Export event log to: ".to_string(),
+                    PromptType::ExportEventLog,
+                    "fresh-event-log.jsonl".to_string(),
+                );
+            }
+            Action::UndoHistoryStats => {
+                let log = self.active_event_log();
+                let count = log.undo_group_count();
+                let size = super::file_open::format_size(log.memory_usage() as u64);
+                self.set_status_message(
+                    t!("status.undo_history_stats", count = count, size = size).to_string(),
+                );
+            }
             Action::SuspendProcess => {
                 self.request_suspend();
             }
@@ -2172,6 +2401,9 @@ impl Editor {
                         PromptResult::ExecuteAction(action) => {
                             return self.handle_action(action);
                         }
+                        PromptResult::RunCommand(action, args) => {
+                            return self.run_command_with_args(action, args);
+                        }
                         PromptResult::EarlyReturn => {
                             return Ok(());
                         }
@@ -2191,6 +2423,9 @@ impl Editor {
                         PromptResult::ExecuteAction(action) => {
                             return self.handle_action(action);
                         }
+                        PromptResult::RunCommand(action, args) => {
+                            return self.run_command_with_args(action, args);
+                        }
                         PromptResult::EarlyReturn => {
                             return Ok(());
                         }
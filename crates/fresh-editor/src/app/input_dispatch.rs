@@ -162,6 +162,20 @@ impl Editor {
                 return Some(result);
             }
 
+            // Delete with nothing after the cursor removes the selected
+            // entry from the bookmark list
+            let deletes_bookmark = event.code == crossterm::event::KeyCode::Delete
+                && event.modifiers.is_empty()
+                && self.prompt.as_ref().is_some_and(|p| {
+                    p.prompt_type == PromptType::ListBookmarks
+                        && p.selection_anchor.is_none()
+                        && p.cursor_pos == p.input.len()
+                });
+            if deletes_bookmark {
+                self.delete_selected_bookmark();
+                return Some(InputResult::Consumed);
+            }
+
             if let Some(ref mut prompt) = self.prompt {
                 let result = prompt.dispatch_input(event, &mut ctx);
                 // Only return and process deferred actions if the prompt handled the input
@@ -212,6 +226,8 @@ impl Editor {
                     .popups
                     .dispatch_input(event, &mut ctx);
                 self.process_deferred_actions(ctx);
+                // Navigation may have moved the completion selection
+                self.update_completion_preview();
                 // If the popup handler returned Ignored (e.g., non-word
                 // character, Ctrl+key, arrow keys), fall through to normal
                 // input handling. The deferred ClosePopup action was already
@@ -110,6 +110,7 @@ impl Editor {
                     disabled: false,
                     keybinding: None,
                     source: None,
+                    match_positions: Vec::new(),
                 }
             })
             .collect();
@@ -213,9 +214,12 @@ impl Editor {
             }
         }
 
-        // Auto-trigger signature help on '(' and ','
+        // Auto-trigger signature help on '(' and ',', and keep an open
+        // one up to date while the arguments are typed
         if c == '(' || c == ',' {
             self.request_signature_help();
+        } else {
+            self.refresh_signature_help();
         }
 
         // Auto-trigger completion on trigger characters
@@ -240,6 +244,7 @@ impl Editor {
 
         // Get description before moving action
         let action_description = format!("{:?}", action);
+        let inserted_newline = matches!(action, Action::InsertNewline);
 
         // Check if this is an editing action and editing is disabled
         let is_editing_action = matches!(
@@ -251,6 +256,14 @@ impl Editor {
                 | Action::DeleteWordForward
                 | Action::DeleteLine
                 | Action::DuplicateLine
+                | Action::TransposeChars
+                | Action::TransposeWords
+                | Action::Surround(_)
+                | Action::AlignCursors(_)
+                | Action::IncrementNumber
+                | Action::DecrementNumber
+                | Action::DuplicateSelectionDown
+                | Action::DuplicateSelectionUp
                 | Action::MoveLineUp
                 | Action::MoveLineDown
                 | Action::DedentSelection
@@ -299,6 +312,8 @@ impl Editor {
             }
         }
 
+        self.update_pending_indent_trim(inserted_newline);
+
         Ok(())
     }
 
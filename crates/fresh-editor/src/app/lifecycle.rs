@@ -77,7 +77,13 @@ impl Editor {
     }
 
     /// Take pending escape sequences, clearing the queue
+    ///
+    /// Includes the OSC 52 sequence of a copy made since the last call, so
+    /// the client's terminal receives it with the next frame.
     pub fn take_pending_escape_sequences(&mut self) -> Vec<u8> {
+        if let Some(osc52) = self.clipboard.take_pending_osc52() {
+            self.pending_escape_sequences.extend_from_slice(&osc52);
+        }
         std::mem::take(&mut self.pending_escape_sequences)
     }
 
@@ -212,7 +218,8 @@ impl Editor {
     /// automatically recovered across sessions), but file-backed modified
     /// buffers still trigger a prompt with a "recoverable" option.
     /// When `auto_save_enabled` is true, file-backed buffers are excluded
-    /// (they will be saved to disk on exit).
+    /// (they will be saved to disk on exit). Plugin scratch buffers are
+    /// always excluded.
     fn count_modified_buffers_needing_prompt(&self) -> usize {
         let hot_exit = self.config.editor.hot_exit;
         let auto_save = self.config.editor.auto_save_enabled;
@@ -224,6 +231,9 @@ impl Editor {
                     return false;
                 }
                 if let Some(meta) = self.buffer_metadata.get(buffer_id) {
+                    if meta.scratch {
+                        return false; // plugin scratch buffer, never saved
+                    }
                     if let Some(path) = meta.file_path() {
                         let is_unnamed = path.as_os_str().is_empty();
                         if is_unnamed && hot_exit {
@@ -239,6 +249,21 @@ impl Editor {
             .count()
     }
 
+    /// Count modified buffers whose changes would be lost if the process
+    /// were killed now. Plugin scratch buffers are excluded.
+    pub fn unsaved_buffer_count(&self) -> usize {
+        self.buffers
+            .iter()
+            .filter(|(buffer_id, state)| {
+                state.buffer.is_modified()
+                    && !self
+                        .buffer_metadata
+                        .get(buffer_id)
+                        .is_some_and(|meta| meta.scratch)
+            })
+            .count()
+    }
+
     /// Handle terminal focus gained event
     pub fn focus_gained(&mut self) {
         self.plugin_manager.run_hook(
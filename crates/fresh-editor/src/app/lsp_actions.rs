@@ -76,6 +76,7 @@ impl Editor {
             disabled: enabled_names.is_empty(),
             keybinding: None,
             source: None,
+            match_positions: Vec::new(),
         });
 
         // Individual server options
@@ -92,6 +93,7 @@ impl Editor {
                 disabled: false,
                 keybinding: None,
                 source: None,
+                match_positions: Vec::new(),
             });
         }
 
@@ -269,6 +271,7 @@ impl Editor {
                         disabled: false,
                         keybinding: None,
                         source: None,
+                        match_positions: Vec::new(),
                     });
                 }
             } else {
@@ -287,6 +290,7 @@ impl Editor {
                     disabled: false,
                     keybinding: None,
                     source: None,
+                    match_positions: Vec::new(),
                 });
             }
         }
@@ -696,7 +700,7 @@ impl Editor {
         } else {
             // --- Indent-based folding on bytes ---
             use crate::view::folding::indent_folding;
-            let tab_size = state.buffer_settings.tab_size;
+            let indent_size = state.buffer_settings.indent_size();
             let max_upward = crate::config::INDENT_FOLD_MAX_UPWARD_SCAN;
             let est_ll = state.buffer.estimated_line_length();
             let max_scan_bytes = crate::config::INDENT_FOLD_MAX_SCAN_LINES * est_ll;
@@ -719,7 +723,7 @@ impl Editor {
             if let Some((hb, sb, eb)) = indent_folding::find_fold_range_at_byte(
                 &state.buffer,
                 byte_pos,
-                tab_size,
+                indent_size,
                 max_scan_bytes,
                 max_upward,
             ) {
@@ -969,12 +973,8 @@ impl Editor {
             }
         }
 
-        // Cancel scheduled inlay hints refresh if it targets this buffer
-        if let Some((scheduled_buf, _)) = &self.scheduled_inlay_hints_request {
-            if *scheduled_buf == buffer_id {
-                self.scheduled_inlay_hints_request = None;
-            }
-        }
+        // Cancel this buffer's scheduled inlay hints refresh
+        self.scheduled_inlay_hints_requests.remove(&buffer_id);
 
         self.folding_ranges_in_flight.remove(&buffer_id);
         self.folding_ranges_debounce.remove(&buffer_id);
@@ -984,6 +984,7 @@ impl Editor {
         // their eventual responses don't repopulate the cleared overlay.
         self.pending_inlay_hints_requests
             .retain(|_, req| req.buffer_id != buffer_id);
+        self.inlay_hints_coverage.remove(&buffer_id);
 
         // Clear all LSP-related overlays for this buffer (diagnostics + inlay hints)
         let diagnostic_ns = crate::services::lsp::diagnostics::lsp_diagnostic_namespace();
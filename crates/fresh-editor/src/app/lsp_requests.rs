@@ -7,6 +7,8 @@
 //! - Find references
 //! - Signature help
 //! - Code actions
+//! - Document symbols
+//! - Workspace symbol search
 //! - Rename operations
 //! - Inlay hints
 
@@ -18,7 +20,9 @@ use std::time::{Duration, Instant};
 use lsp_types::TextDocumentContentChangeEvent;
 
 use crate::model::event::{BufferId, Event};
-use crate::primitives::word_navigation::{find_word_end, find_word_start};
+use crate::primitives::word_navigation::{
+    find_completion_word_start, find_word_end, find_word_start,
+};
 use crate::view::prompt::{Prompt, PromptType};
 
 use crate::services::lsp::async_handler::LspHandle;
@@ -36,6 +40,32 @@ fn space_doc_paragraphs(text: &str) -> String {
     text.replace("\n\n", "\x00").replace(['\n', '\x00'], "\n\n")
 }
 
+/// Key under which completion items from different servers count as the same
+/// suggestion: the label together with the text it inserts.
+fn completion_dedup_key(item: &lsp_types::CompletionItem) -> (String, Option<String>) {
+    let insert = item
+        .text_edit
+        .as_ref()
+        .map(|edit| match edit {
+            lsp_types::CompletionTextEdit::Edit(edit) => edit.new_text.clone(),
+            lsp_types::CompletionTextEdit::InsertAndReplace(edit) => edit.new_text.clone(),
+        })
+        .or_else(|| item.insert_text.clone());
+    (item.label.clone(), insert)
+}
+
+/// Key under which code actions from different servers count as the same
+/// action: the title together with the action kind.
+fn code_action_dedup_key(action: &lsp_types::CodeActionOrCommand) -> (String, Option<String>) {
+    match action {
+        lsp_types::CodeActionOrCommand::Command(cmd) => (cmd.title.clone(), None),
+        lsp_types::CodeActionOrCommand::CodeAction(ca) => (
+            ca.title.clone(),
+            ca.kind.as_ref().map(|k| k.as_str().to_string()),
+        ),
+    }
+}
+
 /// Whether an LSP range (half-open end, like `[start, end)`) contains the given
 /// `(line, character)` LSP position. Zero-length ranges (start == end) are
 /// treated as containing their single anchor point so point-style diagnostics
@@ -58,6 +88,248 @@ fn lsp_range_contains(range: &lsp_types::Range, line: u32, character: u32) -> bo
     true
 }
 
+/// Byte range of a signature-help parameter within its signature's label.
+/// Simple labels are looked up after the opening parenthesis so a parameter
+/// named like the function doesn't match the function name.
+fn signature_parameter_range(
+    signature_label: &str,
+    parameter: &lsp_types::ParameterInformation,
+) -> Option<std::ops::Range<usize>> {
+    let range = match &parameter.label {
+        lsp_types::ParameterLabel::Simple(name) => {
+            let from = signature_label.find('(').map_or(0, |i| i + 1);
+            let start = from + signature_label[from..].find(name.as_str())?;
+            start..start + name.len()
+        }
+        lsp_types::ParameterLabel::LabelOffsets([start, end]) => *start as usize..*end as usize,
+    };
+    (range.start < range.end && signature_label.get(range.clone()).is_some()).then_some(range)
+}
+
+/// Whether a diagnostic belongs in the context of a code-action request for
+/// `requested`. With a bare cursor (empty range) the diagnostic must contain
+/// the cursor; with a selection, any overlap counts.
+fn diagnostic_in_code_action_range(
+    diagnostic: &lsp_types::Range,
+    requested: &lsp_types::Range,
+) -> bool {
+    if requested.start == requested.end {
+        return lsp_range_contains(diagnostic, requested.start.line, requested.start.character);
+    }
+    let key = |p: lsp_types::Position| (p.line, p.character);
+    key(diagnostic.start) < key(requested.end) && key(requested.start) < key(diagnostic.end)
+}
+
+/// Whether any two edits in a `TextEdit[]` response overlap. The LSP spec
+/// forbids overlapping ranges; applying them anyway would corrupt the buffer.
+/// Edits that merely touch (one ends where the next starts) are fine.
+fn text_edits_overlap(edits: &[lsp_types::TextEdit]) -> bool {
+    let key = |p: lsp_types::Position| (p.line, p.character);
+    let mut ranges: Vec<_> = edits
+        .iter()
+        .map(|e| (key(e.range.start), key(e.range.end)))
+        .collect();
+    ranges.sort();
+    ranges.windows(2).any(|w| w[0].1 > w[1].0)
+}
+
+/// Text edits of a workspace edit grouped by document, in response order
+/// (`changes` entries sorted by URI, since that map has no order). Resource
+/// operations (create/rename/delete file) are not included.
+fn workspace_edit_documents(
+    workspace_edit: &lsp_types::WorkspaceEdit,
+) -> Vec<(lsp_types::Uri, Vec<lsp_types::TextEdit>)> {
+    let text_document_edit = |edit: &lsp_types::TextDocumentEdit| {
+        let edits = edit
+            .edits
+            .iter()
+            .map(|one_of| match one_of {
+                lsp_types::OneOf::Left(text_edit) => text_edit.clone(),
+                lsp_types::OneOf::Right(annotated) => annotated.text_edit.clone(),
+            })
+            .collect();
+        (edit.text_document.uri.clone(), edits)
+    };
+
+    let mut documents: Vec<(lsp_types::Uri, Vec<lsp_types::TextEdit>)> = Vec::new();
+    if let Some(changes) = &workspace_edit.changes {
+        let mut changes: Vec<_> = changes.iter().collect();
+        changes.sort_by(|(a, _), (b, _)| a.as_str().cmp(b.as_str()));
+        documents.extend(
+            changes
+                .into_iter()
+                .map(|(uri, edits)| (uri.clone(), edits.clone())),
+        );
+    }
+    match &workspace_edit.document_changes {
+        Some(lsp_types::DocumentChanges::Edits(edits)) => {
+            documents.extend(edits.iter().map(text_document_edit));
+        }
+        Some(lsp_types::DocumentChanges::Operations(ops)) => {
+            documents.extend(ops.iter().filter_map(|op| match op {
+                lsp_types::DocumentChangeOperation::Edit(edit) => Some(text_document_edit(edit)),
+                lsp_types::DocumentChangeOperation::Op(_) => None,
+            }));
+        }
+        None => {}
+    }
+    documents
+}
+
+/// Whether a workspace edit creates, renames or deletes files.
+fn workspace_edit_has_resource_ops(workspace_edit: &lsp_types::WorkspaceEdit) -> bool {
+    matches!(
+        &workspace_edit.document_changes,
+        Some(lsp_types::DocumentChanges::Operations(ops))
+            if ops.iter().any(|op| matches!(op, lsp_types::DocumentChangeOperation::Op(_)))
+    )
+}
+
+/// Whether a workspace edit reaches beyond a single file: it edits more than
+/// one document or creates, renames or deletes files.
+fn workspace_edit_spans_files(workspace_edit: &lsp_types::WorkspaceEdit) -> bool {
+    let documents: std::collections::HashSet<String> = workspace_edit_documents(workspace_edit)
+        .iter()
+        .map(|(uri, _)| uri.as_str().to_string())
+        .collect();
+    workspace_edit_has_resource_ops(workspace_edit) || documents.len() > 1
+}
+
+/// One row of the document-symbols outline popup.
+#[derive(Debug, Clone, PartialEq)]
+struct DocumentSymbolEntry {
+    /// Nesting depth (0 for top-level symbols and for the flat form)
+    depth: usize,
+    name: String,
+    kind: lsp_types::SymbolKind,
+    /// Position the cursor jumps to when the entry is selected
+    position: lsp_types::Position,
+}
+
+/// Flatten a `textDocument/documentSymbol` response into outline rows.
+///
+/// The hierarchical `DocumentSymbol[]` form is walked depth-first so children
+/// follow their parent. The flat `SymbolInformation[]` form carries no
+/// nesting, so it is sorted by position and listed at depth 0.
+fn document_symbol_entries(
+    response: lsp_types::DocumentSymbolResponse,
+) -> Vec<DocumentSymbolEntry> {
+    fn walk(
+        symbols: Vec<lsp_types::DocumentSymbol>,
+        depth: usize,
+        out: &mut Vec<DocumentSymbolEntry>,
+    ) {
+        for symbol in symbols {
+            out.push(DocumentSymbolEntry {
+                depth,
+                name: symbol.name,
+                kind: symbol.kind,
+                position: symbol.selection_range.start,
+            });
+            if let Some(children) = symbol.children {
+                walk(children, depth + 1, out);
+            }
+        }
+    }
+
+    let mut entries = Vec::new();
+    match response {
+        lsp_types::DocumentSymbolResponse::Nested(symbols) => walk(symbols, 0, &mut entries),
+        lsp_types::DocumentSymbolResponse::Flat(symbols) => {
+            entries.extend(symbols.into_iter().map(|symbol| DocumentSymbolEntry {
+                depth: 0,
+                name: symbol.name,
+                kind: symbol.kind,
+                position: symbol.location.range.start,
+            }));
+            entries.sort_by_key(|e| (e.position.line, e.position.character));
+        }
+    }
+    entries
+}
+
+/// One result of a `workspace/symbol` search.
+#[derive(Debug, Clone, PartialEq)]
+struct WorkspaceSymbolEntry {
+    name: String,
+    kind: lsp_types::SymbolKind,
+    uri: lsp_types::Uri,
+    /// Position the cursor jumps to when the entry is selected
+    position: lsp_types::Position,
+}
+
+/// Flatten a `workspace/symbol` response into prompt rows.
+///
+/// `WorkspaceSymbol` results may carry only a URI (the server expects a
+/// `workspaceSymbol/resolve` round-trip for the range); those jump to the
+/// start of the file.
+fn workspace_symbol_entries(
+    response: lsp_types::WorkspaceSymbolResponse,
+) -> Vec<WorkspaceSymbolEntry> {
+    match response {
+        lsp_types::WorkspaceSymbolResponse::Flat(symbols) => symbols
+            .into_iter()
+            .map(|symbol| WorkspaceSymbolEntry {
+                name: symbol.name,
+                kind: symbol.kind,
+                uri: symbol.location.uri,
+                position: symbol.location.range.start,
+            })
+            .collect(),
+        lsp_types::WorkspaceSymbolResponse::Nested(symbols) => symbols
+            .into_iter()
+            .map(|symbol| {
+                let (uri, position) = match symbol.location {
+                    lsp_types::OneOf::Left(location) => (location.uri, location.range.start),
+                    lsp_types::OneOf::Right(location) => {
+                        (location.uri, lsp_types::Position::default())
+                    }
+                };
+                WorkspaceSymbolEntry {
+                    name: symbol.name,
+                    kind: symbol.kind,
+                    uri,
+                    position,
+                }
+            })
+            .collect(),
+    }
+}
+
+/// Short lowercase label for a symbol kind, shown next to the symbol name.
+fn symbol_kind_label(kind: lsp_types::SymbolKind) -> &'static str {
+    use lsp_types::SymbolKind;
+    match kind {
+        SymbolKind::FILE => "file",
+        SymbolKind::MODULE => "module",
+        SymbolKind::NAMESPACE => "namespace",
+        SymbolKind::PACKAGE => "package",
+        SymbolKind::CLASS => "class",
+        SymbolKind::METHOD => "method",
+        SymbolKind::PROPERTY => "property",
+        SymbolKind::FIELD => "field",
+        SymbolKind::CONSTRUCTOR => "constructor",
+        SymbolKind::ENUM => "enum",
+        SymbolKind::INTERFACE => "interface",
+        SymbolKind::FUNCTION => "function",
+        SymbolKind::VARIABLE => "variable",
+        SymbolKind::CONSTANT => "constant",
+        SymbolKind::STRING => "string",
+        SymbolKind::NUMBER => "number",
+        SymbolKind::BOOLEAN => "boolean",
+        SymbolKind::ARRAY => "array",
+        SymbolKind::OBJECT => "object",
+        SymbolKind::KEY => "key",
+        SymbolKind::NULL => "null",
+        SymbolKind::ENUM_MEMBER => "enum member",
+        SymbolKind::STRUCT => "struct",
+        SymbolKind::EVENT => "event",
+        SymbolKind::OPERATOR => "operator",
+        SymbolKind::TYPE_PARAMETER => "type parameter",
+        _ => "symbol",
+    }
+}
+
 const SEMANTIC_TOKENS_FULL_DEBOUNCE_MS: u64 = 500;
 const SEMANTIC_TOKENS_RANGE_DEBOUNCE_MS: u64 = 50;
 const SEMANTIC_TOKENS_RANGE_PADDING_LINES: usize = 10;
@@ -67,11 +339,22 @@ const FOLDING_RANGES_DEBOUNCE_MS: u64 = 300;
 /// low while still refreshing hints after brief editing pauses (including
 /// saves, which naturally follow an edit).
 const INLAY_HINTS_DEBOUNCE_MS: u64 = 500;
+/// Debounce window after the viewport scrolls outside the hinted lines, so a
+/// held-down PageDown doesn't send a request per frame.
+const INLAY_HINTS_SCROLL_DEBOUNCE_MS: u64 = 100;
+/// Lines requested above and below the viewport, so small scrolls stay
+/// within the hinted region.
+const INLAY_HINTS_RANGE_PADDING_LINES: usize = 100;
+/// Pause after the last keystroke in the Workspace Symbols prompt before
+/// the query is sent, so servers don't search on every character.
+const WORKSPACE_SYMBOLS_DEBOUNCE_MS: u64 = 150;
 
 impl Editor {
     /// Handle LSP completion response.
     /// Supports merging from multiple servers: first response creates the menu,
-    /// subsequent responses extend it.
+    /// subsequent responses extend it. Each item is tagged with the server
+    /// that sent it, and an item another server already offered (same label
+    /// and insert text) is shown once.
     pub(crate) fn handle_completion_response(
         &mut self,
         request_id: u64,
@@ -85,6 +368,10 @@ impl Editor {
             );
             return Ok(());
         }
+        let server_name = self
+            .pending_completion_server_names
+            .remove(&request_id)
+            .unwrap_or_default();
 
         if items.is_empty() {
             tracing::debug!("No completion items received");
@@ -133,20 +420,39 @@ impl Editor {
         // Store/extend original items for type-to-filter (merge from multiple servers)
         match &mut self.completion_items {
             Some(existing) => {
-                existing.extend(items);
+                let seen: std::collections::HashSet<(String, Option<String>)> = existing
+                    .iter()
+                    .filter(|(srv_name, _)| *srv_name != server_name)
+                    .map(|(_, item)| completion_dedup_key(item))
+                    .collect();
+                existing.extend(
+                    items
+                        .into_iter()
+                        .filter(|item| !seen.contains(&completion_dedup_key(item)))
+                        .map(|item| (server_name.clone(), item)),
+                );
                 tracing::debug!("Extended completion items, now {} total", existing.len());
             }
             None => {
-                self.completion_items = Some(items);
+                self.completion_items = Some(
+                    items
+                        .into_iter()
+                        .map(|item| (server_name.clone(), item))
+                        .collect(),
+                );
+                // Resolves sent for an earlier popup no longer apply
+                self.pending_completion_preview = None;
             }
         }
 
         // Rebuild popup from ALL merged items (not just the new batch)
         let all_items = self.completion_items.as_ref().unwrap();
-        let all_filtered: Vec<&lsp_types::CompletionItem> = all_items
-            .iter()
-            .filter(|item| matches_prefix(item))
-            .collect();
+        let (item_ids, all_filtered): (Vec<usize>, Vec<&(String, lsp_types::CompletionItem)>) =
+            all_items
+                .iter()
+                .enumerate()
+                .filter(|(_, (_, item))| matches_prefix(item))
+                .unzip();
 
         if all_filtered.is_empty() {
             tracing::debug!("No completion items match prefix '{}'", prefix);
@@ -179,10 +485,11 @@ impl Editor {
             // Convert PopupData to Popup and use show_or_replace to avoid stacking
             let mut popup_obj = crate::state::convert_popup_data_to_popup(&popup_data);
             popup_obj.accept_key_hint = accept_hint;
-            popup_obj.resolver = crate::view::popup::PopupResolver::Completion;
+            popup_obj.resolver = crate::view::popup::PopupResolver::Completion { item_ids };
             popup_obj.focus_key_hint = focus_hint;
             state.popups.show_or_replace(popup_obj);
         }
+        self.update_completion_preview();
 
         tracing::info!(
             "Showing completion popup with {} items",
@@ -320,6 +627,7 @@ impl Editor {
                 self.send_lsp_cancel_request(request_id);
             }
         }
+        self.pending_completion_server_names.clear();
         if let Some(request_id) = self.pending_goto_definition_request.take() {
             tracing::debug!(
                 "Canceling pending LSP goto-definition request {}",
@@ -576,6 +884,7 @@ impl Editor {
                 self.send_lsp_cancel_request(request_id);
             }
         }
+        self.pending_completion_server_names.clear();
         self.completion_items = None;
 
         // Get the current buffer and cursor position
@@ -591,10 +900,10 @@ impl Editor {
         // Use an atomic counter in the closure
         let counter = std::sync::atomic::AtomicU64::new(0);
 
-        let results = self.with_all_lsp_for_buffer_feature(
+        let results = self.with_all_lsp_for_buffer_feature_named(
             buffer_id,
             LspFeature::Completion,
-            |handle, uri, _language| {
+            |handle, uri, _language, server_name| {
                 let idx = counter.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
                 let request_id = base_request_id + idx;
                 let result = handle.completion(
@@ -605,21 +914,24 @@ impl Editor {
                 );
                 if result.is_ok() {
                     tracing::info!(
-                        "Requested completion at {}:{}:{} (request_id={})",
+                        "Requested completion at {}:{}:{} (request_id={}, server={})",
                         uri.as_str(),
                         line,
                         character,
-                        request_id
+                        request_id,
+                        server_name
                     );
                 }
-                (request_id, result.is_ok())
+                (request_id, result.is_ok(), server_name.to_string())
             },
         );
 
         let mut sent_ids = Vec::new();
-        for (request_id, ok) in &results {
+        for (request_id, ok, server_name) in &results {
             if *ok {
                 sent_ids.push(*request_id);
+                self.pending_completion_server_names
+                    .insert(*request_id, server_name.clone());
             }
         }
         // Advance the ID counter past all allocated IDs
@@ -650,7 +962,9 @@ impl Editor {
         let state = self.buffers.get_mut(&buffer_id).unwrap();
         let mut popup_obj = crate::state::convert_popup_data_to_popup(&popup_data);
         popup_obj.accept_key_hint = accept_hint;
-        popup_obj.resolver = crate::view::popup::PopupResolver::Completion;
+        popup_obj.resolver = crate::view::popup::PopupResolver::Completion {
+            item_ids: Vec::new(),
+        };
         popup_obj.focus_key_hint = focus_hint;
         state.popups.show_or_replace(popup_obj);
     }
@@ -660,7 +974,8 @@ impl Editor {
     ///
     /// Only triggers when `completion_popup_auto_show` is enabled. Then:
     /// 1. Trigger characters (like `.`, `::`, etc.): immediate if suggest_on_trigger_characters is enabled
-    /// 2. Word characters: delayed by quick_suggestions_delay_ms if quick_suggestions is enabled
+    /// 2. Word characters: delayed by quick_suggestions_delay_ms if quick_suggestions is enabled,
+    ///    once the word before the cursor has quick_suggestions_min_chars characters
     ///
     /// This provides VS Code-like behavior where suggestions appear while typing,
     /// with debouncing to avoid spamming the LSP server.
@@ -700,6 +1015,17 @@ impl Editor {
 
         // Case 2: Word character with quick suggestions - schedule delayed trigger
         if quick_suggestions_enabled && is_word_char {
+            let word_len = {
+                let cursor_pos = self.active_cursors().primary().position;
+                let state = self.active_state_mut();
+                let word_start = find_completion_word_start(&state.buffer, cursor_pos);
+                state.get_text_range(word_start, cursor_pos).chars().count()
+            };
+            if word_len < self.config.editor.quick_suggestions_min_chars {
+                self.scheduled_completion_trigger = None;
+                return;
+            }
+
             let delay_ms = self.config.editor.quick_suggestions_delay_ms;
             let trigger_time = Instant::now() + Duration::from_millis(delay_ms);
 
@@ -1370,6 +1696,48 @@ impl Editor {
         }
     }
 
+    /// Whether the active buffer's top popup is a signature help popup
+    pub(crate) fn is_signature_help_visible(&self) -> bool {
+        self.active_state()
+            .popups
+            .top()
+            .is_some_and(|p| matches!(p.resolver, crate::view::popup::PopupResolver::SignatureHelp))
+    }
+
+    /// Keep an open signature help popup in step with the arguments being
+    /// typed: re-request it while the cursor is still inside the call's
+    /// parentheses, and dismiss it once the call has been closed.
+    pub(crate) fn refresh_signature_help(&mut self) {
+        if !self.is_signature_help_visible() {
+            return;
+        }
+        if self.cursor_in_call_arguments() {
+            self.request_signature_help();
+        } else {
+            self.pending_signature_help_request = None;
+            self.active_state_mut().popups.hide();
+        }
+    }
+
+    /// Whether an unclosed `(` precedes the primary cursor. Only a window
+    /// of text before the cursor is scanned, and string literals aren't
+    /// special-cased — the server has the final word on the next request.
+    fn cursor_in_call_arguments(&mut self) -> bool {
+        let cursor_pos = self.active_cursors().primary().position;
+        let start = cursor_pos.saturating_sub(1000);
+        let text = self.active_state_mut().get_text_range(start, cursor_pos);
+        let mut depth = 0usize;
+        for b in text.bytes().rev() {
+            match b {
+                b')' => depth += 1,
+                b'(' if depth == 0 => return true,
+                b'(' => depth -= 1,
+                _ => {}
+            }
+        }
+        false
+    }
+
     /// Handle signature help response from LSP
     pub(crate) fn handle_signature_help_response(
         &mut self,
@@ -1387,6 +1755,10 @@ impl Editor {
             Some(help) if !help.signatures.is_empty() => help,
             _ => {
                 tracing::debug!("No signature help available");
+                // The server no longer sees a call at the cursor
+                if self.is_signature_help_visible() {
+                    self.active_state_mut().popups.hide();
+                }
                 return;
             }
         };
@@ -1401,49 +1773,53 @@ impl Editor {
         // Build the display content as markdown
         let mut content = String::new();
 
-        // Add the signature label (function signature)
-        content.push_str(&signature.label);
-        content.push('\n');
-
-        // Add parameter highlighting info
         let active_param = signature_help
             .active_parameter
             .or(signature.active_parameter)
             .unwrap_or(0) as usize;
+        let param_info = signature
+            .parameters
+            .as_ref()
+            .and_then(|params| params.get(active_param));
+        let param_range =
+            param_info.and_then(|param| signature_parameter_range(&signature.label, param));
+
+        // Add the signature label (function signature), with the active
+        // parameter in bold
+        match &param_range {
+            Some(range) => {
+                content.push_str(&signature.label[..range.start]);
+                content.push_str("**");
+                content.push_str(&signature.label[range.clone()]);
+                content.push_str("**");
+                content.push_str(&signature.label[range.end..]);
+            }
+            None => content.push_str(&signature.label),
+        }
+        content.push('\n');
 
-        // If there are parameters, highlight the active one
-        if let Some(params) = &signature.parameters {
-            if let Some(param) = params.get(active_param) {
-                // Get parameter label
-                let param_label = match &param.label {
-                    lsp_types::ParameterLabel::Simple(s) => s.clone(),
-                    lsp_types::ParameterLabel::LabelOffsets(offsets) => {
-                        // Extract substring from signature label
-                        let start = offsets[0] as usize;
-                        let end = offsets[1] as usize;
-                        if end <= signature.label.len() {
-                            signature.label[start..end].to_string()
-                        } else {
-                            String::new()
-                        }
-                    }
-                };
+        // If there are parameters, show the active one below the signature
+        if let Some(param) = param_info {
+            let param_label = match (&param.label, &param_range) {
+                (lsp_types::ParameterLabel::Simple(s), _) => s.clone(),
+                (_, Some(range)) => signature.label[range.clone()].to_string(),
+                (_, None) => String::new(),
+            };
 
-                if !param_label.is_empty() {
-                    content.push_str(&format!("\n> {}\n", param_label));
-                }
+            if !param_label.is_empty() {
+                content.push_str(&format!("\n> {}\n", param_label));
+            }
 
-                // Add parameter documentation if available
-                if let Some(doc) = &param.documentation {
-                    let doc_text = match doc {
-                        lsp_types::Documentation::String(s) => s.clone(),
-                        lsp_types::Documentation::MarkupContent(m) => m.value.clone(),
-                    };
-                    if !doc_text.is_empty() {
-                        content.push('\n');
-                        content.push_str(&doc_text);
-                        content.push('\n');
-                    }
+            // Add parameter documentation if available
+            if let Some(doc) = &param.documentation {
+                let doc_text = match doc {
+                    lsp_types::Documentation::String(s) => s.clone(),
+                    lsp_types::Documentation::MarkupContent(m) => m.value.clone(),
+                };
+                if !doc_text.is_empty() {
+                    content.push('\n');
+                    content.push_str(&doc_text);
+                    content.push('\n');
                 }
             }
         }
@@ -1473,9 +1849,14 @@ impl Editor {
         popup.border_style = Style::default().fg(self.theme.popup_border_fg);
         popup.background_style = Style::default().bg(self.theme.popup_bg);
         popup.focus_key_hint = self.popup_focus_key_hint();
+        popup.resolver = crate::view::popup::PopupResolver::SignatureHelp;
 
-        // Show the popup
+        // Show the popup, replacing the one it refreshes
+        let replaces_open_popup = self.is_signature_help_visible();
         if let Some(state) = self.buffers.get_mut(&self.active_buffer()) {
+            if replaces_open_popup {
+                state.popups.hide();
+            }
             state.popups.show(popup);
             tracing::info!(
                 "Showing signature help popup for {} signatures",
@@ -1525,10 +1906,25 @@ impl Editor {
             (line as u32, character as u32, line as u32, character as u32)
         };
 
-        // Get diagnostics at cursor position for context
-        // TODO: Implement diagnostic retrieval when needed
-        let diagnostics: Vec<lsp_types::Diagnostic> = Vec::new();
+        // Diagnostics under the cursor/selection go into the request context
+        // so servers can offer quick fixes for them.
         let buffer_id = self.active_buffer();
+        let requested = lsp_types::Range {
+            start: lsp_types::Position::new(start_line, start_char),
+            end: lsp_types::Position::new(end_line, end_char),
+        };
+        let diagnostics: Vec<lsp_types::Diagnostic> = self
+            .buffer_metadata
+            .get(&buffer_id)
+            .and_then(|m| m.file_uri())
+            .and_then(|uri| self.get_stored_diagnostics().get(uri.as_str()))
+            .map(|all| {
+                all.iter()
+                    .filter(|d| diagnostic_in_code_action_range(&d.range, &requested))
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default();
 
         // Pre-allocate request IDs for all eligible servers
         let base_request_id = self.next_lsp_request_id;
@@ -1627,7 +2023,18 @@ impl Editor {
 
         match &mut self.pending_code_actions {
             Some(existing) => {
-                existing.extend(tagged_actions);
+                // An action another server already offered (same title and
+                // kind) is listed once.
+                let seen: std::collections::HashSet<(String, Option<String>)> = existing
+                    .iter()
+                    .filter(|(srv_name, _)| *srv_name != server_name)
+                    .map(|(_, action)| code_action_dedup_key(action))
+                    .collect();
+                existing.extend(
+                    tagged_actions
+                        .into_iter()
+                        .filter(|(_, action)| !seen.contains(&code_action_dedup_key(action))),
+                );
                 tracing::debug!("Extended code actions, now {} total", existing.len());
             }
             None => {
@@ -1850,8 +2257,9 @@ impl Editor {
         false
     }
 
-    /// Check if any LSP server for the current buffer supports completionItem/resolve
-    pub(crate) fn server_supports_completion_resolve(&self) -> bool {
+    /// Check if the LSP server `server_name` for the current buffer supports
+    /// completionItem/resolve
+    pub(crate) fn server_supports_completion_resolve(&self, server_name: &str) -> bool {
         let language = match self
             .buffers
             .get(&self.active_buffer())
@@ -1863,7 +2271,7 @@ impl Editor {
 
         if let Some(lsp) = &self.lsp {
             for sh in lsp.get_handles(&language) {
-                if sh.capabilities.completion_resolve {
+                if sh.name == server_name && sh.capabilities.completion_resolve {
                     return true;
                 }
             }
@@ -1871,45 +2279,176 @@ impl Editor {
         false
     }
 
-    /// Send completionItem/resolve to the LSP server
-    pub(crate) fn send_completion_resolve(&mut self, item: lsp_types::CompletionItem) {
-        let language = match self
+    /// Send completionItem/resolve to `server_name`, the server the item
+    /// came from.
+    ///
+    /// Returns the request id, or `None` if the server didn't accept the
+    /// request.
+    pub(crate) fn send_completion_resolve(
+        &mut self,
+        server_name: &str,
+        item: lsp_types::CompletionItem,
+    ) -> Option<u64> {
+        let language = self
             .buffers
             .get(&self.active_buffer())
-            .map(|s| s.language.clone())
-        {
-            Some(l) => l,
-            None => return,
-        };
+            .map(|s| s.language.clone())?;
 
         self.next_lsp_request_id += 1;
         let request_id = self.next_lsp_request_id;
 
         if let Some(lsp) = &mut self.lsp {
             for sh in lsp.get_handles_mut(&language) {
-                if sh.capabilities.completion_resolve {
-                    if let Err(e) = sh.handle.completion_resolve(request_id, item.clone()) {
+                if sh.name == server_name && sh.capabilities.completion_resolve {
+                    if let Err(e) = sh.handle.completion_resolve(request_id, item) {
                         tracing::warn!(
                             "Failed to send completionItem/resolve to '{}': {}",
                             sh.name,
                             e
                         );
+                        return None;
                     }
-                    return;
+                    return Some(request_id);
                 }
             }
         }
+        None
+    }
+
+    /// Index into `completion_items` of the LSP item selected in the
+    /// completion popup, or `None` when a buffer-word row (or no completion
+    /// popup) is selected.
+    pub(crate) fn selected_completion_item_id(&self) -> Option<usize> {
+        use crate::view::popup::PopupResolver;
+
+        let popup = self.active_state().popups.top()?;
+        let PopupResolver::Completion { item_ids } = &popup.resolver else {
+            return None;
+        };
+        item_ids.get(popup.selected_index()?).copied()
     }
 
-    /// Handle a resolved completion item — apply additional_text_edits (e.g. auto-imports).
-    pub(crate) fn handle_completion_resolved(&mut self, item: lsp_types::CompletionItem) {
+    /// Show the documentation of the selected completion item in a preview
+    /// pane beside the completion popup.
+    ///
+    /// Items that arrive without documentation are resolved with
+    /// completionItem/resolve when the server that sent them supports it;
+    /// the preview is
+    /// filled in once the response arrives. The preview is hidden while the
+    /// selected item has no documentation to show.
+    pub(crate) fn update_completion_preview(&mut self) {
+        use crate::view::popup::{Popup, PopupResolver};
+
+        let is_completion_popup = self
+            .active_state()
+            .popups
+            .top()
+            .is_some_and(|p| matches!(p.resolver, PopupResolver::Completion { .. }));
+        if !is_completion_popup {
+            return;
+        }
+
+        let item_id = self.selected_completion_item_id();
+        let item = item_id.and_then(|id| {
+            self.completion_items
+                .as_ref()
+                .and_then(|items| items.get(id))
+                .cloned()
+        });
+
+        let documentation = item
+            .as_ref()
+            .and_then(|(_, item)| item.documentation.as_ref())
+            .map(|doc| match doc {
+                lsp_types::Documentation::String(s) => s.clone(),
+                lsp_types::Documentation::MarkupContent(m) => m.value.clone(),
+            })
+            .filter(|doc| !doc.trim().is_empty());
+
+        let preview = documentation.map(|doc| {
+            let mut preview = Popup::markdown(&doc, &self.theme, Some(&self.grammar_registry));
+            preview.width = 50;
+            preview.max_height = 15;
+            // The preview is never on the popup stack, so marking it focused
+            // only keeps the "[Alt+T to focus]" hint off its border.
+            preview.focused = true;
+            Box::new(preview)
+        });
+
+        // Resolve items whose documentation is left to completionItem/resolve.
+        // A resolved item always carries `documentation` (possibly empty), so
+        // each item is resolved at most once.
+        if let (Some(id), Some((server_name, item))) = (item_id, item) {
+            let already_pending = self
+                .pending_completion_preview
+                .is_some_and(|(_, pending)| pending == id);
+            if item.documentation.is_none()
+                && !already_pending
+                && self.server_supports_completion_resolve(&server_name)
+            {
+                if let Some(request_id) = self.send_completion_resolve(&server_name, item) {
+                    self.pending_completion_preview = Some((request_id, id));
+                }
+            }
+        }
+
+        if let Some(popup) = self.active_state_mut().popups.top_mut() {
+            // Keep the existing preview (and its scroll position) when the
+            // content did not change
+            let unchanged = match (&popup.preview, &preview) {
+                (Some(old), Some(new)) => old.content == new.content,
+                (None, None) => true,
+                _ => false,
+            };
+            if !unchanged {
+                popup.preview = preview;
+            }
+        }
+    }
+
+    /// Handle a resolved completion item.
+    ///
+    /// The response to the current preview request updates the stored item
+    /// and refreshes the documentation preview. The response to accepting an
+    /// item applies its additional_text_edits (e.g. auto-imports) to the
+    /// buffer it was accepted in. Anything else, such as a preview response
+    /// that arrives after the selection moved on, is dropped.
+    pub(crate) fn handle_completion_resolved(
+        &mut self,
+        request_id: u64,
+        mut item: lsp_types::CompletionItem,
+    ) {
+        if let Some((_, item_id)) = self
+            .pending_completion_preview
+            .take_if(|(pending_id, _)| *pending_id == request_id)
+        {
+            item.documentation
+                .get_or_insert_with(|| lsp_types::Documentation::String(String::new()));
+            if let Some((_, stored)) = self
+                .completion_items
+                .as_mut()
+                .and_then(|items| items.get_mut(item_id))
+            {
+                *stored = item;
+            }
+            self.update_completion_preview();
+            return;
+        }
+
+        let Some((_, buffer_id)) = self
+            .pending_completion_accept
+            .take_if(|(pending_id, _)| *pending_id == request_id)
+        else {
+            tracing::debug!("Ignoring stale completion resolve: {}", request_id);
+            return;
+        };
+
         if let Some(additional_edits) = item.additional_text_edits {
             if !additional_edits.is_empty() {
                 tracing::info!(
                     "Applying {} additional text edits from completion resolve",
                     additional_edits.len()
                 );
-                let buffer_id = self.active_buffer();
                 if let Err(e) = self.apply_lsp_text_edits(buffer_id, additional_edits) {
                     tracing::error!("Failed to apply completion additional_text_edits: {}", e);
                 }
@@ -1917,70 +2456,129 @@ impl Editor {
         }
     }
 
-    /// Apply formatting edits from textDocument/formatting response.
-    pub(crate) fn apply_formatting_edits(
+    /// Handle a textDocument/formatting response.
+    ///
+    /// The edits are dropped when the request was superseded, when the
+    /// buffer changed after the request was sent (the edits would land at
+    /// stale offsets), or when the server returned overlapping edits.
+    pub(crate) fn handle_formatting_response(
         &mut self,
-        uri: &str,
+        request_id: u64,
         edits: Vec<lsp_types::TextEdit>,
-    ) -> AnyhowResult<usize> {
-        // Find the buffer for this URI
-        let buffer_id = self
-            .buffer_metadata
-            .iter()
-            .find(|(_, meta)| meta.file_uri().map(|u| u.as_str() == uri).unwrap_or(false))
-            .map(|(id, _)| *id);
+    ) -> AnyhowResult<()> {
+        let Some(request) = self.pending_formatting_requests.remove(&request_id) else {
+            tracing::debug!("Ignoring stale formatting response: {}", request_id);
+            return Ok(());
+        };
+        let buffer_id = request.buffer_id;
 
-        if let Some(buffer_id) = buffer_id {
-            let count = self.apply_lsp_text_edits(buffer_id, edits)?;
-            self.set_status_message(format!("Formatted ({} edits)", count));
-            Ok(count)
-        } else {
-            tracing::warn!("Cannot apply formatting: no buffer for URI {}", uri);
-            Ok(0)
+        let Some(current_version) = self.buffers.get(&buffer_id).map(|s| s.buffer.version()) else {
+            return Ok(());
+        };
+        if current_version != request.version {
+            tracing::debug!(
+                "Discarding formatting edits for {:?}: version {} != current {}",
+                buffer_id,
+                request.version,
+                current_version
+            );
+            self.set_status_message("Formatting discarded: buffer changed".to_string());
+            return Ok(());
         }
+
+        if edits.is_empty() {
+            return Ok(());
+        }
+
+        if text_edits_overlap(&edits) {
+            tracing::warn!("Discarding formatting response with overlapping edits");
+            self.set_status_message(
+                "Formatting discarded: server returned overlapping edits".to_string(),
+            );
+            return Ok(());
+        }
+
+        let count = self.apply_lsp_text_edits(buffer_id, edits)?;
+        self.set_status_message(format!("Formatted ({} edits)", count));
+
+        if request.save_after {
+            let Some(path) = self
+                .buffers
+                .get(&buffer_id)
+                .and_then(|s| s.buffer.file_path().map(|p| p.to_path_buf()))
+            else {
+                return Ok(());
+            };
+            if self.write_buffer(buffer_id, &path)? {
+                // Silent finalize: skips on-save actions, so this can't loop.
+                self.finalize_save_buffer(buffer_id, Some(path), true)?;
+            }
+        }
+        Ok(())
     }
 
-    /// Request document formatting from LSP.
-    pub(crate) fn request_formatting(&mut self) {
+    /// Request document formatting from LSP for the active buffer.
+    ///
+    /// With `save_after` (format-on-save), the buffer is re-saved once the
+    /// edits arrive and are applied, and "not available" messages are
+    /// suppressed since the user didn't ask for formatting explicitly.
+    /// Returns true if the request was sent.
+    pub(crate) fn request_formatting(&mut self, save_after: bool) -> bool {
         let buffer_id = self.active_buffer();
-        let metadata = match self.buffer_metadata.get(&buffer_id) {
-            Some(m) if m.lsp_enabled => m,
-            _ => {
+        let lsp_enabled = self
+            .buffer_metadata
+            .get(&buffer_id)
+            .is_some_and(|m| m.lsp_enabled);
+        if !lsp_enabled {
+            if !save_after {
                 self.set_status_message("LSP not available for this buffer".to_string());
-                return;
             }
-        };
-
-        let uri = match metadata.file_uri() {
-            Some(u) => u.clone(),
-            None => return,
-        };
+            return false;
+        }
 
-        let language = match self.buffers.get(&buffer_id).map(|s| s.language.clone()) {
-            Some(l) => l,
-            None => return,
+        let (version, tab_size, insert_spaces) = match self.buffers.get(&buffer_id) {
+            Some(state) => (
+                state.buffer.version(),
+                state.buffer_settings.indent_size() as u32,
+                !state.buffer_settings.use_tabs,
+            ),
+            None => return false,
         };
 
-        let tab_size = self.config.editor.tab_size as u32;
-        let insert_spaces = !self.config.editor.use_tabs;
-
         self.next_lsp_request_id += 1;
         let request_id = self.next_lsp_request_id;
 
-        if let Some(lsp) = &mut self.lsp {
-            if let Some(sh) = lsp.handle_for_feature_mut(&language, LspFeature::Format) {
-                if let Err(e) = sh.handle.document_formatting(
+        // Use helper to ensure didOpen is sent before the request
+        let sent = self
+            .with_lsp_for_buffer(buffer_id, LspFeature::Format, |handle, uri, _language| {
+                let result = handle.document_formatting(
                     request_id,
                     uri.as_uri().clone(),
                     tab_size,
                     insert_spaces,
-                ) {
+                );
+                if let Err(e) = &result {
                     tracing::warn!("Failed to request formatting: {}", e);
                 }
-            } else {
-                self.set_status_message("Formatting not supported by LSP server".to_string());
-            }
+                result.is_ok()
+            })
+            .unwrap_or(false);
+
+        if sent {
+            self.pending_formatting_requests
+                .retain(|_, r| r.buffer_id != buffer_id);
+            self.pending_formatting_requests.insert(
+                request_id,
+                super::FormattingRequest {
+                    buffer_id,
+                    version,
+                    save_after,
+                },
+            );
+        } else if !save_after {
+            self.set_status_message("Formatting not supported by LSP server".to_string());
         }
+        sent
     }
 
     /// Handle find references response from LSP
@@ -2062,6 +2660,301 @@ impl Editor {
         Ok(())
     }
 
+    /// Request the symbol outline of the active buffer from LSP.
+    pub(crate) fn request_document_symbols(&mut self) {
+        let buffer_id = self.active_buffer();
+        let request_id = self.next_lsp_request_id;
+
+        // Use helper to ensure didOpen is sent before the request
+        let sent = self
+            .with_lsp_for_buffer(
+                buffer_id,
+                LspFeature::DocumentSymbols,
+                |handle, uri, _language| {
+                    let result = handle.document_symbols(request_id, uri.as_uri().clone());
+                    if let Err(e) = &result {
+                        tracing::warn!("Failed to request document symbols: {}", e);
+                    }
+                    result.is_ok()
+                },
+            )
+            .unwrap_or(false);
+
+        if sent {
+            self.next_lsp_request_id += 1;
+            self.pending_document_symbols_request = Some(request_id);
+        } else {
+            self.set_status_message(t!("lsp.no_document_symbols").to_string());
+        }
+    }
+
+    /// Handle a textDocument/documentSymbol response by showing the outline
+    /// popup. Confirming an entry jumps to it (see `jump_to_document_symbol`).
+    pub(crate) fn handle_document_symbols_response(
+        &mut self,
+        request_id: u64,
+        response: Option<lsp_types::DocumentSymbolResponse>,
+    ) {
+        if self.pending_document_symbols_request != Some(request_id) {
+            tracing::debug!("Ignoring stale document symbols response: {}", request_id);
+            return;
+        }
+        self.pending_document_symbols_request = None;
+
+        let entries = response.map(document_symbol_entries).unwrap_or_default();
+        if entries.is_empty() {
+            self.set_status_message(t!("lsp.no_document_symbols").to_string());
+            return;
+        }
+
+        use crate::view::popup::{Popup, PopupListItem, PopupPosition};
+        use ratatui::style::Style;
+
+        // The target position travels in the row's `data` as "line:character"
+        let items: Vec<PopupListItem> = entries
+            .iter()
+            .map(|entry| PopupListItem {
+                text: format!("{}{}", "  ".repeat(entry.depth), entry.name),
+                detail: Some(symbol_kind_label(entry.kind).to_string()),
+                icon: None,
+                data: Some(format!(
+                    "{}:{}",
+                    entry.position.line, entry.position.character
+                )),
+                disabled: false,
+            })
+            .collect();
+
+        let mut popup = Popup::list(items, &self.theme);
+        popup.kind = crate::view::popup::PopupKind::List;
+        popup.title = Some(t!("lsp.popup_document_symbols").to_string());
+        popup.position = PopupPosition::Centered;
+        popup.width = 60;
+        popup.max_height = 20;
+        popup.border_style = Style::default().fg(self.theme.popup_border_fg);
+        popup.background_style = Style::default().bg(self.theme.popup_bg);
+        popup.resolver = crate::view::popup::PopupResolver::DocumentSymbol;
+        popup.focused = true;
+
+        if let Some(state) = self.buffers.get_mut(&self.active_buffer()) {
+            state.popups.show_or_replace(popup);
+        }
+    }
+
+    /// Move the cursor to a symbol chosen from the outline popup, recording
+    /// the departure point in position history so Back returns to it.
+    pub(crate) fn jump_to_document_symbol(&mut self, line: usize, character: usize) {
+        let buffer_id = self.active_buffer();
+        let Some(position) = self
+            .buffers
+            .get(&buffer_id)
+            .map(|state| state.buffer.lsp_position_to_byte(line, character))
+        else {
+            return;
+        };
+
+        self.record_jump_origin();
+        self.move_primary_cursor(position);
+        self.ensure_active_cursor_visible_for_navigation(true);
+    }
+
+    /// Open the Workspace Symbols prompt. Results are requested as the
+    /// user types (see `schedule_workspace_symbols_request`).
+    pub(crate) fn start_workspace_symbols_prompt(&mut self) {
+        self.scheduled_workspace_symbols_request = None;
+        self.pending_workspace_symbols_request = None;
+        self.start_prompt_with_suggestions(
+            t!("lsp.prompt_workspace_symbols").to_string(),
+            PromptType::LspWorkspaceSymbols,
+            vec![crate::input::commands::Suggestion::disabled(
+                t!("lsp.workspace_symbols_hint").to_string(),
+            )],
+        );
+    }
+
+    /// Debounce a workspace symbol search for the prompt's current input.
+    ///
+    /// An empty query is never sent: several servers reject it or return
+    /// nothing, so the prompt shows a hint instead.
+    pub(crate) fn schedule_workspace_symbols_request(&mut self, query: &str) {
+        if query.trim().is_empty() {
+            self.scheduled_workspace_symbols_request = None;
+            self.pending_workspace_symbols_request = None;
+            self.set_workspace_symbol_suggestions(vec![
+                crate::input::commands::Suggestion::disabled(
+                    t!("lsp.workspace_symbols_hint").to_string(),
+                ),
+            ]);
+            return;
+        }
+        self.scheduled_workspace_symbols_request =
+            Some(Instant::now() + Duration::from_millis(WORKSPACE_SYMBOLS_DEBOUNCE_MS));
+    }
+
+    /// Send the Workspace Symbols prompt's query to the active buffer's
+    /// language server.
+    pub(crate) fn request_workspace_symbols(&mut self) {
+        let query = match &self.prompt {
+            Some(prompt) if prompt.prompt_type == PromptType::LspWorkspaceSymbols => {
+                prompt.input.trim().to_string()
+            }
+            _ => return,
+        };
+        if query.is_empty() {
+            return;
+        }
+
+        let buffer_id = self.active_buffer();
+        let request_id = self.next_lsp_request_id;
+
+        let sent = self
+            .with_lsp_for_buffer(
+                buffer_id,
+                LspFeature::WorkspaceSymbols,
+                |handle, _uri, _language| {
+                    let result = handle.workspace_symbols(request_id, query.clone());
+                    if let Err(e) = &result {
+                        tracing::warn!("Failed to request workspace symbols: {}", e);
+                    }
+                    result.is_ok()
+                },
+            )
+            .unwrap_or(false);
+
+        if sent {
+            self.next_lsp_request_id += 1;
+            self.pending_workspace_symbols_request = Some(request_id);
+        } else {
+            self.pending_workspace_symbols_request = None;
+            self.set_workspace_symbol_suggestions(vec![
+                crate::input::commands::Suggestion::disabled(
+                    t!("lsp.workspace_symbols_unavailable").to_string(),
+                ),
+            ]);
+        }
+    }
+
+    /// Handle a workspace/symbol response by listing the results in the
+    /// Workspace Symbols prompt. Each suggestion's value encodes the target
+    /// as "line:character:uri" for `jump_to_workspace_symbol`.
+    pub(crate) fn handle_workspace_symbols_response(
+        &mut self,
+        request_id: u64,
+        response: Option<lsp_types::WorkspaceSymbolResponse>,
+    ) {
+        if self.pending_workspace_symbols_request != Some(request_id) {
+            tracing::debug!("Ignoring stale workspace symbols response: {}", request_id);
+            return;
+        }
+        self.pending_workspace_symbols_request = None;
+
+        let entries = response.map(workspace_symbol_entries).unwrap_or_default();
+        if entries.is_empty() {
+            self.set_workspace_symbol_suggestions(vec![
+                crate::input::commands::Suggestion::disabled(
+                    t!("lsp.no_workspace_symbols").to_string(),
+                ),
+            ]);
+            return;
+        }
+
+        let translation = self.authority.path_translation.clone();
+        let suggestions = entries
+            .into_iter()
+            .map(|entry| {
+                let wire = crate::app::types::LspUri::from_wire(entry.uri.clone());
+                let path = wire
+                    .to_host_path(translation.as_ref())
+                    .map(|path| {
+                        path.strip_prefix(&self.working_dir)
+                            .map(|p| p.to_path_buf())
+                            .unwrap_or(path)
+                            .display()
+                            .to_string()
+                    })
+                    .unwrap_or_else(|| entry.uri.as_str().to_string());
+                crate::input::commands::Suggestion::new(entry.name)
+                    .with_description(format!(
+                        "{}  {}:{}",
+                        symbol_kind_label(entry.kind),
+                        path,
+                        entry.position.line + 1
+                    ))
+                    .with_value(format!(
+                        "{}:{}:{}",
+                        entry.position.line,
+                        entry.position.character,
+                        entry.uri.as_str()
+                    ))
+            })
+            .collect();
+        self.set_workspace_symbol_suggestions(suggestions);
+    }
+
+    /// Replace the Workspace Symbols prompt's suggestion list, if that
+    /// prompt is still open.
+    fn set_workspace_symbol_suggestions(
+        &mut self,
+        suggestions: Vec<crate::input::commands::Suggestion>,
+    ) {
+        if let Some(prompt) = &mut self.prompt {
+            if prompt.prompt_type != PromptType::LspWorkspaceSymbols {
+                return;
+            }
+            prompt.selected_suggestion = if suggestions.is_empty() {
+                None
+            } else {
+                Some(0)
+            };
+            prompt.suggestions = suggestions;
+            prompt.scroll_offset = 0;
+        }
+    }
+
+    /// Open the file of a symbol chosen in the Workspace Symbols prompt and
+    /// move the cursor to it. `target` is "line:character:uri".
+    pub(crate) fn jump_to_workspace_symbol(&mut self, target: &str) {
+        let mut parts = target.splitn(3, ':');
+        let (Some(Ok(line)), Some(Ok(character)), Some(Ok(uri))) = (
+            parts.next().map(str::parse::<usize>),
+            parts.next().map(str::parse::<usize>),
+            parts.next().map(str::parse::<lsp_types::Uri>),
+        ) else {
+            tracing::warn!("Malformed workspace symbol target: {}", target);
+            return;
+        };
+
+        self.record_jump_origin();
+
+        let wire = crate::app::types::LspUri::from_wire(uri);
+        let buffer_id = match self.open_lsp_uri_target(&wire) {
+            Ok(id) => id,
+            Err(e) => {
+                if let Some(confirmation) =
+                    e.downcast_ref::<crate::model::buffer::LargeFileEncodingConfirmation>()
+                {
+                    self.start_large_file_encoding_confirmation(confirmation);
+                } else {
+                    self.set_status_message(
+                        t!("file.error_opening", error = e.to_string()).to_string(),
+                    );
+                }
+                return;
+            }
+        };
+
+        let Some(position) = self
+            .buffers
+            .get(&buffer_id)
+            .map(|state| state.buffer.lsp_position_to_byte(line, character))
+        else {
+            return;
+        };
+
+        self.move_primary_cursor(position);
+        self.ensure_active_cursor_visible_for_navigation(true);
+    }
+
     /// Apply LSP text edits to a buffer and return the number of changes made.
     /// Edits are sorted in reverse order and applied as a batch.
     pub(crate) fn apply_lsp_text_edits(
@@ -2453,12 +3346,19 @@ impl Editor {
     }
 
     /// Handle rename response from LSP
+    ///
+    /// A rename confined to one file is applied right away. One that reaches
+    /// other files is held back and listed in a preview popup, and is only
+    /// applied once the user confirms it (see `apply_confirmed_rename`).
     pub fn handle_rename_response(
         &mut self,
         _request_id: u64,
         result: Result<lsp_types::WorkspaceEdit, String>,
     ) -> AnyhowResult<()> {
         match result {
+            Ok(workspace_edit) if workspace_edit_spans_files(&workspace_edit) => {
+                self.show_rename_preview(workspace_edit);
+            }
             Ok(workspace_edit) => {
                 let total_changes = self.apply_workspace_edit(workspace_edit)?;
                 self.status_message = Some(t!("lsp.renamed", count = total_changes).to_string());
@@ -2480,6 +3380,129 @@ impl Editor {
         Ok(())
     }
 
+    /// List every location a multi-file rename would edit in a popup and
+    /// hold the edit in `pending_rename_edit` until the user confirms it.
+    fn show_rename_preview(&mut self, workspace_edit: lsp_types::WorkspaceEdit) {
+        use crate::view::popup::{Popup, PopupListItem, PopupPosition};
+        use ratatui::style::Style;
+
+        let documents = workspace_edit_documents(&workspace_edit);
+        let translation = self.authority.path_translation.clone();
+        let mut items = Vec::new();
+        for (uri, edits) in &documents {
+            let wire = crate::app::types::LspUri::from_wire(uri.clone());
+            let path = super::lsp_uri_to_host_path(&wire, translation.as_ref()).ok();
+            let display = path
+                .as_ref()
+                .map(|p| {
+                    p.strip_prefix(&self.working_dir)
+                        .unwrap_or(p)
+                        .to_string_lossy()
+                        .into_owned()
+                })
+                .unwrap_or_else(|| uri.as_str().to_string());
+
+            // Preview lines come from the open buffer when there is one (it
+            // may hold unsaved changes the server has seen), else from disk.
+            let open_buffer = path.as_ref().and_then(|p| {
+                self.buffers
+                    .values()
+                    .find(|state| state.buffer.file_path() == Some(p.as_path()))
+            });
+            let disk_lines: Vec<String> = match (open_buffer, &path) {
+                (None, Some(p)) => self
+                    .authority
+                    .filesystem
+                    .read_file(p)
+                    .map(|bytes| {
+                        String::from_utf8_lossy(&bytes)
+                            .lines()
+                            .map(str::to_string)
+                            .collect()
+                    })
+                    .unwrap_or_default(),
+                _ => Vec::new(),
+            };
+
+            for edit in edits {
+                let line = edit.range.start.line as usize;
+                let line_text = match open_buffer {
+                    Some(state) => state
+                        .buffer
+                        .get_line(line)
+                        .map(|bytes| String::from_utf8_lossy(&bytes).into_owned())
+                        .unwrap_or_default(),
+                    None => disk_lines.get(line).cloned().unwrap_or_default(),
+                };
+                items.push(PopupListItem::new(format!(
+                    "{}:{}: {}",
+                    display,
+                    line + 1,
+                    line_text.trim()
+                )));
+            }
+        }
+
+        let (count, files) = (items.len(), documents.len());
+        let mut popup = Popup::list(items, &self.theme);
+        popup.title =
+            Some(t!("lsp.popup_rename_preview", count = count, files = files).to_string());
+        popup.description = Some(t!("lsp.rename_preview_hint").to_string());
+        popup.position = PopupPosition::Centered;
+        popup.width = 80;
+        popup.max_height = 20;
+        popup.border_style = Style::default().fg(self.theme.popup_border_fg);
+        popup.background_style = Style::default().bg(self.theme.popup_bg);
+        popup.resolver = crate::view::popup::PopupResolver::RenamePreview;
+        popup.focused = true;
+
+        self.pending_rename_edit = Some(workspace_edit);
+        if let Some(state) = self.buffers.get_mut(&self.active_buffer()) {
+            state.popups.show_or_replace(popup);
+        }
+    }
+
+    /// Apply a rename confirmed from the preview popup.
+    ///
+    /// Every file with text edits is opened before anything is changed, so a
+    /// file that cannot be opened aborts the rename instead of leaving it
+    /// half applied. Each buffer gets its edits as a single undo step, and the
+    /// buffer the rename was started from stays active.
+    pub(crate) fn apply_confirmed_rename(&mut self, workspace_edit: lsp_types::WorkspaceEdit) {
+        let original_buffer = self.active_buffer();
+
+        // Files created or moved by resource operations don't exist yet, so
+        // only pure text edits can be opened up front.
+        if !workspace_edit_has_resource_ops(&workspace_edit) {
+            let translation = self.authority.path_translation.clone();
+            for (uri, _) in workspace_edit_documents(&workspace_edit) {
+                let wire = crate::app::types::LspUri::from_wire(uri);
+                let Ok(path) = super::lsp_uri_to_host_path(&wire, translation.as_ref()) else {
+                    continue;
+                };
+                if let Err(e) = self.open_file_no_focus(&path) {
+                    self.set_status_message(
+                        t!("lsp.rename_failed", error = e.to_string()).to_string(),
+                    );
+                    return;
+                }
+            }
+        }
+
+        match self.apply_workspace_edit(workspace_edit) {
+            Ok(total_changes) => {
+                self.set_status_message(t!("lsp.renamed", count = total_changes).to_string());
+            }
+            Err(e) => {
+                self.set_status_message(t!("lsp.rename_failed", error = e.to_string()).to_string());
+            }
+        }
+
+        if self.buffers.contains_key(&original_buffer) {
+            self.set_active_buffer(original_buffer);
+        }
+    }
+
     /// Apply events to a specific buffer using bulk edit optimization (O(n) vs O(n²))
     ///
     /// This is similar to `apply_events_as_bulk_edit` but works on a specific buffer
@@ -2857,11 +3880,11 @@ impl Editor {
             // (including inside ranges the user just deleted), and new hints
             // that the server would now produce never arrive.
             if self.config.editor.enable_inlay_hints {
-                self.scheduled_inlay_hints_request = Some((
+                self.scheduled_inlay_hints_requests.insert(
                     buffer_id,
                     std::time::Instant::now()
                         + std::time::Duration::from_millis(INLAY_HINTS_DEBOUNCE_MS),
-                ));
+                );
             }
         }
     }
@@ -3098,14 +4121,24 @@ impl Editor {
         // the response handler can drop stale data if the buffer has moved
         // on by the time hints arrive.
         let (line_count, version) = if let Some(state) = self.buffers.get(&buffer_id) {
-            (
-                state.buffer.line_count().unwrap_or(1000),
-                state.buffer.version(),
-            )
+            (state.buffer.line_count(), state.buffer.version())
         } else {
             return;
         };
-        let last_line = line_count.saturating_sub(1) as u32;
+
+        // Once the buffer has been rendered, only ask for the padded
+        // viewport; before that, ask for the whole document.
+        let (start_line, end_line) = match self.inlay_hints_viewport.get(&buffer_id) {
+            Some(&(start, end)) => {
+                let mut end = end.saturating_add(INLAY_HINTS_RANGE_PADDING_LINES);
+                if let Some(line_count) = line_count {
+                    end = end.min(line_count.saturating_sub(1));
+                }
+                let start = start.saturating_sub(INLAY_HINTS_RANGE_PADDING_LINES);
+                (start.min(end), end)
+            }
+            None => (0, line_count.unwrap_or(1000).saturating_sub(1)),
+        };
         let request_id = self.next_lsp_request_id;
 
         // Use helper to ensure didOpen is sent before the request
@@ -3117,9 +4150,9 @@ impl Editor {
                     let result = handle.inlay_hints(
                         request_id,
                         uri.as_uri().clone(),
+                        start_line as u32,
                         0,
-                        0,
-                        last_line,
+                        end_line as u32,
                         10000,
                     );
                     if result.is_ok() {
@@ -3140,9 +4173,52 @@ impl Editor {
             self.next_lsp_request_id += 1;
             self.pending_inlay_hints_requests
                 .insert(request_id, super::InlayHintsRequest { buffer_id, version });
+            self.inlay_hints_coverage
+                .insert(buffer_id, (start_line, end_line));
         }
     }
 
+    /// Record the rendered line span of a buffer and, if it has scrolled
+    /// outside the lines covered by the last inlay hints request, schedule
+    /// a debounced request for the new viewport.
+    pub(crate) fn maybe_schedule_inlay_hints_for_viewport(
+        &mut self,
+        buffer_id: BufferId,
+        start_line: usize,
+        end_line: usize,
+    ) {
+        if !self.config.editor.enable_inlay_hints {
+            return;
+        }
+        // The viewport may extend past EOF; clamp so a short file that is
+        // fully hinted doesn't look uncovered.
+        let mut end_line = end_line;
+        if let Some(line_count) = self
+            .buffers
+            .get(&buffer_id)
+            .and_then(|s| s.buffer.line_count())
+        {
+            end_line = end_line.min(line_count.saturating_sub(1));
+        }
+        let start_line = start_line.min(end_line);
+        self.inlay_hints_viewport
+            .insert(buffer_id, (start_line, end_line));
+
+        // No coverage means hints were never requested (no server, or not
+        // initialized yet) — the LspInitialized path handles the first one.
+        let Some(&(covered_start, covered_end)) = self.inlay_hints_coverage.get(&buffer_id) else {
+            return;
+        };
+        if start_line >= covered_start && end_line <= covered_end {
+            return;
+        }
+        self.scheduled_inlay_hints_requests
+            .entry(buffer_id)
+            .or_insert_with(|| {
+                Instant::now() + Duration::from_millis(INLAY_HINTS_SCROLL_DEBOUNCE_MS)
+            });
+    }
+
     /// Schedule a folding range refresh for a buffer (debounced).
     pub(crate) fn schedule_folding_ranges_refresh(&mut self, buffer_id: BufferId) {
         let next_time = Instant::now() + Duration::from_millis(FOLDING_RANGES_DEBOUNCE_MS);
@@ -3536,7 +4612,9 @@ mod tests {
     fn test_fs() -> Arc<dyn crate::model::filesystem::FileSystem + Send + Sync> {
         Arc::new(StdFileSystem)
     }
-    use super::{lsp_range_contains, Editor};
+    use super::{
+        lsp_range_contains, signature_parameter_range, workspace_edit_spans_files, Editor,
+    };
 
     fn range(sl: u32, sc: u32, el: u32, ec: u32) -> lsp_types::Range {
         lsp_types::Range {
@@ -3551,6 +4629,57 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_signature_parameter_range() {
+        let param = |label| lsp_types::ParameterInformation {
+            label,
+            documentation: None,
+        };
+        let label = "a(a: i32, b: i32)";
+        let simple = param(lsp_types::ParameterLabel::Simple("a: i32".into()));
+        assert_eq!(signature_parameter_range(label, &simple), Some(2..8));
+        let offsets = param(lsp_types::ParameterLabel::LabelOffsets([10, 16]));
+        assert_eq!(signature_parameter_range(label, &offsets), Some(10..16));
+        let out_of_bounds = param(lsp_types::ParameterLabel::LabelOffsets([10, 99]));
+        assert_eq!(signature_parameter_range(label, &out_of_bounds), None);
+        let missing = param(lsp_types::ParameterLabel::Simple("c".into()));
+        assert_eq!(signature_parameter_range(label, &missing), None);
+    }
+
+    #[test]
+    fn test_workspace_edit_spans_files() {
+        let uri = |name: &str| -> lsp_types::Uri { format!("file:///tmp/{name}").parse().unwrap() };
+        let edit = lsp_types::TextEdit {
+            range: range(0, 0, 0, 1),
+            new_text: "x".to_string(),
+        };
+        let changes = |names: &[&str]| lsp_types::WorkspaceEdit {
+            changes: Some(
+                names
+                    .iter()
+                    .map(|name| (uri(name), vec![edit.clone()]))
+                    .collect(),
+            ),
+            ..Default::default()
+        };
+        assert!(!workspace_edit_spans_files(&changes(&["a.rs"])));
+        assert!(workspace_edit_spans_files(&changes(&["a.rs", "b.rs"])));
+
+        let create = lsp_types::WorkspaceEdit {
+            document_changes: Some(lsp_types::DocumentChanges::Operations(vec![
+                lsp_types::DocumentChangeOperation::Op(lsp_types::ResourceOp::Create(
+                    lsp_types::CreateFile {
+                        uri: uri("new.rs"),
+                        options: None,
+                        annotation_id: None,
+                    },
+                )),
+            ])),
+            ..Default::default()
+        };
+        assert!(workspace_edit_spans_files(&create));
+    }
+
     #[test]
     fn test_lsp_range_contains_inclusive_start_exclusive_end() {
         let r = range(3, 10, 3, 20);
@@ -3878,6 +5007,120 @@ mod tests {
         assert_eq!(state.virtual_texts.len(), 1);
     }
 
+    fn text_edit(start: (u32, u32), end: (u32, u32), text: &str) -> lsp_types::TextEdit {
+        lsp_types::TextEdit {
+            range: lsp_types::Range {
+                start: lsp_types::Position::new(start.0, start.1),
+                end: lsp_types::Position::new(end.0, end.1),
+            },
+            new_text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_diagnostic_in_code_action_range() {
+        use super::diagnostic_in_code_action_range;
+
+        let diag = range(2, 4, 2, 10);
+        // Cursor inside / outside the diagnostic
+        assert!(diagnostic_in_code_action_range(&diag, &range(2, 6, 2, 6)));
+        assert!(!diagnostic_in_code_action_range(
+            &diag,
+            &range(2, 10, 2, 10)
+        ));
+        // Selection overlapping vs. merely touching
+        assert!(diagnostic_in_code_action_range(&diag, &range(1, 0, 2, 5)));
+        assert!(!diagnostic_in_code_action_range(&diag, &range(1, 0, 2, 4)));
+    }
+
+    #[test]
+    fn test_text_edits_overlap() {
+        use super::text_edits_overlap;
+
+        // Disjoint and touching edits are valid, in any order
+        assert!(!text_edits_overlap(&[
+            text_edit((2, 0), (2, 4), ""),
+            text_edit((0, 0), (0, 0), "    "),
+            text_edit((1, 0), (2, 0), "x\n"),
+        ]));
+        // One edit starting inside another is rejected
+        assert!(text_edits_overlap(&[
+            text_edit((0, 0), (1, 3), ""),
+            text_edit((1, 2), (1, 5), "y"),
+        ]));
+    }
+
+    #[test]
+    fn test_document_symbol_entries_handles_both_shapes() {
+        use super::document_symbol_entries;
+
+        let nested: lsp_types::DocumentSymbolResponse = serde_json::from_value(serde_json::json!([
+            {
+                "name": "Outer", "kind": 23,
+                "range": range(0, 0, 4, 1), "selectionRange": range(0, 7, 0, 12),
+                "children": [{
+                    "name": "field", "kind": 8,
+                    "range": range(1, 4, 1, 15), "selectionRange": range(1, 4, 1, 9)
+                }]
+            },
+            { "name": "run", "kind": 12, "range": range(6, 0, 8, 1), "selectionRange": range(6, 3, 6, 6) }
+        ]))
+        .unwrap();
+        let rows: Vec<_> = document_symbol_entries(nested)
+            .into_iter()
+            .map(|e| (e.depth, e.name, e.position.line))
+            .collect();
+        assert_eq!(
+            rows,
+            vec![
+                (0, "Outer".to_string(), 0),
+                (1, "field".to_string(), 1),
+                (0, "run".to_string(), 6),
+            ]
+        );
+
+        // Flat SymbolInformation[] comes back sorted by position at depth 0
+        let flat: lsp_types::DocumentSymbolResponse = serde_json::from_value(serde_json::json!([
+            { "name": "b", "kind": 12, "location": { "uri": "file:///t.rs", "range": range(5, 3, 5, 4) } },
+            { "name": "a", "kind": 12, "location": { "uri": "file:///t.rs", "range": range(1, 3, 1, 4) } }
+        ]))
+        .unwrap();
+        let rows: Vec<_> = document_symbol_entries(flat)
+            .into_iter()
+            .map(|e| (e.depth, e.name, e.position.line))
+            .collect();
+        assert_eq!(rows, vec![(0, "a".to_string(), 1), (0, "b".to_string(), 5)]);
+    }
+
+    #[test]
+    fn test_workspace_symbol_entries_handles_both_shapes() {
+        use super::workspace_symbol_entries;
+
+        let flat: lsp_types::WorkspaceSymbolResponse = serde_json::from_value(serde_json::json!([
+            { "name": "Widget", "kind": 23, "location": { "uri": "file:///a.rs", "range": range(2, 11, 2, 17) } }
+        ]))
+        .unwrap();
+        let rows: Vec<_> = workspace_symbol_entries(flat)
+            .into_iter()
+            .map(|e| (e.name, e.uri.as_str().to_string(), e.position.line))
+            .collect();
+        assert_eq!(
+            rows,
+            vec![("Widget".to_string(), "file:///a.rs".to_string(), 2)]
+        );
+
+        // A WorkspaceSymbol with only a URI jumps to the start of the file
+        let nested: lsp_types::WorkspaceSymbolResponse =
+            serde_json::from_value(serde_json::json!([
+                { "name": "make_widget", "kind": 12, "location": { "uri": "file:///b.rs" } }
+            ]))
+            .unwrap();
+        let entries = workspace_symbol_entries(nested);
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].uri.as_str(), "file:///b.rs");
+        assert_eq!(entries[0].position, lsp_types::Position::default());
+    }
+
     #[test]
     fn test_space_doc_paragraphs_inserts_blank_lines() {
         use super::space_doc_paragraphs;
@@ -8,7 +8,7 @@
 use rust_i18n::t;
 
 use crate::input::keybindings::Action;
-use crate::model::event::{BufferId, EventLog};
+use crate::model::event::BufferId;
 use crate::state::EditorState;
 
 use super::types::{BufferKind, BufferMetadata};
@@ -199,7 +199,8 @@ impl Editor {
             .configure_for_line_numbers(self.config.editor.line_numbers);
 
         self.buffers.insert(buffer_id, state);
-        self.event_logs.insert(buffer_id, EventLog::new());
+        self.event_logs
+            .insert(buffer_id, self.new_event_log(buffer_id));
 
         // Set buffer content
         if let Some(state) = self.buffers.get_mut(&buffer_id) {
@@ -224,7 +225,9 @@ impl Editor {
             hidden_from_tabs: false,
             synthetic_placeholder: false,
             is_preview: false,
+            scratch: false,
             recovery_id: None,
+            detected_indent: None,
         };
         self.buffer_metadata.insert(buffer_id, metadata);
 
@@ -273,7 +276,8 @@ impl Editor {
             .configure_for_line_numbers(self.config.editor.line_numbers);
 
         self.buffers.insert(buffer_id, state);
-        self.event_logs.insert(buffer_id, EventLog::new());
+        self.event_logs
+            .insert(buffer_id, self.new_event_log(buffer_id));
 
         // Set buffer content
         if let Some(state) = self.buffers.get_mut(&buffer_id) {
@@ -298,7 +302,9 @@ impl Editor {
             hidden_from_tabs: false,
             synthetic_placeholder: false,
             is_preview: false,
+            scratch: false,
             recovery_id: None,
+            detected_indent: None,
         };
         self.buffer_metadata.insert(buffer_id, metadata);
 
@@ -12,8 +12,10 @@ mod calibration_actions;
 pub mod calibration_wizard;
 mod click_geometry;
 mod click_handlers;
+pub mod client_view;
 mod clipboard;
 mod composite_buffer_actions;
+mod custom_commands;
 mod dabbrev_actions;
 mod diagnostic_jumps;
 mod editor_accessors;
@@ -21,6 +23,7 @@ mod editor_init;
 mod event_apply;
 pub mod event_debug;
 mod event_debug_actions;
+mod event_replay;
 mod file_explorer;
 pub mod file_open;
 mod file_open_input;
@@ -30,6 +33,8 @@ mod file_operations;
 mod help;
 mod help_actions;
 mod hover;
+mod indent_detection;
+mod indent_trim;
 mod input;
 mod input_dispatch;
 mod input_helpers;
@@ -58,6 +63,7 @@ mod prompt_actions;
 mod prompt_lifecycle;
 mod recovery_actions;
 mod regex_replace;
+mod registers;
 mod render;
 mod scan_orchestrators;
 mod scroll_sync;
@@ -112,6 +118,10 @@ pub fn editor_tick(
     if pending_file_opens {
         needs_render = true;
     }
+    if editor.process_pending_event_replay() {
+        needs_render = true;
+    }
+    editor.plugin_manager.flush_debounced_hooks();
     if editor.process_line_scan() {
         needs_render = true;
     }
@@ -139,6 +149,8 @@ pub fn editor_tick(
         needs_render = true;
     }
     editor.check_diagnostic_pull_timer();
+    editor.check_inlay_hints_timer();
+    editor.check_workspace_symbols_timer();
     if editor.check_warning_log() {
         needs_render = true;
     }
@@ -165,7 +177,8 @@ pub(crate) use path_utils::normalize_path;
 
 use self::types::{
     CachedLayout, FileExplorerContextMenu, InteractiveReplaceState, LspMessageEntry,
-    LspProgressInfo, MouseState, SearchState, TabContextMenu, DEFAULT_BACKGROUND_FILE,
+    LspProgressInfo, MouseState, SearchPreviewOrigin, SearchState, TabContextMenu,
+    DEFAULT_BACKGROUND_FILE,
 };
 use crate::config::Config;
 use crate::config_io::DirectoryContext;
@@ -178,6 +191,7 @@ use crate::input::quick_open::{
 };
 use crate::model::cursor::Cursors;
 use crate::model::event::{Event, EventLog, LeafId, SplitDirection};
+use crate::model::event_stream::{EventStreamSink, EventStreamWriter, RecordKind};
 use crate::model::filesystem::FileSystem;
 use crate::services::async_bridge::{AsyncBridge, AsyncMessage};
 use crate::services::fs::FsManager;
@@ -202,10 +216,11 @@ use ratatui::{
 use std::collections::{HashMap, HashSet};
 use std::ops::Range;
 use std::path::{Path, PathBuf};
-use std::sync::{Arc, RwLock};
+use std::sync::{Arc, Mutex, RwLock};
 use std::time::Instant;
 
 // Re-export BufferId from event module for backward compatibility
+pub use self::event_replay::ReplaySummary;
 pub use self::types::{BufferKind, BufferMetadata, HoverTarget};
 pub use self::warning_domains::{
     GeneralWarningDomain, LspWarningDomain, WarningAction, WarningActionId, WarningDomain,
@@ -275,6 +290,14 @@ struct InlayHintsRequest {
     version: u64,
 }
 
+#[derive(Clone, Debug)]
+struct FormattingRequest {
+    buffer_id: BufferId,
+    version: u64,
+    /// Re-save the buffer once the edits are applied (format-on-save).
+    save_after: bool,
+}
+
 /// State for the dabbrev cycling session (Alt+/ style).
 ///
 /// When the user presses Alt+/ repeatedly, we cycle through candidates
@@ -459,6 +482,11 @@ pub struct Editor {
     /// than on every frame.
     last_window_title: Option<String>,
 
+    /// The configured status bar `format` template and its parsed form,
+    /// so it is only re-parsed (and unknown placeholders only warned
+    /// about) when the template changes.
+    status_bar_format: Option<(String, crate::config::StatusBarFormat)>,
+
     /// Accumulated plugin errors (for test assertions)
     /// These are collected when plugin error messages are received
     plugin_errors: Vec<String>,
@@ -609,6 +637,11 @@ pub struct Editor {
     /// Whether mouse capture is enabled
     mouse_enabled: bool,
 
+    /// Theme the user switched to while this workspace was open. Saved with
+    /// the workspace and applied over the configured theme when it's
+    /// restored, without touching the config.
+    workspace_theme: Option<String>,
+
     /// Whether same-buffer splits sync their scroll positions
     same_buffer_scroll_sync: bool,
 
@@ -644,9 +677,24 @@ pub struct Editor {
     /// Pending LSP completion request IDs (supports multiple servers)
     pending_completion_requests: HashSet<u64>,
 
-    /// Original LSP completion items (for type-to-filter)
+    /// Server name for each pending completion request
+    pending_completion_server_names: HashMap<u64, String>,
+
+    /// Original LSP completion items (for type-to-filter), tagged with the
+    /// name of the server that sent them.
     /// Stored when completion popup is shown, used for re-filtering as user types
-    completion_items: Option<Vec<lsp_types::CompletionItem>>,
+    completion_items: Option<Vec<(String, lsp_types::CompletionItem)>>,
+
+    /// In-flight completionItem/resolve request fetching documentation for
+    /// the completion preview, as (request id, index into `completion_items`)
+    pending_completion_preview: Option<(u64, usize)>,
+
+    /// In-flight completionItem/resolve request fetching additional_text_edits
+    /// for an accepted completion, as (request id, buffer it was accepted in)
+    pending_completion_accept: Option<(u64, BufferId)>,
+
+    /// Multi-file rename waiting for confirmation in the rename preview popup
+    pending_rename_edit: Option<lsp_types::WorkspaceEdit>,
 
     /// Scheduled completion trigger time (for debounced quick suggestions)
     /// When Some, completion will be triggered when this instant is reached
@@ -661,6 +709,10 @@ pub struct Editor {
     /// dabbrev session. Reset when any other action is taken.
     dabbrev_state: Option<DabbrevCycleState>,
 
+    /// Blank line holding only auto-inserted indentation, trimmed once
+    /// the cursor leaves it untouched.
+    pending_indent_trim: Option<indent_trim::PendingIndentTrim>,
+
     /// Pending LSP go-to-definition request ID (if any)
     pending_goto_definition_request: Option<u64>,
 
@@ -670,6 +722,12 @@ pub struct Editor {
     /// Symbol name for pending references request
     pending_references_symbol: String,
 
+    /// Pending LSP document symbols request ID (if any)
+    pending_document_symbols_request: Option<u64>,
+
+    /// Pending LSP workspace symbol search request ID (if any)
+    pending_workspace_symbols_request: Option<u64>,
+
     /// Pending LSP signature help request ID (if any)
     pending_signature_help_request: Option<u64>,
 
@@ -696,6 +754,12 @@ pub struct Editor {
     ///     and `pending_semantic_token_requests`.
     pending_inlay_hints_requests: HashMap<u64, InlayHintsRequest>,
 
+    /// Pending LSP document formatting requests keyed by request id. The
+    /// buffer version at dispatch time lets us drop edits computed against
+    /// content the user has since changed. At most one entry per buffer —
+    /// a newer request supersedes an older one.
+    pending_formatting_requests: HashMap<u64, FormattingRequest>,
+
     /// Pending LSP folding range requests keyed by request ID
     pending_folding_range_requests: HashMap<u64, FoldingRangeRequest>,
 
@@ -742,6 +806,9 @@ pub struct Editor {
     /// Pending search range that should be reused when the next search is confirmed
     pending_search_range: Option<Range<usize>>,
 
+    /// Cursor and viewport to return to if the search prompt is cancelled
+    search_preview_origin: Option<SearchPreviewOrigin>,
+
     /// Interactive replace state (if interactive replace is active)
     interactive_replace_state: Option<InteractiveReplaceState>,
 
@@ -823,6 +890,12 @@ pub struct Editor {
     /// Maps process_id to abort handle
     background_process_handles: HashMap<u64, tokio::task::AbortHandle>,
 
+    /// Custom commands streaming into their output buffer, by buffer
+    custom_command_runs: HashMap<BufferId, custom_commands::CustomCommandRun>,
+
+    /// Id for the next custom command run
+    next_custom_command_run_id: u64,
+
     /// Cancellation senders for host-side processes spawned via
     /// `spawnHostProcess`. Firing the sender (or dropping it) triggers
     /// an in-task `child.start_kill()` so the process is reaped, not
@@ -865,6 +938,10 @@ pub struct Editor {
     /// on cancel or when the user clears the target from the input.
     goto_line_preview: Option<GotoLinePreviewSnapshot>,
 
+    /// Last command run from the command palette with its inline arguments,
+    /// replayed by Repeat Last Command.
+    last_palette_command: Option<(Action, Vec<String>)>,
+
     /// LSP progress tracking (token -> progress info)
     lsp_progress: std::collections::HashMap<String, LspProgressInfo>,
 
@@ -886,16 +963,31 @@ pub struct Editor {
     /// When set, diagnostics will be re-pulled when this instant is reached
     scheduled_diagnostic_pull: Option<(BufferId, Instant)>,
 
-    /// Scheduled inlay hints refresh time per buffer (debounced after didChange)
-    /// When set, inlay hints will be re-requested when this instant is reached
-    scheduled_inlay_hints_request: Option<(BufferId, Instant)>,
+    /// Scheduled inlay hints refresh time per buffer (debounced after didChange
+    /// or after scrolling past the hinted lines).
+    /// A buffer's inlay hints are re-requested when its instant is reached
+    scheduled_inlay_hints_requests: HashMap<BufferId, Instant>,
+
+    /// Scheduled workspace symbol search (debounced while typing in the
+    /// Workspace Symbols prompt). The query is read from the prompt when
+    /// this instant is reached.
+    scheduled_workspace_symbols_request: Option<Instant>,
+
+    /// Inclusive line span covered by the most recent inlay hints request per
+    /// buffer. Scrolling the viewport outside it schedules a fresh request.
+    inlay_hints_coverage: HashMap<BufferId, (usize, usize)>,
+
+    /// Line span last rendered per buffer, used to scope inlay hints requests
+    /// to the visible region. Absent until the buffer has been rendered.
+    inlay_hints_viewport: HashMap<BufferId, (usize, usize)>,
 
     /// Stored LSP diagnostics per URI, per server (push model - publishDiagnostics)
     /// Outer key: URI string, Inner key: server name
     stored_push_diagnostics: HashMap<String, HashMap<String, Vec<lsp_types::Diagnostic>>>,
 
-    /// Stored LSP diagnostics per URI (pull model - native RA diagnostics)
-    stored_pull_diagnostics: HashMap<String, Vec<lsp_types::Diagnostic>>,
+    /// Stored LSP diagnostics per URI, per server (pull model - native RA diagnostics)
+    /// Outer key: URI string, Inner key: server name
+    stored_pull_diagnostics: HashMap<String, HashMap<String, Vec<lsp_types::Diagnostic>>>,
 
     /// Merged view of push + pull diagnostics per URI (for plugin access).
     /// `Arc` wrapper: snapshot refresh is a refcount bump, and mutation is
@@ -924,6 +1016,10 @@ pub struct Editor {
     /// `last_register`, and the `playing` guard flag).
     macros: macros::MacroState,
 
+    /// Named clipboard registers and the numbered yank ring (the unnamed
+    /// register is `clipboard`).
+    registers: registers::RegisterState,
+
     /// Pending plugin action receivers (for async action execution)
     #[cfg(feature = "plugins")]
     pending_plugin_actions: Vec<(
@@ -967,6 +1063,14 @@ pub struct Editor {
     /// Last time we polled for directory changes (for file tree refresh)
     last_file_tree_poll: std::time::Instant,
 
+    /// Last time we polled the themes directory while the theme switcher
+    /// is open (for theme hot-reload)
+    last_theme_poll: std::time::Instant,
+
+    /// Modification times of user theme files, snapshotted while the theme
+    /// switcher is open. `None` when the switcher is closed.
+    theme_file_mod_times: Option<Vec<(PathBuf, std::time::SystemTime)>>,
+
     /// Whether we've resolved and seeded the .git/index path in dir_mod_times
     git_index_resolved: bool,
 
@@ -1006,6 +1110,10 @@ pub struct Editor {
     /// File open dialog state (when PromptType::OpenFile is active)
     file_open_state: Option<file_open::FileOpenState>,
 
+    /// In-flight directory listing for the file open dialog, aborted when a
+    /// newer listing supersedes it
+    file_open_listing_task: Option<tokio::task::AbortHandle>,
+
     /// Cached layout for file browser (for mouse hit testing)
     file_browser_layout: Option<crate::view::ui::FileBrowserLayout>,
 
@@ -1025,9 +1133,17 @@ pub struct Editor {
     /// Last auto-recovery-save time for rate limiting
     last_auto_recovery_save: std::time::Instant,
 
-    /// Last persistent auto-save time for rate limiting (disk)
+    /// Time of the last buffer edit; persistent auto-save (disk) waits for
+    /// this to be `auto_save_interval_secs` in the past
+    last_buffer_edit: std::time::Instant,
+
+    /// Last persistent auto-save attempt for rate limiting (disk)
     last_persistent_auto_save: std::time::Instant,
 
+    /// Consecutive persistent auto-save rounds with a failed write; each
+    /// one doubles the wait before the next attempt
+    persistent_auto_save_failures: u32,
+
     /// Active custom contexts for command visibility
     /// Plugin-defined contexts like "config-editor" that control command availability
     active_custom_contexts: HashSet<String>,
@@ -1142,6 +1258,13 @@ pub struct Editor {
     /// When true, apply hot exit recovery after the next batch of pending file opens
     pending_hot_exit_recovery: bool,
 
+    /// Event log to replay once the pending file opens have run (`--replay`)
+    pending_event_replay: Option<PathBuf>,
+
+    /// Structured event stream enabled by `--event-log`, shared by every
+    /// buffer's event log
+    event_stream: Option<Arc<Mutex<EventStreamWriter>>>,
+
     /// Tracks buffers opened with --wait: maps buffer_id → (wait_id, has_popup)
     wait_tracking: HashMap<BufferId, (u64, bool)>,
     /// Wait IDs that have completed (buffer closed or popup dismissed)
@@ -1297,7 +1420,12 @@ impl Editor {
 
     /// Set completion items for type-to-filter (for testing)
     pub fn set_completion_items(&mut self, items: Vec<lsp_types::CompletionItem>) {
-        self.completion_items = Some(items);
+        self.completion_items = Some(
+            items
+                .into_iter()
+                .map(|item| (String::new(), item))
+                .collect(),
+        );
     }
 
     /// Get the viewport for the active split
@@ -1357,6 +1485,29 @@ impl Editor {
         self.event_logs.get_mut(&self.active_buffer()).unwrap()
     }
 
+    /// Create an empty event log for `buffer_id` with the configured undo
+    /// history limits, streaming to the event stream if one is enabled
+    pub(crate) fn new_event_log(&self, buffer_id: BufferId) -> EventLog {
+        let mut log = EventLog::with_limits(
+            self.config.editor.undo_history_max_groups,
+            self.config.editor.undo_history_max_bytes,
+        );
+        log.set_stream(
+            self.event_stream
+                .as_ref()
+                .map(|writer| EventStreamSink::new(writer.clone(), buffer_id)),
+        );
+        log
+    }
+
+    /// Append a record for the active buffer to the event stream, if one
+    /// is enabled
+    pub(crate) fn record_to_event_stream(&self, kind: RecordKind) {
+        if let Some(writer) = &self.event_stream {
+            EventStreamSink::new(writer.clone(), self.active_buffer()).record(kind);
+        }
+    }
+
     /// Update the buffer's modified flag based on event log position
     /// Call this after undo/redo to correctly track whether the buffer
     /// has returned to its saved state
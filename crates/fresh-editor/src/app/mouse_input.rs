@@ -32,6 +32,12 @@ impl Editor {
     ) -> AnyhowResult<bool> {
         use crossterm::event::{MouseButton, MouseEventKind};
 
+        // With mouse capture off, the terminal owns the mouse; drop any
+        // events still in flight
+        if !self.mouse_enabled {
+            return Ok(false);
+        }
+
         let col = mouse_event.column;
         let row = mouse_event.row;
 
@@ -267,6 +273,10 @@ impl Editor {
                 }
                 needs_render = true;
             }
+            MouseEventKind::Down(MouseButton::Middle) => {
+                self.handle_mouse_middle_click(col, row)?;
+                needs_render = true;
+            }
             _ => {
                 // Ignore other mouse events for now
             }
@@ -1303,6 +1313,59 @@ impl Editor {
         Ok(())
     }
 
+    /// Handle middle click (down event): paste at the clicked position, X11
+    /// primary-selection style. Pastes the active selection when there is
+    /// one, otherwise the clipboard.
+    pub(super) fn handle_mouse_middle_click(&mut self, col: u16, row: u16) -> AnyhowResult<()> {
+        if self.is_mouse_over_any_popup(col, row) {
+            return Ok(());
+        }
+        let Some((split_id, buffer_id, content_rect)) = self
+            .cached_layout
+            .split_areas
+            .iter()
+            .find(|(_, _, content_rect, _, _, _)| in_rect(col, row, *content_rect))
+            .map(|(split_id, buffer_id, content_rect, _, _, _)| {
+                (*split_id, *buffer_id, *content_rect)
+            })
+        else {
+            return Ok(());
+        };
+        if self
+            .buffers
+            .get(&buffer_id)
+            .is_some_and(|state| state.editing_disabled)
+        {
+            self.set_status_message(t!("buffer.editing_disabled").to_string());
+            return Ok(());
+        }
+
+        let selection_range = self.active_cursors().primary().selection_range();
+        let selection = selection_range.map(|range| {
+            self.active_state_mut()
+                .get_text_range(range.start, range.end)
+        });
+
+        self.handle_editor_click(
+            col,
+            row,
+            split_id,
+            buffer_id,
+            content_rect,
+            crossterm::event::KeyModifiers::NONE,
+        )?;
+        // A middle click doesn't start a drag selection
+        self.mouse_state.dragging_text_selection = false;
+        self.mouse_state.drag_selection_split = None;
+        self.mouse_state.drag_selection_anchor = None;
+
+        match selection {
+            Some(text) => self.paste_text(text),
+            None => self.paste(),
+        }
+        Ok(())
+    }
+
     /// Handle mouse click (down event)
     pub(super) fn handle_mouse_click(
         &mut self,
@@ -1753,7 +1816,9 @@ impl Editor {
             )?;
 
         self.focus_split(split_id, buffer_id);
-        if is_on_thumb {
+        // The minimap has no thumb to grab: every press jumps to its row
+        let minimap = self.config.editor.scrollbar_minimap && !self.is_composite_buffer(buffer_id);
+        if is_on_thumb && !minimap {
             self.mouse_state.dragging_scrollbar = Some(split_id);
             self.mouse_state.drag_start_row = Some(row);
             if self.is_composite_buffer(buffer_id) {
@@ -2339,6 +2404,21 @@ impl Editor {
                 return Ok(());
             };
 
+            // Dragging past the top or bottom edge extends the selection to
+            // the line beyond the view; keeping the cursor visible on the
+            // next render then scrolls that line in.
+            let target_position = if row < content_rect.y {
+                let mut iter = state.buffer.line_iterator(target_position, 80);
+                let line_start = iter.current_position();
+                iter.prev().map_or(line_start, |(start, _)| start)
+            } else if row >= content_rect.y + content_rect.height {
+                let mut iter = state.buffer.line_iterator(target_position, 80);
+                iter.next_line();
+                iter.current_position()
+            } else {
+                target_position
+            };
+
             // When drag started with double-click, snap to word boundaries.
             // When dragging forward, anchor at word start and extend to word end.
             // When dragging backward, anchor at word end and extend to word start,
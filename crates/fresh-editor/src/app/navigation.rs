@@ -17,13 +17,15 @@
 //! Use [`Editor::ensure_active_cursor_visible_for_navigation`] right after
 //! any explicit cursor mutation that represents a user-visible jump. Use
 //! [`Editor::jump_active_cursor_to`] when the call site can also delegate
-//! the cursor mutation itself.
+//! the cursor mutation itself, or [`Editor::move_primary_cursor`] when the
+//! jump must go through a `MoveCursor` event.
 //!
 //! Edits (typing, paste, indent, …) should keep using the existing
 //! `ensure_cursor_visible` path — they want the "don't undo a deliberate
 //! scroll" behavior of the skip flag.
 
 use crate::model::buffer::LineNumber;
+use crate::model::event::Event;
 
 use super::Editor;
 
@@ -69,7 +71,7 @@ impl Editor {
     /// visibility invariant.
     ///
     /// Callers that need a `MoveCursor` event (undo + plugin hooks) should
-    /// build the event themselves and call
+    /// use [`Editor::move_primary_cursor`] and call
     /// [`Editor::ensure_active_cursor_visible_for_navigation`] afterwards.
     pub fn jump_active_cursor_to(&mut self, position: usize, opts: JumpOptions) {
         let active_split = self.split_manager.active_split();
@@ -88,6 +90,38 @@ impl Editor {
         self.ensure_active_cursor_visible_for_navigation(opts.recenter_on_scroll);
     }
 
+    /// Move the active buffer's primary cursor to `position` through a
+    /// logged `MoveCursor` event, dropping the selection anchor and sticky
+    /// column. Does not scroll; see the module docs.
+    pub(crate) fn move_primary_cursor(&mut self, position: usize) {
+        let cursor_id = self.active_cursors().primary_id();
+        let cursor = *self.active_cursors().primary();
+        if cursor.position == position && cursor.anchor.is_none() {
+            return;
+        }
+        let event = Event::MoveCursor {
+            cursor_id,
+            old_position: cursor.position,
+            new_position: position,
+            old_anchor: cursor.anchor,
+            new_anchor: None,
+            old_sticky_column: cursor.sticky_column,
+            new_sticky_column: 0,
+        };
+        self.log_and_apply_event(&event);
+    }
+
+    /// Record the primary cursor's current location in position history as
+    /// a standalone entry, so Navigate Back returns there after a jump.
+    pub(crate) fn record_jump_origin(&mut self) {
+        let buffer_id = self.active_buffer();
+        let cursor = *self.active_cursors().primary();
+        self.position_history.commit_pending_movement();
+        self.position_history
+            .record_movement(buffer_id, cursor.position, cursor.anchor);
+        self.position_history.commit_pending_movement();
+    }
+
     /// Guarantee the active cursor is visible in the active viewport.
     ///
     /// Call this immediately after any cursor mutation that represents a
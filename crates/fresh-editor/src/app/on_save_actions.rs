@@ -24,18 +24,35 @@ enum ActionResult {
     Error(String),
 }
 
-impl Editor {
-    /// Run on-save actions for the active buffer after a successful save.
-    /// This includes format-on-save (if enabled) and any on_save actions.
-    /// Returns Ok(true) if actions ran successfully, Ok(false) if no actions,
-    /// or Err with an error message.
-    pub fn run_on_save_actions(&mut self) -> Result<bool, String> {
-        let path = match self.active_state().buffer.file_path() {
-            Some(p) => p.to_path_buf(),
-            None => return Ok(false),
-        };
+/// What the on-save actions of one save did, for the save's status message
+#[derive(Debug, Default)]
+pub(crate) struct OnSaveReport {
+    /// At least one action ran
+    pub ran_action: bool,
+    /// Message an action left for the user (e.g. a missing formatter),
+    /// shown instead of the saved message
+    pub notice: Option<String>,
+}
 
+impl OnSaveReport {
+    /// Combine this report with one from actions that ran after it
+    pub(crate) fn merge(self, later: OnSaveReport) -> OnSaveReport {
+        OnSaveReport {
+            ran_action: self.ran_action || later.ran_action,
+            notice: later.notice.or(self.notice),
+        }
+    }
+}
+
+impl Editor {
+    /// Run the on-save edits that only need the buffer, before it is
+    /// written: whitespace cleanup, then format-on-save with a formatter
+    /// that reads the buffer from stdin. The file is then saved once,
+    /// already cleaned up and formatted.
+    /// Returns what ran, or Err with an error message.
+    pub(crate) fn run_pre_save_actions(&mut self) -> Result<OnSaveReport, String> {
         let mut ran_any_action = false;
+        let mut notice = None;
 
         // Run whitespace cleanup actions first (before formatter)
         if self.config.editor.trim_trailing_whitespace_on_save && self.trim_trailing_whitespace()? {
@@ -46,45 +63,100 @@ impl Editor {
             ran_any_action = true;
         }
 
-        // If whitespace cleanup made changes, re-save
-        if ran_any_action {
-            if let Err(e) = self.active_state_mut().buffer.save() {
-                return Err(format!("Failed to re-save after whitespace cleanup: {}", e));
+        let Some(path) = self
+            .active_state()
+            .buffer
+            .file_path()
+            .map(|p| p.to_path_buf())
+        else {
+            return Ok(OnSaveReport {
+                ran_action: ran_any_action,
+                notice,
+            });
+        };
+        let formatter = self
+            .config
+            .languages
+            .get(&self.active_state().language)
+            .filter(|lc| lc.format_on_save)
+            .and_then(|lc| lc.formatter.clone())
+            .filter(|formatter| formatter.stdin);
+
+        if let Some(formatter) = formatter {
+            match self.run_formatter(&formatter, &path) {
+                ActionResult::Success(output) => {
+                    self.replace_buffer_with_output(&output)?;
+                    ran_any_action = true;
+                }
+                ActionResult::CommandNotFound(cmd) => {
+                    notice = Some(format!(
+                        "Formatter '{}' not found (install it for auto-formatting)",
+                        cmd
+                    ));
+                }
+                ActionResult::Error(e) => {
+                    return Err(e);
+                }
             }
-            self.active_event_log_mut().mark_saved();
         }
 
+        Ok(OnSaveReport {
+            ran_action: ran_any_action,
+            notice,
+        })
+    }
+
+    /// Run on-save actions for the active buffer after a successful save.
+    /// This includes format-on-save with a formatter that reads the file
+    /// from disk or with the LSP server, and any on_save actions. Whitespace
+    /// cleanup and stdin formatters already ran in `run_pre_save_actions`.
+    /// Returns what ran, or Err with an error message.
+    pub(crate) fn run_on_save_actions(&mut self) -> Result<OnSaveReport, String> {
+        let path = match self.active_state().buffer.file_path() {
+            Some(p) => p.to_path_buf(),
+            None => return Ok(OnSaveReport::default()),
+        };
+
+        let mut ran_any_action = false;
+        let mut notice = None;
+
         // Get language from buffer's stored state
         let language = self.active_state().language.clone();
 
         let lang_config = match self.config.languages.get(&language) {
             Some(lc) => lc.clone(),
-            None => return Ok(ran_any_action),
+            None => return Ok(OnSaveReport::default()),
         };
 
-        // Run formatter if format_on_save is enabled
+        // Run formatter if format_on_save is enabled. One that reads stdin
+        // already ran before the write.
         if lang_config.format_on_save {
             if let Some(ref formatter) = lang_config.formatter {
-                match self.run_formatter(formatter, &path) {
-                    ActionResult::Success(output) => {
-                        self.replace_buffer_with_output(&output)?;
-                        // Re-save after formatting
-                        if let Err(e) = self.active_state_mut().buffer.save() {
-                            return Err(format!("Failed to re-save after format: {}", e));
+                if !formatter.stdin {
+                    match self.run_formatter(formatter, &path) {
+                        ActionResult::Success(output) => {
+                            self.replace_buffer_with_output(&output)?;
+                            // The formatter read the file from disk, so its
+                            // output can only be saved with a second write
+                            self.resave_active_buffer(path.clone())
+                                .map_err(|e| format!("Failed to re-save after format: {}", e))?;
+                            ran_any_action = true;
+                        }
+                        ActionResult::CommandNotFound(cmd) => {
+                            notice = Some(format!(
+                                "Formatter '{}' not found (install it for auto-formatting)",
+                                cmd
+                            ));
+                        }
+                        ActionResult::Error(e) => {
+                            return Err(e);
                         }
-                        self.active_event_log_mut().mark_saved();
-                        ran_any_action = true;
-                    }
-                    ActionResult::CommandNotFound(cmd) => {
-                        self.status_message = Some(format!(
-                            "Formatter '{}' not found (install it for auto-formatting)",
-                            cmd
-                        ));
-                    }
-                    ActionResult::Error(e) => {
-                        return Err(e);
                     }
                 }
+            } else if self.request_formatting(true) {
+                // No external formatter: the LSP edits arrive asynchronously
+                // and the buffer is re-saved once they've been applied.
+                ran_any_action = true;
             }
         }
 
@@ -110,7 +182,21 @@ impl Editor {
             }
         }
 
-        Ok(ran_any_action)
+        Ok(OnSaveReport {
+            ran_action: ran_any_action,
+            notice,
+        })
+    }
+
+    /// Write the active buffer to `path` again after an on-save action
+    /// changed it, through `write_buffer` so plugins still get their say.
+    /// The finalize is silent, so on-save actions don't run again.
+    fn resave_active_buffer(&mut self, path: std::path::PathBuf) -> anyhow::Result<()> {
+        let buffer_id = self.active_buffer();
+        if self.write_buffer(buffer_id, &path)? {
+            self.finalize_save_buffer(buffer_id, Some(path), true)?;
+        }
+        Ok(())
     }
 
     /// Format the current buffer using the configured formatter.
@@ -139,7 +225,7 @@ impl Editor {
             Some(f) => f,
             None => {
                 // No external formatter — try LSP formatting
-                self.request_formatting();
+                self.request_formatting(false);
                 return Ok(());
             }
         };
@@ -550,6 +636,54 @@ impl Editor {
         Ok(true)
     }
 
+    /// Convert the leading whitespace of every line in the active buffer to
+    /// the buffer's indent style (`use_tabs`) at its `tab_size`.
+    /// Returns Ok(true) if any changes were made, Ok(false) if buffer unchanged.
+    pub fn retab_buffer(&mut self) -> Result<bool, String> {
+        let content = self.active_state().buffer.to_string().unwrap_or_default();
+        let settings = &self.active_state().buffer_settings;
+        let retabbed = crate::primitives::indent::retab_leading_whitespace(
+            &content,
+            settings.use_tabs,
+            settings.tab_size,
+        );
+
+        if retabbed == content {
+            return Ok(false);
+        }
+
+        self.replace_buffer_with_output(&retabbed)?;
+        Ok(true)
+    }
+
+    /// Rewrite every line break in the active buffer to the buffer's line
+    /// ending, as a single undoable edit. Clears the mixed-endings flag.
+    /// Returns Ok(true) if any changes were made, Ok(false) if buffer unchanged.
+    pub fn normalize_line_endings(&mut self) -> Result<bool, String> {
+        let buffer = &self.active_state().buffer;
+        let target = buffer.line_ending().as_str();
+        let Some(content) = buffer.to_string() else {
+            return Err(t!("error.buffer_not_loaded").to_string());
+        };
+
+        let normalized = content.replace("\r\n", "\n").replace('\r', "\n");
+        let normalized = if target == "\n" {
+            normalized
+        } else {
+            normalized.replace('\n', target)
+        };
+
+        if normalized == content {
+            self.active_state_mut().buffer.set_mixed_line_endings(false);
+            return Ok(false);
+        }
+
+        // Only a successful rewrite makes the endings uniform
+        self.replace_buffer_with_output(&normalized)?;
+        self.active_state_mut().buffer.set_mixed_line_endings(false);
+        Ok(true)
+    }
+
     /// Ensure the buffer ends with a newline.
     /// Returns Ok(true) if a newline was added, Ok(false) if already ends with newline.
     pub fn ensure_final_newline(&mut self) -> Result<bool, String> {
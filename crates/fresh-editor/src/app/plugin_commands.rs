@@ -4,12 +4,13 @@
 
 use crate::model::cursor::Cursors;
 use crate::model::event::{BufferId, ContainerId, CursorId, Event, LeafId, OverlayFace, SplitId};
+use crate::services::fs::WalkGitignores;
 use crate::view::overlay::{OverlayHandle, OverlayNamespace};
 use crate::view::split::SplitViewState;
 use anyhow::Result as AnyhowResult;
 use fresh_core::api::{
     GrepMatch, JsCallbackId, LayoutHints, MenuPosition, OverlayOptions, PluginResponse,
-    ReplaceResult, ViewTransformPayload,
+    ReplaceResult, ViewTransformPayload, VirtualTextAnchor, VirtualTextStyle,
 };
 
 use super::Editor;
@@ -198,45 +199,14 @@ impl Editor {
     ) {
         if let Some(state) = self.buffers.get_mut(&buffer_id) {
             use crate::view::virtual_text::VirtualTextPosition;
-            use fresh_core::api::OverlayColorSpec;
-            use ratatui::style::{Color, Modifier, Style};
 
             let vtext_position = if before {
                 VirtualTextPosition::BeforeChar
             } else {
                 VirtualTextPosition::AfterChar
             };
-
-            // Build a fallback style from any concrete RGB values; theme
-            // keys are passed through separately so the renderer can
-            // resolve them on each frame.
-            let mut fallback = Style::default();
-            let mut fg_theme_key: Option<String> = None;
-            let mut bg_theme_key: Option<String> = None;
-            match &fg {
-                Some(OverlayColorSpec::Rgb(r, g, b)) => {
-                    fallback = fallback.fg(Color::Rgb(*r, *g, *b));
-                }
-                Some(OverlayColorSpec::ThemeKey(k)) => {
-                    fg_theme_key = Some(k.clone());
-                }
-                None => {}
-            }
-            match &bg {
-                Some(OverlayColorSpec::Rgb(r, g, b)) => {
-                    fallback = fallback.bg(Color::Rgb(*r, *g, *b));
-                }
-                Some(OverlayColorSpec::ThemeKey(k)) => {
-                    bg_theme_key = Some(k.clone());
-                }
-                None => {}
-            }
-            if bold {
-                fallback = fallback.add_modifier(Modifier::BOLD);
-            }
-            if italic {
-                fallback = fallback.add_modifier(Modifier::ITALIC);
-            }
+            let (fallback, fg_theme_key, bg_theme_key) =
+                virtual_text_style(fg.as_ref(), bg.as_ref(), bold, italic);
 
             // Replace any existing virtual text with this ID.
             state
@@ -257,6 +227,57 @@ impl Editor {
         }
     }
 
+    /// Handle SetVirtualText command
+    pub(super) fn handle_set_virtual_text(
+        &mut self,
+        buffer_id: BufferId,
+        anchor: VirtualTextAnchor,
+        text: String,
+        style: VirtualTextStyle,
+        id: String,
+    ) {
+        use crate::view::virtual_text::VirtualTextPosition;
+
+        let Some(state) = self.buffers.get_mut(&buffer_id) else {
+            return;
+        };
+        let len = state.buffer.len();
+        let (position, vtext_position) = match anchor {
+            VirtualTextAnchor::Before { position } => {
+                (position.min(len), VirtualTextPosition::BeforeChar)
+            }
+            VirtualTextAnchor::After { position } => {
+                (position.min(len), VirtualTextPosition::AfterChar)
+            }
+            VirtualTextAnchor::EndOfLine { position } => (
+                line_terminator_position(state, position.min(len)),
+                VirtualTextPosition::EndOfLine,
+            ),
+        };
+        let (fallback, fg_theme_key, bg_theme_key) = virtual_text_style(
+            style.fg.as_ref(),
+            style.bg.as_ref(),
+            style.bold,
+            style.italic,
+        );
+
+        state
+            .virtual_texts
+            .remove_by_id(&mut state.marker_list, &id);
+        let vtext_id = state.virtual_texts.add_with_id_and_theme_keys(
+            &mut state.marker_list,
+            position,
+            text,
+            fallback,
+            fg_theme_key,
+            bg_theme_key,
+            vtext_position,
+            0, // priority
+            id,
+        );
+        state.virtual_texts.set_reflow(vtext_id, true);
+    }
+
     /// Handle RemoveVirtualText command
     pub(super) fn handle_remove_virtual_text(
         &mut self,
@@ -929,6 +950,15 @@ impl Editor {
         }
     }
 
+    /// Handle AppendToBuffer command
+    pub(super) fn handle_append_to_buffer(&mut self, buffer_id: BufferId, text: String) {
+        let Some(end) = self.buffers.get(&buffer_id).map(|state| state.buffer.len()) else {
+            tracing::warn!("AppendToBuffer: buffer {:?} not found", buffer_id);
+            return;
+        };
+        self.handle_insert_text(buffer_id, end, text);
+    }
+
     /// Handle DeleteRange command
     pub(super) fn handle_delete_range(
         &mut self,
@@ -960,6 +990,74 @@ impl Editor {
         }
     }
 
+    /// Handle ReplaceRange command: the deletion and insertion are logged
+    /// as one batch so a single undo restores the original text
+    pub(super) fn handle_replace_range(
+        &mut self,
+        buffer_id: BufferId,
+        start: usize,
+        end: usize,
+        text: String,
+        request_id: u64,
+    ) {
+        let callback_id = fresh_core::api::JsCallbackId::from(request_id);
+        let Some(state) = self.buffers.get_mut(&buffer_id) else {
+            self.plugin_manager
+                .reject_callback(callback_id, format!("Buffer {:?} not found", buffer_id));
+            return;
+        };
+        let range = match plugin_text_range(&state.buffer, start, end) {
+            Ok(range) => range,
+            Err(error) => {
+                self.plugin_manager.reject_callback(callback_id, error);
+                return;
+            }
+        };
+
+        let delete_len = range.len();
+        let insert_len = text.len();
+        let mut events = Vec::new();
+        if !range.is_empty() {
+            events.push(Event::Delete {
+                range: range.clone(),
+                deleted_text: state.get_text_range(range.start, range.end),
+                cursor_id: CursorId(0),
+            });
+        }
+        if !text.is_empty() {
+            events.push(Event::Insert {
+                position: range.start,
+                text,
+                cursor_id: CursorId(0),
+            });
+        }
+        let event = match events.len() {
+            0 => None,
+            1 => events.pop(),
+            _ => Some(Event::Batch {
+                events,
+                description: "Plugin replace".to_string(),
+            }),
+        };
+        if let Some(event) = event {
+            // Apply to buffer with dummy cursors (real cursors adjusted below)
+            state.apply(&mut Cursors::default(), &event);
+            if let Some(log) = self.event_logs.get_mut(&buffer_id) {
+                log.append(event);
+            }
+            for leaf_id in self.split_manager.splits_for_buffer(buffer_id) {
+                if let Some(view_state) = self.split_view_states.get_mut(&leaf_id) {
+                    view_state
+                        .cursors
+                        .adjust_for_edit(range.start, delete_len, insert_len);
+                }
+            }
+        }
+
+        self.plugin_manager
+            .resolve_callback(callback_id, "true".to_string());
+    }
+
     /// Handle InsertAtCursor command
     pub(super) fn handle_insert_at_cursor(&mut self, text: String) {
         // Read cursor position first to avoid borrow conflicts
@@ -1563,6 +1661,7 @@ impl Editor {
                     disabled: s.disabled.unwrap_or(false),
                     keybinding: s.keybinding,
                     source,
+                    match_positions: Vec::new(),
                 }
             })
             .collect();
@@ -2115,11 +2214,14 @@ impl Editor {
         let cwd = self.working_dir.clone();
         let cancel = std::sync::atomic::AtomicBool::new(false);
         let mut file_paths: Vec<std::path::PathBuf> = Vec::new();
+        let mut gitignores = WalkGitignores::new(&*self.authority.filesystem, &cwd);
         if let Err(e) =
             self.authority
                 .filesystem
                 .walk_files(&cwd, IGNORED_DIRS, &cancel, &mut |path, _rel| {
-                    file_paths.push(path.to_path_buf());
+                    if !gitignores.is_ignored(path) {
+                        file_paths.push(path.to_path_buf());
+                    }
                     true
                 })
         {
@@ -2304,12 +2406,16 @@ impl Editor {
                     search_id
                 );
                 let mut file_count = 0usize;
+                let mut gitignores = WalkGitignores::new(&*filesystem_walker, &cwd);
 
                 if let Err(e) = filesystem_walker.walk_files(
                     &cwd,
                     IGNORED_DIRS,
                     &cancel_walker,
                     &mut |path, _rel| {
+                        if gitignores.is_ignored(path) {
+                            return true;
+                        }
                         file_count += 1;
                         path_tx.blocking_send(path.to_path_buf()).is_ok()
                     },
@@ -2606,7 +2712,7 @@ impl Editor {
         // via the standard event log machinery.  Project replace has no
         // meaningful cursor positions to restore on undo, so we pass empty
         // cursor lists.
-        let mut saved_path: Option<std::path::PathBuf> = None;
+        let mut buffer_path: Option<std::path::PathBuf> = None;
         let bulk_edit_event = if let Some(state) = self.buffers.get_mut(&buffer_id) {
             let old_snapshot = state.buffer.snapshot_buffer_state();
             let displaced_markers = state.capture_displaced_markers_bulk(&edits_owned);
@@ -2637,23 +2743,7 @@ impl Editor {
             state.highlighter.invalidate_all();
 
             let new_snapshot = state.buffer.snapshot_buffer_state();
-
-            // Save the buffer via the FileSystem trait.  Capture the path
-            // into the outer `saved_path` so we can refresh the watched
-            // mtime after dropping the &mut self borrow on `state` —
-            // otherwise the auto-revert poller sees the new mtime, treats
-            // it as an external change, and reverts the buffer from disk,
-            // wiping the event log we're about to append (see bug #1).
-            if let Some(path) = state.buffer.file_path().map(|p| p.to_path_buf()) {
-                if let Err(e) = state.buffer.save_to_file(&path) {
-                    self.plugin_manager.reject_callback(
-                        callback_id,
-                        format!("Failed to save file {:?}: {}", path, e),
-                    );
-                    return;
-                }
-                saved_path = Some(path);
-            }
+            buffer_path = state.buffer.file_path().map(|p| p.to_path_buf());
 
             Some(Event::BulkEdit {
                 old_snapshot: Some(old_snapshot),
@@ -2672,6 +2762,27 @@ impl Editor {
             None
         };
 
+        // Save the buffer (unless a `before_file_save` handler vetoes it,
+        // which leaves the replacements as unsaved changes).  Remember the
+        // path so we can refresh the watched mtime — otherwise the
+        // auto-revert poller sees the new mtime, treats it as an external
+        // change, and reverts the buffer from disk, wiping the event log
+        // we're about to append (see bug #1).
+        let mut saved_path: Option<std::path::PathBuf> = None;
+        if let (Some(_), Some(path)) = (&bulk_edit_event, buffer_path) {
+            match self.write_buffer(buffer_id, &path) {
+                Ok(true) => saved_path = Some(path),
+                Ok(false) => {}
+                Err(e) => {
+                    self.plugin_manager.reject_callback(
+                        callback_id,
+                        format!("Failed to save file {:?}: {}", path, e),
+                    );
+                    return;
+                }
+            }
+        }
+
         // Refresh the watched mtime for the just-saved file so the
         // auto-revert poller does NOT treat our own save as an external
         // change.  Without this, `handle_file_changed` → `revert_buffer_by_id`
@@ -2693,7 +2804,9 @@ impl Editor {
                 // the modified flag — leaving the user with a reverted buffer
                 // that looks clean even though disk still has the XYZ
                 // content.  We want the tab to show `a.txt*` after undo.
-                event_log.mark_saved();
+                if saved_path.is_some() {
+                    event_log.mark_saved();
+                }
             }
             self.invalidate_layouts_for_buffer(buffer_id);
 
@@ -2848,6 +2961,87 @@ fn translate_plugin_animation_kind(
     }
 }
 
+/// Check a plugin-supplied byte range against the buffer and widen it to
+/// UTF-8 character boundaries, so it never splits a character.
+pub(super) fn plugin_text_range(
+    buffer: &crate::model::buffer::Buffer,
+    start: usize,
+    end: usize,
+) -> Result<std::ops::Range<usize>, String> {
+    let len = buffer.len();
+    if start > end || end > len {
+        return Err(format!(
+            "Invalid range {}..{} for buffer of length {}",
+            start, end, len
+        ));
+    }
+    let start = buffer.snap_to_char_boundary(start);
+    let end = if buffer.snap_to_char_boundary(end) == end {
+        end
+    } else {
+        buffer.next_char_boundary(end)
+    };
+    Ok(start..end)
+}
+
+/// Build the fallback style and theme keys for plugin virtual text. RGB
+/// colors go into the style; theme keys are kept for the renderer to
+/// resolve on each frame.
+fn virtual_text_style(
+    fg: Option<&fresh_core::api::OverlayColorSpec>,
+    bg: Option<&fresh_core::api::OverlayColorSpec>,
+    bold: bool,
+    italic: bool,
+) -> (ratatui::style::Style, Option<String>, Option<String>) {
+    use fresh_core::api::OverlayColorSpec;
+    use ratatui::style::{Color, Modifier, Style};
+
+    let mut fallback = Style::default();
+    let mut fg_theme_key: Option<String> = None;
+    let mut bg_theme_key: Option<String> = None;
+    match fg {
+        Some(OverlayColorSpec::Rgb(r, g, b)) => {
+            fallback = fallback.fg(Color::Rgb(*r, *g, *b));
+        }
+        Some(OverlayColorSpec::ThemeKey(k)) => {
+            fg_theme_key = Some(k.clone());
+        }
+        None => {}
+    }
+    match bg {
+        Some(OverlayColorSpec::Rgb(r, g, b)) => {
+            fallback = fallback.bg(Color::Rgb(*r, *g, *b));
+        }
+        Some(OverlayColorSpec::ThemeKey(k)) => {
+            bg_theme_key = Some(k.clone());
+        }
+        None => {}
+    }
+    if bold {
+        fallback = fallback.add_modifier(Modifier::BOLD);
+    }
+    if italic {
+        fallback = fallback.add_modifier(Modifier::ITALIC);
+    }
+    (fallback, fg_theme_key, bg_theme_key)
+}
+
+/// Byte offset of the line ending of the line containing `pos`: the `\r` of
+/// a CRLF pair (where the renderer places the line break), the `\n`
+/// otherwise, or the buffer end for a last line without one.
+fn line_terminator_position(state: &mut crate::state::EditorState, pos: usize) -> usize {
+    let end = crate::view::folding::indent_folding::find_line_end_byte(&state.buffer, pos);
+    if end == 0 || state.get_text_range(end - 1, end) != "\n" {
+        return end;
+    }
+    let newline = end - 1;
+    if newline > 0 && state.get_text_range(newline - 1, newline) == "\r" {
+        newline - 1
+    } else {
+        newline
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::app::Editor;
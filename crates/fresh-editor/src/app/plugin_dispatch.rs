@@ -19,6 +19,7 @@ use fresh_core::api::{BufferSavedDiff, JsCallbackId, PluginCommand};
 
 use crate::model::event::{BufferId, LeafId, SplitId};
 use crate::services::async_bridge::AsyncMessage;
+use crate::services::process_limits::spawn_limited;
 use crate::view::split::SplitViewState;
 
 use super::Editor;
@@ -462,6 +463,15 @@ impl Editor {
                     before,
                 );
             }
+            PluginCommand::SetVirtualText {
+                buffer_id,
+                anchor,
+                text,
+                style,
+                id,
+            } => {
+                self.handle_set_virtual_text(buffer_id, anchor, text, style, id);
+            }
             PluginCommand::RemoveVirtualText {
                 buffer_id,
                 virtual_text_id,
@@ -1026,6 +1036,17 @@ impl Editor {
             PluginCommand::GetTextPropertiesAtCursor { buffer_id } => {
                 self.handle_get_text_properties_at_cursor(buffer_id);
             }
+            PluginCommand::OpenScratchBuffer {
+                name,
+                content,
+                language,
+                request_id,
+            } => {
+                self.handle_open_scratch_buffer(name, content, language, request_id);
+            }
+            PluginCommand::AppendToBuffer { buffer_id, text } => {
+                self.handle_append_to_buffer(buffer_id, text);
+            }
             PluginCommand::CreateVirtualBufferInExistingSplit {
                 name,
                 mode,
@@ -1076,8 +1097,19 @@ impl Editor {
                 end,
                 request_id,
             } => {
+                let buffer_id = self.resolve_buffer_id(buffer_id);
                 self.handle_get_buffer_text(buffer_id, start, end, request_id);
             }
+            PluginCommand::ReplaceRange {
+                buffer_id,
+                start,
+                end,
+                text,
+                request_id,
+            } => {
+                let buffer_id = self.resolve_buffer_id(buffer_id);
+                self.handle_replace_range(buffer_id, start, end, text, request_id);
+            }
             PluginCommand::GetLineStartPosition {
                 buffer_id,
                 line,
@@ -1316,13 +1348,19 @@ impl Editor {
 
     /// Save a buffer to a specific file path (for :w filename)
     fn handle_save_buffer_to_path(&mut self, buffer_id: BufferId, path: std::path::PathBuf) {
-        if let Some(state) = self.buffers.get_mut(&buffer_id) {
-            // Save to the specified path
-            match state.buffer.save_to_file(&path) {
-                Ok(()) => {
-                    // save_to_file already updates file_path internally via finalize_save
-                    // Run on-save actions (formatting, etc.)
-                    if let Err(e) = self.finalize_save(Some(path)) {
+        if self.buffers.contains_key(&buffer_id) {
+            // Save to the specified path, cleaned up and formatted
+            match self.write_buffer_with_pre_save_actions(buffer_id, &path) {
+                // A plugin vetoed the save and set the status
+                Ok(None) => {}
+                Ok(Some(pre_save)) => {
+                    // save_to_file already updates file_path internally via finalize_save.
+                    // On-save actions (formatting, etc.) work on the active
+                    // buffer, so they only run when that's the one saved.
+                    let pre_save = (buffer_id == self.active_buffer()).then_some(pre_save);
+                    if let Err(e) =
+                        self.finalize_save_after_actions(buffer_id, Some(path), pre_save)
+                    {
                         tracing::warn!("Failed to finalize save: {}", e);
                     }
                     tracing::debug!("Saved buffer {:?} to path", buffer_id);
@@ -1463,15 +1501,8 @@ impl Editor {
     ) {
         let result = if let Some(state) = self.buffers.get_mut(&buffer_id) {
             // Get text from the buffer using the mutable get_text_range method
-            let len = state.buffer.len();
-            if start <= end && end <= len {
-                Ok(state.get_text_range(start, end))
-            } else {
-                Err(format!(
-                    "Invalid range {}..{} for buffer of length {}",
-                    start, end, len
-                ))
-            }
+            super::plugin_commands::plugin_text_range(&state.buffer, start, end)
+                .map(|range| state.get_text_range(range.start, range.end))
         } else {
             Err(format!("Buffer {:?} not found", buffer_id))
         };
@@ -1697,18 +1728,20 @@ impl Editor {
 
             let (kill_tx, mut kill_rx) = tokio::sync::oneshot::channel::<()>();
             self.host_process_handles.insert(process_id, kill_tx);
+            let process_limits = self.config.editor.process_limits.clone();
 
             runtime.spawn(async move {
                 use crate::services::process_hidden::HideWindow;
                 let mut cmd = TokioCommand::new(&command);
                 cmd.args(&args);
+                cmd.stdin(std::process::Stdio::null());
                 cmd.stdout(std::process::Stdio::piped());
                 cmd.stderr(std::process::Stdio::piped());
                 cmd.hide_window();
                 if let Some(ref dir) = effective_cwd {
                     cmd.current_dir(dir);
                 }
-                let mut child = match cmd.spawn() {
+                let mut child = match spawn_limited(cmd, &process_limits) {
                     Ok(c) => c,
                     Err(e) => {
                         #[allow(clippy::let_underscore_must_use)]
@@ -1804,19 +1837,20 @@ impl Editor {
             let sender_stdout = sender.clone();
             let sender_stderr = sender.clone();
             let callback_id_u64 = callback_id.as_u64();
+            let process_limits = self.config.editor.process_limits.clone();
 
             // Receiver may be dropped if editor is shutting down
             #[allow(clippy::let_underscore_must_use)]
             let handle = runtime.spawn(async move {
                 use crate::services::process_hidden::HideWindow;
-                let mut child = match TokioCommand::new(&command)
-                    .args(&args)
+                let mut cmd = TokioCommand::new(&command);
+                cmd.args(&args)
                     .current_dir(&effective_cwd)
+                    .stdin(std::process::Stdio::null())
                     .stdout(std::process::Stdio::piped())
                     .stderr(std::process::Stdio::piped())
-                    .hide_window()
-                    .spawn()
-                {
+                    .hide_window();
+                let mut child = match spawn_limited(cmd, &process_limits) {
                     Ok(child) => child,
                     Err(e) => {
                         let _ = sender.send(crate::services::async_bridge::AsyncMessage::Plugin(
@@ -2200,6 +2234,8 @@ impl Editor {
                         self.config.editor.highlight_current_line,
                         line_wrap.unwrap_or_else(|| self.resolve_line_wrap_for_buffer(buffer_id)),
                         self.config.editor.wrap_indent,
+                        self.config.editor.wrap_mode,
+                        self.config.editor.wrap_indent_extra,
                         self.resolve_wrap_column_for_buffer(buffer_id),
                         self.config.editor.rulers.clone(),
                     );
@@ -2525,6 +2561,8 @@ impl Editor {
                                 self.config.editor.highlight_current_line,
                                 false,
                                 false,
+                                self.config.editor.wrap_mode,
+                                self.config.editor.wrap_indent_extra,
                                 None,
                                 self.config.editor.rulers.clone(),
                             );
@@ -2812,6 +2850,23 @@ impl Editor {
         }
     }
 
+    fn handle_open_scratch_buffer(
+        &mut self,
+        name: String,
+        content: String,
+        language: Option<String>,
+        request_id: Option<u64>,
+    ) {
+        let buffer_id = self.open_scratch_buffer(name, content, language.as_deref());
+        tracing::debug!("Opened scratch buffer {:?}", buffer_id);
+        if let Some(req_id) = request_id {
+            self.plugin_manager.resolve_callback(
+                fresh_core::api::JsCallbackId::from(req_id),
+                buffer_id.0.to_string(),
+            );
+        }
+    }
+
     fn handle_get_text_properties_at_cursor(&self, buffer_id: BufferId) {
         if let Some(state) = self.buffers.get(&buffer_id) {
             let cursor_pos = self
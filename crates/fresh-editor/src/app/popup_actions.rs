@@ -92,6 +92,35 @@ impl Editor {
                 PopupConfirmResult::EarlyReturn
             }
 
+            Some(PopupResolver::RenamePreview) => {
+                self.hide_popup();
+                if let Some(workspace_edit) = self.pending_rename_edit.take() {
+                    self.apply_confirmed_rename(workspace_edit);
+                }
+                PopupConfirmResult::EarlyReturn
+            }
+
+            Some(PopupResolver::DocumentSymbol) => {
+                let target = self
+                    .active_state()
+                    .popups
+                    .top()
+                    .and_then(|p| p.selected_item())
+                    .and_then(|item| item.data.as_ref())
+                    .and_then(|data| {
+                        let (line, character) = data.split_once(':')?;
+                        Some((
+                            line.parse::<usize>().ok()?,
+                            character.parse::<usize>().ok()?,
+                        ))
+                    });
+                self.hide_popup();
+                if let Some((line, character)) = target {
+                    self.jump_to_document_symbol(line, character);
+                }
+                PopupConfirmResult::EarlyReturn
+            }
+
             Some(PopupResolver::LspConfirm { language }) => {
                 let action = self
                     .active_state()
@@ -123,27 +152,28 @@ impl Editor {
                 PopupConfirmResult::EarlyReturn
             }
 
-            Some(PopupResolver::Completion) => {
-                // Grab the selected item's label + insert-text before we
+            Some(PopupResolver::Completion { .. }) => {
+                // Grab the selected item's insert-text and LSP item before we
                 // mutate the popup stack — insert_completion_text edits
                 // the buffer, which invalidates the borrow.
-                let completion_info = self
+                let insert_text = self
                     .active_state()
                     .popups
                     .top()
                     .and_then(|p| p.selected_item())
-                    .map(|item| (item.text.clone(), item.data.clone()));
-                if let Some((label, insert_text)) = completion_info {
-                    if let Some(text) = insert_text {
-                        self.insert_completion_text(text);
-                    }
-                    self.apply_completion_additional_edits(&label);
+                    .and_then(|item| item.data.clone());
+                let item_id = self.selected_completion_item_id();
+                if let Some(text) = insert_text {
+                    self.insert_completion_text(text);
+                }
+                if let Some(id) = item_id {
+                    self.apply_completion_additional_edits(id);
                 }
                 self.hide_popup();
                 PopupConfirmResult::Done
             }
 
-            Some(PopupResolver::None) | None => {
+            Some(PopupResolver::SignatureHelp) | Some(PopupResolver::None) | None => {
                 self.hide_popup();
                 PopupConfirmResult::Done
             }
@@ -202,40 +232,26 @@ impl Editor {
 
         // If this was a snippet, position cursor at the snippet's $0 location
         if let Some(offset) = cursor_offset {
-            let new_cursor_pos = insert_pos + offset;
-            // Get current cursor position after the insert
-            let current_pos = self.active_cursors().primary().position;
-            if current_pos != new_cursor_pos {
-                let move_event = Event::MoveCursor {
-                    cursor_id,
-                    old_position: current_pos,
-                    new_position: new_cursor_pos,
-                    old_anchor: None,
-                    new_anchor: None,
-                    old_sticky_column: 0,
-                    new_sticky_column: 0,
-                };
-                let split_id = self.split_manager.active_split();
-                let buffer_id = self.active_buffer();
-                let state = self.buffers.get_mut(&buffer_id).unwrap();
-                let cursors = &mut self.split_view_states.get_mut(&split_id).unwrap().cursors;
-                state.apply(cursors, &move_event);
-            }
+            self.move_primary_cursor(insert_pos + offset);
         }
     }
 
     /// Apply additional_text_edits from the accepted completion item (e.g. auto-imports).
+    /// `item_id` indexes `completion_items`.
     /// If the item already has additional_text_edits, apply them directly.
-    /// If not and the server supports completionItem/resolve, send a resolve request
-    /// so the server can fill them in (the response is handled asynchronously).
-    fn apply_completion_additional_edits(&mut self, label: &str) {
-        // Find the matching CompletionItem from stored items
-        let item = self
+    /// If not and the server it came from supports completionItem/resolve, send
+    /// that server a resolve request so it can fill them in (the response is
+    /// handled asynchronously).
+    fn apply_completion_additional_edits(&mut self, item_id: usize) {
+        let Some((server_name, item)) = self
             .completion_items
             .as_ref()
-            .and_then(|items| items.iter().find(|item| item.label == label).cloned());
-
-        let Some(item) = item else { return };
+            .and_then(|items| items.get(item_id))
+            .cloned()
+        else {
+            return;
+        };
+        let label = item.label.clone();
 
         if let Some(edits) = &item.additional_text_edits {
             if !edits.is_empty() {
@@ -253,12 +269,15 @@ impl Editor {
         }
 
         // No additional_text_edits present — try resolve if server supports it
-        if self.server_supports_completion_resolve() {
+        if self.server_supports_completion_resolve(&server_name) {
             tracing::info!(
                 "Completion '{}' has no additional_text_edits, sending completionItem/resolve",
                 label
             );
-            self.send_completion_resolve(item);
+            let buffer_id = self.active_buffer();
+            if let Some(request_id) = self.send_completion_resolve(&server_name, item) {
+                self.pending_completion_accept = Some((request_id, buffer_id));
+            }
         }
     }
 
@@ -301,17 +320,27 @@ impl Editor {
                 self.hide_popup();
             }
 
+            Some(PopupResolver::DocumentSymbol) => {
+                self.hide_popup();
+            }
+
+            Some(PopupResolver::RenamePreview) => {
+                self.pending_rename_edit = None;
+                self.hide_popup();
+                self.set_status_message(t!("lsp.rename_preview_cancelled").to_string());
+            }
+
             Some(PopupResolver::LspConfirm { language: _ }) => {
                 self.set_status_message(t!("lsp.startup_cancelled_msg").to_string());
                 self.hide_popup();
             }
 
-            Some(PopupResolver::Completion) => {
+            Some(PopupResolver::Completion { .. }) => {
                 self.hide_popup();
                 self.completion_items = None;
             }
 
-            Some(PopupResolver::RemoteIndicator) => {
+            Some(PopupResolver::RemoteIndicator) | Some(PopupResolver::SignatureHelp) => {
                 self.hide_popup();
             }
 
@@ -462,22 +491,21 @@ impl Editor {
             String::new()
         };
 
-        // Filter LSP items
-        let filtered_lsp: Vec<&lsp_types::CompletionItem> = if prefix.is_empty() {
-            lsp_items.iter().collect()
-        } else {
+        // Filter LSP items, keeping each one's index into `completion_items`
+        let (item_ids, filtered_lsp): (Vec<usize>, Vec<&(String, lsp_types::CompletionItem)>) =
             lsp_items
                 .iter()
-                .filter(|item| {
-                    item.label.to_lowercase().starts_with(&prefix)
+                .enumerate()
+                .filter(|(_, (_, item))| {
+                    prefix.is_empty()
+                        || item.label.to_lowercase().starts_with(&prefix)
                         || item
                             .filter_text
                             .as_ref()
                             .map(|ft| ft.to_lowercase().starts_with(&prefix))
                             .unwrap_or(false)
                 })
-                .collect()
-        };
+                .unzip();
 
         // Build combined items: LSP first, then buffer-word results.
         let mut all_popup_items = lsp_items_to_popup_items(&filtered_lsp);
@@ -521,8 +549,9 @@ impl Editor {
         let state = self.buffers.get_mut(&buffer_id).unwrap();
         let mut popup_obj = crate::state::convert_popup_data_to_popup(&popup_data);
         popup_obj.accept_key_hint = accept_hint;
-        popup_obj.resolver = crate::view::popup::PopupResolver::Completion;
+        popup_obj.resolver = crate::view::popup::PopupResolver::Completion { item_ids };
         state.popups.show_or_replace(popup_obj);
+        self.update_completion_preview();
     }
 }
 
@@ -548,15 +577,27 @@ pub(crate) fn build_completion_popup_from_items(
     }
 }
 
-/// Convert LSP `CompletionItem`s to `PopupListItemData`s.
+/// Convert LSP `CompletionItem`s, tagged with the server that sent them, to
+/// `PopupListItemData`s. The server name is shown in the detail when more than
+/// one server contributed items.
 pub(crate) fn lsp_items_to_popup_items(
-    items: &[&lsp_types::CompletionItem],
+    items: &[&(String, lsp_types::CompletionItem)],
 ) -> Vec<crate::model::event::PopupListItemData> {
     use crate::model::event::PopupListItemData;
 
+    let multiple_servers = {
+        let mut names = std::collections::HashSet::new();
+        for (name, _) in items {
+            if !name.is_empty() {
+                names.insert(name.as_str());
+            }
+        }
+        names.len() > 1
+    };
+
     items
         .iter()
-        .map(|item| {
+        .map(|(srv_name, item)| {
             let icon = match item.kind {
                 Some(lsp_types::CompletionItemKind::FUNCTION)
                 | Some(lsp_types::CompletionItemKind::METHOD) => Some("λ".to_string()),
@@ -568,9 +609,19 @@ pub(crate) fn lsp_items_to_popup_items(
                 _ => None,
             };
 
+            // Show server name in detail when multiple servers contribute
+            let detail = if multiple_servers && !srv_name.is_empty() {
+                match &item.detail {
+                    Some(d) => Some(format!("[{}] {}", srv_name, d)),
+                    None => Some(format!("[{}]", srv_name)),
+                }
+            } else {
+                item.detail.clone()
+            };
+
             PopupListItemData {
                 text: item.label.clone(),
-                detail: item.detail.clone(),
+                detail,
                 icon,
                 data: item
                     .insert_text
@@ -306,6 +306,34 @@ impl Editor {
                 icon, name, label
             )));
 
+            // Process details row for a live server: pid, how many
+            // documents it has open, and how many requests are still
+            // waiting on a reply. A pending count that keeps climbing is
+            // the usual sign of a wedged server worth restarting.
+            if is_active {
+                let details = self.lsp.as_ref().and_then(|mgr| {
+                    mgr.get_handles(language)
+                        .into_iter()
+                        .find(|sh| &sh.name == name)
+                        .map(|sh| {
+                            let pid = sh
+                                .handle
+                                .process_id()
+                                .map(|pid| pid.to_string())
+                                .unwrap_or_else(|| "-".to_string());
+                            format!(
+                                "    pid {} · {} open · {} pending",
+                                pid,
+                                sh.handle.open_document_count(),
+                                sh.handle.pending_request_count()
+                            )
+                        })
+                });
+                if let Some(line) = details {
+                    items.push(crate::view::popup::PopupListItem::new(line));
+                }
+            }
+
             // Progress row immediately UNDER the server's name row, if
             // there's an active `$/progress` notification for this
             // language.  Indented to match the action rows below, and the
@@ -571,6 +599,7 @@ impl Editor {
             resolver: PopupResolver::LspStatus,
             focused,
             focus_key_hint: focus_hint,
+            preview: None,
         };
 
         let buffer_id = self.active_buffer();
@@ -830,6 +859,7 @@ impl Editor {
             // so this popup wants the keyboard immediately.
             focused: true,
             focus_key_hint: None,
+            preview: None,
         };
 
         let buffer_id = self.active_buffer();
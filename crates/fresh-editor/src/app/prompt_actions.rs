@@ -10,6 +10,7 @@ use super::BufferMetadata;
 use super::Editor;
 use crate::config_io::{ConfigLayer, ConfigResolver};
 use crate::input::keybindings::Action;
+use crate::model::event::LeafId;
 use crate::primitives::path_utils::expand_tilde;
 use crate::services::plugins::hooks::HookArgs;
 use crate::view::prompt::PromptType;
@@ -20,6 +21,8 @@ pub enum PromptResult {
     Done,
     /// Prompt handled, should execute this action next
     ExecuteAction(Action),
+    /// Prompt handled, should run this palette command with its inline args
+    RunCommand(Action, Vec<String>),
     /// Prompt handled, should return early from handle_action
     EarlyReturn,
 }
@@ -31,6 +34,8 @@ pub(super) fn parse_path_line_col(input: &str) -> (String, Option<usize>, Option
 /// Convert a parsed goto-line target into a concrete 1-based line number,
 /// clamped into `1..=max_line`. Relative offsets are applied to `current_line`
 /// with saturating arithmetic so users can't underflow past line 1.
+/// Percentages round up, so `1%` of a short file is still line 1 and `100%`
+/// is the last line.
 pub(super) fn resolve_goto_line_target(
     target: crate::input::quick_open::GotoLineTarget,
     current_line: usize,
@@ -46,10 +51,93 @@ pub(super) fn resolve_goto_line_target(
                 current_line.saturating_sub(delta.unsigned_abs())
             }
         }
+        GotoLineTarget::Percent(percent) => max_line.saturating_mul(percent).div_ceil(100),
+        GotoLineTarget::Last => max_line,
     };
     raw.clamp(1, max_line.max(1))
 }
 
+/// Convert a parsed byte-offset prompt target into a byte offset, clamped
+/// to `buffer_len`. Relative targets count from `current`, and percentages
+/// round down, so `100%` is the end.
+pub(super) fn resolve_goto_byte_target(
+    target: crate::input::quick_open::GotoLineTarget,
+    current: usize,
+    buffer_len: usize,
+) -> usize {
+    use crate::input::quick_open::GotoLineTarget;
+    let offset = match target {
+        GotoLineTarget::Absolute(offset) => offset,
+        GotoLineTarget::Relative(delta) => current.saturating_add_signed(delta),
+        // Split the multiplication so huge files can't overflow
+        GotoLineTarget::Percent(percent) => {
+            buffer_len / 100 * percent + buffer_len % 100 * percent / 100
+        }
+        GotoLineTarget::Last => buffer_len,
+    };
+    offset.min(buffer_len)
+}
+
+/// Length of `line` without its line terminator.
+fn line_content_len(line: &[u8]) -> usize {
+    line.iter()
+        .rposition(|&b| b != b'\n' && b != b'\r')
+        .map_or(0, |i| i + 1)
+}
+
+/// Visual cells of `line` without its terminator, as `(byte offset,
+/// column, width)`. Tabs expand to the next multiple of `tab_size`, and an
+/// invalid UTF-8 sequence takes one column, as it is drawn. Offsets are
+/// into the raw bytes, so invalid sequences don't shift them.
+fn visual_cells(line: &[u8], tab_size: usize) -> Vec<(usize, usize, usize)> {
+    let tab_size = tab_size.max(1);
+    let mut cells = Vec::new();
+    let mut col = 0;
+    let mut offset = 0;
+    for chunk in line[..line_content_len(line)].utf8_chunks() {
+        for (i, ch) in chunk.valid().char_indices() {
+            let width = if ch == '\t' {
+                tab_size - col % tab_size
+            } else {
+                crate::primitives::display_width::char_width(ch)
+            };
+            cells.push((offset + i, col, width));
+            col += width;
+        }
+        offset += chunk.valid().len();
+        if !chunk.invalid().is_empty() {
+            cells.push((offset, col, 1));
+            col += 1;
+            offset += chunk.invalid().len();
+        }
+    }
+    cells
+}
+
+/// Byte offset within `line` of 0-based visual `column`, expanding tabs to
+/// the next multiple of `tab_size`. A column inside a tab or a wide
+/// character lands on that character; one past the end lands at the line
+/// end (before any line terminator).
+pub(super) fn visual_column_to_byte(line: &[u8], column: usize, tab_size: usize) -> usize {
+    visual_cells(line, tab_size)
+        .into_iter()
+        .find(|&(_, col, width)| column < col + width)
+        .map_or(line_content_len(line), |(byte_idx, _, _)| byte_idx)
+}
+
+/// 0-based visual column of the cell at `byte` in `line`, or of the line
+/// end when `byte` is past the last cell.
+pub(super) fn byte_to_visual_column(line: &[u8], byte: usize, tab_size: usize) -> usize {
+    let mut end_col = 0;
+    for (byte_idx, col, width) in visual_cells(line, tab_size) {
+        if byte_idx >= byte {
+            return col;
+        }
+        end_col = col + width;
+    }
+    end_col
+}
+
 impl Editor {
     /// Handle prompt confirmation based on the prompt type.
     ///
@@ -104,7 +192,7 @@ impl Editor {
                 self.handle_save_file_as(&input);
             }
             PromptType::Search => {
-                self.perform_search(&input);
+                self.confirm_search_preview(&input);
             }
             PromptType::ReplaceSearch => {
                 self.perform_search(&input);
@@ -138,42 +226,39 @@ impl Editor {
                     self.perform_replace(&search, &input);
                 }
             }
-            PromptType::GotoLine => {
-                let buffer_id = self.active_buffer();
-                if let Some(state) = self.buffers.get(&buffer_id) {
-                    let max_line = state.buffer.line_count().unwrap_or(1);
-                    let current_line = state.primary_cursor_line_number.value() + 1;
-                    match crate::input::quick_open::parse_goto_line_input(&input) {
-                        Some(target) => {
-                            let line = resolve_goto_line_target(target, current_line, max_line);
-                            self.goto_line_col(line, None);
-                            self.set_status_message(t!("goto.jumped", line = line).to_string());
-                        }
-                        None => {
-                            self.set_status_message(
-                                t!("error.invalid_line", input = input.trim()).to_string(),
-                            );
-                        }
-                    }
-                } else {
-                    self.set_status_message(t!("status.no_selection").to_string());
+            PromptType::GotoLine => match crate::input::quick_open::parse_goto_input(&input) {
+                Some(target) => self.goto_position(target),
+                None => {
+                    self.set_status_message(
+                        t!("error.invalid_line", input = input.trim()).to_string(),
+                    );
                 }
-            }
+            },
             PromptType::GotoByteOffset => {
-                // Parse byte offset — strip optional trailing 'B' or 'b' suffix
-                let trimmed = input.trim();
-                let num_str = trimmed
-                    .strip_suffix('B')
-                    .or_else(|| trimmed.strip_suffix('b'))
-                    .unwrap_or(trimmed);
-                match num_str.parse::<usize>() {
-                    Ok(offset) => {
+                match crate::input::quick_open::parse_goto_byte_offset_input(&input) {
+                    Some(target) => {
+                        let buffer_id = self.active_buffer();
+                        let cursor = self.active_cursors().primary();
+                        let origin = (cursor.position, cursor.anchor);
+                        let offset = resolve_goto_byte_target(
+                            target,
+                            origin.0,
+                            self.active_state().buffer.len(),
+                        );
                         self.goto_byte_offset(offset);
+
+                        // Record the jump so Navigate Back returns, as for Go To Line
+                        if self.active_cursors().primary().position != origin.0 {
+                            self.position_history.commit_pending_movement();
+                            self.position_history
+                                .record_movement(buffer_id, origin.0, origin.1);
+                            self.position_history.commit_pending_movement();
+                        }
                         self.set_status_message(
                             t!("goto.jumped_byte", offset = offset).to_string(),
                         );
                     }
-                    Err(_) => {
+                    None => {
                         self.set_status_message(
                             t!("goto.invalid_byte_offset", input = &input).to_string(),
                         );
@@ -252,6 +337,12 @@ impl Editor {
             PromptType::SetPageWidth => {
                 self.handle_set_page_width(&input);
             }
+            PromptType::SetComposeGuides => {
+                self.handle_set_compose_guides(&input);
+            }
+            PromptType::ExportEventLog => {
+                self.handle_export_event_log(&input);
+            }
             PromptType::RecordMacro => {
                 self.handle_register_input(
                     &input,
@@ -403,6 +494,13 @@ impl Editor {
                     return PromptResult::EarlyReturn;
                 }
             }
+            PromptType::ConfirmCloseTabs {
+                split_id,
+                buffer_ids,
+                keep_buffer_id,
+            } => {
+                self.handle_confirm_close_tabs(&input, split_id, buffer_ids, keep_buffer_id);
+            }
             PromptType::ConfirmQuitWithModified => {
                 if self.handle_confirm_quit_modified(&input) {
                     return PromptResult::EarlyReturn;
@@ -416,6 +514,9 @@ impl Editor {
             } => {
                 self.perform_lsp_rename(input, original_text, start_pos, overlay_handle);
             }
+            PromptType::LspWorkspaceSymbols => {
+                self.jump_to_workspace_symbol(&input);
+            }
             PromptType::FileExplorerRename {
                 original_path,
                 original_name,
@@ -616,11 +717,50 @@ impl Editor {
             PromptType::CopyWithFormattingTheme => {
                 self.copy_selection_with_theme(input.trim());
             }
+            PromptType::Surround => {
+                if input == "t" {
+                    self.start_prompt(
+                        t!("surround.tag_prompt").to_string(),
+                        PromptType::SurroundTag,
+                    );
+                } else if !input.is_empty() {
+                    self.surround_selection(&input);
+                }
+            }
+            PromptType::SurroundTag => {
+                let tag = input.trim();
+                if !tag.is_empty() {
+                    self.surround_selection(&format!("<{}>", tag));
+                }
+            }
+            PromptType::AlignCursors => {
+                self.align_cursors(input.trim());
+            }
             PromptType::SwitchToTab => {
                 if let Ok(id) = input.trim().parse::<usize>() {
                     self.switch_to_tab(BufferId(id));
                 }
             }
+            PromptType::ListBookmarks => {
+                if let Some(key) = input.chars().next() {
+                    self.jump_to_bookmark(key);
+                }
+            }
+            PromptType::CopyToRegister => {
+                self.handle_named_register_input(&input, |editor, c| {
+                    editor.copy_selection_to_register(c)
+                });
+            }
+            PromptType::CutToRegister => {
+                self.handle_named_register_input(&input, |editor, c| {
+                    editor.cut_selection_to_register(c)
+                });
+            }
+            PromptType::PasteFromRegister => {
+                if let Some(key) = input.chars().next() {
+                    self.paste_from_register(key);
+                }
+            }
             PromptType::QueryReplaceConfirm => {
                 // This is handled by InsertChar, not PromptConfirm
                 // But if somehow Enter is pressed, treat it as skip (n)
@@ -729,8 +869,12 @@ impl Editor {
             before_len
         );
 
-        match self.active_state_mut().buffer.save_to_file(&full_path) {
-            Ok(()) => {
+        match self.write_buffer(self.active_buffer(), &full_path) {
+            // A plugin vetoed the save; write_buffer set the status
+            Ok(false) => {
+                self.pending_close_buffer = None;
+            }
+            Ok(true) => {
                 let after_save_idx = self.active_event_log().current_index();
                 let after_save_len = self.active_event_log().len();
                 tracing::debug!(
@@ -854,6 +998,94 @@ impl Editor {
         }
     }
 
+    /// Handle SetComposeGuides prompt confirmation.
+    fn handle_set_compose_guides(&mut self, input: &str) {
+        let active_split = self.split_manager.active_split();
+        let trimmed = input.trim();
+
+        if trimmed.is_empty() {
+            if let Some(vs) = self.split_view_states.get_mut(&active_split) {
+                vs.compose_column_guides = None;
+            }
+            self.set_status_message(t!("settings.compose_guides_cleared").to_string());
+            return;
+        }
+
+        let parsed: Result<Vec<u16>, _> = trimmed
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|part| !part.is_empty())
+            .map(str::parse::<u16>)
+            .collect();
+        match parsed {
+            Ok(mut guides) if !guides.is_empty() => {
+                guides.sort_unstable();
+                guides.dedup();
+                let value = guides
+                    .iter()
+                    .map(|col| col.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                if let Some(vs) = self.split_view_states.get_mut(&active_split) {
+                    vs.compose_column_guides = Some(guides);
+                }
+                self.set_status_message(
+                    t!("settings.compose_guides_set", value = value).to_string(),
+                );
+            }
+            _ => {
+                self.set_status_message(
+                    t!("error.invalid_compose_guides", input = input).to_string(),
+                );
+            }
+        }
+    }
+
+    /// Run a command palette action, feeding `args` to the prompt it opens.
+    ///
+    /// Arguments the prompt would reject leave the prompt open with the
+    /// text filled in, so the user can correct it instead of losing it.
+    pub(super) fn run_command_with_args(
+        &mut self,
+        action: Action,
+        args: Vec<String>,
+    ) -> anyhow::Result<()> {
+        self.handle_action(action)?;
+        if args.is_empty() {
+            return Ok(());
+        }
+        let Some(prompt) = self.prompt.as_mut() else {
+            return Ok(());
+        };
+        let input = args.join(" ");
+        // Only input that can be checked here confirms the prompt; any
+        // other prompt gets the arguments filled in and waits for the user
+        let valid = match prompt.prompt_type {
+            PromptType::AddRuler | PromptType::SetTabSize => {
+                matches!(input.trim().parse::<usize>(), Ok(n) if n > 0)
+            }
+            _ => false,
+        };
+        if valid {
+            self.handle_action(Action::PromptConfirmWithText(input))
+        } else {
+            prompt.set_input(input);
+            self.update_prompt_suggestions();
+            Ok(())
+        }
+    }
+
+    /// Re-run the last command chosen from the command palette, with the
+    /// same inline arguments.
+    pub(super) fn repeat_last_command(&mut self) -> anyhow::Result<()> {
+        match self.last_palette_command.clone() {
+            Some((action, args)) => self.run_command_with_args(action, args),
+            None => {
+                self.set_status_message(t!("status.no_command_to_repeat").to_string());
+                Ok(())
+            }
+        }
+    }
+
     /// Handle AddRuler prompt confirmation.
     fn handle_add_ruler(&mut self, input: &str) {
         let trimmed = input.trim();
@@ -930,6 +1162,7 @@ impl Editor {
             Ok(val) if val > 0 => {
                 if let Some(state) = self.buffers.get_mut(&buffer_id) {
                     state.buffer_settings.tab_size = val;
+                    state.buffer_settings.indent_width = None;
                 }
                 self.set_status_message(t!("settings.tab_size_set", value = val).to_string());
             }
@@ -1194,6 +1427,21 @@ impl Editor {
         }
     }
 
+    /// Like `handle_register_input`, but for the writable clipboard
+    /// registers (letters and symbols rather than digits).
+    fn handle_named_register_input<F>(&mut self, input: &str, action: F)
+    where
+        F: FnOnce(&mut Self, char),
+    {
+        match input.trim().chars().next() {
+            Some(c) if super::registers::RegisterState::is_named_key(c) => action(self, c),
+            Some(c) => {
+                self.set_status_message(t!("register.read_only", key = c).to_string());
+            }
+            None => self.set_status_message(t!("register.not_specified").to_string()),
+        }
+    }
+
     /// Handle ConfirmCloseBuffer prompt. Returns true if early return is needed.
     fn handle_confirm_close_buffer(&mut self, input: &str, buffer_id: BufferId) -> bool {
         let input_lower = input.trim().to_lowercase();
@@ -1251,6 +1499,58 @@ impl Editor {
         false
     }
 
+    /// Handle ConfirmCloseTabs prompt: save or discard the modified tabs,
+    /// then close them all.
+    fn handle_confirm_close_tabs(
+        &mut self,
+        input: &str,
+        split_id: LeafId,
+        buffer_ids: Vec<BufferId>,
+        keep_buffer_id: BufferId,
+    ) {
+        let input_lower = input.trim().to_lowercase();
+        let save_key = t!("prompt.key.save").to_string().to_lowercase();
+        let discard_key = t!("prompt.key.discard").to_string().to_lowercase();
+
+        let first_char = input_lower.chars().next();
+        let save_first = save_key.chars().next();
+        let discard_first = discard_key.chars().next();
+
+        if first_char == save_first {
+            // Save the file-backed ones; unnamed buffers stay open and are
+            // reported as skipped
+            let modified: Vec<BufferId> = buffer_ids
+                .iter()
+                .copied()
+                .filter(|&id| self.tab_close_loses_changes(id, split_id))
+                .collect();
+            let old_active = self.active_buffer();
+            for buffer_id in modified {
+                let has_path = self
+                    .buffers
+                    .get(&buffer_id)
+                    .is_some_and(|s| s.buffer.file_path().is_some());
+                if !has_path {
+                    continue;
+                }
+                self.set_active_buffer(buffer_id);
+                if let Err(e) = self.save() {
+                    self.set_status_message(
+                        t!("file.save_failed", error = e.to_string()).to_string(),
+                    );
+                    self.set_active_buffer(old_active);
+                    return;
+                }
+            }
+            self.set_active_buffer(old_active);
+            self.close_tabs_in_split(buffer_ids, split_id, keep_buffer_id, false);
+        } else if first_char == discard_first {
+            self.close_tabs_in_split(buffer_ids, split_id, keep_buffer_id, true);
+        } else {
+            self.set_status_message(t!("buffer.close_cancelled").to_string());
+        }
+    }
+
     /// Handle ConfirmQuitWithModified prompt. Returns true if early return is needed.
     fn handle_confirm_quit_modified(&mut self, input: &str) -> bool {
         let input_lower = input.trim().to_lowercase();
@@ -1470,21 +1770,21 @@ impl Editor {
         use crate::input::quick_open::QuickOpenResult;
 
         // Any live goto-line preview must be resolved before executing the
-        // result: a GotoLine confirm accepts the preview as-is, everything
-        // else (file/buffer/action/etc.) should see the pre-preview state.
-        match &result {
-            QuickOpenResult::GotoLine(_) => {
-                // Commit the preview: discard the saved snapshot without
-                // restoring, since the cursor is already at the target.
-                self.goto_line_preview = None;
-            }
-            _ => {
-                self.restore_goto_line_preview_snapshot();
-            }
+        // result: a GotoLine confirm accepts the preview (`goto_position`
+        // commits it, recording the pre-preview cursor as the jump's origin),
+        // everything else (file/buffer/action/etc.) should see the
+        // pre-preview state.
+        if !matches!(result, QuickOpenResult::GotoLine(_)) {
+            self.restore_goto_line_preview_snapshot();
         }
 
         match result {
-            QuickOpenResult::ExecuteAction(action) => PromptResult::ExecuteAction(action),
+            QuickOpenResult::ExecuteAction { action, args } => {
+                if action != Action::RepeatLastCommand {
+                    self.last_palette_command = Some((action.clone(), args.clone()));
+                }
+                PromptResult::RunCommand(action, args)
+            }
             QuickOpenResult::OpenFile { path, line, column } => {
                 let expanded_path = expand_tilde(&path);
                 let full_path = if expanded_path.is_absolute() {
@@ -1508,16 +1808,7 @@ impl Editor {
                 PromptResult::Done
             }
             QuickOpenResult::GotoLine(target) => {
-                let buffer_id = self.active_buffer();
-                if let Some(state) = self.buffers.get(&buffer_id) {
-                    let max_line = state.buffer.line_count().unwrap_or(1);
-                    let current_line = state.primary_cursor_line_number.value() + 1;
-                    let line = resolve_goto_line_target(target, current_line, max_line);
-                    self.goto_line_col(line, None);
-                    self.set_status_message(t!("goto.jumped", line = line).to_string());
-                } else {
-                    self.set_status_message(t!("status.no_selection").to_string());
-                }
+                self.goto_position(target);
                 PromptResult::Done
             }
             QuickOpenResult::None => {
@@ -1531,6 +1822,64 @@ impl Editor {
         }
     }
 
+    /// Jump to a parsed goto target in the active buffer and report it in
+    /// the status bar. The column is visual, so a tab counts as the width it
+    /// is drawn with; past the end of the line it lands at the line end.
+    ///
+    /// The jump is recorded in position history, so Navigate Back returns
+    /// to where the cursor was before the prompt's live preview moved it.
+    fn goto_position(&mut self, target: crate::input::quick_open::GotoPosition) {
+        let buffer_id = self.active_buffer();
+        let origin = match self.goto_line_preview.take() {
+            Some(snap) if snap.buffer_id == buffer_id => (snap.position, snap.anchor),
+            _ => {
+                let cursor = self.active_cursors().primary();
+                (cursor.position, cursor.anchor)
+            }
+        };
+        let Some(state) = self.buffers.get(&buffer_id) else {
+            self.set_status_message(t!("status.no_selection").to_string());
+            return;
+        };
+        let max_line = state.buffer.line_count().unwrap_or(1);
+        let current_line = state.primary_cursor_line_number.value() + 1;
+        let line = resolve_goto_line_target(target.line, current_line, max_line);
+        let tab_size = state.buffer_settings.tab_size;
+        let line_bytes = target.column.and_then(|_| state.buffer.get_line(line - 1));
+        let byte_column = target.column.map(|column| match &line_bytes {
+            Some(text) => visual_column_to_byte(text, column - 1, tab_size) + 1,
+            // Line not loaded (huge file): treat the column as bytes
+            None => column,
+        });
+        self.goto_line_col(line, byte_column);
+
+        // Report where the cursor landed: past the end of the line is
+        // clamped to the line end
+        let landed_column = match (target.column, &line_bytes) {
+            (Some(_), Some(text)) => {
+                let state = self.active_state();
+                let position = self.active_cursors().primary().position;
+                let line_start = state.buffer.line_start_offset(line - 1).unwrap_or(position);
+                let byte = position.saturating_sub(line_start);
+                Some(byte_to_visual_column(text, byte, tab_size) + 1)
+            }
+            (column, _) => column,
+        };
+
+        if self.active_cursors().primary().position != origin.0 {
+            self.position_history.commit_pending_movement();
+            self.position_history
+                .record_movement(buffer_id, origin.0, origin.1);
+            self.position_history.commit_pending_movement();
+        }
+
+        let message = match landed_column {
+            Some(column) => t!("goto.jumped_col", line = line, column = column),
+            None => t!("goto.jumped", line = line),
+        };
+        self.set_status_message(message.to_string());
+    }
+
     fn open_file_with_jump(
         &mut self,
         full_path: std::path::PathBuf,
@@ -1595,7 +1944,50 @@ impl Editor {
 
 #[cfg(test)]
 mod tests {
-    use super::parse_path_line_col;
+    use super::{
+        byte_to_visual_column, parse_path_line_col, resolve_goto_byte_target, visual_column_to_byte,
+    };
+    use crate::input::quick_open::GotoLineTarget;
+
+    #[test]
+    fn test_resolve_goto_byte_target() {
+        assert_eq!(
+            resolve_goto_byte_target(GotoLineTarget::Absolute(7), 0, 1000),
+            7
+        );
+        assert_eq!(
+            resolve_goto_byte_target(GotoLineTarget::Absolute(5000), 0, 1000),
+            1000
+        );
+        assert_eq!(
+            resolve_goto_byte_target(GotoLineTarget::Relative(10), 100, 1000),
+            110
+        );
+        assert_eq!(
+            resolve_goto_byte_target(GotoLineTarget::Relative(-200), 100, 1000),
+            0
+        );
+        assert_eq!(
+            resolve_goto_byte_target(GotoLineTarget::Relative(2000), 100, 1000),
+            1000
+        );
+        assert_eq!(
+            resolve_goto_byte_target(GotoLineTarget::Percent(50), 0, 1001),
+            500
+        );
+        assert_eq!(
+            resolve_goto_byte_target(GotoLineTarget::Percent(100), 0, 1001),
+            1001
+        );
+        assert_eq!(
+            resolve_goto_byte_target(GotoLineTarget::Last, 0, 1001),
+            1001
+        );
+        assert_eq!(
+            resolve_goto_byte_target(GotoLineTarget::Percent(100), 0, usize::MAX),
+            usize::MAX
+        );
+    }
 
     #[test]
     fn test_parse_path_line_col_empty() {
@@ -1661,4 +2053,33 @@ mod tests {
         assert_eq!(line, Some(12));
         assert_eq!(col, Some(3));
     }
+
+    #[test]
+    fn test_visual_column_to_byte_expands_tabs() {
+        // "\tx" with 4-wide tabs: columns 0-3 are the tab, 4 is 'x'
+        assert_eq!(visual_column_to_byte(b"\tx\n", 0, 4), 0);
+        assert_eq!(visual_column_to_byte(b"\tx\n", 2, 4), 0);
+        assert_eq!(visual_column_to_byte(b"\tx\n", 4, 4), 1);
+        // Past the end clamps to the line end, before the newline
+        assert_eq!(visual_column_to_byte(b"\tx\n", 40, 4), 2);
+        assert_eq!(visual_column_to_byte(b"ab\tc", 4, 4), 3);
+    }
+
+    #[test]
+    fn test_visual_column_to_byte_keeps_invalid_utf8_offsets() {
+        // The invalid byte is one column wide and one byte long, so 'x' is
+        // column 1 at byte 1 (a lossy String would put it at byte 3)
+        assert_eq!(visual_column_to_byte(b"\xffx\n", 1, 4), 1);
+        assert_eq!(visual_column_to_byte(b"\xc3\xa9\xffx", 2, 4), 3);
+        assert_eq!(byte_to_visual_column(b"\xffx\n", 1, 4), 1);
+    }
+
+    #[test]
+    fn test_byte_to_visual_column() {
+        assert_eq!(byte_to_visual_column(b"\tx\n", 0, 4), 0);
+        assert_eq!(byte_to_visual_column(b"\tx\n", 1, 4), 4);
+        // The line end is one past the last cell
+        assert_eq!(byte_to_visual_column(b"\tx\n", 2, 4), 5);
+        assert_eq!(byte_to_visual_column(b"\tx\n", 9, 4), 5);
+    }
 }
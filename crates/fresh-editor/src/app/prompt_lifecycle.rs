@@ -66,8 +66,12 @@ impl Editor {
                 .and_then(|h| h.last().map(|s| s.to_string()))
         });
 
-        // Start the prompt
+        // Start the prompt; plain searches preview matches as the query is typed
+        let previews_matches = matches!(prompt_type, PromptType::Search);
         self.start_prompt(message, prompt_type);
+        if previews_matches {
+            self.begin_search_preview();
+        }
 
         // Pre-fill with default text if available
         if let Some(text) = default_text {
@@ -92,6 +96,7 @@ impl Editor {
     ) {
         // Dismiss transient popups and clear hover state when opening a prompt
         self.on_editor_focus_lost();
+        self.search_preview_origin = None;
 
         // Clear search highlights when starting a new search prompt
         // This ensures old highlights from previous searches don't persist
@@ -149,6 +154,26 @@ impl Editor {
         prompt.cursor_pos = prefix.len();
         self.prompt = Some(prompt);
 
+        // Index the same files the explorer shows
+        let (show_hidden, show_gitignored) = match &self.file_explorer {
+            Some(explorer) => (
+                explorer.ignore_patterns().show_hidden(),
+                explorer.ignore_patterns().show_gitignored(),
+            ),
+            None => (
+                self.config.file_explorer.show_hidden,
+                self.config.file_explorer.show_gitignored,
+            ),
+        };
+        if let Some((provider, _)) = self.quick_open_registry.get_provider_for_input("") {
+            if let Some(fp) = provider
+                .as_any()
+                .downcast_ref::<crate::input::quick_open::providers::FileProvider>()
+            {
+                fp.set_visibility(show_hidden, show_gitignored);
+            }
+        }
+
         self.update_quick_open_suggestions(prefix);
     }
 
@@ -240,8 +265,8 @@ impl Editor {
     /// Only absolute inputs are previewed; relative inputs return `None`.
     pub(super) fn parse_quick_open_goto_line_target(input: &str) -> Option<usize> {
         let rest = input.strip_prefix(':')?;
-        match crate::input::quick_open::parse_goto_line_input(rest) {
-            Some(crate::input::quick_open::GotoLineTarget::Absolute(n)) => Some(n),
+        match crate::input::quick_open::parse_goto_input(rest)?.line {
+            crate::input::quick_open::GotoLineTarget::Absolute(n) => Some(n),
             _ => None,
         }
     }
@@ -409,7 +434,7 @@ impl Editor {
         // Determine initial directory
         let buffer_id = self.active_buffer();
 
-        // For terminal buffers, use the terminal's initial CWD or fall back to project root
+        // For terminal buffers, use the terminal's current CWD or fall back to project root
         // This avoids showing the terminal backing file directory which is confusing for users
         let initial_dir = if self.is_terminal_buffer(buffer_id) {
             self.get_terminal_id(buffer_id)
@@ -487,20 +512,7 @@ impl Editor {
             state.update_shortcuts();
         }
 
-        // Use tokio runtime to load directory
-        if let Some(ref runtime) = self.tokio_runtime {
-            let fs_manager = self.fs_manager.clone();
-            let sender = self.async_bridge.as_ref().map(|b| b.sender());
-
-            runtime.spawn(async move {
-                let result = fs_manager.list_dir_with_metadata(path).await;
-                if let Some(sender) = sender {
-                    // Receiver may have been dropped if the dialog was closed.
-                    #[allow(clippy::let_underscore_must_use)]
-                    let _ = sender.send(AsyncMessage::FileOpenDirectoryLoaded(result));
-                }
-            });
-        } else {
+        if !self.spawn_file_open_listing(path, false) {
             // No runtime, set error
             if let Some(state) = &mut self.file_open_state {
                 state.set_error("Async runtime not available".to_string());
@@ -508,11 +520,56 @@ impl Editor {
         }
     }
 
+    /// List `path` in the background for the file open dialog.
+    ///
+    /// Any listing still in flight is aborted first, so a slow directory
+    /// (e.g. on a network mount) never blocks the prompt and never lands on
+    /// top of a newer listing. Returns false if there is no async runtime.
+    pub(super) fn spawn_file_open_listing(&mut self, path: PathBuf, typed: bool) -> bool {
+        if let Some(task) = self.file_open_listing_task.take() {
+            task.abort();
+        }
+        let Some(ref runtime) = self.tokio_runtime else {
+            return false;
+        };
+        let fs_manager = self.fs_manager.clone();
+        let sender = self.async_bridge.as_ref().map(|b| b.sender());
+
+        let task = runtime.spawn(async move {
+            let result = fs_manager.list_dir_with_metadata(path.clone()).await;
+            if let Some(sender) = sender {
+                // Receiver may have been dropped if the dialog was closed.
+                #[allow(clippy::let_underscore_must_use)]
+                let _ = sender.send(AsyncMessage::FileOpenDirectoryLoaded {
+                    path,
+                    typed,
+                    result,
+                });
+            }
+        });
+        self.file_open_listing_task = Some(task.abort_handle());
+        true
+    }
+
     /// Handle file open directory load result
     pub(super) fn handle_file_open_directory_loaded(
         &mut self,
+        path: PathBuf,
+        typed: bool,
         result: std::io::Result<Vec<crate::services::fs::DirEntry>>,
     ) {
+        if typed {
+            self.handle_typed_directory_loaded(path, result);
+            return;
+        }
+        // Drop listings for a directory the dialog has since left
+        if self
+            .file_open_state
+            .as_ref()
+            .is_none_or(|state| state.current_dir != path)
+        {
+            return;
+        }
         match result {
             Ok(entries) => {
                 if let Some(state) = &mut self.file_open_state {
@@ -661,6 +718,7 @@ impl Editor {
             match &prompt.prompt_type {
                 PromptType::Search | PromptType::ReplaceSearch | PromptType::QueryReplaceSearch => {
                     self.clear_search_highlights();
+                    self.cancel_search_preview();
                 }
                 PromptType::Plugin { custom_type } => {
                     // Fire plugin hook for prompt cancellation
@@ -751,6 +809,11 @@ impl Editor {
                     // where it was before the prompt was opened.
                     self.restore_goto_line_preview_snapshot();
                 }
+                PromptType::LspWorkspaceSymbols => {
+                    // Drop any in-flight search so a late response is ignored
+                    self.scheduled_workspace_symbols_request = None;
+                    self.pending_workspace_symbols_request = None;
+                }
                 _ => {}
             }
         }
@@ -849,6 +912,19 @@ impl Editor {
             if prompt.overlay {
                 self.cleanup_overlay_preview();
             }
+            // Workspace symbol results are the only valid answers; keep the
+            // prompt open while it only shows a hint or no results yet
+            if matches!(prompt.prompt_type, PromptType::LspWorkspaceSymbols) {
+                let has_result = prompt
+                    .selected_suggestion
+                    .and_then(|idx| prompt.suggestions.get(idx))
+                    .is_some_and(|s| !s.disabled);
+                if !has_result {
+                    self.prompt = Some(prompt);
+                    return None;
+                }
+            }
+
             let selected_index = prompt.selected_suggestion;
             // For prompts with suggestions, prefer the selected suggestion over raw input
             let mut final_input = if prompt.sync_input_on_navigate {
@@ -865,9 +941,12 @@ impl Editor {
                     | PromptType::SelectTheme { .. }
                     | PromptType::SelectLocale
                     | PromptType::SwitchToTab
+                    | PromptType::ListBookmarks
+                    | PromptType::PasteFromRegister
                     | PromptType::SetLanguage
                     | PromptType::SetEncoding
                     | PromptType::SetLineEnding
+                    | PromptType::LspWorkspaceSymbols
                     | PromptType::Plugin { .. }
             ) {
                 // Use the selected suggestion if any
@@ -1078,6 +1157,7 @@ impl Editor {
             PromptType::Search | PromptType::ReplaceSearch | PromptType::QueryReplaceSearch => {
                 // Update incremental search highlights as user types
                 self.update_search_highlights(&input);
+                self.preview_search_match(&input);
                 // Reset history navigation when user types - allows Up to navigate history
                 if let Some(history) = self.prompt_histories.get_mut("search") {
                     history.reset_navigation();
@@ -1098,8 +1178,11 @@ impl Editor {
                 // (`+N`/`-N`) inputs are relative, and previewing them as the
                 // user types each digit is disorienting — preview only on
                 // Enter for those.
-                let target = match crate::input::quick_open::parse_goto_line_input(input.trim()) {
-                    Some(crate::input::quick_open::GotoLineTarget::Absolute(n)) => Some(n),
+                let target = match crate::input::quick_open::parse_goto_input(input.trim()) {
+                    Some(crate::input::quick_open::GotoPosition {
+                        line: crate::input::quick_open::GotoLineTarget::Absolute(n),
+                        ..
+                    }) => Some(n),
                     _ => None,
                 };
                 self.apply_goto_line_preview(target);
@@ -1108,6 +1191,10 @@ impl Editor {
                 // For OpenFile/SwitchProject/SaveFileAs, update the file browser filter (native implementation)
                 self.update_file_open_filter();
             }
+            PromptType::LspWorkspaceSymbols => {
+                // Results come from the server; debounce the query
+                self.schedule_workspace_symbols_request(&input);
+            }
             PromptType::Plugin { custom_type } => {
                 // Reset history navigation when user types - allows Up to navigate history
                 let key = format!("plugin:{}", custom_type);
@@ -1132,6 +1219,8 @@ impl Editor {
                 }
             }
             PromptType::SwitchToTab
+            | PromptType::ListBookmarks
+            | PromptType::PasteFromRegister
             | PromptType::SelectTheme { .. }
             | PromptType::StopLspServer
             | PromptType::RestartLspServer
@@ -0,0 +1,256 @@
+//! Named clipboard registers and the numbered yank ring.
+//!
+//! The unnamed register is the clipboard itself (`Editor::clipboard`), so a
+//! plain Copy/Cut/Paste behaves exactly as it always has. `RegisterState`
+//! adds vim-style named registers (any non-digit key, usually `a`-`z`) and
+//! a ring of the most recent copies and cuts, exposed read-only as the
+//! numbered registers `1`-`9` (newest first).
+//!
+//! It also remembers the most recent paste so Cycle Paste can swap the
+//! inserted text for another yank ring entry (Emacs's yank-pop).
+//!
+//! Like `MacroState` and `BookmarkState`, this owns only the storage; the
+//! buffer work (extracting the selection, inserting text, status messages)
+//! lives on `Editor`.
+
+use std::collections::{HashMap, VecDeque};
+
+use crate::model::event::{BufferId, Event};
+
+/// Key of the unnamed register, i.e. the clipboard.
+pub(crate) const UNNAMED_REGISTER: char = '"';
+
+/// How many recent copies the numbered registers remember.
+const YANK_RING_SIZE: usize = 9;
+
+/// A paste that Cycle Paste can still replace.
+#[derive(Debug, Clone)]
+pub(crate) struct PasteCycle {
+    pub buffer_id: BufferId,
+    /// Event log `change_seq` right after the paste. Any later edit, undo
+    /// or logged cursor movement advances it and ends the cycle.
+    pub log_seq: u64,
+    /// The events that made the paste, replayed with other text when
+    /// cycling.
+    pub events: Vec<Event>,
+    /// Yank ring entry that was pasted, if the text came from the ring.
+    pub ring_index: Option<usize>,
+}
+
+/// Owner of the named registers and the yank ring.
+#[derive(Debug, Default)]
+pub(crate) struct RegisterState {
+    /// Register key -> stored text.
+    named: HashMap<char, String>,
+    /// Recent copies and cuts, newest first.
+    yank_ring: VecDeque<String>,
+    /// The latest paste, while it is still the last thing that happened.
+    paste_cycle: Option<PasteCycle>,
+}
+
+impl RegisterState {
+    /// Whether `key` names a writable register. Digits are the read-only
+    /// yank ring and `"` is the clipboard.
+    pub(crate) fn is_named_key(key: char) -> bool {
+        !key.is_ascii_digit() && key != UNNAMED_REGISTER && !key.is_whitespace()
+    }
+
+    /// Store `text` in the named register `key`.
+    pub(crate) fn set(&mut self, key: char, text: String) {
+        self.named.insert(key, text);
+    }
+
+    /// Remember a copy or cut in the yank ring.
+    pub(crate) fn record_yank(&mut self, text: &str) {
+        if self.yank_ring.front().is_some_and(|newest| newest == text) {
+            return;
+        }
+        self.yank_ring.push_front(text.to_string());
+        self.yank_ring.truncate(YANK_RING_SIZE);
+    }
+
+    /// Text held by register `key`: `1`-`9` read the yank ring, anything
+    /// else a named register.
+    pub(crate) fn get(&self, key: char) -> Option<&str> {
+        match key.to_digit(10) {
+            Some(0) => None,
+            Some(n) => self.yank_ring.get(n as usize - 1).map(String::as_str),
+            None => self.named.get(&key).map(String::as_str),
+        }
+    }
+
+    /// Every non-empty register, named ones sorted by key followed by the
+    /// yank ring in order — suitable for a picker.
+    pub(crate) fn entries(&self) -> Vec<(char, &str)> {
+        let mut named: Vec<(char, &str)> = self
+            .named
+            .iter()
+            .map(|(key, text)| (*key, text.as_str()))
+            .collect();
+        named.sort_by_key(|(key, _)| *key);
+        let ring = self
+            .yank_ring
+            .iter()
+            .zip('1'..='9')
+            .map(|(text, key)| (key, text.as_str()));
+        named.into_iter().chain(ring).collect()
+    }
+
+    /// Position of `text` in the yank ring, if it is there. Line breaks
+    /// are compared as LF, since the system clipboard may hand back CRLF
+    /// for text that was copied with LF (or the other way round).
+    pub(crate) fn yank_index_of(&self, text: &str) -> Option<usize> {
+        let text = normalize_line_breaks(text);
+        self.yank_ring
+            .iter()
+            .position(|entry| normalize_line_breaks(entry) == text)
+    }
+
+    /// The yank ring entry after (`forward`, i.e. older) or before `from`,
+    /// wrapping around. Without a starting entry, forward begins at the
+    /// newest copy and backward at the oldest.
+    pub(crate) fn next_yank(&self, from: Option<usize>, forward: bool) -> Option<(usize, &str)> {
+        let len = self.yank_ring.len();
+        if len == 0 {
+            return None;
+        }
+        let index = match (from, forward) {
+            (Some(i), true) => (i + 1) % len,
+            (Some(i), false) => (i + len - 1) % len,
+            (None, true) => 0,
+            (None, false) => len - 1,
+        };
+        Some((index, self.yank_ring[index].as_str()))
+    }
+
+    /// Remember a paste so it can be cycled.
+    pub(crate) fn set_paste_cycle(&mut self, cycle: PasteCycle) {
+        self.paste_cycle = Some(cycle);
+    }
+
+    /// Take the remembered paste if it is still the latest change in
+    /// `buffer_id`, whose event log is now at change `log_seq`.
+    pub(crate) fn take_paste_cycle(
+        &mut self,
+        buffer_id: BufferId,
+        log_seq: u64,
+    ) -> Option<PasteCycle> {
+        self.paste_cycle
+            .take()
+            .filter(|cycle| cycle.buffer_id == buffer_id && cycle.log_seq == log_seq)
+    }
+
+    /// The named registers, for saving to the workspace.
+    pub(crate) fn named(&self) -> &HashMap<char, String> {
+        &self.named
+    }
+
+    /// Replace the named registers with ones restored from the workspace.
+    pub(crate) fn restore_named(&mut self, named: HashMap<char, String>) {
+        self.named = named
+            .into_iter()
+            .filter(|(key, _)| Self::is_named_key(*key))
+            .collect();
+    }
+}
+
+/// `text` with CRLF and lone CR line breaks turned into LF.
+fn normalize_line_breaks(text: &str) -> std::borrow::Cow<'_, str> {
+    if text.contains('\r') {
+        text.replace("\r\n", "\n").replace('\r', "\n").into()
+    } else {
+        text.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn named_registers_are_independent() {
+        let mut r = RegisterState::default();
+        r.set('a', "alpha".into());
+        r.set('b', "beta".into());
+        r.set('a', "again".into());
+        assert_eq!(r.get('a'), Some("again"));
+        assert_eq!(r.get('b'), Some("beta"));
+        assert_eq!(r.get('c'), None);
+    }
+
+    #[test]
+    fn yank_ring_is_newest_first_and_bounded() {
+        let mut r = RegisterState::default();
+        for i in 0..12 {
+            r.record_yank(&format!("copy {i}"));
+        }
+        assert_eq!(r.get('1'), Some("copy 11"));
+        assert_eq!(r.get('9'), Some("copy 3"));
+        assert_eq!(r.get('0'), None);
+
+        // Repeating the newest copy does not shift the ring
+        r.record_yank("copy 11");
+        assert_eq!(r.get('2'), Some("copy 10"));
+    }
+
+    #[test]
+    fn entries_list_named_then_ring() {
+        let mut r = RegisterState::default();
+        r.set('b', "beta".into());
+        r.set('a', "alpha".into());
+        r.record_yank("recent");
+        assert_eq!(
+            r.entries(),
+            vec![('a', "alpha"), ('b', "beta"), ('1', "recent")]
+        );
+    }
+
+    #[test]
+    fn next_yank_wraps_in_both_directions() {
+        let mut r = RegisterState::default();
+        assert_eq!(r.next_yank(None, true), None);
+        for text in ["oldest", "middle", "newest"] {
+            r.record_yank(text);
+        }
+        assert_eq!(r.next_yank(None, true), Some((0, "newest")));
+        assert_eq!(r.next_yank(Some(0), true), Some((1, "middle")));
+        assert_eq!(r.next_yank(Some(2), true), Some((0, "newest")));
+        assert_eq!(r.next_yank(Some(0), false), Some((2, "oldest")));
+        assert_eq!(r.yank_index_of("middle"), Some(1));
+    }
+
+    #[test]
+    fn yank_index_of_ignores_line_ending_style() {
+        let mut r = RegisterState::default();
+        r.record_yank("one\ntwo");
+        r.record_yank("three\r\nfour");
+        assert_eq!(r.yank_index_of("one\r\ntwo"), Some(1));
+        assert_eq!(r.yank_index_of("three\nfour"), Some(0));
+        assert_eq!(r.yank_index_of("one two"), None);
+    }
+
+    #[test]
+    fn paste_cycle_is_dropped_once_the_log_moves_on() {
+        let mut r = RegisterState::default();
+        let cycle = PasteCycle {
+            buffer_id: BufferId(1),
+            log_seq: 4,
+            events: Vec::new(),
+            ring_index: None,
+        };
+        r.set_paste_cycle(cycle.clone());
+        assert!(r.take_paste_cycle(BufferId(1), 5).is_none());
+        // A stale cycle is gone for good
+        assert!(r.take_paste_cycle(BufferId(1), 4).is_none());
+
+        r.set_paste_cycle(cycle);
+        assert!(r.take_paste_cycle(BufferId(1), 4).is_some());
+    }
+
+    #[test]
+    fn digits_and_unnamed_are_not_writable() {
+        assert!(RegisterState::is_named_key('a'));
+        assert!(!RegisterState::is_named_key('3'));
+        assert!(!RegisterState::is_named_key(UNNAMED_REGISTER));
+    }
+}
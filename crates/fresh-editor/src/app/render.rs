@@ -69,6 +69,7 @@ impl Editor {
             self.maybe_request_semantic_tokens_range(buffer_id, start_line, end_line);
             self.maybe_request_semantic_tokens_full_debounced(buffer_id);
             self.maybe_request_folding_ranges_debounced(buffer_id);
+            self.maybe_schedule_inlay_hints_for_viewport(buffer_id, start_line, end_line);
         }
 
         {
@@ -76,6 +77,10 @@ impl Editor {
             for (split_id, view_state) in &self.split_view_states {
                 if let Some(buffer_id) = self.split_manager.get_buffer_id((*split_id).into()) {
                     if let Some(state) = self.buffers.get_mut(&buffer_id) {
+                        state.bracket_highlight_overlay.configure(
+                            self.config.editor.highlight_matching_brackets,
+                            self.config.editor.rainbow_brackets,
+                        );
                         let top_byte = view_state.viewport.top_byte;
                         let height = view_state.viewport.height;
                         if let Err(e) = state.prepare_for_render(top_byte, height) {
@@ -305,9 +310,22 @@ impl Editor {
                         );
                     let viewport_start = viewport_top_byte;
                     let viewport_end = base_tokens
-                        .last()
-                        .and_then(|t| t.source_offset)
+                        .iter()
+                        .rev()
+                        .find_map(|t| {
+                            let offset = t.source_offset?;
+                            Some(match &t.kind {
+                                fresh_core::api::ViewTokenWireKind::Text(text) => {
+                                    offset + text.len()
+                                }
+                                _ => offset + 1,
+                            })
+                        })
                         .unwrap_or(viewport_start);
+                    let lines = crate::view::ui::split_rendering::base_tokens::group_tokens_by_line(
+                        &base_tokens,
+                        viewport_start,
+                    );
                     let cursor_positions: Vec<usize> = self
                         .split_view_states
                         .get(&split_id)
@@ -321,6 +339,7 @@ impl Editor {
                             viewport_start,
                             viewport_end,
                             tokens: base_tokens,
+                            lines,
                             cursor_positions,
                         },
                     );
@@ -461,6 +480,14 @@ impl Editor {
         // popup).
         let mut pending_hardware_cursor: Option<(u16, u16)> = None;
 
+        // Keep every viewport in step with the scroll settings so edits made
+        // in the Settings UI take effect on the next frame.
+        let scroll_offset = self.config.editor.scroll_offset;
+        let overscroll = self.config.editor.overscroll;
+        for view_state in self.split_view_states.values_mut() {
+            view_state.apply_scroll_settings(scroll_offset, overscroll);
+        }
+
         let _content_span = tracing::info_span!("render_content").entered();
         let (
             split_areas,
@@ -501,6 +528,7 @@ impl Editor {
             self.software_cursor_only,
             self.config.editor.show_vertical_scrollbar,
             self.config.editor.show_horizontal_scrollbar,
+            self.config.editor.scrollbar_minimap,
             self.config.editor.diagnostics_inline_text,
             self.config.editor.show_tilde,
             self.config.editor.highlight_current_column,
@@ -754,6 +782,8 @@ impl Editor {
                 .get(&active_buf)
                 .map(|m| m.synthetic_placeholder)
                 .unwrap_or(false);
+            let status_bar_format = self.status_bar_format();
+            let editor_mode = self.effective_mode().map(|m| m.to_string());
             let mut status_ctx = crate::view::ui::status_bar::StatusBarContext {
                 state: self.buffers.get_mut(&active_buf).unwrap(),
                 cursors: status_cursors,
@@ -779,12 +809,14 @@ impl Editor {
                 // safe default for the rare path that builds the
                 // ctx but doesn't run `render_status`.
                 remote_indicator_on_bar: false,
+                editor_mode: editor_mode.as_deref(),
             };
             let status_bar_layout = StatusBarRenderer::render_status_bar(
                 frame,
                 main_chunks[status_bar_idx],
                 &mut status_ctx,
                 &self.config.editor.status_bar,
+                status_bar_format.as_ref(),
             );
 
             // Store status bar layout for click detection
@@ -1057,6 +1089,12 @@ impl Editor {
                         &theme_clone,
                         hover_target.as_ref(),
                     );
+                    if let (Some(preview), Some(preview_area)) = (
+                        popup.preview.as_deref(),
+                        popup.preview_area(*popup_area, size),
+                    ) {
+                        preview.render(frame, preview_area, &theme_clone);
+                    }
                 }
             }
         }
@@ -1679,6 +1717,8 @@ impl Editor {
                 self.config.editor.highlight_current_line,
                 self.resolve_line_wrap_for_buffer(buffer_id),
                 self.config.editor.wrap_indent,
+                self.config.editor.wrap_mode,
+                self.config.editor.wrap_indent_extra,
                 self.resolve_wrap_column_for_buffer(buffer_id),
                 self.config.editor.rulers.clone(),
             );
@@ -2573,6 +2613,31 @@ impl Editor {
         self.last_window_title = Some(new_title);
     }
 
+    /// The parsed status bar `format` template, if one is configured.
+    /// Re-parsed only when the template changes, which is also when
+    /// unknown placeholders get reported.
+    fn status_bar_format(&mut self) -> Option<crate::config::StatusBarFormat> {
+        let Some(template) = self.config.editor.status_bar.format.as_deref() else {
+            self.status_bar_format = None;
+            return None;
+        };
+        if let Some((cached, format)) = &self.status_bar_format {
+            if cached == template {
+                return Some(format.clone());
+            }
+        }
+        let format = crate::config::StatusBarFormat::parse(template);
+        for placeholder in &format.unknown {
+            tracing::warn!(
+                "Unknown status bar placeholder {} in format {:?}; showing it as text",
+                placeholder,
+                template
+            );
+        }
+        self.status_bar_format = Some((template.to_string(), format.clone()));
+        Some(format)
+    }
+
     /// Save all prompt histories to disk
     /// Called on shutdown to persist history across sessions
     pub fn save_histories(&self) {
@@ -127,7 +127,7 @@ impl Editor {
             if let Some(tokens) = view_transform_tokens {
                 // Use view-aware scrolling with the transform's tokens
                 use crate::view::ui::view_pipeline::ViewLineIterator;
-                let tab_size = self.config.editor.tab_size;
+                let tab_size = self.config.editor.effective_tab_size();
                 let view_lines: Vec<_> =
                     ViewLineIterator::new(&tokens, false, false, tab_size, false).collect();
                 view_state
@@ -205,7 +205,10 @@ impl Editor {
             return Ok(());
         }
 
-        if let Some(view_state) = self.split_view_states.get_mut(&target_split) {
+        if let (Some(view_state), Some(state)) = (
+            self.split_view_states.get_mut(&target_split),
+            self.buffers.get(&buffer_id),
+        ) {
             // Line wrap makes horizontal scroll a no-op.
             if view_state.viewport.line_wrap_enabled {
                 return Ok(());
@@ -216,13 +219,18 @@ impl Editor {
             if delta < 0 {
                 viewport.left_column = viewport.left_column.saturating_sub(columns_to_scroll);
             } else {
-                // No max_line_length_seen clamp: that value is stale between
-                // renders (often 0 before any h-scroll), pinning this at 0
-                // even when long lines exist. Overshoot clips at render.
-                viewport.left_column = viewport.left_column.saturating_add(columns_to_scroll);
+                // Stop once the longest visible line's end is on screen, so
+                // scrolling back left responds immediately.
+                let max_left_column =
+                    viewport.max_left_column(&state.buffer, state.buffer_settings.tab_size);
+                viewport.left_column = viewport
+                    .left_column
+                    .saturating_add(columns_to_scroll)
+                    .min(max_left_column.max(viewport.left_column));
             }
             viewport.set_skip_ensure_visible();
         }
+        self.move_cursor_to_visible_columns(target_split, buffer_id);
 
         Ok(())
     }
@@ -279,11 +287,23 @@ impl Editor {
         // what the renderer uses or `max_scroll_row` ends up wrong on
         // wide terminals with `composeWidth` set (mouse-wheel /
         // scrollbar-drag stop short of the buffer's tail).
-        let (wrap_width, show_line_numbers) = self
+        let (wrap_width, show_line_numbers, wrap_mode, wrap_indent_extra) = self
             .split_view_states
             .get(&split_id)
-            .map(|vs| (vs.viewport.effective_width() as usize, vs.show_line_numbers))
-            .unwrap_or((80, true));
+            .map(|vs| {
+                (
+                    vs.viewport.effective_width() as usize,
+                    vs.show_line_numbers,
+                    vs.viewport.wrap_mode,
+                    vs.viewport.wrap_indent_extra,
+                )
+            })
+            .unwrap_or((
+                80,
+                true,
+                self.config.editor.wrap_mode,
+                self.config.editor.wrap_indent_extra,
+            ));
 
         // Get the buffer state and calculate target position using RELATIVE movement
         // Returns (byte_position, view_line_offset) for proper positioning within wrapped lines
@@ -317,6 +337,8 @@ impl Editor {
                         viewport_height,
                         wrap_width,
                         show_line_numbers,
+                        wrap_mode,
+                        wrap_indent_extra,
                         pipeline_inputs_ver,
                     )
                 } else {
@@ -470,11 +492,23 @@ impl Editor {
             .map(|vs| vs.viewport.line_wrap_enabled)
             .unwrap_or(false);
 
-        let (wrap_width, show_line_numbers) = self
+        let (wrap_width, show_line_numbers, wrap_mode, wrap_indent_extra) = self
             .split_view_states
             .get(&split_id)
-            .map(|vs| (vs.viewport.effective_width() as usize, vs.show_line_numbers))
-            .unwrap_or((80, true));
+            .map(|vs| {
+                (
+                    vs.viewport.effective_width() as usize,
+                    vs.show_line_numbers,
+                    vs.viewport.wrap_mode,
+                    vs.viewport.wrap_indent_extra,
+                )
+            })
+            .unwrap_or((
+                80,
+                true,
+                self.config.editor.wrap_mode,
+                self.config.editor.wrap_indent_extra,
+            ));
 
         // Get the buffer state and calculate scroll position
         // Returns (byte_position, view_line_offset) for proper positioning within wrapped lines
@@ -484,7 +518,15 @@ impl Editor {
 
             // For small files, use precise line-based calculations
             // For large files, fall back to byte-based estimation
-            if buffer_len <= large_file_threshold {
+            if self.config.editor.scrollbar_minimap {
+                Self::minimap_jump_position(
+                    state,
+                    relative_row as usize,
+                    scrollbar_height,
+                    viewport_height,
+                    large_file_threshold,
+                )
+            } else if buffer_len <= large_file_threshold {
                 // When line wrapping is enabled, use visual row calculations
                 if line_wrap_enabled {
                     // calculate_scrollbar_jump_visual already handles max scroll limiting
@@ -501,6 +543,8 @@ impl Editor {
                         viewport_height,
                         wrap_width,
                         show_line_numbers,
+                        wrap_mode,
+                        wrap_indent_extra,
                         pipeline_inputs_ver,
                     )
                 } else {
@@ -581,6 +625,43 @@ impl Editor {
         Ok(())
     }
 
+    /// Scroll target for a click on minimap row `row`: the lines that row
+    /// condenses are centred in the viewport. Mirrors the line/byte units
+    /// of `render_minimap_scrollbar`.
+    fn minimap_jump_position(
+        state: &mut crate::state::EditorState,
+        row: usize,
+        scrollbar_height: usize,
+        viewport_height: usize,
+        large_file_threshold: usize,
+    ) -> (usize, usize) {
+        use crate::view::ui::scrollbar::MinimapScale;
+
+        let buffer_len = state.buffer.len();
+        if buffer_len == 0 {
+            return (0, 0);
+        }
+
+        if buffer_len > large_file_threshold {
+            let scale = MinimapScale::new(buffer_len, scrollbar_height);
+            let target_byte = scale.row_units(row).start.min(buffer_len - 1);
+            let line_start = state
+                .buffer
+                .line_iterator(target_byte, 80)
+                .current_position();
+            return (line_start, 0);
+        }
+
+        let total_lines = state.buffer.get_line_number(buffer_len - 1) + 1;
+        let scale = MinimapScale::new(total_lines, scrollbar_height);
+        let target_line = (scale.row_units(row).start + scale.units_per_row / 2)
+            .min(total_lines - 1)
+            .saturating_sub(viewport_height / 2);
+        let line_start = state.buffer.line_start_offset(target_line).unwrap_or(0);
+        let max_top_byte = Self::calculate_max_scroll_position(&mut state.buffer, viewport_height);
+        (line_start.min(max_top_byte), 0)
+    }
+
     /// Handle scrollbar jump (click on track) for composite buffers.
     /// Maps the click ratio to a row-based scroll position.
     fn handle_composite_scrollbar_jump(
@@ -16,6 +16,7 @@
 //! index; subsequent calls (the steady state during a drag) are pure
 //! lookups.
 
+use crate::config::WrapMode;
 use crate::model::buffer::Buffer;
 use crate::primitives::line_wrapping::WrapConfig;
 use crate::state::EditorState;
@@ -52,10 +53,19 @@ fn ensure_index(
     state: &mut EditorState,
     wrap_width: usize,
     show_line_numbers: bool,
+    wrap_mode: WrapMode,
+    wrap_indent_extra: usize,
     pipeline_inputs_ver: u64,
 ) {
     let gutter_width = estimated_gutter_width(&state.buffer, show_line_numbers);
-    let wrap_config = WrapConfig::new(wrap_width, gutter_width, true, true);
+    let wrap_config = WrapConfig::new(
+        wrap_width,
+        gutter_width,
+        true,
+        true,
+        wrap_mode,
+        wrap_indent_extra,
+    );
     let effective_width = wrap_config
         .first_line_width
         .saturating_add(gutter_width)
@@ -71,6 +81,8 @@ fn ensure_index(
         gutter_width: gutter_width as u16,
         wrap_column: None,
         hanging_indent: wrap_config.hanging_indent,
+        wrap_mode: wrap_config.wrap_mode,
+        wrap_indent_extra: wrap_config.wrap_indent_extra,
         line_wrap_enabled: true,
     };
     ensure_built(state, &key);
@@ -87,13 +99,22 @@ pub(crate) fn scrollbar_jump_visual(
     viewport_height: usize,
     wrap_width: usize,
     show_line_numbers: bool,
+    wrap_mode: WrapMode,
+    wrap_indent_extra: usize,
     pipeline_inputs_ver: u64,
 ) -> (usize, usize) {
     if state.buffer.is_empty() || viewport_height == 0 {
         return (0, 0);
     }
 
-    ensure_index(state, wrap_width, show_line_numbers, pipeline_inputs_ver);
+    ensure_index(
+        state,
+        wrap_width,
+        show_line_numbers,
+        wrap_mode,
+        wrap_indent_extra,
+        pipeline_inputs_ver,
+    );
     let total_visual_rows = state.visual_row_index.total_rows() as usize;
     if total_visual_rows == 0 {
         return (0, 0);
@@ -128,13 +149,22 @@ pub(crate) fn scrollbar_drag_relative_visual(
     viewport_height: usize,
     wrap_width: usize,
     show_line_numbers: bool,
+    wrap_mode: WrapMode,
+    wrap_indent_extra: usize,
     pipeline_inputs_ver: u64,
 ) -> (usize, usize) {
     if state.buffer.is_empty() || viewport_height == 0 || scrollbar_height <= 1 {
         return (0, 0);
     }
 
-    ensure_index(state, wrap_width, show_line_numbers, pipeline_inputs_ver);
+    ensure_index(
+        state,
+        wrap_width,
+        show_line_numbers,
+        wrap_mode,
+        wrap_indent_extra,
+        pipeline_inputs_ver,
+    );
     let total_visual_rows = state.visual_row_index.total_rows() as usize;
     if total_visual_rows == 0 {
         return (0, 0);
@@ -14,12 +14,16 @@
 use anyhow::Result as AnyhowResult;
 use rust_i18n::t;
 
-use crate::model::event::Event;
+use crate::model::event::{CursorId, Event};
 use crate::view::prompt::{Prompt, PromptType};
 
-use super::types::{InteractiveReplaceState, SearchState};
+use super::types::{InteractiveReplaceState, SearchPreviewOrigin, SearchState};
 use super::Editor;
 
+/// Most cursors "select all matches" will create; beyond this the editor
+/// stays responsive but the selection is truncated with a warning.
+const MAX_SELECT_ALL_CURSORS: usize = 1_000;
+
 enum SearchDirection {
     Forward,
     Backward,
@@ -143,6 +147,152 @@ impl Editor {
         }
     }
 
+    /// Remember where the primary cursor and viewport are, so the search
+    /// prompt can preview matches and return here if cancelled.
+    pub(super) fn begin_search_preview(&mut self) {
+        let active_split = self.split_manager.active_split();
+        self.search_preview_origin =
+            self.split_view_states
+                .get(&active_split)
+                .map(|vs| SearchPreviewOrigin {
+                    cursor: *vs.cursors.primary(),
+                    top_byte: vs.viewport.top_byte,
+                    top_view_line_offset: vs.viewport.top_view_line_offset,
+                });
+    }
+
+    /// Move the cursor to the match nearest the search origin as the query
+    /// is typed (incremental search).
+    ///
+    /// The first match at or after the origin is selected, wrapping to the
+    /// first match in the buffer. Nothing is recorded in position history;
+    /// that happens when the search is confirmed. With no match the cursor
+    /// stays where it is. Large files are only searched on confirm, by the
+    /// chunked scan.
+    ///
+    /// The buffer is searched in place and the scan stops at the first
+    /// match, so a keystroke never copies the whole buffer.
+    pub(super) fn preview_search_match(&mut self, query: &str) {
+        let Some(origin) = self.search_preview_origin else {
+            return;
+        };
+        if query.is_empty() {
+            self.apply_search_preview_origin(origin);
+            return;
+        }
+        if self.active_state().buffer.is_large_file() {
+            return;
+        }
+        // An invalid (usually half-typed) regex keeps the current preview
+        let Ok(regex) = self.build_search_regex(query) else {
+            return;
+        };
+        let Ok(regex) = regex::bytes::RegexBuilder::new(regex.as_str())
+            .case_insensitive(!self.search_case_sensitive)
+            .build()
+        else {
+            return;
+        };
+
+        let buffer = &self.active_state().buffer;
+        let (range_start, range_end) = match self.pending_search_range {
+            Some(ref range) => {
+                let end = range.end.min(buffer.len());
+                (range.start.min(end), end)
+            }
+            None => (0, buffer.len()),
+        };
+        let from = origin.cursor.position.clamp(range_start, range_end);
+        let found = buffer
+            .find_next_regex_in_range(&regex, from, Some(from..range_end))
+            .or_else(|| {
+                buffer.find_next_regex_in_range(&regex, range_start, Some(range_start..range_end))
+            })
+            .map(|match_pos| {
+                // Measure the match within the same window the chunked
+                // search overlaps by, rather than reading to the end
+                const MATCH_WINDOW: usize = 4096;
+                let window =
+                    buffer.slice_bytes(match_pos..(match_pos + MATCH_WINDOW).min(range_end));
+                let match_len = regex
+                    .find(&window)
+                    .filter(|m| m.start() == 0)
+                    .map_or(0, |m| m.len());
+                (match_pos, match_len)
+            });
+
+        let Some((match_pos, match_len)) = found else {
+            self.set_status_message(format!("No matches found for '{}'", query));
+            return;
+        };
+
+        // Select the tentative match so it stands out from the other
+        // highlighted matches
+        let active_split = self.split_manager.active_split();
+        if let Some(view_state) = self.split_view_states.get_mut(&active_split) {
+            view_state.cursors.primary_mut().anchor =
+                (match_len > 0).then_some(match_pos + match_len);
+        }
+        self.jump_active_cursor_to(
+            match_pos,
+            super::navigation::JumpOptions {
+                clear_anchor: false,
+                ..super::navigation::JumpOptions::navigation()
+            },
+        );
+    }
+
+    /// Return the cursor and viewport to where the search prompt was
+    /// opened. Called when the search prompt is cancelled.
+    pub(super) fn cancel_search_preview(&mut self) {
+        if let Some(origin) = self.search_preview_origin.take() {
+            self.apply_search_preview_origin(origin);
+        }
+    }
+
+    /// Confirm a search typed in the prompt: search from the original
+    /// cursor position, and record that position in history so Navigate
+    /// Back returns to it.
+    pub(super) fn confirm_search_preview(&mut self, query: &str) {
+        let origin = self.search_preview_origin.take();
+        if let Some(origin) = origin {
+            self.apply_search_preview_origin(origin);
+        }
+
+        self.perform_search(query);
+
+        if let Some(origin) = origin {
+            let moved = self.active_cursors().primary().position != origin.cursor.position;
+            if self.search_state.is_some() && moved {
+                let buffer_id = self.active_buffer();
+                self.position_history.commit_pending_movement();
+                self.position_history.record_movement(
+                    buffer_id,
+                    origin.cursor.position,
+                    origin.cursor.anchor,
+                );
+                self.position_history.commit_pending_movement();
+            }
+        }
+    }
+
+    fn apply_search_preview_origin(&mut self, origin: SearchPreviewOrigin) {
+        let active_split = self.split_manager.active_split();
+        let active_buffer = self.active_buffer();
+        let Some(view_state) = self.split_view_states.get_mut(&active_split) else {
+            return;
+        };
+        *view_state.cursors.primary_mut() = origin.cursor;
+        view_state.viewport.top_byte = origin.top_byte;
+        view_state.viewport.top_view_line_offset = origin.top_view_line_offset;
+        if let Some(state) = self.buffers.get_mut(&active_buffer) {
+            if let Some(pos) = state.buffer.offset_to_position(origin.cursor.position) {
+                state.primary_cursor_line_number =
+                    crate::model::buffer::LineNumber::Absolute(pos.line);
+            }
+        }
+    }
+
     /// Build a compiled regex from the current search settings and query.
     fn build_search_regex(&self, query: &str) -> Result<regex::Regex, String> {
         super::regex_replace::build_search_regex(
@@ -522,6 +672,111 @@ impl Editor {
         }
     }
 
+    /// Put a selection on every match of the active search so they can be
+    /// edited together ("select all occurrences").
+    ///
+    /// Matches come from the search overlays, so they follow edits made
+    /// since the search; for large files only the visible matches have
+    /// overlays. Overlapping ranges are dropped and the count is capped at
+    /// `MAX_SELECT_ALL_CURSORS`. The match at or after the cursor becomes
+    /// the primary cursor. One undo step restores the previous cursors.
+    pub(super) fn select_all_matches(&mut self) {
+        let Some(query) = self.search_state.as_ref().map(|ss| ss.query.clone()) else {
+            let find_key = self
+                .get_keybinding_for_action("find")
+                .unwrap_or_else(|| "Ctrl+F".to_string());
+            self.set_status_message(t!("search.no_active", find_key = find_key).to_string());
+            return;
+        };
+
+        let mut ranges: Vec<std::ops::Range<usize>> = {
+            let ns = &self.search_namespace;
+            let state = self.active_state();
+            state
+                .overlays
+                .all()
+                .iter()
+                .filter(|o| o.namespace.as_ref() == Some(ns))
+                .map(|o| o.range(&state.marker_list))
+                .filter(|r| !r.is_empty())
+                .collect()
+        };
+        ranges.sort_by_key(|r| (r.start, r.end));
+        let mut matches: Vec<std::ops::Range<usize>> = Vec::with_capacity(ranges.len());
+        for range in ranges {
+            if matches.last().is_none_or(|prev| range.start >= prev.end) {
+                matches.push(range);
+            }
+        }
+
+        if matches.is_empty() {
+            self.set_status_message(t!("search.no_occurrences", search = &query).to_string());
+            return;
+        }
+
+        let total = matches.len();
+        matches.truncate(MAX_SELECT_ALL_CURSORS);
+
+        // Rotate so the match at or after the cursor is added last and so
+        // ends up as the primary cursor
+        let cursor_pos = self.active_cursors().primary().position;
+        let current = matches.iter().position(|r| r.end > cursor_pos).unwrap_or(0);
+        matches.rotate_left((current + 1) % matches.len());
+
+        let cursors = self.active_cursors();
+        let primary_id = cursors.primary_id();
+        let primary = *cursors.primary();
+        let mut next_id = cursors.ids().iter().map(|id| id.0).max().unwrap_or(0) + 1;
+
+        let mut events: Vec<Event> = cursors
+            .iter()
+            .filter(|(id, _)| *id != primary_id)
+            .map(|(cursor_id, cursor)| Event::RemoveCursor {
+                cursor_id,
+                position: cursor.position,
+                anchor: cursor.anchor,
+            })
+            .collect();
+        events.push(Event::MoveCursor {
+            cursor_id: primary_id,
+            old_position: primary.position,
+            new_position: matches[0].end,
+            old_anchor: primary.anchor,
+            new_anchor: Some(matches[0].start),
+            old_sticky_column: primary.sticky_column,
+            new_sticky_column: 0,
+        });
+        for range in &matches[1..] {
+            events.push(Event::AddCursor {
+                cursor_id: CursorId(next_id),
+                position: range.end,
+                anchor: Some(range.start),
+            });
+            next_id += 1;
+        }
+
+        let batch = Event::Batch {
+            events,
+            description: "Select all matches".to_string(),
+        };
+        self.active_event_log_mut().append(batch.clone());
+        self.apply_event_to_active_buffer(&batch);
+        self.ensure_active_cursor_visible_for_navigation(true);
+
+        if total > matches.len() {
+            self.set_status_message(
+                t!(
+                    "search.select_all_capped",
+                    count = matches.len(),
+                    total = total
+                )
+                .to_string(),
+            );
+        } else {
+            self.set_status_message(t!("search.selected_all", count = total).to_string());
+        }
+    }
+
     /// Find the next match.
     ///
     /// For small files, overlay markers are used as the source of truth
@@ -197,15 +197,23 @@ impl Editor {
 
         // Propagate tab_size/use_tabs/auto_close/whitespace visibility to all open buffers
         // Each buffer resolves its settings from its language + the new global config
-        for state in self.buffers.values_mut() {
+        for (buffer_id, state) in self.buffers.iter_mut() {
             let mut whitespace =
                 crate::config::WhitespaceVisibility::from_editor_config(&self.config.editor);
             state.buffer_settings.auto_close = self.config.editor.auto_close;
+            state.buffer_settings.backspace_unindent = self.config.editor.backspace_unindent;
+            state.buffer_settings.indent_guides = self.config.editor.indent_guides;
+            state.buffer_settings.highlight_all_cursor_lines =
+                self.config.editor.highlight_all_cursor_lines;
+            state.buffer_settings.auto_indent = true;
             if let Some(lang_config) = self.config.languages.get(&state.language) {
-                state.buffer_settings.tab_size =
-                    lang_config.tab_size.unwrap_or(self.config.editor.tab_size);
-                state.buffer_settings.use_tabs =
-                    lang_config.use_tabs.unwrap_or(self.config.editor.use_tabs);
+                state.buffer_settings.tab_size = lang_config
+                    .tab_size_override()
+                    .unwrap_or(self.config.editor.effective_tab_size());
+                state.buffer_settings.auto_indent = lang_config.auto_indent;
+                state.buffer_settings.use_tabs = lang_config
+                    .use_tabs_override()
+                    .unwrap_or(self.config.editor.effective_use_tabs());
                 whitespace =
                     whitespace.with_language_tab_override(lang_config.show_whitespace_tabs);
                 // Auto close: language override (only if globally enabled)
@@ -221,10 +229,24 @@ impl Editor {
                     state.buffer_settings.word_characters.clear();
                 }
             } else {
-                state.buffer_settings.tab_size = self.config.editor.tab_size;
-                state.buffer_settings.use_tabs = self.config.editor.use_tabs;
+                state.buffer_settings.tab_size = self.config.editor.effective_tab_size();
+                state.buffer_settings.use_tabs = self.config.editor.effective_use_tabs();
             }
             state.buffer_settings.whitespace = whitespace;
+            state.buffer_settings.indent_width = None;
+            // Indentation detected from the file still wins
+            let detected_indent = self
+                .buffer_metadata
+                .get(buffer_id)
+                .and_then(|m| m.detected_indent)
+                .filter(|_| self.config.editor.detect_indentation);
+            if let Some(indent) = detected_indent {
+                super::indent_detection::apply_detected_indent(
+                    &mut state.buffer_settings,
+                    indent,
+                    self.config.languages.get(&state.language),
+                );
+            }
         }
 
         // Save ONLY the changes to disk (preserves external edits to the config file)
@@ -51,6 +51,7 @@ impl Editor {
                     disabled: false,
                     keybinding: None,
                     source: None,
+                    match_positions: Vec::new(),
                 }
             })
             .collect();
@@ -93,6 +94,7 @@ impl Editor {
                     disabled: false,
                     keybinding: None,
                     source: None,
+                    match_positions: Vec::new(),
                 }
             })
             .collect();
@@ -170,6 +172,7 @@ impl Editor {
                     disabled: false,
                     keybinding: None,
                     source: None,
+                    match_positions: Vec::new(),
                 }
             })
             .collect();
@@ -228,6 +231,7 @@ impl Editor {
                 disabled: false,
                 keybinding: Some("text".to_string()),
                 source: Some(CommandSource::Builtin),
+                match_positions: Vec::new(),
             },
         ];
 
@@ -291,6 +295,7 @@ impl Editor {
                 disabled: false,
                 keybinding: None,
                 source,
+                match_positions: Vec::new(),
             });
         }
 
@@ -369,6 +374,7 @@ impl Editor {
                     disabled: false,
                     keybinding: None,
                     source: None,
+                    match_positions: Vec::new(),
                 }
             })
             .collect();
@@ -395,6 +401,12 @@ impl Editor {
                 prompt.selection_anchor = Some(0);
             }
         }
+
+        // Snapshot theme files so edits made while the switcher is open
+        // are picked up by poll_theme_file_changes
+        let loader = crate::view::theme::ThemeLoader::new(self.dir_context.themes_dir());
+        self.theme_file_mod_times = Some(loader.user_theme_mtimes());
+        self.last_theme_poll = self.time_source.now();
     }
 
     /// Apply a theme by key (or name for backward compat) and persist to config
@@ -427,6 +439,7 @@ impl Editor {
                     .theme_registry
                     .portable_form(&resolved)
                     .unwrap_or(resolved);
+                self.workspace_theme = Some(to_persist.clone());
                 self.config_mut().theme = to_persist.into();
 
                 // Persist to config file
@@ -498,6 +511,49 @@ impl Editor {
         }
     }
 
+    /// Hot-reload themes whose files changed on disk while the theme
+    /// switcher is open (called from main loop), keeping the selected theme
+    /// previewed. Returns true if themes were reloaded (requires re-render).
+    pub(super) fn poll_theme_file_changes(&mut self) -> bool {
+        let selected = match &self.prompt {
+            Some(prompt) if matches!(prompt.prompt_type, PromptType::SelectTheme { .. }) => prompt
+                .selected_suggestion
+                .and_then(|i| prompt.suggestions.get(i))
+                .map(|s| s.get_value().to_string())
+                .unwrap_or_else(|| prompt.input.clone()),
+            _ => {
+                self.theme_file_mod_times = None;
+                return false;
+            }
+        };
+        if self.theme_file_mod_times.is_none() {
+            return false;
+        }
+
+        let poll_interval =
+            std::time::Duration::from_millis(self.config.editor.auto_revert_poll_interval_ms);
+        if self.time_source.elapsed_since(self.last_theme_poll) < poll_interval {
+            return false;
+        }
+        self.last_theme_poll = self.time_source.now();
+
+        let loader = crate::view::theme::ThemeLoader::new(self.dir_context.themes_dir());
+        let mod_times = loader.user_theme_mtimes();
+        if self.theme_file_mod_times.as_ref() == Some(&mod_times) {
+            return false;
+        }
+        self.theme_file_mod_times = Some(mod_times);
+
+        // reload_themes re-applies the configured theme; put the preview back
+        self.reload_themes();
+        if let Some(theme) = self.theme_registry.get_cloned(&selected) {
+            self.theme = theme;
+        }
+        self.theme.set_terminal_cursor_color();
+        self.reapply_all_overlays();
+        true
+    }
+
     /// Save the current theme setting to the user's config file
     pub(super) fn save_theme_to_config(&mut self) {
         // Create the directory if it doesn't exist
@@ -585,6 +641,7 @@ impl Editor {
                     disabled: false,
                     keybinding: None,
                     source: None,
+                    match_positions: Vec::new(),
                 }
             })
             .collect();
@@ -674,6 +731,7 @@ impl Editor {
                     disabled: false,
                     keybinding: None,
                     source: None,
+                    match_positions: Vec::new(),
                 }
             })
             .collect();
@@ -760,6 +818,7 @@ impl Editor {
                 disabled: false,
                 keybinding: None,
                 source: None,
+                match_positions: Vec::new(),
             })
             .collect();
 
@@ -841,6 +900,7 @@ impl Editor {
                     disabled: false,
                     keybinding: None,
                     source: None,
+                    match_positions: Vec::new(),
                 }
             })
             .collect();
@@ -6,6 +6,7 @@
 //! - Navigating between splits
 //! - Managing per-split view states (cursors, viewport)
 //! - Split size adjustment and maximize
+//! - Moving buffers between splits and swapping split contents
 
 use rust_i18n::t;
 
@@ -76,6 +77,8 @@ impl Editor {
                     self.config.editor.highlight_current_line,
                     self.resolve_line_wrap_for_buffer(current_buffer_id),
                     self.config.editor.wrap_indent,
+                    self.config.editor.wrap_mode,
+                    self.config.editor.wrap_indent_extra,
                     self.resolve_wrap_column_for_buffer(current_buffer_id),
                     self.config.editor.rulers.clone(),
                 );
@@ -345,6 +348,70 @@ impl Editor {
         }
     }
 
+    /// Move the active buffer's tab into the next split, closing the
+    /// current split if that was its last tab
+    pub fn move_buffer_to_next_split(&mut self) {
+        let source = self.split_manager.active_split();
+        let Some(target) = self.split_manager.next_leaf(source) else {
+            self.set_status_message(t!("split.no_other_split").to_string());
+            return;
+        };
+        let buffer_id = self.active_buffer();
+        self.move_tab_to_split(buffer_id, source, target, None);
+        self.resize_visible_terminals();
+    }
+
+    /// Exchange the contents of the active split and the next one, tabs
+    /// and per-file view state included. Focus follows the active content.
+    pub fn swap_splits(&mut self) {
+        let active = self.split_manager.active_split();
+        let Some(other) = self.split_manager.next_leaf(active) else {
+            self.set_status_message(t!("split.no_other_split").to_string());
+            return;
+        };
+        if !self.split_view_states.contains_key(&active)
+            || !self.split_view_states.contains_key(&other)
+        {
+            return;
+        }
+        let (Some(mut active_state), Some(mut other_state)) = (
+            self.split_view_states.remove(&active),
+            self.split_view_states.remove(&other),
+        ) else {
+            return;
+        };
+
+        active_state.invalidate_layout();
+        other_state.invalidate_layout();
+        self.split_view_states.insert(active, other_state);
+        self.split_view_states.insert(other, active_state);
+        self.split_manager.swap_leaf_buffers(active, other);
+        self.split_manager.set_active_split(other);
+
+        self.set_status_message(t!("split.swapped").to_string());
+        // Resize visible terminals to match their new split dimensions
+        self.resize_visible_terminals();
+    }
+
+    /// Restore all splits after the active split was maximized
+    pub fn unzoom_split(&mut self) {
+        match self.split_manager.unmaximize_split() {
+            Ok(()) => {
+                self.set_status_message(t!("split.restored").to_string());
+                self.resize_visible_terminals();
+            }
+            Err(e) => self.set_status_message(e),
+        }
+    }
+
+    /// Reset all split ratios so every pane gets an equal share
+    pub fn equalize_splits(&mut self) {
+        self.split_manager.distribute_splits_evenly();
+        self.set_status_message(t!("split.equalized").to_string());
+        // Resize visible terminals to match new split dimensions
+        self.resize_visible_terminals();
+    }
+
     /// Get cached separator areas for testing
     /// Returns (split_id, direction, x, y, length) tuples
     pub fn get_separator_areas(&self) -> &[(ContainerId, SplitDirection, u16, u16, u16)] {
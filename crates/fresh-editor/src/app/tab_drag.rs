@@ -242,7 +242,7 @@ impl Editor {
     }
 
     /// Move a tab from one split to another
-    fn move_tab_to_split(
+    pub(super) fn move_tab_to_split(
         &mut self,
         buffer_id: BufferId,
         source_split_id: LeafId,
@@ -277,16 +277,35 @@ impl Editor {
             source_view_state
                 .open_buffers
                 .retain(|t| *t != TabTarget::Buffer(buffer_id));
+        }
 
-            // If the source split was showing this buffer, switch to another
-            if self.split_manager.get_buffer_id(source_split_id.into()) == Some(buffer_id) {
-                if let Some(next_buffer) = source_view_state.buffer_tab_ids().next() {
-                    self.split_manager
-                        .set_split_buffer(source_split_id, next_buffer);
-                }
+        // If the source split was showing this buffer, switch to another
+        if self.split_manager.get_buffer_id(source_split_id.into()) == Some(buffer_id) {
+            let next_buffer = self
+                .split_view_states
+                .get(&source_split_id)
+                .and_then(|vs| vs.buffer_tab_ids().next());
+            if let Some(next_buffer) = next_buffer {
+                self.set_pane_buffer(source_split_id, next_buffer);
             }
         }
 
+        // Carry the buffer's cursors and scroll position along with the tab.
+        // A source split that is about to close is dropped here; it still
+        // has the buffer active, so its view state can't be split apart.
+        let moved_view_state = if source_becomes_empty {
+            self.split_view_states
+                .remove(&source_split_id)
+                .and_then(|mut vs| vs.keyed_states.remove(&buffer_id))
+        } else {
+            self.split_view_states
+                .get_mut(&source_split_id)
+                .and_then(|vs| {
+                    vs.remove_from_history(buffer_id);
+                    vs.keyed_states.remove(&buffer_id)
+                })
+        };
+
         // Add to target split's tab bar
         if let Some(target_view_state) = self.split_view_states.get_mut(&target_split_id) {
             // Don't add duplicate
@@ -297,11 +316,16 @@ impl Editor {
                     .open_buffers
                     .insert(final_idx, TabTarget::Buffer(buffer_id));
             }
+            if let Some(view_state) = moved_view_state {
+                target_view_state
+                    .keyed_states
+                    .entry(buffer_id)
+                    .or_insert(view_state);
+            }
         }
 
         // Focus the target split and switch to the dropped buffer
-        self.split_manager
-            .set_split_buffer(target_split_id, buffer_id);
+        self.set_pane_buffer(target_split_id, buffer_id);
         self.split_manager.set_active_split(target_split_id);
         self.set_active_buffer(buffer_id);
 
@@ -392,6 +416,8 @@ impl Editor {
                     self.config.editor.highlight_current_line,
                     self.resolve_line_wrap_for_buffer(buffer_id),
                     self.config.editor.wrap_indent,
+                    self.config.editor.wrap_mode,
+                    self.config.editor.wrap_indent_extra,
                     self.resolve_wrap_column_for_buffer(buffer_id),
                     self.config.editor.rulers.clone(),
                 );
@@ -90,10 +90,11 @@ impl Editor {
             .terminal_backing_files
             .get(&predicted_terminal_id)
             .cloned();
+        let cwd = self.new_terminal_cwd();
         match self.terminal_manager.spawn(
             cols,
             rows,
-            Some(self.working_dir.clone()),
+            Some(cwd),
             Some(log_path.clone()),
             backing_path_for_spawn,
             self.resolved_terminal_wrapper(),
@@ -122,6 +123,26 @@ impl Editor {
         }
     }
 
+    /// Directory a terminal opened from the active split starts in: the
+    /// current directory of the terminal shown there, the directory of the
+    /// file shown there, or the workspace root.
+    fn new_terminal_cwd(&self) -> std::path::PathBuf {
+        let buffer_id = self.active_buffer();
+        let dir = if self.is_terminal_buffer(buffer_id) {
+            self.get_terminal_id(buffer_id)
+                .and_then(|tid| self.terminal_manager.get(tid))
+                .and_then(|handle| handle.cwd())
+        } else {
+            self.buffers
+                .get(&buffer_id)
+                .and_then(|state| state.buffer.file_path())
+                .and_then(|path| path.parent())
+                .map(|dir| dir.to_path_buf())
+        };
+        dir.filter(|dir| self.authority.filesystem.is_dir(dir).unwrap_or(false))
+            .unwrap_or_else(|| self.working_dir.clone())
+    }
+
     /// Open a new terminal in the current split
     pub fn open_terminal(&mut self) {
         let Some(terminal_id) = self.spawn_terminal_session() else {
@@ -223,7 +244,7 @@ impl Editor {
 
         // Initialize event log for undo/redo
         self.event_logs
-            .insert(buffer_id, crate::model::event::EventLog::new());
+            .insert(buffer_id, self.new_event_log(buffer_id));
 
         // Set up split view state
         if let Some(view_state) = self.split_view_states.get_mut(&split_id) {
@@ -279,7 +300,7 @@ impl Editor {
         self.buffer_metadata.insert(buffer_id, metadata);
         self.terminal_buffers.insert(buffer_id, terminal_id);
         self.event_logs
-            .insert(buffer_id, crate::model::event::EventLog::new());
+            .insert(buffer_id, self.new_event_log(buffer_id));
 
         buffer_id
     }
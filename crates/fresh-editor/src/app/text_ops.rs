@@ -1,13 +1,15 @@
 //! Text-manipulation orchestrators on `Editor`.
 //!
-//! Smart-home, comment toggling, bracket matching — operations that read
+//! Smart-home, comment toggling, bracket matching, surround — operations that read
 //! cursor + buffer state, compute a target position or edit, and apply
 //! events to the active buffer. Pure decision logic for smart-home lives
 //! in `super::smart_home`; these methods are the cross-cutting drivers.
 
 use rust_i18n::t;
 
+use crate::input::keybindings::Action;
 use crate::model::event::{Event, LeafId};
+use crate::view::prompt::PromptType;
 
 use super::Editor;
 
@@ -324,138 +326,72 @@ impl Editor {
     }
 
     /// Go to matching bracket
+    ///
+    /// Uses the bracket the cursor is on or just after; otherwise jumps to
+    /// the closing bracket of the nearest enclosing pair.
     pub(super) fn goto_matching_bracket(&mut self) {
         let cursor = *self.active_cursors().primary();
         let cursor_id = self.active_cursors().primary_id();
         let state = self.active_state_mut();
 
         let pos = cursor.position;
-        if pos >= state.buffer.len() {
-            self.set_status_message(t!("diagnostics.bracket_none").to_string());
-            return;
-        }
-
-        let bytes = state.buffer.slice_bytes(pos..pos + 1);
-        if bytes.is_empty() {
-            self.set_status_message(t!("diagnostics.bracket_none").to_string());
-            return;
-        }
-
-        let ch = bytes[0] as char;
 
         // All supported bracket pairs
         const BRACKET_PAIRS: &[(char, char)] = &[('(', ')'), ('[', ']'), ('{', '}'), ('<', '>')];
 
-        let bracket_info = match ch {
-            '(' => Some(('(', ')', true)),
-            ')' => Some(('(', ')', false)),
-            '[' => Some(('[', ']', true)),
-            ']' => Some(('[', ']', false)),
-            '{' => Some(('{', '}', true)),
-            '}' => Some(('{', '}', false)),
-            '<' => Some(('<', '>', true)),
-            '>' => Some(('<', '>', false)),
-            _ => None,
-        };
-
         // Limit searches to avoid O(n) scans on huge files.
         use crate::view::bracket_highlight_overlay::MAX_BRACKET_SEARCH_BYTES;
 
-        // If cursor is not on a bracket, search backward for the nearest
-        // enclosing opening bracket, then jump to its matching close.
-        let (opening, closing, search_start, forward) =
-            if let Some((opening, closing, forward)) = bracket_info {
-                (opening, closing, pos, forward)
-            } else {
-                // Search backward from cursor to find enclosing opening bracket.
-                // Track depth per bracket type to handle nesting correctly.
-                let mut depths: Vec<i32> = vec![0; BRACKET_PAIRS.len()];
-                let mut found = None;
-                let search_limit = pos.saturating_sub(MAX_BRACKET_SEARCH_BYTES);
-                let mut search_pos = pos.saturating_sub(1);
-                loop {
-                    let b = state.buffer.slice_bytes(search_pos..search_pos + 1);
-                    if !b.is_empty() {
-                        let c = b[0] as char;
-                        for (i, &(open, close)) in BRACKET_PAIRS.iter().enumerate() {
-                            if c == close {
-                                depths[i] += 1;
-                            } else if c == open {
-                                if depths[i] > 0 {
-                                    depths[i] -= 1;
-                                } else {
-                                    // Found an unmatched opening bracket — this encloses us
-                                    found = Some((open, close, search_pos));
-                                    break;
-                                }
-                            }
-                        }
-                        if found.is_some() {
-                            break;
-                        }
-                    }
-                    if search_pos <= search_limit {
-                        break;
-                    }
-                    search_pos -= 1;
-                }
-
-                if let Some((opening, closing, bracket_pos)) = found {
-                    // Jump forward from the enclosing opening bracket to its match
-                    (opening, closing, bracket_pos, true)
-                } else {
-                    self.set_status_message(t!("diagnostics.bracket_none").to_string());
-                    return;
-                }
-            };
-
-        // Find matching bracket (bounded to MAX_BRACKET_SEARCH_BYTES)
-        let buffer_len = state.buffer.len();
-        let mut depth = 1;
-        let matching_pos = if forward {
-            let search_limit = (search_start + 1 + MAX_BRACKET_SEARCH_BYTES).min(buffer_len);
-            let mut search_pos = search_start + 1;
-            let mut found = None;
-            while search_pos < search_limit && depth > 0 {
-                let b = state.buffer.slice_bytes(search_pos..search_pos + 1);
-                if !b.is_empty() {
-                    let c = b[0] as char;
-                    if c == opening {
-                        depth += 1;
-                    } else if c == closing {
-                        depth -= 1;
-                        if depth == 0 {
-                            found = Some(search_pos);
-                        }
-                    }
-                }
-                search_pos += 1;
-            }
-            found
+        // A bracket at (or just before) the cursor matches the same way it
+        // is highlighted, skipping brackets inside strings and comments.
+        let matching_pos = if let Some((_, matching)) = state
+            .bracket_highlight_overlay
+            .matching_pair(&state.buffer, pos)
+        {
+            matching
         } else {
-            let search_limit = search_start.saturating_sub(MAX_BRACKET_SEARCH_BYTES);
-            let mut search_pos = search_start.saturating_sub(1);
+            // Search backward from cursor to find enclosing opening bracket.
+            // Track depth per bracket type to handle nesting correctly.
+            let mut depths: Vec<i32> = vec![0; BRACKET_PAIRS.len()];
             let mut found = None;
+            let search_limit = pos.saturating_sub(MAX_BRACKET_SEARCH_BYTES);
+            let mut search_pos = pos.saturating_sub(1);
             loop {
                 let b = state.buffer.slice_bytes(search_pos..search_pos + 1);
                 if !b.is_empty() {
                     let c = b[0] as char;
-                    if c == closing {
-                        depth += 1;
-                    } else if c == opening {
-                        depth -= 1;
-                        if depth == 0 {
-                            found = Some(search_pos);
-                            break;
+                    for (i, &(open, close)) in BRACKET_PAIRS.iter().enumerate() {
+                        if c == close {
+                            depths[i] += 1;
+                        } else if c == open {
+                            if depths[i] > 0 {
+                                depths[i] -= 1;
+                            } else {
+                                // Found an unmatched opening bracket — this encloses us
+                                found = Some(search_pos);
+                                break;
+                            }
                         }
                     }
+                    if found.is_some() {
+                        break;
+                    }
                 }
                 if search_pos <= search_limit {
                     break;
                 }
                 search_pos -= 1;
             }
-            found
+
+            let Some(bracket_pos) = found else {
+                self.set_status_message(t!("diagnostics.bracket_none").to_string());
+                return;
+            };
+            // Jump forward from the enclosing opening bracket to its match
+            state
+                .bracket_highlight_overlay
+                .matching_pair(&state.buffer, bracket_pos)
+                .and_then(|(_, matching)| matching)
         };
 
         if let Some(new_pos) = matching_pos {
@@ -474,4 +410,37 @@ impl Editor {
             self.set_status_message(t!("diagnostics.bracket_no_match").to_string());
         }
     }
+
+    /// Wrap (or unwrap) every selection in `delimiter`. An empty delimiter
+    /// opens a prompt for one; answering `t` there asks for a tag name.
+    pub(super) fn surround_selection(&mut self, delimiter: &str) {
+        let has_selection = self
+            .active_cursors()
+            .iter()
+            .any(|(_, cursor)| cursor.selection_range().is_some_and(|r| !r.is_empty()));
+        if !has_selection {
+            self.set_status_message(t!("status.no_selection").to_string());
+            return;
+        }
+
+        if delimiter.is_empty() {
+            self.start_prompt(t!("surround.prompt").to_string(), PromptType::Surround);
+            return;
+        }
+        if let Err(e) = self.apply_action_as_events(Action::Surround(delimiter.to_string())) {
+            tracing::warn!("Surround failed: {}", e);
+        }
+    }
+
+    /// Pad lines so the cursors, or `delimiter` on each cursor's line, line
+    /// up in one column. An empty delimiter aligns the cursors themselves.
+    pub(super) fn align_cursors(&mut self, delimiter: &str) {
+        if self.active_cursors().count() < 2 {
+            self.set_status_message(t!("align.needs_cursors").to_string());
+            return;
+        }
+        if let Err(e) = self.apply_action_as_events(Action::AlignCursors(delimiter.to_string())) {
+            tracing::warn!("Align cursors failed: {}", e);
+        }
+    }
 }
@@ -153,13 +153,14 @@ impl Editor {
         self.set_status_message(status.to_string());
     }
 
-    /// Reset buffer settings (tab_size, use_tabs, auto_close, whitespace visibility) to config defaults
+    /// Reset buffer settings (tab_size, use_tabs, auto_indent, auto_close, whitespace visibility) to config defaults
     pub fn reset_buffer_settings(&mut self) {
         use crate::config::WhitespaceVisibility;
         let buffer_id = self.active_buffer();
 
         // Determine settings from config using buffer's stored language
         let mut whitespace = WhitespaceVisibility::from_editor_config(&self.config.editor);
+        let mut auto_indent = true;
         let mut auto_close = self.config.editor.auto_close;
         let mut word_characters = String::new();
         let (tab_size, use_tabs) = if let Some(state) = self.buffers.get(&buffer_id) {
@@ -167,6 +168,7 @@ impl Editor {
             if let Some(lang_config) = self.config.languages.get(language) {
                 whitespace =
                     whitespace.with_language_tab_override(lang_config.show_whitespace_tabs);
+                auto_indent = lang_config.auto_indent;
                 // Auto close: language override (only if globally enabled)
                 if auto_close {
                     if let Some(lang_auto_close) = lang_config.auto_close {
@@ -177,44 +179,77 @@ impl Editor {
                     word_characters = wc.clone();
                 }
                 (
-                    lang_config.tab_size.unwrap_or(self.config.editor.tab_size),
-                    lang_config.use_tabs.unwrap_or(self.config.editor.use_tabs),
+                    lang_config
+                        .tab_size_override()
+                        .unwrap_or(self.config.editor.effective_tab_size()),
+                    lang_config
+                        .use_tabs_override()
+                        .unwrap_or(self.config.editor.effective_use_tabs()),
                 )
             } else {
-                (self.config.editor.tab_size, self.config.editor.use_tabs)
+                (
+                    self.config.editor.effective_tab_size(),
+                    self.config.editor.effective_use_tabs(),
+                )
             }
         } else {
-            (self.config.editor.tab_size, self.config.editor.use_tabs)
+            (
+                self.config.editor.effective_tab_size(),
+                self.config.editor.effective_use_tabs(),
+            )
         };
 
         // Apply settings to buffer
         if let Some(state) = self.buffers.get_mut(&buffer_id) {
             state.buffer_settings.tab_size = tab_size;
+            state.buffer_settings.indent_width = None;
             state.buffer_settings.use_tabs = use_tabs;
+            state.buffer_settings.auto_indent = auto_indent;
             state.buffer_settings.auto_close = auto_close;
+            state.buffer_settings.backspace_unindent = self.config.editor.backspace_unindent;
+            state.buffer_settings.indent_guides = self.config.editor.indent_guides;
+            state.buffer_settings.highlight_all_cursor_lines =
+                self.config.editor.highlight_all_cursor_lines;
             state.buffer_settings.whitespace = whitespace;
             state.buffer_settings.word_characters = word_characters;
         }
+        self.reapply_detected_indent(buffer_id);
 
         self.set_status_message(t!("toggle.buffer_settings_reset").to_string());
     }
 
     /// Toggle mouse capture on/off
     pub fn toggle_mouse_capture(&mut self) {
-        use std::io::stdout;
-
-        self.mouse_enabled = !self.mouse_enabled;
+        self.set_mouse_capture(!self.mouse_enabled);
 
         if self.mouse_enabled {
+            self.set_status_message(t!("toggle.mouse_capture_enabled").to_string());
+        } else {
+            self.set_status_message(t!("toggle.mouse_capture_disabled").to_string());
+        }
+    }
+
+    /// Turn mouse capture on or off, telling the terminal to start or stop
+    /// reporting mouse events. While off, mouse events are ignored and the
+    /// terminal's native selection works.
+    pub(super) fn set_mouse_capture(&mut self, enabled: bool) {
+        self.mouse_enabled = enabled;
+
+        if self.session_mode {
+            // In session mode, queue the escape sequences for the clients
+            let sequences = crate::server::mouse_tracking_sequences(
+                enabled,
+                self.config.editor.mouse_hover_enabled,
+            );
+            self.queue_escape_sequences(&sequences);
+        } else if enabled {
             // Best-effort terminal mouse capture toggle.
             #[allow(clippy::let_underscore_must_use)]
-            let _ = crossterm::execute!(stdout(), crossterm::event::EnableMouseCapture);
-            self.set_status_message(t!("toggle.mouse_capture_enabled").to_string());
+            let _ = crossterm::execute!(std::io::stdout(), crossterm::event::EnableMouseCapture);
         } else {
             // Best-effort terminal mouse capture toggle.
             #[allow(clippy::let_underscore_must_use)]
-            let _ = crossterm::execute!(stdout(), crossterm::event::DisableMouseCapture);
-            self.set_status_message(t!("toggle.mouse_capture_disabled").to_string());
+            let _ = crossterm::execute!(std::io::stdout(), crossterm::event::DisableMouseCapture);
         }
     }
 
@@ -383,6 +418,9 @@ impl Editor {
         // Update clipboard configuration
         self.clipboard.apply_config(&self.config.clipboard);
 
+        // Re-register custom commands from the new config
+        self.register_custom_commands();
+
         // Apply bar visibility changes immediately
         self.menu_bar_visible = self.config.editor.show_menu_bar;
         self.tab_bar_visible = self.config.editor.show_tab_bar;
@@ -1,5 +1,6 @@
 use crate::app::file_open::SortMode;
 use crate::model::event::{BufferId, ContainerId, LeafId, SplitDirection};
+use crate::primitives::indent_detection::DetectedIndent;
 use crate::services::async_bridge::LspMessageType;
 use ratatui::layout::Rect;
 use rust_i18n::t;
@@ -111,6 +112,20 @@ impl SearchState {
     pub const MAX_MATCHES: usize = 100_000;
 }
 
+/// Where the primary cursor and viewport were when the search prompt opened.
+///
+/// Typing in the prompt previews the nearest match by moving the cursor;
+/// Escape restores this position and Enter records it in position history.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct SearchPreviewOrigin {
+    /// Primary cursor (position, anchor, sticky column)
+    pub cursor: crate::model::cursor::Cursor,
+    /// Viewport top byte
+    pub top_byte: usize,
+    /// Viewport offset into a wrapped top line
+    pub top_view_line_offset: usize,
+}
+
 /// State for interactive replace (query-replace)
 #[derive(Debug, Clone)]
 pub(super) struct InteractiveReplaceState {
@@ -205,10 +220,18 @@ pub struct BufferMetadata {
     /// exploration flow only.
     pub is_preview: bool,
 
+    /// Whether this is a plugin scratch buffer: editable, not backed by a
+    /// file, and closed without a save prompt even when modified.
+    pub scratch: bool,
+
     /// Stable recovery ID for unnamed buffers.
     /// For file-backed buffers, recovery ID is computed from the path hash.
     /// For unnamed buffers, this is generated once and reused across auto-saves.
     pub recovery_id: Option<String>,
+
+    /// Indentation detected from the file's content when it was opened.
+    /// Overrides the configured `use_tabs`/`tab_size` for this buffer.
+    pub detected_indent: Option<DetectedIndent>,
 }
 
 impl BufferMetadata {
@@ -271,7 +294,9 @@ impl BufferMetadata {
             hidden_from_tabs: false,
             synthetic_placeholder: false,
             is_preview: false,
+            scratch: false,
             recovery_id: None,
+            detected_indent: None,
         }
     }
 
@@ -292,7 +317,9 @@ impl BufferMetadata {
             hidden_from_tabs: false,
             synthetic_placeholder: false,
             is_preview: false,
+            scratch: false,
             recovery_id: None,
+            detected_indent: None,
         }
     }
 
@@ -347,7 +374,9 @@ impl BufferMetadata {
             hidden_from_tabs: false,
             synthetic_placeholder: false,
             is_preview: false,
+            scratch: false,
             recovery_id: None,
+            detected_indent: None,
         }
     }
 
@@ -386,7 +415,9 @@ impl BufferMetadata {
             hidden_from_tabs: false,
             synthetic_placeholder: false,
             is_preview: false,
+            scratch: false,
             recovery_id: None,
+            detected_indent: None,
         }
     }
 
@@ -523,7 +554,9 @@ impl BufferMetadata {
             hidden_from_tabs: false,
             synthetic_placeholder: false,
             is_preview: false,
+            scratch: false,
             recovery_id: None,
+            detected_indent: None,
         }
     }
 
@@ -542,7 +575,9 @@ impl BufferMetadata {
             hidden_from_tabs: true,
             synthetic_placeholder: false,
             is_preview: false,
+            scratch: false,
             recovery_id: None,
+            detected_indent: None,
         }
     }
 
@@ -7,6 +7,8 @@
 //! - create_virtual_buffer / set_virtual_buffer_content: helpers for
 //!   creating buffers backed by virtual content (LSP help text, plugin
 //!   panels, search results, etc.).
+//! - open_scratch_buffer: editable plugin buffers with no file that close
+//!   without a save prompt.
 
 use std::path::Path;
 use std::sync::Arc;
@@ -84,9 +86,13 @@ impl Editor {
         state.buffer.clear_modified();
 
         // Set tab size, auto_close, and auto_surround from config
-        state.buffer_settings.tab_size = self.config.editor.tab_size;
+        state.buffer_settings.tab_size = self.config.editor.effective_tab_size();
         state.buffer_settings.auto_close = self.config.editor.auto_close;
         state.buffer_settings.auto_surround = self.config.editor.auto_surround;
+        state.buffer_settings.backspace_unindent = self.config.editor.backspace_unindent;
+        state.buffer_settings.indent_guides = self.config.editor.indent_guides;
+        state.buffer_settings.highlight_all_cursor_lines =
+            self.config.editor.highlight_all_cursor_lines;
 
         // Apply line_numbers default from config
         state
@@ -95,7 +101,7 @@ impl Editor {
 
         self.buffers.insert(buffer_id, state);
         self.event_logs
-            .insert(buffer_id, crate::model::event::EventLog::new());
+            .insert(buffer_id, self.new_event_log(buffer_id));
 
         // Create metadata for this buffer (no file path)
         let metadata =
@@ -114,6 +120,8 @@ impl Editor {
                 self.config.editor.highlight_current_line,
                 line_wrap,
                 self.config.editor.wrap_indent,
+                self.config.editor.wrap_mode,
+                self.config.editor.wrap_indent_extra,
                 wrap_column,
                 self.config.editor.rulers.clone(),
             );
@@ -273,7 +281,7 @@ impl Editor {
 
         self.buffers.insert(buffer_id, state);
         self.event_logs
-            .insert(buffer_id, crate::model::event::EventLog::new());
+            .insert(buffer_id, self.new_event_log(buffer_id));
 
         // Set virtual buffer metadata
         let metadata = super::types::BufferMetadata::virtual_buffer(name, mode, read_only);
@@ -309,7 +317,7 @@ impl Editor {
 
         self.buffers.insert(buffer_id, state);
         self.event_logs
-            .insert(buffer_id, crate::model::event::EventLog::new());
+            .insert(buffer_id, self.new_event_log(buffer_id));
 
         // Set virtual buffer metadata
         let metadata = super::types::BufferMetadata::virtual_buffer(name, mode, read_only);
@@ -327,6 +335,8 @@ impl Editor {
                 self.config.editor.highlight_current_line,
                 line_wrap,
                 self.config.editor.wrap_indent,
+                self.config.editor.wrap_mode,
+                self.config.editor.wrap_indent_extra,
                 wrap_column,
                 self.config.editor.rulers.clone(),
             );
@@ -339,6 +349,8 @@ impl Editor {
                 self.config.editor.highlight_current_line,
                 line_wrap,
                 self.config.editor.wrap_indent,
+                self.config.editor.wrap_mode,
+                self.config.editor.wrap_indent_extra,
                 wrap_column,
                 self.config.editor.rulers.clone(),
             );
@@ -348,6 +360,76 @@ impl Editor {
         buffer_id
     }
 
+    /// Open an editable scratch buffer holding `content` as a new tab in
+    /// the active split and switch to it.
+    ///
+    /// The buffer has no file behind it and is closed without a save
+    /// prompt. `language` names the syntax to highlight with ("text" for
+    /// none); when it is `None` or unknown the language is detected from
+    /// `name`, as for other virtual buffers.
+    pub fn open_scratch_buffer(
+        &mut self,
+        name: String,
+        content: String,
+        language: Option<&str>,
+    ) -> BufferId {
+        use crate::primitives::detected_language::DetectedLanguage;
+
+        let buffer_id = self.create_virtual_buffer(name, String::new(), false);
+        if let Some(meta) = self.buffer_metadata.get_mut(&buffer_id) {
+            meta.scratch = true;
+        }
+        if let Some(state) = self.buffers.get_mut(&buffer_id) {
+            state.buffer.insert(0, &content);
+            state.buffer.clear_modified();
+        }
+
+        let detected = language.and_then(|language| {
+            if language.eq_ignore_ascii_case("text") {
+                Some(DetectedLanguage::plain_text())
+            } else {
+                DetectedLanguage::from_syntax_name(
+                    language,
+                    &self.grammar_registry,
+                    &self.config.languages,
+                )
+            }
+        });
+        match detected {
+            Some(detected) => {
+                let language = detected.name.clone();
+                if let Some(state) = self.buffers.get_mut(&buffer_id) {
+                    state.apply_language(detected);
+                }
+                #[cfg(feature = "plugins")]
+                self.update_plugin_state_snapshot();
+                self.plugin_manager.run_hook(
+                    "language_changed",
+                    crate::services::plugins::hooks::HookArgs::LanguageChanged {
+                        buffer_id,
+                        language,
+                    },
+                );
+            }
+            None => {
+                if let Some(language) = language {
+                    tracing::warn!("Scratch buffer: unknown language '{}'", language);
+                }
+            }
+        }
+
+        self.set_active_buffer(buffer_id);
+        buffer_id
+    }
+
+    /// Whether `buffer_id` is a plugin scratch buffer, which is closed
+    /// without prompting even when modified.
+    pub fn is_scratch_buffer(&self, buffer_id: BufferId) -> bool {
+        self.buffer_metadata
+            .get(&buffer_id)
+            .is_some_and(|meta| meta.scratch)
+    }
+
     /// Set the content of a virtual buffer with text properties
     ///
     /// # Arguments
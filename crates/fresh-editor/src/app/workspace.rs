@@ -288,6 +288,7 @@ impl Editor {
             syntax_highlighting: Some(self.config.editor.syntax_highlighting),
             enable_inlay_hints: Some(self.config.editor.enable_inlay_hints),
             mouse_enabled: Some(self.mouse_enabled),
+            theme: self.workspace_theme.clone(),
             menu_bar_hidden: None,
         };
 
@@ -328,6 +329,19 @@ impl Editor {
         // Capture bookmarks
         let bookmarks =
             serialize_bookmarks(&self.bookmarks, &self.buffer_metadata, &self.working_dir);
+        let anonymous_bookmarks = serialize_anonymous_bookmarks(
+            &self.bookmarks,
+            &self.buffers,
+            &self.buffer_metadata,
+            &self.working_dir,
+        );
+
+        // Capture named registers if the user opted in
+        let registers = if self.config.editor.persist_registers {
+            self.registers.named().clone()
+        } else {
+            HashMap::new()
+        };
 
         // Capture external files (files outside working_dir)
         // These are stored as absolute paths since they can't be made relative
@@ -397,12 +411,15 @@ impl Editor {
             working_dir: self.working_dir.clone(),
             split_layout,
             active_split_id: SplitId::from(self.split_manager.active_split()).0,
+            maximized_split_id: self.split_manager.maximized_split().map(|id| id.0),
             split_states,
             config_overrides,
             file_explorer,
             histories,
             search_options,
             bookmarks,
+            anonymous_bookmarks,
+            registers,
             terminals,
             external_files,
             read_only_files,
@@ -741,8 +758,22 @@ impl Editor {
             self.split_manager
                 .set_active_split(LeafId(new_active_split));
         }
+        if let Some(&maximized) = workspace
+            .maximized_split_id
+            .and_then(|id| split_id_map.get(&id))
+        {
+            self.split_manager
+                .restore_maximized_split(LeafId(maximized));
+        }
 
         self.restore_bookmarks_from_workspace(&workspace.bookmarks, &path_to_buffer);
+        self.restore_anonymous_bookmarks_from_workspace(
+            &workspace.anonymous_bookmarks,
+            &path_to_buffer,
+        );
+        if self.config.editor.persist_registers {
+            self.registers.restore_named(workspace.registers.clone());
+        }
         self.clean_orphaned_buffers();
         self.log_restore_summary();
 
@@ -780,7 +811,12 @@ impl Editor {
             self.config_mut().editor.enable_inlay_hints = enable_inlay_hints;
         }
         if let Some(mouse_enabled) = overrides.mouse_enabled {
-            self.mouse_enabled = mouse_enabled;
+            if mouse_enabled != self.mouse_enabled {
+                self.set_mouse_capture(mouse_enabled);
+            }
+        }
+        if let Some(theme) = overrides.theme.as_deref() {
+            self.restore_workspace_theme(theme);
         }
         // `overrides.menu_bar_hidden` is a legacy field — kept for serde
         // compatibility with workspaces written by older builds, but no
@@ -788,6 +824,26 @@ impl Editor {
         // See issue #1156.
     }
 
+    /// Switch to the theme saved with the workspace, keeping the configured
+    /// theme when the saved one is no longer installed. The switch only
+    /// lasts for this workspace: the config keeps its own theme.
+    fn restore_workspace_theme(&mut self, key: &str) {
+        let Some(theme) = self.theme_registry.get_cloned(key) else {
+            tracing::warn!(
+                "Workspace theme '{}' not found, keeping '{}'",
+                key,
+                self.config.theme.0
+            );
+            return;
+        };
+        self.workspace_theme = Some(key.to_string());
+        if theme.name != self.theme.name {
+            self.theme = theme;
+            self.theme.set_terminal_cursor_color();
+            self.reapply_all_overlays();
+        }
+    }
+
     fn restore_search_options(&mut self, opts: &SearchOptions) {
         self.search_case_sensitive = opts.case_sensitive;
         self.search_whole_word = opts.whole_word;
@@ -1141,6 +1197,24 @@ impl Editor {
         }
     }
 
+    /// Re-create anonymous bookmarks as markers in their restored buffers.
+    fn restore_anonymous_bookmarks_from_workspace(
+        &mut self,
+        bookmarks: &[SerializedBookmark],
+        path_to_buffer: &HashMap<PathBuf, BufferId>,
+    ) {
+        for bookmark in bookmarks {
+            let Some(&buffer_id) = path_to_buffer.get(&bookmark.file_path) else {
+                continue;
+            };
+            if let Some(state) = self.buffers.get_mut(&buffer_id) {
+                let pos = bookmark.position.min(state.buffer.len());
+                let marker = state.marker_list.create(pos, true);
+                self.bookmarks.add_anonymous(buffer_id, marker);
+            }
+        }
+    }
+
     /// Drop the initial empty unnamed buffer if it is no longer referenced by any
     /// split after the workspace has been applied.
     fn clean_orphaned_buffers(&mut self) {
@@ -1205,9 +1279,10 @@ impl Editor {
     /// Restore a terminal from serialized workspace metadata.
     ///
     /// Uses the incremental streaming architecture for fast restore:
-    /// 1. Load backing file directly as read-only buffer (lazy load)
-    /// 2. Skip log replay entirely - user sees last workspace state immediately
-    /// 3. Spawn new PTY for live terminal when user re-enters terminal mode
+    /// 1. Trim the backing file to `terminal.restore_scrollback_lines`
+    /// 2. Load backing file directly as read-only buffer (lazy load)
+    /// 3. Skip log replay entirely - user sees last workspace state immediately
+    /// 4. Spawn new PTY for live terminal when user re-enters terminal mode
     ///
     /// Performance: O(1) for restore vs O(total_history) with log replay
     fn restore_terminal_from_workspace(
@@ -1236,6 +1311,10 @@ impl Editor {
                 .unwrap_or(&terminals_root),
         );
 
+        // Trim before spawning: the terminal records the backing file's
+        // length as the end of its history when it starts
+        self.trim_terminal_backing_file(&backing_path);
+
         // Record paths using the predicted ID so buffer creation can reuse them
         let predicted_id = self.terminal_manager.next_terminal_id();
         self.terminal_log_files
@@ -1243,11 +1322,18 @@ impl Editor {
         self.terminal_backing_files
             .insert(predicted_id, backing_path.clone());
 
+        // Start in the saved directory, or the workspace root if it is gone
+        let cwd = terminal
+            .cwd
+            .clone()
+            .filter(|dir| self.authority.filesystem.is_dir(dir).unwrap_or(false))
+            .unwrap_or_else(|| self.working_dir.clone());
+
         // Spawn the terminal with backing file for incremental scrollback
         let terminal_id = match self.terminal_manager.spawn(
             terminal.cols,
             terminal.rows,
-            terminal.cwd.clone(),
+            Some(cwd),
             Some(log_path.clone()),
             Some(backing_path.clone()),
             self.resolved_terminal_wrapper(),
@@ -1283,6 +1369,65 @@ impl Editor {
         Some(buffer_id)
     }
 
+    /// Keep only the last `terminal.restore_scrollback_lines` lines of a
+    /// terminal backing file so restoring a long-lived terminal stays cheap.
+    ///
+    /// Works on raw bytes, so output that isn't valid UTF-8 (binary output,
+    /// or a character cut off mid-write) is kept as written. A backing file
+    /// that can't be read is emptied so the terminal starts fresh. A missing
+    /// file is left alone; the terminal creates it when it spawns.
+    fn trim_terminal_backing_file(&self, backing_path: &Path) {
+        let fs = &self.authority.filesystem;
+        let max_lines = self.config.terminal.restore_scrollback_lines;
+
+        let bytes = match fs.read_file(backing_path) {
+            Ok(bytes) => bytes,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return,
+            Err(e) => {
+                tracing::warn!(
+                    "Unreadable terminal backing file {:?}, starting fresh: {}",
+                    backing_path,
+                    e
+                );
+                #[allow(clippy::let_underscore_must_use)]
+                let _ = fs.set_file_length(backing_path, 0);
+                return;
+            }
+        };
+        if bytes.is_empty() {
+            return;
+        }
+
+        if max_lines == 0 {
+            // Scrollback restore disabled
+            #[allow(clippy::let_underscore_must_use)]
+            let _ = fs.set_file_length(backing_path, 0);
+            return;
+        }
+
+        // Start of the last `max_lines` lines (a trailing newline doesn't
+        // begin another line)
+        let body = bytes.strip_suffix(b"\n").unwrap_or(&bytes);
+        let start = body
+            .iter()
+            .enumerate()
+            .rev()
+            .filter(|&(_, &b)| b == b'\n')
+            .nth(max_lines - 1)
+            .map_or(0, |(i, _)| i + 1);
+        if start == 0 {
+            return;
+        }
+
+        if let Err(e) = fs.write_file(backing_path, &bytes[start..]) {
+            tracing::warn!(
+                "Failed to trim terminal backing file {:?}: {}",
+                backing_path,
+                e
+            );
+        }
+    }
+
     /// Load a terminal backing file directly as a read-only buffer.
     ///
     /// This is used for fast workspace restore - we load the pre-rendered backing
@@ -1500,6 +1645,8 @@ impl Editor {
                             self.config.editor.highlight_current_line,
                             self.resolve_line_wrap_for_buffer(second_buffer_id),
                             self.config.editor.wrap_indent,
+                            self.config.editor.wrap_mode,
+                            self.config.editor.wrap_indent_extra,
                             self.resolve_wrap_column_for_buffer(second_buffer_id),
                             self.config.editor.rulers.clone(),
                         );
@@ -2151,6 +2298,36 @@ fn serialize_bookmarks(
         .collect()
 }
 
+fn serialize_anonymous_bookmarks(
+    bookmarks: &BookmarkState,
+    buffers: &HashMap<BufferId, EditorState>,
+    buffer_metadata: &HashMap<BufferId, super::types::BufferMetadata>,
+    working_dir: &Path,
+) -> Vec<SerializedBookmark> {
+    let mut serialized = Vec::new();
+    for (buffer_id, markers) in bookmarks.iter_anonymous() {
+        let (Some(state), Some(rel_path)) = (
+            buffers.get(&buffer_id),
+            buffer_metadata
+                .get(&buffer_id)
+                .and_then(|meta| meta.file_path())
+                .and_then(|abs_path| abs_path.strip_prefix(working_dir).ok()),
+        ) else {
+            continue;
+        };
+        serialized.extend(markers.iter().filter_map(|marker| {
+            state
+                .marker_list
+                .get_position(*marker)
+                .map(|position| SerializedBookmark {
+                    file_path: rel_path.to_path_buf(),
+                    position,
+                })
+        }));
+    }
+    serialized
+}
+
 /// Collect all unique file paths from split_states
 fn collect_file_paths_from_states(
     split_states: &HashMap<usize, SerializedSplitViewState>,
@@ -18,7 +18,8 @@ use std::sync::Arc;
 
 use crate::server::ipc::{ClientConnection, SocketPaths};
 use crate::server::protocol::{
-    ClientControl, ClientHello, ServerControl, TermSize, PROTOCOL_VERSION,
+    ClientControl, ClientHello, ServerControl, ServerHello, SessionInfo, TermSize, VersionMismatch,
+    PROTOCOL_VERSION,
 };
 
 #[cfg(unix)]
@@ -67,8 +68,42 @@ pub fn run_client_with_connection(
     config: ClientConfig,
     conn: ClientConnection,
 ) -> io::Result<ClientExitReason> {
-    // Perform handshake
-    let hello = ClientHello::new(config.term_size);
+    match handshake(&conn, &config.socket_paths, config.term_size)? {
+        Handshake::Connected(server_hello) => {
+            tracing::info!(
+                "Connected to session '{}' (server {})",
+                server_hello.session_id,
+                server_hello.server_version
+            );
+        }
+        Handshake::Mismatch(mismatch) => {
+            return Ok(ClientExitReason::VersionMismatch {
+                server_version: mismatch.server_version,
+            });
+        }
+    }
+
+    run_client_relay(conn)
+}
+
+/// Outcome of the handshake with a server
+#[derive(Debug)]
+pub enum Handshake {
+    /// The server accepted the client
+    Connected(ServerHello),
+    /// The server speaks an incompatible protocol version
+    Mismatch(VersionMismatch),
+}
+
+/// Send the client hello, with the token to resume this client's view by,
+/// and read the server's answer
+pub fn handshake(
+    conn: &ClientConnection,
+    socket_paths: &SocketPaths,
+    term_size: TermSize,
+) -> io::Result<Handshake> {
+    let mut hello = ClientHello::new(term_size);
+    hello.session_token = Some(socket_paths.client_token());
     let hello_json = serde_json::to_string(&ClientControl::Hello(hello))
         .map_err(|e| io::Error::other(e.to_string()))?;
     conn.write_control(&hello_json)?;
@@ -82,32 +117,83 @@ pub fn run_client_with_connection(
         serde_json::from_str(&response).map_err(|e| io::Error::other(e.to_string()))?;
 
     match server_msg {
-        ServerControl::Hello(server_hello) => {
-            if server_hello.protocol_version != PROTOCOL_VERSION {
-                return Ok(ClientExitReason::VersionMismatch {
-                    server_version: server_hello.server_version,
-                });
-            }
-            tracing::info!(
-                "Connected to session '{}' (server {})",
-                server_hello.session_id,
-                server_hello.server_version
-            );
-        }
-        ServerControl::VersionMismatch(mismatch) => {
-            return Ok(ClientExitReason::VersionMismatch {
-                server_version: mismatch.server_version,
-            });
+        ServerControl::Hello(server_hello) if server_hello.protocol_version != PROTOCOL_VERSION => {
+            // Same advice the server gives when it detects the mismatch
+            let action = if PROTOCOL_VERSION > server_hello.protocol_version {
+                "upgrade_server"
+            } else {
+                "restart_server"
+            };
+            Ok(Handshake::Mismatch(VersionMismatch {
+                client_version: env!("CARGO_PKG_VERSION").to_string(),
+                action: action.to_string(),
+                message: format!(
+                    "Protocol version mismatch: server={}, client={}",
+                    server_hello.protocol_version, PROTOCOL_VERSION
+                ),
+                server_version: server_hello.server_version,
+            }))
         }
+        ServerControl::Hello(server_hello) => Ok(Handshake::Connected(server_hello)),
+        ServerControl::VersionMismatch(mismatch) => Ok(Handshake::Mismatch(mismatch)),
         ServerControl::Error { message } => {
-            return Err(io::Error::other(format!("Server error: {}", message)));
+            Err(io::Error::other(format!("Server error: {}", message)))
         }
-        _ => {
-            return Err(io::Error::other("Unexpected server response"));
+        _ => Err(io::Error::other("Unexpected server response")),
+    }
+}
+
+/// Connect to a session's server and handshake, restarting the server once
+/// when it answers with a `restart_server` version mismatch.
+///
+/// Stopping the server discards its unsaved buffers and disconnects every
+/// other client, so `allow_restart` is asked first with the mismatch and
+/// the server's session info (`None` when the server can't report it, as
+/// servers from older builds can't).
+/// `restart` must stop the running server and start a compatible one at the
+/// same socket paths, returning once the new server accepts connections.
+/// Any other outcome, including a refused restart or a second mismatch
+/// after the restart, is returned to the caller.
+pub fn connect_with_restart(
+    socket_paths: &SocketPaths,
+    term_size: TermSize,
+    allow_restart: impl FnOnce(&VersionMismatch, Option<&SessionInfo>) -> bool,
+    restart: impl FnOnce() -> io::Result<()>,
+) -> io::Result<(ClientConnection, Handshake)> {
+    let conn = ClientConnection::connect(socket_paths)?;
+    match handshake(&conn, socket_paths, term_size)? {
+        Handshake::Mismatch(mismatch) if mismatch.action == "restart_server" => {
+            let info = socket_paths.query_session_info().ok();
+            if !allow_restart(&mismatch, info.as_ref()) {
+                return Ok((conn, Handshake::Mismatch(mismatch)));
+            }
+            tracing::info!("Restarting server: {}", mismatch.message);
+            drop(conn);
+            restart()?;
+            let conn = ClientConnection::connect(socket_paths)?;
+            let outcome = handshake(&conn, socket_paths, term_size)?;
+            Ok((conn, outcome))
         }
+        outcome => Ok((conn, outcome)),
     }
+}
 
-    run_client_relay(conn)
+/// What restarting a server with session info `info` would lose: other
+/// attached clients and unsaved buffers. A server that reports nothing
+/// (`None`, or no buffer count) is assumed to lose nothing, since there's
+/// no way to ask it.
+pub fn restart_losses(info: Option<&SessionInfo>) -> Vec<String> {
+    let Some(info) = info else {
+        return Vec::new();
+    };
+    let mut losses = Vec::new();
+    if info.client_count > 0 {
+        losses.push(format!("{} other client(s) attached", info.client_count));
+    }
+    if let Some(n) = info.modified_buffers.filter(|&n| n > 0) {
+        losses.push(format!("{} buffer(s) with unsaved changes", n));
+    }
+    losses
 }
 
 /// Run the relay loop with an already-handshaked connection
@@ -404,6 +404,11 @@ pub struct Config {
     #[serde(default)]
     pub warnings: WarningsConfig,
 
+    /// Custom shell commands shown in the command palette, keyed by the
+    /// command name (e.g. "Run tests"). Output streams into a buffer.
+    #[serde(default)]
+    pub commands: HashMap<String, CustomCommandConfig>,
+
     /// Plugin configurations by plugin name
     /// Plugins are auto-discovered from the plugins directory.
     /// Use this to enable/disable specific plugins.
@@ -534,6 +539,7 @@ impl WhitespaceVisibility {
 /// - `"{cursor:compact}"` — cursor position as `1:1`
 /// - `"{diagnostics}"` — error/warning/info counts (e.g. `E:1 W:2`)
 /// - `"{cursor_count}"` — number of active cursors (hidden when only 1)
+/// - `"{selection}"` — selected characters, lines and words (hidden without a selection)
 /// - `"{messages}"` — editor and plugin status messages
 /// - `"{chord}"` — in-progress chord key sequence
 /// - `"{line_ending}"` — line ending format (LF, CRLF, Auto)
@@ -545,6 +551,10 @@ impl WhitespaceVisibility {
 /// - `"{palette}"` — command palette shortcut hint
 /// - `"{clock}"` — current time (HH:MM) with blinking colon separator
 /// - `"{remote}"` — remote authority indicator (Local / SSH / Container / Disconnected)
+/// - `"{file}"` — bare file name, without prefixes or indicators
+/// - `"{modified}"` — `[+]` when the buffer has unsaved changes
+/// - `"{line}"` / `"{col}"` — cursor line and column numbers
+/// - `"{mode}"` — active editor mode (e.g. set by a vi-mode plugin)
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(try_from = "String", into = "String")]
 pub enum StatusBarElement {
@@ -558,6 +568,8 @@ pub enum StatusBarElement {
     Diagnostics,
     /// Active cursor count (hidden when 1)
     CursorCount,
+    /// Selected character, line and word counts (hidden without a selection)
+    Selection,
     /// Status messages from editor and plugins
     Messages,
     /// In-progress chord key sequence
@@ -583,6 +595,16 @@ pub enum StatusBarElement {
     /// the bottom-left of the status bar as a persistent remote-state entry
     /// point.
     RemoteIndicator,
+    /// Bare file name, without session/remote prefixes or indicators
+    File,
+    /// Unsaved-changes marker (`[+]`, hidden when unmodified)
+    Modified,
+    /// Cursor line number
+    Line,
+    /// Cursor column number
+    Column,
+    /// Active editor mode (hidden when no mode is active)
+    Mode,
 }
 
 impl TryFrom<String> for StatusBarElement {
@@ -599,6 +621,7 @@ impl TryFrom<String> for StatusBarElement {
             "cursor:compact" => Ok(Self::CursorCompact),
             "diagnostics" => Ok(Self::Diagnostics),
             "cursor_count" => Ok(Self::CursorCount),
+            "selection" => Ok(Self::Selection),
             "messages" => Ok(Self::Messages),
             "chord" => Ok(Self::Chord),
             "line_ending" => Ok(Self::LineEnding),
@@ -610,6 +633,11 @@ impl TryFrom<String> for StatusBarElement {
             "palette" => Ok(Self::Palette),
             "clock" => Ok(Self::Clock),
             "remote" => Ok(Self::RemoteIndicator),
+            "file" => Ok(Self::File),
+            "modified" => Ok(Self::Modified),
+            "line" => Ok(Self::Line),
+            "col" => Ok(Self::Column),
+            "mode" => Ok(Self::Mode),
             _ => Err(format!("Unknown status bar element: {}", s)),
         }
     }
@@ -623,6 +651,7 @@ impl From<StatusBarElement> for String {
             StatusBarElement::CursorCompact => "{cursor:compact}".to_string(),
             StatusBarElement::Diagnostics => "{diagnostics}".to_string(),
             StatusBarElement::CursorCount => "{cursor_count}".to_string(),
+            StatusBarElement::Selection => "{selection}".to_string(),
             StatusBarElement::Messages => "{messages}".to_string(),
             StatusBarElement::Chord => "{chord}".to_string(),
             StatusBarElement::LineEnding => "{line_ending}".to_string(),
@@ -634,6 +663,11 @@ impl From<StatusBarElement> for String {
             StatusBarElement::Palette => "{palette}".to_string(),
             StatusBarElement::Clock => "{clock}".to_string(),
             StatusBarElement::RemoteIndicator => "{remote}".to_string(),
+            StatusBarElement::File => "{file}".to_string(),
+            StatusBarElement::Modified => "{modified}".to_string(),
+            StatusBarElement::Line => "{line}".to_string(),
+            StatusBarElement::Column => "{col}".to_string(),
+            StatusBarElement::Mode => "{mode}".to_string(),
         }
     }
 }
@@ -651,6 +685,7 @@ impl schemars::JsonSchema for StatusBarElement {
                 {"value": "{cursor:compact}", "name": "Cursor (compact)"},
                 {"value": "{diagnostics}", "name": "Diagnostics"},
                 {"value": "{cursor_count}", "name": "Cursor Count"},
+                {"value": "{selection}", "name": "Selection"},
                 {"value": "{messages}", "name": "Messages"},
                 {"value": "{chord}", "name": "Chord"},
                 {"value": "{line_ending}", "name": "Line Ending"},
@@ -661,12 +696,100 @@ impl schemars::JsonSchema for StatusBarElement {
                 {"value": "{update}", "name": "Update"},
                 {"value": "{palette}", "name": "Palette"},
                 {"value": "{clock}", "name": "Clock"},
-                {"value": "{remote}", "name": "Remote Indicator"}
+                {"value": "{remote}", "name": "Remote Indicator"},
+                {"value": "{file}", "name": "File Name"},
+                {"value": "{modified}", "name": "Modified"},
+                {"value": "{line}", "name": "Line"},
+                {"value": "{col}", "name": "Column"},
+                {"value": "{mode}", "name": "Mode"}
             ]
         })
     }
 }
 
+/// One piece of a parsed status bar `format` template.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StatusBarSegment {
+    /// A `{placeholder}` filled in by the editor
+    Element(StatusBarElement),
+    /// Literal text, including unknown placeholders
+    Text(String),
+}
+
+/// A parsed status bar `format` template, split at `{spacer}` into the
+/// left-aligned and right-aligned parts.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StatusBarFormat {
+    pub left: Vec<StatusBarSegment>,
+    pub right: Vec<StatusBarSegment>,
+    /// Placeholders that didn't name an element; they render literally
+    pub unknown: Vec<String>,
+}
+
+impl StatusBarFormat {
+    /// Parse a template such as `"{filename} {modified} {spacer}{line}:{col}"`.
+    pub fn parse(template: &str) -> Self {
+        let mut format = Self::default();
+        let mut right = false;
+        let mut text = String::new();
+        let mut rest = template;
+
+        while let Some(open) = rest.find('{') {
+            text.push_str(&rest[..open]);
+            let Some(close) = rest[open..].find('}') else {
+                rest = &rest[open..];
+                break;
+            };
+            let placeholder = &rest[open..open + close + 1];
+            rest = &rest[open + close + 1..];
+
+            if placeholder == "{spacer}" {
+                format.side(right).extend(take_text(&mut text));
+                right = true;
+                continue;
+            }
+            match StatusBarElement::try_from(placeholder.to_string()) {
+                Ok(element) => {
+                    let side = format.side(right);
+                    side.extend(take_text(&mut text));
+                    side.push(StatusBarSegment::Element(element));
+                }
+                Err(_) => {
+                    format.unknown.push(placeholder.to_string());
+                    text.push_str(placeholder);
+                }
+            }
+        }
+        text.push_str(rest);
+        format.side(right).extend(take_text(&mut text));
+        format
+    }
+
+    /// All elements the template uses, on either side.
+    pub fn elements(&self) -> impl Iterator<Item = &StatusBarElement> {
+        self.left
+            .iter()
+            .chain(&self.right)
+            .filter_map(|segment| match segment {
+                StatusBarSegment::Element(element) => Some(element),
+                StatusBarSegment::Text(_) => None,
+            })
+    }
+
+    fn side(&mut self, right: bool) -> &mut Vec<StatusBarSegment> {
+        if right {
+            &mut self.right
+        } else {
+            &mut self.left
+        }
+    }
+}
+
+/// Move accumulated literal text into a segment, if there is any.
+fn take_text(text: &mut String) -> Option<StatusBarSegment> {
+    (!text.is_empty()).then(|| StatusBarSegment::Text(std::mem::take(text)))
+}
+
 fn default_status_bar_left() -> Vec<StatusBarElement> {
     // `{remote}` leads so the clickable Remote Indicator is the
     // first thing on the bottom-left, matching the spec's
@@ -685,6 +808,7 @@ fn default_status_bar_left() -> Vec<StatusBarElement> {
         StatusBarElement::Cursor,
         StatusBarElement::Diagnostics,
         StatusBarElement::CursorCount,
+        StatusBarElement::Selection,
         StatusBarElement::Messages,
     ]
 }
@@ -715,10 +839,20 @@ fn default_status_bar_right() -> Vec<StatusBarElement> {
 ///   }
 /// }
 /// ```
+///
+/// Alternatively, `format` lays out the whole bar from a template with
+/// literal text between placeholders:
+/// ```json
+/// {
+///   "status_bar": {
+///     "format": "{mode} {file}{modified} {spacer}{line}:{col} {language} {lsp}"
+///   }
+/// }
+/// ```
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct StatusBarConfig {
     /// Elements shown on the left side of the status bar.
-    /// Default: ["{filename}", "{cursor}", "{diagnostics}", "{cursor_count}", "{messages}"]
+    /// Default: ["{filename}", "{cursor}", "{diagnostics}", "{cursor_count}", "{selection}", "{messages}"]
     #[serde(default = "default_status_bar_left")]
     #[schemars(extend("x-section" = "Status Bar", "x-dual-list-sibling" = "/editor/status_bar/right"))]
     pub left: Vec<StatusBarElement>,
@@ -728,6 +862,15 @@ pub struct StatusBarConfig {
     #[serde(default = "default_status_bar_right")]
     #[schemars(extend("x-section" = "Status Bar", "x-dual-list-sibling" = "/editor/status_bar/left"))]
     pub right: Vec<StatusBarElement>,
+
+    /// Template for the whole status bar, replacing `left` and `right` when set.
+    /// Placeholders use the element names (e.g. "{file}", "{line}:{col}"),
+    /// anything else is shown as literal text, and "{spacer}" right-aligns
+    /// everything after it. Unknown placeholders are shown as-is.
+    /// Default: null (use `left` and `right`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(extend("x-section" = "Status Bar"))]
+    pub format: Option<String>,
 }
 
 impl Default for StatusBarConfig {
@@ -735,6 +878,7 @@ impl Default for StatusBarConfig {
         Self {
             left: default_status_bar_left(),
             right: default_status_bar_right(),
+            format: None,
         }
     }
 }
@@ -779,6 +923,13 @@ pub struct EditorConfig {
     #[schemars(extend("x-section" = "Display"))]
     pub highlight_current_column: bool,
 
+    /// With multiple cursors, highlight the line of every cursor rather
+    /// than only the primary one. Requires highlight_current_line.
+    /// Default: false
+    #[serde(default = "default_false")]
+    #[schemars(extend("x-section" = "Display"))]
+    pub highlight_all_cursor_lines: bool,
+
     /// Wrap long lines to fit the window width (default for new views)
     #[serde(default = "default_true")]
     #[schemars(extend("x-section" = "Display"))]
@@ -789,6 +940,19 @@ pub struct EditorConfig {
     #[schemars(extend("x-section" = "Display"))]
     pub wrap_indent: bool,
 
+    /// Extra columns to indent wrapped continuation lines by, on top of
+    /// the original line's leading whitespace. Only applies with `wrap_indent`.
+    #[serde(default)]
+    #[schemars(extend("x-section" = "Display"))]
+    pub wrap_indent_extra: usize,
+
+    /// Where wrapped lines break: `word` prefers the last space or word
+    /// boundary before the window edge (falling back to a hard break for
+    /// words longer than the width); `char` breaks at the exact column.
+    #[serde(default)]
+    #[schemars(extend("x-section" = "Display"))]
+    pub wrap_mode: WrapMode,
+
     /// Column at which to wrap lines when line wrapping is enabled.
     /// If not specified (`null`), lines wrap at the viewport edge (default behavior).
     /// Example: `80` wraps at column 80. The actual wrap column is clamped to the
@@ -872,6 +1036,14 @@ pub struct EditorConfig {
     #[schemars(extend("x-section" = "Display"))]
     pub show_horizontal_scrollbar: bool,
 
+    /// Draw the vertical scrollbar as a condensed minimap of the whole
+    /// buffer: each row shows the text density of a band of lines, with
+    /// diagnostics and search matches as colored ticks. Click to jump.
+    /// Default: false
+    #[serde(default = "default_false")]
+    #[schemars(extend("x-section" = "Display"))]
+    pub scrollbar_minimap: bool,
+
     /// Show tilde (~) markers on lines after the end of the file.
     /// These vim-style markers indicate lines that are not part of the file content.
     /// Default: true
@@ -911,6 +1083,13 @@ pub struct EditorConfig {
     #[schemars(extend("x-section" = "Display"))]
     pub rulers: Vec<usize>,
 
+    /// Draw vertical guide lines on leading whitespace at each indentation
+    /// level, with the guide of the block containing the cursor highlighted.
+    /// Default: false
+    #[serde(default = "default_false")]
+    #[schemars(extend("x-section" = "Display"))]
+    pub indent_guides: bool,
+
     // ===== Whitespace =====
     /// Master toggle for whitespace indicator visibility.
     /// When disabled, no whitespace indicators (·, →) are shown regardless
@@ -976,6 +1155,28 @@ pub struct EditorConfig {
     #[schemars(extend("x-section" = "Editing"))]
     pub tab_size: usize,
 
+    /// Indent with tab characters or spaces.
+    /// If not specified (`null`), falls back to `use_tabs`.
+    #[serde(default)]
+    #[schemars(extend("x-section" = "Editing"))]
+    pub indent_style: Option<IndentStyle>,
+
+    /// Columns per indentation level. Tab characters are displayed this
+    /// wide too, so indenting with tabs or spaces lines up the same.
+    /// If not specified (`null`), falls back to `tab_size`.
+    #[serde(default)]
+    #[schemars(extend("x-section" = "Editing"))]
+    pub indent_width: Option<usize>,
+
+    /// Detect each file's indentation (tabs or spaces, and the indent
+    /// width) from its content when it is opened, overriding `use_tabs`
+    /// and the indent width for that buffer. Languages that set
+    /// `use_tabs` or `tab_size` explicitly keep them.
+    /// Default: false
+    #[serde(default)]
+    #[schemars(extend("x-section" = "Editing"))]
+    pub detect_indentation: bool,
+
     /// Automatically indent new lines based on the previous line
     #[serde(default = "default_true")]
     #[schemars(extend("x-section" = "Editing"))]
@@ -999,11 +1200,26 @@ pub struct EditorConfig {
     #[schemars(extend("x-section" = "Editing"))]
     pub auto_surround: bool,
 
+    /// When indenting with spaces, Backspace inside a line's leading
+    /// whitespace deletes back to the previous tab stop (one indent level)
+    /// instead of a single space. Elsewhere Backspace deletes one character.
+    /// Default: true
+    #[serde(default = "default_true")]
+    #[schemars(extend("x-section" = "Editing"))]
+    pub backspace_unindent: bool,
+
     /// Minimum lines to keep visible above/below cursor when scrolling
     #[serde(default = "default_scroll_offset")]
     #[schemars(extend("x-section" = "Editing"))]
     pub scroll_offset: usize,
 
+    /// Allow scrolling past the end of the file so the last line can reach
+    /// the top of the viewport. Files shorter than the viewport never scroll.
+    /// Default: false
+    #[serde(default = "default_false")]
+    #[schemars(extend("x-section" = "Editing"))]
+    pub overscroll: bool,
+
     /// Default line ending format for new files.
     /// Files loaded from disk will use their detected line ending format.
     /// Options: "lf" (Unix/Linux/macOS), "crlf" (Windows), "cr" (Classic Mac)
@@ -1066,6 +1282,13 @@ pub struct EditorConfig {
     #[schemars(extend("x-section" = "Completion"))]
     pub quick_suggestions_delay_ms: u64,
 
+    /// Number of identifier characters that must precede the cursor before
+    /// quick suggestions request completions. Trigger characters ignore it.
+    /// Default: 1
+    #[serde(default = "default_quick_suggestions_min_chars")]
+    #[schemars(extend("x-section" = "Completion"))]
+    pub quick_suggestions_min_chars: usize,
+
     /// Whether trigger characters (like `.`, `::`, `->`) immediately show completions.
     /// When true, typing a trigger character bypasses quick_suggestions_delay_ms.
     /// Default: true
@@ -1130,8 +1353,9 @@ pub struct EditorConfig {
     #[schemars(extend("x-section" = "Recovery"))]
     pub auto_save_enabled: bool,
 
-    /// Interval in seconds for persistent auto-save.
-    /// Modified buffers are saved to their original file at this interval.
+    /// Seconds of editing inactivity before persistent auto-save.
+    /// Modified buffers are saved to their original file once no edit has
+    /// been made for this long.
     /// Only effective when auto_save_enabled is true.
     /// Default: 30 seconds
     #[serde(default = "default_auto_save_interval")]
@@ -1162,6 +1386,14 @@ pub struct EditorConfig {
     #[schemars(extend("x-section" = "Startup"))]
     pub restore_previous_session: bool,
 
+    /// Whether named clipboard registers (`"a`, `"b`, ...) are saved with
+    /// the workspace and restored on the next session in the same
+    /// directory. The numbered yank ring is never saved.
+    /// Default: false
+    #[serde(default)]
+    #[schemars(extend("x-section" = "Startup"))]
+    pub persist_registers: bool,
+
     /// When Fresh is launched with one or more file arguments (e.g.
     /// `fresh src/main.rs README.md`), skip the workspace session restore
     /// and open only the files passed on the command line. Hot-exit
@@ -1256,6 +1488,19 @@ pub struct EditorConfig {
     #[schemars(extend("x-section" = "Performance"))]
     pub snapshot_interval: usize,
 
+    /// Maximum number of undo steps kept per buffer. Older steps are
+    /// dropped once the limit is exceeded; 0 means unlimited.
+    #[serde(default = "default_undo_history_max_groups")]
+    #[schemars(extend("x-section" = "Performance"))]
+    pub undo_history_max_groups: usize,
+
+    /// Approximate memory budget for a buffer's undo history, in bytes.
+    /// The oldest undo steps are dropped when it is exceeded (the most
+    /// recent step is always kept); 0 means unlimited.
+    #[serde(default = "default_undo_history_max_bytes")]
+    #[schemars(extend("x-section" = "Performance"))]
+    pub undo_history_max_bytes: usize,
+
     /// Number of bytes to look back/forward from the viewport for syntax highlighting context.
     /// Larger values improve accuracy for multi-line constructs (strings, comments, nested blocks)
     /// but may slow down highlighting for very large files.
@@ -1296,12 +1541,69 @@ pub struct EditorConfig {
     #[serde(default = "default_file_tree_poll_interval")]
     #[schemars(extend("x-section" = "Performance"))]
     pub file_tree_poll_interval_ms: u64,
+
+    /// Minimum interval in milliseconds between deliveries of each hook in
+    /// `plugin_debounced_hooks`. Calls in between are coalesced: plugins
+    /// receive the latest one once the interval has passed.
+    /// 0 delivers every call immediately.
+    /// Default: 50ms
+    #[serde(default = "default_plugin_hook_debounce")]
+    #[schemars(extend("x-section" = "Performance"))]
+    pub plugin_hook_debounce_ms: u64,
+
+    /// Plugin hooks that fire often enough to be debounced (see
+    /// `plugin_hook_debounce_ms`). Other hooks are always delivered
+    /// immediately.
+    /// Default: ["prompt_changed", "mouse_move"]
+    #[serde(default = "default_plugin_debounced_hooks")]
+    #[schemars(extend("x-section" = "Performance"))]
+    pub plugin_debounced_hooks: Vec<String>,
+
+    /// Maximum time in milliseconds a single plugin hook handler or
+    /// command may run. A handler that runs longer is aborted and the
+    /// editor carries on as if it had returned nothing.
+    /// 0 disables the limit; handlers of a `before_*` hook are still
+    /// aborted once the editor stops waiting for them (2 seconds).
+    /// Default: 5000ms
+    #[serde(default = "default_plugin_handler_timeout")]
+    #[schemars(extend("x-section" = "Performance"))]
+    pub plugin_handler_timeout_ms: u64,
+
+    /// Number of timeouts in a row after which a plugin is disabled for
+    /// the rest of the session (see `plugin_handler_timeout_ms`).
+    /// 0 never disables a plugin.
+    /// Default: 3
+    #[serde(default = "default_plugin_disable_after_timeouts")]
+    #[schemars(extend("x-section" = "Performance"))]
+    pub plugin_disable_after_timeouts: u32,
+
+    /// Resource limits (memory and CPU) for processes started by plugins
+    /// and custom commands. LSP servers and commands with their own
+    /// `process_limits` use those instead.
+    #[serde(default)]
+    #[schemars(extend("x-section" = "Performance"))]
+    pub process_limits: ProcessLimits,
 }
 
 fn default_tab_size() -> usize {
     4
 }
 
+impl EditorConfig {
+    /// Whether Tab inserts a tab character: `indent_style` if set,
+    /// otherwise `use_tabs`.
+    pub fn effective_use_tabs(&self) -> bool {
+        self.indent_style
+            .map_or(self.use_tabs, IndentStyle::uses_tabs)
+    }
+
+    /// Columns per indent level and per tab character: `indent_width` if
+    /// set, otherwise `tab_size`.
+    pub fn effective_tab_size(&self) -> usize {
+        self.indent_width.unwrap_or(self.tab_size)
+    }
+}
+
 /// Large file threshold in bytes
 /// Files larger than this will use optimized algorithms (estimation, viewport-only parsing)
 /// Files smaller will use exact algorithms (full line tracking, complete parsing)
@@ -1339,6 +1641,10 @@ fn default_quick_suggestions_delay() -> u64 {
     150 // 150ms — fast enough to feel responsive, slow enough to not interrupt typing
 }
 
+fn default_quick_suggestions_min_chars() -> usize {
+    1
+}
+
 fn default_scroll_offset() -> usize {
     3
 }
@@ -1351,6 +1657,14 @@ fn default_snapshot_interval() -> usize {
     100
 }
 
+fn default_undo_history_max_groups() -> usize {
+    10_000
+}
+
+fn default_undo_history_max_bytes() -> usize {
+    256 * 1024 * 1024 // 256MB
+}
+
 fn default_estimated_line_length() -> usize {
     80
 }
@@ -1387,28 +1701,54 @@ fn default_file_tree_poll_interval() -> u64 {
     3000 // 3 seconds between directory mtime checks
 }
 
+fn default_plugin_hook_debounce() -> u64 {
+    50 // at most 20 deliveries per second per debounced hook
+}
+
+fn default_plugin_debounced_hooks() -> Vec<String> {
+    ["prompt_changed", "mouse_move"].map(String::from).to_vec()
+}
+
+fn default_plugin_handler_timeout() -> u64 {
+    5000 // long enough for real work, short enough to notice a hang
+}
+
+fn default_plugin_disable_after_timeouts() -> u32 {
+    3
+}
+
 impl Default for EditorConfig {
     fn default() -> Self {
         Self {
             use_tabs: false,
             tab_size: default_tab_size(),
+            indent_style: None,
+            indent_width: None,
+            detect_indentation: false,
             auto_indent: true,
             auto_close: true,
             auto_surround: true,
+            backspace_unindent: true,
             animations: true,
             cursor_jump_animation: true,
             line_numbers: true,
             relative_line_numbers: false,
             scroll_offset: default_scroll_offset(),
+            overscroll: false,
             syntax_highlighting: true,
             highlight_current_line: true,
             highlight_current_column: false,
+            highlight_all_cursor_lines: false,
             line_wrap: true,
             wrap_indent: true,
+            wrap_indent_extra: 0,
+            wrap_mode: WrapMode::default(),
             wrap_column: None,
             page_width: default_page_width(),
             highlight_timeout_ms: default_highlight_timeout(),
             snapshot_interval: default_snapshot_interval(),
+            undo_history_max_groups: default_undo_history_max_groups(),
+            undo_history_max_bytes: default_undo_history_max_bytes(),
             large_file_threshold_bytes: default_large_file_threshold(),
             estimated_line_length: default_estimated_line_length(),
             enable_inlay_hints: true,
@@ -1418,6 +1758,7 @@ impl Default for EditorConfig {
             auto_save_interval_secs: default_auto_save_interval(),
             hot_exit: true,
             restore_previous_session: true,
+            persist_registers: false,
             skip_session_restore_when_files_passed: true,
             auto_create_empty_buffer_on_last_buffer_close: true,
             recovery_enabled: true,
@@ -1429,6 +1770,11 @@ impl Default for EditorConfig {
             auto_revert_poll_interval_ms: default_auto_revert_poll_interval(),
             read_concurrency: default_read_concurrency(),
             file_tree_poll_interval_ms: default_file_tree_poll_interval(),
+            plugin_hook_debounce_ms: default_plugin_hook_debounce(),
+            plugin_debounced_hooks: default_plugin_debounced_hooks(),
+            plugin_handler_timeout_ms: default_plugin_handler_timeout(),
+            plugin_disable_after_timeouts: default_plugin_disable_after_timeouts(),
+            process_limits: ProcessLimits::default(),
             default_line_ending: LineEndingOption::default(),
             trim_trailing_whitespace_on_save: false,
             ensure_final_newline_on_save: false,
@@ -1442,6 +1788,7 @@ impl Default for EditorConfig {
             completion_popup_auto_show: false,
             quick_suggestions: true,
             quick_suggestions_delay_ms: default_quick_suggestions_delay(),
+            quick_suggestions_min_chars: default_quick_suggestions_min_chars(),
             suggest_on_trigger_characters: true,
             show_menu_bar: true,
             menu_bar_mnemonics: true,
@@ -1451,10 +1798,12 @@ impl Default for EditorConfig {
             show_prompt_line: false,
             show_vertical_scrollbar: true,
             show_horizontal_scrollbar: false,
+            scrollbar_minimap: false,
             show_tilde: true,
             use_terminal_bg: false,
             set_window_title: true,
             rulers: Vec::new(),
+            indent_guides: false,
             whitespace_show: true,
             whitespace_spaces_leading: false,
             whitespace_spaces_inner: false,
@@ -1475,6 +1824,33 @@ pub enum FileExplorerSide {
     Right,
 }
 
+/// Whether indentation is made of tab characters or spaces.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum IndentStyle {
+    /// One tab character per indent level.
+    Tabs,
+    /// `indent_width` spaces per indent level.
+    Spaces,
+}
+
+impl IndentStyle {
+    pub fn uses_tabs(self) -> bool {
+        self == Self::Tabs
+    }
+}
+
+/// How soft-wrapped lines choose their break points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum WrapMode {
+    /// Break at the window edge, even mid-word.
+    Char,
+    /// Break at word boundaries, hard-breaking only words wider than the window.
+    #[default]
+    Word,
+}
+
 /// File explorer configuration
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct FileExplorerConfig {
@@ -1772,6 +2148,16 @@ pub struct ClipboardConfig {
     /// Disable this if you don't have a display server or it causes issues
     #[serde(default = "default_true")]
     pub use_system_clipboard: bool,
+
+    /// Largest copy, in bytes, sent to the terminal via OSC 52 (default: 100000)
+    /// Many terminals silently drop larger OSC 52 payloads; bigger copies
+    /// stay in the internal clipboard and the system clipboard only
+    #[serde(default = "default_osc52_max_bytes")]
+    pub osc52_max_bytes: usize,
+}
+
+fn default_osc52_max_bytes() -> usize {
+    100_000
 }
 
 impl Default for ClipboardConfig {
@@ -1779,6 +2165,7 @@ impl Default for ClipboardConfig {
         Self {
             use_osc52: true,
             use_system_clipboard: true,
+            osc52_max_bytes: default_osc52_max_bytes(),
         }
     }
 }
@@ -1804,6 +2191,16 @@ pub struct TerminalConfig {
     /// (e.g. `docker exec`) keep their own wrapper.
     #[serde(default)]
     pub shell: Option<TerminalShellConfig>,
+
+    /// Maximum number of scrollback lines restored into a terminal when a
+    /// workspace is reopened. Older lines are dropped; 0 restores none
+    /// (default: 10000)
+    #[serde(default = "default_restore_scrollback_lines")]
+    pub restore_scrollback_lines: usize,
+}
+
+fn default_restore_scrollback_lines() -> usize {
+    10_000
 }
 
 impl Default for TerminalConfig {
@@ -1811,6 +2208,7 @@ impl Default for TerminalConfig {
         Self {
             jump_to_end_on_output: true,
             shell: None,
+            restore_scrollback_lines: default_restore_scrollback_lines(),
         }
     }
 }
@@ -1844,6 +2242,22 @@ impl Default for WarningsConfig {
     }
 }
 
+/// A custom command defined in config and run from the command palette
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct CustomCommandConfig {
+    /// Shell command line to run (e.g. "cargo test")
+    pub command: String,
+
+    /// Working directory, relative to the workspace root (default: the
+    /// workspace root)
+    #[serde(default)]
+    pub cwd: Option<String>,
+
+    /// Process resource limits (memory and CPU)
+    #[serde(default)]
+    pub process_limits: ProcessLimits,
+}
+
 /// Package manager configuration for plugins and themes
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct PackagesConfig {
@@ -2084,11 +2498,24 @@ pub struct LanguageConfig {
     #[serde(default)]
     pub tab_size: Option<usize>,
 
+    /// Indent with tab characters or spaces in this language.
+    /// Takes precedence over this language's `use_tabs`. If neither is
+    /// specified, falls back to the global editor setting.
+    #[serde(default)]
+    pub indent_style: Option<IndentStyle>,
+
+    /// Columns per indentation level (and per tab character) in this language.
+    /// Takes precedence over this language's `tab_size`. If neither is
+    /// specified, falls back to the global editor setting.
+    #[serde(default)]
+    pub indent_width: Option<usize>,
+
     /// The formatter for this language (used by format_buffer command)
     #[serde(default)]
     pub formatter: Option<FormatterConfig>,
 
-    /// Whether to automatically format on save (uses the formatter above)
+    /// Whether to automatically format on save (uses the formatter above,
+    /// or the LSP server's document formatting if no formatter is set)
     #[serde(default)]
     pub format_on_save: bool,
 
@@ -2109,6 +2536,28 @@ pub struct LanguageConfig {
     /// - Rust (default): `""` (standard alphanumeric + underscore)
     #[serde(default)]
     pub word_characters: Option<String>,
+
+    /// Command used by "Run Current File" for this language, run through
+    /// the shell with `{file}` replaced by the file's path
+    /// (e.g. `"python3 {file}"`)
+    #[serde(default)]
+    pub run_command: Option<String>,
+}
+
+impl LanguageConfig {
+    /// This language's tabs-vs-spaces override, if it sets one:
+    /// `indent_style` if set, otherwise `use_tabs`.
+    pub fn use_tabs_override(&self) -> Option<bool> {
+        self.indent_style
+            .map(IndentStyle::uses_tabs)
+            .or(self.use_tabs)
+    }
+
+    /// This language's indent width override, if it sets one:
+    /// `indent_width` if set, otherwise `tab_size`.
+    pub fn tab_size_override(&self) -> Option<usize> {
+        self.indent_width.or(self.tab_size)
+    }
 }
 
 /// Resolved editor configuration for a specific buffer.
@@ -2175,8 +2624,8 @@ impl BufferConfig {
         // Start with global editor settings
         let mut whitespace = WhitespaceVisibility::from_editor_config(editor);
         let mut config = BufferConfig {
-            tab_size: editor.tab_size,
-            use_tabs: editor.use_tabs,
+            tab_size: editor.effective_tab_size(),
+            use_tabs: editor.effective_use_tabs(),
             auto_indent: editor.auto_indent,
             auto_close: editor.auto_close,
             auto_surround: editor.auto_surround,
@@ -2207,12 +2656,12 @@ impl BufferConfig {
             });
         if let Some(lang_config) = lang_config_ref {
             // Tab size: use language setting if specified, else global
-            if let Some(ts) = lang_config.tab_size {
+            if let Some(ts) = lang_config.tab_size_override() {
                 config.tab_size = ts;
             }
 
             // Use tabs: language override (only if explicitly set)
-            if let Some(use_tabs) = lang_config.use_tabs {
+            if let Some(use_tabs) = lang_config.use_tabs_override() {
                 config.use_tabs = use_tabs;
             }
 
@@ -2226,8 +2675,8 @@ impl BufferConfig {
                 config.wrap_column = lang_config.wrap_column;
             }
 
-            // Auto indent: language override
-            config.auto_indent = lang_config.auto_indent;
+            // Auto indent: language override (only if globally enabled)
+            config.auto_indent &= lang_config.auto_indent;
 
             // Auto close: language override (only if globally enabled)
             if config.auto_close {
@@ -2396,6 +2845,7 @@ impl Default for Config {
             lsp: Self::default_lsp_config(),
             universal_lsp: Self::default_universal_lsp_config(),
             warnings: WarningsConfig::default(),
+            commands: HashMap::new(),
             plugins: HashMap::new(), // Populated when scanning for plugins
             packages: PackagesConfig::default(),
         }
@@ -2908,6 +3358,13 @@ impl MenuConfig {
                         when: None,
                         checkbox: None,
                     },
+                    MenuItem::Action {
+                        label: t!("menu.selection.select_all_matches").to_string(),
+                        action: "select_all_matches".to_string(),
+                        args: HashMap::new(),
+                        when: None,
+                        checkbox: None,
+                    },
                     MenuItem::Action {
                         label: t!("menu.selection.remove_secondary_cursors").to_string(),
                         action: "remove_secondary_cursors".to_string(),
@@ -3312,6 +3769,8 @@ impl Config {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: Some(FormatterConfig {
                     command: "rustfmt".to_string(),
                     args: vec!["--edition".to_string(), "2021".to_string()],
@@ -3321,6 +3780,7 @@ impl Config {
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -3342,6 +3802,8 @@ impl Config {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: Some(FormatterConfig {
                     command: "prettier".to_string(),
                     args: vec!["--stdin-filepath".to_string(), "$FILE".to_string()],
@@ -3351,6 +3813,7 @@ impl Config {
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: Some("node {file}".to_string()),
             },
         );
 
@@ -3372,6 +3835,8 @@ impl Config {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: Some(FormatterConfig {
                     command: "prettier".to_string(),
                     args: vec!["--stdin-filepath".to_string(), "$FILE".to_string()],
@@ -3381,6 +3846,7 @@ impl Config {
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -3402,6 +3868,8 @@ impl Config {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: Some(FormatterConfig {
                     command: "ruff".to_string(),
                     args: vec![
@@ -3415,6 +3883,7 @@ impl Config {
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: Some("python3 {file}".to_string()),
             },
         );
 
@@ -3436,6 +3905,8 @@ impl Config {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: Some(FormatterConfig {
                     command: "clang-format".to_string(),
                     args: vec![],
@@ -3445,6 +3916,7 @@ impl Config {
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -3473,6 +3945,8 @@ impl Config {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: Some(FormatterConfig {
                     command: "clang-format".to_string(),
                     args: vec![],
@@ -3482,6 +3956,7 @@ impl Config {
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -3503,10 +3978,13 @@ impl Config {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -3543,10 +4021,13 @@ impl Config {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: Some("bash {file}".to_string()),
             },
         );
 
@@ -3572,10 +4053,13 @@ impl Config {
                 page_width: None,
                 use_tabs: Some(true), // Makefiles require tabs for recipes
                 tab_size: Some(8),    // Makefiles traditionally use 8-space tabs
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -3597,10 +4081,13 @@ impl Config {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -3622,6 +4109,8 @@ impl Config {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: Some(FormatterConfig {
                     command: "prettier".to_string(),
                     args: vec!["--stdin-filepath".to_string(), "$FILE".to_string()],
@@ -3631,6 +4120,7 @@ impl Config {
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -3677,6 +4167,8 @@ impl Config {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: Some(FormatterConfig {
                     command: "prettier".to_string(),
                     args: vec!["--stdin-filepath".to_string(), "$FILE".to_string()],
@@ -3686,6 +4178,7 @@ impl Config {
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -3707,10 +4200,13 @@ impl Config {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -3732,6 +4228,8 @@ impl Config {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: Some(FormatterConfig {
                     command: "prettier".to_string(),
                     args: vec!["--stdin-filepath".to_string(), "$FILE".to_string()],
@@ -3741,6 +4239,7 @@ impl Config {
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -3762,10 +4261,13 @@ impl Config {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -3788,6 +4290,8 @@ impl Config {
                 page_width: None,
                 use_tabs: Some(true), // Go convention is to use tabs
                 tab_size: Some(8),    // Go convention is 8-space tab width
+                indent_style: None,
+                indent_width: None,
                 formatter: Some(FormatterConfig {
                     command: "gofmt".to_string(),
                     args: vec![],
@@ -3797,6 +4301,7 @@ impl Config {
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: Some("go run {file}".to_string()),
             },
         );
 
@@ -3818,10 +4323,13 @@ impl Config {
                 page_width: None,
                 use_tabs: Some(true),
                 tab_size: Some(8),
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -3843,10 +4351,13 @@ impl Config {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -3868,10 +4379,13 @@ impl Config {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -3900,10 +4414,13 @@ impl Config {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -3925,10 +4442,13 @@ impl Config {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -3951,10 +4471,13 @@ impl Config {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -3981,10 +4504,13 @@ impl Config {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -4011,10 +4537,13 @@ impl Config {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -4036,10 +4565,13 @@ impl Config {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -4061,10 +4593,13 @@ impl Config {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -4086,10 +4621,13 @@ impl Config {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -4115,10 +4653,13 @@ impl Config {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -4140,10 +4681,13 @@ impl Config {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -4165,10 +4709,13 @@ impl Config {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -4190,10 +4737,13 @@ impl Config {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -4215,10 +4765,13 @@ impl Config {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -4240,10 +4793,13 @@ impl Config {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -4265,10 +4821,13 @@ impl Config {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -4290,10 +4849,13 @@ impl Config {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -4320,10 +4882,13 @@ impl Config {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -4345,10 +4910,13 @@ impl Config {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -4370,10 +4938,13 @@ impl Config {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -4395,10 +4966,13 @@ impl Config {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: Some("perl {file}".to_string()),
             },
         );
 
@@ -4420,10 +4994,13 @@ impl Config {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -4445,10 +5022,13 @@ impl Config {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -4475,10 +5055,13 @@ impl Config {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -4500,10 +5083,13 @@ impl Config {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -4525,10 +5111,13 @@ impl Config {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -4550,10 +5139,13 @@ impl Config {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -4575,10 +5167,13 @@ impl Config {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -4600,10 +5195,13 @@ impl Config {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -4630,10 +5228,13 @@ impl Config {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -4655,10 +5256,13 @@ impl Config {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -4684,10 +5288,13 @@ impl Config {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: Some("ruby {file}".to_string()),
             },
         );
 
@@ -4709,10 +5316,13 @@ impl Config {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: Some("php {file}".to_string()),
             },
         );
 
@@ -4734,10 +5344,13 @@ impl Config {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: Some("lua {file}".to_string()),
             },
         );
 
@@ -4759,10 +5372,13 @@ impl Config {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -4784,10 +5400,13 @@ impl Config {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -4809,10 +5428,13 @@ impl Config {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -4834,10 +5456,13 @@ impl Config {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -4859,10 +5484,13 @@ impl Config {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -4884,10 +5512,13 @@ impl Config {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -4909,10 +5540,13 @@ impl Config {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -4934,10 +5568,13 @@ impl Config {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -4959,10 +5596,13 @@ impl Config {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -4984,10 +5624,13 @@ impl Config {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -5011,10 +5654,13 @@ impl Config {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -5036,10 +5682,13 @@ impl Config {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -5061,10 +5710,13 @@ impl Config {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -5086,10 +5738,13 @@ impl Config {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -5111,10 +5766,13 @@ impl Config {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -5140,10 +5798,13 @@ impl Config {
                 page_width: None,
                 use_tabs: Some(true),
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -5165,10 +5826,13 @@ impl Config {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -5190,10 +5854,13 @@ impl Config {
                 page_width: None,
                 use_tabs: Some(true),
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -5215,10 +5882,13 @@ impl Config {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -5240,10 +5910,13 @@ impl Config {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -5265,10 +5938,13 @@ impl Config {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -6500,6 +7176,11 @@ impl Config {
                 "tab_size must be greater than 0".to_string(),
             ));
         }
+        if self.editor.indent_width == Some(0) {
+            return Err(ConfigError::ValidationError(
+                "indent_width must be greater than 0".to_string(),
+            ));
+        }
 
         // Validate scroll offset
         if self.editor.scroll_offset > 100 {
@@ -6993,6 +7674,8 @@ mod tests {
                 page_width: None,
                 use_tabs: Some(true), // Go uses tabs
                 tab_size: Some(8),    // Go uses 8-space tabs
+                indent_style: None,
+                indent_width: None,
                 formatter: Some(FormatterConfig {
                     command: "gofmt".to_string(),
                     args: vec![],
@@ -7002,6 +7685,7 @@ mod tests {
                 format_on_save: true,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -7025,6 +7709,29 @@ mod tests {
         assert!(!buffer_config.use_tabs);
     }
 
+    #[test]
+    fn test_buffer_config_indent_style_and_width_take_precedence() {
+        let mut config = Config::default();
+        config.editor.use_tabs = false;
+        config.editor.tab_size = 4;
+        config.editor.indent_style = Some(IndentStyle::Tabs);
+        config.editor.indent_width = Some(2);
+
+        let buffer_config = BufferConfig::resolve(&config, None);
+        assert!(buffer_config.use_tabs);
+        assert_eq!(buffer_config.tab_size, 2);
+
+        let rust = config.languages.get_mut("rust").unwrap();
+        rust.use_tabs = Some(true);
+        rust.tab_size = Some(8);
+        rust.indent_style = Some(IndentStyle::Spaces);
+        rust.indent_width = Some(3);
+
+        let buffer_config = BufferConfig::resolve(&config, Some("rust"));
+        assert!(!buffer_config.use_tabs);
+        assert_eq!(buffer_config.tab_size, 3);
+    }
+
     #[test]
     fn test_buffer_config_per_language_line_wrap() {
         let mut config = Config::default();
@@ -7102,6 +7809,8 @@ mod tests {
             LanguageConfig {
                 use_tabs: Some(true),
                 tab_size: Some(8),
+                indent_style: None,
+                indent_width: None,
                 ..Default::default()
             },
         );
@@ -7276,4 +7985,42 @@ mod tests {
             "Default quicklsp should be merged from defaults"
         );
     }
+
+    #[test]
+    fn test_status_bar_format_parses_placeholders_and_literals() {
+        use StatusBarSegment::{Element, Text};
+
+        let format = StatusBarFormat::parse("[{file}{modified}] {spacer}{line}:{col} {bogus}");
+        assert_eq!(
+            format.left,
+            vec![
+                Text("[".to_string()),
+                Element(StatusBarElement::File),
+                Element(StatusBarElement::Modified),
+                Text("] ".to_string()),
+            ]
+        );
+        assert_eq!(
+            format.right,
+            vec![
+                Element(StatusBarElement::Line),
+                Text(":".to_string()),
+                Element(StatusBarElement::Column),
+                Text(" {bogus}".to_string()),
+            ]
+        );
+        assert_eq!(format.unknown, vec!["{bogus}".to_string()]);
+
+        // An unclosed brace is just text
+        let format = StatusBarFormat::parse("{cursor:compact} {oops");
+        assert_eq!(
+            format.left,
+            vec![
+                Element(StatusBarElement::CursorCompact),
+                Text(" {oops".to_string()),
+            ]
+        );
+        assert!(format.right.is_empty());
+        assert!(format.unknown.is_empty());
+    }
 }
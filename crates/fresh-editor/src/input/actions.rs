@@ -1,17 +1,20 @@
 //! Action to event conversion - translates high-level actions into buffer events
 
+use crate::input::align::align_cursors;
 use crate::input::keybindings::Action;
 use crate::input::line_move::{move_lines, LineMoveDirection};
+use crate::input::number_increment::increment_numbers;
+use crate::input::surround::surround_selections;
 use crate::model::buffer::{Buffer, LineEnding};
 use crate::model::buffer_position::{byte_to_2d, pos_2d_to_byte};
-use crate::model::cursor::{Cursors, Position2D, SelectionMode};
+use crate::model::cursor::{Cursor, Cursors, Position2D, SelectionMode};
 use crate::model::event::{CursorId, Event};
 use crate::primitives::display_width::{byte_offset_at_visual_column, str_width};
 use crate::primitives::highlighter::HighlightCategory;
 use crate::primitives::indent_pattern::PatternIndentCalculator;
 use crate::primitives::word_navigation::{
-    find_vi_word_end, find_word_end, find_word_end_right, find_word_start, find_word_start_left,
-    find_word_start_right,
+    find_prev_word_end, find_vi_word_end, find_word_end, find_word_end_right, find_word_start,
+    find_word_start_left, find_word_start_right, is_cursor_on_word_char,
 };
 use crate::state::EditorState;
 use std::ops::Range;
@@ -1576,6 +1579,18 @@ fn handle_select_page_down(
     }
 }
 
+/// Visual column reached by a run of leading whitespace, with tabs
+/// advancing to the next multiple of `tab_size`.
+fn indent_column(whitespace: &[u8], tab_size: usize) -> usize {
+    whitespace.iter().fold(0, |column, &b| {
+        if b == b'\t' {
+            (column / tab_size + 1) * tab_size
+        } else {
+            column + 1
+        }
+    })
+}
+
 fn handle_delete_backward(
     state: &mut EditorState,
     cursors: &Cursors,
@@ -1596,16 +1611,16 @@ fn handle_delete_backward(
                 Some((*cursor_id, range))
             } else if cursor.position > 0 {
                 // Smart backspace: if cursor is after only whitespace indentation,
-                // dedent by one indent unit instead of deleting a single character.
-                // Deletes from just before the cursor (not from line start) so the
-                // cursor naturally ends up at the right position.
+                // dedent back to the previous tab stop instead of deleting a single
+                // character. Deletes from just before the cursor (not from line start)
+                // so the cursor naturally ends up at the right position.
                 let iter = state
                     .buffer
                     .line_iterator(cursor.position, estimated_line_length);
                 let line_start = iter.current_position();
                 let prefix_len = cursor.position - line_start;
 
-                if prefix_len > 0 {
+                if state.buffer_settings.backspace_unindent && prefix_len > 0 {
                     let prefix_bytes = state.buffer.slice_bytes(line_start..cursor.position);
                     let all_whitespace = prefix_bytes.iter().all(|&b| b == b' ' || b == b'\t');
 
@@ -1614,13 +1629,20 @@ fn handle_delete_backward(
                         let chars_to_remove = if last_byte == b'\t' {
                             1
                         } else {
-                            // Count trailing spaces and remove up to tab_size
+                            // Remove trailing spaces back to the previous tab stop,
+                            // so a misaligned indent snaps to the indent grid
                             let trailing_spaces = prefix_bytes
                                 .iter()
                                 .rev()
                                 .take_while(|&&b| b == b' ')
                                 .count();
-                            trailing_spaces.min(tab_size)
+                            let tab_size = tab_size.max(1);
+                            let column = indent_column(&prefix_bytes, tab_size);
+                            let to_tab_stop = match column % tab_size {
+                                0 => tab_size,
+                                past_stop => past_stop,
+                            };
+                            trailing_spaces.min(to_tab_stop)
                         };
                         if chars_to_remove > 0 {
                             return Some((
@@ -1774,6 +1796,33 @@ fn handle_sort_lines(state: &mut EditorState, cursors: &Cursors, events: &mut Ve
     }
 }
 
+/// Whole-line block `(start, end)` covered by a cursor: its selected lines,
+/// or its current line. `end` includes the last line's terminator, if any.
+fn cursor_line_block(
+    buffer: &mut Buffer,
+    cursor: &Cursor,
+    estimated_line_length: usize,
+) -> Option<(usize, usize)> {
+    if let Some(range) = cursor.selection_range() {
+        // Has selection: the selected lines
+        let start_line = buffer.get_line_number(range.start);
+        let end_line = buffer.get_line_number(range.end.saturating_sub(1).max(range.start));
+        let line_start = buffer.line_start_offset(start_line)?;
+        // Get end of last line
+        let mut iter =
+            buffer.line_iterator(buffer.line_start_offset(end_line)?, estimated_line_length);
+        let end_line_start = iter.current_position();
+        iter.next_line()
+            .map(|(_, content)| (line_start, end_line_start + content.len()))
+    } else {
+        // No selection: the current line
+        let mut iter = buffer.line_iterator(cursor.position, estimated_line_length);
+        let line_start = iter.current_position();
+        iter.next_line()
+            .map(|(_, content)| (line_start, line_start + content.len()))
+    }
+}
+
 fn handle_duplicate_line(
     state: &mut EditorState,
     cursors: &Cursors,
@@ -1785,34 +1834,8 @@ fn handle_duplicate_line(
     let mut cursor_data: Vec<_> = cursors
         .iter()
         .filter_map(|(cursor_id, cursor)| {
-            if let Some(range) = cursor.selection_range() {
-                // Has selection: duplicate selected lines
-                let start_line = state.buffer.get_line_number(range.start);
-                let end_line = state
-                    .buffer
-                    .get_line_number(range.end.saturating_sub(1).max(range.start));
-                let line_start = state.buffer.line_start_offset(start_line)?;
-                // Get end of last line
-                let mut iter = state.buffer.line_iterator(
-                    state.buffer.line_start_offset(end_line)?,
-                    estimated_line_length,
-                );
-                let end_line_start = iter.current_position();
-                iter.next_line().map(|(_, content)| {
-                    let line_end = end_line_start + content.len();
-                    (cursor_id, line_start, line_end)
-                })
-            } else {
-                // No selection: duplicate current line
-                let mut iter = state
-                    .buffer
-                    .line_iterator(cursor.position, estimated_line_length);
-                let line_start = iter.current_position();
-                iter.next_line().map(|(_, content)| {
-                    let line_end = line_start + content.len();
-                    (cursor_id, line_start, line_end)
-                })
-            }
+            cursor_line_block(&mut state.buffer, cursor, estimated_line_length)
+                .map(|(line_start, line_end)| (cursor_id, line_start, line_end))
         })
         .collect();
     cursor_data.sort_by_key(|(_, start, _)| std::cmp::Reverse(*start));
@@ -1857,6 +1880,211 @@ fn handle_duplicate_line(
     }
 }
 
+/// Copy the lines covered by each cursor (its selected lines, or its
+/// current line) and insert the copy directly below (`down`) or above,
+/// moving the cursor and any selection onto the copy.
+fn handle_duplicate_selection(
+    state: &mut EditorState,
+    cursors: &Cursors,
+    events: &mut Vec<Event>,
+    estimated_line_length: usize,
+    down: bool,
+) {
+    let mut cursor_data: Vec<_> = cursors
+        .iter()
+        .filter_map(|(cursor_id, cursor)| {
+            cursor_line_block(&mut state.buffer, cursor, estimated_line_length)
+                .map(|(block_start, block_end)| (cursor_id, *cursor, block_start, block_end))
+        })
+        .collect();
+    // Process cursors in reverse order to avoid position shifts
+    cursor_data.sort_by_key(|(_, _, start, _)| std::cmp::Reverse(*start));
+
+    let line_ending = state.buffer.line_ending().as_str();
+    let mut copies = Vec::with_capacity(cursor_data.len());
+    for (cursor_id, cursor, block_start, block_end) in cursor_data {
+        let block_text = state.get_text_range(block_start, block_end);
+        let has_trailing_newline = block_text.ends_with('\n');
+
+        // The copy goes after the block (needing a separator first when the
+        // block is the unterminated last line) or before it
+        let (position, insert_text, copy_start) = if down {
+            if has_trailing_newline {
+                (block_end, block_text, block_end)
+            } else {
+                (
+                    block_end,
+                    format!("{}{}", line_ending, block_text),
+                    block_end + line_ending.len(),
+                )
+            }
+        } else if has_trailing_newline {
+            (block_start, block_text, block_start)
+        } else {
+            (
+                block_start,
+                format!("{}{}", block_text, line_ending),
+                block_start,
+            )
+        };
+        copies.push((
+            cursor_id,
+            cursor,
+            block_start,
+            position,
+            insert_text,
+            copy_start,
+        ));
+    }
+
+    for (cursor_id, cursor, block_start, position, insert_text, copy_start) in &copies {
+        // Multi-cursor edits are applied as one bulk edit, which expects
+        // final positions: account for copies inserted earlier in the
+        // buffer, unless the insert is at the cursor (the bulk edit shifts
+        // those itself)
+        let shift = if *position == cursor.position {
+            0
+        } else {
+            copies
+                .iter()
+                .filter(|(_, _, _, other, _, _)| other < position)
+                .map(|(_, _, _, _, text, _)| text.len())
+                .sum()
+        };
+
+        // Keep the cursor (and selection) at the same offsets within the copy
+        let new_position = shift + copy_start + (cursor.position - block_start);
+        let new_anchor = cursor
+            .anchor
+            .map(|anchor| shift + copy_start + (anchor.max(*block_start) - block_start));
+        events.push(Event::Insert {
+            position: *position,
+            text: insert_text.clone(),
+            cursor_id: *cursor_id,
+        });
+        events.push(Event::MoveCursor {
+            cursor_id: *cursor_id,
+            old_position: position + insert_text.len(),
+            new_position,
+            old_anchor: None,
+            new_anchor,
+            old_sticky_column: cursor.sticky_column,
+            new_sticky_column: cursor.sticky_column,
+        });
+    }
+}
+
+/// Read `range` from the buffer and reorder its byte sub-ranges (given in
+/// buffer offsets) into `order`. Returns the original and reordered text,
+/// or `None` if the range can't be read or isn't valid UTF-8, so a swap
+/// never mangles bytes the way a lossy conversion would.
+fn reorder_text_range(
+    state: &mut EditorState,
+    range: Range<usize>,
+    order: &[Range<usize>],
+) -> Option<(String, String)> {
+    let bytes = state
+        .buffer
+        .get_text_range_mut(range.start, range.len())
+        .ok()?;
+    if bytes.len() != range.len() {
+        return None;
+    }
+    let reordered: Vec<u8> = order
+        .iter()
+        .flat_map(|part| &bytes[part.start - range.start..part.end - range.start])
+        .copied()
+        .collect();
+    Some((
+        String::from_utf8(bytes).ok()?,
+        String::from_utf8(reordered).ok()?,
+    ))
+}
+
+/// Swap the character before each cursor with the one at the cursor,
+/// leaving the cursor after both (readline/Emacs C-t). No-op at the start
+/// or end of the buffer and when either character is a line break.
+fn handle_transpose_chars(state: &mut EditorState, cursors: &Cursors, events: &mut Vec<Event>) {
+    let cursor_positions: Vec<_> = cursors.iter().map(|(id, c)| (id, c.position)).collect();
+
+    for (cursor_id, pos) in cursor_positions {
+        if pos == 0 || pos >= state.buffer.len() {
+            continue;
+        }
+        let before = state.buffer.prev_char_boundary(pos);
+        let after = state.buffer.next_char_boundary(pos);
+        if before >= pos || after <= pos {
+            continue;
+        }
+        let Some((text, swapped)) =
+            reorder_text_range(state, before..after, &[pos..after, before..pos])
+        else {
+            continue;
+        };
+        if text.contains(['\n', '\r']) {
+            continue;
+        }
+
+        events.push(Event::Delete {
+            range: before..after,
+            deleted_text: text,
+            cursor_id,
+        });
+        events.push(Event::Insert {
+            position: before,
+            text: swapped,
+            cursor_id,
+        });
+    }
+}
+
+/// Swap the word at or before each cursor with the following word,
+/// leaving the cursor after both (Emacs M-t). A cursor inside a word
+/// swaps that word with the next one. No-op without a following word.
+fn handle_transpose_words(state: &mut EditorState, cursors: &Cursors, events: &mut Vec<Event>) {
+    let cursor_positions: Vec<_> = cursors.iter().map(|(id, c)| (id, c.position)).collect();
+
+    for (cursor_id, pos) in cursor_positions {
+        let buffer = &state.buffer;
+        let inside_word = pos > 0
+            && is_cursor_on_word_char(buffer, pos)
+            && is_cursor_on_word_char(buffer, buffer.prev_char_boundary(pos));
+        let first_end = if inside_word {
+            find_word_end(buffer, pos)
+        } else {
+            find_prev_word_end(buffer, pos)
+        };
+        let first_start = find_word_start(buffer, first_end);
+        let second_end = find_word_end(buffer, first_end);
+        let second_start = find_word_start(buffer, second_end);
+        if !(first_start < first_end && first_end <= second_start && second_start < second_end) {
+            continue;
+        }
+
+        let Some((text, swapped)) = reorder_text_range(
+            state,
+            first_start..second_end,
+            &[
+                second_start..second_end,
+                first_end..second_start,
+                first_start..first_end,
+            ],
+        ) else {
+            continue;
+        };
+        events.push(Event::Delete {
+            range: first_start..second_end,
+            deleted_text: text,
+            cursor_id,
+        });
+        events.push(Event::Insert {
+            position: first_start,
+            text: swapped,
+            cursor_id,
+        });
+    }
+}
+
 /// Convert an action into a sequence of events that can be applied to the editor state
 ///
 /// # Parameters
@@ -2838,32 +3066,25 @@ pub fn action_to_events(
         }
 
         Action::TransposeChars => {
-            // Transpose the character before the cursor with the one at the cursor
-            // Collect cursor positions first to avoid borrow issues
-            let cursor_positions: Vec<_> = cursors.iter().map(|(id, c)| (id, c.position)).collect();
-
-            for (cursor_id, pos) in cursor_positions {
-                // Need at least 2 characters: one before and one at cursor
-                if pos > 0 && pos < state.buffer.len() {
-                    // Get the two characters as a string
-                    let text = state.get_text_range(pos - 1, pos + 1);
-                    let chars: Vec<char> = text.chars().collect();
-                    if chars.len() >= 2 {
-                        // Delete both characters and insert them swapped
-                        events.push(Event::Delete {
-                            range: (pos - 1)..(pos + 1),
-                            deleted_text: text,
-                            cursor_id,
-                        });
-                        let swapped = format!("{}{}", chars[1], chars[0]);
-                        events.push(Event::Insert {
-                            position: pos - 1,
-                            text: swapped,
-                            cursor_id,
-                        });
-                    }
-                }
-            }
+            handle_transpose_chars(state, cursors, &mut events);
+        }
+
+        Action::TransposeWords => {
+            handle_transpose_words(state, cursors, &mut events);
+        }
+
+        Action::Surround(delimiter) => {
+            surround_selections(state, cursors, &mut events, &delimiter);
+        }
+
+        Action::AlignCursors(delimiter) => {
+            align_cursors(
+                state,
+                cursors,
+                &mut events,
+                &delimiter,
+                estimated_line_length,
+            );
         }
 
         Action::ToUpperCase => {
@@ -2882,6 +3103,14 @@ pub fn action_to_events(
             handle_sort_lines(state, cursors, &mut events);
         }
 
+        Action::IncrementNumber => {
+            increment_numbers(state, cursors, &mut events, 1, estimated_line_length);
+        }
+
+        Action::DecrementNumber => {
+            increment_numbers(state, cursors, &mut events, -1, estimated_line_length);
+        }
+
         Action::OpenLine => {
             // Insert a newline at the cursor position and immediately
             // move the cursor back — Emacs C-o semantics ("open a
@@ -2913,6 +3142,14 @@ pub fn action_to_events(
             handle_duplicate_line(state, cursors, &mut events, estimated_line_length);
         }
 
+        Action::DuplicateSelectionDown => {
+            handle_duplicate_selection(state, cursors, &mut events, estimated_line_length, true);
+        }
+
+        Action::DuplicateSelectionUp => {
+            handle_duplicate_selection(state, cursors, &mut events, estimated_line_length, false);
+        }
+
         Action::Recenter => {
             // Scroll so that the cursor is centered in the view
             // This is handled specially - we emit a Recenter event
@@ -2953,6 +3190,21 @@ pub fn action_to_events(
             }
         }
 
+        Action::CollapseToSinglePrimary => {
+            // Unlike RemoveSecondaryCursors, keep the primary (not the
+            // original) cursor, selection and all
+            let primary_id = cursors.primary_id();
+            for (cursor_id, cursor) in cursors.iter() {
+                if cursor_id != primary_id {
+                    events.push(Event::RemoveCursor {
+                        cursor_id,
+                        position: cursor.position,
+                        anchor: cursor.anchor,
+                    });
+                }
+            }
+        }
+
         Action::ScrollUp => {
             events.push(Event::Scroll { line_offset: -1 });
         }
@@ -2972,6 +3224,8 @@ pub fn action_to_events(
         | Action::New
         | Action::Close
         | Action::CloseTab
+        | Action::CloseOtherTabs
+        | Action::CloseTabsToRight
         | Action::GotoLine
         | Action::ScanLineIndex
         | Action::NextBuffer
@@ -2991,16 +3245,29 @@ pub fn action_to_events(
         | Action::CopyRelativeFilePath
         | Action::Cut
         | Action::Paste
+        | Action::CopyToRegister(_)
+        | Action::CutToRegister(_)
+        | Action::PasteFromRegister(_)
+        | Action::PromptCopyToRegister
+        | Action::PromptAlignCursors
+        | Action::PromptCutToRegister
+        | Action::PromptPasteFromRegister
+        | Action::CyclePasteForward
+        | Action::CyclePasteBackward
         | Action::YankWordForward
         | Action::YankWordBackward
         | Action::YankToLineEnd
         | Action::YankToLineStart
         | Action::YankViWordEnd
         | Action::AddCursorNextMatch
+        | Action::SkipLastSelectionMatch
+        | Action::SelectAllMatches
         | Action::AddCursorAbove
         | Action::AddCursorBelow
+        | Action::RotatePrimaryCursor
         | Action::CommandPalette
         | Action::QuickOpen
+        | Action::RepeatLastCommand
         | Action::QuickOpenBuffers
         | Action::QuickOpenFiles
         | Action::OpenLiveGrep
@@ -3015,9 +3282,14 @@ pub fn action_to_events(
         | Action::ToggleReadOnly
         | Action::TogglePageView
         | Action::SetPageWidth
+        | Action::SetComposeGuides
         | Action::IncreaseSplitSize
         | Action::DecreaseSplitSize
         | Action::ToggleMaximizeSplit
+        | Action::UnzoomSplit
+        | Action::EqualizeSplits
+        | Action::MoveBufferToNextSplit
+        | Action::SwapSplits
         | Action::Undo
         | Action::Redo
         | Action::GoToMatchingBracket
@@ -3037,6 +3309,9 @@ pub fn action_to_events(
         | Action::JumpToBookmark(_)
         | Action::ClearBookmark(_)
         | Action::ListBookmarks
+        | Action::ToggleBookmark
+        | Action::NextBookmark
+        | Action::PrevBookmark
         | Action::ToggleSearchCaseSensitive
         | Action::ToggleSearchWholeWord
         | Action::ToggleSearchRegex
@@ -3100,6 +3375,7 @@ pub fn action_to_events(
         | Action::ToggleVerticalScrollbar
         | Action::ToggleHorizontalScrollbar
         | Action::FocusFileExplorer
+        | Action::RevealInExplorer
         | Action::FocusEditor
         | Action::SetBackground
         | Action::SetBackgroundBlend
@@ -3133,6 +3409,9 @@ pub fn action_to_events(
         | Action::LspHover
         | Action::LspSignatureHelp
         | Action::LspCodeActions
+        | Action::LspFormat
+        | Action::LspDocumentSymbols
+        | Action::LspWorkspaceSymbols
         | Action::LspRestart
         | Action::LspStop
         | Action::LspToggleForBuffer
@@ -3173,7 +3452,11 @@ pub fn action_to_events(
         | Action::ToggleAutoRevert
         | Action::FormatBuffer
         | Action::TrimTrailingWhitespace
+        | Action::Retab
         | Action::EnsureFinalNewline
+        | Action::NormalizeLineEndings
+        | Action::CursorToTop
+        | Action::CursorToBottom
         | Action::OpenTerminal
         | Action::CloseTerminal
         | Action::FocusTerminal
@@ -3197,14 +3480,19 @@ pub fn action_to_events(
         | Action::ReloadWithEncoding
         | Action::SetLanguage
         | Action::ToggleIndentationStyle
+        | Action::ShowIndentation
         | Action::ToggleTabIndicators
         | Action::ToggleWhitespaceIndicators
         | Action::ToggleDebugHighlights
         | Action::ResetBufferSettings
         | Action::ShellCommand
         | Action::ShellCommandReplace
+        | Action::RunCustomCommand(_)
+        | Action::RunCurrentFile
         | Action::CalibrateInput
         | Action::EventDebug
+        | Action::UndoHistoryStats
+        | Action::ExportEventLog
         | Action::SuspendProcess
         | Action::LoadPluginFromBuffer
         | Action::InitReload
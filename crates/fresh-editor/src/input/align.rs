@@ -0,0 +1,140 @@
+//! Line up multiple cursors, or a delimiter on each cursor's line.
+
+use std::collections::HashSet;
+
+use crate::model::cursor::Cursors;
+use crate::model::event::{CursorId, Event};
+use crate::primitives::display_width::char_width;
+use crate::state::EditorState;
+
+/// Visual width of `text`, expanding tabs to the next multiple of `tab_size`.
+fn visual_width(text: &str, tab_size: usize) -> usize {
+    let tab_size = tab_size.max(1);
+    text.chars().fold(0, |col, ch| {
+        if ch == '\t' {
+            col + tab_size - col % tab_size
+        } else {
+            col + char_width(ch)
+        }
+    })
+}
+
+/// Padding to insert on one line.
+struct Pad {
+    cursor_id: CursorId,
+    position: usize,
+    width: usize,
+}
+
+/// Pad each cursor's line with spaces so that the cursors — or, with a
+/// non-empty `delimiter`, the first occurrence of it on each line — line up
+/// at the rightmost of their columns. Only the first cursor on a line
+/// counts, and lines without the delimiter are left unchanged.
+///
+/// Aligned cursors end up after their padding; when aligning a delimiter
+/// the cursors stay where they were in the text.
+pub(crate) fn align_cursors(
+    state: &mut EditorState,
+    cursors: &Cursors,
+    events: &mut Vec<Event>,
+    delimiter: &str,
+    estimated_line_length: usize,
+) {
+    let tab_size = state.buffer_settings.tab_size;
+    let mut by_position: Vec<_> = cursors.iter().collect();
+    by_position.sort_by_key(|(_, cursor)| cursor.position);
+
+    // (cursor id, byte offset to align, its visual column)
+    let mut targets = Vec::new();
+    let mut lines = HashSet::new();
+    for (cursor_id, cursor) in by_position {
+        let mut iter = state
+            .buffer
+            .line_iterator(cursor.position, estimated_line_length);
+        let line_start = iter.current_position();
+        let Some((_, content)) = iter.next_line() else {
+            continue;
+        };
+        if !lines.insert(line_start) {
+            continue;
+        }
+        let content = content.trim_end_matches(['\n', '\r']);
+        let offset = if delimiter.is_empty() {
+            (cursor.position - line_start).min(content.len())
+        } else {
+            match content.find(delimiter) {
+                Some(offset) => offset,
+                None => continue,
+            }
+        };
+        let column = visual_width(&content[..offset], tab_size);
+        targets.push((cursor_id, line_start + offset, column));
+    }
+
+    let Some(max_column) = targets.iter().map(|&(_, _, column)| column).max() else {
+        return;
+    };
+    let pads: Vec<Pad> = targets
+        .into_iter()
+        .filter(|&(_, _, column)| column < max_column)
+        .map(|(cursor_id, position, column)| Pad {
+            cursor_id,
+            position,
+            width: max_column - column,
+        })
+        .collect();
+
+    for pad in &pads {
+        events.push(Event::Insert {
+            position: pad.position,
+            text: " ".repeat(pad.width),
+            cursor_id: pad.cursor_id,
+        });
+    }
+    if delimiter.is_empty() {
+        // Each cursor follows its own insert past the padding
+        return;
+    }
+
+    // Keep the cursors that own a pad where they were in the text instead
+    let shift_before = |pos: usize| -> usize {
+        pads.iter()
+            .filter(|pad| pad.position < pos)
+            .map(|pad| pad.width)
+            .sum()
+    };
+    for pad in &pads {
+        let Some(cursor) = cursors.get(pad.cursor_id) else {
+            continue;
+        };
+        // The bulk edit adds the shift of earlier lines itself when the
+        // insert is at the cursor, and otherwise takes the position as is
+        let new_position = if pad.position == cursor.position {
+            cursor.position + pad.width
+        } else {
+            cursor.position + shift_before(cursor.position)
+        };
+        events.push(Event::MoveCursor {
+            cursor_id: pad.cursor_id,
+            old_position: cursor.position,
+            new_position,
+            old_anchor: cursor.anchor,
+            new_anchor: cursor.anchor.map(|anchor| anchor + shift_before(anchor)),
+            old_sticky_column: cursor.sticky_column,
+            new_sticky_column: cursor.sticky_column,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_visual_width_expands_tabs() {
+        assert_eq!(visual_width("let x", 4), 5);
+        assert_eq!(visual_width("\tx", 4), 5);
+        assert_eq!(visual_width("ab\tc", 4), 5);
+        assert_eq!(visual_width("", 4), 0);
+    }
+}
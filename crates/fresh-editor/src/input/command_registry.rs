@@ -228,29 +228,33 @@ impl CommandRegistry {
 
         // Helper to create a suggestion from a command
         let current_context_ref = &current_context;
-        let make_suggestion =
-            |cmd: &Command, score: i32, localized_name: String, localized_desc: String| {
-                let mut available = is_available(cmd);
-                if cmd.action == Action::FindInSelection && !selection_active {
-                    available = false;
-                }
-                // Disable LSP start/restart/toggle commands when no LSP is configured
-                if !has_lsp_config
-                    && matches!(cmd.action, Action::LspRestart | Action::LspToggleForBuffer)
-                {
-                    available = false;
-                }
-                let keybinding = keybinding_resolver
-                    .get_keybinding_for_action(&cmd.action, current_context_ref.clone());
-                let history_pos = self.history_position(&cmd.name);
-
-                let suggestion = Suggestion::new(localized_name)
-                    .with_description(localized_desc)
-                    .set_disabled(!available)
-                    .with_keybinding(keybinding)
-                    .with_source(Some(cmd.source.clone()));
-                (suggestion, history_pos, score)
-            };
+        let make_suggestion = |cmd: &Command,
+                               score: i32,
+                               localized_name: String,
+                               localized_desc: String,
+                               match_positions: Vec<usize>| {
+            let mut available = is_available(cmd);
+            if cmd.action == Action::FindInSelection && !selection_active {
+                available = false;
+            }
+            // Disable LSP start/restart/toggle commands when no LSP is configured
+            if !has_lsp_config
+                && matches!(cmd.action, Action::LspRestart | Action::LspToggleForBuffer)
+            {
+                available = false;
+            }
+            let keybinding = keybinding_resolver
+                .get_keybinding_for_action(&cmd.action, current_context_ref.clone());
+            let history_pos = self.history_position(&cmd.name);
+
+            let suggestion = Suggestion::new(localized_name)
+                .with_description(localized_desc)
+                .set_disabled(!available)
+                .with_keybinding(keybinding)
+                .with_source(Some(cmd.source.clone()))
+                .with_match_positions(match_positions);
+            (suggestion, history_pos, score)
+        };
 
         // Match by name or description
         // Commands with unmet custom contexts are completely hidden
@@ -263,15 +267,27 @@ impl CommandRegistry {
                 let name_result = fuzzy_match(query, &localized_name);
                 if name_result.matched {
                     let localized_desc = cmd.get_localized_description();
-                    let (suggestion, hist, score) =
-                        make_suggestion(cmd, name_result.score, localized_name, localized_desc);
+                    let (suggestion, hist, score) = make_suggestion(
+                        cmd,
+                        name_result.score,
+                        localized_name,
+                        localized_desc,
+                        name_result.match_positions,
+                    );
                     Some((suggestion, hist, score, 0))
                 } else if !query.is_empty() {
                     let localized_desc = cmd.get_localized_description();
                     let desc_result = fuzzy_match(query, &localized_desc);
                     if desc_result.matched {
-                        let (suggestion, hist, score) =
-                            make_suggestion(cmd, desc_result.score, localized_name, localized_desc);
+                        // The matched characters are in the description,
+                        // which isn't highlighted
+                        let (suggestion, hist, score) = make_suggestion(
+                            cmd,
+                            desc_result.score,
+                            localized_name,
+                            localized_desc,
+                            Vec::new(),
+                        );
                         Some((suggestion, hist, score, 1))
                     } else {
                         None
@@ -76,6 +76,8 @@ pub struct Suggestion {
     pub keybinding: Option<String>,
     /// Source of the command (for command palette)
     pub source: Option<CommandSource>,
+    /// Char indices in `text` matched by the query, highlighted in the list
+    pub match_positions: Vec<usize>,
 }
 
 impl Suggestion {
@@ -88,6 +90,7 @@ impl Suggestion {
             disabled: false,
             keybinding: None,
             source: None,
+            match_positions: Vec::new(),
         }
     }
 
@@ -100,6 +103,7 @@ impl Suggestion {
             disabled: true,
             keybinding: None,
             source: None,
+            match_positions: Vec::new(),
         }
     }
 
@@ -123,6 +127,11 @@ impl Suggestion {
         self
     }
 
+    pub fn with_match_positions(mut self, match_positions: Vec<usize>) -> Self {
+        self.match_positions = match_positions;
+        self
+    }
+
     pub fn set_disabled(mut self, disabled: bool) -> Self {
         self.disabled = disabled;
         self
@@ -197,6 +206,20 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal, Terminal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.close_other_tabs",
+        desc_key: "cmd.close_other_tabs_desc",
+        action: || Action::CloseOtherTabs,
+        contexts: &[Normal, Terminal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.close_tabs_to_right",
+        desc_key: "cmd.close_tabs_to_right_desc",
+        action: || Action::CloseTabsToRight,
+        contexts: &[Normal, Terminal],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.revert_file",
         desc_key: "cmd.revert_file_desc",
@@ -225,6 +248,13 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.retab",
+        desc_key: "cmd.retab_desc",
+        action: || Action::Retab,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.ensure_final_newline",
         desc_key: "cmd.ensure_final_newline_desc",
@@ -261,6 +291,13 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.repeat_last_command",
+        desc_key: "cmd.repeat_last_command_desc",
+        action: || Action::RepeatLastCommand,
+        contexts: &[],
+        custom_contexts: &[],
+    },
     // Edit operations
     CommandDef {
         name_key: "cmd.undo",
@@ -318,6 +355,41 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.copy_to_register",
+        desc_key: "cmd.copy_to_register_desc",
+        action: || Action::PromptCopyToRegister,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.cut_to_register",
+        desc_key: "cmd.cut_to_register_desc",
+        action: || Action::PromptCutToRegister,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.paste_from_register",
+        desc_key: "cmd.paste_from_register_desc",
+        action: || Action::PromptPasteFromRegister,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.cycle_paste_forward",
+        desc_key: "cmd.cycle_paste_forward_desc",
+        action: || Action::CyclePasteForward,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.cycle_paste_backward",
+        desc_key: "cmd.cycle_paste_backward_desc",
+        action: || Action::CyclePasteBackward,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.delete_line",
         desc_key: "cmd.delete_line_desc",
@@ -353,6 +425,20 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.transpose_words",
+        desc_key: "cmd.transpose_words_desc",
+        action: || Action::TransposeWords,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.surround",
+        desc_key: "cmd.surround_desc",
+        action: || Action::Surround(String::new()),
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.transform_uppercase",
         desc_key: "cmd.transform_uppercase_desc",
@@ -374,6 +460,20 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.increment_number",
+        desc_key: "cmd.increment_number_desc",
+        action: || Action::IncrementNumber,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.decrement_number",
+        desc_key: "cmd.decrement_number_desc",
+        action: || Action::DecrementNumber,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.open_line",
         desc_key: "cmd.open_line_desc",
@@ -388,6 +488,20 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.duplicate_selection_down",
+        desc_key: "cmd.duplicate_selection_down_desc",
+        action: || Action::DuplicateSelectionDown,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.duplicate_selection_up",
+        desc_key: "cmd.duplicate_selection_up_desc",
+        action: || Action::DuplicateSelectionUp,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.recenter",
         desc_key: "cmd.recenter_desc",
@@ -395,6 +509,20 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.cursor_to_top",
+        desc_key: "cmd.cursor_to_top_desc",
+        action: || Action::CursorToTop,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.cursor_to_bottom",
+        desc_key: "cmd.cursor_to_bottom_desc",
+        action: || Action::CursorToBottom,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.set_mark",
         desc_key: "cmd.set_mark_desc",
@@ -453,6 +581,20 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.skip_last_selection_match",
+        desc_key: "cmd.skip_last_selection_match_desc",
+        action: || Action::SkipLastSelectionMatch,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.select_all_matches",
+        desc_key: "cmd.select_all_matches_desc",
+        action: || Action::SelectAllMatches,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.remove_secondary_cursors",
         desc_key: "cmd.remove_secondary_cursors_desc",
@@ -460,6 +602,34 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.collapse_to_single_primary",
+        desc_key: "cmd.collapse_to_single_primary_desc",
+        action: || Action::CollapseToSinglePrimary,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.rotate_primary_cursor",
+        desc_key: "cmd.rotate_primary_cursor_desc",
+        action: || Action::RotatePrimaryCursor,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.align_cursors",
+        desc_key: "cmd.align_cursors_desc",
+        action: || Action::AlignCursors(String::new()),
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.align_cursors_on_delimiter",
+        desc_key: "cmd.align_cursors_on_delimiter_desc",
+        action: || Action::PromptAlignCursors,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     // Buffer navigation
     CommandDef {
         name_key: "cmd.next_buffer",
@@ -546,6 +716,34 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal, Terminal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.unzoom_split",
+        desc_key: "cmd.unzoom_split_desc",
+        action: || Action::UnzoomSplit,
+        contexts: &[Normal, Terminal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.equalize_splits",
+        desc_key: "cmd.equalize_splits_desc",
+        action: || Action::EqualizeSplits,
+        contexts: &[Normal, Terminal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.move_buffer_to_next_split",
+        desc_key: "cmd.move_buffer_to_next_split_desc",
+        action: || Action::MoveBufferToNextSplit,
+        contexts: &[Normal, Terminal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.swap_splits",
+        desc_key: "cmd.swap_splits_desc",
+        action: || Action::SwapSplits,
+        contexts: &[Normal, Terminal],
+        custom_contexts: &[],
+    },
     // View toggles
     CommandDef {
         name_key: "cmd.toggle_line_numbers",
@@ -605,6 +803,13 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.normalize_line_endings",
+        desc_key: "cmd.normalize_line_endings_desc",
+        action: || Action::NormalizeLineEndings,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.set_encoding",
         desc_key: "cmd.set_encoding_desc",
@@ -633,6 +838,13 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.show_indentation",
+        desc_key: "cmd.show_indentation_desc",
+        action: || Action::ShowIndentation,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.toggle_tab_indicators",
         desc_key: "cmd.toggle_tab_indicators_desc",
@@ -746,6 +958,13 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal, Terminal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.reveal_in_explorer",
+        desc_key: "cmd.reveal_in_explorer_desc",
+        action: || Action::RevealInExplorer,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.focus_editor",
         desc_key: "cmd.focus_editor_desc",
@@ -831,6 +1050,13 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.set_compose_guides",
+        desc_key: "cmd.set_compose_guides_desc",
+        action: || Action::SetComposeGuides,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.toggle_read_only",
         desc_key: "cmd.toggle_read_only_desc",
@@ -973,6 +1199,27 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.lsp_format",
+        desc_key: "cmd.lsp_format_desc",
+        action: || Action::LspFormat,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.document_symbols",
+        desc_key: "cmd.document_symbols_desc",
+        action: || Action::LspDocumentSymbols,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.workspace_symbols",
+        desc_key: "cmd.workspace_symbols_desc",
+        action: || Action::LspWorkspaceSymbols,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.start_restart_lsp",
         desc_key: "cmd.start_restart_lsp_desc",
@@ -1068,6 +1315,27 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.toggle_bookmark",
+        desc_key: "cmd.toggle_bookmark_desc",
+        action: || Action::ToggleBookmark,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.next_bookmark",
+        desc_key: "cmd.next_bookmark_desc",
+        action: || Action::NextBookmark,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.prev_bookmark",
+        desc_key: "cmd.prev_bookmark_desc",
+        action: || Action::PrevBookmark,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.list_macros",
         desc_key: "cmd.list_macros_desc",
@@ -1290,6 +1558,13 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.run_current_file",
+        desc_key: "cmd.run_current_file_desc",
+        action: || Action::RunCurrentFile,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     // Debugging
     CommandDef {
         name_key: "cmd.event_debug",
@@ -1298,6 +1573,20 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.undo_history_stats",
+        desc_key: "cmd.undo_history_stats_desc",
+        action: || Action::UndoHistoryStats,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.export_event_log",
+        desc_key: "cmd.export_event_log_desc",
+        action: || Action::ExportEventLog,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     // Process control (Unix job-control suspend)
     CommandDef {
         name_key: "cmd.suspend_process",
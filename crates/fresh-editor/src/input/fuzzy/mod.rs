@@ -183,6 +183,23 @@ where
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_command_name_ranking() {
+        let commands = [
+            "Copy File Path",
+            "Stop Profiling",
+            "Open File",
+            "Toggle Panel Focus",
+        ];
+        let results = fuzzy_filter("opf", &commands, |c| c);
+        assert_eq!(commands[results[0].0], "Open File");
+        assert_eq!(results[0].1.match_positions, vec![0, 1, 5]);
+
+        // A prefix match outranks the same letters scattered mid-word
+        let results = fuzzy_filter("open", &["Reopen Closed Tab", "Open File"], |c| c);
+        assert_eq!(results[0].0, 1);
+    }
+
     #[test]
     fn test_empty_query_matches_everything() {
         let result = fuzzy_match("", "anything");
@@ -366,11 +366,17 @@ pub enum Action {
     DeleteToLineStart,
     DeleteViWordEnd, // Delete from cursor to end of word (vim de)
     TransposeChars,
+    TransposeWords,
+    Surround(String), // Wrap/unwrap selections in a delimiter (empty = prompt for one)
     OpenLine,
     DuplicateLine,
+    DuplicateSelectionDown,
+    DuplicateSelectionUp,
 
     // View
-    Recenter,
+    Recenter,       // Scroll so the cursor line is centered (vim zz)
+    CursorToTop,    // Scroll so the cursor line is at the top (vim zt)
+    CursorToBottom, // Scroll so the cursor line is at the bottom (vim zb)
 
     // Selection
     SetMark,
@@ -380,6 +386,20 @@ pub enum Action {
     CopyWithTheme(String),
     Cut,
     Paste,
+    /// Copy the selection (or line) into a named register, like vim's `"ay`.
+    CopyToRegister(char),
+    /// Cut the selection (or line) into a named register.
+    CutToRegister(char),
+    /// Paste from a register: `"` is the clipboard, `1`-`9` recent copies.
+    PasteFromRegister(char),
+    PromptCopyToRegister,
+    PromptCutToRegister,
+    /// Pick a register from a list of previews and paste it.
+    PromptPasteFromRegister,
+    /// Replace the text just pasted with the next older yank (Emacs's yank-pop).
+    CyclePasteForward,
+    /// Replace the text just pasted with the next newer yank.
+    CyclePasteBackward,
     /// Copy the absolute filesystem path of the active buffer's file to the clipboard.
     CopyFilePath,
     /// Copy the active buffer's file path relative to the workspace root, falling
@@ -397,7 +417,17 @@ pub enum Action {
     AddCursorAbove,
     AddCursorBelow,
     AddCursorNextMatch,
+    SkipLastSelectionMatch,
+    SelectAllMatches,
     RemoveSecondaryCursors,
+    /// Drop every cursor but the primary, keeping its selection.
+    CollapseToSinglePrimary,
+    /// Make the next cursor (in buffer order) the primary one.
+    RotatePrimaryCursor,
+    /// Pad lines so the cursors, or a delimiter on each cursor's line,
+    /// share one column (empty = the cursors themselves).
+    AlignCursors(String),
+    PromptAlignCursors,
 
     // File operations
     Save,
@@ -407,6 +437,8 @@ pub enum Action {
     New,
     Close,
     CloseTab,
+    CloseOtherTabs,
+    CloseTabsToRight,
     Quit,
     ForceQuit,
     Detach,
@@ -414,7 +446,9 @@ pub enum Action {
     ToggleAutoRevert,
     FormatBuffer,
     TrimTrailingWhitespace,
+    Retab,
     EnsureFinalNewline,
+    NormalizeLineEndings,
 
     // Navigation
     GotoLine,
@@ -435,6 +469,9 @@ pub enum Action {
     JumpToBookmark(char),
     ClearBookmark(char),
     ListBookmarks,
+    ToggleBookmark,
+    NextBookmark,
+    PrevBookmark,
 
     // Search options
     ToggleSearchCaseSensitive,
@@ -478,6 +515,8 @@ pub enum Action {
     QuickOpenBuffers,
     /// Quick Open - files (empty prefix)
     QuickOpenFiles,
+    /// Re-run the last command palette command with the same arguments
+    RepeatLastCommand,
     /// Open Live Grep as a floating overlay (issue #1796).
     OpenLiveGrep,
     /// Re-open Live Grep with the prior query and selection.
@@ -503,6 +542,7 @@ pub enum Action {
     ToggleReadOnly,
     TogglePageView,
     SetPageWidth,
+    SetComposeGuides,
     InspectThemeAtCursor,
     SelectTheme,
     SelectKeybindingMap,
@@ -532,6 +572,10 @@ pub enum Action {
     IncreaseSplitSize,
     DecreaseSplitSize,
     ToggleMaximizeSplit,
+    UnzoomSplit,
+    EqualizeSplits,
+    MoveBufferToNextSplit,
+    SwapSplits,
 
     // Prompt mode actions
     PromptConfirm,
@@ -602,6 +646,7 @@ pub enum Action {
     ToggleVerticalScrollbar,
     ToggleHorizontalScrollbar,
     FocusFileExplorer,
+    RevealInExplorer,
     FocusEditor,
     FileExplorerUp,
     FileExplorerDown,
@@ -635,6 +680,9 @@ pub enum Action {
     LspHover,
     LspSignatureHelp,
     LspCodeActions,
+    LspFormat,
+    LspDocumentSymbols,
+    LspWorkspaceSymbols,
     LspRestart,
     LspStop,
     LspToggleForBuffer,
@@ -656,6 +704,7 @@ pub enum Action {
     ReloadWithEncoding,
     SetLanguage,
     ToggleIndentationStyle,
+    ShowIndentation,
     ToggleTabIndicators,
     ToggleWhitespaceIndicators,
     ResetBufferSettings,
@@ -718,6 +767,8 @@ pub enum Action {
     // Shell command operations
     ShellCommand,        // Run shell command on buffer/selection, output to new buffer
     ShellCommandReplace, // Run shell command on buffer/selection, replace content
+    RunCustomCommand(String), // Run a command from the `commands` config section
+    RunCurrentFile,      // Run the active file with its language's `run_command`
 
     // Case conversion
     ToUpperCase, // Convert selection to uppercase
@@ -725,11 +776,17 @@ pub enum Action {
     ToggleCase,  // Toggle case of character under cursor (vim ~)
     SortLines,   // Sort selected lines alphabetically
 
+    // Number increment/decrement (vim Ctrl+A / Ctrl+X)
+    IncrementNumber, // Add 1 to the number under or after the cursor
+    DecrementNumber, // Subtract 1 from the number under or after the cursor
+
     // Input calibration
     CalibrateInput, // Open the input calibration wizard
 
     // Event debug
-    EventDebug, // Open the event debug dialog
+    EventDebug,       // Open the event debug dialog
+    UndoHistoryStats, // Show undo history size for the active buffer
+    ExportEventLog,   // Write the active buffer's event log to a file
 
     // Process control
     SuspendProcess, // Suspend the editor process (SIGTSTP on Unix); resume with `fg`
@@ -884,14 +941,25 @@ impl Action {
             "delete_to_line_start" => DeleteToLineStart,
             "delete_vi_word_end" => DeleteViWordEnd,
             "transpose_chars" => TransposeChars,
+            "transpose_words" => TransposeWords,
             "open_line" => OpenLine,
             "duplicate_line" => DuplicateLine,
+            "duplicate_selection_down" => DuplicateSelectionDown,
+            "duplicate_selection_up" => DuplicateSelectionUp,
             "recenter" => Recenter,
+            "center_cursor" => Recenter,
+            "cursor_to_top" => CursorToTop,
+            "cursor_to_bottom" => CursorToBottom,
             "set_mark" => SetMark,
 
             "copy" => Copy,
             "cut" => Cut,
             "paste" => Paste,
+            "prompt_copy_to_register" => PromptCopyToRegister,
+            "prompt_cut_to_register" => PromptCutToRegister,
+            "prompt_paste_from_register" => PromptPasteFromRegister,
+            "cycle_paste_forward" => CyclePasteForward,
+            "cycle_paste_backward" => CyclePasteBackward,
             "copy_file_path" => CopyFilePath,
             "copy_relative_file_path" => CopyRelativeFilePath,
 
@@ -904,7 +972,12 @@ impl Action {
             "add_cursor_above" => AddCursorAbove,
             "add_cursor_below" => AddCursorBelow,
             "add_cursor_next_match" => AddCursorNextMatch,
+            "skip_last_selection_match" => SkipLastSelectionMatch,
+            "select_all_matches" => SelectAllMatches,
             "remove_secondary_cursors" => RemoveSecondaryCursors,
+            "collapse_to_single_primary" => CollapseToSinglePrimary,
+            "rotate_primary_cursor" => RotatePrimaryCursor,
+            "prompt_align_cursors" => PromptAlignCursors,
 
             "save" => Save,
             "save_as" => SaveAs,
@@ -913,6 +986,8 @@ impl Action {
             "new" => New,
             "close" => Close,
             "close_tab" => CloseTab,
+            "close_other_tabs" => CloseOtherTabs,
+            "close_tabs_to_right" => CloseTabsToRight,
             "quit" => Quit,
             "force_quit" => ForceQuit,
             "detach" => Detach,
@@ -920,7 +995,9 @@ impl Action {
             "toggle_auto_revert" => ToggleAutoRevert,
             "format_buffer" => FormatBuffer,
             "trim_trailing_whitespace" => TrimTrailingWhitespace,
+            "retab" => Retab,
             "ensure_final_newline" => EnsureFinalNewline,
+            "normalize_line_endings" => NormalizeLineEndings,
             "goto_line" => GotoLine,
             "scan_line_index" => ScanLineIndex,
             "goto_matching_bracket" => GoToMatchingBracket,
@@ -934,6 +1011,9 @@ impl Action {
             "toggle_fold" => ToggleFold,
 
             "list_bookmarks" => ListBookmarks,
+            "toggle_bookmark" => ToggleBookmark,
+            "next_bookmark" => NextBookmark,
+            "prev_bookmark" => PrevBookmark,
 
             "toggle_search_case_sensitive" => ToggleSearchCaseSensitive,
             "toggle_search_whole_word" => ToggleSearchWholeWord,
@@ -966,6 +1046,7 @@ impl Action {
             "quick_open" => QuickOpen,
             "quick_open_buffers" => QuickOpenBuffers,
             "quick_open_files" => QuickOpenFiles,
+            "repeat_last_command" => RepeatLastCommand,
             "open_live_grep" => OpenLiveGrep,
             "resume_live_grep" => ResumeLiveGrep,
             "live_grep_export_quickfix" => LiveGrepExportQuickfix,
@@ -977,6 +1058,7 @@ impl Action {
             "toggle_read_only" => ToggleReadOnly,
             "toggle_page_view" => TogglePageView,
             "set_page_width" => SetPageWidth,
+            "set_compose_guides" => SetComposeGuides,
 
             "next_buffer" => NextBuffer,
             "prev_buffer" => PrevBuffer,
@@ -996,6 +1078,11 @@ impl Action {
             "increase_split_size" => IncreaseSplitSize,
             "decrease_split_size" => DecreaseSplitSize,
             "toggle_maximize_split" => ToggleMaximizeSplit,
+            "zoom_split" => ToggleMaximizeSplit,
+            "unzoom_split" => UnzoomSplit,
+            "equalize_splits" => EqualizeSplits,
+            "move_buffer_to_next_split" => MoveBufferToNextSplit,
+            "swap_splits" => SwapSplits,
 
             "prompt_confirm" => PromptConfirm,
             "prompt_cancel" => PromptCancel,
@@ -1047,6 +1134,7 @@ impl Action {
             "toggle_vertical_scrollbar" => ToggleVerticalScrollbar,
             "toggle_horizontal_scrollbar" => ToggleHorizontalScrollbar,
             "focus_file_explorer" => FocusFileExplorer,
+            "reveal_in_explorer" => RevealInExplorer,
             "focus_editor" => FocusEditor,
             "file_explorer_up" => FileExplorerUp,
             "file_explorer_down" => FileExplorerDown,
@@ -1079,6 +1167,9 @@ impl Action {
             "lsp_hover" => LspHover,
             "lsp_signature_help" => LspSignatureHelp,
             "lsp_code_actions" => LspCodeActions,
+            "lsp_format" => LspFormat,
+            "lsp_document_symbols" => LspDocumentSymbols,
+            "lsp_workspace_symbols" => LspWorkspaceSymbols,
             "lsp_restart" => LspRestart,
             "lsp_stop" => LspStop,
             "lsp_toggle_for_buffer" => LspToggleForBuffer,
@@ -1103,6 +1194,7 @@ impl Action {
             "reload_with_encoding" => ReloadWithEncoding,
             "set_language" => SetLanguage,
             "toggle_indentation_style" => ToggleIndentationStyle,
+            "show_indentation" => ShowIndentation,
             "toggle_tab_indicators" => ToggleTabIndicators,
             "toggle_whitespace_indicators" => ToggleWhitespaceIndicators,
             "reset_buffer_settings" => ResetBufferSettings,
@@ -1138,14 +1230,19 @@ impl Action {
 
             "shell_command" => ShellCommand,
             "shell_command_replace" => ShellCommandReplace,
+            "run_current_file" => RunCurrentFile,
 
             "to_upper_case" => ToUpperCase,
             "to_lower_case" => ToLowerCase,
             "toggle_case" => ToggleCase,
             "sort_lines" => SortLines,
+            "increment_number" => IncrementNumber,
+            "decrement_number" => DecrementNumber,
 
             "calibrate_input" => CalibrateInput,
             "event_debug" => EventDebug,
+            "undo_history_stats" => UndoHistoryStats,
+            "export_event_log" => ExportEventLog,
             "suspend_process" => SuspendProcess,
             "load_plugin_from_buffer" => LoadPluginFromBuffer,
             "init_reload" => InitReload,
@@ -1182,6 +1279,9 @@ impl Action {
             "play_macro" => PlayMacro,
             "toggle_macro_recording" => ToggleMacroRecording,
             "show_macro" => ShowMacro,
+            "copy_to_register" => CopyToRegister,
+            "cut_to_register" => CutToRegister,
+            "paste_from_register" => PasteFromRegister,
         }
         custom {
             "copy_with_theme" => CopyWithTheme : {
@@ -1201,6 +1301,20 @@ impl Action {
                 let text = args.get("text")?.as_str()?;
                 Self::PromptConfirmWithText(text.to_string())
             },
+            "run_custom_command" => RunCustomCommand : {
+                let name = args.get("name")?.as_str()?;
+                Self::RunCustomCommand(name.to_string())
+            },
+            "surround" => Surround : {
+                // Empty delimiter = prompt for one
+                let delimiter = args.get("delimiter").and_then(|v| v.as_str()).unwrap_or("");
+                Self::Surround(delimiter.to_string())
+            },
+            "align_cursors" => AlignCursors : {
+                // Empty delimiter = align the cursors themselves
+                let delimiter = args.get("delimiter").and_then(|v| v.as_str()).unwrap_or("");
+                Self::AlignCursors(delimiter.to_string())
+            },
         }
     }
 
@@ -1320,13 +1434,24 @@ impl Action {
                 | Action::DeleteToLineEnd
                 | Action::DeleteToLineStart
                 | Action::TransposeChars
+                | Action::TransposeWords
+                | Action::Surround(_)
+                | Action::AlignCursors(_)
+                | Action::IncrementNumber
+                | Action::DecrementNumber
                 | Action::OpenLine
                 | Action::DuplicateLine
+                | Action::DuplicateSelectionDown
+                | Action::DuplicateSelectionUp
                 | Action::MoveLineUp
                 | Action::MoveLineDown
                 // Clipboard editing (but not Copy)
                 | Action::Cut
                 | Action::Paste
+                | Action::CutToRegister(_)
+                | Action::PasteFromRegister(_)
+                | Action::CyclePasteForward
+                | Action::CyclePasteBackward
                 // Undo/Redo
                 | Action::Undo
                 | Action::Redo
@@ -1350,12 +1475,23 @@ impl Action {
                 | Action::DeleteToLineStart
                 | Action::DeleteViWordEnd
                 | Action::TransposeChars
+                | Action::TransposeWords
+                | Action::Surround(_)
+                | Action::AlignCursors(_)
+                | Action::IncrementNumber
+                | Action::DecrementNumber
                 | Action::OpenLine
                 | Action::DuplicateLine
+                | Action::DuplicateSelectionDown
+                | Action::DuplicateSelectionUp
                 | Action::MoveLineUp
                 | Action::MoveLineDown
                 | Action::Cut
                 | Action::Paste
+                | Action::CutToRegister(_)
+                | Action::PasteFromRegister(_)
+                | Action::CyclePasteForward
+                | Action::CyclePasteBackward
         )
     }
 }
@@ -1427,50 +1563,78 @@ impl KeybindingResolver {
     /// Load default bindings from a vector of keybinding definitions (into default_bindings/default_chord_bindings)
     fn load_default_bindings_from_vec(&mut self, bindings: &[crate::config::Keybinding]) {
         for binding in bindings {
-            // Determine context from "when" clause
-            let context = if let Some(ref when) = binding.when {
-                KeyContext::from_when_clause(when).unwrap_or(KeyContext::Normal)
+            let Some((context, sequence, action)) = Self::parse_binding(binding) else {
+                continue;
+            };
+            if binding.keys.is_empty() {
+                let (key_code, modifiers) = sequence[0];
+                self.insert_binding_with_equivalents(
+                    context,
+                    key_code,
+                    modifiers,
+                    action,
+                    &binding.key,
+                );
             } else {
+                self.default_chord_bindings
+                    .entry(context)
+                    .or_default()
+                    .insert(sequence, action);
+            }
+        }
+    }
+
+    /// Parse a keybinding definition into its context, key sequence and
+    /// action. A single-key binding yields a one-element sequence.
+    ///
+    /// Bindings with an unknown key name are skipped and an unknown `when`
+    /// context falls back to Normal; both are logged as warnings so a typo
+    /// in the user's config doesn't stop the editor from starting.
+    fn parse_binding(
+        binding: &crate::config::Keybinding,
+    ) -> Option<(KeyContext, Vec<(KeyCode, KeyModifiers)>, Action)> {
+        let context = match binding.when.as_deref() {
+            Some(when) => KeyContext::from_when_clause(when).unwrap_or_else(|| {
+                tracing::warn!(
+                    "Keybinding for '{}' has unknown context '{}', using normal",
+                    binding.action,
+                    when
+                );
                 KeyContext::Normal
-            };
+            }),
+            None => KeyContext::Normal,
+        };
 
-            if let Some(action) = Action::from_str(&binding.action, &binding.args) {
-                // Check if this is a chord binding (has keys field)
-                if !binding.keys.is_empty() {
-                    // Parse the chord sequence
-                    let mut sequence = Vec::new();
-                    for key_press in &binding.keys {
-                        if let Some(key_code) = Self::parse_key(&key_press.key) {
-                            let modifiers = Self::parse_modifiers(&key_press.modifiers);
-                            sequence.push((key_code, modifiers));
-                        } else {
-                            // Invalid key in sequence, skip this binding
-                            break;
-                        }
-                    }
+        let Some(action) = Action::from_str(&binding.action, &binding.args) else {
+            tracing::warn!("Ignoring keybinding: invalid action '{}'", binding.action);
+            return None;
+        };
 
-                    // Only add if all keys in sequence were valid
-                    if sequence.len() == binding.keys.len() && !sequence.is_empty() {
-                        self.default_chord_bindings
-                            .entry(context)
-                            .or_default()
-                            .insert(sequence, action);
-                    }
-                } else if let Some(key_code) = Self::parse_key(&binding.key) {
-                    // Single key binding (legacy format)
-                    let modifiers = Self::parse_modifiers(&binding.modifiers);
+        // Chord bindings (keys field) take precedence over key + modifiers
+        let presses: Vec<(&str, &[String])> = if binding.keys.is_empty() {
+            vec![(binding.key.as_str(), binding.modifiers.as_slice())]
+        } else {
+            binding
+                .keys
+                .iter()
+                .map(|press| (press.key.as_str(), press.modifiers.as_slice()))
+                .collect()
+        };
 
-                    // Insert the primary binding
-                    self.insert_binding_with_equivalents(
-                        context,
-                        key_code,
-                        modifiers,
-                        action,
-                        &binding.key,
-                    );
-                }
-            }
+        let mut sequence = Vec::with_capacity(presses.len());
+        for (key, modifiers) in presses {
+            let Some(key_code) = Self::parse_key(key) else {
+                tracing::warn!(
+                    "Ignoring keybinding for '{}': unknown key '{}'",
+                    binding.action,
+                    key
+                );
+                return None;
+            };
+            sequence.push((key_code, Self::parse_modifiers(modifiers)));
         }
+
+        Some((context, sequence, action))
     }
 
     /// Insert a binding and automatically add terminal key equivalents.
@@ -1519,43 +1683,19 @@ impl KeybindingResolver {
     /// Load custom bindings from a vector of keybinding definitions (into bindings/chord_bindings)
     fn load_bindings_from_vec(&mut self, bindings: &[crate::config::Keybinding]) {
         for binding in bindings {
-            // Determine context from "when" clause
-            let context = if let Some(ref when) = binding.when {
-                KeyContext::from_when_clause(when).unwrap_or(KeyContext::Normal)
-            } else {
-                KeyContext::Normal
+            let Some((context, sequence, action)) = Self::parse_binding(binding) else {
+                continue;
             };
-
-            if let Some(action) = Action::from_str(&binding.action, &binding.args) {
-                // Check if this is a chord binding (has keys field)
-                if !binding.keys.is_empty() {
-                    // Parse the chord sequence
-                    let mut sequence = Vec::new();
-                    for key_press in &binding.keys {
-                        if let Some(key_code) = Self::parse_key(&key_press.key) {
-                            let modifiers = Self::parse_modifiers(&key_press.modifiers);
-                            sequence.push((key_code, modifiers));
-                        } else {
-                            // Invalid key in sequence, skip this binding
-                            break;
-                        }
-                    }
-
-                    // Only add if all keys in sequence were valid
-                    if sequence.len() == binding.keys.len() && !sequence.is_empty() {
-                        self.chord_bindings
-                            .entry(context)
-                            .or_default()
-                            .insert(sequence, action);
-                    }
-                } else if let Some(key_code) = Self::parse_key(&binding.key) {
-                    // Single key binding (legacy format)
-                    let modifiers = Self::parse_modifiers(&binding.modifiers);
-                    self.bindings
-                        .entry(context)
-                        .or_default()
-                        .insert((key_code, modifiers), action);
-                }
+            if binding.keys.is_empty() {
+                self.bindings
+                    .entry(context)
+                    .or_default()
+                    .insert(sequence[0], action);
+            } else {
+                self.chord_bindings
+                    .entry(context)
+                    .or_default()
+                    .insert(sequence, action);
             }
         }
     }
@@ -1658,6 +1798,10 @@ impl KeybindingResolver {
                 | Action::SplitVertical
                 | Action::CloseSplit
                 | Action::ToggleMaximizeSplit
+                | Action::UnzoomSplit
+                | Action::EqualizeSplits
+                | Action::MoveBufferToNextSplit
+                | Action::SwapSplits
                 // Tab/buffer navigation
                 | Action::NextBuffer
                 | Action::PrevBuffer
@@ -2196,15 +2340,30 @@ impl KeybindingResolver {
             Action::DeleteToLineStart => t!("action.delete_to_line_start"),
             Action::DeleteViWordEnd => t!("action.delete_word_forward"),
             Action::TransposeChars => t!("action.transpose_chars"),
+            Action::TransposeWords => t!("action.transpose_words"),
+            Action::Surround(delimiter) if delimiter.is_empty() => t!("action.surround"),
+            Action::Surround(delimiter) => t!("action.surround_with", delimiter = delimiter),
             Action::OpenLine => t!("action.open_line"),
             Action::DuplicateLine => t!("action.duplicate_line"),
+            Action::DuplicateSelectionDown => t!("action.duplicate_selection_down"),
+            Action::DuplicateSelectionUp => t!("action.duplicate_selection_up"),
             Action::Recenter => t!("action.recenter"),
+            Action::CursorToTop => t!("action.cursor_to_top"),
+            Action::CursorToBottom => t!("action.cursor_to_bottom"),
             Action::SetMark => t!("action.set_mark"),
             Action::Copy => t!("action.copy"),
             Action::CopyWithTheme(theme) if theme.is_empty() => t!("action.copy_with_formatting"),
             Action::CopyWithTheme(theme) => t!("action.copy_with_theme", theme = theme),
             Action::Cut => t!("action.cut"),
             Action::Paste => t!("action.paste"),
+            Action::CopyToRegister(c) => t!("action.copy_to_register", key = c),
+            Action::CutToRegister(c) => t!("action.cut_to_register", key = c),
+            Action::PasteFromRegister(c) => t!("action.paste_from_register", key = c),
+            Action::PromptCopyToRegister => t!("action.prompt_copy_to_register"),
+            Action::PromptCutToRegister => t!("action.prompt_cut_to_register"),
+            Action::PromptPasteFromRegister => t!("action.prompt_paste_from_register"),
+            Action::CyclePasteForward => t!("action.cycle_paste_forward"),
+            Action::CyclePasteBackward => t!("action.cycle_paste_backward"),
             Action::CopyFilePath => t!("action.copy_file_path"),
             Action::CopyRelativeFilePath => t!("action.copy_relative_file_path"),
             Action::YankWordForward => t!("action.yank_word_forward"),
@@ -2215,7 +2374,18 @@ impl KeybindingResolver {
             Action::AddCursorAbove => t!("action.add_cursor_above"),
             Action::AddCursorBelow => t!("action.add_cursor_below"),
             Action::AddCursorNextMatch => t!("action.add_cursor_next_match"),
+            Action::SkipLastSelectionMatch => t!("action.skip_last_selection_match"),
+            Action::SelectAllMatches => t!("action.select_all_matches"),
             Action::RemoveSecondaryCursors => t!("action.remove_secondary_cursors"),
+            Action::CollapseToSinglePrimary => t!("action.collapse_to_single_primary"),
+            Action::RotatePrimaryCursor => t!("action.rotate_primary_cursor"),
+            Action::AlignCursors(delimiter) if delimiter.is_empty() => {
+                t!("action.align_cursors")
+            }
+            Action::AlignCursors(delimiter) => {
+                t!("action.align_cursors_on", delimiter = delimiter)
+            }
+            Action::PromptAlignCursors => t!("action.prompt_align_cursors"),
             Action::Save => t!("action.save"),
             Action::SaveAs => t!("action.save_as"),
             Action::Open => t!("action.open"),
@@ -2223,6 +2393,8 @@ impl KeybindingResolver {
             Action::New => t!("action.new"),
             Action::Close => t!("action.close"),
             Action::CloseTab => t!("action.close_tab"),
+            Action::CloseOtherTabs => t!("action.close_other_tabs"),
+            Action::CloseTabsToRight => t!("action.close_tabs_to_right"),
             Action::Quit => t!("action.quit"),
             Action::ForceQuit => t!("action.force_quit"),
             Action::Detach => t!("action.detach"),
@@ -2230,7 +2402,9 @@ impl KeybindingResolver {
             Action::ToggleAutoRevert => t!("action.toggle_auto_revert"),
             Action::FormatBuffer => t!("action.format_buffer"),
             Action::TrimTrailingWhitespace => t!("action.trim_trailing_whitespace"),
+            Action::Retab => t!("action.retab"),
             Action::EnsureFinalNewline => t!("action.ensure_final_newline"),
+            Action::NormalizeLineEndings => t!("action.normalize_line_endings"),
             Action::GotoLine => t!("action.goto_line"),
             Action::ScanLineIndex => t!("action.scan_line_index"),
             Action::GoToMatchingBracket => t!("action.goto_matching_bracket"),
@@ -2245,6 +2419,9 @@ impl KeybindingResolver {
             Action::JumpToBookmark(c) => t!("action.jump_to_bookmark", key = c),
             Action::ClearBookmark(c) => t!("action.clear_bookmark", key = c),
             Action::ListBookmarks => t!("action.list_bookmarks"),
+            Action::ToggleBookmark => t!("action.toggle_bookmark"),
+            Action::NextBookmark => t!("action.next_bookmark"),
+            Action::PrevBookmark => t!("action.prev_bookmark"),
             Action::ToggleSearchCaseSensitive => t!("action.toggle_search_case_sensitive"),
             Action::ToggleSearchWholeWord => t!("action.toggle_search_whole_word"),
             Action::ToggleSearchRegex => t!("action.toggle_search_regex"),
@@ -2275,6 +2452,7 @@ impl KeybindingResolver {
             Action::QuickOpen => t!("action.quick_open"),
             Action::QuickOpenBuffers => t!("action.quick_open_buffers"),
             Action::QuickOpenFiles => t!("action.quick_open_files"),
+            Action::RepeatLastCommand => t!("action.repeat_last_command"),
             Action::OpenLiveGrep => t!("action.open_live_grep"),
             Action::ResumeLiveGrep => t!("action.resume_live_grep"),
             Action::LiveGrepExportQuickfix => t!("action.live_grep_export_quickfix"),
@@ -2287,6 +2465,7 @@ impl KeybindingResolver {
             Action::ToggleReadOnly => t!("action.toggle_read_only"),
             Action::TogglePageView => t!("action.toggle_page_view"),
             Action::SetPageWidth => t!("action.set_page_width"),
+            Action::SetComposeGuides => t!("action.set_compose_guides"),
             Action::NextBuffer => t!("action.next_buffer"),
             Action::PrevBuffer => t!("action.prev_buffer"),
             Action::NavigateBack => t!("action.navigate_back"),
@@ -2299,6 +2478,10 @@ impl KeybindingResolver {
             Action::IncreaseSplitSize => t!("action.increase_split_size"),
             Action::DecreaseSplitSize => t!("action.decrease_split_size"),
             Action::ToggleMaximizeSplit => t!("action.toggle_maximize_split"),
+            Action::UnzoomSplit => t!("action.unzoom_split"),
+            Action::EqualizeSplits => t!("action.equalize_splits"),
+            Action::MoveBufferToNextSplit => t!("action.move_buffer_to_next_split"),
+            Action::SwapSplits => t!("action.swap_splits"),
             Action::PromptConfirm => t!("action.prompt_confirm"),
             Action::PromptConfirmWithText(ref text) => {
                 format!("{} ({})", t!("action.prompt_confirm"), text).into()
@@ -2351,6 +2534,7 @@ impl KeybindingResolver {
             Action::ToggleVerticalScrollbar => t!("action.toggle_vertical_scrollbar"),
             Action::ToggleHorizontalScrollbar => t!("action.toggle_horizontal_scrollbar"),
             Action::FocusFileExplorer => t!("action.focus_file_explorer"),
+            Action::RevealInExplorer => t!("action.reveal_in_explorer"),
             Action::FocusEditor => t!("action.focus_editor"),
             Action::FileExplorerUp => t!("action.file_explorer_up"),
             Action::FileExplorerDown => t!("action.file_explorer_down"),
@@ -2384,6 +2568,9 @@ impl KeybindingResolver {
             Action::LspHover => t!("action.lsp_hover"),
             Action::LspSignatureHelp => t!("action.lsp_signature_help"),
             Action::LspCodeActions => t!("action.lsp_code_actions"),
+            Action::LspFormat => t!("action.lsp_format"),
+            Action::LspDocumentSymbols => t!("action.lsp_document_symbols"),
+            Action::LspWorkspaceSymbols => t!("action.lsp_workspace_symbols"),
             Action::LspRestart => t!("action.lsp_restart"),
             Action::LspStop => t!("action.lsp_stop"),
             Action::LspToggleForBuffer => t!("action.lsp_toggle_for_buffer"),
@@ -2403,6 +2590,7 @@ impl KeybindingResolver {
             Action::ReloadWithEncoding => t!("action.reload_with_encoding"),
             Action::SetLanguage => t!("action.set_language"),
             Action::ToggleIndentationStyle => t!("action.toggle_indentation_style"),
+            Action::ShowIndentation => t!("action.show_indentation"),
             Action::ToggleTabIndicators => t!("action.toggle_tab_indicators"),
             Action::ToggleWhitespaceIndicators => t!("action.toggle_whitespace_indicators"),
             Action::ResetBufferSettings => t!("action.reset_buffer_settings"),
@@ -2453,12 +2641,18 @@ impl KeybindingResolver {
             Action::SettingsInherit => t!("action.settings_inherit"),
             Action::ShellCommand => t!("action.shell_command"),
             Action::ShellCommandReplace => t!("action.shell_command_replace"),
+            Action::RunCustomCommand(name) => t!("action.run_custom_command", name = name),
+            Action::RunCurrentFile => t!("action.run_current_file"),
             Action::ToUpperCase => t!("action.to_uppercase"),
             Action::ToLowerCase => t!("action.to_lowercase"),
             Action::ToggleCase => t!("action.to_uppercase"),
             Action::SortLines => t!("action.sort_lines"),
+            Action::IncrementNumber => t!("action.increment_number"),
+            Action::DecrementNumber => t!("action.decrement_number"),
             Action::CalibrateInput => t!("action.calibrate_input"),
             Action::EventDebug => t!("action.event_debug"),
+            Action::UndoHistoryStats => t!("action.undo_history_stats"),
+            Action::ExportEventLog => t!("action.export_event_log"),
             Action::SuspendProcess => t!("action.suspend_process"),
             Action::LoadPluginFromBuffer => "Load Plugin from Buffer".into(),
             Action::InitReload => "Reload init.ts".into(),
@@ -2871,6 +3065,12 @@ mod tests {
             Action::from_str("calibrate_input", &args),
             Some(Action::CalibrateInput)
         );
+
+        // zoom_split is an alias for toggling the maximized split
+        assert_eq!(
+            Action::from_str("zoom_split", &args),
+            Some(Action::ToggleMaximizeSplit)
+        );
     }
 
     #[test]
@@ -3038,6 +3238,87 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_custom_keybinding_overrides_and_chords() {
+        use crate::config::{KeyPress, Keybinding};
+
+        let key = |key: &str, modifiers: &[&str]| KeyPress {
+            key: key.to_string(),
+            modifiers: modifiers.iter().map(|m| m.to_string()).collect(),
+        };
+
+        let mut config = Config::default();
+        config.keybindings = vec![
+            Keybinding {
+                key: "s".to_string(),
+                modifiers: vec!["ctrl".to_string(), "alt".to_string()],
+                keys: vec![],
+                action: "save".to_string(),
+                args: HashMap::new(),
+                when: Some("normal".to_string()),
+            },
+            // Overrides the default Ctrl+K (delete to line end)
+            Keybinding {
+                key: "k".to_string(),
+                modifiers: vec!["ctrl".to_string()],
+                keys: vec![],
+                action: "save".to_string(),
+                args: HashMap::new(),
+                when: None,
+            },
+            Keybinding {
+                key: String::new(),
+                modifiers: vec![],
+                keys: vec![key("j", &["ctrl"]), key("c", &["ctrl"])],
+                action: "toggle_comment".to_string(),
+                args: HashMap::new(),
+                when: Some("normal".to_string()),
+            },
+            // Invalid: unknown key name, skipped without affecting the rest
+            Keybinding {
+                key: "NotAKey".to_string(),
+                modifiers: vec![],
+                keys: vec![],
+                action: "quit".to_string(),
+                args: HashMap::new(),
+                when: None,
+            },
+        ];
+
+        let resolver = KeybindingResolver::new(&config);
+
+        let ctrl_alt_s = KeyEvent::new(
+            KeyCode::Char('s'),
+            KeyModifiers::CONTROL | KeyModifiers::ALT,
+        );
+        assert_eq!(
+            resolver.resolve(&ctrl_alt_s, KeyContext::Normal),
+            Action::Save
+        );
+        assert_eq!(
+            resolver.resolve_chord(&[], &ctrl_alt_s, KeyContext::Normal),
+            ChordResolution::NoMatch
+        );
+
+        let ctrl_k = KeyEvent::new(KeyCode::Char('k'), KeyModifiers::CONTROL);
+        assert_eq!(resolver.resolve(&ctrl_k, KeyContext::Normal), Action::Save);
+
+        let ctrl_j = KeyEvent::new(KeyCode::Char('j'), KeyModifiers::CONTROL);
+        let ctrl_c = KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL);
+        assert_eq!(
+            resolver.resolve_chord(&[], &ctrl_j, KeyContext::Normal),
+            ChordResolution::Partial
+        );
+        assert_eq!(
+            resolver.resolve_chord(
+                &[(KeyCode::Char('j'), KeyModifiers::CONTROL)],
+                &ctrl_c,
+                KeyContext::Normal
+            ),
+            ChordResolution::Complete(Action::ToggleComment)
+        );
+    }
+
     #[test]
     fn test_all_context_default_bindings_exist() {
         let config = Config::default();
@@ -3,6 +3,7 @@
 //! This module handles the input-to-action-to-event translation.
 
 pub mod actions;
+mod align;
 pub mod buffer_mode;
 pub mod command_registry;
 pub mod commands;
@@ -14,8 +15,10 @@ pub mod key_translator;
 pub mod keybindings;
 mod line_move;
 pub mod multi_cursor;
+mod number_increment;
 pub mod position_history;
 pub mod quick_open;
+mod surround;
 
 #[cfg(test)]
 pub mod tests_language_features;
@@ -0,0 +1,174 @@
+//! Increment/decrement helpers for the number under or after the cursor.
+
+use crate::model::cursor::Cursors;
+use crate::model::event::Event;
+use crate::state::EditorState;
+use std::ops::Range;
+
+/// A number token found on a line, as byte offsets within the line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct NumberToken {
+    range: Range<usize>,
+    hex: bool,
+}
+
+/// Find the first number that the cursor is on, touching, or before on
+/// `line`. Decimal numbers take a leading `-` as their sign unless it
+/// follows a word character (`a-1` is a subtraction, not a negative).
+fn find_number(line: &str, col: usize) -> Option<NumberToken> {
+    let bytes = line.as_bytes();
+    let is_word = |b: u8| b.is_ascii_alphanumeric() || b == b'_';
+    let mut i = 0;
+    while i < bytes.len() {
+        if !bytes[i].is_ascii_digit() {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        let token = if bytes[i] == b'0'
+            && matches!(bytes.get(i + 1), Some(b'x' | b'X'))
+            && bytes.get(i + 2).is_some_and(|b| b.is_ascii_hexdigit())
+        {
+            let mut end = i + 2;
+            while end < bytes.len() && bytes[end].is_ascii_hexdigit() {
+                end += 1;
+            }
+            NumberToken {
+                range: start..end,
+                hex: true,
+            }
+        } else {
+            let mut end = i;
+            while end < bytes.len() && bytes[end].is_ascii_digit() {
+                end += 1;
+            }
+            let negative =
+                start > 0 && bytes[start - 1] == b'-' && (start < 2 || !is_word(bytes[start - 2]));
+            NumberToken {
+                range: if negative { start - 1 } else { start }..end,
+                hex: false,
+            }
+        };
+        if token.range.end >= col {
+            return Some(token);
+        }
+        i = token.range.end;
+    }
+    None
+}
+
+/// Format `text` (a number token) plus `delta`, keeping leading zeros,
+/// sign and hex digit case. Returns `None` when the result overflows.
+fn add_to_number(text: &str, hex: bool, delta: i64) -> Option<String> {
+    if hex {
+        let (prefix, digits) = text.split_at(2);
+        let value = u64::from_str_radix(digits, 16).ok()?;
+        let new_value = value.wrapping_add_signed(delta);
+        let formatted = if digits.chars().any(|c| c.is_ascii_uppercase()) {
+            format!("{:0width$X}", new_value, width = digits.len())
+        } else {
+            format!("{:0width$x}", new_value, width = digits.len())
+        };
+        return Some(format!("{}{}", prefix, formatted));
+    }
+
+    let (negative, digits) = match text.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, text),
+    };
+    let magnitude: i128 = digits.parse().ok()?;
+    let value = if negative { -magnitude } else { magnitude };
+    let new_value = value.checked_add(i128::from(delta))?;
+    let width = if digits.len() > 1 && digits.starts_with('0') {
+        digits.len()
+    } else {
+        0
+    };
+    let sign = if new_value < 0 { "-" } else { "" };
+    Some(format!(
+        "{}{:0width$}",
+        sign,
+        new_value.unsigned_abs(),
+        width = width
+    ))
+}
+
+/// Add `delta` to the number under or after each cursor on its line,
+/// leaving each cursor just after its updated number.
+pub(crate) fn increment_numbers(
+    state: &mut EditorState,
+    cursors: &Cursors,
+    events: &mut Vec<Event>,
+    delta: i64,
+    estimated_line_length: usize,
+) {
+    let mut edits = Vec::new();
+    for (cursor_id, cursor) in cursors.iter() {
+        let mut iter = state
+            .buffer
+            .line_iterator(cursor.position, estimated_line_length);
+        let line_start = iter.current_position();
+        let Some((_, line)) = iter.next_line() else {
+            continue;
+        };
+        let Some(token) = find_number(&line, cursor.position - line_start) else {
+            continue;
+        };
+        let text = &line[token.range.clone()];
+        let Some(replacement) = add_to_number(text, token.hex, delta) else {
+            continue;
+        };
+        let range = line_start + token.range.start..line_start + token.range.end;
+        edits.push((cursor_id, range, text.to_string(), replacement));
+    }
+
+    // Cursors on the same number change it once; apply from the end so
+    // earlier offsets stay valid
+    edits.sort_by_key(|(_, range, _, _)| std::cmp::Reverse(range.start));
+    edits.dedup_by_key(|(_, range, _, _)| range.start);
+
+    for (cursor_id, range, deleted_text, replacement) in edits {
+        events.push(Event::Delete {
+            range: range.clone(),
+            deleted_text,
+            cursor_id,
+        });
+        events.push(Event::Insert {
+            position: range.start,
+            text: replacement,
+            cursor_id,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_number_under_or_after_cursor() {
+        let token = |range: Range<usize>, hex| Some(NumberToken { range, hex });
+        assert_eq!(find_number("v9", 0), token(1..2, false));
+        assert_eq!(find_number("a 12 b 34", 4), token(2..4, false));
+        assert_eq!(find_number("a 12 b 34", 5), token(7..9, false));
+        assert_eq!(find_number("x = -5", 0), token(4..6, false));
+        assert_eq!(find_number("a-1", 0), token(2..3, false));
+        assert_eq!(find_number("0xff;", 3), token(0..4, true));
+        assert_eq!(find_number("no digits", 0), None);
+    }
+
+    #[test]
+    fn test_add_to_number() {
+        assert_eq!(add_to_number("9", false, 1).as_deref(), Some("10"));
+        assert_eq!(add_to_number("007", false, -1).as_deref(), Some("006"));
+        assert_eq!(add_to_number("0", false, -1).as_deref(), Some("-1"));
+        assert_eq!(add_to_number("-1", false, 2).as_deref(), Some("1"));
+        assert_eq!(add_to_number("-010", false, 1).as_deref(), Some("-009"));
+        assert_eq!(add_to_number("0x0f", true, 1).as_deref(), Some("0x10"));
+        assert_eq!(add_to_number("0xFF", true, 1).as_deref(), Some("0x100"));
+        assert_eq!(
+            add_to_number("0x00", true, -1).as_deref(),
+            Some("0xffffffffffffffff")
+        );
+    }
+}
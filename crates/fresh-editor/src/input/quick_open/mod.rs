@@ -22,8 +22,9 @@ use std::collections::HashMap;
 /// Result of confirming a selection in a provider
 #[derive(Debug, Clone)]
 pub enum QuickOpenResult {
-    /// Execute an editor action
-    ExecuteAction(Action),
+    /// Execute an editor action. Non-empty `args` are typed into the prompt
+    /// the action opens and confirmed (e.g. `Add Ruler 40`).
+    ExecuteAction { action: Action, args: Vec<String> },
     /// Open a file at optional line/column
     OpenFile {
         path: String,
@@ -33,7 +34,7 @@ pub enum QuickOpenResult {
     /// Show a buffer by ID
     ShowBuffer(usize),
     /// Go to a line in the current buffer
-    GotoLine(GotoLineTarget),
+    GotoLine(GotoPosition),
     /// Do nothing (provider handled it internally)
     None,
     /// Show an error message
@@ -48,7 +49,20 @@ pub enum GotoLineTarget {
     /// Absolute 1-based line number (input had no leading sign).
     Absolute(usize),
     /// Signed offset from the current cursor line (input had `+`/`-` prefix).
+    /// `Relative(0)` is the current line itself, used for `:column` input.
     Relative(isize),
+    /// Percentage of the way through the file, `0..=100` (input `50%`).
+    Percent(usize),
+    /// The last line of the file (input `$`).
+    Last,
+}
+
+/// A parsed goto target: a line and, optionally, a column on it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GotoPosition {
+    pub line: GotoLineTarget,
+    /// 1-based visual column (tabs expanded), if the input had one.
+    pub column: Option<usize>,
 }
 
 /// Parse a goto-line input string.
@@ -56,7 +70,9 @@ pub enum GotoLineTarget {
 /// - `"500"` → `Absolute(500)`
 /// - `"+3"` → `Relative(3)`
 /// - `"-3"` → `Relative(-3)`
-/// - `"0"`, `"+0"`, `"-0"`, `""`, `"abc"` → `None`
+/// - `"50%"` → `Percent(50)`
+/// - `"$"` → `Last`
+/// - `"0"`, `"+0"`, `"-0"`, `"101%"`, `""`, `"abc"` → `None`
 ///
 /// Whitespace around the input is ignored. The leading-sign convention is
 /// independent of any display setting: the user's literal input decides.
@@ -65,6 +81,9 @@ pub fn parse_goto_line_input(input: &str) -> Option<GotoLineTarget> {
     if trimmed.is_empty() {
         return None;
     }
+    if let Some(target) = parse_goto_file_fraction(trimmed) {
+        return target;
+    }
     if let Some(rest) = trimmed
         .strip_prefix('+')
         .or_else(|| trimmed.strip_prefix('-'))
@@ -87,6 +106,87 @@ pub fn parse_goto_line_input(input: &str) -> Option<GotoLineTarget> {
     }
 }
 
+/// Parse the goto forms that are relative to the whole file, shared by the
+/// goto-line and byte-offset prompts: `$` is the end and `N%` (`0..=100`)
+/// a percentage. Returns `None` when `trimmed` is neither form, and
+/// `Some(None)` when it is a malformed percentage like `101%`.
+fn parse_goto_file_fraction(trimmed: &str) -> Option<Option<GotoLineTarget>> {
+    if trimmed == "$" {
+        return Some(Some(GotoLineTarget::Last));
+    }
+    let percent = trimmed.strip_suffix('%')?;
+    if percent.is_empty() || !percent.bytes().all(|b| b.is_ascii_digit()) {
+        return Some(None);
+    }
+    Some(
+        percent
+            .parse::<usize>()
+            .ok()
+            .filter(|&p| p <= 100)
+            .map(GotoLineTarget::Percent),
+    )
+}
+
+/// Parse input to the byte-offset prompt used for large files whose lines
+/// have not been scanned. `Absolute(n)` is a byte offset, so `0` is valid,
+/// and an optional trailing `B`/`b` is allowed; `+N`/`-N` is
+/// `Relative(±N)` bytes from the cursor, and `50%` and `$` work as in
+/// [`parse_goto_line_input`].
+pub fn parse_goto_byte_offset_input(input: &str) -> Option<GotoLineTarget> {
+    let trimmed = input.trim();
+    if let Some(target) = parse_goto_file_fraction(trimmed) {
+        return target;
+    }
+    let number = trimmed
+        .strip_suffix('B')
+        .or_else(|| trimmed.strip_suffix('b'))
+        .unwrap_or(trimmed);
+    let (sign, digits) = match number.strip_prefix('+') {
+        Some(rest) => (Some(1), rest),
+        None => match number.strip_prefix('-') {
+            Some(rest) => (Some(-1), rest),
+            None => (None, number),
+        },
+    };
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    match sign {
+        Some(sign) => {
+            let delta = digits.parse::<isize>().ok()?;
+            (delta != 0).then_some(GotoLineTarget::Relative(sign * delta))
+        }
+        None => digits.parse::<usize>().ok().map(GotoLineTarget::Absolute),
+    }
+}
+
+/// Parse a goto input with an optional `:column` part.
+///
+/// - `"10"`, `"+3"` → the line as in [`parse_goto_line_input`], no column
+/// - `"10:5"`, `"-2:5"` → that line, column 5
+/// - `":5"` → column 5 of the current line
+/// - `"10:"`, `"10:0"`, `"10:x"`, `":"` → `None`
+pub fn parse_goto_input(input: &str) -> Option<GotoPosition> {
+    let trimmed = input.trim();
+    let Some((line, column)) = trimmed.split_once(':') else {
+        return parse_goto_line_input(trimmed).map(|line| GotoPosition { line, column: None });
+    };
+    let column = column.trim();
+    if column.is_empty() || !column.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let column = column.parse::<usize>().ok().filter(|&c| c > 0)?;
+    let line = if line.trim().is_empty() {
+        GotoLineTarget::Relative(0)
+    } else {
+        parse_goto_line_input(line)?
+    };
+    Some(GotoPosition {
+        line,
+        column: Some(column),
+    })
+}
+
 /// Context provided to providers when generating suggestions
 #[derive(Debug, Clone)]
 pub struct QuickOpenContext {
@@ -328,6 +428,94 @@ mod tests {
         assert_eq!(parse_goto_line_input("+-3"), None);
         assert_eq!(parse_goto_line_input("abc"), None);
         assert_eq!(parse_goto_line_input("3a"), None);
+        assert_eq!(parse_goto_line_input("%"), None);
+        assert_eq!(parse_goto_line_input("101%"), None);
+        assert_eq!(parse_goto_line_input("+5%"), None);
+        assert_eq!(parse_goto_line_input("$$"), None);
+    }
+
+    #[test]
+    fn parse_goto_byte_offset_input_accepts_bytes_relative_percent_and_last() {
+        assert_eq!(
+            parse_goto_byte_offset_input("0"),
+            Some(GotoLineTarget::Absolute(0))
+        );
+        assert_eq!(
+            parse_goto_byte_offset_input(" 1024B "),
+            Some(GotoLineTarget::Absolute(1024))
+        );
+        assert_eq!(
+            parse_goto_byte_offset_input("50%"),
+            Some(GotoLineTarget::Percent(50))
+        );
+        assert_eq!(
+            parse_goto_byte_offset_input("$"),
+            Some(GotoLineTarget::Last)
+        );
+        assert_eq!(
+            parse_goto_byte_offset_input("+5"),
+            Some(GotoLineTarget::Relative(5))
+        );
+        assert_eq!(
+            parse_goto_byte_offset_input("-512B"),
+            Some(GotoLineTarget::Relative(-512))
+        );
+        assert_eq!(parse_goto_byte_offset_input("+0"), None);
+        assert_eq!(parse_goto_byte_offset_input("+-5"), None);
+        assert_eq!(parse_goto_byte_offset_input("-"), None);
+        assert_eq!(parse_goto_byte_offset_input("101%"), None);
+        assert_eq!(parse_goto_byte_offset_input("B"), None);
+        assert_eq!(parse_goto_byte_offset_input(""), None);
+    }
+
+    #[test]
+    fn parse_goto_line_input_percent_and_last() {
+        assert_eq!(
+            parse_goto_line_input("50%"),
+            Some(GotoLineTarget::Percent(50))
+        );
+        assert_eq!(
+            parse_goto_line_input(" 0% "),
+            Some(GotoLineTarget::Percent(0))
+        );
+        assert_eq!(parse_goto_line_input("$"), Some(GotoLineTarget::Last));
+        assert_eq!(
+            parse_goto_input("$:4"),
+            Some(GotoPosition {
+                line: GotoLineTarget::Last,
+                column: Some(4)
+            })
+        );
+    }
+
+    #[test]
+    fn parse_goto_input_with_column() {
+        let at = |line, column| {
+            Some(GotoPosition {
+                line,
+                column: Some(column),
+            })
+        };
+        assert_eq!(
+            parse_goto_input("10:5"),
+            at(GotoLineTarget::Absolute(10), 5)
+        );
+        assert_eq!(
+            parse_goto_input(" -2:7 "),
+            at(GotoLineTarget::Relative(-2), 7)
+        );
+        assert_eq!(parse_goto_input(":3"), at(GotoLineTarget::Relative(0), 3));
+        assert_eq!(
+            parse_goto_input("42"),
+            Some(GotoPosition {
+                line: GotoLineTarget::Absolute(42),
+                column: None
+            })
+        );
+
+        for invalid in [":", "10:", "10:0", "10:x", "10:+3", "x:3", "1:2:3"] {
+            assert_eq!(parse_goto_input(invalid), None, "input {invalid:?}");
+        }
     }
 
     #[test]
@@ -7,10 +7,11 @@
 //! - GotoLineProvider: Go to a specific line (prefix: ":")
 
 use super::{
-    parse_goto_line_input, GotoLineTarget, QuickOpenContext, QuickOpenProvider, QuickOpenResult,
+    parse_goto_input, GotoLineTarget, GotoPosition, QuickOpenContext, QuickOpenProvider,
+    QuickOpenResult,
 };
 use crate::input::commands::Suggestion;
-use crate::input::fuzzy::FuzzyMatcher;
+use crate::input::fuzzy::{fuzzy_match, FuzzyMatcher};
 use rust_i18n::t;
 
 // ============================================================================
@@ -51,22 +52,33 @@ impl QuickOpenProvider for CommandProvider {
     fn suggestions(&self, query: &str, context: &QuickOpenContext) -> Vec<Suggestion> {
         let registry = self.command_registry.read().unwrap();
         let keybindings = self.keybinding_resolver.read().unwrap();
+        let filter = |query: &str| {
+            registry.filter(
+                query,
+                context.key_context.clone(),
+                &keybindings,
+                context.has_selection,
+                &context.custom_contexts,
+                context.buffer_mode.as_deref(),
+                context.has_lsp_config,
+            )
+        };
 
-        registry.filter(
-            query,
-            context.key_context.clone(),
-            &keybindings,
-            context.has_selection,
-            &context.custom_contexts,
-            context.buffer_mode.as_deref(),
-            context.has_lsp_config,
-        )
+        let suggestions = filter(query);
+        if !suggestions.is_empty() {
+            return suggestions;
+        }
+        // "Add Ruler 40": match the command without its inline argument
+        match split_command_argument(query) {
+            Some((name, _)) => filter(name),
+            None => suggestions,
+        }
     }
 
     fn on_select(
         &self,
         suggestion: Option<&Suggestion>,
-        _query: &str,
+        query: &str,
         _context: &QuickOpenContext,
     ) -> QuickOpenResult {
         let suggestion = match suggestion {
@@ -94,7 +106,19 @@ impl QuickOpenProvider for CommandProvider {
         if let Ok(mut reg) = self.command_registry.write() {
             reg.record_usage(&name);
         }
-        QuickOpenResult::ExecuteAction(action)
+
+        // The trailing word is an argument only if the query doesn't already
+        // match the command name with it included
+        let args = match split_command_argument(query) {
+            Some((name_query, arg))
+                if !fuzzy_match(query, &suggestion.text).matched
+                    && fuzzy_match(name_query, &suggestion.text).matched =>
+            {
+                vec![arg.to_string()]
+            }
+            _ => Vec::new(),
+        };
+        QuickOpenResult::ExecuteAction { action, args }
     }
 
     fn as_any(&self) -> &dyn std::any::Any {
@@ -102,6 +126,14 @@ impl QuickOpenProvider for CommandProvider {
     }
 }
 
+/// Split a command palette query into the command part and a trailing
+/// argument word, e.g. `"Add Ruler 40"` -> `("Add Ruler", "40")`.
+fn split_command_argument(query: &str) -> Option<(&str, &str)> {
+    let (name, arg) = query.trim().rsplit_once(char::is_whitespace)?;
+    let name = name.trim_end();
+    (!name.is_empty() && !arg.is_empty()).then_some((name, arg))
+}
+
 // ============================================================================
 // Buffer Provider (prefix: "#")
 // ============================================================================
@@ -215,16 +247,27 @@ impl QuickOpenProvider for GotoLineProvider {
             ];
         }
 
-        match parse_goto_line_input(query) {
-            Some(target) => {
-                let label = match target {
-                    GotoLineTarget::Absolute(n) => {
-                        t!("quick_open.goto_line", line = n.to_string()).to_string()
-                    }
-                    GotoLineTarget::Relative(d) => {
-                        // Format with explicit sign so "+3" reads back as "+3", not "3".
-                        t!("quick_open.goto_line", line = format!("{:+}", d)).to_string()
+        match parse_goto_input(query) {
+            Some(GotoPosition { line, column }) => {
+                let line_label = match line {
+                    GotoLineTarget::Absolute(n) => n.to_string(),
+                    // Format with explicit sign so "+3" reads back as "+3", not "3".
+                    GotoLineTarget::Relative(d) => format!("{:+}", d),
+                    GotoLineTarget::Percent(p) => format!("{p}%"),
+                    GotoLineTarget::Last => "$".to_string(),
+                };
+                let label = match (line, column) {
+                    // `:column` alone stays on the current line
+                    (GotoLineTarget::Relative(0), Some(column)) => {
+                        t!("quick_open.goto_column", column = column).to_string()
                     }
+                    (_, Some(column)) => t!(
+                        "quick_open.goto_line_col",
+                        line = line_label,
+                        column = column
+                    )
+                    .to_string(),
+                    (_, None) => t!("quick_open.goto_line", line = line_label).to_string(),
                 };
                 vec![Suggestion::new(label)
                     .with_description(t!("quick_open.press_enter").to_string())
@@ -245,7 +288,7 @@ impl QuickOpenProvider for GotoLineProvider {
     ) -> QuickOpenResult {
         suggestion
             .and_then(|s| s.value.as_deref())
-            .and_then(parse_goto_line_input)
+            .and_then(parse_goto_input)
             .map(QuickOpenResult::GotoLine)
             .unwrap_or(QuickOpenResult::None)
     }
@@ -285,6 +328,14 @@ struct FrecencyData {
     last_access: std::time::Instant,
 }
 
+/// Which files the index includes, mirroring the file explorer's
+/// `show_hidden` / `show_gitignored` settings.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+struct FileVisibility {
+    show_hidden: bool,
+    show_gitignored: bool,
+}
+
 /// Shared state between the FileProvider and its background loading task.
 ///
 /// Wrapped in a single `Arc<Mutex<>>` to keep the FileProvider struct flat.
@@ -293,13 +344,18 @@ struct FileCache {
     files: Option<std::sync::Arc<Vec<FileEntry>>>,
     /// Whether a background load is in progress.
     loading: bool,
+    /// Visibility the cached list was (or is being) built with.
+    visibility: FileVisibility,
 }
 
 /// Provider for finding files in the project.
 ///
 /// Uses `git ls-files` via [`ProcessSpawner`] as the fast path (respects
 /// `.gitignore`, works on remote hosts), then falls back to recursive
-/// directory walking via the [`FileSystem`] trait.
+/// directory walking via the [`FileSystem`] trait, which applies `.gitignore`
+/// files itself. Hidden and gitignored files follow the file explorer's
+/// settings (see [`FileProvider::set_visibility`]); the walk always skips
+/// hidden entries. Indexes stop at `MAX_FILES` files.
 ///
 /// File enumeration runs on a background thread to avoid blocking the UI.
 /// When the cache is empty, `suggestions()` returns a "Loading…" placeholder
@@ -329,6 +385,7 @@ impl FileProvider {
             cache: std::sync::Arc::new(std::sync::Mutex::new(FileCache {
                 files: None,
                 loading: false,
+                visibility: FileVisibility::default(),
             })),
             frecency: std::sync::Arc::new(std::sync::RwLock::new(std::collections::HashMap::new())),
             filesystem,
@@ -349,6 +406,23 @@ impl FileProvider {
         }
     }
 
+    /// Include hidden and/or gitignored files, as the file explorer does.
+    /// Changing either setting drops the cached list so the next query
+    /// re-indexes.
+    pub fn set_visibility(&self, show_hidden: bool, show_gitignored: bool) {
+        let visibility = FileVisibility {
+            show_hidden,
+            show_gitignored,
+        };
+        let changed = match self.cache.lock() {
+            Ok(mut c) => std::mem::replace(&mut c.visibility, visibility) != visibility,
+            Err(_) => false,
+        };
+        if changed {
+            self.clear_cache();
+        }
+    }
+
     /// Cancel any in-progress background file load.
     /// Called when the user closes Quick Open so we don't keep walking.
     pub fn cancel_loading(&self) {
@@ -505,6 +579,7 @@ impl FileProvider {
         };
 
         cache.loading = true;
+        let visibility = cache.visibility;
         // Reset cancel flag for this new load
         self.cancel
             .store(false, std::sync::atomic::Ordering::Relaxed);
@@ -516,7 +591,7 @@ impl FileProvider {
 
         handle.spawn_blocking(move || {
             // Fast path: git ls-files returns everything at once.
-            if let Some(files) = try_git_files_blocking(&process_spawner, &cwd) {
+            if let Some(files) = try_git_files_blocking(&process_spawner, &cwd, visibility) {
                 let frecency_map = frecency.read().ok();
                 let entries: Vec<FileEntry> = files
                     .into_iter()
@@ -545,7 +620,7 @@ impl FileProvider {
 
             // Slow path: directory walk with periodic incremental updates so
             // the UI can show partial results while the scan continues.
-            walk_dir_with_updates(&*filesystem, &cwd, &cancel, &frecency, &sender);
+            walk_dir_with_updates(&*filesystem, &cwd, visibility, &cancel, &frecency, &sender);
         });
 
         None
@@ -571,16 +646,20 @@ impl FileProvider {
         Some(files)
     }
 
+    fn visibility(&self) -> FileVisibility {
+        self.cache.lock().map(|c| c.visibility).unwrap_or_default()
+    }
+
     /// Synchronous `try_git_files` — used by the sync fallback path.
     fn try_git_files(&self, cwd: &str) -> Option<Vec<String>> {
         let handle = self.runtime_handle.as_ref()?;
-        try_git_files_with_handle(&self.process_spawner, cwd, handle)
+        try_git_files_with_handle(&self.process_spawner, cwd, handle, self.visibility())
     }
 
     /// Synchronous `try_walk_dir` — used by the sync fallback path.
     fn try_walk_dir(&self, cwd: &str) -> Option<Vec<String>> {
         let cancel = std::sync::atomic::AtomicBool::new(false);
-        try_walk_dir_blocking(&*self.filesystem, cwd, &cancel)
+        try_walk_dir_blocking(&*self.filesystem, cwd, self.visibility(), &cancel)
     }
 }
 
@@ -596,28 +675,30 @@ impl FileProvider {
 fn try_git_files_blocking(
     spawner: &std::sync::Arc<dyn crate::services::remote::ProcessSpawner>,
     cwd: &str,
+    visibility: FileVisibility,
 ) -> Option<Vec<String>> {
     // Inside spawn_blocking we can use Handle::current() since the runtime is alive.
     let handle = tokio::runtime::Handle::try_current().ok()?;
-    try_git_files_with_handle(spawner, cwd, &handle)
+    try_git_files_with_handle(spawner, cwd, &handle, visibility)
 }
 
 fn try_git_files_with_handle(
     spawner: &std::sync::Arc<dyn crate::services::remote::ProcessSpawner>,
     cwd: &str,
     handle: &tokio::runtime::Handle,
+    visibility: FileVisibility,
 ) -> Option<Vec<String>> {
+    let mut args = vec![
+        "ls-files".to_string(),
+        "--cached".to_string(),
+        "--others".to_string(),
+    ];
+    // Without --exclude-standard, untracked gitignored files are listed too
+    if !visibility.show_gitignored {
+        args.push("--exclude-standard".to_string());
+    }
     let result = handle
-        .block_on(spawner.spawn(
-            "git".to_string(),
-            vec![
-                "ls-files".to_string(),
-                "--cached".to_string(),
-                "--others".to_string(),
-                "--exclude-standard".to_string(),
-            ],
-            Some(cwd.to_string()),
-        ))
+        .block_on(spawner.spawn("git".to_string(), args, Some(cwd.to_string())))
         .ok()?;
 
     if result.exit_code != 0 {
@@ -628,30 +709,48 @@ fn try_git_files_with_handle(
         .stdout
         .lines()
         .filter(|line| !line.is_empty() && !line.starts_with(".git/"))
+        .filter(|line| visibility.show_hidden || !has_hidden_component(line))
+        .filter(|line| !visibility.show_gitignored || !in_ignored_dir(line))
+        .take(MAX_FILES)
         .map(|s| s.to_string())
         .collect();
 
     Some(files)
 }
 
+/// Whether any component of a `/`-separated relative path starts with `.`.
+fn has_hidden_component(rel: &str) -> bool {
+    rel.split('/').any(|part| part.starts_with('.'))
+}
+
+/// Whether a relative path lies inside one of `IGNORED_DIRS`. Only needed
+/// when gitignored files are listed, since git would otherwise skip them.
+fn in_ignored_dir(rel: &str) -> bool {
+    let mut dirs = rel.split('/');
+    dirs.next_back();
+    dirs.any(|dir| IGNORED_DIRS.contains(&dir))
+}
+
 /// Walk the directory tree via `FileSystem::walk_files` (blocking).
 fn try_walk_dir_blocking(
     fs: &dyn crate::model::filesystem::FileSystem,
     cwd: &str,
+    visibility: FileVisibility,
     cancel: &std::sync::atomic::AtomicBool,
 ) -> Option<Vec<String>> {
     use std::path::Path;
 
     let base = Path::new(cwd);
     let mut files = Vec::new();
+    let mut gitignores = crate::services::fs::WalkGitignores::new(fs, base);
 
     // Errors (e.g., root doesn't exist) are treated as "no files found".
-    drop(
-        fs.walk_files(base, IGNORED_DIRS, cancel, &mut |_path, rel| {
+    drop(fs.walk_files(base, IGNORED_DIRS, cancel, &mut |path, rel| {
+        if visibility.show_gitignored || !gitignores.is_ignored(path) {
             files.push(rel.to_string());
-            files.len() < MAX_FILES
-        }),
-    );
+        }
+        files.len() < MAX_FILES
+    }));
 
     if files.is_empty() {
         None
@@ -669,6 +768,7 @@ const WALK_UPDATE_INTERVAL: std::time::Duration = std::time::Duration::from_mill
 fn walk_dir_with_updates(
     fs: &dyn crate::model::filesystem::FileSystem,
     cwd: &str,
+    visibility: FileVisibility,
     cancel: &std::sync::atomic::AtomicBool,
     frecency: &std::sync::RwLock<std::collections::HashMap<String, FrecencyData>>,
     sender: &std::sync::mpsc::Sender<crate::services::async_bridge::AsyncMessage>,
@@ -679,11 +779,15 @@ fn walk_dir_with_updates(
     let mut paths: Vec<String> = Vec::new();
     let mut last_send = std::time::Instant::now();
     let mut receiver_gone = false;
+    let mut gitignores = crate::services::fs::WalkGitignores::new(fs, base);
 
     // `walk_files` errors (e.g. root doesn't exist, permission denied at the
     // top level) are treated as "no files found" — any paths already
     // collected in `paths` are still surfaced via the final send below.
-    if let Err(e) = fs.walk_files(base, IGNORED_DIRS, cancel, &mut |_path, rel| {
+    if let Err(e) = fs.walk_files(base, IGNORED_DIRS, cancel, &mut |path, rel| {
+        if !visibility.show_gitignored && gitignores.is_ignored(path) {
+            return true;
+        }
         paths.push(rel.to_string());
 
         // Send a partial snapshot at regular intervals.
@@ -896,6 +1000,12 @@ impl QuickOpenProvider for FileProvider {
                 "Scanning for more files…"
             };
             suggestions.push(Suggestion::disabled(msg.to_string()));
+        } else if files.as_ref().is_some_and(|f| f.len() >= MAX_FILES) {
+            // The index stopped at the cap; files beyond it are only
+            // reachable through the prefix probe.
+            suggestions.push(Suggestion::disabled(
+                t!("quick_open.files_truncated", max = MAX_FILES).to_string(),
+            ));
         }
 
         suggestions
@@ -1025,7 +1135,10 @@ mod tests {
         let suggestions = provider.suggestions("42", &context);
         let result = provider.on_select(suggestions.first(), "42", &context);
         match result {
-            QuickOpenResult::GotoLine(GotoLineTarget::Absolute(line)) => assert_eq!(line, 42),
+            QuickOpenResult::GotoLine(GotoPosition {
+                line: GotoLineTarget::Absolute(line),
+                ..
+            }) => assert_eq!(line, 42),
             other => panic!("expected absolute GotoLine result, got {:?}", other),
         }
     }
@@ -1048,13 +1161,19 @@ mod tests {
 
             let suggestions = provider.suggestions("+3", &context);
             match provider.on_select(suggestions.first(), "+3", &context) {
-                QuickOpenResult::GotoLine(GotoLineTarget::Relative(d)) => assert_eq!(d, 3),
+                QuickOpenResult::GotoLine(GotoPosition {
+                    line: GotoLineTarget::Relative(d),
+                    ..
+                }) => assert_eq!(d, 3),
                 other => panic!("expected relative GotoLine, got {:?}", other),
             }
 
             let suggestions = provider.suggestions("-7", &context);
             match provider.on_select(suggestions.first(), "-7", &context) {
-                QuickOpenResult::GotoLine(GotoLineTarget::Relative(d)) => assert_eq!(d, -7),
+                QuickOpenResult::GotoLine(GotoPosition {
+                    line: GotoLineTarget::Relative(d),
+                    ..
+                }) => assert_eq!(d, -7),
                 other => panic!("expected relative GotoLine, got {:?}", other),
             }
 
@@ -1080,7 +1199,10 @@ mod tests {
             assert_eq!(suggestions.len(), 1);
             assert!(!suggestions[0].disabled);
             match provider.on_select(suggestions.first(), "42", &context) {
-                QuickOpenResult::GotoLine(GotoLineTarget::Absolute(n)) => assert_eq!(n, 42),
+                QuickOpenResult::GotoLine(GotoPosition {
+                    line: GotoLineTarget::Absolute(n),
+                    ..
+                }) => assert_eq!(n, 42),
                 other => panic!("expected absolute GotoLine, got {:?}", other),
             }
         }
@@ -1185,6 +1307,60 @@ mod tests {
         assert_eq!(suggestions[0].value.as_deref(), Some("visible.txt"));
     }
 
+    #[test]
+    fn test_file_provider_walk_respects_gitignore() {
+        let dir = tempfile::tempdir().unwrap();
+        let base = dir.path();
+
+        std::fs::write(base.join(".gitignore"), b"build/\n*.log\n").unwrap();
+        std::fs::write(base.join("app.rs"), b"").unwrap();
+        std::fs::write(base.join("debug.log"), b"").unwrap();
+        std::fs::create_dir(base.join("build")).unwrap();
+        std::fs::write(base.join("build").join("out.rs"), b"").unwrap();
+        // Nested .gitignore applies below its own directory
+        std::fs::create_dir(base.join("src")).unwrap();
+        std::fs::write(base.join("src").join(".gitignore"), b"gen.rs\n").unwrap();
+        std::fs::write(base.join("src").join("gen.rs"), b"").unwrap();
+        std::fs::write(base.join("src").join("main.rs"), b"").unwrap();
+
+        let provider = make_file_provider();
+        let context = make_test_context(&base.display().to_string());
+        let paths = |suggestions: Vec<Suggestion>| {
+            let mut paths: Vec<String> = suggestions.into_iter().filter_map(|s| s.value).collect();
+            paths.sort();
+            paths
+        };
+
+        assert_eq!(
+            paths(provider.suggestions("", &context)),
+            vec!["app.rs", "src/main.rs"]
+        );
+
+        // Showing gitignored files (as the explorer can) re-indexes
+        provider.set_visibility(false, true);
+        assert_eq!(
+            paths(provider.suggestions("", &context)),
+            vec![
+                "app.rs",
+                "build/out.rs",
+                "debug.log",
+                "src/gen.rs",
+                "src/main.rs"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_git_listing_filters() {
+        assert!(has_hidden_component(".github/workflows/ci.yml"));
+        assert!(has_hidden_component("src/.env"));
+        assert!(!has_hidden_component("src/main.rs"));
+
+        assert!(in_ignored_dir("target/debug/build.rs"));
+        assert!(in_ignored_dir("web/node_modules/pkg/index.js"));
+        assert!(!in_ignored_dir("src/target.rs"));
+    }
+
     #[test]
     fn test_file_provider_fuzzy_filter() {
         let dir = tempfile::tempdir().unwrap();
@@ -0,0 +1,209 @@
+//! Surround/unwrap helpers for wrapping selections in delimiters.
+
+use crate::model::cursor::Cursors;
+use crate::model::event::{CursorId, Event};
+use crate::state::EditorState;
+
+/// Resolve a delimiter spec into its opening and closing text.
+///
+/// Brackets map to their pair from either side (`(` or `)` gives `(…)`),
+/// an HTML-like tag (`<div class="x">`) closes with `</div>`, and anything
+/// else (quotes, `*`, `**`, …) is used on both sides.
+pub(crate) fn surround_pair(spec: &str) -> Option<(String, String)> {
+    let pair = |open: &str, close: &str| Some((open.to_string(), close.to_string()));
+    match spec {
+        "" => None,
+        "(" | ")" => pair("(", ")"),
+        "[" | "]" => pair("[", "]"),
+        "{" | "}" => pair("{", "}"),
+        "<" | ">" => pair("<", ">"),
+        tag if tag.len() > 2 && tag.starts_with('<') && tag.ends_with('>') => {
+            let name = tag[1..tag.len() - 1]
+                .split(|c: char| c.is_whitespace() || c == '/')
+                .next()
+                .filter(|name| !name.is_empty())?;
+            Some((tag.to_string(), format!("</{}>", name)))
+        }
+        other => pair(other, other),
+    }
+}
+
+/// One buffer edit in pre-edit coordinates.
+struct Edit {
+    cursor_id: CursorId,
+    position: usize,
+    deleted: String,
+    inserted: String,
+}
+
+impl Edit {
+    fn delta(&self) -> isize {
+        self.inserted.len() as isize - self.deleted.len() as isize
+    }
+}
+
+/// Wrap each cursor's selection in the delimiters described by `spec`, or
+/// unwrap it when it is already surrounded by them (either just outside
+/// the selection or as its first and last characters). The selection stays
+/// on the wrapped content either way, so applying the same spec twice is a
+/// no-op. Cursors without a selection are left alone.
+pub(crate) fn surround_selections(
+    state: &mut EditorState,
+    cursors: &Cursors,
+    events: &mut Vec<Event>,
+    spec: &str,
+) {
+    let Some((open, close)) = surround_pair(spec) else {
+        return;
+    };
+    let buffer_len = state.buffer.len();
+
+    let mut edits = Vec::new();
+    // (cursor id, original cursor, content range in pre-edit coordinates)
+    let mut selections = Vec::new();
+    for (cursor_id, cursor) in cursors.iter() {
+        let Some(range) = cursor.selection_range().filter(|r| !r.is_empty()) else {
+            continue;
+        };
+        let (start, end) = (range.start, range.end);
+        let outside = start >= open.len()
+            && end + close.len() <= buffer_len
+            && state.get_text_range(start - open.len(), start) == open
+            && state.get_text_range(end, end + close.len()) == close;
+        let inside = !outside
+            && end - start >= open.len() + close.len()
+            && state.get_text_range(start, start + open.len()) == open
+            && state.get_text_range(end - close.len(), end) == close;
+
+        let (content, removals) = if outside {
+            (start..end, Some((start - open.len(), end)))
+        } else if inside {
+            (
+                start + open.len()..end - close.len(),
+                Some((start, end - close.len())),
+            )
+        } else {
+            (start..end, None)
+        };
+
+        match removals {
+            Some((open_at, close_at)) => {
+                edits.push(Edit {
+                    cursor_id,
+                    position: close_at,
+                    deleted: close.clone(),
+                    inserted: String::new(),
+                });
+                edits.push(Edit {
+                    cursor_id,
+                    position: open_at,
+                    deleted: open.clone(),
+                    inserted: String::new(),
+                });
+            }
+            None => {
+                edits.push(Edit {
+                    cursor_id,
+                    position: end,
+                    deleted: String::new(),
+                    inserted: close.clone(),
+                });
+                edits.push(Edit {
+                    cursor_id,
+                    position: start,
+                    deleted: String::new(),
+                    inserted: open.clone(),
+                });
+            }
+        }
+        selections.push((cursor_id, *cursor, content));
+    }
+
+    if edits.is_empty() {
+        return;
+    }
+
+    // Net shift of everything at `pos`, counting inserts exactly at `pos`
+    // only when they land before it (the opening delimiter of a content
+    // start)
+    let shift_before = |pos: usize, include_inserts_at: bool| -> isize {
+        edits
+            .iter()
+            .filter(|e| {
+                e.position < pos
+                    || (include_inserts_at && e.position == pos && e.deleted.is_empty())
+            })
+            .map(Edit::delta)
+            .sum()
+    };
+
+    for edit in &edits {
+        if edit.deleted.is_empty() {
+            events.push(Event::Insert {
+                position: edit.position,
+                text: edit.inserted.clone(),
+                cursor_id: edit.cursor_id,
+            });
+        } else {
+            events.push(Event::Delete {
+                range: edit.position..edit.position + edit.deleted.len(),
+                deleted_text: edit.deleted.clone(),
+                cursor_id: edit.cursor_id,
+            });
+        }
+    }
+
+    for (cursor_id, cursor, content) in selections {
+        let new_start = (content.start as isize + shift_before(content.start, true)) as usize;
+        let new_end = (content.end as isize + shift_before(content.end, false)) as usize;
+        let forward = cursor.anchor.is_none_or(|anchor| anchor <= cursor.position);
+        let (mut new_position, new_anchor) = if forward {
+            (new_end, new_start)
+        } else {
+            (new_start, new_end)
+        };
+
+        // The bulk edit re-applies the shift of earlier edits to cursors
+        // that inserted at their own position; hand it the unshifted value
+        let inserts_at_cursor = edits.iter().any(|e| {
+            e.cursor_id == cursor_id && e.deleted.is_empty() && e.position == cursor.position
+        });
+        if inserts_at_cursor {
+            new_position = (new_position as isize - shift_before(cursor.position, false)) as usize;
+        }
+
+        events.push(Event::MoveCursor {
+            cursor_id,
+            old_position: cursor.position,
+            new_position,
+            old_anchor: cursor.anchor,
+            new_anchor: Some(new_anchor),
+            old_sticky_column: cursor.sticky_column,
+            new_sticky_column: cursor.sticky_column,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pair(open: &str, close: &str) -> Option<(String, String)> {
+        Some((open.to_string(), close.to_string()))
+    }
+
+    #[test]
+    fn test_surround_pair() {
+        assert_eq!(surround_pair("("), pair("(", ")"));
+        assert_eq!(surround_pair("]"), pair("[", "]"));
+        assert_eq!(surround_pair("\""), pair("\"", "\""));
+        assert_eq!(surround_pair("**"), pair("**", "**"));
+        assert_eq!(surround_pair("<div>"), pair("<div>", "</div>"));
+        assert_eq!(
+            surround_pair("<a href=\"#\">"),
+            pair("<a href=\"#\">", "</a>")
+        );
+        assert_eq!(surround_pair("<>"), pair("<>", "<>"));
+        assert_eq!(surround_pair(""), None);
+    }
+}
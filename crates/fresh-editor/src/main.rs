@@ -86,6 +86,10 @@ struct Cli {
     #[arg(long, value_name = "LOG_FILE")]
     event_log: Option<PathBuf>,
 
+    /// Replay a recorded event log after startup
+    #[arg(long, value_name = "LOG_FILE")]
+    replay: Option<PathBuf>,
+
     /// Don't restore previous workspace (only hot-exit content — unsaved
     /// modified files and unnamed buffers with content — is still restored
     /// so in-progress work is not lost)
@@ -157,6 +161,7 @@ struct Args {
     config: Option<PathBuf>,
     log_file: Option<PathBuf>,
     event_log: Option<PathBuf>,
+    replay: Option<PathBuf>,
     no_session: bool,
     /// Force workspace restore even if `editor.restore_previous_session`
     /// is disabled in the config.
@@ -409,6 +414,7 @@ impl From<Cli> for Args {
             config: cli.config,
             log_file: cli.log_file,
             event_log: cli.event_log,
+            replay: cli.replay,
             no_session: cli.no_restore,
             force_restore: cli.restore,
             no_upgrade_check: cli.no_upgrade_check,
@@ -852,6 +858,11 @@ fn handle_first_run_setup(
         editor.schedule_hot_exit_recovery();
     }
 
+    // Replay after the CLI files open so the log's events land in them
+    if let Some(replay_path) = &args.replay {
+        editor.queue_event_replay(replay_path.clone());
+    }
+
     if show_file_explorer {
         editor.show_file_explorer();
     }
@@ -2462,53 +2473,28 @@ fn list_grammars_command() -> AnyhowResult<()> {
 
 fn list_sessions_command() -> AnyhowResult<()> {
     let socket_dir = SocketPaths::socket_directory()?;
-
-    if !socket_dir.exists() {
-        println!("No active sessions.");
-        return Ok(());
-    }
+    let (live, stale_cleaned) = SocketPaths::list_sessions_in_dir(&socket_dir)?;
 
     let mut sessions = Vec::new();
-    let mut stale_cleaned = 0;
-
-    for entry in std::fs::read_dir(&socket_dir)? {
-        let entry = entry?;
-        let path = entry.path();
-        let filename = path.file_name().and_then(|s| s.to_str()).unwrap_or("");
-
-        // Look for control sockets (*.ctrl.sock)
-        if let Some(name) = filename.strip_suffix(".ctrl.sock") {
-            // Get socket paths for this session to check if server is alive
-            let socket_paths = SocketPaths::for_session_name(name)?;
-
-            // Check if server is actually running, clean up if stale
-            if socket_paths.cleanup_if_stale() {
-                stale_cleaned += 1;
-                continue;
-            }
+    for (name, socket_paths) in &live {
+        // Ask the server for its working directory and client count; an
+        // older server can't answer, so fall back to the session name alone.
+        // A server that doesn't answer in time is reported as unresponsive
+        let (info, unresponsive) = match socket_paths.query_session_info() {
+            Ok(info) => (Some(info), false),
+            Err(e) => (None, e.kind() == io::ErrorKind::TimedOut),
+        };
 
-            // Only show sessions with running servers
-            if !socket_paths.is_server_alive() {
-                continue;
+        // Working-directory sessions are shown by their path. Only use the
+        // decoded name if it looks like a real absolute path (i.e., not just
+        // "/<name>" which happens with simple session names)
+        let display_name = match workspace::decode_filename_to_path(name) {
+            Some(decoded_path) if decoded_path.components().count() > 2 => {
+                decoded_path.display().to_string()
             }
-
-            // Try to decode the session name (for working-dir based sessions)
-            // Only show the decoded path if it looks like a real absolute path
-            let display_name = if let Some(decoded_path) = workspace::decode_filename_to_path(name)
-            {
-                // Only use decoded path if it has more than one component
-                // (i.e., not just "/<name>" which happens with simple session names)
-                if decoded_path.components().count() > 2 {
-                    decoded_path.display().to_string()
-                } else {
-                    name.to_string()
-                }
-            } else {
-                name.to_string()
-            };
-
-            sessions.push((name.to_string(), display_name));
-        }
+            _ => name.to_string(),
+        };
+        sessions.push((name.to_string(), display_name, info, unresponsive));
     }
 
     if stale_cleaned > 0 {
@@ -2519,19 +2505,29 @@ fn list_sessions_command() -> AnyhowResult<()> {
         println!("No active sessions.");
     } else {
         println!("Active sessions:");
-        for (id, display) in &sessions {
-            if display != id {
+        for (id, display, info, unresponsive) in &sessions {
+            let clients = match info {
+                Some(info) if info.client_count == 1 => Some("1 client".to_string()),
+                Some(info) => Some(format!("{} clients", info.client_count)),
+                None if *unresponsive => Some("not responding".to_string()),
+                None => None,
+            };
+            match (display != id, info, clients) {
                 // Working-directory session: show path and usable name
-                println!("  {}  (name: {})", display, id);
-            } else {
-                // Named session
-                println!("  {}", id);
+                (true, _, Some(clients)) => println!("  {}  (name: {}, {})", display, id, clients),
+                (true, _, None) => println!("  {}  (name: {})", display, id),
+                // Named session: show where it was started
+                (false, Some(info), Some(clients)) => {
+                    println!("  {}  ({}, {})", id, info.working_dir, clients)
+                }
+                (false, None, Some(status)) => println!("  {}  ({})", id, status),
+                (false, _, _) => println!("  {}", id),
             }
         }
         println!();
         // Show the most convenient attach form for each session type
         if sessions.len() == 1 {
-            let (id, display) = &sessions[0];
+            let (id, display, _, _) = &sessions[0];
             if display != id {
                 println!("Attach with: fresh -a  (from that directory)");
                 println!("         or: fresh -a {}", id);
@@ -2901,9 +2897,7 @@ fn run_attach_command(args: &Args) -> AnyhowResult<()> {
 
 fn run_attach(session_name: Option<&str>, files: &[String]) -> AnyhowResult<()> {
     use crossterm::terminal::enable_raw_mode;
-    use fresh::server::protocol::{
-        ClientControl, ClientHello, ServerControl, TermSize, PROTOCOL_VERSION,
-    };
+    use fresh::server::protocol::{ClientControl, TermSize};
     use fresh::server::spawn_server_detached;
 
     // Initialize tracing to a file for debugging
@@ -2956,70 +2950,36 @@ fn run_attach(session_name: Option<&str>, files: &[String]) -> AnyhowResult<()>
     // Wait for server to be ready - the PID file is the semantic signal
     // that the server has successfully bound and is ready to accept connections.
     if server_was_started {
-        use fresh::server::daemon::is_process_running;
-
-        // Wait for PID file to appear with a valid running PID
-        // This is the semantic condition: server writes PID after bind() succeeds
-        loop {
-            if let Ok(Some(pid)) = socket_paths.read_pid() {
-                if is_process_running(pid) {
-                    break; // Server is ready
-                }
-            }
-            // Yield to scheduler - we're waiting for an event (PID file creation),
-            // not delaying for time. The yield is just to avoid busy-spinning.
-            std::thread::yield_now();
-        }
-    }
-
-    // Now connect - server is ready
-    let conn = fresh::server::ipc::ClientConnection::connect(&socket_paths)?;
-
-    if server_was_started {
+        wait_for_server_ready(&socket_paths)?;
         eprintln!("Server started.");
     }
 
     let term_size = TermSize::new(cols, rows);
 
-    // Perform handshake
-    let hello = ClientHello::new(term_size);
-    let hello_json = serde_json::to_string(&ClientControl::Hello(hello))?;
-    conn.write_control(&hello_json)?;
-
-    // Read server response
-    let response = conn
-        .read_control()?
-        .ok_or_else(|| anyhow::anyhow!("Server closed connection during handshake"))?;
-
-    let server_msg: ServerControl = serde_json::from_str(&response)?;
-
-    match server_msg {
-        ServerControl::Hello(server_hello) => {
-            if server_hello.protocol_version != PROTOCOL_VERSION {
-                eprintln!(
-                    "Version mismatch: server is v{}",
-                    server_hello.server_version
-                );
-                eprintln!("Please restart the server with the same version as the client.");
-                return Ok(());
-            }
+    // Connect and handshake. A server from an incompatible build that asks
+    // to be restarted is replaced by one running this binary, once.
+    let (conn, handshake) =
+        client::connect_with_restart(&socket_paths, term_size, confirm_server_restart, || {
+            eprintln!("Restarting the session server...");
+            socket_paths.stop_server()?;
+            spawn_server_detached(session_name, ssh_url.as_deref())?;
+            wait_for_server_ready(&socket_paths)?;
+            eprintln!("Server restarted.");
+            Ok(())
+        })?;
+
+    match handshake {
+        client::Handshake::Connected(server_hello) => {
             tracing::info!(
                 "Connected to session '{}' (server {})",
                 server_hello.session_id,
                 server_hello.server_version
             );
         }
-        ServerControl::VersionMismatch(mismatch) => {
-            eprintln!("Version mismatch: server is v{}", mismatch.server_version);
-            eprintln!("Please restart the server with the same version as the client.");
+        client::Handshake::Mismatch(mismatch) => {
+            print_version_mismatch(&mismatch);
             return Ok(());
         }
-        ServerControl::Error { message } => {
-            return Err(anyhow::anyhow!("Server error: {}", message));
-        }
-        _ => {
-            return Err(anyhow::anyhow!("Unexpected server response"));
-        }
     }
 
     // Send file open requests if any files were specified on the
@@ -3100,6 +3060,94 @@ fn run_attach(session_name: Option<&str>, files: &[String]) -> AnyhowResult<()>
     Ok(())
 }
 
+/// How long to wait for a freshly spawned server to come up
+const SERVER_START_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+
+/// Block until the session's server has written its PID file and is running,
+/// failing if that doesn't happen within `SERVER_START_TIMEOUT`
+fn wait_for_server_ready(socket_paths: &SocketPaths) -> std::io::Result<()> {
+    use fresh::server::daemon::is_process_running;
+
+    let deadline = std::time::Instant::now() + SERVER_START_TIMEOUT;
+    // Wait for PID file to appear with a valid running PID
+    // This is the semantic condition: server writes PID after bind() succeeds
+    loop {
+        if let Ok(Some(pid)) = socket_paths.read_pid() {
+            if is_process_running(pid) {
+                return Ok(()); // Server is ready
+            }
+        }
+        if std::time::Instant::now() >= deadline {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::TimedOut,
+                format!(
+                    "session server did not start within {}s",
+                    SERVER_START_TIMEOUT.as_secs()
+                ),
+            ));
+        }
+        // Yield to scheduler - we're waiting for an event (PID file creation),
+        // not delaying for time. The yield is just to avoid busy-spinning.
+        std::thread::yield_now();
+    }
+}
+
+/// Decide whether an incompatible session server may be stopped and
+/// replaced.
+///
+/// Stopping the server discards its unsaved buffers and disconnects its
+/// other clients, so when the server reports either the user is asked, or
+/// the restart is refused when stdin is not a terminal. A server that
+/// can't report its state is restarted without asking.
+fn confirm_server_restart(
+    mismatch: &fresh::server::protocol::VersionMismatch,
+    info: Option<&fresh::server::protocol::SessionInfo>,
+) -> bool {
+    use std::io::{BufRead, IsTerminal, Write};
+
+    let losses = client::restart_losses(info);
+    if losses.is_empty() {
+        return true;
+    }
+
+    eprintln!(
+        "Session server v{} is incompatible with this client (v{}) and must be restarted,",
+        mismatch.server_version, mismatch.client_version
+    );
+    eprintln!("but it has {}.", losses.join(" and "));
+    if !std::io::stdin().is_terminal() {
+        return false;
+    }
+    eprint!("Restart it anyway and lose them? [y/N] ");
+    // Best-effort: the prompt is still answerable if the flush fails
+    #[allow(clippy::let_underscore_must_use)]
+    let _ = std::io::stderr().flush();
+    let mut answer = String::new();
+    if std::io::stdin().lock().read_line(&mut answer).is_err() {
+        return false;
+    }
+    matches!(answer.trim().to_ascii_lowercase().as_str(), "y" | "yes")
+}
+
+/// Explain a version mismatch the client could not resolve by restarting
+fn print_version_mismatch(mismatch: &fresh::server::protocol::VersionMismatch) {
+    eprintln!(
+        "Version mismatch: session server is v{}, this client is v{}.",
+        mismatch.server_version, mismatch.client_version
+    );
+    if mismatch.action == "upgrade_server" {
+        eprintln!("The server is older than this client and must be upgraded.");
+        eprintln!(
+            "Stop it with `fresh --cmd session kill`, then attach again to start a new server."
+        );
+    } else {
+        eprintln!("The server was not restarted.");
+        eprintln!(
+            "Save your work in the session, then stop it with `fresh --cmd session kill` and attach again."
+        );
+    }
+}
+
 /// Print deprecation warnings for old CLI flags
 fn print_deprecation_warnings(cli: &Cli) {
     // Only print warnings if no --cmd is used (i.e., using deprecated flags directly)
@@ -3429,6 +3477,7 @@ fn build_localized_cli_command() -> clap::Command {
         .mut_arg("config", |a| a.help(t("cli.arg.config")))
         .mut_arg("log_file", |a| a.help(t("cli.arg.log_file")))
         .mut_arg("event_log", |a| a.help(t("cli.arg.event_log")))
+        .mut_arg("replay", |a| a.help(t("cli.arg.replay")))
         .mut_arg("no_restore", |a| a.help(t("cli.arg.no_restore")))
         .mut_arg("restore", |a| a.help(t("cli.arg.restore")))
         .mut_arg("no_upgrade_check", |a| {
@@ -4176,12 +4225,8 @@ fn handle_key_event(editor: &mut Editor, key_event: KeyEvent) -> AnyhowResult<()
         key_event.state
     );
 
-    // Log the keystroke
-    let key_code = format!("{:?}", key_event.code);
-    let modifiers = format!("{:?}", key_event.modifiers);
-    editor.log_keystroke(&key_code, &modifiers);
-
-    // Delegate to the editor's handle_key method
+    // Delegate to the editor's handle_key method (which also records the
+    // keystroke to the event stream)
     editor.handle_key(key_event.code, key_event.modifiers)?;
 
     Ok(())
@@ -50,6 +50,9 @@ impl LineEnding {
 pub struct BufferFormat {
     line_ending: LineEnding,
     original_line_ending: LineEnding,
+    /// The content mixed several line ending styles when it was detected.
+    /// Cleared once the buffer has been normalized.
+    mixed_line_endings: bool,
     encoding: Encoding,
     original_encoding: Encoding,
 }
@@ -59,15 +62,32 @@ impl BufferFormat {
         Self {
             line_ending,
             original_line_ending: line_ending,
+            mixed_line_endings: false,
             encoding,
             original_encoding: encoding,
         }
     }
 
+    /// Detect the line ending (and whether it is mixed) from content bytes.
+    pub fn detect(bytes: &[u8], encoding: Encoding) -> Self {
+        Self {
+            mixed_line_endings: detect_mixed_line_endings(bytes),
+            ..Self::new(detect_line_ending(bytes), encoding)
+        }
+    }
+
     pub fn line_ending(&self) -> LineEnding {
         self.line_ending
     }
 
+    pub fn mixed_line_endings(&self) -> bool {
+        self.mixed_line_endings
+    }
+
+    pub fn set_mixed_line_endings(&mut self, mixed: bool) {
+        self.mixed_line_endings = mixed;
+    }
+
     pub fn encoding(&self) -> Encoding {
         self.encoding
     }
@@ -109,6 +129,10 @@ impl BufferFormat {
     /// Called after a successful save to make the current values the
     /// new "original" baseline.
     pub(super) fn promote_current_to_original(&mut self) {
+        // A changed line ending means the save rewrote every line break
+        if self.line_ending_changed_since_load() {
+            self.mixed_line_endings = false;
+        }
         self.original_line_ending = self.line_ending;
         self.original_encoding = self.encoding;
     }
@@ -121,6 +145,33 @@ impl BufferFormat {
 /// Uses majority voting: counts CRLF, LF-only, and CR-only
 /// occurrences and returns the most common format.
 pub fn detect_line_ending(bytes: &[u8]) -> LineEnding {
+    let (crlf_count, lf_only_count, cr_only_count) = count_line_endings(bytes);
+
+    // Use majority voting to determine line ending
+    if crlf_count > lf_only_count && crlf_count > cr_only_count {
+        LineEnding::CRLF
+    } else if cr_only_count > lf_only_count && cr_only_count > crlf_count {
+        LineEnding::CR
+    } else {
+        // Default to LF if no clear winner or if LF wins
+        LineEnding::LF
+    }
+}
+
+/// Check whether a sample of bytes uses more than one line ending style.
+///
+/// Looks at the same sample as `detect_line_ending`.
+pub fn detect_mixed_line_endings(bytes: &[u8]) -> bool {
+    let (crlf_count, lf_only_count, cr_only_count) = count_line_endings(bytes);
+    [crlf_count, lf_only_count, cr_only_count]
+        .iter()
+        .filter(|&&count| count > 0)
+        .count()
+        > 1
+}
+
+/// Count `(CRLF, LF-only, CR-only)` line breaks in the first 8KB of `bytes`.
+fn count_line_endings(bytes: &[u8]) -> (usize, usize, usize) {
     // Only check the first 8KB for line ending detection (same as binary detection)
     let check_len = bytes.len().min(8 * 1024);
     let sample = &bytes[..check_len];
@@ -148,15 +199,7 @@ pub fn detect_line_ending(bytes: &[u8]) -> LineEnding {
         i += 1;
     }
 
-    // Use majority voting to determine line ending
-    if crlf_count > lf_only_count && crlf_count > cr_only_count {
-        LineEnding::CRLF
-    } else if cr_only_count > lf_only_count && cr_only_count > crlf_count {
-        LineEnding::CR
-    } else {
-        // Default to LF if no clear winner or if LF wins
-        LineEnding::LF
-    }
+    (crlf_count, lf_only_count, cr_only_count)
 }
 
 /// Detect the text encoding from a sample of bytes.
@@ -258,7 +258,7 @@ impl TextBuffer {
         let bytes = content.len();
 
         // For binary files, detect line ending but don't convert encoding
-        let line_ending = format::detect_line_ending(&content);
+        let format = BufferFormat::detect(&content, Encoding::Utf8);
 
         // Create initial StringBuffer with ID 0
         let buffer = StringBuffer::new(0, content);
@@ -278,7 +278,7 @@ impl TextBuffer {
             next_buffer_id: 1,
             persistence: Persistence::new(fs, None, saved_root, Some(bytes)),
             file_kind: BufferFileKind::new(false, true),
-            format: BufferFormat::new(line_ending, Encoding::Utf8),
+            format,
             version: 0,
             config: BufferConfig::default(),
         }
@@ -292,7 +292,7 @@ impl TextBuffer {
         let bytes = utf8_content.len();
 
         // Auto-detect line ending format from content
-        let line_ending = format::detect_line_ending(&utf8_content);
+        let format = BufferFormat::detect(&utf8_content, encoding);
 
         // Create initial StringBuffer with ID 0
         let buffer = StringBuffer::new(0, utf8_content);
@@ -312,7 +312,7 @@ impl TextBuffer {
             next_buffer_id: 1,
             persistence: Persistence::new(fs, None, saved_root, Some(bytes)),
             file_kind: BufferFileKind::new(false, false),
-            format: BufferFormat::new(line_ending, encoding),
+            format,
             version: 0,
             config: BufferConfig::default(),
         }
@@ -330,7 +330,7 @@ impl TextBuffer {
         let bytes = utf8_content.len();
 
         // Auto-detect line ending format from content
-        let line_ending = format::detect_line_ending(&utf8_content);
+        let format = BufferFormat::detect(&utf8_content, encoding);
 
         // Create initial StringBuffer with ID 0
         let buffer = StringBuffer::new(0, utf8_content);
@@ -350,7 +350,7 @@ impl TextBuffer {
             next_buffer_id: 1,
             persistence: Persistence::new(fs, None, saved_root, Some(bytes)),
             file_kind: BufferFileKind::new(false, false),
-            format: BufferFormat::new(line_ending, encoding),
+            format,
             version: 0,
             config: BufferConfig::default(),
         }
@@ -582,7 +582,7 @@ impl TextBuffer {
         }
 
         // UTF-8/ASCII files can use lazy loading
-        let line_ending = format::detect_line_ending(&sample);
+        let format = BufferFormat::detect(&sample, encoding);
 
         // Create an unloaded buffer that references the entire file
         let buffer = StringBuffer {
@@ -621,7 +621,7 @@ impl TextBuffer {
                 Some(file_size),
             ),
             file_kind: BufferFileKind::new(true, is_binary),
-            format: BufferFormat::new(line_ending, encoding),
+            format,
             version: 0,
             config: BufferConfig::default(),
         })
@@ -2324,6 +2324,19 @@ impl TextBuffer {
         self.format.line_ending()
     }
 
+    /// Whether the content mixed several line ending styles when loaded.
+    ///
+    /// `line_ending()` is then only the dominant style; new lines use it, but
+    /// the other styles stay on disk until the buffer is normalized.
+    pub fn has_mixed_line_endings(&self) -> bool {
+        self.format.mixed_line_endings()
+    }
+
+    /// Record whether the content mixes line ending styles.
+    pub fn set_mixed_line_endings(&mut self, mixed: bool) {
+        self.format.set_mixed_line_endings(mixed);
+    }
+
     /// Set the line ending format for this buffer
     ///
     /// This marks the buffer as modified since the line ending format has changed.
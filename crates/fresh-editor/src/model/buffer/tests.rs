@@ -1139,6 +1139,19 @@ fn test_detect_lf() {
     );
 }
 
+#[test]
+fn test_detect_mixed_line_endings() {
+    assert!(super::format::detect_mixed_line_endings(
+        b"one\r\ntwo\nthree\r\n"
+    ));
+    assert!(!super::format::detect_mixed_line_endings(b"one\r\ntwo\r\n"));
+    assert!(!super::format::detect_mixed_line_endings(b"no newline"));
+
+    let buffer = TextBuffer::from_bytes(b"a\r\nb\r\nc\n".to_vec(), test_fs());
+    assert_eq!(buffer.line_ending(), LineEnding::CRLF);
+    assert!(buffer.has_mixed_line_endings());
+}
+
 #[test]
 fn test_normalize_crlf() {
     let input = b"hello\r\nworld\r\n".to_vec();
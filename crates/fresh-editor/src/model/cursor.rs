@@ -231,6 +231,16 @@ impl Cursors {
         self.primary_id
     }
 
+    /// Make an existing cursor the primary one. Returns false (and changes
+    /// nothing) if there is no cursor with that ID.
+    pub fn set_primary(&mut self, id: CursorId) -> bool {
+        if !self.cursors.contains_key(&id) {
+            return false;
+        }
+        self.primary_id = id;
+        true
+    }
+
     /// Get a cursor by ID
     pub fn get(&self, id: CursorId) -> Option<&Cursor> {
         self.cursors.get(&id)
@@ -397,6 +407,18 @@ mod tests {
         assert_eq!(cursor.selection_range(), Some(5..10));
     }
 
+    #[test]
+    fn test_cursors_set_primary() {
+        let mut cursors = Cursors::new();
+        let second = cursors.add(Cursor::new(10));
+        assert_eq!(cursors.primary_id(), second);
+
+        assert!(cursors.set_primary(CursorId(0)));
+        assert_eq!(cursors.primary().position, 0);
+        assert!(!cursors.set_primary(CursorId(99)));
+        assert_eq!(cursors.primary_id(), CursorId(0));
+    }
+
     #[test]
     fn test_cursor_move_to() {
         let mut cursor = Cursor::new(5);
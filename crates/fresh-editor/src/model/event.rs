@@ -1,4 +1,5 @@
 use crate::model::buffer::BufferSnapshot;
+use crate::model::event_stream::{EventStreamSink, RecordKind};
 pub use fresh_core::api::{OverlayColorSpec, OverlayOptions};
 pub use fresh_core::overlay::{OverlayHandle, OverlayNamespace};
 pub use fresh_core::{BufferId, ContainerId, CursorId, LeafId, SplitDirection, SplitId};
@@ -509,6 +510,41 @@ impl Event {
         }
     }
 
+    /// Approximate heap bytes owned by this event (text payloads, nested
+    /// events, cursor lists). `BulkEdit` snapshots are shared `Arc` trees and
+    /// are not counted.
+    pub fn heap_size(&self) -> usize {
+        match self {
+            Self::Insert { text, .. } => text.len(),
+            Self::Delete { deleted_text, .. } => deleted_text.len(),
+            Self::Batch {
+                events,
+                description,
+            } => {
+                description.len()
+                    + events
+                        .iter()
+                        .map(|e| std::mem::size_of::<Event>() + e.heap_size())
+                        .sum::<usize>()
+            }
+            Self::BulkEdit {
+                old_cursors,
+                new_cursors,
+                description,
+                edits,
+                displaced_markers,
+                ..
+            } => {
+                description.len()
+                    + (old_cursors.len() + new_cursors.len())
+                        * std::mem::size_of::<(CursorId, usize, Option<usize>)>()
+                    + edits.len() * std::mem::size_of::<(usize, usize, usize)>()
+                    + displaced_markers.len() * std::mem::size_of::<(u64, usize)>()
+            }
+            _ => 0,
+        }
+    }
+
     /// Returns true if this event is a write action (modifies state in a way that should be undoable)
     /// Returns false for readonly actions like cursor movement, scrolling, viewport changes, etc.
     ///
@@ -587,6 +623,14 @@ impl LogEntry {
         self.description = Some(description);
         self
     }
+
+    /// Approximate memory held by this entry, including the entry itself.
+    pub fn memory_usage(&self) -> usize {
+        std::mem::size_of::<Self>()
+            + self.event.heap_size()
+            + self.description.as_ref().map_or(0, |d| d.len())
+            + self.displaced_markers.len() * std::mem::size_of::<(u64, usize)>()
+    }
 }
 
 /// Snapshot of editor state for fast undo/redo
@@ -617,13 +661,26 @@ pub struct EventLog {
     /// How often to create snapshots (every N events)
     snapshot_interval: usize,
 
-    /// Optional file for streaming events to disk (runtime only)
-    #[cfg(feature = "runtime")]
-    stream_file: Option<std::fs::File>,
+    /// Event stream this log's events are also written to
+    stream: Option<EventStreamSink>,
 
     /// Index at which the buffer was last saved (for tracking modified status)
     /// When current_index equals saved_at_index, the buffer is not modified
     saved_at_index: Option<usize>,
+
+    /// Maximum number of undo groups kept (0 = unlimited)
+    max_undo_groups: usize,
+
+    /// Maximum approximate bytes of history kept (0 = unlimited)
+    max_undo_bytes: usize,
+
+    /// Running total of `LogEntry::memory_usage` over `entries`
+    entries_bytes: usize,
+
+    /// Bumped whenever the log changes (append, fold, undo, redo, clear).
+    /// Unlike `current_index` it never goes back, including when old
+    /// history is trimmed, so it identifies a state of the log.
+    change_seq: u64,
 }
 
 impl EventLog {
@@ -634,12 +691,43 @@ impl EventLog {
             current_index: 0,
             snapshots: Vec::new(),
             snapshot_interval: 100,
-            #[cfg(feature = "runtime")]
-            stream_file: None,
+            stream: None,
             saved_at_index: Some(0), // New buffer starts at "saved" state (index 0)
+            max_undo_groups: 0,
+            max_undo_bytes: 0,
+            entries_bytes: 0,
+            change_seq: 0,
         }
     }
 
+    /// Create a new empty event log whose undo history is capped at
+    /// `max_groups` undo groups and roughly `max_bytes` bytes (0 = unlimited).
+    pub fn with_limits(max_groups: usize, max_bytes: usize) -> Self {
+        let mut log = Self::new();
+        log.set_limits(max_groups, max_bytes);
+        log
+    }
+
+    /// Change the undo history limits (0 = unlimited). Takes effect on the
+    /// next write action.
+    pub fn set_limits(&mut self, max_groups: usize, max_bytes: usize) {
+        self.max_undo_groups = max_groups;
+        self.max_undo_bytes = max_bytes;
+    }
+
+    /// Approximate memory held by the logged events, in bytes.
+    pub fn memory_usage(&self) -> usize {
+        self.entries_bytes
+    }
+
+    /// Number of undo groups (write actions) in the log.
+    pub fn undo_group_count(&self) -> usize {
+        self.entries
+            .iter()
+            .filter(|e| e.event.is_write_action())
+            .count()
+    }
+
     /// Mark the current position as the saved point
     /// Call this when the buffer is saved to disk
     pub fn mark_saved(&mut self) {
@@ -677,35 +765,13 @@ impl EventLog {
         }
     }
 
-    /// Enable streaming events to a file (runtime only)
-    #[cfg(feature = "runtime")]
-    pub fn enable_streaming<P: AsRef<std::path::Path>>(&mut self, path: P) -> std::io::Result<()> {
-        use std::io::Write;
-
-        let mut file = std::fs::OpenOptions::new()
-            .create(true)
-            .write(true)
-            .truncate(true)
-            .open(path)?;
-
-        // Write header
-        writeln!(file, "# Event Log Stream")?;
-        writeln!(file, "# Started at: {}", chrono::Local::now())?;
-        writeln!(file, "# Format: JSON Lines (one event per line)")?;
-        writeln!(file, "#")?;
-
-        self.stream_file = Some(file);
-        Ok(())
+    /// Write this log's events, and its buffer's render state, to an
+    /// event stream as well (`None` stops streaming).
+    pub fn set_stream(&mut self, stream: Option<EventStreamSink>) {
+        self.stream = stream;
     }
 
-    /// Disable streaming (runtime only)
-    #[cfg(feature = "runtime")]
-    pub fn disable_streaming(&mut self) {
-        self.stream_file = None;
-    }
-
-    /// Log rendering state (for debugging, runtime only)
-    #[cfg(feature = "runtime")]
+    /// Log rendering state (for debugging)
     pub fn log_render_state(
         &mut self,
         cursor_pos: usize,
@@ -713,45 +779,12 @@ impl EventLog {
         screen_cursor_y: u16,
         buffer_len: usize,
     ) {
-        if let Some(ref mut file) = self.stream_file {
-            use std::io::Write;
-
-            let render_info = serde_json::json!({
-                "type": "render",
-                "timestamp": chrono::Local::now().to_rfc3339(),
-                "cursor_position": cursor_pos,
-                "screen_cursor": {"x": screen_cursor_x, "y": screen_cursor_y},
-                "buffer_length": buffer_len,
-            });
-
-            if let Err(e) = writeln!(file, "{render_info}") {
-                tracing::trace!("Warning: Failed to write render info to stream: {e}");
-            }
-            if let Err(e) = file.flush() {
-                tracing::trace!("Warning: Failed to flush event stream: {e}");
-            }
-        }
-    }
-
-    /// Log keystroke (for debugging, runtime only)
-    #[cfg(feature = "runtime")]
-    pub fn log_keystroke(&mut self, key_code: &str, modifiers: &str) {
-        if let Some(ref mut file) = self.stream_file {
-            use std::io::Write;
-
-            let keystroke_info = serde_json::json!({
-                "type": "keystroke",
-                "timestamp": chrono::Local::now().to_rfc3339(),
-                "key": key_code,
-                "modifiers": modifiers,
+        if let Some(stream) = &self.stream {
+            stream.record(RecordKind::Render {
+                cursor_position: cursor_pos,
+                screen_cursor: (screen_cursor_x, screen_cursor_y),
+                buffer_length: buffer_len,
             });
-
-            if let Err(e) = writeln!(file, "{keystroke_info}") {
-                tracing::trace!("Warning: Failed to write keystroke to stream: {e}");
-            }
-            if let Err(e) = file.flush() {
-                tracing::trace!("Warning: Failed to flush event stream: {e}");
-            }
         }
     }
 
@@ -765,6 +798,10 @@ impl EventLog {
         if self.current_index < self.entries.len() {
             if event.is_write_action() {
                 // Write action: truncate redo history and log normally
+                self.entries_bytes -= self.entries[self.current_index..]
+                    .iter()
+                    .map(LogEntry::memory_usage)
+                    .sum::<usize>();
                 self.entries.truncate(self.current_index);
 
                 // Invalidate saved_at_index if it pointed to a truncated entry
@@ -779,29 +816,22 @@ impl EventLog {
             }
         }
 
-        // Stream event to file if enabled (runtime only)
-        #[cfg(feature = "runtime")]
-        if let Some(ref mut file) = self.stream_file {
-            use std::io::Write;
-
-            let stream_entry = serde_json::json!({
-                "index": self.entries.len(),
-                "timestamp": chrono::Local::now().to_rfc3339(),
-                "event": event,
+        if let Some(stream) = &self.stream {
+            stream.record(RecordKind::Event {
+                event: event.clone(),
             });
-
-            // Write JSON line and flush immediately for real-time logging
-            if let Err(e) = writeln!(file, "{stream_entry}") {
-                tracing::trace!("Warning: Failed to write to event stream: {e}");
-            }
-            if let Err(e) = file.flush() {
-                tracing::trace!("Warning: Failed to flush event stream: {e}");
-            }
         }
 
+        let is_write = event.is_write_action();
         let entry = LogEntry::new(event);
+        self.entries_bytes += entry.memory_usage();
         self.entries.push(entry);
         self.current_index = self.entries.len();
+        self.change_seq += 1;
+
+        if is_write {
+            self.trim_to_limits();
+        }
 
         // Check if we should create a snapshot
         if self.entries.len().is_multiple_of(self.snapshot_interval) {
@@ -812,12 +842,125 @@ impl EventLog {
         self.current_index - 1
     }
 
+    /// Drop the oldest undo groups while the history exceeds its limits.
+    ///
+    /// A group is a write action plus the readonly events logged after it,
+    /// matching what one `undo()` step consumes. Only entries before
+    /// `current_index` are dropped and the most recent group is always kept,
+    /// so redo history and the latest undo step survive. Undo applies
+    /// inverses backwards from the current buffer state, so forgetting the
+    /// oldest entries leaves the remaining ones consistent with it.
+    fn trim_to_limits(&mut self) {
+        if self.max_undo_groups == 0 && self.max_undo_bytes == 0 {
+            return;
+        }
+
+        let is_write = |e: &LogEntry| e.event.is_write_action();
+        let mut groups = self.entries[..self.current_index]
+            .iter()
+            .filter(|e| is_write(e))
+            .count();
+        let mut bytes = self.entries_bytes;
+        let over = |groups: usize, bytes: usize| {
+            (self.max_undo_groups > 0 && groups > self.max_undo_groups)
+                || (self.max_undo_bytes > 0 && bytes > self.max_undo_bytes)
+        };
+
+        let mut drop = 0;
+        while groups > 1 && over(groups, bytes) {
+            // The oldest group ends where the second write action begins
+            let Some(first) = self.entries[drop..self.current_index]
+                .iter()
+                .position(is_write)
+                .map(|i| drop + i)
+            else {
+                break;
+            };
+            let Some(end) = self.entries[first + 1..self.current_index]
+                .iter()
+                .position(is_write)
+                .map(|i| first + 1 + i)
+            else {
+                break;
+            };
+            bytes -= self.entries[drop..end]
+                .iter()
+                .map(LogEntry::memory_usage)
+                .sum::<usize>();
+            groups -= 1;
+            drop = end;
+        }
+
+        if drop == 0 {
+            return;
+        }
+
+        self.entries.drain(..drop);
+        self.entries_bytes = bytes;
+        self.current_index -= drop;
+        // A save point inside the dropped history can no longer be reached
+        self.saved_at_index = self.saved_at_index.and_then(|i| i.checked_sub(drop));
+        self.snapshots.retain(|s| s.log_index >= drop);
+        for snapshot in &mut self.snapshots {
+            snapshot.log_index -= drop;
+        }
+    }
+
+    /// Fold `event` into the most recent undo group, so a single undo
+    /// reverts it together with the write action that started the group.
+    ///
+    /// Returns false without logging anything when there is no group to
+    /// join, when redo history exists, or when the buffer was saved after
+    /// the group's write action; callers then `append` the event instead.
+    pub fn fold_into_last_write(&mut self, event: Event) -> bool {
+        if self.current_index < self.entries.len() {
+            return false;
+        }
+        let Some(index) = self.entries.iter().rposition(|e| e.event.is_write_action()) else {
+            return false;
+        };
+        if self.saved_at_index.is_some_and(|saved| saved > index) {
+            return false;
+        }
+
+        if let Some(stream) = &self.stream {
+            stream.record(RecordKind::Event {
+                event: event.clone(),
+            });
+        }
+
+        self.change_seq += 1;
+        let entry = &mut self.entries[index];
+        self.entries_bytes -= entry.memory_usage();
+        let previous = std::mem::replace(&mut entry.event, Event::Recenter);
+        entry.event = match previous {
+            Event::Batch {
+                mut events,
+                description,
+            } => {
+                events.push(event);
+                Event::Batch {
+                    events,
+                    description,
+                }
+            }
+            previous => Event::Batch {
+                events: vec![previous, event],
+                description: entry.description.clone().unwrap_or_default(),
+            },
+        };
+        self.entries_bytes += entry.memory_usage();
+        true
+    }
+
     /// Set displaced markers on the last appended entry.
     /// Call this right after `append()` to record markers that were inside
     /// the deleted range, so undo can restore them to exact positions.
     pub fn set_displaced_markers_on_last(&mut self, markers: Vec<(u64, usize)>) {
         if let Some(entry) = self.entries.last_mut() {
+            self.entries_bytes -= entry.memory_usage();
             entry.displaced_markers = markers;
+            self.entries_bytes += entry.memory_usage();
         }
     }
 
@@ -826,6 +969,18 @@ impl EventLog {
         self.current_index
     }
 
+    /// Counter that advances on every change to the log. Two equal values
+    /// mean nothing was logged, undone or redone in between, even when
+    /// trimming kept `current_index` the same.
+    pub fn change_seq(&self) -> u64 {
+        self.change_seq
+    }
+
+    /// Index the buffer was last saved at, if the log still reaches it
+    pub fn saved_at_index(&self) -> Option<usize> {
+        self.saved_at_index
+    }
+
     /// Get the number of events in the log
     pub fn len(&self) -> usize {
         self.entries.len()
@@ -858,6 +1013,7 @@ impl EventLog {
         // Keep moving backward until we find a write action
         while self.can_undo() && !found_write_action {
             self.current_index -= 1;
+            self.change_seq += 1;
             let entry = &self.entries[self.current_index];
 
             // Check if this is a write action - we'll stop after processing it
@@ -893,6 +1049,7 @@ impl EventLog {
             }
 
             self.current_index += 1;
+            self.change_seq += 1;
 
             // Mark if we found a write action
             if event.is_write_action() {
@@ -929,6 +1086,8 @@ impl EventLog {
         self.entries.clear();
         self.current_index = 0;
         self.snapshots.clear();
+        self.entries_bytes = 0;
+        self.change_seq += 1;
     }
 
     /// Save event log to JSON Lines format
@@ -959,6 +1118,7 @@ impl EventLog {
                 continue;
             }
             let entry: LogEntry = serde_json::from_str(&line)?;
+            log.entries_bytes += entry.memory_usage();
             log.entries.push(entry);
         }
 
@@ -1326,4 +1486,148 @@ mod tests {
             "Should not be at saved position after undo + new edit"
         );
     }
+
+    fn insert(text: &str) -> Event {
+        Event::Insert {
+            position: 0,
+            text: text.to_string(),
+            cursor_id: CursorId(0),
+        }
+    }
+
+    #[test]
+    fn test_group_limit_trims_oldest_group() {
+        let mut log = EventLog::with_limits(3, 0);
+        for text in ["a", "b", "c"] {
+            log.append(insert(text));
+            log.append(Event::Scroll { line_offset: 1 });
+        }
+        assert_eq!(log.undo_group_count(), 3);
+
+        log.append(insert("d"));
+        assert_eq!(log.undo_group_count(), 3);
+        // "a" and the scroll logged after it are gone
+        assert!(matches!(&log.entries()[0].event, Event::Insert { text, .. } if text == "b"));
+
+        // The three retained groups undo newest-first, then history ends
+        for expected in ["d", "c", "b"] {
+            let undone = log.undo();
+            assert!(
+                undone
+                    .iter()
+                    .any(|(e, _)| matches!(e, Event::Delete { deleted_text, .. } if deleted_text == expected)),
+                "expected to undo {expected}"
+            );
+        }
+        assert!(!log.can_undo());
+
+        // Redo still replays everything that was retained
+        for _ in 0..3 {
+            assert!(!log.redo().is_empty());
+        }
+        assert!(!log.can_redo());
+    }
+
+    #[test]
+    fn test_byte_limit_keeps_latest_group_and_saved_point() {
+        let big = "x".repeat(1000);
+        let mut log = EventLog::with_limits(0, 1500);
+
+        log.append(insert(&big));
+        log.mark_saved();
+        assert!(log.memory_usage() >= 1000);
+
+        // The second large insert pushes the log over budget and the first
+        // group goes; the save point sat right after it and is still reachable
+        log.append(insert(&big));
+        assert_eq!(log.undo_group_count(), 1);
+        assert!(log.memory_usage() < 1500);
+        assert!(!log.is_at_saved_position());
+        log.undo();
+        assert!(log.is_at_saved_position());
+        log.redo();
+
+        // A single group larger than the budget is still kept
+        log.append(insert(&"y".repeat(4000)));
+        assert_eq!(log.undo_group_count(), 1);
+        assert!(log.can_undo());
+    }
+
+    #[test]
+    fn test_fold_into_last_write_joins_its_undo_group() {
+        let mut log = EventLog::new();
+        log.append(insert("a"));
+        log.append(insert("b"));
+        log.append(Event::Scroll { line_offset: 1 });
+
+        assert!(log.fold_into_last_write(Event::Delete {
+            range: 0..1,
+            deleted_text: "b".to_string(),
+            cursor_id: CursorId::UNDO_SENTINEL,
+        }));
+        assert_eq!(log.undo_group_count(), 2);
+
+        // One undo reverts the folded delete before the insert it joined
+        let undone: Vec<Event> = log.undo().into_iter().map(|(e, _)| e).collect();
+        let Some(Event::Batch { events, .. }) = undone.last() else {
+            panic!("expected a batch, got {:?}", undone);
+        };
+        assert!(matches!(&events[0], Event::Insert { text, .. } if text == "b"));
+        assert!(matches!(&events[1], Event::Delete { deleted_text, .. } if deleted_text == "b"));
+        assert_eq!(log.current_index(), 1);
+    }
+
+    #[test]
+    fn test_fold_into_last_write_refuses_after_save_or_undo() {
+        let mut log = EventLog::new();
+        let delete = || Event::Delete {
+            range: 0..1,
+            deleted_text: "a".to_string(),
+            cursor_id: CursorId::UNDO_SENTINEL,
+        };
+        assert!(!log.fold_into_last_write(delete()));
+
+        log.append(insert("a"));
+        log.mark_saved();
+        assert!(!log.fold_into_last_write(delete()));
+
+        log.append(insert("b"));
+        log.undo();
+        assert!(!log.fold_into_last_write(delete()));
+        assert_eq!(log.len(), 2);
+    }
+
+    #[test]
+    fn test_change_seq_advances_when_trimming_holds_index() {
+        let mut log = EventLog::with_limits(2, 0);
+        log.append(insert("a"));
+        log.append(insert("b"));
+
+        // At the cap each append drops the oldest group, so the index stays
+        // put while the log clearly changed
+        let (index, seq) = (log.current_index(), log.change_seq());
+        log.append(insert("c"));
+        assert_eq!(log.current_index(), index);
+        assert_ne!(log.change_seq(), seq);
+
+        let seq = log.change_seq();
+        log.undo();
+        assert_ne!(log.change_seq(), seq);
+        let seq = log.change_seq();
+        log.redo();
+        assert_ne!(log.change_seq(), seq);
+    }
+
+    #[test]
+    fn test_trimming_never_drops_redo_history() {
+        let mut log = EventLog::with_limits(2, 0);
+        log.append(insert("a"));
+        log.append(insert("b"));
+        log.undo();
+
+        // Non-write events after undo are not logged and never trim
+        log.append(Event::Scroll { line_offset: 1 });
+        assert_eq!(log.undo_group_count(), 2);
+        assert!(log.can_redo());
+    }
 }
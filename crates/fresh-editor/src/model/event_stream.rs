@@ -0,0 +1,308 @@
+//! Structured event stream: the JSON Lines format written by `--event-log`
+//! and by Export Event Log, and read back by `--replay`.
+//!
+//! Every line is one JSON object. `type` names the record kind, `time_us`
+//! is the time since the stream started in microseconds, and `buffer_id`
+//! is the buffer that was active (or that the record applies to):
+//!
+//! ```text
+//! {"type":"header","time_us":0,"version":1,"started_at_ms":1760000000000}
+//! {"type":"open_file","time_us":12,"buffer_id":1,"path":"/tmp/a.txt"}
+//! {"type":"keystroke","time_us":40,"buffer_id":1,"key":"Char('x')","modifiers":"NONE"}
+//! {"type":"action","time_us":41,"buffer_id":1,"action":{"InsertChar":"x"}}
+//! {"type":"event","time_us":43,"buffer_id":1,"event":{"Insert":{…}}}
+//! {"type":"render","time_us":60,"buffer_id":1,"cursor_position":1,"screen_cursor":[9,1],"buffer_length":1}
+//! ```
+//!
+//! The header is always the first line and carries the format version and
+//! the wall-clock start time (milliseconds since the Unix epoch). `time_us`
+//! comes from a monotonic clock and strictly increases from one record to
+//! the next, so it orders records even when they land within the same
+//! microsecond. `action` and `event` hold the serde encoding of
+//! [`Action`](crate::input::keybindings::Action) and [`Event`].
+//!
+//! The file is only ever appended to, and each record is written with a
+//! single `write` and flushed right away, so a crash loses at most the line
+//! being written. The reader skips a torn final line, `#` comment lines,
+//! and record types it doesn't know, and still accepts the unversioned
+//! `{"event":…}` lines older builds wrote.
+
+use std::fs::{File, OpenOptions};
+use std::io::{self, BufRead, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use super::event::{BufferId, Event};
+
+/// Version written in the stream header. Bump it when a record changes
+/// shape in a way older readers would misread.
+pub const EVENT_STREAM_VERSION: u32 = 1;
+
+/// One line of an event stream.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StreamRecord {
+    /// Microseconds since the stream started
+    #[serde(default)]
+    pub time_us: u64,
+    /// Buffer the record applies to, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub buffer_id: Option<BufferId>,
+    #[serde(flatten)]
+    pub kind: RecordKind,
+}
+
+/// What a stream record describes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RecordKind {
+    /// First line of every stream
+    Header { version: u32, started_at_ms: u64 },
+    /// A file was opened into `buffer_id`
+    OpenFile { path: PathBuf },
+    /// A key press, before it is resolved to an action
+    Keystroke { key: String, modifiers: String },
+    /// An action about to be handled (serde encoding of `Action`)
+    Action { action: serde_json::Value },
+    /// An event applied to `buffer_id`
+    Event { event: Event },
+    /// Cursor state after a render, for debugging display issues
+    Render {
+        cursor_position: usize,
+        screen_cursor: (u16, u16),
+        buffer_length: usize,
+    },
+    /// A record type this build doesn't know
+    #[serde(other)]
+    Unknown,
+}
+
+/// Appends records to an event stream file.
+pub struct EventStreamWriter {
+    file: File,
+    started: Instant,
+    last_time_us: Option<u64>,
+}
+
+impl EventStreamWriter {
+    /// Create (or truncate) the stream at `path` and write its header.
+    pub fn create(path: &Path) -> io::Result<Self> {
+        let file = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .truncate(true)
+            .open(path)?;
+        let mut writer = Self {
+            file,
+            started: Instant::now(),
+            last_time_us: None,
+        };
+        let started_at_ms = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or(0);
+        writer.write(
+            None,
+            RecordKind::Header {
+                version: EVENT_STREAM_VERSION,
+                started_at_ms,
+            },
+        )?;
+        Ok(writer)
+    }
+
+    /// Append one record and flush it.
+    pub fn write(&mut self, buffer_id: Option<BufferId>, kind: RecordKind) -> io::Result<()> {
+        let elapsed = self.started.elapsed().as_micros() as u64;
+        let time_us = match self.last_time_us {
+            Some(last) => elapsed.max(last + 1),
+            None => elapsed,
+        };
+        self.last_time_us = Some(time_us);
+
+        let record = StreamRecord {
+            time_us,
+            buffer_id,
+            kind,
+        };
+        let mut line = serde_json::to_string(&record).map_err(io::Error::other)?;
+        line.push('\n');
+        // One write per line so a crash can't interleave partial records
+        self.file.write_all(line.as_bytes())?;
+        self.file.flush()
+    }
+}
+
+/// A writer shared by every buffer's event log, tagged with the buffer
+/// whose events go through it.
+#[derive(Clone)]
+pub struct EventStreamSink {
+    writer: std::sync::Arc<std::sync::Mutex<EventStreamWriter>>,
+    buffer_id: BufferId,
+}
+
+impl EventStreamSink {
+    pub fn new(
+        writer: std::sync::Arc<std::sync::Mutex<EventStreamWriter>>,
+        buffer_id: BufferId,
+    ) -> Self {
+        Self { writer, buffer_id }
+    }
+
+    /// Append a record for this sink's buffer. Failures are traced and
+    /// otherwise ignored: losing the stream must not disturb editing.
+    pub fn record(&self, kind: RecordKind) {
+        let Ok(mut writer) = self.writer.lock() else {
+            return;
+        };
+        if let Err(e) = writer.write(Some(self.buffer_id), kind) {
+            tracing::trace!("Failed to write to event stream: {e}");
+        }
+    }
+}
+
+/// Reads the records of an event stream in order.
+pub struct EventStreamReader<R> {
+    reader: R,
+    line_number: usize,
+}
+
+impl EventStreamReader<io::BufReader<File>> {
+    /// Open the stream at `path`.
+    pub fn open(path: &Path) -> io::Result<Self> {
+        Ok(Self::new(io::BufReader::new(File::open(path)?)))
+    }
+}
+
+impl<R: BufRead> EventStreamReader<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            line_number: 0,
+        }
+    }
+
+    /// Number of the line the last record came from (1-based).
+    pub fn line_number(&self) -> usize {
+        self.line_number
+    }
+}
+
+impl<R: BufRead> Iterator for EventStreamReader<R> {
+    type Item = io::Result<StreamRecord>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let mut line = String::new();
+            match self.reader.read_line(&mut line) {
+                Ok(0) => return None,
+                Ok(_) => {}
+                Err(e) => return Some(Err(e)),
+            }
+            self.line_number += 1;
+            let complete = line.ends_with('\n');
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+            match parse_record(trimmed) {
+                Ok(record) => return Some(Ok(record)),
+                // A line cut off by a crash mid-write
+                Err(_) if !complete => return None,
+                Err(e) => {
+                    return Some(Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("line {}: {}", self.line_number, e),
+                    )))
+                }
+            }
+        }
+    }
+}
+
+/// Parse one line, accepting the unversioned `{"event":…}` lines (event
+/// log entries and the old stream format) as event records.
+fn parse_record(line: &str) -> serde_json::Result<StreamRecord> {
+    let value: serde_json::Value = serde_json::from_str(line)?;
+    if value.get("type").is_none() {
+        if let Some(event) = value.get("event") {
+            return Ok(StreamRecord {
+                time_us: 0,
+                buffer_id: None,
+                kind: RecordKind::Event {
+                    event: serde_json::from_value(event.clone())?,
+                },
+            });
+        }
+    }
+    serde_json::from_value(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::event::CursorId;
+
+    #[test]
+    fn test_stream_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("events.jsonl");
+        let mut writer = EventStreamWriter::create(&path).unwrap();
+        writer
+            .write(
+                Some(BufferId(1)),
+                RecordKind::OpenFile {
+                    path: PathBuf::from("/tmp/a.txt"),
+                },
+            )
+            .unwrap();
+        writer
+            .write(
+                Some(BufferId(1)),
+                RecordKind::Event {
+                    event: Event::Insert {
+                        position: 0,
+                        text: "hi".to_string(),
+                        cursor_id: CursorId(0),
+                    },
+                },
+            )
+            .unwrap();
+        drop(writer);
+
+        let records: Vec<StreamRecord> = EventStreamReader::open(&path)
+            .unwrap()
+            .collect::<io::Result<_>>()
+            .unwrap();
+        assert_eq!(records.len(), 3);
+        assert!(matches!(
+            records[0].kind,
+            RecordKind::Header {
+                version: EVENT_STREAM_VERSION,
+                ..
+            }
+        ));
+        assert!(matches!(records[1].kind, RecordKind::OpenFile { .. }));
+        assert!(matches!(
+            &records[2].kind,
+            RecordKind::Event { event: Event::Insert { text, .. } } if text == "hi"
+        ));
+        assert_eq!(records[2].buffer_id, Some(BufferId(1)));
+        assert!(records.windows(2).all(|w| w[0].time_us < w[1].time_us));
+    }
+
+    #[test]
+    fn test_reader_tolerates_legacy_and_torn_lines() {
+        let input = "# Event Log Stream\n\
+             {\"index\":0,\"timestamp\":\"2025-01-01T00:00:00Z\",\"event\":{\"Insert\":{\"position\":0,\"text\":\"a\",\"cursor_id\":0}}}\n\
+             {\"type\":\"something_new\",\"time_us\":5}\n\
+             {\"type\":\"event\",\"time_us\":9,\"ev";
+        let records: Vec<StreamRecord> = EventStreamReader::new(io::Cursor::new(input))
+            .collect::<io::Result<_>>()
+            .unwrap();
+        assert_eq!(records.len(), 2);
+        assert!(matches!(records[0].kind, RecordKind::Event { .. }));
+        assert!(matches!(records[1].kind, RecordKind::Unknown));
+    }
+}
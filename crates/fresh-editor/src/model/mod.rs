@@ -12,9 +12,11 @@ pub mod edit;
 pub mod encoding;
 pub mod encoding_heuristics;
 pub mod event;
+pub mod event_stream;
 pub mod filesystem;
 pub mod line_diff;
 pub mod marker;
 pub mod marker_tree;
 pub mod piece_tree;
 pub mod piece_tree_diff;
+pub mod selection_stats;
@@ -0,0 +1,169 @@
+//! Character, line and word counts for the selected text.
+//!
+//! The counts are made in one streaming pass over the selected bytes, read
+//! from the buffer in fixed-size chunks so a large selection is never copied
+//! out whole. The status bar asks for them on every frame, so the last result
+//! is cached until the selection or the buffer changes.
+
+use std::ops::Range;
+
+use crate::model::buffer::Buffer;
+
+/// Bytes read from the buffer per step of the scan.
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// Counts over one or more selections.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SelectionStats {
+    /// Unicode scalar values
+    pub chars: usize,
+    /// Lines touched by the selection. A selection ending right after a
+    /// newline does not count the line it ends on.
+    pub lines: usize,
+    /// Runs of non-whitespace characters
+    pub words: usize,
+}
+
+impl std::ops::AddAssign for SelectionStats {
+    fn add_assign(&mut self, other: Self) {
+        self.chars += other.chars;
+        self.lines += other.lines;
+        self.words += other.words;
+    }
+}
+
+/// Streaming counter for the text of a single selection.
+#[derive(Debug, Default)]
+struct Counter {
+    stats: SelectionStats,
+    newlines: usize,
+    in_word: bool,
+    last_byte: Option<u8>,
+}
+
+impl Counter {
+    fn feed(&mut self, chunk: &[u8]) {
+        for &b in chunk {
+            // Count every byte that starts a UTF-8 sequence; this stays
+            // correct when a chunk boundary splits a character
+            if b & 0xC0 != 0x80 {
+                self.stats.chars += 1;
+            }
+            if b == b'\n' {
+                self.newlines += 1;
+            }
+            let is_space = b.is_ascii_whitespace();
+            if !is_space && !self.in_word {
+                self.stats.words += 1;
+            }
+            self.in_word = !is_space;
+        }
+        if let Some(&b) = chunk.last() {
+            self.last_byte = Some(b);
+        }
+    }
+
+    fn finish(mut self) -> SelectionStats {
+        self.stats.lines = match self.last_byte {
+            None => 0,
+            Some(b'\n') => self.newlines,
+            Some(_) => self.newlines + 1,
+        };
+        self.stats
+    }
+}
+
+/// Count the text of `range`, reading it from `buffer` chunk by chunk.
+fn count_range(buffer: &mut Buffer, range: Range<usize>) -> SelectionStats {
+    let mut counter = Counter::default();
+    let end = range.end.min(buffer.len());
+    let mut offset = range.start;
+    while offset < end {
+        let len = CHUNK_SIZE.min(end - offset);
+        match buffer.get_text_range_mut(offset, len) {
+            Ok(chunk) if !chunk.is_empty() => {
+                counter.feed(&chunk);
+                offset += chunk.len();
+            }
+            Ok(_) => break,
+            Err(e) => {
+                tracing::warn!("Failed to read selection {}..{}: {}", offset, end, e);
+                break;
+            }
+        }
+    }
+    counter.finish()
+}
+
+/// Last computed counts, keyed by the selections and the buffer version.
+#[derive(Debug, Default)]
+pub struct SelectionStatsCache {
+    key: Option<(u64, Vec<Range<usize>>)>,
+    stats: SelectionStats,
+}
+
+impl SelectionStatsCache {
+    /// Counts summed over `ranges`, recomputed only when the selections or
+    /// the buffer changed since the last call.
+    pub fn get(&mut self, buffer: &mut Buffer, ranges: &[Range<usize>]) -> SelectionStats {
+        let version = buffer.version();
+        let fresh = self
+            .key
+            .as_ref()
+            .is_some_and(|(v, r)| *v == version && r == ranges);
+        if !fresh {
+            let mut stats = SelectionStats::default();
+            for range in ranges {
+                stats += count_range(buffer, range.clone());
+            }
+            self.stats = stats;
+            self.key = Some((version, ranges.to_vec()));
+        }
+        self.stats
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn count(chunks: &[&[u8]]) -> SelectionStats {
+        let mut counter = Counter::default();
+        for chunk in chunks {
+            counter.feed(chunk);
+        }
+        counter.finish()
+    }
+
+    #[test]
+    fn counts_chars_lines_and_words() {
+        assert_eq!(
+            count(&[b"hello world\nfoo bar\n"]),
+            SelectionStats {
+                chars: 20,
+                lines: 2,
+                words: 4
+            }
+        );
+        assert_eq!(
+            count(&[b"one two"]),
+            SelectionStats {
+                chars: 7,
+                lines: 1,
+                words: 2
+            }
+        );
+        assert_eq!(count(&[]), SelectionStats::default());
+    }
+
+    #[test]
+    fn chunk_boundaries_do_not_change_the_counts() {
+        let text = "héllo wörld\nnext".as_bytes();
+        let whole = count(&[text]);
+        for split in 1..text.len() {
+            assert_eq!(count(&[&text[..split], &text[split..]]), whole);
+        }
+        assert_eq!(whole.chars, 16);
+        assert_eq!(whole.words, 3);
+    }
+}
@@ -4,11 +4,11 @@
 //! enabling a 4-level overlay architecture (System → User → Project → Session).
 
 use crate::config::{
-    ClipboardConfig, CursorStyle, FileBrowserConfig, FileExplorerConfig, FormatterConfig,
-    Keybinding, KeybindingMapName, KeymapConfig, LanguageConfig, LineEndingOption, OnSaveAction,
-    PluginConfig, TerminalConfig, ThemeName, WarningsConfig,
+    ClipboardConfig, CursorStyle, CustomCommandConfig, FileBrowserConfig, FileExplorerConfig,
+    FormatterConfig, IndentStyle, Keybinding, KeybindingMapName, KeymapConfig, LanguageConfig,
+    LineEndingOption, OnSaveAction, PluginConfig, TerminalConfig, ThemeName, WarningsConfig,
 };
-use crate::types::LspLanguageConfig;
+use crate::types::{LspLanguageConfig, ProcessLimits};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -90,6 +90,7 @@ pub struct PartialConfig {
     pub lsp: Option<HashMap<String, LspLanguageConfig>>,
     pub universal_lsp: Option<HashMap<String, LspLanguageConfig>>,
     pub warnings: Option<PartialWarningsConfig>,
+    pub commands: Option<HashMap<String, CustomCommandConfig>>,
     pub plugins: Option<HashMap<String, PartialPluginConfig>>,
     pub packages: Option<PartialPackagesConfig>,
 }
@@ -119,6 +120,7 @@ impl Merge for PartialConfig {
         self.default_language.merge_from(&other.default_language);
         merge_hashmap(&mut self.lsp, &other.lsp);
         merge_hashmap(&mut self.universal_lsp, &other.universal_lsp);
+        merge_hashmap(&mut self.commands, &other.commands);
         merge_hashmap_recursive(&mut self.plugins, &other.plugins);
 
         self.active_keybinding_map
@@ -141,23 +143,33 @@ fn merge_partial<T: Merge + Clone>(target: &mut Option<T>, other: &Option<T>) {
 pub struct PartialEditorConfig {
     pub use_tabs: Option<bool>,
     pub tab_size: Option<usize>,
+    pub indent_style: Option<Option<IndentStyle>>,
+    pub indent_width: Option<Option<usize>>,
+    pub detect_indentation: Option<bool>,
     pub auto_indent: Option<bool>,
     pub auto_close: Option<bool>,
     pub auto_surround: Option<bool>,
+    pub backspace_unindent: Option<bool>,
     pub animations: Option<bool>,
     pub cursor_jump_animation: Option<bool>,
     pub line_numbers: Option<bool>,
     pub relative_line_numbers: Option<bool>,
     pub scroll_offset: Option<usize>,
+    pub overscroll: Option<bool>,
     pub syntax_highlighting: Option<bool>,
     pub highlight_current_line: Option<bool>,
     pub highlight_current_column: Option<bool>,
+    pub highlight_all_cursor_lines: Option<bool>,
     pub line_wrap: Option<bool>,
     pub wrap_indent: Option<bool>,
+    pub wrap_indent_extra: Option<usize>,
+    pub wrap_mode: Option<crate::config::WrapMode>,
     pub wrap_column: Option<Option<usize>>,
     pub page_width: Option<Option<usize>>,
     pub highlight_timeout_ms: Option<u64>,
     pub snapshot_interval: Option<usize>,
+    pub undo_history_max_groups: Option<usize>,
+    pub undo_history_max_bytes: Option<usize>,
     pub large_file_threshold_bytes: Option<u64>,
     pub estimated_line_length: Option<usize>,
     pub enable_inlay_hints: Option<bool>,
@@ -169,6 +181,7 @@ pub struct PartialEditorConfig {
     pub auto_save_interval_secs: Option<u32>,
     pub hot_exit: Option<bool>,
     pub restore_previous_session: Option<bool>,
+    pub persist_registers: Option<bool>,
     pub skip_session_restore_when_files_passed: Option<bool>,
     pub auto_create_empty_buffer_on_last_buffer_close: Option<bool>,
     pub highlight_context_bytes: Option<usize>,
@@ -178,6 +191,11 @@ pub struct PartialEditorConfig {
     pub auto_revert_poll_interval_ms: Option<u64>,
     pub read_concurrency: Option<usize>,
     pub file_tree_poll_interval_ms: Option<u64>,
+    pub plugin_hook_debounce_ms: Option<u64>,
+    pub plugin_debounced_hooks: Option<Vec<String>>,
+    pub plugin_handler_timeout_ms: Option<u64>,
+    pub plugin_disable_after_timeouts: Option<u32>,
+    pub process_limits: Option<ProcessLimits>,
     pub default_line_ending: Option<LineEndingOption>,
     pub trim_trailing_whitespace_on_save: Option<bool>,
     pub ensure_final_newline_on_save: Option<bool>,
@@ -191,6 +209,7 @@ pub struct PartialEditorConfig {
     pub completion_popup_auto_show: Option<bool>,
     pub quick_suggestions: Option<bool>,
     pub quick_suggestions_delay_ms: Option<u64>,
+    pub quick_suggestions_min_chars: Option<usize>,
     pub suggest_on_trigger_characters: Option<bool>,
     pub show_menu_bar: Option<bool>,
     pub menu_bar_mnemonics: Option<bool>,
@@ -200,10 +219,12 @@ pub struct PartialEditorConfig {
     pub show_prompt_line: Option<bool>,
     pub show_vertical_scrollbar: Option<bool>,
     pub show_horizontal_scrollbar: Option<bool>,
+    pub scrollbar_minimap: Option<bool>,
     pub show_tilde: Option<bool>,
     pub use_terminal_bg: Option<bool>,
     pub set_window_title: Option<bool>,
     pub rulers: Option<Vec<usize>>,
+    pub indent_guides: Option<bool>,
     pub whitespace_show: Option<bool>,
     pub whitespace_spaces_leading: Option<bool>,
     pub whitespace_spaces_inner: Option<bool>,
@@ -217,25 +238,40 @@ impl Merge for PartialEditorConfig {
     fn merge_from(&mut self, other: &Self) {
         self.use_tabs.merge_from(&other.use_tabs);
         self.tab_size.merge_from(&other.tab_size);
+        self.indent_style.merge_from(&other.indent_style);
+        self.indent_width.merge_from(&other.indent_width);
+        self.detect_indentation
+            .merge_from(&other.detect_indentation);
         self.auto_indent.merge_from(&other.auto_indent);
         self.auto_close.merge_from(&other.auto_close);
         self.auto_surround.merge_from(&other.auto_surround);
+        self.backspace_unindent
+            .merge_from(&other.backspace_unindent);
         self.animations.merge_from(&other.animations);
         self.cursor_jump_animation
             .merge_from(&other.cursor_jump_animation);
         self.line_numbers.merge_from(&other.line_numbers);
         self.relative_line_numbers
             .merge_from(&other.relative_line_numbers);
+        self.highlight_all_cursor_lines
+            .merge_from(&other.highlight_all_cursor_lines);
         self.scroll_offset.merge_from(&other.scroll_offset);
+        self.overscroll.merge_from(&other.overscroll);
         self.syntax_highlighting
             .merge_from(&other.syntax_highlighting);
         self.line_wrap.merge_from(&other.line_wrap);
         self.wrap_indent.merge_from(&other.wrap_indent);
+        self.wrap_indent_extra.merge_from(&other.wrap_indent_extra);
+        self.wrap_mode.merge_from(&other.wrap_mode);
         self.wrap_column.merge_from(&other.wrap_column);
         self.page_width.merge_from(&other.page_width);
         self.highlight_timeout_ms
             .merge_from(&other.highlight_timeout_ms);
         self.snapshot_interval.merge_from(&other.snapshot_interval);
+        self.undo_history_max_groups
+            .merge_from(&other.undo_history_max_groups);
+        self.undo_history_max_bytes
+            .merge_from(&other.undo_history_max_bytes);
         self.large_file_threshold_bytes
             .merge_from(&other.large_file_threshold_bytes);
         self.estimated_line_length
@@ -255,6 +291,7 @@ impl Merge for PartialEditorConfig {
         self.hot_exit.merge_from(&other.hot_exit);
         self.restore_previous_session
             .merge_from(&other.restore_previous_session);
+        self.persist_registers.merge_from(&other.persist_registers);
         self.skip_session_restore_when_files_passed
             .merge_from(&other.skip_session_restore_when_files_passed);
         self.auto_create_empty_buffer_on_last_buffer_close
@@ -272,6 +309,15 @@ impl Merge for PartialEditorConfig {
         self.read_concurrency.merge_from(&other.read_concurrency);
         self.file_tree_poll_interval_ms
             .merge_from(&other.file_tree_poll_interval_ms);
+        self.plugin_hook_debounce_ms
+            .merge_from(&other.plugin_hook_debounce_ms);
+        self.plugin_debounced_hooks
+            .merge_from(&other.plugin_debounced_hooks);
+        self.plugin_handler_timeout_ms
+            .merge_from(&other.plugin_handler_timeout_ms);
+        self.plugin_disable_after_timeouts
+            .merge_from(&other.plugin_disable_after_timeouts);
+        self.process_limits.merge_from(&other.process_limits);
         self.default_line_ending
             .merge_from(&other.default_line_ending);
         self.trim_trailing_whitespace_on_save
@@ -295,6 +341,8 @@ impl Merge for PartialEditorConfig {
         self.quick_suggestions.merge_from(&other.quick_suggestions);
         self.quick_suggestions_delay_ms
             .merge_from(&other.quick_suggestions_delay_ms);
+        self.quick_suggestions_min_chars
+            .merge_from(&other.quick_suggestions_min_chars);
         self.suggest_on_trigger_characters
             .merge_from(&other.suggest_on_trigger_characters);
         self.show_menu_bar.merge_from(&other.show_menu_bar);
@@ -310,10 +358,12 @@ impl Merge for PartialEditorConfig {
             .merge_from(&other.show_vertical_scrollbar);
         self.show_horizontal_scrollbar
             .merge_from(&other.show_horizontal_scrollbar);
+        self.scrollbar_minimap.merge_from(&other.scrollbar_minimap);
         self.show_tilde.merge_from(&other.show_tilde);
         self.use_terminal_bg.merge_from(&other.use_terminal_bg);
         self.set_window_title.merge_from(&other.set_window_title);
         self.rulers.merge_from(&other.rulers);
+        self.indent_guides.merge_from(&other.indent_guides);
         self.whitespace_show.merge_from(&other.whitespace_show);
         self.whitespace_spaces_leading
             .merge_from(&other.whitespace_spaces_leading);
@@ -382,6 +432,7 @@ impl Merge for PartialFileBrowserConfig {
 pub struct PartialClipboardConfig {
     pub use_osc52: Option<bool>,
     pub use_system_clipboard: Option<bool>,
+    pub osc52_max_bytes: Option<usize>,
 }
 
 impl Merge for PartialClipboardConfig {
@@ -389,6 +440,7 @@ impl Merge for PartialClipboardConfig {
         self.use_osc52.merge_from(&other.use_osc52);
         self.use_system_clipboard
             .merge_from(&other.use_system_clipboard);
+        self.osc52_max_bytes.merge_from(&other.osc52_max_bytes);
     }
 }
 
@@ -398,6 +450,7 @@ impl Merge for PartialClipboardConfig {
 pub struct PartialTerminalConfig {
     pub jump_to_end_on_output: Option<bool>,
     pub shell: Option<crate::config::TerminalShellConfig>,
+    pub restore_scrollback_lines: Option<usize>,
 }
 
 impl Merge for PartialTerminalConfig {
@@ -405,6 +458,8 @@ impl Merge for PartialTerminalConfig {
         self.jump_to_end_on_output
             .merge_from(&other.jump_to_end_on_output);
         self.shell.merge_from(&other.shell);
+        self.restore_scrollback_lines
+            .merge_from(&other.restore_scrollback_lines);
     }
 }
 
@@ -443,12 +498,15 @@ pub struct PartialPluginConfig {
     pub enabled: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub path: Option<std::path::PathBuf>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<Vec<fresh_core::config::PluginPermission>>,
 }
 
 impl Merge for PartialPluginConfig {
     fn merge_from(&mut self, other: &Self) {
         self.enabled.merge_from(&other.enabled);
         self.path.merge_from(&other.path);
+        self.permissions.merge_from(&other.permissions);
     }
 }
 
@@ -471,10 +529,13 @@ pub struct PartialLanguageConfig {
     pub page_width: Option<Option<usize>>,
     pub use_tabs: Option<bool>,
     pub tab_size: Option<usize>,
+    pub indent_style: Option<IndentStyle>,
+    pub indent_width: Option<usize>,
     pub formatter: Option<FormatterConfig>,
     pub format_on_save: Option<bool>,
     pub on_save: Option<Vec<OnSaveAction>>,
     pub word_characters: Option<Option<String>>,
+    pub run_command: Option<Option<String>>,
 }
 
 impl Merge for PartialLanguageConfig {
@@ -495,10 +556,13 @@ impl Merge for PartialLanguageConfig {
         self.page_width.merge_from(&other.page_width);
         self.use_tabs.merge_from(&other.use_tabs);
         self.tab_size.merge_from(&other.tab_size);
+        self.indent_style.merge_from(&other.indent_style);
+        self.indent_width.merge_from(&other.indent_width);
         self.formatter.merge_from(&other.formatter);
         self.format_on_save.merge_from(&other.format_on_save);
         self.on_save.merge_from(&other.on_save);
         self.word_characters.merge_from(&other.word_characters);
+        self.run_command.merge_from(&other.run_command);
     }
 }
 
@@ -509,23 +573,33 @@ impl From<&crate::config::EditorConfig> for PartialEditorConfig {
         Self {
             use_tabs: Some(cfg.use_tabs),
             tab_size: Some(cfg.tab_size),
+            indent_style: Some(cfg.indent_style),
+            indent_width: Some(cfg.indent_width),
+            detect_indentation: Some(cfg.detect_indentation),
             auto_indent: Some(cfg.auto_indent),
             auto_close: Some(cfg.auto_close),
             auto_surround: Some(cfg.auto_surround),
+            backspace_unindent: Some(cfg.backspace_unindent),
             animations: Some(cfg.animations),
             cursor_jump_animation: Some(cfg.cursor_jump_animation),
             line_numbers: Some(cfg.line_numbers),
             relative_line_numbers: Some(cfg.relative_line_numbers),
             scroll_offset: Some(cfg.scroll_offset),
+            overscroll: Some(cfg.overscroll),
             syntax_highlighting: Some(cfg.syntax_highlighting),
             highlight_current_line: Some(cfg.highlight_current_line),
             highlight_current_column: Some(cfg.highlight_current_column),
+            highlight_all_cursor_lines: Some(cfg.highlight_all_cursor_lines),
             line_wrap: Some(cfg.line_wrap),
             wrap_indent: Some(cfg.wrap_indent),
+            wrap_indent_extra: Some(cfg.wrap_indent_extra),
+            wrap_mode: Some(cfg.wrap_mode),
             wrap_column: Some(cfg.wrap_column),
             page_width: Some(cfg.page_width),
             highlight_timeout_ms: Some(cfg.highlight_timeout_ms),
             snapshot_interval: Some(cfg.snapshot_interval),
+            undo_history_max_groups: Some(cfg.undo_history_max_groups),
+            undo_history_max_bytes: Some(cfg.undo_history_max_bytes),
             large_file_threshold_bytes: Some(cfg.large_file_threshold_bytes),
             estimated_line_length: Some(cfg.estimated_line_length),
             enable_inlay_hints: Some(cfg.enable_inlay_hints),
@@ -537,6 +611,7 @@ impl From<&crate::config::EditorConfig> for PartialEditorConfig {
             auto_save_interval_secs: Some(cfg.auto_save_interval_secs),
             hot_exit: Some(cfg.hot_exit),
             restore_previous_session: Some(cfg.restore_previous_session),
+            persist_registers: Some(cfg.persist_registers),
             skip_session_restore_when_files_passed: Some(
                 cfg.skip_session_restore_when_files_passed,
             ),
@@ -550,6 +625,11 @@ impl From<&crate::config::EditorConfig> for PartialEditorConfig {
             auto_revert_poll_interval_ms: Some(cfg.auto_revert_poll_interval_ms),
             read_concurrency: Some(cfg.read_concurrency),
             file_tree_poll_interval_ms: Some(cfg.file_tree_poll_interval_ms),
+            plugin_hook_debounce_ms: Some(cfg.plugin_hook_debounce_ms),
+            plugin_debounced_hooks: Some(cfg.plugin_debounced_hooks.clone()),
+            plugin_handler_timeout_ms: Some(cfg.plugin_handler_timeout_ms),
+            plugin_disable_after_timeouts: Some(cfg.plugin_disable_after_timeouts),
+            process_limits: Some(cfg.process_limits.clone()),
             default_line_ending: Some(cfg.default_line_ending.clone()),
             trim_trailing_whitespace_on_save: Some(cfg.trim_trailing_whitespace_on_save),
             ensure_final_newline_on_save: Some(cfg.ensure_final_newline_on_save),
@@ -565,6 +645,7 @@ impl From<&crate::config::EditorConfig> for PartialEditorConfig {
             completion_popup_auto_show: Some(cfg.completion_popup_auto_show),
             quick_suggestions: Some(cfg.quick_suggestions),
             quick_suggestions_delay_ms: Some(cfg.quick_suggestions_delay_ms),
+            quick_suggestions_min_chars: Some(cfg.quick_suggestions_min_chars),
             suggest_on_trigger_characters: Some(cfg.suggest_on_trigger_characters),
             show_menu_bar: Some(cfg.show_menu_bar),
             menu_bar_mnemonics: Some(cfg.menu_bar_mnemonics),
@@ -574,10 +655,12 @@ impl From<&crate::config::EditorConfig> for PartialEditorConfig {
             show_prompt_line: Some(cfg.show_prompt_line),
             show_vertical_scrollbar: Some(cfg.show_vertical_scrollbar),
             show_horizontal_scrollbar: Some(cfg.show_horizontal_scrollbar),
+            scrollbar_minimap: Some(cfg.scrollbar_minimap),
             show_tilde: Some(cfg.show_tilde),
             use_terminal_bg: Some(cfg.use_terminal_bg),
             set_window_title: Some(cfg.set_window_title),
             rulers: Some(cfg.rulers.clone()),
+            indent_guides: Some(cfg.indent_guides),
             whitespace_show: Some(cfg.whitespace_show),
             whitespace_spaces_leading: Some(cfg.whitespace_spaces_leading),
             whitespace_spaces_inner: Some(cfg.whitespace_spaces_inner),
@@ -595,9 +678,17 @@ impl PartialEditorConfig {
         crate::config::EditorConfig {
             use_tabs: self.use_tabs.unwrap_or(defaults.use_tabs),
             tab_size: self.tab_size.unwrap_or(defaults.tab_size),
+            indent_style: self.indent_style.unwrap_or(defaults.indent_style),
+            indent_width: self.indent_width.unwrap_or(defaults.indent_width),
+            detect_indentation: self
+                .detect_indentation
+                .unwrap_or(defaults.detect_indentation),
             auto_indent: self.auto_indent.unwrap_or(defaults.auto_indent),
             auto_close: self.auto_close.unwrap_or(defaults.auto_close),
             auto_surround: self.auto_surround.unwrap_or(defaults.auto_surround),
+            backspace_unindent: self
+                .backspace_unindent
+                .unwrap_or(defaults.backspace_unindent),
             animations: self.animations.unwrap_or(defaults.animations),
             cursor_jump_animation: self
                 .cursor_jump_animation
@@ -607,6 +698,7 @@ impl PartialEditorConfig {
                 .relative_line_numbers
                 .unwrap_or(defaults.relative_line_numbers),
             scroll_offset: self.scroll_offset.unwrap_or(defaults.scroll_offset),
+            overscroll: self.overscroll.unwrap_or(defaults.overscroll),
             syntax_highlighting: self
                 .syntax_highlighting
                 .unwrap_or(defaults.syntax_highlighting),
@@ -616,14 +708,25 @@ impl PartialEditorConfig {
             highlight_current_column: self
                 .highlight_current_column
                 .unwrap_or(defaults.highlight_current_column),
+            highlight_all_cursor_lines: self
+                .highlight_all_cursor_lines
+                .unwrap_or(defaults.highlight_all_cursor_lines),
             line_wrap: self.line_wrap.unwrap_or(defaults.line_wrap),
             wrap_indent: self.wrap_indent.unwrap_or(defaults.wrap_indent),
+            wrap_indent_extra: self.wrap_indent_extra.unwrap_or(defaults.wrap_indent_extra),
+            wrap_mode: self.wrap_mode.unwrap_or(defaults.wrap_mode),
             wrap_column: self.wrap_column.unwrap_or(defaults.wrap_column),
             page_width: self.page_width.unwrap_or(defaults.page_width),
             highlight_timeout_ms: self
                 .highlight_timeout_ms
                 .unwrap_or(defaults.highlight_timeout_ms),
             snapshot_interval: self.snapshot_interval.unwrap_or(defaults.snapshot_interval),
+            undo_history_max_groups: self
+                .undo_history_max_groups
+                .unwrap_or(defaults.undo_history_max_groups),
+            undo_history_max_bytes: self
+                .undo_history_max_bytes
+                .unwrap_or(defaults.undo_history_max_bytes),
             large_file_threshold_bytes: self
                 .large_file_threshold_bytes
                 .unwrap_or(defaults.large_file_threshold_bytes),
@@ -651,6 +754,7 @@ impl PartialEditorConfig {
             restore_previous_session: self
                 .restore_previous_session
                 .unwrap_or(defaults.restore_previous_session),
+            persist_registers: self.persist_registers.unwrap_or(defaults.persist_registers),
             skip_session_restore_when_files_passed: self
                 .skip_session_restore_when_files_passed
                 .unwrap_or(defaults.skip_session_restore_when_files_passed),
@@ -676,6 +780,21 @@ impl PartialEditorConfig {
             file_tree_poll_interval_ms: self
                 .file_tree_poll_interval_ms
                 .unwrap_or(defaults.file_tree_poll_interval_ms),
+            plugin_hook_debounce_ms: self
+                .plugin_hook_debounce_ms
+                .unwrap_or(defaults.plugin_hook_debounce_ms),
+            plugin_debounced_hooks: self
+                .plugin_debounced_hooks
+                .unwrap_or_else(|| defaults.plugin_debounced_hooks.clone()),
+            plugin_handler_timeout_ms: self
+                .plugin_handler_timeout_ms
+                .unwrap_or(defaults.plugin_handler_timeout_ms),
+            plugin_disable_after_timeouts: self
+                .plugin_disable_after_timeouts
+                .unwrap_or(defaults.plugin_disable_after_timeouts),
+            process_limits: self
+                .process_limits
+                .unwrap_or_else(|| defaults.process_limits.clone()),
             default_line_ending: self
                 .default_line_ending
                 .unwrap_or(defaults.default_line_ending.clone()),
@@ -709,6 +828,9 @@ impl PartialEditorConfig {
             quick_suggestions_delay_ms: self
                 .quick_suggestions_delay_ms
                 .unwrap_or(defaults.quick_suggestions_delay_ms),
+            quick_suggestions_min_chars: self
+                .quick_suggestions_min_chars
+                .unwrap_or(defaults.quick_suggestions_min_chars),
             suggest_on_trigger_characters: self
                 .suggest_on_trigger_characters
                 .unwrap_or(defaults.suggest_on_trigger_characters),
@@ -728,10 +850,12 @@ impl PartialEditorConfig {
             show_horizontal_scrollbar: self
                 .show_horizontal_scrollbar
                 .unwrap_or(defaults.show_horizontal_scrollbar),
+            scrollbar_minimap: self.scrollbar_minimap.unwrap_or(defaults.scrollbar_minimap),
             show_tilde: self.show_tilde.unwrap_or(defaults.show_tilde),
             use_terminal_bg: self.use_terminal_bg.unwrap_or(defaults.use_terminal_bg),
             set_window_title: self.set_window_title.unwrap_or(defaults.set_window_title),
             rulers: self.rulers.unwrap_or_else(|| defaults.rulers.clone()),
+            indent_guides: self.indent_guides.unwrap_or(defaults.indent_guides),
             whitespace_show: self.whitespace_show.unwrap_or(defaults.whitespace_show),
             whitespace_spaces_leading: self
                 .whitespace_spaces_leading
@@ -810,6 +934,7 @@ impl From<&ClipboardConfig> for PartialClipboardConfig {
         Self {
             use_osc52: Some(cfg.use_osc52),
             use_system_clipboard: Some(cfg.use_system_clipboard),
+            osc52_max_bytes: Some(cfg.osc52_max_bytes),
         }
     }
 }
@@ -821,6 +946,7 @@ impl PartialClipboardConfig {
             use_system_clipboard: self
                 .use_system_clipboard
                 .unwrap_or(defaults.use_system_clipboard),
+            osc52_max_bytes: self.osc52_max_bytes.unwrap_or(defaults.osc52_max_bytes),
         }
     }
 }
@@ -830,6 +956,7 @@ impl From<&TerminalConfig> for PartialTerminalConfig {
         Self {
             jump_to_end_on_output: Some(cfg.jump_to_end_on_output),
             shell: cfg.shell.clone(),
+            restore_scrollback_lines: Some(cfg.restore_scrollback_lines),
         }
     }
 }
@@ -841,6 +968,9 @@ impl PartialTerminalConfig {
                 .jump_to_end_on_output
                 .unwrap_or(defaults.jump_to_end_on_output),
             shell: self.shell.or_else(|| defaults.shell.clone()),
+            restore_scrollback_lines: self
+                .restore_scrollback_lines
+                .unwrap_or(defaults.restore_scrollback_lines),
         }
     }
 }
@@ -887,6 +1017,7 @@ impl From<&PluginConfig> for PartialPluginConfig {
         Self {
             enabled: Some(cfg.enabled),
             path: cfg.path.clone(),
+            permissions: Some(cfg.permissions.clone()),
         }
     }
 }
@@ -896,6 +1027,9 @@ impl PartialPluginConfig {
         PluginConfig {
             enabled: self.enabled.unwrap_or(defaults.enabled),
             path: self.path.or_else(|| defaults.path.clone()),
+            permissions: self
+                .permissions
+                .unwrap_or_else(|| defaults.permissions.clone()),
         }
     }
 }
@@ -918,10 +1052,13 @@ impl From<&LanguageConfig> for PartialLanguageConfig {
             page_width: Some(cfg.page_width),
             use_tabs: cfg.use_tabs,
             tab_size: cfg.tab_size,
+            indent_style: cfg.indent_style,
+            indent_width: cfg.indent_width,
             formatter: cfg.formatter.clone(),
             format_on_save: Some(cfg.format_on_save),
             on_save: Some(cfg.on_save.clone()),
             word_characters: Some(cfg.word_characters.clone()),
+            run_command: Some(cfg.run_command.clone()),
         }
     }
 }
@@ -952,12 +1089,17 @@ impl PartialLanguageConfig {
             page_width: self.page_width.unwrap_or(defaults.page_width),
             use_tabs: self.use_tabs.or(defaults.use_tabs),
             tab_size: self.tab_size.or(defaults.tab_size),
+            indent_style: self.indent_style.or(defaults.indent_style),
+            indent_width: self.indent_width.or(defaults.indent_width),
             formatter: self.formatter.or_else(|| defaults.formatter.clone()),
             format_on_save: self.format_on_save.unwrap_or(defaults.format_on_save),
             on_save: self.on_save.unwrap_or_else(|| defaults.on_save.clone()),
             word_characters: self
                 .word_characters
                 .unwrap_or_else(|| defaults.word_characters.clone()),
+            run_command: self
+                .run_command
+                .unwrap_or_else(|| defaults.run_command.clone()),
         }
     }
 }
@@ -1016,6 +1158,7 @@ impl From<&crate::config::Config> for PartialConfig {
                     .collect(),
             ),
             warnings: Some(PartialWarningsConfig::from(&cfg.warnings)),
+            commands: Some(cfg.commands.clone()),
             // Only include plugins that differ from defaults
             // Path is auto-discovered at runtime and should never be saved
             plugins: {
@@ -1023,13 +1166,17 @@ impl From<&crate::config::Config> for PartialConfig {
                 let non_default_plugins: HashMap<String, PartialPluginConfig> = cfg
                     .plugins
                     .iter()
-                    .filter(|(_, v)| v.enabled != default_plugin.enabled)
+                    .filter(|(_, v)| {
+                        v.enabled != default_plugin.enabled || !v.permissions.is_empty()
+                    })
                     .map(|(k, v)| {
                         (
                             k.clone(),
                             PartialPluginConfig {
                                 enabled: Some(v.enabled),
                                 path: None, // Don't save path - it's auto-discovered
+                                permissions: (!v.permissions.is_empty())
+                                    .then(|| v.permissions.clone()),
                             },
                         )
                     })
@@ -1191,6 +1338,7 @@ impl PartialConfig {
                 .warnings
                 .map(|e| e.resolve(&defaults.warnings))
                 .unwrap_or_else(|| defaults.warnings.clone()),
+            commands: self.commands.unwrap_or_else(|| defaults.commands.clone()),
             plugins,
             packages: self
                 .packages
@@ -1219,10 +1367,13 @@ impl Default for LanguageConfig {
             page_width: None,
             use_tabs: None,
             tab_size: None,
+            indent_style: None,
+            indent_width: None,
             formatter: None,
             format_on_save: false,
             on_save: Vec::new(),
             word_characters: None,
+            run_command: None,
         }
     }
 }
@@ -1529,6 +1680,7 @@ mod tests {
             PluginConfig {
                 enabled: true, // Default value
                 path: Some(std::path::PathBuf::from("/path/to/plugin.ts")),
+                permissions: Vec::new(),
             },
         );
 
@@ -1550,6 +1702,7 @@ mod tests {
             PluginConfig {
                 enabled: true,
                 path: Some(std::path::PathBuf::from("/path/to/enabled.ts")),
+                permissions: Vec::new(),
             },
         );
         config.plugins.insert(
@@ -1557,6 +1710,7 @@ mod tests {
             PluginConfig {
                 enabled: false, // Not default!
                 path: Some(std::path::PathBuf::from("/path/to/disabled.ts")),
+                permissions: Vec::new(),
             },
         );
 
@@ -1580,6 +1734,29 @@ mod tests {
         assert!(disabled.path.is_none(), "Path should not be serialized");
     }
 
+    #[test]
+    fn plugin_permissions_are_serialized() {
+        // Granted permissions are user choices and must survive a save
+        let mut config = crate::config::Config::default();
+        config.plugins.insert(
+            "trusted_plugin".to_string(),
+            PluginConfig {
+                enabled: true,
+                path: Some(std::path::PathBuf::from("/path/to/trusted.ts")),
+                permissions: vec![fresh_core::config::PluginPermission::Process],
+            },
+        );
+
+        let partial = PartialConfig::from(&config);
+        let plugins = partial.plugins.unwrap();
+        let plugin = plugins.get("trusted_plugin").unwrap();
+        assert_eq!(
+            plugin.permissions,
+            Some(vec![fresh_core::config::PluginPermission::Process])
+        );
+        assert!(plugin.path.is_none());
+    }
+
     #[test]
     fn plugin_path_never_serialized() {
         // Even for disabled plugins, path should never be serialized
@@ -1589,6 +1766,7 @@ mod tests {
             PluginConfig {
                 enabled: false,
                 path: Some(std::path::PathBuf::from("/some/path/plugin.ts")),
+                permissions: Vec::new(),
             },
         );
 
@@ -1611,6 +1789,7 @@ mod tests {
                 PartialPluginConfig {
                     enabled: Some(false),
                     path: None,
+                    permissions: None,
                 },
             )])),
             ..Default::default()
@@ -1639,6 +1818,7 @@ mod tests {
                 PartialPluginConfig {
                     enabled: Some(false), // User disabled
                     path: None,
+                    permissions: None,
                 },
             )])),
             ..Default::default()
@@ -1650,6 +1830,7 @@ mod tests {
                 PartialPluginConfig {
                     enabled: Some(true), // Lower layer has it enabled
                     path: None,
+                    permissions: None,
                 },
             )])),
             ..Default::default()
@@ -1676,6 +1857,7 @@ mod tests {
             PluginConfig {
                 enabled: true,
                 path: Some(std::path::PathBuf::from("/a.ts")),
+                permissions: Vec::new(),
             },
         );
         config.plugins.insert(
@@ -1683,6 +1865,7 @@ mod tests {
             PluginConfig {
                 enabled: false,
                 path: Some(std::path::PathBuf::from("/b.ts")),
+                permissions: Vec::new(),
             },
         );
         config.plugins.insert(
@@ -1690,6 +1873,7 @@ mod tests {
             PluginConfig {
                 enabled: true,
                 path: Some(std::path::PathBuf::from("/c.ts")),
+                permissions: Vec::new(),
             },
         );
 
@@ -908,10 +908,13 @@ mod tests {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
         registry.apply_language_config(&languages);
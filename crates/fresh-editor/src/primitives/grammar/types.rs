@@ -1689,10 +1689,13 @@ mod tests {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
         registry.apply_language_config(&languages);
@@ -1733,10 +1736,13 @@ mod tests {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
         registry.apply_language_config(&languages);
@@ -1780,10 +1786,13 @@ mod tests {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -1806,10 +1815,13 @@ mod tests {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -2056,10 +2068,13 @@ mod tests {
             page_width: None,
             use_tabs: None,
             tab_size: None,
+            indent_style: None,
+            indent_width: None,
             formatter: None,
             format_on_save: false,
             on_save: vec![],
             word_characters: None,
+            run_command: None,
         }
     }
 
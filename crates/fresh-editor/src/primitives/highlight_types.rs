@@ -73,6 +73,26 @@ impl HighlightCategory {
             Self::Variable => "Variable",
         }
     }
+
+    /// Get the scope name used for theme color overrides (e.g. "keyword",
+    /// "punctuation.bracket").
+    pub fn scope_name(&self) -> &'static str {
+        match self {
+            Self::Attribute => "attribute",
+            Self::Comment => "comment",
+            Self::Constant => "constant",
+            Self::Function => "function",
+            Self::Keyword => "keyword",
+            Self::Number => "number",
+            Self::Operator => "operator",
+            Self::PunctuationBracket => "punctuation.bracket",
+            Self::PunctuationDelimiter => "punctuation.delimiter",
+            Self::Property => "property",
+            Self::String => "string",
+            Self::Type => "type",
+            Self::Variable => "variable",
+        }
+    }
 }
 
 /// Get the color for a highlight category from the theme.
@@ -1224,11 +1224,74 @@ impl Default for IndentCalculator {
     }
 }
 
+/// Rewrite the leading whitespace of every line in `content` using tabs
+/// (`use_tabs`) or spaces, preserving each line's visual indent width.
+///
+/// Tabs in the existing indentation advance to the next multiple of
+/// `tab_size`. When converting to tabs, a width that isn't a multiple of
+/// `tab_size` keeps the remainder as spaces. Line endings and everything
+/// after the first non-whitespace character are left untouched.
+pub fn retab_leading_whitespace(content: &str, use_tabs: bool, tab_size: usize) -> String {
+    let tab_size = tab_size.max(1);
+    let mut result = String::with_capacity(content.len());
+    for line in content.split_inclusive('\n') {
+        let ws_len = line
+            .bytes()
+            .take_while(|&b| b == b' ' || b == b'\t')
+            .count();
+        let (leading, rest) = line.split_at(ws_len);
+        // Whitespace-only lines: don't invent indentation on blank lines
+        // and keep the line ending intact.
+        if rest.is_empty() || rest == "\n" || rest == "\r\n" {
+            result.push_str(line);
+            continue;
+        }
+        let width = leading.bytes().fold(0, |width, b| match b {
+            b'\t' => (width / tab_size + 1) * tab_size,
+            _ => width + 1,
+        });
+        if use_tabs {
+            result.push_str(&"\t".repeat(width / tab_size));
+            result.push_str(&" ".repeat(width % tab_size));
+        } else {
+            result.push_str(&" ".repeat(width));
+        }
+        result.push_str(rest);
+    }
+    result
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::model::buffer::Buffer;
 
+    #[test]
+    fn test_retab_spaces_to_tabs() {
+        let input = "fn main() {\n    let x = 1;\n        if x {\n  odd\n}\n";
+        assert_eq!(
+            retab_leading_whitespace(input, true, 4),
+            "fn main() {\n\tlet x = 1;\n\t\tif x {\n  odd\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_retab_mixed_to_spaces() {
+        // A tab after two spaces advances to the next tab stop (column 4)
+        let input = "\tone\n  \ttwo\r\n\t    three\n";
+        assert_eq!(
+            retab_leading_whitespace(input, false, 4),
+            "    one\n    two\r\n        three\n"
+        );
+    }
+
+    #[test]
+    fn test_retab_leaves_blank_lines_alone() {
+        let input = "a\n    \n\t\nb";
+        assert_eq!(retab_leading_whitespace(input, true, 4), input);
+        assert_eq!(retab_leading_whitespace(input, false, 4), input);
+    }
+
     #[test]
     fn test_current_and_previous_line_indent() {
         let buffer = Buffer::from_str_test("fn main() {\n    let x = 1;");
@@ -0,0 +1,151 @@
+//! Guess a file's indentation from its content.
+//!
+//! The leading whitespace of the first non-blank lines is sampled: whether
+//! tabs or spaces indent more lines decides the style, and for spaces the
+//! most common step between a line and a more indented next line decides
+//! the width. Steps of one column are ignored as alignment rather than
+//! indentation, and a width seen only once is not trusted.
+
+use std::cmp::Reverse;
+
+/// Non-blank lines sampled from the start of a file.
+pub const SAMPLE_LINES: usize = 1000;
+
+/// Widest indent step considered.
+const MAX_WIDTH: usize = 8;
+
+/// Times a step must occur before it is taken as the indent width.
+const MIN_STEPS: usize = 2;
+
+/// Indentation found in a file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DetectedIndent {
+    /// Whether lines are indented with tabs rather than spaces
+    pub use_tabs: bool,
+    /// Columns per indent level, when indented with spaces and a
+    /// consistent step was found
+    pub width: Option<usize>,
+}
+
+/// Detect the indentation of `lines`, or `None` when no sampled line is
+/// indented.
+pub fn detect_indent<I, S>(lines: I) -> Option<DetectedIndent>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let mut tab_lines = 0usize;
+    let mut space_lines = 0usize;
+    // steps[n] counts lines indented n columns deeper than the line before
+    let mut steps = [0usize; MAX_WIDTH + 1];
+    let mut previous = 0usize;
+
+    let non_blank = lines
+        .into_iter()
+        .filter(|line| !line.as_ref().trim().is_empty())
+        .take(SAMPLE_LINES);
+    for line in non_blank {
+        let line = line.as_ref();
+        let rest = line.trim_start_matches([' ', '\t']);
+        let leading = &line[..line.len() - rest.len()];
+        if leading.starts_with('\t') {
+            tab_lines += 1;
+            previous = 0;
+            continue;
+        }
+        // Spaces followed by a tab, and the ` * ` continuation lines of
+        // block comments, say nothing about the indent step
+        if leading.contains('\t') || rest.starts_with('*') {
+            continue;
+        }
+        let indent = leading.len();
+        if indent > 0 {
+            space_lines += 1;
+        }
+        if indent > previous && indent - previous <= MAX_WIDTH {
+            steps[indent - previous] += 1;
+        }
+        previous = indent;
+    }
+
+    if tab_lines == 0 && space_lines == 0 {
+        return None;
+    }
+    if tab_lines > space_lines {
+        return Some(DetectedIndent {
+            use_tabs: true,
+            width: None,
+        });
+    }
+    let width = (2..=MAX_WIDTH)
+        .filter(|&width| steps[width] >= MIN_STEPS)
+        .max_by_key(|&width| (steps[width], Reverse(width)));
+    Some(DetectedIndent {
+        use_tabs: false,
+        width,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn detect(text: &str) -> Option<DetectedIndent> {
+        detect_indent(text.lines())
+    }
+
+    #[test]
+    fn detects_space_width() {
+        let two = "fn a() {\n  if x {\n    y();\n  }\n}\n";
+        assert_eq!(
+            detect(two),
+            Some(DetectedIndent {
+                use_tabs: false,
+                width: Some(2)
+            })
+        );
+        let four = "def a():\n    if x:\n        y()\n\n    return 1\n";
+        assert_eq!(
+            detect(four),
+            Some(DetectedIndent {
+                use_tabs: false,
+                width: Some(4)
+            })
+        );
+    }
+
+    #[test]
+    fn detects_tabs() {
+        assert_eq!(
+            detect("func a() {\n\tif x {\n\t\ty()\n\t}\n}\n"),
+            Some(DetectedIndent {
+                use_tabs: true,
+                width: None
+            })
+        );
+    }
+
+    #[test]
+    fn ignores_alignment_and_comment_continuations() {
+        let text =
+            "/**\n * Doc\n */\nfn a() {\n    b(1,\n      2);\n    if c {\n        d();\n    }\n}\n";
+        assert_eq!(detect(text).and_then(|d| d.width), Some(4));
+    }
+
+    #[test]
+    fn a_single_step_sets_no_width() {
+        assert_eq!(
+            detect("      x\n"),
+            Some(DetectedIndent {
+                use_tabs: false,
+                width: None
+            })
+        );
+    }
+
+    #[test]
+    fn unindented_text_detects_nothing() {
+        assert_eq!(detect("one\ntwo\n\nthree\n"), None);
+        assert_eq!(detect(""), None);
+    }
+}
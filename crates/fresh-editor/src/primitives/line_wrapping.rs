@@ -16,6 +16,8 @@
 //!
 //! See `docs/internal/line-wrap-cache-plan.md` for the design.
 
+use crate::config::WrapMode;
+
 /// Configuration for line wrapping geometry.
 ///
 /// Carries the widths that callers feed into the renderer's wrap.
@@ -38,6 +40,10 @@ pub struct WrapConfig {
     /// Whether continuation lines should visually align with the
     /// first line's leading whitespace (hanging indent).
     pub hanging_indent: bool,
+    /// Whether rows break at word boundaries or at the exact column.
+    pub wrap_mode: WrapMode,
+    /// Columns added to the hanging indent of continuation lines.
+    pub wrap_indent_extra: usize,
 }
 
 impl WrapConfig {
@@ -52,11 +58,16 @@ impl WrapConfig {
     ///   vertical scrollbar.
     /// * `hanging_indent` — whether continuation lines align to the
     ///   first line's leading whitespace.
+    /// * `wrap_mode` — whether rows break at word boundaries or at the
+    ///   exact column.
+    /// * `wrap_indent_extra` — columns added to the hanging indent.
     pub fn new(
         content_area_width: usize,
         gutter_width: usize,
         has_scrollbar: bool,
         hanging_indent: bool,
+        wrap_mode: WrapMode,
+        wrap_indent_extra: usize,
     ) -> Self {
         let scrollbar_width = usize::from(has_scrollbar);
         let text_area_width = content_area_width
@@ -68,6 +79,8 @@ impl WrapConfig {
             continuation_line_width: text_area_width,
             gutter_width,
             hanging_indent,
+            wrap_mode,
+            wrap_indent_extra,
         }
     }
 
@@ -78,6 +91,8 @@ impl WrapConfig {
             continuation_line_width: usize::MAX,
             gutter_width,
             hanging_indent: false,
+            wrap_mode: WrapMode::Word,
+            wrap_indent_extra: 0,
         }
     }
 }
@@ -88,7 +103,7 @@ mod tests {
 
     #[test]
     fn new_subtracts_scrollbar_and_gutter() {
-        let cfg = WrapConfig::new(100, 6, true, false);
+        let cfg = WrapConfig::new(100, 6, true, false, WrapMode::Word, 0);
         assert_eq!(cfg.first_line_width, 100 - 1 - 6);
         assert_eq!(cfg.continuation_line_width, 100 - 1 - 6);
         assert_eq!(cfg.gutter_width, 6);
@@ -97,7 +112,7 @@ mod tests {
 
     #[test]
     fn new_without_scrollbar_omits_its_column() {
-        let cfg = WrapConfig::new(100, 6, false, true);
+        let cfg = WrapConfig::new(100, 6, false, true, WrapMode::Word, 0);
         assert_eq!(cfg.first_line_width, 100 - 6);
         assert!(cfg.hanging_indent);
     }
@@ -105,7 +120,7 @@ mod tests {
     #[test]
     fn new_clamps_to_zero_on_oversize_deductions() {
         // Saturating — no underflow.
-        let cfg = WrapConfig::new(3, 6, true, false);
+        let cfg = WrapConfig::new(3, 6, true, false, WrapMode::Word, 0);
         assert_eq!(cfg.first_line_width, 0);
     }
 
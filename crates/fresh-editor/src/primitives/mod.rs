@@ -18,6 +18,7 @@
 pub mod display_width;
 pub mod glob_match;
 pub mod grapheme;
+pub mod indent_detection;
 pub mod line_wrapping;
 pub mod path_utils;
 pub mod snippet;
@@ -298,6 +298,30 @@ pub fn find_word_end(buffer: &Buffer, pos: usize) -> usize {
     start + current_idx
 }
 
+/// Find the end of the word before the given position, skipping any
+/// non-word characters to the left. Returns `pos` when it already ends a
+/// word, and 0 when there is no earlier word.
+pub fn find_prev_word_end(buffer: &Buffer, pos: usize) -> usize {
+    let actual_pos = pos.min(buffer.len());
+    let start = actual_pos.saturating_sub(1000);
+    let bytes = buffer.slice_bytes(start..actual_pos);
+    let text = String::from_utf8_lossy(&bytes);
+
+    let mut current_idx = text.len();
+    while current_idx > 0 {
+        let prev = prev_grapheme_boundary(&text, current_idx);
+        if get_grapheme_class(&text[prev..current_idx]) == CharClass::Word {
+            break;
+        }
+        current_idx = prev;
+    }
+
+    if current_idx == 0 {
+        return start;
+    }
+    actual_pos.saturating_sub(text.len() - current_idx)
+}
+
 /// Find the start of the word to the left of the given position
 pub fn find_word_start_left(buffer: &Buffer, pos: usize) -> usize {
     if pos == 0 {
@@ -625,6 +649,15 @@ mod tests {
         assert_eq!(find_word_end(&buffer, 6), 11); // End of "world"
     }
 
+    #[test]
+    fn test_find_prev_word_end() {
+        let buffer = Buffer::from_str_test("hello, world test");
+        assert_eq!(find_prev_word_end(&buffer, 7), 5); // Skips ", " back to "hello"
+        assert_eq!(find_prev_word_end(&buffer, 12), 12); // Already ends "world"
+        assert_eq!(find_prev_word_end(&buffer, 3), 3); // Inside "hello"
+        assert_eq!(find_prev_word_end(&buffer, 0), 0);
+    }
+
     #[test]
     fn test_find_word_start_left() {
         let buffer = Buffer::from_str_test("hello world test");
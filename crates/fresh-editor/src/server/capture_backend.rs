@@ -3,10 +3,12 @@
 //! Instead of writing to a terminal, this backend captures all output
 //! to a buffer that can be sent to clients.
 
+use crate::view::color_support::{convert_buffer_colors, ColorCapability};
 use ratatui::backend::{Backend, ClearType, WindowSize};
-use ratatui::buffer::Cell;
+use ratatui::buffer::{Buffer, Cell};
 use ratatui::layout::{Position, Size};
 use ratatui::style::{Color, Modifier};
+use ratatui::Terminal;
 use std::io::{self, Write};
 
 /// A backend that captures output to a buffer
@@ -59,6 +61,11 @@ impl CaptureBackend {
         self.size = Size::new(cols, rows);
     }
 
+    /// The hardware cursor position, if the last frame left it visible
+    pub fn visible_cursor(&self) -> Option<Position> {
+        self.cursor_visible.then_some(self.cursor)
+    }
+
     /// Reset style state to force full output on next draw
     /// Call this when a new client connects to ensure they get a complete frame
     pub fn reset_style_state(&mut self) {
@@ -323,6 +330,30 @@ impl Backend for CaptureBackend {
     }
 }
 
+/// Redraw an already rendered `frame` through `terminal` with its colors
+/// downsampled for `capability`, returning the captured output.
+///
+/// Each capability gets its own terminal so the diff against the previous
+/// frame is taken on the converted colors, letting one editor render serve
+/// clients with different color support.
+pub fn draw_downsampled(
+    terminal: &mut Terminal<CaptureBackend>,
+    frame: &Buffer,
+    cursor: Option<Position>,
+    capability: ColorCapability,
+) -> io::Result<Vec<u8>> {
+    let mut converted = frame.clone();
+    convert_buffer_colors(&mut converted, capability);
+    terminal.draw(|f| {
+        converted.resize(f.area());
+        *f.buffer_mut() = converted;
+        if let Some(position) = cursor {
+            f.set_cursor_position(position);
+        }
+    })?;
+    Ok(terminal.backend_mut().take_buffer())
+}
+
 /// Generate terminal setup sequences
 ///
 /// Uses shared constants from `terminal_modes::sequences` to stay in sync
@@ -340,19 +371,7 @@ pub fn terminal_setup_sequences(mouse_hover_enabled: bool) -> Vec<u8> {
     // Enter alternate screen
     buf.extend_from_slice(seq::ENTER_ALTERNATE_SCREEN);
     // Enable mouse tracking (SGR format)
-    buf.extend_from_slice(seq::ENABLE_MOUSE_CLICK);
-    buf.extend_from_slice(seq::ENABLE_MOUSE_DRAG);
-    // On Windows, only enable all-motion tracking (mode 1003) when hover is
-    // enabled. Mode 1003 generates extreme event volume that can cause input
-    // corruption on Windows. On other platforms, always enable it.
-    if cfg!(windows) {
-        if mouse_hover_enabled {
-            buf.extend_from_slice(seq::ENABLE_MOUSE_MOTION);
-        }
-    } else {
-        buf.extend_from_slice(seq::ENABLE_MOUSE_MOTION);
-    }
-    buf.extend_from_slice(seq::ENABLE_SGR_MOUSE);
+    buf.extend_from_slice(&mouse_tracking_sequences(true, mouse_hover_enabled));
     // Enable focus events
     buf.extend_from_slice(seq::ENABLE_FOCUS_EVENTS);
     // Enable bracketed paste
@@ -363,6 +382,35 @@ pub fn terminal_setup_sequences(mouse_hover_enabled: bool) -> Vec<u8> {
     buf
 }
 
+/// Generate the sequences that turn mouse reporting (SGR format) on or off
+///
+/// Sent on their own when mouse capture is toggled at runtime, so the
+/// client's terminal hands mouse events back to its native selection.
+/// `mouse_hover_enabled` only matters when enabling on Windows; see
+/// [`terminal_setup_sequences`].
+pub fn mouse_tracking_sequences(enabled: bool, mouse_hover_enabled: bool) -> Vec<u8> {
+    use crate::services::terminal_modes::sequences as seq;
+
+    let mut buf = Vec::new();
+    if enabled {
+        buf.extend_from_slice(seq::ENABLE_MOUSE_CLICK);
+        buf.extend_from_slice(seq::ENABLE_MOUSE_DRAG);
+        // On Windows, only enable all-motion tracking (mode 1003) when hover
+        // is enabled. Mode 1003 generates extreme event volume that can cause
+        // input corruption on Windows. On other platforms, always enable it.
+        if !cfg!(windows) || mouse_hover_enabled {
+            buf.extend_from_slice(seq::ENABLE_MOUSE_MOTION);
+        }
+        buf.extend_from_slice(seq::ENABLE_SGR_MOUSE);
+    } else {
+        buf.extend_from_slice(seq::DISABLE_SGR_MOUSE);
+        buf.extend_from_slice(seq::DISABLE_MOUSE_MOTION);
+        buf.extend_from_slice(seq::DISABLE_MOUSE_DRAG);
+        buf.extend_from_slice(seq::DISABLE_MOUSE_CLICK);
+    }
+    buf
+}
+
 /// Generate terminal teardown sequences
 ///
 /// Uses shared constants from `terminal_modes::sequences` to stay in sync
@@ -381,10 +429,7 @@ pub fn terminal_teardown_sequences() -> Vec<u8> {
     // Disable focus events
     buf.extend_from_slice(seq::DISABLE_FOCUS_EVENTS);
     // Disable mouse tracking
-    buf.extend_from_slice(seq::DISABLE_SGR_MOUSE);
-    buf.extend_from_slice(seq::DISABLE_MOUSE_MOTION);
-    buf.extend_from_slice(seq::DISABLE_MOUSE_DRAG);
-    buf.extend_from_slice(seq::DISABLE_MOUSE_CLICK);
+    buf.extend_from_slice(&mouse_tracking_sequences(false, false));
     // Reset attributes
     buf.extend_from_slice(seq::RESET_ATTRIBUTES);
     // Leave alternate screen
@@ -510,4 +555,33 @@ mod tests {
         backend.clear_region(ClearType::CurrentLine).unwrap();
         assert!(backend.take_buffer().ends_with(b"\x1b[2K"));
     }
+
+    #[test]
+    fn test_draw_downsampled_converts_theme_colors_per_capability() {
+        let theme = crate::view::theme::Theme::load_builtin(crate::view::theme::THEME_DARK)
+            .expect("dark theme is builtin");
+        assert_eq!(theme.syntax_keyword, Color::Rgb(86, 156, 214));
+
+        let mut frame = Buffer::empty(ratatui::layout::Rect::new(0, 0, 5, 1));
+        frame.set_string(0, 0, "fn", Style::default().fg(theme.syntax_keyword));
+
+        let draw = |capability| {
+            let mut terminal = Terminal::new(CaptureBackend::new(5, 1)).unwrap();
+            let output = draw_downsampled(&mut terminal, &frame, None, capability).unwrap();
+            String::from_utf8_lossy(&output).into_owned()
+        };
+
+        // Truecolor clients get the theme color as-is
+        assert!(draw(ColorCapability::TrueColor).contains("\x1b[38;2;86;156;214mfn"));
+
+        // A 16-color client gets the nearest ANSI color (bright cyan)
+        let basic = draw(ColorCapability::Color16);
+        assert!(basic.contains("\x1b[96mfn"), "output: {:?}", basic);
+        assert!(!basic.contains("38;2;"));
+
+        // NO_COLOR clients get no color at all
+        let mono = draw(ColorCapability::Monochrome);
+        assert!(mono.contains("fn"));
+        assert!(!mono.contains("38;"));
+    }
 }
@@ -94,3 +94,12 @@ pub fn is_process_running(pid: u32) -> bool {
     // Send signal 0 to check if process exists
     unsafe { libc::kill(pid as i32, 0) == 0 }
 }
+
+/// Ask the process with the given PID to terminate (SIGTERM)
+pub fn terminate_process(pid: u32) -> io::Result<()> {
+    if unsafe { libc::kill(pid as i32, libc::SIGTERM) } == 0 {
+        Ok(())
+    } else {
+        Err(io::Error::last_os_error())
+    }
+}
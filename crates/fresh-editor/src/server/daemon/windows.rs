@@ -6,7 +6,8 @@ use std::path::PathBuf;
 
 use windows_sys::Win32::Foundation::{CloseHandle, STILL_ACTIVE};
 use windows_sys::Win32::System::Threading::{
-    GetExitCodeProcess, OpenProcess, PROCESS_QUERY_LIMITED_INFORMATION,
+    GetExitCodeProcess, OpenProcess, TerminateProcess, PROCESS_QUERY_LIMITED_INFORMATION,
+    PROCESS_TERMINATE,
 };
 
 const DETACHED_PROCESS: u32 = 0x00000008;
@@ -82,3 +83,22 @@ pub fn is_process_running(pid: u32) -> bool {
         result != 0 && exit_code == STILL_ACTIVE as u32
     }
 }
+
+/// Terminate the process with the given PID
+pub fn terminate_process(pid: u32) -> io::Result<()> {
+    unsafe {
+        let handle = OpenProcess(PROCESS_TERMINATE, 0, pid);
+        if handle.is_null() {
+            return Err(io::Error::last_os_error());
+        }
+
+        let result = TerminateProcess(handle, 1);
+        CloseHandle(handle);
+
+        if result == 0 {
+            Err(io::Error::last_os_error())
+        } else {
+            Ok(())
+        }
+    }
+}
@@ -15,21 +15,27 @@ use std::time::{Duration, Instant};
 use crossterm::event::{Event, KeyEventKind};
 use ratatui::Terminal;
 
+use crate::app::client_view::ClientView;
 use crate::app::Editor;
 use crate::config::Config;
 use crate::config_io::DirectoryContext;
 // Filesystem is now owned by `self.current_authority`; the server no
 // longer constructs a `StdFileSystem` directly.
 use crate::server::capture_backend::{
-    terminal_setup_sequences, terminal_teardown_sequences, CaptureBackend,
+    draw_downsampled, mouse_tracking_sequences, terminal_setup_sequences,
+    terminal_teardown_sequences, CaptureBackend,
 };
 use crate::server::input_parser::InputParser;
 use crate::server::ipc::{ServerConnection, ServerListener, SocketPaths, StreamWrapper};
 use crate::server::protocol::{
-    ClientControl, ServerControl, ServerHello, TermSize, VersionMismatch, PROTOCOL_VERSION,
+    ClientControl, ServerControl, ServerHello, SessionInfo, TermSize, VersionMismatch,
+    PROTOCOL_VERSION,
 };
 use crate::view::color_support::ColorCapability;
 
+/// How long a dropped client's view is kept for it to resume on reconnect
+const CLIENT_RESUME_GRACE: Duration = Duration::from_secs(5 * 60);
+
 /// Configuration for the editor server
 pub struct EditorServerConfig {
     /// Working directory for this session
@@ -69,6 +75,9 @@ pub struct EditorServer {
     clients: Vec<ConnectedClient>,
     editor: Option<Editor>,
     terminal: Option<Terminal<CaptureBackend>>,
+    /// Per-capability terminals re-encoding each frame for clients with
+    /// limited color support (the editor itself renders in truecolor)
+    downsampled_terminals: std::collections::HashMap<ColorCapability, Terminal<CaptureBackend>>,
     last_client_activity: Instant,
     shutdown: Arc<AtomicBool>,
     /// Effective terminal size (from the primary/first client)
@@ -79,6 +88,9 @@ pub struct EditorServer {
     next_wait_id: u64,
     /// Maps wait_id → client_id for clients waiting on file events
     waiting_clients: std::collections::HashMap<u64, u64>,
+    /// Views of clients that dropped, keyed by their session token, with
+    /// when they dropped
+    detached_views: std::collections::HashMap<String, (ClientView, Instant)>,
     /// Current authority. Carried across editor rebuilds so plugin-
     /// installed authorities (e.g. a devcontainer attach) survive the
     /// restart-based transition: the old editor is dropped, a new one
@@ -157,13 +169,16 @@ struct ConnectedClient {
     /// Background writer for non-blocking data output
     data_writer: ClientDataWriter,
     term_size: TermSize,
-    env: std::collections::HashMap<String, Option<String>>,
+    /// Color support detected from the client's environment
+    color_capability: ColorCapability,
     id: u64,
     input_parser: InputParser,
     /// Whether this client needs a full screen render on next frame
     needs_full_render: bool,
     /// If set, this client is waiting for a --wait completion signal
     wait_id: Option<u64>,
+    /// Token the client sent to resume its view after reconnecting
+    session_token: Option<String>,
 }
 
 impl EditorServer {
@@ -197,12 +212,14 @@ impl EditorServer {
             clients: Vec::new(),
             editor: None,
             terminal: None,
+            downsampled_terminals: std::collections::HashMap::new(),
             last_client_activity: Instant::now(),
             shutdown: Arc::new(AtomicBool::new(false)),
             term_size: TermSize::new(80, 24), // Default until first client connects
             last_input_client: None,
             next_wait_id: 1,
             waiting_clients: std::collections::HashMap::new(),
+            detached_views: std::collections::HashMap::new(),
             current_authority,
             session_keepalive,
         })
@@ -252,6 +269,10 @@ impl EditorServer {
                 }
             }
 
+            // Forget the views of dropped clients that didn't come back
+            self.detached_views
+                .retain(|_, (_, dropped_at)| dropped_at.elapsed() <= CLIENT_RESUME_GRACE);
+
             // Accept new connections
             tracing::debug!("[server] main loop: calling accept()");
             match self.listener.accept() {
@@ -263,7 +284,10 @@ impl EditorServer {
                         .map(|e| e.config().editor.cursor_style)
                         .unwrap_or(self.config.editor_config.editor.cursor_style);
                     match self.handle_new_connection(conn, next_client_id, cursor_style) {
-                        Ok(client) => {
+                        Ok(None) => {
+                            tracing::debug!("Answered session info query");
+                        }
+                        Ok(Some(client)) => {
                             tracing::info!("Client {} connected", client.id);
 
                             // Initialize editor on first-ever client, or update size if reconnecting
@@ -280,6 +304,9 @@ impl EditorServer {
                             }
                             // Note: full redraw is handled via client.needs_full_render flag
 
+                            if let Some(token) = &client.session_token {
+                                self.resume_client_view(token);
+                            }
                             self.clients.push(client);
                             self.last_client_activity = Instant::now();
                             next_client_id += 1;
@@ -458,6 +485,8 @@ impl EditorServer {
                         #[allow(clippy::let_underscore_must_use)]
                         let _ = client.conn.write_control(&msg);
                     }
+                    // The copy's OSC 52 sequence goes out with the next frame
+                    needs_render = true;
                 }
 
                 if editor.check_mouse_hover_timer() {
@@ -534,7 +563,8 @@ impl EditorServer {
         // construction so plugins and init.ts load against the correct
         // backend from the first tick.
         let filesystem = self.current_authority.filesystem.clone();
-        let color_capability = ColorCapability::TrueColor; // Assume truecolor for now
+        // Colors are downsampled per client at render time
+        let color_capability = ColorCapability::TrueColor;
 
         let mut editor = Editor::with_working_dir(
             self.config.editor_config.clone(),
@@ -664,6 +694,9 @@ impl EditorServer {
         // servers, and plugin threads.
         self.editor = None;
         self.terminal = None;
+        self.downsampled_terminals.clear();
+        // Saved views refer to the old editor's splits
+        self.detached_views.clear();
 
         // Apply the pending changes before building the next editor.
         if let Some(dir) = new_working_dir {
@@ -710,13 +743,39 @@ impl EditorServer {
         Ok(())
     }
 
+    /// Session identifier reported to clients
+    fn session_id(&self) -> String {
+        self.config
+            .session_name
+            .clone()
+            .unwrap_or_else(|| crate::workspace::encode_path_for_filename(&self.config.working_dir))
+    }
+
+    /// Metadata answering a session info query
+    fn session_info(&self) -> SessionInfo {
+        let mut info = SessionInfo::new(
+            self.session_id(),
+            &self.config.working_dir,
+            self.clients.len(),
+        );
+        info.modified_buffers = Some(
+            self.editor
+                .as_ref()
+                .map_or(0, |editor| editor.unsaved_buffer_count()),
+        );
+        info
+    }
+
     /// Handle a new client connection
+    ///
+    /// Returns `None` when the connection was a one-shot session info
+    /// query rather than a client attaching.
     fn handle_new_connection(
         &self,
         conn: ServerConnection,
         client_id: u64,
         cursor_style: crate::config::CursorStyle,
-    ) -> io::Result<ConnectedClient> {
+    ) -> io::Result<Option<ConnectedClient>> {
         // Read client hello
         // On Windows, don't toggle blocking mode - named pipes don't support mode switching
         // after connection. The read_control() method handles this internally.
@@ -731,6 +790,10 @@ impl EditorServer {
 
         let hello = match client_msg {
             ClientControl::Hello(h) => h,
+            ClientControl::SessionInfo => {
+                conn.write_control(&self.session_info().to_reply()?)?;
+                return Ok(None);
+            }
             _ => {
                 return Err(io::Error::other("Expected Hello message"));
             }
@@ -760,11 +823,7 @@ impl EditorServer {
         }
 
         // Send server hello
-        let session_id = self.config.session_name.clone().unwrap_or_else(|| {
-            crate::workspace::encode_path_for_filename(&self.config.working_dir)
-        });
-
-        let server_hello = ServerHello::new(session_id);
+        let server_hello = ServerHello::new(self.session_id());
         let response = serde_json::to_string(&ServerControl::Hello(server_hello))
             .map_err(|e| io::Error::other(e.to_string()))?;
         conn.write_control(&response)?;
@@ -776,33 +835,53 @@ impl EditorServer {
 
         // Send terminal setup sequences
         let mouse_hover_enabled = self.config.editor_config.editor.mouse_hover_enabled;
-        let setup = terminal_setup_sequences(mouse_hover_enabled);
+        let mut setup = terminal_setup_sequences(mouse_hover_enabled);
+        // Mouse capture may have been turned off from another client
+        if self.editor.as_ref().is_some_and(|e| !e.is_mouse_enabled()) {
+            setup.extend_from_slice(&mouse_tracking_sequences(false, mouse_hover_enabled));
+        }
         conn.write_data(&setup)?;
 
         // Send cursor style (from editor if running, otherwise from config)
         conn.write_data(cursor_style.to_escape_sequence())?;
 
+        let color_capability =
+            ColorCapability::from_env(|key| hello.env.get(key).cloned().flatten());
         tracing::debug!(
-            "Client {} connected: {}x{}, TERM={:?}",
+            "Client {} connected: {}x{}, TERM={:?}, colors={:?}",
             client_id,
             hello.term_size.cols,
             hello.term_size.rows,
-            hello.term()
+            hello.term(),
+            color_capability
         );
 
         // Create background writer for non-blocking render output
         let data_writer = ClientDataWriter::new(conn.data.clone(), client_id);
 
-        Ok(ConnectedClient {
+        Ok(Some(ConnectedClient {
             conn,
             data_writer,
             term_size: hello.term_size,
-            env: hello.env,
+            color_capability,
             id: client_id,
             input_parser: InputParser::new(),
             needs_full_render: true,
             wait_id: None,
-        })
+            session_token: hello.session_token,
+        }))
+    }
+
+    /// Restore the view a reconnecting client had when it dropped, if it
+    /// comes back within `CLIENT_RESUME_GRACE`; otherwise it starts fresh.
+    fn resume_client_view(&mut self, token: &str) {
+        let Some((view, _)) = self.detached_views.remove(token) else {
+            return;
+        };
+        if let Some(ref mut editor) = self.editor {
+            tracing::info!("Resuming view of reconnecting client");
+            editor.restore_client_view(&view);
+        }
     }
 
     /// Process messages from connected clients
@@ -942,6 +1021,14 @@ impl EditorServer {
                 ClientControl::Hello(_) => {
                     tracing::warn!("Unexpected Hello from client");
                 }
+                ClientControl::SessionInfo => {
+                    let reply = self.session_info().to_reply().unwrap_or_default();
+                    if let Some(client) = self.clients.get(idx) {
+                        // Best-effort reply
+                        #[allow(clippy::let_underscore_must_use)]
+                        let _ = client.conn.write_control(&reply);
+                    }
+                }
                 ClientControl::Resize { cols, rows } => {
                     if let Some(client) = self.clients.get_mut(idx) {
                         client.term_size = TermSize::new(cols, rows);
@@ -1038,6 +1125,11 @@ impl EditorServer {
                     editor.remove_wait_tracking(wait_id);
                 }
             }
+            // Keep the view so the client can resume it if it reconnects
+            if let (Some(token), Some(editor)) = (client.session_token.clone(), &self.editor) {
+                self.detached_views
+                    .insert(token, (editor.capture_client_view(), Instant::now()));
+            }
             // Best-effort teardown via the non-blocking writer
             let teardown = terminal_teardown_sequences();
             let _ = client.data_writer.try_write(&teardown);
@@ -1053,7 +1145,11 @@ impl EditorServer {
 
     /// Update terminal size after resize
     fn update_terminal_size(&mut self) -> io::Result<()> {
-        if let Some(ref mut terminal) = self.terminal {
+        let terminals = self
+            .terminal
+            .iter_mut()
+            .chain(self.downsampled_terminals.values_mut());
+        for terminal in terminals {
             let backend = terminal.backend_mut();
             backend.resize(self.term_size.cols, self.term_size.rows);
         }
@@ -1107,6 +1203,17 @@ impl EditorServer {
             return Ok(());
         };
 
+        // Drop terminals for capabilities no client uses any more, so a
+        // returning capability starts from a clean diff
+        let capabilities: std::collections::HashSet<ColorCapability> = self
+            .clients
+            .iter()
+            .map(|c| c.color_capability)
+            .filter(|&c| c != ColorCapability::TrueColor)
+            .collect();
+        self.downsampled_terminals
+            .retain(|capability, _| capabilities.contains(capability));
+
         // Check if any client needs a full render (e.g., newly connected)
         let any_needs_full = self.clients.iter().any(|c| c.needs_full_render);
         if any_needs_full {
@@ -1115,24 +1222,48 @@ impl EditorServer {
                 self.clients.iter().filter(|c| c.needs_full_render).count()
             );
             // Force full redraw by invalidating terminal state
-            terminal.backend_mut().reset_style_state();
-            // Best-effort terminal clear for full redraw
-            #[allow(clippy::let_underscore_must_use)]
-            let _ = terminal.clear();
+            let terminals =
+                std::iter::once(&mut *terminal).chain(self.downsampled_terminals.values_mut());
+            for terminal in terminals {
+                terminal.backend_mut().reset_style_state();
+                // Best-effort terminal clear for full redraw
+                #[allow(clippy::let_underscore_must_use)]
+                let _ = terminal.clear();
+            }
         }
 
         // Take any pending escape sequences (e.g., cursor style changes)
         let pending_sequences = editor.take_pending_escape_sequences();
 
         // Render to capture backend
-        terminal
+        let frame_buffer = terminal
             .draw(|frame| editor.render(frame))
-            .map_err(|e| io::Error::other(e.to_string()))?;
+            .map_err(|e| io::Error::other(e.to_string()))?
+            .buffer
+            .clone();
 
         // Get the captured output
-        let output = terminal.backend_mut().take_buffer();
+        let mut outputs = std::collections::HashMap::new();
+        outputs.insert(
+            ColorCapability::TrueColor,
+            terminal.backend_mut().take_buffer(),
+        );
 
-        if output.is_empty() && pending_sequences.is_empty() {
+        // Re-encode the frame for each limited color capability in use
+        let cursor = terminal.backend().visible_cursor();
+        for capability in capabilities {
+            let downsampled = match self.downsampled_terminals.entry(capability) {
+                std::collections::hash_map::Entry::Occupied(entry) => entry.into_mut(),
+                std::collections::hash_map::Entry::Vacant(entry) => {
+                    let backend = CaptureBackend::new(self.term_size.cols, self.term_size.rows);
+                    entry.insert(Terminal::new(backend)?)
+                }
+            };
+            let output = draw_downsampled(downsampled, &frame_buffer, cursor, capability)?;
+            outputs.insert(capability, output);
+        }
+
+        if outputs.values().all(|o| o.is_empty()) && pending_sequences.is_empty() {
             return Ok(());
         }
 
@@ -1141,6 +1272,7 @@ impl EditorServer {
             if client.wait_id.is_some() {
                 continue;
             }
+            let output = &outputs[&client.color_capability];
             // Combine pending sequences and output into a single frame
             let frame = if !pending_sequences.is_empty() && !output.is_empty() {
                 let mut combined = Vec::with_capacity(pending_sequences.len() + output.len());
@@ -1182,21 +1314,3 @@ impl EditorServer {
         Ok(())
     }
 }
-
-impl ConnectedClient {
-    /// Get the client's TERM environment variable
-    #[allow(dead_code)]
-    pub fn term(&self) -> Option<&str> {
-        self.env.get("TERM").and_then(|v| v.as_deref())
-    }
-
-    /// Check if the client supports truecolor
-    #[allow(dead_code)]
-    pub fn supports_truecolor(&self) -> bool {
-        self.env
-            .get("COLORTERM")
-            .and_then(|v| v.as_deref())
-            .map(|v| v == "truecolor" || v == "24bit")
-            .unwrap_or(false)
-    }
-}
@@ -478,9 +478,11 @@ impl InputParser {
             return ParseResult::Incomplete;
         }
 
+        // Each byte is value + 32, and coordinates are 1-indexed like SGR;
+        // convert to 0-indexed
         let cb = bytes[3].wrapping_sub(32);
-        let cx = bytes[4].wrapping_sub(32);
-        let cy = bytes[5].wrapping_sub(32);
+        let cx = bytes[4].wrapping_sub(32).saturating_sub(1);
+        let cy = bytes[5].wrapping_sub(32).saturating_sub(1);
 
         let button = match cb & 0b11 {
             0 => MouseButton::Left,
@@ -743,6 +745,23 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_x10_mouse_matches_sgr_coordinates() {
+        let mut parser = InputParser::new();
+        // The same left click at column 10, row 5 in both encodings;
+        // X10 sends each value + 32
+        let sgr = parser.parse(b"\x1b[<0;10;5M");
+        let x10 = parser.parse(&[0x1b, b'[', b'M', 32, 32 + 10, 32 + 5]);
+        match (&sgr[0], &x10[0]) {
+            (Event::Mouse(sgr), Event::Mouse(x10)) => {
+                assert_eq!((x10.column, x10.row), (sgr.column, sgr.row));
+                assert_eq!((x10.column, x10.row), (9, 4));
+                assert_eq!(x10.kind, MouseEventKind::Down(MouseButton::Left));
+            }
+            _ => panic!("Expected mouse events"),
+        }
+    }
+
     #[test]
     fn test_focus_events() {
         let mut parser = InputParser::new();
@@ -9,11 +9,13 @@
 use std::io::{self, BufRead, BufReader, Read, Write};
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
 use interprocess::local_socket::{
     prelude::*, Listener, ListenerNonblockingMode, ListenerOptions, Stream,
 };
 
+use crate::server::protocol::{ClientControl, ServerControl, SessionInfo};
 use crate::workspace::encode_path_for_filename;
 
 // Platform-specific implementations
@@ -27,6 +29,17 @@ use platform_unix as platform;
 #[cfg(windows)]
 use platform_windows as platform;
 
+/// How long `SocketPaths::stop_server` waits for the server process to exit
+const SERVER_STOP_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// How long `SocketPaths::query_session_info` waits for the server's reply
+/// before treating the server as unresponsive
+const SESSION_INFO_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// File in the socket directory holding the secret client tokens are
+/// derived from
+const CLIENT_TOKEN_FILE: &str = "client-token";
+
 /// Socket paths for a session
 #[derive(Debug, Clone)]
 pub struct SocketPaths {
@@ -72,6 +85,115 @@ impl SocketPaths {
         }
     }
 
+    /// List the sessions in `socket_dir` whose server is running, sorted by
+    /// name.
+    ///
+    /// Sessions whose server has died have their socket and PID files
+    /// removed; how many were cleaned up is returned alongside the list.
+    pub fn list_sessions_in_dir(
+        socket_dir: &Path,
+    ) -> io::Result<(Vec<(String, SocketPaths)>, usize)> {
+        let mut sessions = Vec::new();
+        let mut stale_cleaned = 0;
+        if !socket_dir.exists() {
+            return Ok((sessions, stale_cleaned));
+        }
+
+        for entry in std::fs::read_dir(socket_dir)? {
+            let path = entry?.path();
+            let filename = path.file_name().and_then(|s| s.to_str()).unwrap_or("");
+            let Some(name) = filename.strip_suffix(".ctrl.sock") else {
+                continue;
+            };
+            let paths = Self::for_session_name_in_dir(name, socket_dir);
+            if paths.cleanup_if_stale() {
+                stale_cleaned += 1;
+                continue;
+            }
+            if paths.is_server_alive() {
+                sessions.push((name.to_string(), paths));
+            }
+        }
+
+        sessions.sort_by(|a, b| a.0.cmp(&b.0));
+        Ok((sessions, stale_cleaned))
+    }
+
+    /// Ask the session's server for its working directory and client count.
+    ///
+    /// A server that doesn't reply within `SESSION_INFO_TIMEOUT` (e.g. one
+    /// that is hung) fails the query with `io::ErrorKind::TimedOut`.
+    pub fn query_session_info(&self) -> io::Result<SessionInfo> {
+        let conn = ClientConnection::connect(self)?;
+        let query = serde_json::to_string(&ClientControl::SessionInfo)
+            .map_err(|e| io::Error::other(e.to_string()))?;
+        conn.write_control(&query)?;
+
+        let response = conn
+            .read_control_timeout(SESSION_INFO_TIMEOUT)?
+            .ok_or_else(|| {
+                io::Error::new(io::ErrorKind::UnexpectedEof, "Server closed connection")
+            })?;
+        match serde_json::from_str(&response).map_err(|e| io::Error::other(e.to_string()))? {
+            ServerControl::SessionInfo(info) => Ok(info),
+            _ => Err(io::Error::other("Unexpected server response")),
+        }
+    }
+
+    /// Token a client sends so the server can resume its view after a
+    /// dropped connection.
+    ///
+    /// `FRESH_CLIENT_TOKEN` is used when set. Otherwise the token is
+    /// derived from the client's terminal, so each terminal gets its own
+    /// and a client that reattaches from the same one (e.g. inside tmux
+    /// after an SSH blip) sends the same token. A client without a terminal
+    /// gets a token of its own and doesn't resume.
+    pub fn client_token(&self) -> String {
+        if let Some(token) = std::env::var("FRESH_CLIENT_TOKEN")
+            .ok()
+            .filter(|token| !token.is_empty())
+        {
+            return token;
+        }
+        match platform::terminal_id() {
+            Some(terminal) => self.client_token_for_terminal(&terminal),
+            None => generate_client_token(),
+        }
+    }
+
+    /// The default token of a client running in `terminal`
+    fn client_token_for_terminal(&self, terminal: &str) -> String {
+        use sha2::{Digest, Sha256};
+
+        let mut hasher = Sha256::new();
+        hasher.update(self.client_token_secret().as_bytes());
+        hasher.update(terminal.as_bytes());
+        let digest = hasher.finalize();
+        digest[..8].iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    /// Secret mixed into terminal-derived tokens, so they can't be
+    /// guessed from the terminal name. Read from the socket directory,
+    /// generating and saving one the first time.
+    fn client_token_secret(&self) -> String {
+        let path = self.pid.with_file_name(CLIENT_TOKEN_FILE);
+        if let Ok(saved) = std::fs::read_to_string(&path) {
+            let saved = saved.trim();
+            if !saved.is_empty() {
+                return saved.to_string();
+            }
+        }
+        let secret = generate_client_token();
+        if let Err(e) = std::fs::write(&path, &secret) {
+            tracing::warn!(
+                "Failed to save client token secret to {}: {}",
+                path.display(),
+                e
+            );
+        }
+        secret
+    }
+
     /// Check if the sockets exist (server might be running)
     pub fn exists(&self) -> bool {
         self.data.exists() && self.control.exists()
@@ -110,6 +232,29 @@ impl SocketPaths {
         false
     }
 
+    /// Stop the session's server: terminate its process, wait for it to
+    /// exit, and remove the session files.
+    pub fn stop_server(&self) -> io::Result<()> {
+        use crate::server::daemon::{is_process_running, terminate_process};
+
+        if let Some(pid) = self.read_pid()? {
+            if is_process_running(pid) {
+                terminate_process(pid)?;
+                let deadline = Instant::now() + SERVER_STOP_TIMEOUT;
+                while is_process_running(pid) {
+                    if Instant::now() >= deadline {
+                        return Err(io::Error::new(
+                            io::ErrorKind::TimedOut,
+                            format!("Server process {} did not exit", pid),
+                        ));
+                    }
+                    std::thread::sleep(Duration::from_millis(20));
+                }
+            }
+        }
+        self.cleanup()
+    }
+
     /// Clean up stale session files if server is not running
     /// Returns true if files were cleaned up
     pub fn cleanup_if_stale(&self) -> bool {
@@ -138,6 +283,16 @@ impl SocketPaths {
     }
 }
 
+/// A random token for [`SocketPaths::client_token`] and its secret
+fn generate_client_token() -> String {
+    use std::hash::{BuildHasher, Hasher};
+
+    // `RandomState` is seeded from the OS's random source
+    let mut hasher = std::collections::hash_map::RandomState::new().build_hasher();
+    hasher.write_u32(std::process::id());
+    format!("{:016x}", hasher.finish())
+}
+
 /// Type alias for interprocess local socket stream
 type LocalStream = Stream;
 type LocalListener = Listener;
@@ -447,6 +602,40 @@ impl ClientConnection {
         }
     }
 
+    /// Read a control message, failing with `io::ErrorKind::TimedOut` if
+    /// none arrives within `timeout`
+    pub fn read_control_timeout(&self, timeout: Duration) -> io::Result<Option<String>> {
+        let deadline = Instant::now() + timeout;
+        let mut line = Vec::new();
+        let mut byte = [0u8; 1];
+        loop {
+            // One byte at a time so nothing past the newline is consumed
+            match self.control.try_read(&mut byte) {
+                Ok(0) if line.is_empty() => return Ok(None),
+                Ok(0) => break,
+                Ok(_) => {
+                    line.push(byte[0]);
+                    if byte[0] == b'\n' {
+                        break;
+                    }
+                }
+                Err(e) if e.kind() == io::ErrorKind::WouldBlock => {
+                    if Instant::now() >= deadline {
+                        return Err(io::Error::new(
+                            io::ErrorKind::TimedOut,
+                            "Server did not respond",
+                        ));
+                    }
+                    std::thread::sleep(Duration::from_millis(10));
+                }
+                Err(e) => return Err(e),
+            }
+        }
+        String::from_utf8(line)
+            .map(Some)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
     /// Write a control message
     pub fn write_control(&self, msg: &str) -> io::Result<()> {
         self.control.write_all(msg.as_bytes())?;
@@ -500,6 +689,24 @@ mod tests {
             .contains("my-session.ctrl.sock"));
     }
 
+    #[test]
+    fn test_client_token_is_stable_per_terminal() {
+        let temp_dir = TempDir::new().unwrap();
+        let paths = SocketPaths::for_session_name_in_dir("one", temp_dir.path());
+        let token = paths.client_token_for_terminal("/dev/pts/3");
+        assert!(!token.is_empty());
+
+        // A later client in the same terminal, even of another session,
+        // sends the same token; a client in another terminal doesn't
+        let other = SocketPaths::for_session_name_in_dir("two", temp_dir.path());
+        assert_eq!(other.client_token_for_terminal("/dev/pts/3"), token);
+        assert_ne!(paths.client_token_for_terminal("/dev/pts/4"), token);
+
+        // Without the saved secret the tokens change
+        std::fs::remove_file(temp_dir.path().join(CLIENT_TOKEN_FILE)).unwrap();
+        assert_ne!(paths.client_token_for_terminal("/dev/pts/3"), token);
+    }
+
     #[test]
     fn test_exists_returns_false_for_missing_sockets() {
         let temp_dir = TempDir::new().unwrap();
@@ -578,4 +785,46 @@ mod tests {
         // No sockets exist, should return false (nothing to clean)
         assert!(!paths.cleanup_if_stale());
     }
+
+    #[test]
+    fn test_list_sessions_cleans_stale_sockets() {
+        let temp_dir = TempDir::new().unwrap();
+        // Socket files left behind by a server that is no longer running
+        let paths = SocketPaths::for_session_name_in_dir("dead-session", temp_dir.path());
+        std::fs::write(&paths.data, "").unwrap();
+        std::fs::write(&paths.control, "").unwrap();
+
+        let (sessions, stale_cleaned) = SocketPaths::list_sessions_in_dir(temp_dir.path()).unwrap();
+        assert!(sessions.is_empty());
+        assert_eq!(stale_cleaned, 1);
+        assert!(!paths.exists());
+    }
+
+    #[test]
+    fn test_query_session_info_times_out_on_unresponsive_server() {
+        let temp_dir = TempDir::new().unwrap();
+        let paths = SocketPaths::for_session_name_in_dir("hung-session", temp_dir.path());
+        let mut listener = ServerListener::bind(paths.clone()).unwrap();
+
+        // A server that accepts the connection but never answers
+        let (done_tx, done_rx) = std::sync::mpsc::channel::<()>();
+        let server = std::thread::spawn(move || {
+            let conn = loop {
+                if let Some(conn) = listener.accept().unwrap() {
+                    break conn;
+                }
+                std::thread::sleep(Duration::from_millis(10));
+            };
+            drop(done_rx.recv());
+            drop(conn);
+        });
+
+        let started = Instant::now();
+        let err = paths.query_session_info().unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::TimedOut);
+        assert!(started.elapsed() < SESSION_INFO_TIMEOUT * 3);
+
+        done_tx.send(()).unwrap();
+        server.join().unwrap();
+    }
 }
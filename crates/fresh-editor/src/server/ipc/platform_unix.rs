@@ -47,6 +47,18 @@ pub fn try_read_nonblocking(stream: &mut LocalStream, buf: &mut [u8]) -> io::Res
     result
 }
 
+/// Identity of the client's terminal: the path of the terminal on stdin
+/// (e.g. `/dev/pts/3`), or the parent process for a client without one
+pub fn terminal_id() -> Option<String> {
+    let mut buf = [0 as libc::c_char; 256];
+    let rc = unsafe { libc::ttyname_r(0, buf.as_mut_ptr(), buf.len()) };
+    if rc != 0 {
+        return Some(format!("ppid:{}", std::os::unix::process::parent_id()));
+    }
+    let name = unsafe { std::ffi::CStr::from_ptr(buf.as_ptr()) };
+    Some(name.to_string_lossy().into_owned())
+}
+
 /// Check if server is alive by trying to connect (Unix-specific fallback)
 pub fn check_server_by_connect(control_path: &Path) -> bool {
     if let Ok(name) = socket_name_for_path(control_path) {
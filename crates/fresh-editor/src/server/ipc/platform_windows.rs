@@ -78,6 +78,14 @@ pub fn try_read_nonblocking(stream: &mut LocalStream, buf: &mut [u8]) -> io::Res
     stream.read(buf)
 }
 
+/// Identity of the client's terminal: the Windows Terminal tab it runs
+/// in, if any
+pub fn terminal_id() -> Option<String> {
+    std::env::var("WT_SESSION")
+        .ok()
+        .filter(|session| !session.is_empty())
+}
+
 /// Check if server is alive by trying to connect (not used on Windows)
 ///
 /// On Windows, we don't try to connect to verify - it can leave pipes in busy state.
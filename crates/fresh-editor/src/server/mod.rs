@@ -23,9 +23,12 @@ mod runner;
 #[cfg(test)]
 mod tests;
 
-pub use capture_backend::{terminal_setup_sequences, terminal_teardown_sequences, CaptureBackend};
+pub use capture_backend::{
+    mouse_tracking_sequences, terminal_setup_sequences, terminal_teardown_sequences, CaptureBackend,
+};
 pub use daemon::{
-    daemonize, is_process_running, read_pid_file, spawn_server_detached, write_pid_file,
+    daemonize, is_process_running, read_pid_file, spawn_server_detached, terminate_process,
+    write_pid_file,
 };
 pub use editor_server::{EditorServer, EditorServerConfig};
 pub use input_parser::InputParser;
@@ -33,8 +33,13 @@ pub struct ClientHello {
     /// Initial terminal size
     pub term_size: TermSize,
     /// Environment variables relevant for rendering
-    /// Keys: TERM, COLORTERM, LANG, LC_ALL
+    /// Keys: TERM, COLORTERM, NO_COLOR, FRESH_COLOR_MODE, WT_SESSION, LANG, LC_ALL
     pub env: HashMap<String, Option<String>>,
+    /// Token identifying this client across reconnects (see
+    /// `SocketPaths::client_token`). A client that drops and reconnects
+    /// with the same token soon after resumes the view it had.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub session_token: Option<String>,
 }
 
 impl ClientHello {
@@ -43,7 +48,15 @@ impl ClientHello {
         let mut env = HashMap::new();
 
         // Collect terminal-relevant environment variables
-        for key in &["TERM", "COLORTERM", "LANG", "LC_ALL"] {
+        for key in &[
+            "TERM",
+            "COLORTERM",
+            "NO_COLOR",
+            "FRESH_COLOR_MODE",
+            "WT_SESSION",
+            "LANG",
+            "LC_ALL",
+        ] {
             env.insert(key.to_string(), std::env::var(key).ok());
         }
 
@@ -52,6 +65,7 @@ impl ClientHello {
             client_version: env!("CARGO_PKG_VERSION").to_string(),
             term_size,
             env,
+            session_token: None,
         }
     }
 
@@ -91,6 +105,44 @@ impl ServerHello {
     }
 }
 
+/// Session metadata sent in answer to `ClientControl::SessionInfo`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionInfo {
+    /// Session identifier (session name or encoded working directory)
+    pub session_id: String,
+    /// Working directory the server was started in
+    pub working_dir: String,
+    /// Number of clients currently attached
+    pub client_count: usize,
+    /// Server binary version
+    pub server_version: String,
+    /// Server process ID
+    pub pid: u32,
+    /// Number of buffers with unsaved changes, when the server hosts an
+    /// editor (absent from servers that predate the field)
+    #[serde(default)]
+    pub modified_buffers: Option<usize>,
+}
+
+impl SessionInfo {
+    pub fn new(session_id: String, working_dir: &std::path::Path, client_count: usize) -> Self {
+        Self {
+            session_id,
+            working_dir: working_dir.display().to_string(),
+            client_count,
+            server_version: env!("CARGO_PKG_VERSION").to_string(),
+            pid: std::process::id(),
+            modified_buffers: None,
+        }
+    }
+
+    /// Serialize as the `ServerControl::SessionInfo` reply
+    pub fn to_reply(&self) -> std::io::Result<String> {
+        serde_json::to_string(&ServerControl::SessionInfo(self.clone()))
+            .map_err(|e| std::io::Error::other(e.to_string()))
+    }
+}
+
 /// Version mismatch error response
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VersionMismatch {
@@ -107,6 +159,9 @@ pub struct VersionMismatch {
 pub enum ClientControl {
     /// Initial handshake
     Hello(ClientHello),
+    /// Ask for session metadata instead of attaching (sent in place of
+    /// Hello; the server answers and closes the connection)
+    SessionInfo,
     /// Terminal was resized
     Resize { cols: u16, rows: u16 },
     /// Keepalive ping
@@ -145,6 +200,8 @@ pub enum ServerControl {
     Hello(ServerHello),
     /// Version mismatch error
     VersionMismatch(VersionMismatch),
+    /// Answer to a session metadata query
+    SessionInfo(SessionInfo),
     /// Keepalive pong
     Pong,
     /// Set terminal title
@@ -219,6 +276,13 @@ mod tests {
         assert_eq!(parsed.term_size.rows, 40);
     }
 
+    #[test]
+    fn test_client_hello_without_token_parses() {
+        let json = r#"{"protocol_version":1,"client_version":"0.1.0","term_size":{"cols":80,"rows":24},"env":{}}"#;
+        let parsed: ClientHello = serde_json::from_str(json).unwrap();
+        assert_eq!(parsed.session_token, None);
+    }
+
     #[test]
     fn test_control_messages_use_snake_case_tags() {
         let resize = ClientControl::Resize {
@@ -287,6 +351,7 @@ mod tests {
                 cols: 100,
                 rows: 50,
             },
+            ClientControl::SessionInfo,
             ClientControl::Ping,
             ClientControl::Detach,
             ClientControl::Quit,
@@ -313,6 +378,11 @@ mod tests {
     fn test_all_server_control_variants_serialize() {
         let variants: Vec<ServerControl> = vec![
             ServerControl::Hello(ServerHello::new("test".to_string())),
+            ServerControl::SessionInfo(SessionInfo::new(
+                "test".to_string(),
+                std::path::Path::new("/tmp/project"),
+                2,
+            )),
             ServerControl::Pong,
             ServerControl::SetTitle {
                 title: "Test".to_string(),
@@ -16,7 +16,8 @@ use crate::server::capture_backend::{terminal_setup_sequences, terminal_teardown
 use crate::server::input_parser::InputParser;
 use crate::server::ipc::{ServerConnection, ServerListener, SocketPaths};
 use crate::server::protocol::{
-    ClientControl, ServerControl, ServerHello, TermSize, VersionMismatch, PROTOCOL_VERSION,
+    ClientControl, ServerControl, ServerHello, SessionInfo, TermSize, VersionMismatch,
+    PROTOCOL_VERSION,
 };
 use crossterm::event::Event;
 
@@ -67,9 +68,20 @@ impl Server {
         } else {
             SocketPaths::for_working_dir(&config.working_dir)?
         };
+        Self::with_socket_paths(config, socket_paths)
+    }
 
+    /// Create a server listening at `socket_paths` instead of the
+    /// session's default location
+    pub fn with_socket_paths(config: ServerConfig, socket_paths: SocketPaths) -> io::Result<Self> {
         let listener = ServerListener::bind(socket_paths)?;
 
+        // Write PID file so session listings see this server as alive
+        // without connecting to it
+        if let Err(e) = listener.paths().write_pid(std::process::id()) {
+            tracing::warn!("Failed to write PID file: {}", e);
+        }
+
         Ok(Self {
             config,
             listener,
@@ -111,7 +123,10 @@ impl Server {
             // Try to accept new connections
             match self.listener.accept() {
                 Ok(Some(conn)) => match self.handle_new_connection(conn, next_client_id) {
-                    Ok(client) => {
+                    Ok(None) => {
+                        tracing::debug!("Answered session info query");
+                    }
+                    Ok(Some(client)) => {
                         tracing::info!("Client {} connected", client.id);
                         self.clients.push(client);
                         self.last_client_activity = Instant::now();
@@ -148,12 +163,32 @@ impl Server {
         Ok(())
     }
 
+    /// Session identifier reported to clients
+    fn session_id(&self) -> String {
+        self.config
+            .session_name
+            .clone()
+            .unwrap_or_else(|| crate::workspace::encode_path_for_filename(&self.config.working_dir))
+    }
+
+    /// Metadata answering a session info query
+    fn session_info(&self) -> SessionInfo {
+        SessionInfo::new(
+            self.session_id(),
+            &self.config.working_dir,
+            self.clients.len(),
+        )
+    }
+
     /// Handle a new client connection (perform handshake)
+    ///
+    /// Returns `None` when the connection was a one-shot session info
+    /// query rather than a client attaching.
     fn handle_new_connection(
         &self,
         conn: ServerConnection,
         client_id: u64,
-    ) -> io::Result<ConnectedClient> {
+    ) -> io::Result<Option<ConnectedClient>> {
         // Read client hello (blocking read)
         // On Windows, named pipes are already in blocking mode from accept().
         // On Unix, we need to explicitly set blocking mode for the handshake.
@@ -168,6 +203,10 @@ impl Server {
 
         let hello = match client_msg {
             ClientControl::Hello(h) => h,
+            ClientControl::SessionInfo => {
+                conn.write_control(&self.session_info().to_reply()?)?;
+                return Ok(None);
+            }
             _ => {
                 return Err(io::Error::other("Expected Hello message"));
             }
@@ -197,11 +236,7 @@ impl Server {
         }
 
         // Send server hello
-        let session_id = self.config.session_name.clone().unwrap_or_else(|| {
-            crate::workspace::encode_path_for_filename(&self.config.working_dir)
-        });
-
-        let server_hello = ServerHello::new(session_id);
+        let server_hello = ServerHello::new(self.session_id());
         let response = serde_json::to_string(&ServerControl::Hello(server_hello))
             .map_err(|e| io::Error::other(e.to_string()))?;
         conn.write_control(&response)?;
@@ -226,13 +261,13 @@ impl Server {
         let setup = terminal_setup_sequences(self.config.mouse_hover_enabled);
         conn.write_data(&setup)?;
 
-        Ok(ConnectedClient {
+        Ok(Some(ConnectedClient {
             conn,
             term_size: hello.term_size,
             env: hello.env,
             id: client_id,
             input_parser: InputParser::new(),
-        })
+        }))
     }
 
     /// Process messages from connected clients
@@ -341,6 +376,14 @@ impl Server {
 
     /// Handle a control message from a client
     fn handle_control_message(&mut self, client_idx: usize, msg: ClientControl) -> io::Result<()> {
+        if matches!(msg, ClientControl::SessionInfo) {
+            let reply = self.session_info().to_reply()?;
+            if let Some(client) = self.clients.get(client_idx) {
+                client.conn.write_control(&reply)?;
+            }
+            return Ok(());
+        }
+
         let client = match self.clients.get_mut(client_idx) {
             Some(c) => c,
             None => return Ok(()), // Client already disconnected
@@ -351,6 +394,8 @@ impl Server {
                 // Shouldn't happen after initial handshake
                 tracing::warn!("Client {} sent unexpected Hello", client.id);
             }
+            // Answered above
+            ClientControl::SessionInfo => {}
             ClientControl::Resize { cols, rows } => {
                 client.term_size = TermSize::new(cols, rows);
                 tracing::debug!("Client {} resized to {}x{}", client.id, cols, rows);
@@ -6,10 +6,12 @@ mod integration_tests {
     use std::thread;
     use std::time::Duration;
 
+    use crate::client;
     use crate::server::daemon::is_process_running;
-    use crate::server::ipc::{ClientConnection, SocketPaths};
+    use crate::server::ipc::{ClientConnection, ServerListener, SocketPaths};
     use crate::server::protocol::{
-        ClientControl, ClientHello, ServerControl, ServerHello, TermSize, PROTOCOL_VERSION,
+        ClientControl, ClientHello, ServerControl, ServerHello, SessionInfo, TermSize,
+        VersionMismatch, PROTOCOL_VERSION,
     };
     use crate::server::runner::{Server, ServerConfig};
 
@@ -162,6 +164,189 @@ mod integration_tests {
         std::fs::remove_dir_all(&temp_dir).ok();
     }
 
+    /// Stand in for a server from another build: answer one hello with a
+    /// version mismatch suggesting `action`, then, for `restart_server`,
+    /// the client's session info query with `info` (`None`: a server that
+    /// predates the query and doesn't answer it), then go away
+    fn spawn_mismatched_server(
+        session_name: &str,
+        action: &str,
+        info: Option<SessionInfo>,
+    ) -> thread::JoinHandle<()> {
+        let paths = SocketPaths::for_session_name(session_name).unwrap();
+        let mut listener = ServerListener::bind(paths).unwrap();
+        let action = action.to_string();
+        thread::spawn(move || {
+            let mut accept = || loop {
+                if let Some(conn) = listener.accept().unwrap() {
+                    break conn;
+                }
+                thread::sleep(Duration::from_millis(5));
+            };
+            let conn = accept();
+            conn.read_control().unwrap();
+            let answers_query = action == "restart_server";
+            let mismatch = VersionMismatch {
+                server_version: "0.0.1".to_string(),
+                client_version: env!("CARGO_PKG_VERSION").to_string(),
+                action,
+                message: "Protocol version mismatch".to_string(),
+            };
+            conn.write_control(
+                &serde_json::to_string(&ServerControl::VersionMismatch(mismatch)).unwrap(),
+            )
+            .unwrap();
+            match info {
+                Some(info) if answers_query => {
+                    let query = accept();
+                    query.read_control().unwrap();
+                    query.write_control(&info.to_reply().unwrap()).unwrap();
+                }
+                _ => {}
+            }
+            // Dropping the listener removes the socket files
+        })
+    }
+
+    /// Session info of an idle stand-in server with nothing to lose
+    fn idle_session_info(session_name: &str) -> SessionInfo {
+        let mut info = SessionInfo::new(session_name.to_string(), &std::env::temp_dir(), 0);
+        info.modified_buffers = Some(0);
+        info
+    }
+
+    /// Have the client connect to a stand-in server answering with
+    /// `restart_server` and `info`, restart it with a real server, and
+    /// check the retried handshake succeeds
+    fn assert_client_restarts_server(label: &str, info: Option<SessionInfo>) {
+        let temp_dir =
+            std::env::temp_dir().join(format!("fresh-test-{}-{}", label, std::process::id()));
+        std::fs::create_dir_all(&temp_dir).unwrap();
+
+        let session_name = unique_session_name(label);
+        let expected = info.clone();
+        let old_server = spawn_mismatched_server(&session_name, "restart_server", info);
+        let socket_paths = SocketPaths::for_session_name(&session_name).unwrap();
+
+        let mut restarts = 0;
+        let mut new_server = None;
+        let (conn, handshake) = client::connect_with_restart(
+            &socket_paths,
+            TermSize::new(80, 24),
+            |mismatch, info| {
+                assert_eq!(mismatch.action, "restart_server");
+                assert_eq!(
+                    info.map(|info| info.modified_buffers),
+                    expected.map(|info| info.modified_buffers)
+                );
+                client::restart_losses(info).is_empty()
+            },
+            || {
+                restarts += 1;
+                old_server.join().unwrap();
+                let mut server = Server::new(ServerConfig {
+                    working_dir: temp_dir.clone(),
+                    session_name: Some(session_name.clone()),
+                    idle_timeout: Some(Duration::from_secs(5)),
+                    mouse_hover_enabled: true,
+                })?;
+                let shutdown = server.shutdown_handle();
+                new_server = Some((shutdown, thread::spawn(move || server.run())));
+                Ok(())
+            },
+        )
+        .unwrap();
+
+        assert_eq!(restarts, 1);
+        match handshake {
+            client::Handshake::Connected(server_hello) => {
+                assert_eq!(server_hello.session_id, session_name);
+            }
+            other => panic!("Expected Connected after restart, got {:?}", other),
+        }
+
+        drop(conn);
+        let (shutdown, handle) = new_server.unwrap();
+        shutdown.store(true, Ordering::SeqCst);
+        drop(handle.join());
+        std::fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    /// Test the client replaces a server that asks to be restarted and retries
+    #[test]
+    fn test_client_restarts_server_on_version_mismatch() {
+        assert_client_restarts_server("restart", Some(idle_session_info("restart")));
+    }
+
+    /// Test a server from an older build that can't report its session
+    /// info is still restarted
+    #[test]
+    fn test_client_restarts_server_without_session_info() {
+        assert_client_restarts_server("restart-noinfo", None);
+    }
+
+    /// Test the client does not restart a server that is older than itself
+    #[test]
+    fn test_client_reports_upgrade_server_mismatch() {
+        let session_name = unique_session_name("upgrade");
+        let old_server = spawn_mismatched_server(
+            &session_name,
+            "upgrade_server",
+            Some(idle_session_info(&session_name)),
+        );
+        let socket_paths = SocketPaths::for_session_name(&session_name).unwrap();
+
+        let (_conn, handshake) = client::connect_with_restart(
+            &socket_paths,
+            TermSize::new(80, 24),
+            |_, _| panic!("upgrade_server must not offer a restart"),
+            || panic!("upgrade_server must not restart the server"),
+        )
+        .unwrap();
+
+        match handshake {
+            client::Handshake::Mismatch(mismatch) => {
+                assert_eq!(mismatch.action, "upgrade_server");
+                assert_eq!(mismatch.server_version, "0.0.1");
+            }
+            other => panic!("Expected Mismatch, got {:?}", other),
+        }
+        old_server.join().unwrap();
+    }
+
+    /// Test a refused restart leaves a busy server running and reports the
+    /// mismatch
+    #[test]
+    fn test_client_keeps_busy_server_when_restart_refused() {
+        let session_name = unique_session_name("busy");
+        let mut info = SessionInfo::new(session_name.clone(), &std::env::temp_dir(), 1);
+        info.modified_buffers = Some(2);
+        let old_server = spawn_mismatched_server(&session_name, "restart_server", Some(info));
+        let socket_paths = SocketPaths::for_session_name(&session_name).unwrap();
+
+        let (_conn, handshake) = client::connect_with_restart(
+            &socket_paths,
+            TermSize::new(80, 24),
+            |_, info| {
+                let info = info.expect("server reports its session info");
+                assert_eq!(info.client_count, 1);
+                assert_eq!(info.modified_buffers, Some(2));
+                assert_eq!(client::restart_losses(Some(info)).len(), 2);
+                false
+            },
+            || panic!("a refused restart must not stop the server"),
+        )
+        .unwrap();
+
+        match handshake {
+            client::Handshake::Mismatch(mismatch) => {
+                assert_eq!(mismatch.action, "restart_server");
+            }
+            other => panic!("Expected Mismatch, got {:?}", other),
+        }
+        old_server.join().unwrap();
+    }
+
     /// Test idle timeout causes server shutdown
     #[test]
     fn test_idle_timeout_triggers_shutdown() {
@@ -276,6 +461,55 @@ mod integration_tests {
         std::fs::remove_dir_all(&temp_dir).ok();
     }
 
+    /// Test the session listing reports every running server with its working dir
+    #[test]
+    fn test_session_list_reports_running_servers() {
+        let temp_dir = std::env::temp_dir().join(format!("fresh-test-list-{}", std::process::id()));
+        let dirs = [temp_dir.join("alpha"), temp_dir.join("beta")];
+        // Sockets live in a private directory so the listing's stale-socket
+        // cleanup never touches real sessions
+        let socket_dir = temp_dir.join("sockets");
+        std::fs::create_dir_all(&socket_dir).unwrap();
+
+        let mut running = Vec::new();
+        for dir in &dirs {
+            std::fs::create_dir_all(dir).unwrap();
+            let session_name = unique_session_name("list");
+            let config = ServerConfig {
+                working_dir: dir.clone(),
+                session_name: Some(session_name.clone()),
+                idle_timeout: Some(Duration::from_secs(5)),
+                mouse_hover_enabled: true,
+            };
+            let socket_paths = SocketPaths::for_session_name_in_dir(&session_name, &socket_dir);
+            let mut server = Server::with_socket_paths(config, socket_paths).unwrap();
+            let shutdown = server.shutdown_handle();
+            let handle = thread::spawn(move || server.run());
+            running.push((session_name, dir.clone(), shutdown, handle));
+        }
+
+        let (sessions, _) = SocketPaths::list_sessions_in_dir(&socket_dir).unwrap();
+        assert_eq!(sessions.len(), running.len());
+
+        for (session_name, dir, _, _) in &running {
+            let (_, paths) = sessions
+                .iter()
+                .find(|(name, _)| name == session_name)
+                .unwrap_or_else(|| panic!("session {} not listed", session_name));
+            let info = paths.query_session_info().unwrap();
+            assert_eq!(&info.session_id, session_name);
+            assert_eq!(info.working_dir, dir.display().to_string());
+            assert_eq!(info.client_count, 0);
+            assert_eq!(info.pid, std::process::id());
+        }
+
+        for (_, _, shutdown, handle) in running {
+            shutdown.store(true, Ordering::SeqCst);
+            drop(handle.join());
+        }
+        std::fs::remove_dir_all(&temp_dir).ok();
+    }
+
     /// E2E test: Server signals readiness via PID file, client connects using semantic condition
     ///
     /// This test verifies the cross-platform synchronization mechanism:
@@ -1146,6 +1380,140 @@ mod integration_tests {
         teardown_editor_server_e2e(conn, shutdown_handle, server_handle, socket_paths, temp_dir);
     }
 
+    /// Connect a client that identifies itself with `token` and complete the handshake.
+    fn connect_with_token(socket_paths: &SocketPaths, token: &str) -> ClientConnection {
+        let conn = ClientConnection::connect(socket_paths).expect("Failed to connect");
+        let mut hello = ClientHello::new(TermSize::new(80, 24));
+        hello.session_token = Some(token.to_string());
+        conn.write_control(&serde_json::to_string(&ClientControl::Hello(hello)).unwrap())
+            .unwrap();
+        let response = conn.read_control().unwrap().unwrap();
+        assert!(matches!(
+            serde_json::from_str::<ServerControl>(&response).unwrap(),
+            ServerControl::Hello(_)
+        ));
+        conn
+    }
+
+    /// Read from the client data pipe until the rendered screen satisfies `pred`
+    /// or a few seconds pass, and return the last screen either way.
+    fn read_screen_until(
+        conn: &ClientConnection,
+        output: &mut Vec<u8>,
+        pred: impl Fn(&str) -> bool,
+    ) -> String {
+        let deadline = std::time::Instant::now() + Duration::from_secs(10);
+        let mut buf = [0u8; 8192];
+        loop {
+            let screen = vt100_screen_text(output);
+            if pred(&screen) || std::time::Instant::now() >= deadline {
+                return screen;
+            }
+            match conn.data.try_read(&mut buf) {
+                Ok(0) => return screen,
+                Ok(n) => output.extend_from_slice(&buf[..n]),
+                Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => {
+                    thread::sleep(Duration::from_millis(5));
+                }
+                Err(_) => return screen,
+            }
+        }
+    }
+
+    /// Wait until the server reports `count` attached clients.
+    fn wait_for_client_count(socket_paths: &SocketPaths, count: usize) {
+        while socket_paths.query_session_info().unwrap().client_count != count {
+            thread::sleep(Duration::from_millis(5));
+        }
+    }
+
+    /// A client that drops and reconnects with the same session token resumes
+    /// the scroll position it had, even after another client scrolled away.
+    #[test]
+    #[cfg_attr(
+        windows,
+        ignore = "Windows named pipe handling needs further investigation for sustained connections"
+    )]
+    fn test_reconnecting_client_resumes_its_view() {
+        let (conn, mut output, shutdown_handle, server_handle, socket_paths, temp_dir) =
+            setup_editor_server_e2e("resume-view");
+
+        // Client A fills the buffer, leaving the view scrolled to the end
+        let client_a = connect_with_token(&socket_paths, "client-a");
+        let text: Vec<String> = (1..=60).map(|n| format!("L{}", n)).collect();
+        client_a.write_data(text.join("\r").as_bytes()).unwrap();
+        let mut output_a = Vec::new();
+        read_until_contains(&client_a, &mut output_a, "L60");
+
+        // A drops; wait until the server has noticed
+        drop(client_a);
+        wait_for_client_count(&socket_paths, 1);
+
+        // The other client jumps to the top of the buffer
+        conn.write_data(b"\x1b[1;5H").unwrap();
+        let screen = read_screen_until(&conn, &mut output, |s| !s.contains("L60"));
+        assert!(!screen.contains("L60"), "Screen:\n{}", screen);
+
+        // A comes back within the grace period and sees the end again
+        let client_a = connect_with_token(&socket_paths, "client-a");
+        let mut output_a = Vec::new();
+        let screen = read_screen_until(&client_a, &mut output_a, |s| s.contains("L60"));
+        assert!(
+            screen.contains("L60"),
+            "Reconnected client should resume its scroll position.\nScreen:\n{}",
+            screen
+        );
+
+        drop(client_a);
+        teardown_editor_server_e2e(conn, shutdown_handle, server_handle, socket_paths, temp_dir);
+    }
+
+    /// Connect with the token the client derives by default, as `fresh -a`
+    /// does.
+    fn connect_with_default_token(socket_paths: &SocketPaths) -> ClientConnection {
+        let conn = ClientConnection::connect(socket_paths).expect("Failed to connect");
+        let outcome = crate::client::handshake(&conn, socket_paths, TermSize::new(80, 24))
+            .expect("Handshake failed");
+        assert!(matches!(outcome, crate::client::Handshake::Connected(_)));
+        conn
+    }
+
+    /// Without `FRESH_CLIENT_TOKEN`, a client reconnecting from the same
+    /// terminal still resumes its view.
+    #[test]
+    #[cfg_attr(
+        windows,
+        ignore = "Windows named pipe handling needs further investigation for sustained connections"
+    )]
+    fn test_reconnecting_client_resumes_with_default_token() {
+        let (conn, mut output, shutdown_handle, server_handle, socket_paths, temp_dir) =
+            setup_editor_server_e2e("resume-default-token");
+
+        let client_a = connect_with_default_token(&socket_paths);
+        let text: Vec<String> = (1..=60).map(|n| format!("L{}", n)).collect();
+        client_a.write_data(text.join("\r").as_bytes()).unwrap();
+        let mut output_a = Vec::new();
+        read_until_contains(&client_a, &mut output_a, "L60");
+
+        drop(client_a);
+        wait_for_client_count(&socket_paths, 1);
+        conn.write_data(b"\x1b[1;5H").unwrap();
+        let screen = read_screen_until(&conn, &mut output, |s| !s.contains("L60"));
+        assert!(!screen.contains("L60"), "Screen:\n{}", screen);
+
+        let client_a = connect_with_default_token(&socket_paths);
+        let mut output_a = Vec::new();
+        let screen = read_screen_until(&client_a, &mut output_a, |s| s.contains("L60"));
+        assert!(
+            screen.contains("L60"),
+            "Reconnected client should resume its scroll position.\nScreen:\n{}",
+            screen
+        );
+
+        drop(client_a);
+        teardown_editor_server_e2e(conn, shutdown_handle, server_handle, socket_paths, temp_dir);
+    }
+
     /// Poll the control socket (non-blocking) for newline-delimited JSON messages.
     /// Appends to `ctrl_buf` across calls to handle partial reads.
     /// Returns parsed lines (may be empty if no complete line is available yet).
@@ -1254,7 +1622,10 @@ mod integration_tests {
             text, "CLIPTEST",
             "SetClipboard should contain the copied text"
         );
-        assert!(use_osc52, "use_osc52 should be true by default");
+        assert!(
+            !use_osc52,
+            "OSC 52 travels in the output stream, so the client must not emit it again"
+        );
         assert!(use_sys, "use_system_clipboard should be true by default");
 
         // Restore blocking mode before teardown writes Quit
@@ -1264,6 +1635,40 @@ mod integration_tests {
         teardown_editor_server_e2e(conn, shutdown_handle, server_handle, socket_paths, temp_dir);
     }
 
+    /// E2E test: Copy in session mode writes an OSC 52 sequence carrying the
+    /// base64-encoded text into the client's output stream, so the terminal
+    /// the client runs in updates the host clipboard
+    #[test]
+    fn test_copy_writes_osc52_to_output_stream() {
+        let (conn, mut output, shutdown_handle, server_handle, socket_paths, temp_dir) =
+            setup_editor_server_e2e("clipboard-osc52");
+
+        conn.write_data(b"CLIPTEST").unwrap();
+        read_until_contains(&conn, &mut output, "CLIPTEST");
+
+        let mut ctrl_buf = Vec::new();
+
+        // Select all (Ctrl+A), synced with a ping/pong round-trip
+        conn.write_data(&[0x01]).unwrap();
+        conn.write_control(&serde_json::to_string(&ClientControl::Ping).unwrap())
+            .unwrap();
+        wait_for_control(&conn, &mut ctrl_buf, Duration::from_secs(5), |ctrl| {
+            matches!(ctrl, ServerControl::Pong).then_some(())
+        })
+        .expect("Timed out waiting for Pong after Ctrl+A");
+
+        // Copy (Ctrl+C); base64("CLIPTEST") == "Q0xJUFRFU1Q="
+        output.clear();
+        conn.write_data(&[0x03]).unwrap();
+        read_until_contains(&conn, &mut output, "\x1b]52;c;Q0xJUFRFU1Q=\x07");
+
+        // Restore blocking mode before teardown writes Quit
+        #[allow(clippy::let_underscore_must_use)]
+        let _ = conn.control.set_nonblocking(false);
+
+        teardown_editor_server_e2e(conn, shutdown_handle, server_handle, socket_paths, temp_dir);
+    }
+
     /// Authority transitions in session mode must rebuild the editor in
     /// place (not shut the daemon down).  This test drives the rebuild
     /// path directly against an `EditorServer`, without running the
@@ -10,11 +10,13 @@
 //! - Computation should be sync (editing, rendering)
 //! - Main loop remains responsive and simple
 
+use crate::model::filesystem::DirEntry;
 use crate::services::terminal::TerminalId;
 use crate::view::file_tree::{FileTreeView, NodeId};
 use lsp_types::{
-    CodeActionOrCommand, CompletionItem, Diagnostic, FoldingRange, InlayHint, Location,
-    SemanticTokensFullDeltaResult, SemanticTokensRangeResult, SemanticTokensResult, SignatureHelp,
+    CodeActionOrCommand, CompletionItem, Diagnostic, DocumentSymbolResponse, FoldingRange,
+    InlayHint, Location, SemanticTokensFullDeltaResult, SemanticTokensRangeResult,
+    SemanticTokensResult, SignatureHelp, WorkspaceSymbolResponse,
 };
 use serde_json::Value;
 use std::sync::mpsc;
@@ -91,6 +93,18 @@ pub enum AsyncMessage {
         locations: Vec<Location>,
     },
 
+    /// LSP document symbols response (hierarchical or flat form)
+    LspDocumentSymbols {
+        request_id: u64,
+        response: Option<DocumentSymbolResponse>,
+    },
+
+    /// LSP workspace symbol search response
+    LspWorkspaceSymbols {
+        request_id: u64,
+        response: Option<WorkspaceSymbolResponse>,
+    },
+
     /// LSP signature help response
     LspSignatureHelp {
         request_id: u64,
@@ -132,6 +146,8 @@ pub enum AsyncMessage {
         diagnostics: Vec<Diagnostic>,
         /// True if diagnostics haven't changed since previous_result_id
         unchanged: bool,
+        /// Name of the server that sent these diagnostics (for per-server tracking)
+        server_name: String,
     },
 
     /// LSP inlay hints response (textDocument/inlayHint)
@@ -183,11 +199,21 @@ pub enum AsyncMessage {
     /// Contains the updated FileTreeView with the path expanded and selected
     FileExplorerExpandedToPath(FileTreeView),
 
+    /// File explorer subtree listed for an active path filter
+    /// Contains each listed directory with its entries, breadth-first
+    FileExplorerFilterLoaded(Vec<(std::path::PathBuf, Vec<DirEntry>)>),
+
     /// Plugin-related async messages
     Plugin(fresh_core::api::PluginAsyncMessage),
 
-    /// File open dialog: directory listing completed
-    FileOpenDirectoryLoaded(std::io::Result<Vec<crate::services::fs::DirEntry>>),
+    /// File open dialog: directory listing completed.
+    /// `typed` is set when the listing is for a directory typed into the
+    /// prompt rather than one the dialog already navigated to.
+    FileOpenDirectoryLoaded {
+        path: std::path::PathBuf,
+        typed: bool,
+        result: std::io::Result<Vec<crate::services::fs::DirEntry>>,
+    },
 
     /// File open dialog: async shortcuts (Windows drive letters) loaded
     FileOpenShortcutsLoaded(Vec<crate::app::file_open::NavigationShortcut>),
@@ -198,6 +224,21 @@ pub enum AsyncMessage {
     /// Terminal process exited
     TerminalExited { terminal_id: TerminalId },
 
+    /// A line of stdout/stderr from a config-defined custom command
+    CustomCommandOutput {
+        buffer_id: crate::model::event::BufferId,
+        run_id: u64,
+        text: String,
+    },
+
+    /// A config-defined custom command exited
+    CustomCommandExited {
+        buffer_id: crate::model::event::BufferId,
+        run_id: u64,
+        name: String,
+        exit_code: i32,
+    },
+
     /// LSP progress notification ($/progress)
     LspProgress {
         language: String,
@@ -264,7 +264,7 @@ impl Authority {
     pub fn local() -> Self {
         Self {
             filesystem: Arc::new(StdFileSystem),
-            process_spawner: Arc::new(LocalProcessSpawner),
+            process_spawner: Arc::new(LocalProcessSpawner::default()),
             long_running_spawner: Arc::new(LocalLongRunningSpawner),
             terminal_wrapper: TerminalWrapper::host_shell(),
             display_label: String::new(),
@@ -272,6 +272,16 @@ impl Authority {
         }
     }
 
+    /// Run this authority's one-shot processes under `limits` where the
+    /// spawner supports it (the local spawner does; remote and container
+    /// spawners are left as they are).
+    pub fn with_process_limits(mut self, limits: &crate::types::ProcessLimits) -> Self {
+        if let Some(spawner) = self.process_spawner.with_process_limits(limits) {
+            self.process_spawner = spawner;
+        }
+        self
+    }
+
     /// Build an SSH authority. The caller already holds the connection
     /// (and its keepalive resources) so we just wire the parts in. Label
     /// is left empty — the status bar falls back to the filesystem's own
@@ -311,7 +321,7 @@ impl Authority {
             Arc<dyn LongRunningSpawner>,
         ) = match payload.spawner {
             SpawnerSpec::Local => (
-                Arc::new(LocalProcessSpawner),
+                Arc::new(LocalProcessSpawner::default()),
                 Arc::new(LocalLongRunningSpawner),
             ),
             SpawnerSpec::DockerExec {
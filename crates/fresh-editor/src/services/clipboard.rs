@@ -2,14 +2,13 @@
 //!
 //! This module provides a unified clipboard interface that:
 //! - Maintains an internal clipboard for in-editor copy/paste
-//! - Uses crossterm's OSC 52 escape sequences for copying to system clipboard
+//! - Uses OSC 52 escape sequences for copying to system clipboard
 //! - Uses arboard crate for reading from system clipboard
 //! - Supports copying HTML-formatted text for rich text editors
 //! - Gracefully falls back to internal clipboard if system clipboard is unavailable
 //! - Respects clipboard configuration to disable problematic methods
 
-use crossterm::clipboard::CopyToClipboard;
-use crossterm::execute;
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
 use std::io::{stdout, Write};
 use std::sync::Mutex;
 
@@ -32,7 +31,7 @@ static SYSTEM_CLIPBOARD: Mutex<Option<arboard::Clipboard>> = Mutex::new(None);
 /// selection/data-source on drop, leaving the clipboard empty.
 pub fn copy_to_system_clipboard(text: &str, use_osc52: bool, use_system_clipboard: bool) {
     if use_osc52 {
-        if let Err(e) = execute!(stdout(), CopyToClipboard::to_clipboard_from(text)) {
+        if let Err(e) = stdout().write_all(&osc52_sequence(text)) {
             tracing::debug!("OSC 52 clipboard copy failed: {}", e);
         }
         #[allow(clippy::let_underscore_must_use)]
@@ -44,6 +43,12 @@ pub fn copy_to_system_clipboard(text: &str, use_osc52: bool, use_system_clipboar
     }
 }
 
+/// Build the OSC 52 escape sequence asking the terminal to put `text` on
+/// the system clipboard (`ESC ] 52 ; c ; <base64> BEL`).
+pub fn osc52_sequence(text: &str) -> Vec<u8> {
+    format!("\x1b]52;c;{}\x07", BASE64.encode(text)).into_bytes()
+}
+
 /// Set text on the arboard system clipboard, creating it if needed.
 fn set_system_clipboard_text(text: &str) {
     if let Ok(mut guard) = SYSTEM_CLIPBOARD.lock() {
@@ -94,11 +99,16 @@ pub struct Clipboard {
     use_osc52: bool,
     /// When true, system clipboard (arboard/X11/Wayland) is used for copy/paste
     use_system_clipboard: bool,
+    /// Copies larger than this many bytes are not sent via OSC 52
+    osc52_max_bytes: usize,
     /// When true, skip direct stdout writes (OSC 52 / arboard) and queue text
     /// for the server to send to clients via control messages instead
     session_mode: bool,
     /// Clipboard data pending delivery to clients (session mode only)
     pending_clipboard: Option<PendingClipboard>,
+    /// OSC 52 sequence pending delivery in the clients' output stream
+    /// (session mode only)
+    pending_osc52: Option<Vec<u8>>,
 }
 
 impl Clipboard {
@@ -109,8 +119,10 @@ impl Clipboard {
             internal_only: false,
             use_osc52: true,
             use_system_clipboard: true,
+            osc52_max_bytes: crate::config::ClipboardConfig::default().osc52_max_bytes,
             session_mode: false,
             pending_clipboard: None,
+            pending_osc52: None,
         }
     }
 
@@ -119,6 +131,7 @@ impl Clipboard {
     pub fn apply_config(&mut self, config: &crate::config::ClipboardConfig) {
         self.use_osc52 = config.use_osc52;
         self.use_system_clipboard = config.use_system_clipboard;
+        self.osc52_max_bytes = config.osc52_max_bytes;
     }
 
     /// Enable internal-only mode (for testing)
@@ -139,6 +152,27 @@ impl Clipboard {
         self.pending_clipboard.take()
     }
 
+    /// Take the OSC 52 sequence queued in session mode, clearing the request
+    pub fn take_pending_osc52(&mut self) -> Option<Vec<u8>> {
+        self.pending_osc52.take()
+    }
+
+    /// Whether `text` should be sent via OSC 52: enabled and within the size cap
+    fn osc52_enabled_for(&self, text: &str) -> bool {
+        if !self.use_osc52 {
+            return false;
+        }
+        if text.len() > self.osc52_max_bytes {
+            tracing::debug!(
+                "Skipping OSC 52 copy: {} bytes exceeds osc52_max_bytes ({})",
+                text.len(),
+                self.osc52_max_bytes
+            );
+            return false;
+        }
+        true
+    }
+
     /// Copy HTML-formatted text to the system clipboard
     ///
     /// Uses arboard to copy HTML with a plain text fallback.
@@ -189,19 +223,24 @@ impl Clipboard {
     /// Methods can be disabled via clipboard configuration.
     pub fn copy(&mut self, text: String) {
         self.internal = text.clone();
+        let use_osc52 = self.osc52_enabled_for(&text);
 
         // In session mode, the server process has no terminal or display server.
-        // Queue the text for delivery to clients via a control message instead.
+        // The OSC 52 sequence rides along with the rendered output so it reaches
+        // the client's terminal; the rest is delivered via a control message.
         if self.session_mode {
+            if use_osc52 {
+                self.pending_osc52 = Some(osc52_sequence(&text));
+            }
             self.pending_clipboard = Some(PendingClipboard {
                 text,
-                use_osc52: self.use_osc52,
+                use_osc52: false,
                 use_system_clipboard: self.use_system_clipboard,
             });
             return;
         }
 
-        copy_to_system_clipboard(&text, self.use_osc52, self.use_system_clipboard);
+        copy_to_system_clipboard(&text, use_osc52, self.use_system_clipboard);
     }
 
     /// Get text from clipboard, preferring system clipboard
@@ -316,6 +355,7 @@ mod tests {
         let config = crate::config::ClipboardConfig {
             use_osc52: false,
             use_system_clipboard: true,
+            ..Default::default()
         };
         clipboard.apply_config(&config);
         assert!(!clipboard.use_osc52);
@@ -328,6 +368,7 @@ mod tests {
         let config = crate::config::ClipboardConfig {
             use_osc52: true,
             use_system_clipboard: false,
+            ..Default::default()
         };
         clipboard.apply_config(&config);
         assert!(clipboard.use_osc52);
@@ -340,10 +381,40 @@ mod tests {
         let config = crate::config::ClipboardConfig {
             use_osc52: false,
             use_system_clipboard: false,
+            ..Default::default()
         };
         clipboard.apply_config(&config);
 
         clipboard.copy("internal only".to_string());
         assert_eq!(clipboard.get_internal(), "internal only");
     }
+
+    #[test]
+    fn test_osc52_sequence_encodes_payload() {
+        assert_eq!(osc52_sequence("hello"), b"\x1b]52;c;aGVsbG8=\x07".to_vec());
+        assert_eq!(osc52_sequence(""), b"\x1b]52;c;\x07".to_vec());
+    }
+
+    #[test]
+    fn test_session_mode_queues_osc52_within_size_cap() {
+        let mut clipboard = Clipboard::new();
+        clipboard.apply_config(&crate::config::ClipboardConfig {
+            use_system_clipboard: false,
+            osc52_max_bytes: 5,
+            ..Default::default()
+        });
+        clipboard.set_session_mode(true);
+
+        clipboard.copy("hello".to_string());
+        assert_eq!(
+            clipboard.take_pending_osc52(),
+            Some(osc52_sequence("hello"))
+        );
+        let pending = clipboard.take_pending_clipboard().unwrap();
+        assert!(!pending.use_osc52, "OSC 52 is sent in the output stream");
+
+        clipboard.copy("too long".to_string());
+        assert_eq!(clipboard.take_pending_osc52(), None);
+        assert_eq!(clipboard.get_internal(), "too long");
+    }
 }
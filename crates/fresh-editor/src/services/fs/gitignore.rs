@@ -0,0 +1,61 @@
+// `.gitignore` filtering for `FileSystem::walk_files`
+//
+// `walk_files` only knows about a fixed list of skipped directory names.
+// Callers that should match what git (and the file explorer) consider
+// ignored run each walked file through `WalkGitignores`.
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use crate::model::filesystem::FileSystem;
+use crate::view::file_tree::IgnorePatterns;
+
+/// `.gitignore` rules gathered during a directory walk.
+///
+/// Each directory's `.gitignore` is read through the walk's filesystem the
+/// first time a file beneath it is seen, so this works for remote trees too.
+pub struct WalkGitignores<'a> {
+    fs: &'a dyn FileSystem,
+    root: &'a Path,
+    patterns: IgnorePatterns,
+    loaded: HashSet<PathBuf>,
+}
+
+impl<'a> WalkGitignores<'a> {
+    pub fn new(fs: &'a dyn FileSystem, root: &'a Path) -> Self {
+        let mut patterns = IgnorePatterns::new();
+        // `walk_files` already skips hidden entries; only gitignore rules apply
+        patterns.set_show_hidden(true);
+        Self {
+            fs,
+            root,
+            patterns,
+            loaded: HashSet::new(),
+        }
+    }
+
+    /// Whether `path` (a file under the root) or any directory between it
+    /// and the root is gitignored.
+    pub fn is_ignored(&mut self, path: &Path) -> bool {
+        let Some(parent) = path.parent() else {
+            return false;
+        };
+        let dirs: Vec<&Path> = parent
+            .ancestors()
+            .take_while(|dir| dir.starts_with(self.root))
+            .collect();
+        // Load outermost first so nested rules are known before matching
+        for dir in dirs.iter().rev() {
+            if self.loaded.insert(dir.to_path_buf()) {
+                if let Ok(contents) = self.fs.read_file(&dir.join(".gitignore")) {
+                    self.patterns
+                        .load_gitignore_from_bytes(dir, &contents, None);
+                }
+            }
+        }
+        dirs.iter()
+            .filter(|dir| **dir != self.root)
+            .any(|dir| self.patterns.is_ignored(dir, true))
+            || self.patterns.is_ignored(path, false)
+    }
+}
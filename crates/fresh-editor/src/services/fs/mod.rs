@@ -3,6 +3,7 @@
 // This module provides async filesystem operations with request deduplication
 // and batching, wrapping the core FileSystem trait from model/filesystem.
 
+pub mod gitignore;
 pub mod manager;
 pub mod slow;
 
@@ -11,5 +12,6 @@ pub use crate::model::filesystem::{
     DirEntry, EntryType, FileMetadata, FilePermissions, FileReader, FileSystem, FileSystemExt,
     FileWriter, NoopFileSystem, StdFileSystem,
 };
+pub use gitignore::WalkGitignores;
 pub use manager::FsManager;
 pub use slow::{BackendMetrics, SlowFileSystem, SlowFsConfig};
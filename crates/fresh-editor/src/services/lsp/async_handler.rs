@@ -36,7 +36,7 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::collections::HashMap;
 use std::path::PathBuf;
-use std::sync::atomic::{AtomicBool, AtomicI64, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicI64, AtomicU32, Ordering};
 use std::sync::{mpsc as std_mpsc, Arc, Mutex};
 use std::time::{Duration, Instant};
 use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
@@ -262,13 +262,14 @@ impl LspClientState {
 fn create_client_capabilities() -> ClientCapabilities {
     use lsp_types::{
         CodeActionClientCapabilities, CompletionClientCapabilities, DiagnosticClientCapabilities,
-        DiagnosticTag, DynamicRegistrationClientCapabilities, FoldingRangeCapability,
-        FoldingRangeClientCapabilities, FoldingRangeKind, FoldingRangeKindCapability,
-        GeneralClientCapabilities, GotoCapability, HoverClientCapabilities,
-        InlayHintClientCapabilities, MarkupKind, PublishDiagnosticsClientCapabilities,
-        RenameClientCapabilities, SignatureHelpClientCapabilities, TagSupport,
-        TextDocumentClientCapabilities, TextDocumentSyncClientCapabilities,
-        WorkspaceClientCapabilities, WorkspaceEditClientCapabilities,
+        DiagnosticTag, DocumentSymbolClientCapabilities, DynamicRegistrationClientCapabilities,
+        FoldingRangeCapability, FoldingRangeClientCapabilities, FoldingRangeKind,
+        FoldingRangeKindCapability, GeneralClientCapabilities, GotoCapability,
+        HoverClientCapabilities, InlayHintClientCapabilities, MarkupKind,
+        PublishDiagnosticsClientCapabilities, RenameClientCapabilities,
+        SignatureHelpClientCapabilities, TagSupport, TextDocumentClientCapabilities,
+        TextDocumentSyncClientCapabilities, WorkspaceClientCapabilities,
+        WorkspaceEditClientCapabilities, WorkspaceSymbolClientCapabilities,
     };
 
     ClientCapabilities {
@@ -283,6 +284,9 @@ fn create_client_capabilities() -> ClientCapabilities {
                 ..Default::default()
             }),
             workspace_folders: Some(true),
+            symbol: Some(WorkspaceSymbolClientCapabilities {
+                ..Default::default()
+            }),
             ..Default::default()
         }),
         text_document: Some(TextDocumentClientCapabilities {
@@ -305,6 +309,10 @@ fn create_client_capabilities() -> ClientCapabilities {
                 ..Default::default()
             }),
             references: Some(DynamicRegistrationClientCapabilities::default()),
+            document_symbol: Some(DocumentSymbolClientCapabilities {
+                hierarchical_document_symbol_support: Some(true),
+                ..Default::default()
+            }),
             code_action: Some(CodeActionClientCapabilities {
                 ..Default::default()
             }),
@@ -648,6 +656,12 @@ enum LspCommand {
     /// Request folding ranges for a document
     FoldingRange { request_id: u64, uri: Uri },
 
+    /// Request the symbol outline of a document
+    DocumentSymbols { request_id: u64, uri: Uri },
+
+    /// Search symbols across the workspace
+    WorkspaceSymbols { request_id: u64, query: String },
+
     /// Request semantic tokens for the entire document
     SemanticTokensFull { request_id: u64, uri: Uri },
 
@@ -2116,6 +2130,7 @@ impl LspState {
                         result_id,
                         diagnostics,
                         unchanged: false,
+                        server_name: (*self.server_name).clone(),
                     });
                 } else if let Ok(unchanged_report) = serde_json::from_value::<
                     lsp_types::RelatedUnchangedDocumentDiagnosticReport,
@@ -2137,6 +2152,7 @@ impl LspState {
                         result_id: Some(result_id),
                         diagnostics: Vec::new(),
                         unchanged: true,
+                        server_name: (*self.server_name).clone(),
                     });
                 } else {
                     // Fallback: try to parse as DocumentDiagnosticReportResult
@@ -2150,6 +2166,7 @@ impl LspState {
                         result_id: None,
                         diagnostics: Vec::new(),
                         unchanged: false,
+                        server_name: (*self.server_name).clone(),
                     });
                 }
 
@@ -2164,6 +2181,7 @@ impl LspState {
                     result_id: None,
                     diagnostics: Vec::new(),
                     unchanged: false,
+                    server_name: (*self.server_name).clone(),
                 });
                 Err(e)
             }
@@ -2302,6 +2320,94 @@ impl LspState {
         }
     }
 
+    /// Handle document symbol request
+    async fn handle_document_symbols(
+        &self,
+        request_id: u64,
+        uri: Uri,
+        pending: &PendingRequests,
+    ) -> Result<(), String> {
+        use lsp_types::{DocumentSymbolParams, DocumentSymbolResponse};
+
+        tracing::trace!("LSP: document symbol request for {}", uri.as_str());
+
+        let params = DocumentSymbolParams {
+            text_document: TextDocumentIdentifier { uri },
+            work_done_progress_params: WorkDoneProgressParams::default(),
+            partial_result_params: PartialResultParams::default(),
+        };
+
+        let result = self
+            .send_request_sequential::<_, Option<DocumentSymbolResponse>>(
+                "textDocument/documentSymbol",
+                Some(params),
+                pending,
+            )
+            .await;
+
+        match result {
+            Ok(response) => {
+                let _ = self.async_tx.send(AsyncMessage::LspDocumentSymbols {
+                    request_id,
+                    response,
+                });
+                Ok(())
+            }
+            Err(e) => {
+                tracing::debug!("Document symbol request failed: {}", e);
+                let _ = self.async_tx.send(AsyncMessage::LspDocumentSymbols {
+                    request_id,
+                    response: None,
+                });
+                Err(e)
+            }
+        }
+    }
+
+    /// Handle workspace symbol request
+    async fn handle_workspace_symbols(
+        &self,
+        request_id: u64,
+        query: String,
+        pending: &PendingRequests,
+    ) -> Result<(), String> {
+        use lsp_types::{WorkspaceSymbolParams, WorkspaceSymbolResponse};
+
+        tracing::trace!("LSP: workspace symbol request for {:?}", query);
+
+        let params = WorkspaceSymbolParams {
+            query,
+            work_done_progress_params: WorkDoneProgressParams::default(),
+            partial_result_params: PartialResultParams::default(),
+        };
+
+        let result = self
+            .send_request_sequential::<_, Option<WorkspaceSymbolResponse>>(
+                "workspace/symbol",
+                Some(params),
+                pending,
+            )
+            .await;
+
+        match result {
+            Ok(response) => {
+                let _ = self.async_tx.send(AsyncMessage::LspWorkspaceSymbols {
+                    request_id,
+                    response,
+                });
+                Ok(())
+            }
+            Err(e) => {
+                tracing::debug!("Workspace symbol request failed: {}", e);
+                let _ = self.async_tx.send(AsyncMessage::LspWorkspaceSymbols {
+                    request_id,
+                    response: None,
+                });
+                Err(e)
+            }
+        }
+    }
+
     async fn handle_semantic_tokens_full(
         &self,
         request_id: u64,
@@ -2551,8 +2657,9 @@ struct LspTask {
     /// Next request ID
     next_id: i64,
 
-    /// Pending requests waiting for response
-    pending: HashMap<i64, oneshot::Sender<Result<Value, String>>>,
+    /// Pending requests waiting for response (shared with the handle so
+    /// the status popup can report how many are in flight)
+    pending: PendingRequests,
 
     /// Server capabilities
     capabilities: Option<ServerCapabilities>,
@@ -2608,6 +2715,8 @@ impl LspTask {
         stderr_log_path: std::path::PathBuf,
         language_id_overrides: HashMap<String, String>,
         document_versions: Arc<std::sync::Mutex<HashMap<PathBuf, i64>>>,
+        pending: PendingRequests,
+        process_id: Arc<AtomicU32>,
         long_running_spawner: Arc<dyn crate::services::remote::LongRunningSpawner>,
     ) -> Result<Self, String> {
         tracing::info!("Spawning async LSP server: {} {:?}", command, args);
@@ -2637,6 +2746,7 @@ impl LspTask {
             .spawn_stdio(command, args, env_pairs, None, Some(process_limits))
             .await
             .map_err(|e| format!("Failed to spawn LSP server '{}': {}", command, e))?;
+        process_id.store(stdio_child.id().unwrap_or(0), Ordering::Relaxed);
 
         let stdin = stdio_child
             .take_stdin()
@@ -2694,7 +2804,7 @@ impl LspTask {
             stdin,
             stdout,
             next_id: 0,
-            pending: HashMap::new(),
+            pending,
             capabilities: None,
             document_versions,
             pending_opens: HashMap::new(),
@@ -2822,7 +2932,7 @@ impl LspTask {
             language_id_overrides: Arc::new(self.language_id_overrides.clone()),
         };
 
-        let pending = Arc::new(Mutex::new(self.pending));
+        let pending = self.pending;
         let async_tx = state.async_tx.clone();
         let language_clone: String = (*state.language).clone();
         let server_name: String = (*state.server_name).clone();
@@ -3218,6 +3328,7 @@ impl LspTask {
                             result_id: None,
                             diagnostics: Vec::new(),
                             unchanged: false,
+                            server_name: server_name.clone(),
                         });
                     }
                 }
@@ -3260,6 +3371,34 @@ impl LspTask {
                         });
                     }
                 }
+                LspCommand::DocumentSymbols { request_id, uri } => {
+                    if initialized {
+                        tracing::info!("Processing DocumentSymbols request for {}", uri.as_str());
+                        spawn_request!(state, pending, |s, p| s
+                            .handle_document_symbols(request_id, uri, &p)
+                            .await);
+                    } else {
+                        tracing::trace!("LSP not initialized, cannot get document symbols");
+                        let _ = state.async_tx.send(AsyncMessage::LspDocumentSymbols {
+                            request_id,
+                            response: None,
+                        });
+                    }
+                }
+                LspCommand::WorkspaceSymbols { request_id, query } => {
+                    if initialized {
+                        tracing::info!("Processing WorkspaceSymbols request for {:?}", query);
+                        spawn_request!(state, pending, |s, p| s
+                            .handle_workspace_symbols(request_id, query, &p)
+                            .await);
+                    } else {
+                        tracing::trace!("LSP not initialized, cannot search workspace symbols");
+                        let _ = state.async_tx.send(AsyncMessage::LspWorkspaceSymbols {
+                            request_id,
+                            response: None,
+                        });
+                    }
+                }
                 LspCommand::SemanticTokensFull { request_id, uri } => {
                     if initialized {
                         tracing::info!("Processing SemanticTokens request for {}", uri.as_str());
@@ -3989,6 +4128,13 @@ pub struct LspHandle {
     /// Document version tracking (shared with the async LSP task).
     /// Used to check document versions in workspace/applyEdit.
     document_versions: Arc<std::sync::Mutex<HashMap<PathBuf, i64>>>,
+
+    /// Requests sent to the server that are still awaiting a response
+    /// (shared with the async LSP task).
+    pending: PendingRequests,
+
+    /// OS process id of the server, or 0 until the process has been spawned.
+    process_id: Arc<AtomicU32>,
 }
 
 // Channel sends and state transitions in LspHandle are best-effort: async_tx.send()
@@ -4043,6 +4189,10 @@ impl LspHandle {
         let document_versions: Arc<std::sync::Mutex<HashMap<PathBuf, i64>>> =
             Arc::new(std::sync::Mutex::new(HashMap::new()));
         let document_versions_for_task = document_versions.clone();
+        let pending: PendingRequests = Arc::new(Mutex::new(HashMap::new()));
+        let pending_for_task = pending.clone();
+        let process_id = Arc::new(AtomicU32::new(0));
+        let process_id_for_task = process_id.clone();
 
         let state_clone = state.clone();
         let stderr_log_path_clone = stderr_log_path.clone();
@@ -4058,6 +4208,8 @@ impl LspHandle {
                 stderr_log_path_clone.clone(),
                 language_id_overrides,
                 document_versions_for_task,
+                pending_for_task,
+                process_id_for_task,
                 long_running_spawner,
             )
             .await
@@ -4129,6 +4281,8 @@ impl LspHandle {
             state,
             runtime: runtime.clone(),
             document_versions,
+            pending,
+            process_id,
         })
     }
 
@@ -4151,6 +4305,30 @@ impl LspHandle {
             .and_then(|versions| versions.get(path).copied())
     }
 
+    /// OS process id of the server, once it has been spawned.
+    ///
+    /// For container or remote authorities this is the id of the local
+    /// wrapper process rather than the server itself.
+    pub fn process_id(&self) -> Option<u32> {
+        match self.process_id.load(Ordering::Relaxed) {
+            0 => None,
+            pid => Some(pid),
+        }
+    }
+
+    /// Number of documents currently open with this server.
+    pub fn open_document_count(&self) -> usize {
+        self.document_versions
+            .lock()
+            .map(|versions| versions.len())
+            .unwrap_or(0)
+    }
+
+    /// Number of requests sent to this server that are still awaiting a response.
+    pub fn pending_request_count(&self) -> usize {
+        self.pending.lock().map(|p| p.len()).unwrap_or(0)
+    }
+
     /// Initialize the server (non-blocking)
     ///
     /// This sends the initialize request asynchronously. The server will be ready
@@ -4597,6 +4775,20 @@ impl LspHandle {
             .map_err(|_| "Failed to send folding_range command".to_string())
     }
 
+    /// Request the symbol outline of a document
+    pub fn document_symbols(&self, request_id: u64, uri: Uri) -> Result<(), String> {
+        self.command_tx
+            .try_send(LspCommand::DocumentSymbols { request_id, uri })
+            .map_err(|_| "Failed to send document_symbols command".to_string())
+    }
+
+    /// Search symbols across the workspace matching `query`
+    pub fn workspace_symbols(&self, request_id: u64, query: String) -> Result<(), String> {
+        self.command_tx
+            .try_send(LspCommand::WorkspaceSymbols { request_id, query })
+            .map_err(|_| "Failed to send workspace_symbols command".to_string())
+    }
+
     /// Request semantic tokens for an entire document
     pub fn semantic_tokens_full(&self, request_id: u64, uri: Uri) -> Result<(), String> {
         self.command_tx
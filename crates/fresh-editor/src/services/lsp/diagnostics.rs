@@ -17,11 +17,33 @@ pub fn lsp_diagnostic_namespace() -> OverlayNamespace {
     OverlayNamespace::from_string("lsp-diagnostic".to_string())
 }
 
+/// Overlay priority of an error diagnostic (highest)
+pub const ERROR_PRIORITY: i32 = 100;
+/// Overlay priority of a warning diagnostic
+pub const WARNING_PRIORITY: i32 = 50;
+/// Overlay priority of an information diagnostic
+pub const INFO_PRIORITY: i32 = 30;
+/// Overlay priority of a hint diagnostic (lowest)
+pub const HINT_PRIORITY: i32 = 10;
+
 /// Cache for diagnostic hash to avoid redundant updates, keyed by file path.
 /// This prevents diagnostics from one buffer from invalidating another buffer's cache.
 static DIAGNOSTIC_CACHE: LazyLock<Mutex<HashMap<String, u64>>> =
     LazyLock::new(|| Mutex::new(HashMap::new()));
 
+/// A hashable value for a diagnostic severity (`DiagnosticSeverity` itself
+/// isn't `Hash`)
+pub(crate) fn severity_value(severity: Option<DiagnosticSeverity>) -> i32 {
+    match severity {
+        Some(DiagnosticSeverity::ERROR) => 1,
+        Some(DiagnosticSeverity::WARNING) => 2,
+        Some(DiagnosticSeverity::INFORMATION) => 3,
+        Some(DiagnosticSeverity::HINT) => 4,
+        None => 0,
+        _ => -1,
+    }
+}
+
 /// Compute a hash for a slice of diagnostics
 /// This hash is used to quickly detect if diagnostics have changed
 fn compute_diagnostic_hash(diagnostics: &[Diagnostic]) -> u64 {
@@ -38,16 +60,7 @@ fn compute_diagnostic_hash(diagnostics: &[Diagnostic]) -> u64 {
         diag.range.end.line.hash(&mut hasher);
         diag.range.end.character.hash(&mut hasher);
 
-        // Hash severity - match on all variants to get a hashable value
-        let severity_value: i32 = match diag.severity {
-            Some(DiagnosticSeverity::ERROR) => 1,
-            Some(DiagnosticSeverity::WARNING) => 2,
-            Some(DiagnosticSeverity::INFORMATION) => 3,
-            Some(DiagnosticSeverity::HINT) => 4,
-            None => 0,
-            _ => -1,
-        };
-        severity_value.hash(&mut hasher);
+        severity_value(diag.severity).hash(&mut hasher);
 
         // Hash the message (most important part)
         diag.message.hash(&mut hasher);
@@ -184,28 +197,28 @@ pub fn diagnostic_to_overlay(
             OverlayFace::Background {
                 color: theme.diagnostic_error_bg,
             },
-            100, // Highest priority
+            ERROR_PRIORITY,
             "diagnostic.error_bg",
         ),
         Some(DiagnosticSeverity::WARNING) => (
             OverlayFace::Background {
                 color: theme.diagnostic_warning_bg,
             },
-            50, // Medium priority
+            WARNING_PRIORITY,
             "diagnostic.warning_bg",
         ),
         Some(DiagnosticSeverity::INFORMATION) => (
             OverlayFace::Background {
                 color: theme.diagnostic_info_bg,
             },
-            30, // Lower priority
+            INFO_PRIORITY,
             "diagnostic.info_bg",
         ),
         Some(DiagnosticSeverity::HINT) | None => (
             OverlayFace::Background {
                 color: theme.diagnostic_hint_bg,
             },
-            10, // Lowest priority
+            HINT_PRIORITY,
             "diagnostic.hint_bg",
         ),
         _ => return None, // Unknown severity
@@ -313,7 +326,7 @@ mod tests {
 
         let (range, face, priority, theme_key) = result.unwrap();
         assert_eq!(range, 0..5);
-        assert_eq!(priority, 100); // Error has highest priority
+        assert_eq!(priority, ERROR_PRIORITY);
         assert_eq!(theme_key, "diagnostic.error_bg");
 
         match face {
@@ -355,7 +368,7 @@ mod tests {
 
         let (range, face, priority, theme_key) = result.unwrap();
         assert_eq!(range, 6..11);
-        assert_eq!(priority, 50); // Warning has medium priority
+        assert_eq!(priority, WARNING_PRIORITY);
         assert_eq!(theme_key, "diagnostic.warning_bg");
 
         match face {
@@ -2040,10 +2040,13 @@ mod tests {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
         languages.insert(
@@ -2064,10 +2067,13 @@ mod tests {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
         languages.insert(
@@ -2088,10 +2094,13 @@ mod tests {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
         languages
@@ -2153,10 +2162,13 @@ mod tests {
                 page_width: None,
                 use_tabs: None,
                 tab_size: None,
+                indent_style: None,
+                indent_width: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
                 word_characters: None,
+                run_command: None,
             },
         );
 
@@ -2268,10 +2280,13 @@ mod tests {
             page_width: None,
             use_tabs: None,
             tab_size: None,
+            indent_style: None,
+            indent_width: None,
             formatter: None,
             format_on_save: false,
             on_save: vec![],
             word_characters: None,
+            run_command: None,
         };
         languages.insert(
             "c".to_string(),
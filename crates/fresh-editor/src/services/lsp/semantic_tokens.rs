@@ -20,14 +20,21 @@ pub fn is_semantic_token_overlay(overlay: &crate::view::overlay::Overlay) -> boo
 }
 
 /// Map an LSP semantic token type + modifiers to a theme color.
+///
+/// A theme scope override for the token type (e.g. `macro` or
+/// `rust.macro`) takes precedence over the built-in mapping below.
 pub fn semantic_token_color(
     token_type: &str,
     modifiers: &[String],
+    language: &str,
     theme: &crate::view::theme::Theme,
 ) -> Color {
     if modifiers.iter().any(|m| m == "deprecated") {
         return theme.diagnostic_warning_fg;
     }
+    if let Some(color) = theme.scope_color(language, token_type) {
+        return color;
+    }
 
     match token_type {
         "keyword" | "modifier" => theme.syntax_keyword,
@@ -76,7 +83,8 @@ pub fn apply_semantic_tokens_range_to_state(
     let mut new_overlays = Vec::with_capacity(tokens.len());
 
     for token in tokens {
-        let color = semantic_token_color(&token.token_type, &token.modifiers, theme);
+        let color =
+            semantic_token_color(&token.token_type, &token.modifiers, &state.language, theme);
         let overlay = Overlay::with_namespace(
             &mut state.marker_list,
             token.range.clone(),
@@ -168,4 +176,23 @@ mod tests {
             .range(&state.marker_list);
         assert_eq!(moved_range, 6..10);
     }
+
+    #[test]
+    fn semantic_token_color_prefers_scope_overrides() {
+        let mut theme = Theme::load_builtin(THEME_DARK).expect("dark theme must exist");
+        let any_macro = Color::Rgb(0, 128, 255);
+        let rust_macro = Color::Rgb(255, 0, 128);
+        theme.scopes.insert("macro".to_string(), any_macro);
+        theme.scopes.insert("rust.macro".to_string(), rust_macro);
+
+        assert_eq!(
+            semantic_token_color("macro", &[], "rust", &theme),
+            rust_macro
+        );
+        assert_eq!(semantic_token_color("macro", &[], "c", &theme), any_macro);
+        assert_eq!(
+            semantic_token_color("function", &[], "rust", &theme),
+            theme.syntax_function
+        );
+    }
 }
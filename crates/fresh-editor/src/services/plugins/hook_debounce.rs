@@ -0,0 +1,174 @@
+//! Coalescing of high-frequency plugin hooks.
+//!
+//! Some hooks fire on every keystroke or mouse movement (`prompt_changed`,
+//! `mouse_move`). Running a JS handler for each one can fall behind fast
+//! typing, so hooks named in `editor.plugin_debounced_hooks` are delivered
+//! at most once per `editor.plugin_hook_debounce_ms`:
+//!
+//! - the first call after a quiet interval goes out immediately;
+//! - later calls inside the interval are held, and only the latest
+//!   arguments are kept;
+//! - the held call goes out once the interval has passed (on the next
+//!   editor tick), or before any other hook so plugins still see hooks in
+//!   the order they fired.
+//!
+//! All other hooks are delivered immediately.
+
+use std::collections::{HashMap, HashSet};
+use std::time::{Duration, Instant};
+
+use super::hooks::HookArgs;
+use crate::services::time_source::SharedTimeSource;
+
+/// Decides when debounced hooks are delivered.
+#[derive(Debug)]
+pub struct HookDebouncer {
+    interval: Duration,
+    hooks: HashSet<String>,
+    time_source: SharedTimeSource,
+    /// When each debounced hook was last delivered
+    last_sent: HashMap<String, Instant>,
+    /// Held calls (latest arguments per hook), oldest first
+    pending: Vec<(String, HookArgs)>,
+}
+
+impl HookDebouncer {
+    pub fn new(
+        interval: Duration,
+        hooks: impl IntoIterator<Item = String>,
+        time_source: SharedTimeSource,
+    ) -> Self {
+        Self {
+            interval,
+            hooks: hooks.into_iter().collect(),
+            time_source,
+            last_sent: HashMap::new(),
+            pending: Vec::new(),
+        }
+    }
+
+    /// Route one hook call. Returns the calls to deliver now, in order:
+    /// empty when the call is held back.
+    pub fn submit(&mut self, hook_name: &str, args: HookArgs) -> Vec<(String, HookArgs)> {
+        if self.interval.is_zero() || !self.hooks.contains(hook_name) {
+            // Deliver anything held first to keep hooks in order
+            let mut out = std::mem::take(&mut self.pending);
+            out.push((hook_name.to_string(), args));
+            return out;
+        }
+
+        let now = self.time_source.now();
+        let due = self
+            .last_sent
+            .get(hook_name)
+            .is_none_or(|&last| now.saturating_duration_since(last) >= self.interval);
+        if due && !self.pending.iter().any(|(name, _)| name == hook_name) {
+            self.last_sent.insert(hook_name.to_string(), now);
+            return vec![(hook_name.to_string(), args)];
+        }
+
+        match self.pending.iter_mut().find(|(name, _)| name == hook_name) {
+            Some((_, held)) => *held = args,
+            None => self.pending.push((hook_name.to_string(), args)),
+        }
+        Vec::new()
+    }
+
+    /// Take the held calls whose interval has passed.
+    pub fn take_due(&mut self) -> Vec<(String, HookArgs)> {
+        if self.pending.is_empty() {
+            return Vec::new();
+        }
+        let now = self.time_source.now();
+        let interval = self.interval;
+        let last_sent = &self.last_sent;
+        let (due, held): (Vec<_>, Vec<_>) = std::mem::take(&mut self.pending)
+            .into_iter()
+            .partition(|(name, _)| {
+                last_sent
+                    .get(name)
+                    .is_none_or(|&last| now.saturating_duration_since(last) >= interval)
+            });
+        self.pending = held;
+        for (name, _) in &due {
+            self.last_sent.insert(name.clone(), now);
+        }
+        due
+    }
+
+    /// Take every held call, due or not.
+    pub fn take_all(&mut self) -> Vec<(String, HookArgs)> {
+        std::mem::take(&mut self.pending)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::time_source::TestTimeSource;
+    use std::sync::Arc;
+
+    fn changed(input: &str) -> HookArgs {
+        HookArgs::PromptChanged {
+            prompt_type: "test".to_string(),
+            input: input.to_string(),
+        }
+    }
+
+    fn inputs(calls: &[(String, HookArgs)]) -> Vec<String> {
+        calls
+            .iter()
+            .map(|(name, args)| match args {
+                HookArgs::PromptChanged { input, .. } => format!("{name}:{input}"),
+                _ => name.clone(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_debounced_hook_coalesces_to_latest() {
+        let time = Arc::new(TestTimeSource::new());
+        let mut debouncer = HookDebouncer::new(
+            Duration::from_millis(50),
+            ["prompt_changed".to_string()],
+            time.clone(),
+        );
+
+        assert_eq!(
+            inputs(&debouncer.submit("prompt_changed", changed("a"))),
+            ["prompt_changed:a"]
+        );
+        for input in ["ab", "abc", "abcd"] {
+            assert!(debouncer
+                .submit("prompt_changed", changed(input))
+                .is_empty());
+        }
+        assert!(debouncer.take_due().is_empty());
+
+        time.advance(Duration::from_millis(50));
+        assert_eq!(inputs(&debouncer.take_due()), ["prompt_changed:abcd"]);
+        assert!(debouncer.take_due().is_empty());
+    }
+
+    #[test]
+    fn test_other_hooks_flush_held_calls_first() {
+        let time = Arc::new(TestTimeSource::new());
+        let mut debouncer = HookDebouncer::new(
+            Duration::from_millis(50),
+            ["prompt_changed".to_string()],
+            time,
+        );
+
+        debouncer.submit("prompt_changed", changed("a"));
+        debouncer.submit("prompt_changed", changed("ab"));
+        let confirmed = HookArgs::PromptConfirmed {
+            prompt_type: "test".to_string(),
+            input: "ab".to_string(),
+            selected_index: None,
+        };
+        assert_eq!(
+            inputs(&debouncer.submit("prompt_confirmed", confirmed)),
+            ["prompt_changed:ab", "prompt_confirmed"]
+        );
+    }
+}
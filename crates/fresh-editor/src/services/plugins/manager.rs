@@ -15,8 +15,14 @@ use std::sync::{Arc, RwLock};
 #[cfg(feature = "plugins")]
 use super::bridge::EditorServiceBridge;
 #[cfg(feature = "plugins")]
+use super::hook_debounce::HookDebouncer;
+#[cfg(feature = "plugins")]
 use fresh_plugin_runtime::PluginThreadHandle;
 
+/// How long the editor waits for handlers of a hook that can veto an
+/// operation before going ahead without them.
+pub const VETO_HOOK_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(2);
+
 /// Unified plugin manager that abstracts over the plugin system.
 ///
 /// When the `plugins` feature is enabled, this wraps `PluginThreadHandle`.
@@ -24,6 +30,9 @@ use fresh_plugin_runtime::PluginThreadHandle;
 pub struct PluginManager {
     #[cfg(feature = "plugins")]
     inner: Option<PluginThreadHandle>,
+    /// Holds back high-frequency hooks (see `hook_debounce`)
+    #[cfg(feature = "plugins")]
+    hook_debouncer: std::sync::Mutex<HookDebouncer>,
     #[cfg(not(feature = "plugins"))]
     _phantom: std::marker::PhantomData<()>,
 }
@@ -51,6 +60,7 @@ impl PluginManager {
                     Ok(handle) => {
                         return Self {
                             inner: Some(handle),
+                            hook_debouncer: Self::immediate_hooks(),
                         }
                     }
                     Err(e) => {
@@ -62,7 +72,10 @@ impl PluginManager {
             } else {
                 tracing::info!("Plugins disabled via --no-plugins flag");
             }
-            Self { inner: None }
+            Self {
+                inner: None,
+                hook_debouncer: Self::immediate_hooks(),
+            }
         }
 
         #[cfg(not(feature = "plugins"))]
@@ -79,6 +92,79 @@ impl PluginManager {
         }
     }
 
+    /// A debouncer that delivers every hook immediately, used until
+    /// `configure_hook_debounce` is called.
+    #[cfg(feature = "plugins")]
+    fn immediate_hooks() -> std::sync::Mutex<HookDebouncer> {
+        std::sync::Mutex::new(HookDebouncer::new(
+            std::time::Duration::ZERO,
+            Vec::new(),
+            crate::services::time_source::RealTimeSource::shared(),
+        ))
+    }
+
+    /// Set how often the hooks in `hooks` may be delivered: at most once
+    /// per `interval`, keeping the latest arguments. A zero interval
+    /// delivers every hook immediately. Calls held under the previous
+    /// settings are delivered first.
+    pub fn configure_hook_debounce(
+        &self,
+        interval: std::time::Duration,
+        hooks: &[String],
+        time_source: crate::services::time_source::SharedTimeSource,
+    ) {
+        #[cfg(feature = "plugins")]
+        {
+            let held = std::mem::replace(
+                &mut *self.hook_debouncer.lock().unwrap(),
+                HookDebouncer::new(interval, hooks.iter().cloned(), time_source),
+            )
+            .take_all();
+            self.send_hooks(held);
+        }
+        #[cfg(not(feature = "plugins"))]
+        {
+            let _ = (interval, hooks, time_source);
+        }
+    }
+
+    /// Limit how long a single hook handler or action may run. Handlers
+    /// that run longer are aborted as if they had returned nothing, and a
+    /// plugin that times out `max_timeouts` times in a row is unloaded
+    /// (0: never). A zero `timeout` removes the limit; handlers of a veto
+    /// hook are still aborted after [`VETO_HOOK_TIMEOUT`].
+    pub fn configure_handler_limits(&self, timeout: std::time::Duration, max_timeouts: u32) {
+        #[cfg(feature = "plugins")]
+        {
+            if let Some(ref manager) = self.inner {
+                manager.set_handler_limits(timeout, max_timeouts);
+            }
+        }
+        #[cfg(not(feature = "plugins"))]
+        {
+            let _ = (timeout, max_timeouts);
+        }
+    }
+
+    /// Deliver held debounced hooks whose interval has passed. Called
+    /// from the editor tick.
+    pub fn flush_debounced_hooks(&self) {
+        #[cfg(feature = "plugins")]
+        {
+            let due = self.hook_debouncer.lock().unwrap().take_due();
+            self.send_hooks(due);
+        }
+    }
+
+    #[cfg(feature = "plugins")]
+    fn send_hooks(&self, calls: Vec<(String, super::hooks::HookArgs)>) {
+        if let Some(ref manager) = self.inner {
+            for (hook_name, args) in calls {
+                manager.run_hook(&hook_name, args);
+            }
+        }
+    }
+
     /// Check if the plugin system is active (has a running plugin thread).
     pub fn is_active(&self) -> bool {
         #[cfg(feature = "plugins")]
@@ -214,11 +300,15 @@ impl PluginManager {
     }
 
     /// Run a hook (fire-and-forget).
+    ///
+    /// Debounced hooks (see `configure_hook_debounce`) may be held back and
+    /// delivered later with only their latest arguments.
     pub fn run_hook(&self, hook_name: &str, args: super::hooks::HookArgs) {
         #[cfg(feature = "plugins")]
         {
-            if let Some(ref manager) = self.inner {
-                manager.run_hook(hook_name, args);
+            if self.inner.is_some() {
+                let calls = self.hook_debouncer.lock().unwrap().submit(hook_name, args);
+                self.send_hooks(calls);
             }
         }
         #[cfg(not(feature = "plugins"))]
@@ -227,6 +317,40 @@ impl PluginManager {
         }
     }
 
+    /// Run a hook whose handlers can veto the operation it announces
+    /// (`before_file_save`, `before_file_open`) and wait for the verdict.
+    ///
+    /// Returns false if any handler returned `false`. Allows the operation
+    /// if the plugins don't answer within [`VETO_HOOK_TIMEOUT`]. Callers
+    /// check `has_hook_handlers` first to skip building costly arguments.
+    pub fn run_veto_hook(&self, hook_name: &str, args: super::hooks::HookArgs) -> bool {
+        self.run_veto_hooks(hook_name, vec![args])
+            .pop()
+            .unwrap_or(true)
+    }
+
+    /// Run a veto hook for several operations at once, waiting at most
+    /// [`VETO_HOOK_TIMEOUT`] in total for all the verdicts.
+    ///
+    /// Returns one verdict per entry of `args`, in order.
+    pub fn run_veto_hooks(&self, hook_name: &str, args: Vec<super::hooks::HookArgs>) -> Vec<bool> {
+        #[cfg(feature = "plugins")]
+        {
+            // Held hooks fired before these; deliver them first
+            let held = self.hook_debouncer.lock().unwrap().take_all();
+            self.send_hooks(held);
+            match self.inner.as_ref() {
+                Some(m) => m.run_hooks_blocking(hook_name, args, VETO_HOOK_TIMEOUT),
+                None => vec![true; args.len()],
+            }
+        }
+        #[cfg(not(feature = "plugins"))]
+        {
+            let _ = hook_name;
+            vec![true; args.len()]
+        }
+    }
+
     /// Deliver a response to a pending async plugin operation.
     pub fn deliver_response(&self, response: super::api::PluginResponse) {
         #[cfg(feature = "plugins")]
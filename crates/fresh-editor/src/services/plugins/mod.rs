@@ -11,6 +11,8 @@ pub mod api {
 }
 pub mod bridge;
 pub mod event_hooks;
+#[cfg(feature = "plugins")]
+pub mod hook_debounce;
 pub mod hooks;
 pub mod manager;
 
@@ -2,6 +2,9 @@
 ///
 /// Provides cross-platform support for limiting memory and CPU usage of spawned processes.
 /// On Linux, uses user-delegated cgroups v2 if available, otherwise falls back to setrlimit.
+/// On macOS, memory is capped with setrlimit; on Windows, children are placed in a
+/// Job Object with memory and CPU-rate limits. Limit types a platform can't
+/// express are skipped rather than treated as errors.
 /// Memory and CPU limits are decoupled - memory can work without CPU delegation.
 // Re-export the type from the shared types module
 pub use crate::types::ProcessLimits;
@@ -29,6 +32,20 @@ pub struct PostSpawnAction {
     /// to `<dir>/cgroup.procs`).  `None` means no cgroup move is needed.
     #[cfg(target_os = "linux")]
     cgroup_dir: Option<PathBuf>,
+    /// Limits for a Job Object the child should be assigned to.  `None`
+    /// means no job is needed.
+    #[cfg(windows)]
+    job_limits: Option<JobLimits>,
+}
+
+/// Limits applied to a Windows Job Object.
+#[cfg(windows)]
+#[derive(Debug, Clone, Copy)]
+struct JobLimits {
+    /// Per-process committed memory limit in bytes
+    memory_bytes: Option<u64>,
+    /// CPU rate in hundredths of a percent of total system CPU (1..=10000)
+    cpu_rate: Option<u32>,
 }
 
 impl PostSpawnAction {
@@ -52,6 +69,19 @@ impl PostSpawnAction {
                 );
             }
         }
+
+        #[cfg(windows)]
+        if let Some(limits) = self.job_limits {
+            // The job outlives its handle for as long as the child runs, so
+            // the handle returned here can simply be dropped.
+            if let Err(e) = assign_to_limited_job(_child_pid, limits) {
+                tracing::info!(
+                    "Failed to assign child {} to a limited job object: {}",
+                    _child_pid,
+                    e
+                );
+            }
+        }
     }
 }
 
@@ -68,6 +98,8 @@ impl ProcessLimits {
     /// Apply these limits to a tokio Command before spawning
     ///
     /// On Linux, tries user-delegated cgroups v2, otherwise falls back to setrlimit.
+    /// On other Unix platforms, memory is capped with setrlimit. On Windows the
+    /// limits are recorded for a Job Object that the child joins after spawn.
     /// Memory and CPU limits are handled independently.
     ///
     /// Returns a `PostSpawnAction` that the caller must apply to the child
@@ -87,15 +119,133 @@ impl ProcessLimits {
             self.apply_linux_limits(_cmd)
         }
 
-        #[cfg(not(target_os = "linux"))]
+        #[cfg(all(unix, not(target_os = "linux")))]
+        {
+            Ok(self.apply_rlimit_limits(_cmd))
+        }
+
+        #[cfg(windows)]
+        {
+            Ok(self.apply_windows_limits())
+        }
+
+        #[cfg(not(any(unix, windows)))]
         {
-            // TODO: Implement for macOS using setrlimit
-            // TODO: Implement for Windows using Job Objects
-            tracing::info!("Process resource limits are not yet implemented for this platform");
+            tracing::info!("Process resource limits are not implemented for this platform");
             Ok(PostSpawnAction::default())
         }
     }
 
+    /// Log which mechanism enforces each limit.
+    #[cfg(any(unix, windows))]
+    fn log_limits(&self, memory_method: &str, cpu_method: &str) {
+        tracing::info!(
+            "Using resource limits: memory={} ({}), CPU={} ({})",
+            self.max_memory_percent
+                .map(|p| format!("{}%", p))
+                .unwrap_or("unlimited".to_string()),
+            memory_method,
+            self.max_cpu_percent
+                .map(|c| format!("{}%", c))
+                .unwrap_or("unlimited".to_string()),
+            cpu_method
+        );
+    }
+
+    /// Best-effort limits for non-Linux Unix (macOS, BSDs).
+    ///
+    /// Memory is capped with `RLIMIT_AS`. There is no per-process CPU *rate*
+    /// limit: `RLIMIT_CPU` caps total CPU seconds and would eventually kill a
+    /// long-lived server, so `max_cpu_percent` is not enforced here.
+    #[cfg(all(unix, not(target_os = "linux")))]
+    fn apply_rlimit_limits(&self, cmd: &mut tokio::process::Command) -> PostSpawnAction {
+        let memory_method = match self.memory_limit_bytes() {
+            Some(mem_limit) => {
+                // See the Linux fallback: only async-signal-safe calls here.
+                unsafe {
+                    cmd.pre_exec(move || {
+                        #[allow(clippy::let_underscore_must_use)]
+                        let _ = apply_memory_limit_setrlimit(mem_limit);
+                        Ok(())
+                    });
+                }
+                "setrlimit"
+            }
+            None => "none",
+        };
+        let cpu_method = if self.max_cpu_percent.is_some() {
+            "unavailable"
+        } else {
+            "none"
+        };
+        self.log_limits(memory_method, cpu_method);
+        PostSpawnAction::default()
+    }
+
+    /// Record Job Object limits for the child to be assigned after spawn.
+    #[cfg(windows)]
+    fn apply_windows_limits(&self) -> PostSpawnAction {
+        let memory_bytes = self.memory_limit_bytes();
+        let cpu_rate = self
+            .max_cpu_percent
+            .map(|percent| job_cpu_rate(percent, SystemResources::cpu_count().unwrap_or(1)));
+        let method = |set: bool| if set { "job object" } else { "none" };
+        self.log_limits(method(memory_bytes.is_some()), method(cpu_rate.is_some()));
+
+        let job_limits = (memory_bytes.is_some() || cpu_rate.is_some()).then_some(JobLimits {
+            memory_bytes,
+            cpu_rate,
+        });
+        PostSpawnAction { job_limits }
+    }
+
+    /// Name of the cgroup for children spawned with these limits. Each
+    /// distinct limit set maps to its own cgroup under this editor process.
+    #[cfg(target_os = "linux")]
+    fn cgroup_name(&self, editor_pid: u32) -> String {
+        let part = |value: Option<u32>| value.map_or("max".to_string(), |v| v.to_string());
+        format!(
+            "editor-limits-{}-mem{}-cpu{}",
+            editor_pid,
+            part(self.max_memory_percent),
+            part(self.max_cpu_percent)
+        )
+    }
+
+    /// Create (or reuse) the cgroup for these limits under `cgroup_base` and
+    /// write the limits into it.
+    ///
+    /// Limits are written on every call, not only when the cgroup is
+    /// created, so a reused cgroup always carries exactly these limits.
+    /// Returns the cgroup directory and whether the memory and CPU limits
+    /// took effect, or `None` when neither did.
+    #[cfg(target_os = "linux")]
+    fn setup_limit_cgroup(&self, cgroup_base: &Path) -> Option<(PathBuf, bool, bool)> {
+        let cgroup_dir = cgroup_base.join(self.cgroup_name(std::process::id()));
+        let created = match fs::create_dir(&cgroup_dir) {
+            Ok(()) => true,
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => false,
+            Err(_) => return None,
+        };
+
+        let memory_set = self
+            .memory_limit_bytes()
+            .is_some_and(|bytes| set_cgroup_memory(&cgroup_dir, bytes).is_ok());
+        let cpu_set = self
+            .max_cpu_percent
+            .is_some_and(|percent| set_cgroup_cpu(&cgroup_dir, percent).is_ok());
+
+        if !memory_set && !cpu_set {
+            // If no limit took effect, drop the empty cgroup.
+            if created {
+                #[allow(clippy::let_underscore_must_use)]
+                let _ = fs::remove_dir(&cgroup_dir);
+            }
+            return None;
+        }
+        Some((cgroup_dir, memory_set, cpu_set))
+    }
+
     #[cfg(target_os = "linux")]
     fn apply_linux_limits(&self, cmd: &mut tokio::process::Command) -> io::Result<PostSpawnAction> {
         let max_memory_bytes = self.memory_limit_bytes();
@@ -109,72 +259,27 @@ impl ProcessLimits {
         let mut cpu_method = "none";
         let mut action = PostSpawnAction::default();
 
-        // Try to set up cgroup limits.  One cgroup per editor process; reused
-        // across all LSP spawns.  All children write their PIDs to the same
-        // `cgroup.procs` after spawn.
+        // Try to set up cgroup limits in the cgroup for this limit set.
+        // Children spawned with the same limits share it; a different limit
+        // set (e.g. a task vs. an LSP server) gets its own budget.
         if let Some(ref cgroup_base) = cgroup_path {
-            let pid = std::process::id();
-            let cgroup_name = format!("editor-lsp-{}", pid);
-            let cgroup_full = cgroup_base.join(&cgroup_name);
-
-            // create_dir returns AlreadyExists if another spawn already set it
-            // up in this process; that's fine — we'll reuse it.
-            let cgroup_usable = match fs::create_dir(&cgroup_full) {
-                Ok(()) => {
-                    // Freshly created — set limits on it.
-                    if let Some(memory_bytes) = max_memory_bytes {
-                        if set_cgroup_memory(&cgroup_full, memory_bytes).is_ok() {
-                            memory_method = "cgroup";
-                            tracing::debug!(
-                                "Set memory limit via cgroup: {} MB ({}% of system)",
-                                memory_bytes / 1024 / 1024,
-                                self.max_memory_percent.unwrap_or(0)
-                            );
-                        }
-                    }
-                    if let Some(cpu_pct) = self.max_cpu_percent {
-                        if set_cgroup_cpu(&cgroup_full, cpu_pct).is_ok() {
-                            cpu_method = "cgroup";
-                            tracing::debug!("Set CPU limit via cgroup: {}%", cpu_pct);
-                        }
-                    }
-                    // If no limit took effect, drop the empty cgroup.
-                    if memory_method != "cgroup" && cpu_method != "cgroup" {
-                        #[allow(clippy::let_underscore_must_use)]
-                        let _ = fs::remove_dir(&cgroup_full);
-                        false
-                    } else {
-                        true
-                    }
+            if let Some((cgroup_dir, memory_set, cpu_set)) = self.setup_limit_cgroup(cgroup_base) {
+                if memory_set {
+                    memory_method = "cgroup";
+                    tracing::debug!(
+                        "Set memory limit via cgroup: {} MB ({}% of system)",
+                        max_memory_bytes.unwrap_or(0) / 1024 / 1024,
+                        self.max_memory_percent.unwrap_or(0)
+                    );
                 }
-                Err(e) if e.kind() == io::ErrorKind::AlreadyExists => {
-                    // Already set up by a previous spawn in this process.
-                    // Assume the limits were applied then; reuse the cgroup.
-                    if max_memory_bytes.is_some() {
-                        memory_method = "cgroup";
-                    }
-                    if self.max_cpu_percent.is_some() {
-                        cpu_method = "cgroup";
-                    }
-                    true
+                if cpu_set {
+                    cpu_method = "cgroup";
+                    tracing::debug!(
+                        "Set CPU limit via cgroup: {}%",
+                        self.max_cpu_percent.unwrap_or(0)
+                    );
                 }
-                Err(_) => false,
-            };
-
-            if cgroup_usable {
-                action.cgroup_dir = Some(cgroup_full);
-                tracing::info!(
-                    "Using resource limits: memory={} ({}), CPU={} ({})",
-                    self.max_memory_percent
-                        .map(|p| format!("{}%", p))
-                        .unwrap_or("unlimited".to_string()),
-                    memory_method,
-                    self.max_cpu_percent
-                        .map(|c| format!("{}%", c))
-                        .unwrap_or("unlimited".to_string()),
-                    cpu_method
-                );
-                return Ok(action);
+                action.cgroup_dir = Some(cgroup_dir);
             }
         }
 
@@ -199,26 +304,38 @@ impl ProcessLimits {
             memory_method = "setrlimit";
         }
 
-        tracing::info!(
-            "Using resource limits: memory={} ({}), CPU={} ({})",
-            self.max_memory_percent
-                .map(|p| format!("{}%", p))
-                .unwrap_or("unlimited".to_string()),
+        self.log_limits(
             memory_method,
-            self.max_cpu_percent
-                .map(|c| format!("{}%", c))
-                .unwrap_or("unlimited".to_string()),
             if cpu_method == "none" {
                 "unavailable"
             } else {
                 cpu_method
-            }
+            },
         );
 
         Ok(action)
     }
 }
 
+/// Spawn `cmd` with `limits` applied.
+///
+/// On Linux the child is placed in a memory/CPU-limited cgroup when one can
+/// be set up, and otherwise gets its memory capped with `setrlimit` before
+/// exec. On macOS only the memory cap applies; on Windows the child joins a
+/// Job Object carrying both limits. Every subprocess that should honour
+/// `ProcessLimits` is created through here.
+pub fn spawn_limited(
+    mut cmd: tokio::process::Command,
+    limits: &ProcessLimits,
+) -> io::Result<tokio::process::Child> {
+    let post_spawn = limits.apply_to_command(&mut cmd)?;
+    let child = cmd.spawn()?;
+    if let Some(pid) = child.id() {
+        post_spawn.apply_to_child(pid);
+    }
+    Ok(child)
+}
+
 /// Find a writable user-delegated cgroup
 #[cfg(target_os = "linux")]
 fn find_user_cgroup() -> Option<PathBuf> {
@@ -312,9 +429,18 @@ impl SystemResources {
             Self::linux_total_memory_mb()
         }
 
-        #[cfg(not(target_os = "linux"))]
+        #[cfg(target_os = "macos")]
+        {
+            Self::macos_total_memory_mb()
+        }
+
+        #[cfg(windows)]
+        {
+            Self::windows_total_memory_mb()
+        }
+
+        #[cfg(not(any(target_os = "linux", target_os = "macos", windows)))]
         {
-            // TODO: Implement for other platforms
             Err(io::Error::new(
                 io::ErrorKind::Unsupported,
                 "Memory detection not implemented for this platform",
@@ -322,6 +448,37 @@ impl SystemResources {
         }
     }
 
+    #[cfg(target_os = "macos")]
+    fn macos_total_memory_mb() -> io::Result<u64> {
+        let mut bytes: u64 = 0;
+        let mut len = std::mem::size_of::<u64>();
+        let ret = unsafe {
+            libc::sysctlbyname(
+                b"hw.memsize\0".as_ptr() as *const libc::c_char,
+                &mut bytes as *mut u64 as *mut libc::c_void,
+                &mut len,
+                std::ptr::null_mut(),
+                0,
+            )
+        };
+        if ret != 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(bytes / 1024 / 1024)
+    }
+
+    #[cfg(windows)]
+    fn windows_total_memory_mb() -> io::Result<u64> {
+        use windows_sys::Win32::System::SystemInformation::{GlobalMemoryStatusEx, MEMORYSTATUSEX};
+
+        let mut status: MEMORYSTATUSEX = unsafe { std::mem::zeroed() };
+        status.dwLength = std::mem::size_of::<MEMORYSTATUSEX>() as u32;
+        if unsafe { GlobalMemoryStatusEx(&mut status) } == 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(status.ullTotalPhys / 1024 / 1024)
+    }
+
     #[cfg(target_os = "linux")]
     fn linux_total_memory_mb() -> io::Result<u64> {
         // Read from /proc/meminfo
@@ -347,37 +504,129 @@ impl SystemResources {
 
     /// Get total number of CPU cores
     pub fn cpu_count() -> io::Result<usize> {
-        #[cfg(target_os = "linux")]
-        {
-            Ok(num_cpus())
-        }
-
-        #[cfg(not(target_os = "linux"))]
-        {
-            // TODO: Implement for other platforms
-            Err(io::Error::new(
-                io::ErrorKind::Unsupported,
-                "CPU detection not implemented for this platform",
-            ))
-        }
+        Ok(num_cpus())
     }
 }
 
-/// Apply memory limit via setrlimit (fallback method)
-#[cfg(target_os = "linux")]
+/// Apply memory limit via setrlimit (fallback method on Linux, the only
+/// method on other Unix platforms)
+///
+/// Called from `pre_exec`, so it must stay async-signal-safe: no allocation,
+/// and errors are returned without formatting.
+#[cfg(unix)]
 fn apply_memory_limit_setrlimit(bytes: u64) -> io::Result<()> {
-    use nix::sys::resource::{setrlimit, Resource};
-
     // Set RLIMIT_AS (address space / virtual memory limit)
-    // On 32-bit platforms, rlim_t is u32, so we need to convert carefully.
-    // If bytes exceeds what rlim_t can represent, clamp to rlim_t::MAX.
-    let limit = bytes as nix::libc::rlim_t;
-    setrlimit(Resource::RLIMIT_AS, limit, limit)
-        .map_err(|e| io::Error::other(format!("setrlimit AS failed: {}", e)))
+    // On 32-bit platforms, rlim_t is u32, so clamp to what it can represent.
+    let limit = libc::rlim_t::try_from(bytes).unwrap_or(libc::rlim_t::MAX);
+    let rlim = libc::rlimit {
+        rlim_cur: limit,
+        rlim_max: limit,
+    };
+    if unsafe { libc::setrlimit(libc::RLIMIT_AS, &rlim) } != 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(())
 }
 
-/// Get the number of CPU cores (Linux)
-#[cfg(target_os = "linux")]
+/// Convert a CPU percentage relative to one core into a Job Object CPU rate,
+/// which is in hundredths of a percent of all cores combined.
+#[cfg(windows)]
+fn job_cpu_rate(percent_of_one_core: u32, cpus: usize) -> u32 {
+    let rate = percent_of_one_core as u64 * 100 / cpus.max(1) as u64;
+    rate.clamp(1, 10_000) as u32
+}
+
+/// Owned Job Object handle, closed on drop.
+///
+/// Closing the handle does not end the job: it stays alive (and keeps
+/// limiting its processes) until the last process in it exits.
+#[cfg(windows)]
+struct JobHandle(windows_sys::Win32::Foundation::HANDLE);
+
+#[cfg(windows)]
+impl Drop for JobHandle {
+    fn drop(&mut self) {
+        unsafe { windows_sys::Win32::Foundation::CloseHandle(self.0) };
+    }
+}
+
+/// Create a Job Object with `limits` and assign the process `pid` to it.
+#[cfg(windows)]
+fn assign_to_limited_job(pid: u32, limits: JobLimits) -> io::Result<JobHandle> {
+    use windows_sys::Win32::Foundation::CloseHandle;
+    use windows_sys::Win32::System::JobObjects::{
+        AssignProcessToJobObject, CreateJobObjectW, JobObjectCpuRateControlInformation,
+        JobObjectExtendedLimitInformation, JOBOBJECT_CPU_RATE_CONTROL_INFORMATION,
+        JOBOBJECT_EXTENDED_LIMIT_INFORMATION, JOB_OBJECT_CPU_RATE_CONTROL_ENABLE,
+        JOB_OBJECT_CPU_RATE_CONTROL_HARD_CAP, JOB_OBJECT_LIMIT_PROCESS_MEMORY,
+    };
+    use windows_sys::Win32::System::Threading::{
+        OpenProcess, PROCESS_SET_QUOTA, PROCESS_TERMINATE,
+    };
+
+    let job = unsafe { CreateJobObjectW(std::ptr::null(), std::ptr::null()) };
+    if job.is_null() {
+        return Err(io::Error::last_os_error());
+    }
+    let job = JobHandle(job);
+
+    if let Some(bytes) = limits.memory_bytes {
+        let mut info: JOBOBJECT_EXTENDED_LIMIT_INFORMATION = unsafe { std::mem::zeroed() };
+        info.BasicLimitInformation.LimitFlags = JOB_OBJECT_LIMIT_PROCESS_MEMORY;
+        info.ProcessMemoryLimit = usize::try_from(bytes).unwrap_or(usize::MAX);
+        set_job_information(&job, JobObjectExtendedLimitInformation, &info)?;
+    }
+
+    // CPU rate control needs Windows 8+; older systems reject it, in which
+    // case only the memory limit applies.
+    if let Some(rate) = limits.cpu_rate {
+        let mut info: JOBOBJECT_CPU_RATE_CONTROL_INFORMATION = unsafe { std::mem::zeroed() };
+        info.ControlFlags =
+            JOB_OBJECT_CPU_RATE_CONTROL_ENABLE | JOB_OBJECT_CPU_RATE_CONTROL_HARD_CAP;
+        info.Anonymous.CpuRate = rate;
+        if let Err(e) = set_job_information(&job, JobObjectCpuRateControlInformation, &info) {
+            tracing::debug!("Job object CPU rate limit unavailable: {}", e);
+        }
+    }
+
+    let process = unsafe { OpenProcess(PROCESS_SET_QUOTA | PROCESS_TERMINATE, 0, pid) };
+    if process.is_null() {
+        return Err(io::Error::last_os_error());
+    }
+    let assigned = unsafe { AssignProcessToJobObject(job.0, process) };
+    let result = if assigned == 0 {
+        Err(io::Error::last_os_error())
+    } else {
+        Ok(job)
+    };
+    unsafe { CloseHandle(process) };
+    result
+}
+
+/// Set one information class on a Job Object.
+#[cfg(windows)]
+fn set_job_information<T>(
+    job: &JobHandle,
+    class: windows_sys::Win32::System::JobObjects::JOBOBJECTINFOCLASS,
+    info: &T,
+) -> io::Result<()> {
+    use windows_sys::Win32::System::JobObjects::SetInformationJobObject;
+
+    let ok = unsafe {
+        SetInformationJobObject(
+            job.0,
+            class,
+            info as *const T as *const std::ffi::c_void,
+            std::mem::size_of::<T>() as u32,
+        )
+    };
+    if ok == 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+/// Get the number of CPU cores
 fn num_cpus() -> usize {
     std::thread::available_parallelism()
         .map(|n| n.get())
@@ -428,7 +677,7 @@ mod tests {
     }
 
     #[test]
-    #[cfg(target_os = "linux")]
+    #[cfg(any(target_os = "linux", target_os = "macos", windows))]
     fn test_system_resources_memory() {
         let mem_mb = SystemResources::total_memory_mb();
         assert!(mem_mb.is_ok());
@@ -440,7 +689,6 @@ mod tests {
     }
 
     #[test]
-    #[cfg(target_os = "linux")]
     fn test_system_resources_cpu() {
         let cpu_count = SystemResources::cpu_count();
         assert!(cpu_count.is_ok());
@@ -526,6 +774,182 @@ mod tests {
         assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "test");
     }
 
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_each_limit_set_gets_its_own_cgroup() {
+        // A plain directory stands in for the delegated cgroup: the limit
+        // files are then ordinary files we can read back.
+        let base = tempfile::tempdir().unwrap();
+        let lsp = ProcessLimits {
+            max_memory_percent: Some(50),
+            max_cpu_percent: Some(90),
+            enabled: true,
+        };
+        let task = ProcessLimits {
+            max_memory_percent: Some(10),
+            max_cpu_percent: None,
+            enabled: true,
+        };
+
+        let (lsp_dir, memory_set, cpu_set) = lsp.setup_limit_cgroup(base.path()).unwrap();
+        assert!(memory_set && cpu_set);
+        let (task_dir, memory_set, cpu_set) = task.setup_limit_cgroup(base.path()).unwrap();
+        assert!(memory_set && !cpu_set);
+        assert_ne!(lsp_dir, task_dir);
+
+        let read = |dir: &Path, file: &str| fs::read_to_string(dir.join(file)).unwrap();
+        assert_eq!(
+            read(&lsp_dir, "memory.max"),
+            lsp.memory_limit_bytes().unwrap().to_string()
+        );
+        assert_eq!(read(&lsp_dir, "cpu.max"), "90000 100000");
+        assert_eq!(
+            read(&task_dir, "memory.max"),
+            task.memory_limit_bytes().unwrap().to_string()
+        );
+        assert!(!task_dir.join("cpu.max").exists());
+
+        // Reusing a cgroup rewrites its limits instead of trusting them
+        fs::write(lsp_dir.join("memory.max"), "1").unwrap();
+        let (again, _, _) = lsp.setup_limit_cgroup(base.path()).unwrap();
+        assert_eq!(again, lsp_dir);
+        assert_eq!(
+            read(&lsp_dir, "memory.max"),
+            lsp.memory_limit_bytes().unwrap().to_string()
+        );
+    }
+
+    #[tokio::test]
+    #[cfg(target_os = "linux")]
+    async fn test_spawn_limited_caps_child_memory() {
+        // Enforced by this limit set's own cgroup when a user cgroup is
+        // delegated, otherwise by setrlimit.
+        // Smallest percentage that still leaves room (>= 256 MB) for the
+        // shell and coreutils to start under the address-space cap.
+        let total_mb = SystemResources::total_memory_mb().unwrap();
+        let percent = (256 * 100).div_ceil(total_mb).max(1) as u32;
+        let limits = ProcessLimits {
+            max_memory_percent: Some(percent),
+            max_cpu_percent: None,
+            enabled: true,
+        };
+        let limit_bytes = limits.memory_limit_bytes().unwrap();
+
+        // Within the limit, the child runs normally
+        let mut cmd = tokio::process::Command::new("sh");
+        cmd.args(["-c", "echo ok"]);
+        cmd.stdout(std::process::Stdio::piped());
+        let output = spawn_limited(cmd, &limits)
+            .unwrap()
+            .wait_with_output()
+            .await
+            .unwrap();
+        assert!(output.status.success());
+
+        // Holding twice the limit in a shell variable must fail
+        let script = format!(
+            "x=$(head -c {} /dev/zero | tr '\\0' a); echo survived",
+            limit_bytes * 2
+        );
+        let mut cmd = tokio::process::Command::new("sh");
+        cmd.args(["-c", &script]);
+        cmd.stdout(std::process::Stdio::piped());
+        cmd.stderr(std::process::Stdio::null());
+        let output = spawn_limited(cmd, &limits)
+            .unwrap()
+            .wait_with_output()
+            .await
+            .unwrap();
+        assert!(
+            !String::from_utf8_lossy(&output.stdout).contains("survived"),
+            "child exceeding the memory limit should have been stopped"
+        );
+    }
+
+    #[tokio::test]
+    #[cfg(target_os = "macos")]
+    async fn test_spawn_limited_sets_child_rlimit() {
+        let limits = ProcessLimits {
+            max_memory_percent: Some(50),
+            max_cpu_percent: Some(90),
+            enabled: true,
+        };
+        let limit_bytes = limits.memory_limit_bytes().unwrap();
+
+        // `ulimit -v` reports the child's RLIMIT_AS in KiB
+        let mut cmd = tokio::process::Command::new("sh");
+        cmd.args(["-c", "ulimit -v"]);
+        cmd.stdout(std::process::Stdio::piped());
+        let output = spawn_limited(cmd, &limits)
+            .unwrap()
+            .wait_with_output()
+            .await
+            .unwrap();
+        assert!(output.status.success());
+        assert_eq!(
+            String::from_utf8_lossy(&output.stdout).trim(),
+            (limit_bytes / 1024).to_string()
+        );
+    }
+
+    #[test]
+    #[cfg(windows)]
+    fn test_job_cpu_rate() {
+        // 100% of one core on a 4-core machine is a quarter of the system
+        assert_eq!(job_cpu_rate(100, 4), 2_500);
+        assert_eq!(job_cpu_rate(200, 1), 10_000);
+        assert_eq!(job_cpu_rate(0, 8), 1);
+    }
+
+    #[tokio::test]
+    #[cfg(windows)]
+    async fn test_job_object_limits_child() {
+        use windows_sys::Win32::System::JobObjects::{
+            IsProcessInJob, JobObjectExtendedLimitInformation, QueryInformationJobObject,
+            JOBOBJECT_EXTENDED_LIMIT_INFORMATION, JOB_OBJECT_LIMIT_PROCESS_MEMORY,
+        };
+
+        // Keep the child alive long enough to inspect it
+        let mut cmd = tokio::process::Command::new("cmd");
+        cmd.args(["/C", "ping -n 5 127.0.0.1 > NUL"]);
+        let mut child = cmd.spawn().unwrap();
+        let pid = child.id().unwrap();
+
+        let memory_bytes = 256 * 1024 * 1024;
+        let job = assign_to_limited_job(
+            pid,
+            JobLimits {
+                memory_bytes: Some(memory_bytes),
+                cpu_rate: Some(job_cpu_rate(50, SystemResources::cpu_count().unwrap())),
+            },
+        )
+        .unwrap();
+
+        let mut in_job = 0;
+        let process = child.raw_handle().unwrap();
+        assert_ne!(unsafe { IsProcessInJob(process, job.0, &mut in_job) }, 0);
+        assert_ne!(in_job, 0, "child should be in the limited job");
+
+        let mut info: JOBOBJECT_EXTENDED_LIMIT_INFORMATION = unsafe { std::mem::zeroed() };
+        let ok = unsafe {
+            QueryInformationJobObject(
+                job.0,
+                JobObjectExtendedLimitInformation,
+                &mut info as *mut _ as *mut std::ffi::c_void,
+                std::mem::size_of::<JOBOBJECT_EXTENDED_LIMIT_INFORMATION>() as u32,
+                std::ptr::null_mut(),
+            )
+        };
+        assert_ne!(ok, 0);
+        assert_ne!(
+            info.BasicLimitInformation.LimitFlags & JOB_OBJECT_LIMIT_PROCESS_MEMORY,
+            0
+        );
+        assert_eq!(info.ProcessMemoryLimit, memory_bytes as usize);
+
+        drop(child.kill().await);
+    }
+
     #[test]
     #[cfg(target_os = "linux")]
     fn test_user_cgroup_detection() {
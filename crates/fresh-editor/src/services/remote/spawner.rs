@@ -16,7 +16,7 @@
 //!   inside the container (via `docker exec -i`) instead of on the host.
 
 use crate::services::process_hidden::HideWindow;
-use crate::services::process_limits::PostSpawnAction;
+use crate::services::process_limits::spawn_limited;
 use crate::services::remote::channel::{AgentChannel, ChannelError};
 use crate::services::remote::protocol::{decode_base64, exec_params};
 use crate::types::ProcessLimits;
@@ -59,12 +59,29 @@ pub trait ProcessSpawner: Send + Sync {
         args: Vec<String>,
         cwd: Option<String>,
     ) -> Result<SpawnResult, SpawnError>;
+
+    /// A copy of this spawner that runs processes under `limits`, or
+    /// `None` when the spawner can't apply resource limits (remote and
+    /// container spawners run processes outside this host's control).
+    fn with_process_limits(&self, _limits: &ProcessLimits) -> Option<Arc<dyn ProcessSpawner>> {
+        None
+    }
 }
 
 /// Local process spawner using tokio
 ///
-/// Used for local file editing (the default).
-pub struct LocalProcessSpawner;
+/// Used for local file editing (the default). Processes run under
+/// `process_limits` (the default limits unless configured otherwise).
+#[derive(Default)]
+pub struct LocalProcessSpawner {
+    process_limits: ProcessLimits,
+}
+
+impl LocalProcessSpawner {
+    pub fn new(process_limits: ProcessLimits) -> Self {
+        Self { process_limits }
+    }
+}
 
 #[async_trait::async_trait]
 impl ProcessSpawner for LocalProcessSpawner {
@@ -75,15 +92,19 @@ impl ProcessSpawner for LocalProcessSpawner {
         cwd: Option<String>,
     ) -> Result<SpawnResult, SpawnError> {
         let mut cmd = tokio::process::Command::new(&command);
-        cmd.args(&args);
-        cmd.hide_window();
+        cmd.args(&args)
+            .stdin(std::process::Stdio::null())
+            .stdout(std::process::Stdio::piped())
+            .stderr(std::process::Stdio::piped())
+            .hide_window();
 
         if let Some(ref dir) = cwd {
             cmd.current_dir(dir);
         }
 
-        let output = cmd
-            .output()
+        let output = spawn_limited(cmd, &self.process_limits)
+            .map_err(|e| SpawnError::Process(e.to_string()))?
+            .wait_with_output()
             .await
             .map_err(|e| SpawnError::Process(e.to_string()))?;
 
@@ -93,6 +114,10 @@ impl ProcessSpawner for LocalProcessSpawner {
             exit_code: output.status.code().unwrap_or(-1),
         })
     }
+
+    fn with_process_limits(&self, limits: &ProcessLimits) -> Option<Arc<dyn ProcessSpawner>> {
+        Some(Arc::new(Self::new(limits.clone())))
+    }
 }
 
 /// Remote process spawner via SSH agent
@@ -189,12 +214,8 @@ impl StdioChild {
     /// `tokio::process::Child`. Pulls the piped streams out of the
     /// child so callers can take them individually later.
     ///
-    /// This constructor is for spawners that don't participate in
-    /// host-side resource limiting (the Docker variant is the
-    /// canonical example). Local spawners should prefer
-    /// [`Self::from_local_tokio_child`] so a `PostSpawnAction` produced
-    /// by [`ProcessLimits::apply_to_command`] is applied to the child's
-    /// PID before the spawner returns.
+    /// Local spawners create the child with [`spawn_limited`] first so
+    /// host-side resource limits are already in place.
     pub fn from_tokio_child(mut child: tokio::process::Child, spawned_locally: bool) -> Self {
         let stdin = child.stdin.take();
         let stdout = child.stdout.take();
@@ -208,22 +229,6 @@ impl StdioChild {
         }
     }
 
-    /// Construct a `StdioChild` for a locally-spawned child while
-    /// applying any host-side `PostSpawnAction` (cgroup attachment)
-    /// returned by [`ProcessLimits::apply_to_command`]. Best-effort:
-    /// failure to attach logs a warning but doesn't fail the spawn,
-    /// matching the pre-refactor behavior.
-    pub fn from_local_tokio_child(
-        child: tokio::process::Child,
-        post_spawn: PostSpawnAction,
-    ) -> Self {
-        let out = Self::from_tokio_child(child, true);
-        if let Some(pid) = out.inner.id() {
-            post_spawn.apply_to_child(pid);
-        }
-        out
-    }
-
     /// Take the stdin stream. Returns `None` after the first call.
     pub fn take_stdin(&mut self) -> Option<ChildStdin> {
         self.stdin.take()
@@ -311,8 +316,7 @@ pub trait LongRunningSpawner: Send + Sync {
 /// Functionally equivalent to how `LspHandle::spawn` works today, but
 /// exposed through the trait so non-local authorities can substitute
 /// their own implementation without any LSP-side awareness. Applies
-/// any `ProcessLimits` passed in via the same machinery the
-/// pre-refactor LSP code used (`apply_to_command` + `apply_to_child`).
+/// any `ProcessLimits` passed in via [`spawn_limited`].
 pub struct LocalLongRunningSpawner;
 
 #[async_trait::async_trait]
@@ -337,21 +341,11 @@ impl LongRunningSpawner for LocalLongRunningSpawner {
             cmd.current_dir(dir);
         }
 
-        // Apply pre-spawn hooks (cgroup path selection, setrlimit
-        // via `pre_exec`). Errors bubble up so callers see
-        // configuration problems early — matches the pre-refactor
-        // LSP behavior.
-        let post_spawn = match limits {
-            Some(lim) => lim
-                .apply_to_command(&mut cmd)
-                .map_err(|e| SpawnError::Process(format!("Failed to apply process limits: {e}")))?,
-            None => PostSpawnAction::default(),
-        };
-
-        let child = cmd
-            .spawn()
+        // Errors bubble up so callers see configuration problems early.
+        let unlimited = ProcessLimits::unlimited();
+        let child = spawn_limited(cmd, limits.unwrap_or(&unlimited))
             .map_err(|e| SpawnError::Process(e.to_string()))?;
-        Ok(StdioChild::from_local_tokio_child(child, post_spawn))
+        Ok(StdioChild::from_tokio_child(child, true))
     }
 
     async fn command_exists(&self, command: &str) -> bool {
@@ -366,7 +360,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_local_spawner() {
-        let spawner = LocalProcessSpawner;
+        let spawner = LocalProcessSpawner::default();
         let result = spawner
             .spawn("echo".to_string(), vec!["hello".to_string()], None)
             .await
@@ -376,6 +370,23 @@ mod tests {
         assert!(result.stdout.trim() == "hello");
     }
 
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_local_spawner_gives_children_empty_stdin() {
+        // `cat` would wait on an inherited terminal; with a null stdin it
+        // sees EOF and exits at once
+        let spawner = LocalProcessSpawner::default()
+            .with_process_limits(&ProcessLimits::unlimited())
+            .expect("local spawner accepts limits");
+        let result = spawner
+            .spawn("cat".to_string(), vec![], None)
+            .await
+            .unwrap();
+
+        assert_eq!(result.exit_code, 0);
+        assert_eq!(result.stdout, "");
+    }
+
     #[tokio::test]
     async fn local_long_running_spawn_stdio_pipes_output() {
         let spawner = LocalLongRunningSpawner;
@@ -55,6 +55,9 @@ pub struct TerminalHandle {
     rows: u16,
     /// Working directory used for the terminal
     cwd: Option<std::path::PathBuf>,
+    /// Process id of a locally spawned shell, used to look up its current
+    /// directory. `None` when a wrapper (container, remote) owns the cwd.
+    pid: Option<u32>,
     /// Shell executable used to spawn the terminal
     shell: String,
 }
@@ -99,8 +102,25 @@ impl TerminalHandle {
         (self.cols, self.rows)
     }
 
-    /// Get the working directory configured for the terminal
+    /// Get the terminal's current working directory: the one the shell
+    /// last reported via OSC 7, else the local shell process's directory
+    /// where the platform exposes it, else the directory it started in.
     pub fn cwd(&self) -> Option<std::path::PathBuf> {
+        let reported = self
+            .state
+            .lock()
+            .ok()
+            .and_then(|state| state.reported_cwd().map(|dir| dir.to_path_buf()));
+        if reported.is_some() {
+            return reported;
+        }
+        #[cfg(target_os = "linux")]
+        if let Some(dir) = self
+            .pid
+            .and_then(|pid| std::fs::read_link(format!("/proc/{}/cwd", pid)).ok())
+        {
+            return Some(dir);
+        }
         self.cwd.clone()
     }
 
@@ -237,6 +257,7 @@ impl TerminalManager {
                 .map_err(|e| format!("Failed to spawn shell '{}': {}", shell, e))?;
 
             tracing::debug!("Shell process spawned successfully");
+            let pid = if skip_cwd { None } else { child.process_id() };
 
             // Create terminal state
             let state = Arc::new(Mutex::new(TerminalState::new(cols, rows)));
@@ -479,6 +500,7 @@ impl TerminalManager {
                 cols,
                 rows,
                 cwd: cwd.clone(),
+                pid,
                 shell,
             })
         })();
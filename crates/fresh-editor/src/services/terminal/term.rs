@@ -34,11 +34,17 @@ use alacritty_terminal::term::test::TermSize;
 use alacritty_terminal::term::{Config as TermConfig, Term, TermMode};
 use alacritty_terminal::vte::ansi::Processor;
 use std::io::{self, Write};
+use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
 
 // Keep a generous scrollback so sync-to-buffer can include deep history.
 const SCROLLBACK_LINES: usize = 200_000;
 
+/// Start of the OSC 7 sequence shells use to report their working directory
+const OSC7_PREFIX: &[u8] = b"\x1b]7;";
+/// Longest unterminated OSC 7 sequence carried over to the next read
+const OSC7_MAX_LEN: usize = 4096;
+
 /// Event listener that captures PtyWrite events for sending back to the PTY.
 ///
 /// When the terminal emulator needs to respond to queries (like DSR cursor position
@@ -88,6 +94,10 @@ pub struct TerminalState {
     backing_file_history_end: u64,
     /// Queue of data to write back to the PTY (for DSR responses, etc.)
     pty_write_queue: Arc<Mutex<Vec<String>>>,
+    /// Working directory last reported by the shell via OSC 7
+    reported_cwd: Option<PathBuf>,
+    /// Tail of the previous read holding an incomplete OSC 7 sequence
+    osc7_pending: Vec<u8>,
 }
 
 impl TerminalState {
@@ -112,6 +122,8 @@ impl TerminalState {
             synced_history_lines: 0,
             backing_file_history_end: 0,
             pty_write_queue,
+            reported_cwd: None,
+            osc7_pending: Vec::new(),
         }
     }
 
@@ -129,10 +141,58 @@ impl TerminalState {
 
     /// Process output from the PTY
     pub fn process_output(&mut self, data: &[u8]) {
+        self.scan_osc7(data);
         self.parser.advance(&mut self.term, data);
         self.dirty = true;
     }
 
+    /// Record the working directory from any OSC 7 sequence in `data`.
+    /// The emulator ignores OSC 7, so it is picked out of the raw bytes;
+    /// a sequence split across reads is completed on the next one.
+    fn scan_osc7(&mut self, data: &[u8]) {
+        let joined;
+        let bytes = if self.osc7_pending.is_empty() {
+            data
+        } else {
+            let mut pending = std::mem::take(&mut self.osc7_pending);
+            pending.extend_from_slice(data);
+            joined = pending;
+            &joined[..]
+        };
+
+        let mut rest = bytes;
+        while let Some(start) = rest
+            .windows(OSC7_PREFIX.len())
+            .position(|window| window == OSC7_PREFIX)
+        {
+            let body = &rest[start + OSC7_PREFIX.len()..];
+            // Terminated by BEL or ST (`ESC \`)
+            let Some(end) = body.iter().position(|&b| b == 0x07 || b == 0x1b) else {
+                if rest.len() - start <= OSC7_MAX_LEN {
+                    self.osc7_pending = rest[start..].to_vec();
+                }
+                return;
+            };
+            if let Some(dir) = parse_osc7_url(&body[..end]) {
+                self.reported_cwd = Some(dir);
+            }
+            rest = &body[end..];
+        }
+
+        // Keep a trailing partial prefix so the next read can complete it
+        for keep in (1..OSC7_PREFIX.len()).rev() {
+            if rest.ends_with(&OSC7_PREFIX[..keep]) {
+                self.osc7_pending = rest[rest.len() - keep..].to_vec();
+                break;
+            }
+        }
+    }
+
+    /// Working directory the shell last reported via OSC 7, if any
+    pub fn reported_cwd(&self) -> Option<&Path> {
+        self.reported_cwd.as_deref()
+    }
+
     /// Resize the terminal
     pub fn resize(&mut self, cols: u16, rows: u16) {
         if cols != self.cols || rows != self.rows {
@@ -595,6 +655,33 @@ impl Default for TerminalCell {
     }
 }
 
+/// Parse an OSC 7 payload (`file://host/path`, percent-encoded) into a path.
+fn parse_osc7_url(payload: &[u8]) -> Option<PathBuf> {
+    let url = std::str::from_utf8(payload).ok()?;
+    let after_scheme = url.strip_prefix("file://")?;
+    let path = &after_scheme[after_scheme.find('/')?..];
+
+    let mut decoded = Vec::with_capacity(path.len());
+    let mut bytes = path.bytes();
+    while let Some(b) = bytes.next() {
+        if b == b'%' {
+            let hex = [bytes.next()?, bytes.next()?];
+            let value = u8::from_str_radix(std::str::from_utf8(&hex).ok()?, 16).ok()?;
+            decoded.push(value);
+        } else {
+            decoded.push(b);
+        }
+    }
+    let path = String::from_utf8(decoded).ok()?;
+    // `file:///C:/dir` names `C:/dir` on Windows
+    #[cfg(windows)]
+    let path = match path.strip_prefix('/') {
+        Some(rest) if rest.as_bytes().get(1) == Some(&b':') => rest.to_string(),
+        _ => path,
+    };
+    Some(PathBuf::from(path))
+}
+
 /// Convert alacritty color to RGB
 fn color_to_rgb(color: &alacritty_terminal::vte::ansi::Color) -> Option<(u8, u8, u8)> {
     use alacritty_terminal::vte::ansi::Color;
@@ -688,6 +775,22 @@ mod tests {
         assert!(content.contains("Hello, World!"));
     }
 
+    #[test]
+    fn test_osc7_reports_cwd() {
+        let mut state = TerminalState::new(80, 24);
+        assert_eq!(state.reported_cwd(), None);
+
+        state.process_output(b"prompt\x1b]7;file://host/home/me/my%20dir\x07$ ");
+        assert_eq!(state.reported_cwd(), Some(Path::new("/home/me/my dir")));
+
+        // A sequence split across reads, terminated by ST
+        state.process_output(b"\x1b]");
+        state.process_output(b"7;file:///tmp/pro");
+        state.process_output(b"ject\x1b\\");
+        assert_eq!(state.reported_cwd(), Some(Path::new("/tmp/project")));
+        assert!(!state.content_string().contains("project"));
+    }
+
     #[test]
     fn test_terminal_resize() {
         let mut state = TerminalState::new(80, 24);
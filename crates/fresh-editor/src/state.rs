@@ -9,6 +9,7 @@ use crate::model::event::{
 };
 use crate::model::filesystem::FileSystem;
 use crate::model::marker::{MarkerId, MarkerList};
+use crate::model::selection_stats::SelectionStatsCache;
 use crate::primitives::detected_language::DetectedLanguage;
 use crate::primitives::grammar::GrammarRegistry;
 use crate::primitives::highlight_engine::HighlightEngine;
@@ -103,6 +104,16 @@ pub struct BufferSettings {
     /// Set based on language config; can be changed per-buffer by user
     pub tab_size: usize,
 
+    /// Columns per indent level when it differs from `tab_size`, e.g. a
+    /// two-space indent detected in a file. `None` indents by `tab_size`.
+    /// Tab characters are still displayed `tab_size` columns wide.
+    pub indent_width: Option<usize>,
+
+    /// Whether this buffer's language allows smart indentation (indent on
+    /// Enter, dedent on a typed closing delimiter). Set from language config;
+    /// the global `editor.auto_indent` flag still gates it at action time.
+    pub auto_indent: bool,
+
     /// Whether to auto-close brackets, parentheses, and quotes.
     /// Set based on global + language config.
     pub auto_close: bool,
@@ -111,19 +122,44 @@ pub struct BufferSettings {
     /// Set based on global + language config.
     pub auto_surround: bool,
 
+    /// Whether Backspace inside leading whitespace deletes back to the
+    /// previous tab stop instead of a single space. Set from global config.
+    pub backspace_unindent: bool,
+
+    /// Whether to draw indent guides on leading whitespace. Set from
+    /// global config.
+    pub indent_guides: bool,
+
+    /// Whether every cursor's line gets the current-line highlight, not
+    /// only the primary cursor's. Set from global config.
+    pub highlight_all_cursor_lines: bool,
+
     /// Extra characters (beyond alphanumeric + `_`) considered part of
     /// identifiers for this language. Used by completion providers.
     pub word_characters: String,
 }
 
+impl BufferSettings {
+    /// Columns per indent level: the detected indent width if any,
+    /// otherwise `tab_size`.
+    pub fn indent_size(&self) -> usize {
+        self.indent_width.unwrap_or(self.tab_size)
+    }
+}
+
 impl Default for BufferSettings {
     fn default() -> Self {
         Self {
             whitespace: crate::config::WhitespaceVisibility::default(),
             use_tabs: false,
             tab_size: 4,
+            indent_width: None,
+            auto_indent: true,
             auto_close: true,
             auto_surround: true,
+            backspace_unindent: true,
+            indent_guides: false,
+            highlight_all_cursor_lines: false,
             word_characters: String::new(),
         }
     }
@@ -209,6 +245,9 @@ pub struct EditorState {
     /// Bracket matching highlight overlay
     pub bracket_highlight_overlay: BracketHighlightOverlay,
 
+    /// Cached character/line/word counts of the selection, for the status bar
+    pub selection_stats: SelectionStatsCache,
+
     /// Cached LSP semantic tokens (converted to buffer byte ranges)
     pub semantic_tokens: Option<SemanticTokenStore>,
 
@@ -293,6 +332,7 @@ impl EditorState {
             debug_highlight_mode: false,
             reference_highlight_overlay: ReferenceHighlightOverlay::new(),
             bracket_highlight_overlay: BracketHighlightOverlay::new(),
+            selection_stats: SelectionStatsCache::default(),
             semantic_tokens: None,
             folding_ranges: LspFoldRanges::new(),
             language: "text".to_string(),
@@ -546,18 +586,8 @@ impl EditorState {
                 }
 
                 // Update primary cursor line number if this is the primary cursor
-                // Try to get exact line number from buffer, or estimate for large files
                 if *cursor_id == cursors.primary_id() {
-                    self.primary_cursor_line_number =
-                        match self.buffer.offset_to_position(*new_position) {
-                            Some(pos) => LineNumber::Absolute(pos.line),
-                            None => {
-                                // Large file without line metadata - estimate line number
-                                // Use default estimated_line_length of 80 bytes
-                                let estimated_line = *new_position / 80;
-                                LineNumber::Absolute(estimated_line)
-                            }
-                        };
+                    self.set_primary_cursor_line_number(*new_position);
                 }
             }
 
@@ -577,10 +607,15 @@ impl EditorState {
                 cursors.insert_with_id(*cursor_id, cursor);
 
                 cursors.normalize();
+
+                // The new cursor becomes primary, so the gutter's relative
+                // line numbers must be measured from it.
+                self.set_primary_cursor_line_number(cursors.primary().position);
             }
 
             Event::RemoveCursor { cursor_id, .. } => {
                 cursors.remove(*cursor_id);
+                self.set_primary_cursor_line_number(cursors.primary().position);
             }
 
             // View events (Scroll, SetViewport, Recenter) are now handled at Editor level
@@ -862,6 +897,15 @@ impl EditorState {
         }
     }
 
+    /// Recompute `primary_cursor_line_number` for a primary cursor at `position`.
+    /// Large files without line metadata get an estimate based on 80-byte lines.
+    fn set_primary_cursor_line_number(&mut self, position: usize) {
+        self.primary_cursor_line_number = match self.buffer.offset_to_position(position) {
+            Some(pos) => LineNumber::Absolute(pos.line),
+            None => LineNumber::Absolute(position / 80),
+        };
+    }
+
     /// Capture positions of markers strictly inside a deleted range.
     /// Call this BEFORE applying the delete. Returns encoded displaced markers.
     pub fn capture_displaced_markers(&self, range: &Range<usize>) -> Vec<(u64, usize)> {
@@ -1081,7 +1125,9 @@ pub(crate) fn convert_popup_data_to_popup(data: &PopupData) -> Popup {
     // action) because the same `List` kind is used for all four, so we
     // can't infer which feature owns the popup from its kind alone.
     let resolver = match kind {
-        PopupKind::Completion => crate::view::popup::PopupResolver::Completion,
+        PopupKind::Completion => crate::view::popup::PopupResolver::Completion {
+            item_ids: Vec::new(),
+        },
         _ => crate::view::popup::PopupResolver::None,
     };
 
@@ -1131,6 +1177,7 @@ pub(crate) fn convert_popup_data_to_popup(data: &PopupData) -> Popup {
         resolver,
         focused,
         focus_key_hint: None,
+        preview: None,
     }
 }
 
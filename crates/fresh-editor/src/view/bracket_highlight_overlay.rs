@@ -1,8 +1,15 @@
 //! Bracket matching highlight using the overlay system
 //!
 //! This module manages bracket pair highlighting through overlays.
-//! When the cursor is on a bracket, the matching bracket is highlighted.
-//! Optional rainbow colors can be applied based on nesting depth.
+//! When the cursor is on a bracket (or just after one), the matching bracket
+//! is highlighted. Optional rainbow colors can be applied based on nesting
+//! depth.
+//!
+//! Matching is a bounded byte scan. Where syntax highlighting is available,
+//! brackets inside strings and comments are skipped, so `f(")")` still
+//! pairs the outer parentheses.
+
+use std::ops::Range;
 
 use crate::model::buffer::Buffer;
 use crate::model::marker::MarkerList;
@@ -59,6 +66,31 @@ fn get_bracket_pair(ch: char) -> Option<(char, char, bool)> {
     None
 }
 
+/// The bracket the cursor is on or, failing that, just after, with its pair
+/// info.
+fn bracket_at_cursor(
+    buffer: &Buffer,
+    cursor_position: usize,
+) -> Option<(usize, (char, char, bool))> {
+    let bracket_at = |pos: usize| {
+        let bytes = buffer.slice_bytes(pos..pos + 1);
+        bytes.first().and_then(|&b| get_bracket_pair(b as char))
+    };
+    if cursor_position < buffer.len() {
+        if let Some(pair) = bracket_at(cursor_position) {
+            return Some((cursor_position, pair));
+        }
+    }
+    let before = cursor_position.checked_sub(1)?;
+    bracket_at(before).map(|pair| (before, pair))
+}
+
+/// Whether `pos` lies in one of the sorted, non-overlapping `ranges`.
+fn in_ranges(ranges: &[Range<usize>], pos: usize) -> bool {
+    let i = ranges.partition_point(|r| r.end <= pos);
+    ranges.get(i).is_some_and(|r| r.start <= pos)
+}
+
 /// Manager for bracket highlight overlays
 pub struct BracketHighlightOverlay {
     /// Whether bracket highlighting is enabled
@@ -71,6 +103,10 @@ pub struct BracketHighlightOverlay {
     pub match_color: Color,
     /// Last cursor position where we computed brackets
     last_cursor_pos: Option<usize>,
+    /// String and comment ranges from the last highlight pass, sorted.
+    /// Brackets inside them are ignored unless the cursor's bracket is
+    /// itself inside one.
+    skipped_ranges: Vec<Range<usize>>,
 }
 
 impl BracketHighlightOverlay {
@@ -82,6 +118,47 @@ impl BracketHighlightOverlay {
             rainbow_colors: DEFAULT_BRACKET_COLORS.to_vec(),
             match_color: Color::Rgb(255, 215, 0), // Gold
             last_cursor_pos: None,
+            skipped_ranges: Vec::new(),
+        }
+    }
+
+    /// Apply the `highlight_matching_brackets` / `rainbow_brackets` settings.
+    pub fn configure(&mut self, enabled: bool, rainbow_enabled: bool) {
+        if self.enabled != enabled || self.rainbow_enabled != rainbow_enabled {
+            self.enabled = enabled;
+            self.rainbow_enabled = rainbow_enabled;
+            self.invalidate();
+        }
+    }
+
+    /// Set the string and comment ranges whose brackets don't count.
+    pub fn set_skipped_ranges(&mut self, mut ranges: Vec<Range<usize>>) {
+        ranges.sort_by_key(|r| r.start);
+        if ranges != self.skipped_ranges {
+            self.skipped_ranges = ranges;
+            self.invalidate();
+        }
+    }
+
+    /// Position of the bracket at (or just before) the cursor and of its
+    /// match, if one is found within the scan limit.
+    pub fn matching_pair(
+        &self,
+        buffer: &Buffer,
+        cursor_position: usize,
+    ) -> Option<(usize, Option<usize>)> {
+        let (pos, (opening, closing, forward)) = bracket_at_cursor(buffer, cursor_position)?;
+        let matching = self.find_matching_bracket(buffer, pos, opening, closing, forward);
+        Some((pos, matching))
+    }
+
+    /// Ranges to skip while scanning from the bracket at `position`: none
+    /// when that bracket is itself inside a string or comment.
+    fn skipped_for(&self, position: usize) -> &[Range<usize>] {
+        if in_ranges(&self.skipped_ranges, position) {
+            &[]
+        } else {
+            &self.skipped_ranges
         }
     }
 
@@ -95,7 +172,13 @@ impl BracketHighlightOverlay {
         marker_list: &mut MarkerList,
         cursor_position: usize,
     ) -> bool {
+        let ns = bracket_highlight_namespace();
         if !self.enabled {
+            // Drop highlights left from before the setting was turned off
+            if self.last_cursor_pos.take().is_some() {
+                overlays.clear_namespace(&ns, marker_list);
+                return true;
+            }
             return false;
         }
 
@@ -106,38 +189,25 @@ impl BracketHighlightOverlay {
         self.last_cursor_pos = Some(cursor_position);
 
         // Clear existing bracket overlays
-        let ns = bracket_highlight_namespace();
         overlays.clear_namespace(&ns, marker_list);
 
-        // Check if cursor is on a bracket
-        let buf_len = buffer.len();
-        if cursor_position >= buf_len {
+        // Check if cursor is on (or just after) a bracket
+        let Some((bracket_pos, (opening, closing, forward))) =
+            bracket_at_cursor(buffer, cursor_position)
+        else {
             return true;
-        }
-
-        let bytes = buffer.slice_bytes(cursor_position..cursor_position + 1);
-        if bytes.is_empty() {
-            return true;
-        }
-
-        let ch = bytes[0] as char;
-
-        // Get bracket pair info
-        let (opening, closing, forward) = match get_bracket_pair(ch) {
-            Some(pair) => pair,
-            None => return true, // Not on a bracket
         };
 
-        // Calculate nesting depth at cursor position for rainbow colors
+        // Calculate nesting depth at the bracket for rainbow colors
         let depth = if self.rainbow_enabled {
-            self.calculate_nesting_depth(buffer, cursor_position, opening, closing, forward)
+            self.calculate_nesting_depth(buffer, bracket_pos, opening, closing, forward)
         } else {
             0
         };
 
         // Find matching bracket
         let matching_pos =
-            self.find_matching_bracket(buffer, cursor_position, opening, closing, forward);
+            self.find_matching_bracket(buffer, bracket_pos, opening, closing, forward);
 
         // Determine color based on depth
         let color = if self.rainbow_enabled && !self.rainbow_colors.is_empty() {
@@ -150,7 +220,7 @@ impl BracketHighlightOverlay {
         let cursor_face = OverlayFace::Foreground { color };
         let cursor_overlay = Overlay::with_namespace(
             marker_list,
-            cursor_position..cursor_position + 1,
+            bracket_pos..bracket_pos + 1,
             cursor_face,
             ns.clone(),
         )
@@ -186,16 +256,20 @@ impl BracketHighlightOverlay {
         let scan_start = position.saturating_sub(MAX_BRACKET_SEARCH_BYTES);
         let open = opening as u8;
         let close = closing as u8;
+        let skipped = self.skipped_for(position);
         let mut depth: usize = 0;
         let mut pos = scan_start;
 
         while pos < position {
             let chunk_end = (pos + BRACKET_SCAN_CHUNK).min(position);
             let chunk = buffer.slice_bytes(pos..chunk_end);
-            for &b in &chunk {
+            for (i, &b) in chunk.iter().enumerate() {
+                if (b != open && b != close) || in_ranges(skipped, pos + i) {
+                    continue;
+                }
                 if b == open {
                     depth += 1;
-                } else if b == close {
+                } else {
                     depth = depth.saturating_sub(1);
                 }
             }
@@ -221,6 +295,7 @@ impl BracketHighlightOverlay {
         let buffer_len = buffer.len();
         let open = opening as u8;
         let close = closing as u8;
+        let skipped = self.skipped_for(position);
         let mut depth: i32 = 1;
 
         if forward {
@@ -230,9 +305,12 @@ impl BracketHighlightOverlay {
                 let chunk_end = (pos + BRACKET_SCAN_CHUNK).min(search_limit);
                 let chunk = buffer.slice_bytes(pos..chunk_end);
                 for (i, &b) in chunk.iter().enumerate() {
+                    if (b != open && b != close) || in_ranges(skipped, pos + i) {
+                        continue;
+                    }
                     if b == open {
                         depth += 1;
-                    } else if b == close {
+                    } else {
                         depth -= 1;
                         if depth == 0 {
                             return Some(pos + i);
@@ -248,9 +326,12 @@ impl BracketHighlightOverlay {
                 let chunk_start = pos.saturating_sub(BRACKET_SCAN_CHUNK).max(search_limit);
                 let chunk = buffer.slice_bytes(chunk_start..pos);
                 for (i, &b) in chunk.iter().enumerate().rev() {
+                    if (b != open && b != close) || in_ranges(skipped, chunk_start + i) {
+                        continue;
+                    }
                     if b == close {
                         depth += 1;
-                    } else if b == open {
+                    } else {
                         depth -= 1;
                         if depth == 0 {
                             return Some(chunk_start + i);
@@ -367,4 +448,32 @@ mod tests {
             2
         );
     }
+
+    #[test]
+    fn test_bracket_just_before_cursor_is_matched() {
+        let buffer = Buffer::from_str_test("(a) b");
+        let overlay = BracketHighlightOverlay::new();
+
+        // On the bracket
+        assert_eq!(overlay.matching_pair(&buffer, 0), Some((0, Some(2))));
+        // Just after the closing bracket, including at the end of the buffer
+        assert_eq!(overlay.matching_pair(&buffer, 3), Some((2, Some(0))));
+        assert_eq!(overlay.matching_pair(&buffer, 5), None);
+        let buffer = Buffer::from_str_test("(a)");
+        assert_eq!(overlay.matching_pair(&buffer, 3), Some((2, Some(0))));
+    }
+
+    #[test]
+    fn test_brackets_in_strings_are_skipped() {
+        // f(")") — the ')' at 3 is inside the string literal at 2..5
+        let buffer = Buffer::from_str_test("f(\")\")");
+        let mut overlay = BracketHighlightOverlay::new();
+        assert_eq!(overlay.matching_pair(&buffer, 1), Some((1, Some(3))));
+
+        overlay.set_skipped_ranges(vec![2..5]);
+        assert_eq!(overlay.matching_pair(&buffer, 1), Some((1, Some(5))));
+        assert_eq!(overlay.matching_pair(&buffer, 5), Some((5, Some(1))));
+        // A bracket inside the string itself falls back to a plain scan
+        assert_eq!(overlay.matching_pair(&buffer, 3), Some((3, Some(1))));
+    }
 }
@@ -13,10 +13,10 @@
 //!
 //! The Editor will automatically convert colors during rendering based on the capability.
 
-use ratatui::style::Color;
+use ratatui::style::{Color, Modifier};
 
 /// Terminal color capability levels
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ColorCapability {
     /// Full 24-bit RGB color support (16 million colors)
     TrueColor,
@@ -24,25 +24,41 @@ pub enum ColorCapability {
     Color256,
     /// Basic 16 color palette (standard ANSI colors)
     Color16,
+    /// No color at all (`NO_COLOR`); only text attributes are kept
+    Monochrome,
 }
 
 impl ColorCapability {
-    /// Detect the terminal's color capability
-    /// Can be overridden with FRESH_COLOR_MODE env var: "truecolor", "256", or "16"
+    /// Detect the terminal's color capability from the process environment
+    /// Can be overridden with FRESH_COLOR_MODE env var: "truecolor", "256", "16" or "none"
     pub fn detect() -> Self {
+        Self::from_env(|key| std::env::var(key).ok())
+    }
+
+    /// Detect a color capability from environment variables looked up with
+    /// `var`, so the same rules apply to a remote client's environment.
+    /// A non-empty `NO_COLOR` selects monochrome unless FRESH_COLOR_MODE
+    /// says otherwise.
+    pub fn from_env(var: impl Fn(&str) -> Option<String>) -> Self {
         // Check for manual override first
-        if let Ok(mode) = std::env::var("FRESH_COLOR_MODE") {
+        if let Some(mode) = var("FRESH_COLOR_MODE") {
             match mode.to_lowercase().as_str() {
                 "truecolor" | "24bit" | "true" => return ColorCapability::TrueColor,
                 "256" | "256color" => return ColorCapability::Color256,
                 "16" | "basic" | "ansi" => return ColorCapability::Color16,
+                "none" | "mono" | "monochrome" => return ColorCapability::Monochrome,
                 _ => {} // Fall through to auto-detection
             }
         }
 
+        // https://no-color.org: any non-empty value disables color
+        if var("NO_COLOR").is_some_and(|v| !v.is_empty()) {
+            return ColorCapability::Monochrome;
+        }
+
         // Check TERM first for multiplexers that don't support truecolor
         // (they may pass through COLORTERM from the outer terminal)
-        if let Ok(term) = std::env::var("TERM") {
+        if let Some(term) = var("TERM") {
             let t = term.to_lowercase();
 
             // GNU Screen doesn't support truecolor - cap at 256
@@ -56,7 +72,7 @@ impl ColorCapability {
                     return ColorCapability::TrueColor;
                 }
                 // Check COLORTERM - tmux can pass through truecolor if configured
-                if let Ok(colorterm) = std::env::var("COLORTERM") {
+                if let Some(colorterm) = var("COLORTERM") {
                     let ct = colorterm.to_lowercase();
                     if ct == "truecolor" || ct == "24bit" {
                         return ColorCapability::TrueColor;
@@ -67,7 +83,7 @@ impl ColorCapability {
         }
 
         // Check COLORTERM - reliable for truecolor (but not inside Screen/tmux)
-        if let Ok(colorterm) = std::env::var("COLORTERM") {
+        if let Some(colorterm) = var("COLORTERM") {
             let ct = colorterm.to_lowercase();
             if ct == "truecolor" || ct == "24bit" {
                 return ColorCapability::TrueColor;
@@ -75,12 +91,12 @@ impl ColorCapability {
         }
 
         // Windows Terminal sets WT_SESSION and supports truecolor
-        if std::env::var("WT_SESSION").is_ok() {
+        if var("WT_SESSION").is_some() {
             return ColorCapability::TrueColor;
         }
 
         // Check TERM for other indicators
-        if let Ok(term) = std::env::var("TERM") {
+        if let Some(term) = var("TERM") {
             let t = term.to_lowercase();
 
             // Check for truecolor indicators
@@ -283,6 +299,7 @@ pub fn convert_color(color: Color, capability: ColorCapability) -> Color {
             Color::Indexed(idx) => indexed_to_16(idx),
             _ => color, // Named colors are already 16-color compatible
         },
+        ColorCapability::Monochrome => Color::Reset,
     }
 }
 
@@ -496,6 +513,11 @@ pub fn convert_buffer_colors(buffer: &mut ratatui::buffer::Buffer, capability: C
         return;
     }
 
+    // Colors are about to be dropped; keep highlights visible as attributes
+    if capability == ColorCapability::Monochrome {
+        mark_monochrome_highlights(buffer);
+    }
+
     // Iterate through all cells and convert colors
     for cell in buffer.content.iter_mut() {
         cell.fg = convert_color(cell.fg, capability);
@@ -508,6 +530,44 @@ pub fn convert_buffer_colors(buffer: &mut ratatui::buffer::Buffer, capability: C
     }
 }
 
+/// Contrast ratio against the base background above which a highlight is
+/// shown in reverse video rather than bold in monochrome mode.
+const MONOCHROME_REVERSE_CONTRAST: f64 = 1.5;
+
+/// Pre-conversion pass for monochrome terminals: cells whose background
+/// differs from the most common background (selections, search matches,
+/// the status bar, menus) get REVERSED when the difference is strong and
+/// BOLD otherwise, so they stay distinguishable once colors are reset.
+fn mark_monochrome_highlights(buffer: &mut ratatui::buffer::Buffer) {
+    let mut counts: std::collections::HashMap<Color, usize> = std::collections::HashMap::new();
+    for cell in buffer.content.iter() {
+        *counts.entry(cell.bg).or_default() += 1;
+    }
+    let Some(base) = counts
+        .into_iter()
+        .max_by_key(|&(_, count)| count)
+        .map(|(color, _)| color)
+    else {
+        return;
+    };
+    let base_rgb = color_to_rgb(base);
+
+    for cell in buffer.content.iter_mut() {
+        if cell.bg == base || cell.bg == Color::Reset {
+            continue;
+        }
+        let strong = match (color_to_rgb(cell.bg), base_rgb) {
+            (Some(bg), Some(base)) => contrast_ratio(bg, base) >= MONOCHROME_REVERSE_CONTRAST,
+            _ => true,
+        };
+        if strong {
+            cell.modifier.insert(Modifier::REVERSED);
+        } else {
+            cell.modifier.insert(Modifier::BOLD);
+        }
+    }
+}
+
 /// Post-conversion pass: ensure every fg/bg pair in the buffer has sufficient
 /// WCAG contrast ratio. Adjusts fg colors when contrast is too low.
 fn enforce_minimum_contrast(buffer: &mut ratatui::buffer::Buffer) {
@@ -814,4 +874,84 @@ mod tests {
             buffer.content[0].bg
         );
     }
+
+    fn detect_with(vars: &[(&str, &str)]) -> ColorCapability {
+        ColorCapability::from_env(|key| {
+            vars.iter()
+                .find(|(k, _)| *k == key)
+                .map(|(_, v)| v.to_string())
+        })
+    }
+
+    #[test]
+    fn test_from_env_detects_client_capability() {
+        assert_eq!(
+            detect_with(&[("TERM", "xterm-256color"), ("COLORTERM", "truecolor")]),
+            ColorCapability::TrueColor
+        );
+        assert_eq!(
+            detect_with(&[("TERM", "xterm-256color")]),
+            ColorCapability::Color256
+        );
+        assert_eq!(detect_with(&[("TERM", "linux")]), ColorCapability::Color16);
+        assert_eq!(
+            detect_with(&[("TERM", "screen"), ("COLORTERM", "truecolor")]),
+            ColorCapability::Color256
+        );
+    }
+
+    #[test]
+    fn test_from_env_honors_no_color() {
+        assert_eq!(
+            detect_with(&[("TERM", "xterm-256color"), ("NO_COLOR", "1")]),
+            ColorCapability::Monochrome
+        );
+        // An empty NO_COLOR doesn't count
+        assert_eq!(
+            detect_with(&[("TERM", "xterm-256color"), ("NO_COLOR", "")]),
+            ColorCapability::Color256
+        );
+        // An explicit FRESH_COLOR_MODE wins
+        assert_eq!(
+            detect_with(&[("NO_COLOR", "1"), ("FRESH_COLOR_MODE", "16")]),
+            ColorCapability::Color16
+        );
+    }
+
+    #[test]
+    fn test_convert_color_monochrome() {
+        for color in [Color::Rgb(100, 150, 200), Color::Indexed(33), Color::Red] {
+            assert_eq!(
+                convert_color(color, ColorCapability::Monochrome),
+                Color::Reset
+            );
+        }
+    }
+
+    #[test]
+    fn test_monochrome_keeps_background_highlights_as_modifiers() {
+        use ratatui::buffer::Buffer;
+        use ratatui::layout::Rect;
+
+        let editor_bg = Color::Rgb(30, 30, 30);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+        for cell in buffer.content.iter_mut() {
+            cell.bg = editor_bg;
+        }
+        // Selection: far from the editor background
+        buffer.content[1].bg = Color::Rgb(200, 200, 200);
+        // Current-line style tint: close to the editor background
+        buffer.content[2].bg = Color::Rgb(40, 40, 40);
+
+        convert_buffer_colors(&mut buffer, ColorCapability::Monochrome);
+
+        assert!(buffer
+            .content
+            .iter()
+            .all(|c| c.bg == Color::Reset && c.fg == Color::Reset));
+        assert!(buffer.content[0].modifier.is_empty());
+        assert!(buffer.content[1].modifier.contains(Modifier::REVERSED));
+        assert!(buffer.content[2].modifier.contains(Modifier::BOLD));
+        assert!(!buffer.content[2].modifier.contains(Modifier::REVERSED));
+    }
 }
@@ -27,7 +27,7 @@ pub enum IgnoreStatus {
 }
 
 /// Manages ignore patterns for file filtering
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct IgnorePatterns {
     /// Gitignore matchers per directory
     /// Key: directory path, Value: gitignore rules for that directory
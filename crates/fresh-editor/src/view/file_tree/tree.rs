@@ -1,7 +1,9 @@
 use super::node::{NodeId, NodeState, TreeNode};
+use crate::input::fuzzy::{fuzzy_match_prepared, PreparedPattern};
 use crate::model::filesystem::DirEntry;
 use crate::services::fs::FsManager;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::future::Future;
 use std::io;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
@@ -25,6 +27,30 @@ pub struct FileTree {
     next_id: usize,
     /// Filesystem manager for async operations
     fs_manager: Arc<FsManager>,
+    /// Active path filter, if any (see `set_filter`)
+    filter: Option<TreeFilter>,
+}
+
+/// Upper bound on the number of entries listed to search for filter matches.
+/// Keeps filtering responsive in huge trees; matches beyond the cap are
+/// simply not found.
+const FILTER_MAX_NODES: usize = 20_000;
+
+/// State of an active path filter
+#[derive(Debug)]
+struct TreeFilter {
+    /// Current filter query (may be empty while the user is typing)
+    query: String,
+    /// Directories that were expanded before the filter was applied
+    expanded_before: HashSet<PathBuf>,
+    /// Whether the subtree has been loaded for this filter
+    loaded: bool,
+    /// Whether a subtree listing has been started but not applied yet
+    loading: bool,
+    /// Nodes whose relative path matches the query
+    matches: HashSet<NodeId>,
+    /// Matching nodes plus all of their ancestors
+    visible: HashSet<NodeId>,
 }
 
 impl FileTree {
@@ -69,6 +95,7 @@ impl FileTree {
             root_id,
             next_id: 1,
             fs_manager,
+            filter: None,
         })
     }
 
@@ -141,25 +168,11 @@ impl FileTree {
 
         match result {
             Ok(entries) => {
-                // Sort entries: directories first, then by name
-                let mut sorted_entries = entries;
-                sorted_entries.sort_by(|a, b| match (a.is_dir(), b.is_dir()) {
-                    (true, false) => std::cmp::Ordering::Less,
-                    (false, true) => std::cmp::Ordering::Greater,
-                    _ => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
-                });
-
-                // Create child nodes
-                let mut child_ids = Vec::new();
-                for entry in sorted_entries {
-                    let child_id = self.add_node(entry, Some(id));
-                    child_ids.push(child_id);
-                }
+                self.set_children(id, entries);
 
-                // Update parent node
-                if let Some(node) = self.get_node_mut(id) {
-                    node.children = child_ids;
-                    node.state = NodeState::Expanded;
+                // New children need to be checked against an active filter
+                if self.filter.as_ref().is_some_and(|f| !f.query.is_empty()) {
+                    self.refresh_filter_matches();
                 }
 
                 Ok(())
@@ -174,6 +187,29 @@ impl FileTree {
         }
     }
 
+    /// Create child nodes for a directory's entries and mark it expanded
+    fn set_children(&mut self, id: NodeId, mut entries: Vec<DirEntry>) {
+        // Sort entries: directories first, then by name
+        entries.sort_by(|a, b| match (a.is_dir(), b.is_dir()) {
+            (true, false) => std::cmp::Ordering::Less,
+            (false, true) => std::cmp::Ordering::Greater,
+            _ => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
+        });
+
+        // Create child nodes
+        let mut child_ids = Vec::new();
+        for entry in entries {
+            let child_id = self.add_node(entry, Some(id));
+            child_ids.push(child_id);
+        }
+
+        // Update parent node
+        if let Some(node) = self.get_node_mut(id) {
+            node.children = child_ids;
+            node.state = NodeState::Expanded;
+        }
+    }
+
     /// Collapse a directory node
     ///
     /// This removes all child nodes from memory to save space.
@@ -285,7 +321,7 @@ impl FileTree {
     /// Get all visible nodes in tree order
     ///
     /// Returns a flat list of nodes that should be visible, respecting
-    /// the expansion state of parent directories.
+    /// the expansion state of parent directories and the active filter.
     pub fn get_visible_nodes(&self) -> Vec<NodeId> {
         let mut visible = Vec::new();
         self.collect_visible_recursive(self.root_id, &mut visible);
@@ -294,6 +330,10 @@ impl FileTree {
 
     /// Recursively collect visible nodes
     fn collect_visible_recursive(&self, id: NodeId, visible: &mut Vec<NodeId>) {
+        if !self.passes_filter(id) {
+            return;
+        }
+
         visible.push(id);
 
         if let Some(node) = self.get_node(id) {
@@ -305,6 +345,233 @@ impl FileTree {
         }
     }
 
+    // ==================== Filter Methods ====================
+
+    /// Narrow the visible nodes to those whose path (relative to the root)
+    /// fuzzy-matches `query`, plus their ancestors.
+    ///
+    /// The first call snapshots which directories are expanded. Only loaded
+    /// nodes are matched; to find nested matches, list the subtree with
+    /// `list_subtree` once `begin_filter_load` asks for it and pass the
+    /// result to `apply_filter_listings`, which expands the listed
+    /// directories (and so the parents of every match). An empty query keeps
+    /// the filter active but restores the original expansion and shows the
+    /// unfiltered tree.
+    ///
+    /// Call `clear_filter` to leave filter mode.
+    pub fn set_filter(&mut self, query: &str) {
+        if self.filter.is_none() {
+            let mut expanded_before = Vec::new();
+            self.collect_expanded_recursive(self.root_id, &mut expanded_before);
+            self.filter = Some(TreeFilter {
+                query: String::new(),
+                expanded_before: expanded_before.into_iter().collect(),
+                loaded: false,
+                loading: false,
+                matches: HashSet::new(),
+                visible: HashSet::new(),
+            });
+        }
+
+        if query.is_empty() {
+            if self.filter.as_ref().is_some_and(|f| f.loaded) {
+                self.restore_filter_expansion();
+            }
+            if let Some(filter) = self.filter.as_mut() {
+                filter.query.clear();
+                filter.loaded = false;
+                filter.matches.clear();
+                filter.visible.clear();
+            }
+            return;
+        }
+
+        if let Some(filter) = self.filter.as_mut() {
+            filter.query = query.to_string();
+        }
+        self.refresh_filter_matches();
+    }
+
+    /// Check whether the active filter still needs the subtree listed, and
+    /// if so mark the listing as started. Returns true at most once per
+    /// listing, so the caller starts `list_subtree` exactly when this does.
+    pub fn begin_filter_load(&mut self) -> bool {
+        match self.filter.as_mut() {
+            Some(filter) if !filter.query.is_empty() && !filter.loaded && !filter.loading => {
+                filter.loading = true;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// List every directory under the root breadth-first, skipping symlinked
+    /// directories (which may form cycles) and anything `should_descend`
+    /// rejects.
+    ///
+    /// The returned future doesn't borrow the tree, so it can run on the
+    /// async runtime while the tree stays usable.
+    pub fn list_subtree(
+        &self,
+        should_descend: impl Fn(&DirEntry) -> bool + Send + 'static,
+    ) -> impl Future<Output = Vec<(PathBuf, Vec<DirEntry>)>> + Send + 'static {
+        let fs_manager = Arc::clone(&self.fs_manager);
+        let root_path = self.root_path.clone();
+        async move {
+            let mut listings = Vec::new();
+            let mut listed = 0;
+            let mut queue = VecDeque::from([root_path]);
+            while let Some(path) = queue.pop_front() {
+                if listed >= FILTER_MAX_NODES {
+                    break;
+                }
+                let entries = match fs_manager.list_dir_with_metadata(path.clone()).await {
+                    Ok(entries) => entries,
+                    Err(e) => {
+                        tracing::debug!("Failed to list {:?} for filter: {}", path, e);
+                        continue;
+                    }
+                };
+                listed += entries.len();
+                queue.extend(
+                    entries
+                        .iter()
+                        .filter(|e| e.is_dir() && !e.is_symlink() && should_descend(e))
+                        .map(|e| e.path.clone()),
+                );
+                listings.push((path, entries));
+            }
+            listings
+        }
+    }
+
+    /// Expand every collapsed directory in `listings` (from `list_subtree`)
+    /// and re-match the filter against the loaded tree.
+    ///
+    /// Returns false, applying nothing, if the filter was cleared or emptied
+    /// while the listing ran.
+    pub fn apply_filter_listings(&mut self, listings: Vec<(PathBuf, Vec<DirEntry>)>) -> bool {
+        let Some(filter) = self.filter.as_mut() else {
+            return false;
+        };
+        filter.loading = false;
+        if filter.query.is_empty() || filter.loaded {
+            return false;
+        }
+        filter.loaded = true;
+
+        // Listings are breadth-first, so each directory's node exists by the
+        // time its listing is applied
+        for (path, entries) in listings {
+            let Some(node) = self.get_node_by_path(&path) else {
+                continue;
+            };
+            if node.is_dir() && !node.is_expanded() {
+                let id = node.id;
+                self.set_children(id, entries);
+            }
+        }
+
+        self.refresh_filter_matches();
+        true
+    }
+
+    /// Leave filter mode, collapsing every directory that was expanded only
+    /// to search for matches.
+    pub fn clear_filter(&mut self) {
+        if self.filter.as_ref().is_some_and(|f| f.loaded) {
+            self.restore_filter_expansion();
+        }
+        self.filter = None;
+    }
+
+    /// The active filter query, or None when not in filter mode
+    pub fn filter_query(&self) -> Option<&str> {
+        self.filter.as_ref().map(|f| f.query.as_str())
+    }
+
+    /// Check whether a node's own path matches the active filter query
+    pub fn node_matches_filter(&self, id: NodeId) -> bool {
+        match &self.filter {
+            Some(filter) if !filter.query.is_empty() => filter.matches.contains(&id),
+            _ => true,
+        }
+    }
+
+    /// Check whether a node survives the active filter: it matches, or one of
+    /// its descendants does. The root always passes.
+    pub fn passes_filter(&self, id: NodeId) -> bool {
+        match &self.filter {
+            Some(filter) if !filter.query.is_empty() => {
+                id == self.root_id || filter.visible.contains(&id)
+            }
+            _ => true,
+        }
+    }
+
+    /// Recompute the matching and visible node sets for the current query
+    fn refresh_filter_matches(&mut self) {
+        let Some(query) = self.filter.as_ref().map(|f| f.query.clone()) else {
+            return;
+        };
+
+        let mut matches = HashSet::new();
+        let mut visible = HashSet::new();
+        if !query.is_empty() {
+            let pattern = PreparedPattern::new(&query);
+            for (&id, node) in &self.nodes {
+                if id == self.root_id {
+                    continue;
+                }
+                let relative = node
+                    .entry
+                    .path
+                    .strip_prefix(&self.root_path)
+                    .unwrap_or(&node.entry.path)
+                    .to_string_lossy();
+                if fuzzy_match_prepared(&pattern, &relative).matched {
+                    matches.insert(id);
+                    let mut current = Some(id);
+                    while let Some(node_id) = current {
+                        if !visible.insert(node_id) {
+                            break;
+                        }
+                        current = self.get_node(node_id).and_then(|n| n.parent);
+                    }
+                }
+            }
+        }
+
+        if let Some(filter) = self.filter.as_mut() {
+            filter.matches = matches;
+            filter.visible = visible;
+        }
+    }
+
+    /// Collapse every directory that was not expanded before the filter
+    fn restore_filter_expansion(&mut self) {
+        let Some(keep) = self.filter.as_ref().map(|f| f.expanded_before.clone()) else {
+            return;
+        };
+        self.collapse_unless_in(self.root_id, &keep);
+    }
+
+    fn collapse_unless_in(&mut self, id: NodeId, keep: &HashSet<PathBuf>) {
+        let Some(node) = self.get_node(id) else {
+            return;
+        };
+        if !node.is_expanded() {
+            return;
+        }
+        if !keep.contains(&node.entry.path) {
+            self.collapse_node(id);
+            return;
+        }
+        for child_id in node.children.clone() {
+            self.collapse_unless_in(child_id, keep);
+        }
+    }
+
     /// Get the parent chain for a node (from root to node)
     pub fn get_ancestors(&self, id: NodeId) -> Vec<NodeId> {
         let mut ancestors = Vec::new();
@@ -779,6 +1046,42 @@ mod tests {
         assert!(result.is_none(), "Should return None for nonexistent paths");
     }
 
+    #[tokio::test]
+    async fn test_filter_shows_only_matching_branches() {
+        let (_temp_dir, mut tree) = create_test_tree().await;
+        tree.expand_node(tree.root_id()).await.unwrap();
+
+        // Nothing loaded matches yet, so only the root is left
+        tree.set_filter("file3");
+        assert_eq!(tree.get_visible_nodes(), vec![tree.root_id()]);
+
+        // The subtree is listed once per filter
+        assert!(tree.begin_filter_load());
+        assert!(!tree.begin_filter_load());
+        let listings = tree.list_subtree(|_| true).await;
+        assert!(tree.apply_filter_listings(listings));
+
+        // Only the branch leading to dir2/subdir/file3.txt remains, with its
+        // parent directories expanded
+        let names: Vec<String> = tree
+            .get_visible_nodes()
+            .into_iter()
+            .skip(1)
+            .map(|id| tree.get_node(id).unwrap().entry.name.clone())
+            .collect();
+        assert_eq!(names, vec!["dir2", "subdir", "file3.txt"]);
+
+        // Clearing restores the original expansion: only the root was open
+        tree.clear_filter();
+        assert_eq!(tree.filter_query(), None);
+        let visible = tree.get_visible_nodes();
+        assert_eq!(visible.len(), 4); // root + 3 children
+        let dir2 = tree
+            .get_node_by_path(&tree.root_path().join("dir2"))
+            .unwrap();
+        assert!(dir2.is_collapsed());
+    }
+
     // End-to-end observable behavior for `reload_expanded_node` —
     // preserved expansion state, visibility of newly-appeared files,
     // freshness of rendered metadata — is exercised at the e2e harness
@@ -5,6 +5,7 @@ use super::tree::FileTree;
 use crate::input::fuzzy::FuzzyMatch;
 use crate::model::filesystem::DirEntry;
 use std::collections::{HashMap, HashSet};
+use std::future::Future;
 use std::path::PathBuf;
 
 /// View state for file tree navigation and filtering
@@ -61,7 +62,8 @@ impl FileTreeView {
     /// Get visible nodes filtered by ignore patterns (hidden files, gitignored, etc.)
     ///
     /// Walks the expanded tree and skips ignored nodes along with their entire
-    /// subtree. The root node is never filtered out.
+    /// subtree, as well as branches without a match for the active path
+    /// filter. The root node is never filtered out.
     fn filtered_visible_nodes(&self) -> Vec<NodeId> {
         let mut result = Vec::new();
         self.collect_filtered_visible(self.tree.root_id(), &mut result);
@@ -71,7 +73,7 @@ impl FileTreeView {
     /// Recursively collect visible nodes, skipping ignored subtrees.
     fn collect_filtered_visible(&self, id: NodeId, result: &mut Vec<NodeId>) {
         let is_root = id == self.tree.root_id();
-        if !is_root && !self.is_node_visible(id) {
+        if !is_root && (!self.is_node_visible(id) || !self.tree.passes_filter(id)) {
             return;
         }
 
@@ -701,6 +703,103 @@ impl FileTreeView {
             .map(|node| self.search.matches(&node.entry.name))
             .unwrap_or(false)
     }
+
+    // ==================== Filter Methods ====================
+
+    /// Check if filter mode is active
+    pub fn is_filter_active(&self) -> bool {
+        self.tree.filter_query().is_some()
+    }
+
+    /// Get the current filter query (empty when not filtering)
+    pub fn filter_query(&self) -> &str {
+        self.tree.filter_query().unwrap_or("")
+    }
+
+    /// Filter the tree down to paths matching `query`, entering filter mode
+    /// if needed, and select the first matching node.
+    ///
+    /// Only loaded nodes are matched; `begin_filter_load` lists the rest.
+    pub fn set_filter(&mut self, query: &str) {
+        self.tree.set_filter(query);
+        self.select_first_filter_match();
+    }
+
+    /// Start listing the rest of the tree for the active filter, if it still
+    /// needs it. Ignored directories are not searched.
+    ///
+    /// Run the returned future off the UI thread and pass its output to
+    /// `apply_filter_listings`.
+    pub fn begin_filter_load(
+        &mut self,
+    ) -> Option<impl Future<Output = Vec<(PathBuf, Vec<DirEntry>)>> + Send + 'static> {
+        if !self.tree.begin_filter_load() {
+            return None;
+        }
+        let ignore_patterns = self.ignore_patterns.clone();
+        Some(
+            self.tree.list_subtree(move |entry| {
+                !ignore_patterns.is_ignored(&entry.path, entry.is_dir())
+            }),
+        )
+    }
+
+    /// Add the listings from a `begin_filter_load` future to the tree and
+    /// select the first match among the newly loaded nodes.
+    pub fn apply_filter_listings(&mut self, listings: Vec<(PathBuf, Vec<DirEntry>)>) {
+        if self.tree.apply_filter_listings(listings) {
+            self.select_first_filter_match();
+        }
+    }
+
+    /// Select the first node matching the filter query, or keep the current
+    /// selection if it is still visible
+    fn select_first_filter_match(&mut self) {
+        let visible = self.filtered_visible_nodes();
+        let first_match = if self.filter_query().is_empty() {
+            None
+        } else {
+            visible
+                .iter()
+                .copied()
+                .find(|&id| id != self.tree.root_id() && self.tree.node_matches_filter(id))
+        };
+        if let Some(id) = first_match {
+            self.selected_node = Some(id);
+        } else if !self.selected_node.is_some_and(|id| visible.contains(&id)) {
+            self.selected_node = Some(self.tree.root_id());
+        }
+        self.multi_selection.clear();
+        self.selection_anchor = None;
+        self.scroll_offset = 0;
+        self.update_scroll_for_selection();
+    }
+
+    /// Leave filter mode and restore the expansion state from before it.
+    ///
+    /// The selection stays on the same path if it is still loaded, otherwise
+    /// it moves to the nearest ancestor that is.
+    pub fn clear_filter(&mut self) {
+        let selected_path = self
+            .selected_node
+            .and_then(|id| self.tree.get_node(id))
+            .map(|node| node.entry.path.clone());
+
+        self.tree.clear_filter();
+
+        let mut path = selected_path.as_deref();
+        self.selected_node = Some(self.tree.root_id());
+        while let Some(p) = path {
+            if let Some(node) = self.tree.get_node_by_path(p) {
+                self.selected_node = Some(node.id);
+                break;
+            }
+            path = p.parent();
+        }
+        self.multi_selection.clear();
+        self.selection_anchor = None;
+        self.update_scroll_for_selection();
+    }
 }
 
 #[cfg(test)]
@@ -37,14 +37,15 @@
 //!     self.map.len() == self.order.len()
 //!     self.current_bytes <= self.byte_budget  (after any insert)
 
+use crate::config::WrapMode;
 use crate::state::EditorState;
 use crate::view::ui::split_rendering::base_tokens::build_base_tokens;
 use crate::view::ui::split_rendering::transforms::{
-    apply_conceal_ranges, apply_soft_breaks, apply_wrapping_transform,
+    apply_conceal_ranges, apply_soft_breaks, apply_wrapping_transform, reflow_inline_virtual_text,
 };
 use crate::view::ui::view_pipeline::{ViewLine, ViewLineIterator};
 use fresh_core::api::ViewTokenWireKind;
-use std::collections::{HashMap, VecDeque};
+use std::collections::{BTreeMap, HashMap, VecDeque};
 use std::sync::Arc;
 
 /// Default byte budget: 8 MiB.  Comfortably holds the full layout for a
@@ -80,6 +81,8 @@ pub struct LineWrapKey {
     pub gutter_width: u16,
     pub wrap_column: Option<u32>,
     pub hanging_indent: bool,
+    pub wrap_mode: WrapMode,
+    pub wrap_indent_extra: usize,
     pub line_wrap_enabled: bool,
 }
 
@@ -280,6 +283,8 @@ pub fn layout_for_plain_text(
     effective_width: usize,
     gutter_width: usize,
     hanging_indent: bool,
+    wrap_mode: WrapMode,
+    wrap_indent_extra: usize,
     tab_size: usize,
 ) -> Vec<ViewLine> {
     use crate::view::ui::view_pipeline::LineStart;
@@ -289,7 +294,14 @@ pub fn layout_for_plain_text(
         kind: ViewTokenWireKind::Text(line_text.to_string()),
         style: None,
     }];
-    let wrapped = apply_wrapping_transform(tokens, effective_width, gutter_width, hanging_indent);
+    let wrapped = apply_wrapping_transform(
+        tokens,
+        effective_width,
+        gutter_width,
+        hanging_indent,
+        wrap_mode,
+        wrap_indent_extra,
+    );
     let mut lines: Vec<ViewLine> =
         ViewLineIterator::new(&wrapped, false, true, tab_size, false).collect();
     // Invariant: every logical line is at least one visual row.  An
@@ -410,6 +422,8 @@ pub struct WrapGeometry {
     pub effective_width: usize,
     pub gutter_width: usize,
     pub hanging_indent: bool,
+    pub wrap_mode: WrapMode,
+    pub wrap_indent_extra: usize,
     pub wrap_column: Option<u32>,
     pub line_wrap_enabled: bool,
     pub view_mode: CacheViewMode,
@@ -427,6 +441,8 @@ impl WrapGeometry {
             gutter_width: self.gutter_width as u16,
             wrap_column: self.wrap_column,
             hanging_indent: self.hanging_indent,
+            wrap_mode: self.wrap_mode,
+            wrap_indent_extra: self.wrap_indent_extra,
             line_wrap_enabled: self.line_wrap_enabled,
         }
     }
@@ -446,7 +462,7 @@ impl WrapGeometry {
 ///   1. `build_base_tokens(top_byte=line_start, count=1)`
 ///   2. `apply_soft_breaks` (Compose mode, when any soft breaks overlap)
 ///   3. `apply_conceal_ranges` (Compose mode, when any conceals overlap)
-///   4. `apply_wrapping_transform`
+///   4. `apply_wrapping_transform`, then `reflow_inline_virtual_text`
 /// followed by `ViewLineIterator::collect()` to materialise the
 /// `Vec<ViewLine>`.
 ///
@@ -506,7 +522,23 @@ pub fn compute_line_layout(
             geom.effective_width,
             geom.gutter_width,
             geom.hanging_indent,
+            geom.wrap_mode,
+            geom.wrap_indent_extra,
         );
+        // Room for plugin virtual text, as in the renderer
+        if !state.virtual_texts.is_empty() {
+            let widths = state.virtual_texts.reflow_widths_in_range(
+                &state.marker_list,
+                line_start,
+                line_end + 1,
+            );
+            tokens = reflow_inline_virtual_text(
+                tokens,
+                &widths,
+                geom.effective_width,
+                geom.gutter_width,
+            );
+        }
     }
 
     // Materialise the ViewLines.  `build_base_tokens` may emit tokens
@@ -611,9 +643,11 @@ pub fn placeholder_layout_for_row_count(n: u32) -> Vec<ViewLine> {
 /// `soft_breaks_in_line` is the slice of `(byte_position, indent)` pairs
 /// for breaks falling **inside** `[line_start, line_start + line_text.len())`.
 /// Callers should pre-filter from the buffer-wide list.
+/// `virtual_text_widths` is as for [`count_visual_rows_for_text`].
 ///
 /// When `soft_breaks_in_line` is empty this is a thin wrapper over
 /// [`count_visual_rows_for_text`].
+#[allow(clippy::too_many_arguments)]
 pub fn count_visual_rows_for_text_with_soft_breaks(
     line_text: &str,
     line_start: usize,
@@ -621,6 +655,9 @@ pub fn count_visual_rows_for_text_with_soft_breaks(
     effective_width: usize,
     gutter_width: usize,
     hanging_indent: bool,
+    wrap_mode: WrapMode,
+    wrap_indent_extra: usize,
+    virtual_text_widths: &BTreeMap<usize, usize>,
 ) -> u32 {
     if soft_breaks_in_line.is_empty() {
         return count_visual_rows_for_text(
@@ -628,8 +665,19 @@ pub fn count_visual_rows_for_text_with_soft_breaks(
             effective_width,
             gutter_width,
             hanging_indent,
+            wrap_mode,
+            wrap_indent_extra,
+            virtual_text_widths,
         );
     }
+    // Virtual text of a segment, re-keyed to the segment as its rows
+    // are counted (after `indent` columns of leading spaces)
+    let segment_widths = |start: usize, end: usize, indent: u16| -> BTreeMap<usize, usize> {
+        virtual_text_widths
+            .range(start..end)
+            .map(|(&pos, &width)| (pos - start + indent as usize, width))
+            .collect()
+    };
 
     let mut total: u32 = 0;
     let mut prev_end: usize = 0; // byte offset within `line_text`
@@ -658,6 +706,9 @@ pub fn count_visual_rows_for_text_with_soft_breaks(
             effective_width,
             gutter_width,
             hanging_indent,
+            wrap_mode,
+            wrap_indent_extra,
+            &segment_widths(prev_end, rel, prev_indent),
         ));
         // The renderer's `apply_soft_breaks` consumes the Space token
         // *at* the break position when one is present (see
@@ -672,12 +723,16 @@ pub fn count_visual_rows_for_text_with_soft_breaks(
         prev_indent = indent;
     }
     let segment = &line_text[prev_end..];
+    // The last segment also carries the text at the line's end
     total = total.saturating_add(count_segment_rows_with_indent(
         segment,
         prev_indent,
         effective_width,
         gutter_width,
         hanging_indent,
+        wrap_mode,
+        wrap_indent_extra,
+        &segment_widths(prev_end, line_text.len() + 1, prev_indent),
     ));
     total.max(1)
 }
@@ -687,18 +742,30 @@ pub fn count_visual_rows_for_text_with_soft_breaks(
 /// columns reserved at the front.  An empty segment still occupies
 /// one visual row (matches the renderer, which emits a trailing
 /// `Break` for the broken position).
+#[allow(clippy::too_many_arguments)]
 fn count_segment_rows_with_indent(
     segment: &str,
     leading_indent: u16,
     effective_width: usize,
     gutter_width: usize,
     hanging_indent: bool,
+    wrap_mode: WrapMode,
+    wrap_indent_extra: usize,
+    virtual_text_widths: &BTreeMap<usize, usize>,
 ) -> u32 {
-    if segment.is_empty() && leading_indent == 0 {
+    if segment.is_empty() && leading_indent == 0 && virtual_text_widths.is_empty() {
         return 1;
     }
     if leading_indent == 0 {
-        return count_visual_rows_for_text(segment, effective_width, gutter_width, hanging_indent);
+        return count_visual_rows_for_text(
+            segment,
+            effective_width,
+            gutter_width,
+            hanging_indent,
+            wrap_mode,
+            wrap_indent_extra,
+            virtual_text_widths,
+        );
     }
     // Prepend the indent columns; this lets the renderer's word-wrap
     // see the same `current_line_width` it would after
@@ -708,7 +775,15 @@ fn count_segment_rows_with_indent(
         prefixed.push(' ');
     }
     prefixed.push_str(segment);
-    count_visual_rows_for_text(&prefixed, effective_width, gutter_width, hanging_indent)
+    count_visual_rows_for_text(
+        &prefixed,
+        effective_width,
+        gutter_width,
+        hanging_indent,
+        wrap_mode,
+        wrap_indent_extra,
+        virtual_text_widths,
+    )
 }
 
 /// Count visual rows for a single line's text under the renderer's
@@ -719,26 +794,58 @@ fn count_segment_rows_with_indent(
 /// tallies non-empty rows.  A trailing `Break` emitted when the last
 /// chunk exactly fills the effective width is followed by nothing
 /// meaningful and does not count as a row.
+///
+/// `virtual_text_widths` holds the reflowing inline virtual text drawn in
+/// the line (see `VirtualTextManager::reflow_widths_in_range`), keyed by
+/// byte offset within `line_text`; an entry at `line_text.len()` is
+/// end-of-line text.  When present the line is tokenized like the
+/// renderer's base tokens and run through `reflow_inline_virtual_text`
+/// too, so the rows that text pushes onto are counted.
 pub fn count_visual_rows_for_text(
     line_text: &str,
     effective_width: usize,
     gutter_width: usize,
     hanging_indent: bool,
+    wrap_mode: WrapMode,
+    wrap_indent_extra: usize,
+    virtual_text_widths: &BTreeMap<usize, usize>,
 ) -> u32 {
-    use crate::view::ui::split_rendering::transforms::apply_wrapping_transform;
     use fresh_core::api::ViewTokenWire;
 
-    let tokens = vec![ViewTokenWire {
-        source_offset: Some(0),
-        kind: ViewTokenWireKind::Text(line_text.to_string()),
-        style: None,
-    }];
-    let wrapped = apply_wrapping_transform(tokens, effective_width, gutter_width, hanging_indent);
+    let tokens = if virtual_text_widths.is_empty() {
+        vec![ViewTokenWire {
+            source_offset: Some(0),
+            kind: ViewTokenWireKind::Text(line_text.to_string()),
+            style: None,
+        }]
+    } else {
+        line_tokens(line_text)
+    };
+    let mut wrapped = apply_wrapping_transform(
+        tokens,
+        effective_width,
+        gutter_width,
+        hanging_indent,
+        wrap_mode,
+        wrap_indent_extra,
+    );
+    if !virtual_text_widths.is_empty() {
+        wrapped =
+            reflow_inline_virtual_text(wrapped, virtual_text_widths, effective_width, gutter_width);
+    }
     let mut rows: u32 = 0;
     let mut row_has_content = false;
     for t in &wrapped {
         match &t.kind {
-            ViewTokenWireKind::Newline => break,
+            ViewTokenWireKind::Newline => {
+                // End-of-line text moved onto a row of its own
+                if t.source_offset
+                    .is_some_and(|offset| virtual_text_widths.contains_key(&offset))
+                {
+                    row_has_content = true;
+                }
+                break;
+            }
             ViewTokenWireKind::Break => {
                 if row_has_content {
                     rows += 1;
@@ -761,6 +868,48 @@ pub fn count_visual_rows_for_text(
     rows.max(1)
 }
 
+/// Tokenize one line the way `build_base_tokens` does (words, spaces,
+/// control characters), ending with the line terminator, so virtual text
+/// lands on the token it is anchored to.
+fn line_tokens(line_text: &str) -> Vec<fresh_core::api::ViewTokenWire> {
+    use crate::view::ui::split_rendering::base_tokens::is_control_char;
+    use fresh_core::api::ViewTokenWire;
+
+    let mut tokens: Vec<ViewTokenWire> = Vec::new();
+    for (offset, ch) in line_text.char_indices() {
+        let kind = match ch {
+            ' ' => ViewTokenWireKind::Space,
+            '\t' => ViewTokenWireKind::Text(ch.to_string()),
+            _ if is_control_char(ch) => ViewTokenWireKind::BinaryByte(ch as u8),
+            _ => {
+                if let Some(ViewTokenWire {
+                    source_offset: Some(start),
+                    kind: ViewTokenWireKind::Text(text),
+                    ..
+                }) = tokens.last_mut()
+                {
+                    if *start + text.len() == offset {
+                        text.push(ch);
+                        continue;
+                    }
+                }
+                ViewTokenWireKind::Text(ch.to_string())
+            }
+        };
+        tokens.push(ViewTokenWire {
+            source_offset: Some(offset),
+            kind,
+            style: None,
+        });
+    }
+    tokens.push(ViewTokenWire {
+        source_offset: Some(line_text.len()),
+        kind: ViewTokenWireKind::Newline,
+        style: None,
+    });
+    tokens
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -775,6 +924,8 @@ mod tests {
             gutter_width: 6,
             wrap_column: None,
             hanging_indent: false,
+            wrap_mode: WrapMode::Word,
+            wrap_indent_extra: 0,
             line_wrap_enabled: true,
         }
     }
@@ -968,8 +1119,30 @@ mod tests {
     #[test]
     fn empty_line_is_one_row() {
         for width in [5usize, 10, 42, 80, 120] {
-            assert_eq!(count_visual_rows_for_text("", width, 0, false), 1);
-            assert_eq!(count_visual_rows_for_text("", width, 6, false), 1);
+            assert_eq!(
+                count_visual_rows_for_text(
+                    "",
+                    width,
+                    0,
+                    false,
+                    WrapMode::Word,
+                    0,
+                    &BTreeMap::new()
+                ),
+                1
+            );
+            assert_eq!(
+                count_visual_rows_for_text(
+                    "",
+                    width,
+                    6,
+                    false,
+                    WrapMode::Word,
+                    0,
+                    &BTreeMap::new()
+                ),
+                1
+            );
         }
     }
 
@@ -980,7 +1153,10 @@ mod tests {
         // "hello world" = 11 chars; at effective_width=80, gutter=6 →
         // available width = 74 > 11, must be 1 row.
         for text in ["hello", "hello world", "a b c d"] {
-            assert_eq!(count_visual_rows_for_text(text, 80, 6, false), 1);
+            assert_eq!(
+                count_visual_rows_for_text(text, 80, 6, false, WrapMode::Word, 0, &BTreeMap::new()),
+                1
+            );
         }
     }
 
@@ -1004,7 +1180,15 @@ mod tests {
             // effective_width must be > gutter to leave any available
             // width; start well above.
             for w in [10usize, 15, 20, 30, 50, 80, 120, 200] {
-                let rows = count_visual_rows_for_text(text, w, gutter, false);
+                let rows = count_visual_rows_for_text(
+                    text,
+                    w,
+                    gutter,
+                    false,
+                    WrapMode::Word,
+                    0,
+                    &BTreeMap::new(),
+                );
                 if let Some(prev) = prev_rows {
                     assert!(
                         rows <= prev,
@@ -1038,7 +1222,8 @@ mod tests {
         ];
         for (text, w) in cases {
             assert!(
-                count_visual_rows_for_text(text, w, 0, false) >= 1,
+                count_visual_rows_for_text(text, w, 0, false, WrapMode::Word, 0, &BTreeMap::new())
+                    >= 1,
                 "row count < 1 for text={:?}, width={}",
                 text,
                 w,
@@ -1059,7 +1244,15 @@ mod tests {
         let mut prev_rows: u32 = 0;
         for len in (0..=base.len()).step_by(5) {
             let prefix = &base[..len];
-            let rows = count_visual_rows_for_text(prefix, width, gutter, false);
+            let rows = count_visual_rows_for_text(
+                prefix,
+                width,
+                gutter,
+                false,
+                WrapMode::Word,
+                0,
+                &BTreeMap::new(),
+            );
             assert!(
                 rows >= prev_rows,
                 "prefix property violated: len={}, rows={}, prev_rows={}",
@@ -1077,13 +1270,33 @@ mod tests {
         let text = "word00 word01 word02 word03 word04 word05 word06 word07 word08 word09";
         let w = 30usize;
         let g = 4usize;
-        let r1 = count_visual_rows_for_text(text, w, g, false);
+        let r1 = count_visual_rows_for_text(text, w, g, false, WrapMode::Word, 0, &BTreeMap::new());
         for _ in 0..16 {
-            let r = count_visual_rows_for_text(text, w, g, false);
+            let r =
+                count_visual_rows_for_text(text, w, g, false, WrapMode::Word, 0, &BTreeMap::new());
             assert_eq!(r, r1, "non-deterministic row count");
         }
     }
 
+    /// Reflowing virtual text adds the rows the renderer's
+    /// `reflow_inline_virtual_text` pass creates for it.
+    #[test]
+    fn count_includes_reflowed_virtual_text_rows() {
+        // "hello world" fills 11 of 15 columns
+        let text = "hello world";
+        let rows = |widths: &[(usize, usize)]| {
+            let widths: BTreeMap<usize, usize> = widths.iter().copied().collect();
+            count_visual_rows_for_text(text, 15, 0, false, WrapMode::Word, 0, &widths)
+        };
+        assert_eq!(rows(&[]), 1);
+        // End-of-line text that fits after the last word
+        assert_eq!(rows(&[(11, 4)]), 1);
+        // End-of-line text that doesn't: it gets a row of its own
+        assert_eq!(rows(&[(11, 7)]), 2);
+        // Inline text inside "world" carries the word to the next row
+        assert_eq!(rows(&[(8, 6)]), 2);
+    }
+
     // -------------------------------------------------------------------
     // Layer 3 (partial): shadow-model property test.
     //
@@ -1129,7 +1342,15 @@ mod tests {
             let text = &texts[t_idx];
             let width = widths[w_idx];
 
-            let shadow_rows = count_visual_rows_for_text(text, width, 2, false);
+            let shadow_rows = count_visual_rows_for_text(
+                text,
+                width,
+                2,
+                false,
+                WrapMode::Word,
+                0,
+                &BTreeMap::new(),
+            );
 
             let key = LineWrapKey {
                 pipeline_inputs_version: 0,
@@ -1139,6 +1360,8 @@ mod tests {
                 gutter_width: 2,
                 wrap_column: None,
                 hanging_indent: false,
+                wrap_mode: WrapMode::Word,
+                wrap_indent_extra: 0,
                 line_wrap_enabled: true,
             };
             let real_val = real.get_or_insert_with(key, || dummy_lines(shadow_rows));
@@ -1171,6 +1394,8 @@ mod tests {
             gutter_width: 6,
             wrap_column: None,
             hanging_indent: false,
+            wrap_mode: WrapMode::Word,
+            wrap_indent_extra: 0,
             line_wrap_enabled: true,
         };
         cache.get_or_insert_with(key_v0, || dummy_lines(5));
@@ -1213,11 +1438,13 @@ mod tests {
             gutter_width: 6,
             wrap_column: None,
             hanging_indent: false,
+            wrap_mode: WrapMode::Word,
+            wrap_indent_extra: 0,
             line_wrap_enabled: true,
         };
 
         // Vary each field in turn; each variation must be a distinct key.
-        let variations: [LineWrapKey; 8] = [
+        let variations: [LineWrapKey; 10] = [
             LineWrapKey {
                 pipeline_inputs_version: 2,
                 ..base
@@ -1246,6 +1473,14 @@ mod tests {
                 hanging_indent: true,
                 ..base
             },
+            LineWrapKey {
+                wrap_mode: WrapMode::Char,
+                ..base
+            },
+            LineWrapKey {
+                wrap_indent_extra: 2,
+                ..base
+            },
             LineWrapKey {
                 line_wrap_enabled: false,
                 ..base
@@ -93,7 +93,10 @@ pub enum PopupResolver {
     #[default]
     None,
     /// LSP completion popup. Confirm inserts the selected item's text.
-    Completion,
+    /// Row `i` below `item_ids.len()` is the LSP item at index
+    /// `item_ids[i]` of `Editor::completion_items`; the rows after them
+    /// are buffer-word completions.
+    Completion { item_ids: Vec<usize> },
     /// "Start LSP server?" confirmation. Confirm dispatches the selected
     /// row's `data` (e.g. "allow_once") through
     /// `handle_lsp_confirmation_response`.
@@ -105,6 +108,9 @@ pub enum PopupResolver {
     /// `Editor::pending_code_actions` (heavy `lsp_types` payload stays
     /// there to keep the view crate free of LSP types).
     CodeAction,
+    /// LSP document-symbols outline. Selected row's `data` is the
+    /// symbol's "line:character" LSP position; confirm jumps there.
+    DocumentSymbol,
     /// Plugin-requested action popup (`editor.showActionPopup`). Confirm
     /// fires `action_popup_result` with this popup's id and the selected
     /// row's `data` as the action id.
@@ -114,6 +120,12 @@ pub enum PopupResolver {
     /// Confirm dispatches the selected row's `data` through
     /// `handle_remote_indicator_action`.
     RemoteIndicator,
+    /// LSP signature help. Stays open while the user types the call's
+    /// arguments and is refreshed on each edit; confirm/cancel dismiss it.
+    SignatureHelp,
+    /// Preview of a multi-file LSP rename listing every edit location.
+    /// Confirm applies `Editor::pending_rename_edit`; cancel drops it.
+    RenamePreview,
 }
 
 /// Content of a popup window
@@ -288,6 +300,11 @@ pub struct Popup {
     /// constructing the popup so the hint reflects the user's actual
     /// keybinding for `popup_focus`.
     pub focus_key_hint: Option<String>,
+
+    /// Secondary popup drawn beside this one (e.g. the documentation of
+    /// the selected completion item). It is not part of the popup stack:
+    /// it never takes focus and is scrolled through keys of its owner.
+    pub preview: Option<Box<Popup>>,
 }
 
 impl Popup {
@@ -311,6 +328,7 @@ impl Popup {
             resolver: PopupResolver::None,
             focused: false,
             focus_key_hint: None,
+            preview: None,
         }
     }
 
@@ -342,6 +360,7 @@ impl Popup {
             resolver: PopupResolver::None,
             focused: false,
             focus_key_hint: None,
+            preview: None,
         }
     }
 
@@ -365,6 +384,7 @@ impl Popup {
             resolver: PopupResolver::None,
             focused: false,
             focus_key_hint: None,
+            preview: None,
         }
     }
 
@@ -465,6 +485,14 @@ impl Popup {
         }
     }
 
+    /// Index of the currently selected item (if this is a list popup)
+    pub fn selected_index(&self) -> Option<usize> {
+        match &self.content {
+            PopupContent::List { items, selected } if *selected < items.len() => Some(*selected),
+            _ => None,
+        }
+    }
+
     /// Get the actual visible content height (accounting for borders)
     fn visible_height(&self) -> usize {
         let border_offset = if self.bordered { 2 } else { 0 };
@@ -863,6 +891,43 @@ impl Popup {
         description_lines + content_lines + border_height
     }
 
+    /// Calculate the area of the preview pane, placed beside `anchor` (the
+    /// area this popup was rendered in).
+    ///
+    /// The preview goes to the right of the popup, or to the left when there
+    /// is more room there. Returns `None` when there is no preview or not
+    /// enough room to show one.
+    pub fn preview_area(&self, anchor: Rect, terminal_area: Rect) -> Option<Rect> {
+        let preview = self.preview.as_ref()?;
+
+        let right_room = terminal_area.width.saturating_sub(anchor.x + anchor.width);
+        let left_room = anchor.x;
+        let (x, width) = if right_room >= preview.width || right_room >= left_room {
+            (anchor.x + anchor.width, preview.width.min(right_room))
+        } else {
+            let width = preview.width.min(left_room);
+            (anchor.x - width, width)
+        };
+
+        // Too narrow for a border plus any content
+        if width < 4 {
+            return None;
+        }
+
+        let height = preview
+            .content_height_for_width(width)
+            .min(preview.max_height)
+            .min(terminal_area.height);
+        let y = anchor.y.min(terminal_area.height.saturating_sub(height));
+
+        Some(Rect {
+            x,
+            y,
+            width,
+            height,
+        })
+    }
+
     /// Calculate the area where this popup should be rendered
     pub fn calculate_area(&self, terminal_area: Rect, cursor_pos: Option<(u16, u16)>) -> Rect {
         match self.position {
@@ -1714,6 +1779,40 @@ mod tests {
         assert_eq!(area.y, 47);
     }
 
+    #[test]
+    fn test_popup_preview_area() {
+        let theme = crate::view::theme::Theme::load_builtin(theme::THEME_DARK).unwrap();
+        let terminal_area = Rect {
+            x: 0,
+            y: 0,
+            width: 100,
+            height: 50,
+        };
+        let mut popup = Popup::list(vec![PopupListItem::new("item".to_string())], &theme);
+        let anchor = Rect {
+            x: 10,
+            y: 5,
+            width: 30,
+            height: 3,
+        };
+
+        // No preview, no area
+        assert_eq!(popup.preview_area(anchor, terminal_area), None);
+
+        popup.preview = Some(Box::new(
+            Popup::text(vec!["docs".to_string()], &theme).with_width(40),
+        ));
+
+        // Room on the right: the preview sits flush against the popup
+        let area = popup.preview_area(anchor, terminal_area).unwrap();
+        assert_eq!((area.x, area.y, area.width, area.height), (40, 5, 40, 3));
+
+        // Popup near the right edge: the preview moves to the left
+        let anchor_right = Rect { x: 65, ..anchor };
+        let area = popup.preview_area(anchor_right, terminal_area).unwrap();
+        assert_eq!((area.x, area.width), (25, 40));
+    }
+
     #[test]
     fn test_clamp_rect_to_bounds() {
         let bounds = Rect {
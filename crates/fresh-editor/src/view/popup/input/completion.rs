@@ -9,6 +9,7 @@
 //! - Ctrl+Space: toggle (dismiss) the popup
 //! - Backspace: remove last filter character
 //! - Arrow keys: navigate the list
+//! - Alt+Up/Alt+Down: scroll the documentation preview
 
 use super::base::{try_handle_shared, SharedHandleResult};
 use crate::input::handler::{DeferredAction, InputContext, InputResult};
@@ -53,6 +54,11 @@ pub fn handle_completion_input(
             InputResult::Consumed
         }
 
+        // Alt+Up/Alt+Down scroll the documentation preview
+        KeyCode::Up | KeyCode::Down if event.modifiers == KeyModifiers::ALT => {
+            InputResult::Consumed
+        }
+
         // Type-to-filter: only word characters (letters, digits, underscore)
         KeyCode::Char(c)
             if (event.modifiers.is_empty() || event.modifiers == KeyModifiers::SHIFT)
@@ -116,6 +122,15 @@ pub fn handle_completion_input_with_popup(
             InputResult::Consumed
         }
 
+        // Alt+Up/Alt+Down scroll the documentation preview without moving
+        // the selection
+        KeyCode::Up | KeyCode::Down if event.modifiers == KeyModifiers::ALT => {
+            if let Some(preview) = popup.preview.as_mut() {
+                preview.scroll_by(if event.code == KeyCode::Up { -1 } else { 1 });
+            }
+            InputResult::Consumed
+        }
+
         // Type-to-filter: only word characters (letters, digits, underscore)
         KeyCode::Char(c)
             if (event.modifiers.is_empty() || event.modifiers == KeyModifiers::SHIFT)
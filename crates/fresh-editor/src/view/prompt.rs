@@ -64,6 +64,9 @@ pub enum PromptType {
         end_pos: usize,
         overlay_handle: crate::view::overlay::OverlayHandle,
     },
+    /// LSP workspace symbol search; suggestions are filled from
+    /// `workspace/symbol` responses as the user types
+    LspWorkspaceSymbols,
     /// Record a macro - prompts for register (0-9)
     RecordMacro,
     /// Play a macro - prompts for register (0-9)
@@ -74,6 +77,10 @@ pub enum PromptType {
     JumpToBookmark,
     /// Set page width (empty clears to viewport)
     SetPageWidth,
+    /// Export the active buffer's event log to a file
+    ExportEventLog,
+    /// Set compose mode column guides (comma-separated, empty clears)
+    SetComposeGuides,
     /// Add a vertical ruler at a column position
     AddRuler,
     /// Remove a vertical ruler (select from list)
@@ -101,6 +108,12 @@ pub enum PromptType {
     SelectLocale,
     /// Select a theme for copy with formatting
     CopyWithFormattingTheme,
+    /// Delimiter to surround the selection with (`t` asks for a tag)
+    Surround,
+    /// Tag name to surround the selection with
+    SurroundTag,
+    /// Delimiter to line up on each cursor's line (empty = the cursors)
+    AlignCursors,
     /// Confirm reverting a modified file
     ConfirmRevert,
     /// Confirm saving over a file that changed on disk
@@ -118,6 +131,13 @@ pub enum PromptType {
     ConfirmCloseBuffer {
         buffer_id: crate::model::event::BufferId,
     },
+    /// Confirm closing several tabs of a split when some are modified
+    /// (save/discard/cancel). `keep_buffer_id` is shown once they are closed.
+    ConfirmCloseTabs {
+        split_id: crate::model::event::LeafId,
+        buffer_ids: Vec<crate::model::event::BufferId>,
+        keep_buffer_id: crate::model::event::BufferId,
+    },
     /// Confirm quitting with modified buffers
     ConfirmQuitWithModified,
     /// File Explorer rename operation
@@ -162,6 +182,14 @@ pub enum PromptType {
     ConfirmLargeFileEncoding { path: std::path::PathBuf },
     /// Switch to a tab by name (from the current split's open buffers)
     SwitchToTab,
+    /// Pick a bookmark to jump to (or delete) from the bookmark list
+    ListBookmarks,
+    /// Register to copy the selection into
+    CopyToRegister,
+    /// Register to cut the selection into
+    CutToRegister,
+    /// Pick a register to paste from the register list
+    PasteFromRegister,
     /// Run shell command on buffer/selection
     /// If replace is true, replace the input with the output
     /// If replace is false, output goes to a new buffer
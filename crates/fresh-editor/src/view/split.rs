@@ -23,6 +23,7 @@
 /// └────────────────────┘      └──────────┴─────────┘
 ///  (horizontal split)          (mixed splits)
 /// ```
+use crate::config::WrapMode;
 use crate::model::buffer::Buffer;
 use crate::model::cursor::Cursors;
 use crate::model::event::{BufferId, ContainerId, LeafId, SplitDirection, SplitId};
@@ -220,15 +221,19 @@ impl BufferViewState {
     /// Apply editor config defaults for display settings.
     ///
     /// Sets `show_line_numbers`, `highlight_current_line`, `line_wrap`,
-    /// `wrap_column`, and `rulers` from the given config values. Call this after
+    /// `wrap_indent`, `wrap_mode`, `wrap_indent_extra`, `wrap_column`, and
+    /// `rulers` from the given config values. Call this after
     /// creating a new `BufferViewState` (via `new()` or `ensure_buffer_state()`)
     /// to ensure the view respects the user's settings.
+    #[allow(clippy::too_many_arguments)]
     pub fn apply_config_defaults(
         &mut self,
         line_numbers: bool,
         highlight_current_line: bool,
         line_wrap: bool,
         wrap_indent: bool,
+        wrap_mode: WrapMode,
+        wrap_indent_extra: usize,
         wrap_column: Option<usize>,
         rulers: Vec<usize>,
     ) {
@@ -236,6 +241,8 @@ impl BufferViewState {
         self.highlight_current_line = highlight_current_line;
         self.viewport.line_wrap_enabled = line_wrap;
         self.viewport.wrap_indent = wrap_indent;
+        self.viewport.wrap_mode = wrap_mode;
+        self.viewport.wrap_indent_extra = wrap_indent_extra;
         self.viewport.wrap_column = wrap_column;
         self.rulers = rulers;
     }
@@ -446,6 +453,15 @@ impl SplitViewState {
         }
     }
 
+    /// Apply the editor's vertical scroll settings (`scroll_offset` and
+    /// `overscroll`) to every buffer view in this split.
+    pub fn apply_scroll_settings(&mut self, scroll_offset: usize, overscroll: bool) {
+        for buf_state in self.keyed_states.values_mut() {
+            buf_state.viewport.set_scroll_offset(scroll_offset);
+            buf_state.viewport.overscroll = overscroll;
+        }
+    }
+
     /// Mark layout as needing rebuild (call after buffer changes)
     pub fn invalidate_layout(&mut self) {
         self.layout_dirty = true;
@@ -1524,6 +1540,26 @@ impl SplitManager {
         }
     }
 
+    /// The leaf after `split_id` in navigation order (circular), or `None`
+    /// when it is the only leaf
+    pub fn next_leaf(&self, split_id: LeafId) -> Option<LeafId> {
+        let leaf_ids = self.root.leaf_split_ids();
+        let pos = leaf_ids.iter().position(|id| *id == split_id)?;
+        let next = leaf_ids[(pos + 1) % leaf_ids.len()];
+        (next != split_id).then_some(next)
+    }
+
+    /// Exchange the buffers shown by two leaf splits
+    pub fn swap_leaf_buffers(&mut self, a: LeafId, b: LeafId) {
+        let (Some(buffer_a), Some(buffer_b)) =
+            (self.get_buffer_id(a.into()), self.get_buffer_id(b.into()))
+        else {
+            return;
+        };
+        self.set_split_buffer(a, buffer_b);
+        self.set_split_buffer(b, buffer_a);
+    }
+
     /// Navigate to the previous split (circular)
     pub fn prev_split(&mut self) {
         let leaf_ids = self.root.leaf_split_ids();
@@ -1591,6 +1627,14 @@ impl SplitManager {
         Ok(())
     }
 
+    /// Maximize a specific leaf split, as saved in a workspace. Ignored
+    /// when the split doesn't exist or is the only one.
+    pub fn restore_maximized_split(&mut self, split_id: LeafId) {
+        if self.root.count_leaves() > 1 && self.root.find(split_id.into()).is_some() {
+            self.maximized_split = Some(split_id.into());
+        }
+    }
+
     /// Check if a split is currently maximized
     pub fn is_maximized(&self) -> bool {
         self.maximized_split.is_some()
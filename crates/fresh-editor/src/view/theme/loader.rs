@@ -6,6 +6,7 @@
 
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
+use std::time::SystemTime;
 
 use super::types::{Theme, ThemeFile, ThemeInfo, BUILTIN_THEMES};
 
@@ -402,6 +403,34 @@ impl ThemeLoader {
         }
     }
 
+    /// Modification times of every file under the user themes directory,
+    /// sorted by path. Two snapshots differ when a theme file was added,
+    /// removed or edited on disk.
+    pub fn user_theme_mtimes(&self) -> Vec<(PathBuf, SystemTime)> {
+        let mut mtimes = Vec::new();
+        if let Some(ref user_dir) = self.user_themes_dir {
+            Self::collect_mtimes(user_dir, &mut mtimes);
+        }
+        mtimes.sort();
+        mtimes
+    }
+
+    fn collect_mtimes(dir: &Path, mtimes: &mut Vec<(PathBuf, SystemTime)>) {
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            return;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                Self::collect_mtimes(&path, mtimes);
+            } else if path.extension().is_some_and(|ext| ext == "json") {
+                if let Ok(modified) = entry.metadata().and_then(|m| m.modified()) {
+                    mtimes.push((path, modified));
+                }
+            }
+        }
+    }
+
     /// Read the `repository` field from a package.json manifest value.
     fn read_repository(manifest: &serde_json::Value) -> Option<String> {
         manifest
@@ -6,6 +6,7 @@
 use ratatui::style::Color;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
 
 pub const THEME_DARK: &str = "dark";
 pub const THEME_LIGHT: &str = "light";
@@ -259,6 +260,11 @@ pub struct ThemeFile {
     pub diagnostic: DiagnosticColors,
     /// Syntax highlighting colors
     pub syntax: SyntaxColors,
+    /// Color overrides for highlight scopes, keyed by scope (`macro`,
+    /// `keyword`, …) or by language-qualified scope (`rust.macro`).
+    /// Scopes are tree-sitter categories and LSP semantic token types.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub scopes: BTreeMap<String, ColorDef>,
 }
 
 /// Editor area colors
@@ -311,6 +317,15 @@ pub struct EditorColors {
     /// Whitespace indicator foreground color (for tab arrows and space dots)
     #[serde(default = "default_whitespace_indicator_fg")]
     pub whitespace_indicator_fg: ColorDef,
+    /// Whitespace indicator foreground color for trailing whitespace
+    #[serde(default = "default_trailing_whitespace_fg")]
+    pub trailing_whitespace_fg: ColorDef,
+    /// Indent guide foreground color
+    #[serde(default = "default_indent_guide_fg")]
+    pub indent_guide_fg: ColorDef,
+    /// Indent guide foreground color for the block containing the cursor
+    #[serde(default = "default_indent_guide_active_fg")]
+    pub indent_guide_active_fg: ColorDef,
     /// Background color for lines after end-of-file (optional override).
     /// When not set, computed as a slightly contrasting shade of `bg`
     /// (lighter for dark themes, darker for light themes) to give post-EOF
@@ -359,6 +374,15 @@ fn default_ruler_bg() -> ColorDef {
 fn default_whitespace_indicator_fg() -> ColorDef {
     ColorDef::Rgb(70, 70, 70) // Subdued dark gray, subtle but visible
 }
+fn default_trailing_whitespace_fg() -> ColorDef {
+    ColorDef::Rgb(160, 70, 70) // Muted red, flags whitespace that can be trimmed
+}
+fn default_indent_guide_fg() -> ColorDef {
+    ColorDef::Rgb(60, 60, 60) // Faint gray, just above the default editor bg
+}
+fn default_indent_guide_active_fg() -> ColorDef {
+    ColorDef::Rgb(110, 110, 110) // Brighter gray so the cursor's block stands out
+}
 
 /// UI element colors (tabs, menus, status bar, etc.)
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -983,6 +1007,11 @@ pub struct Theme {
 
     // Whitespace indicator color (tab arrows, space dots)
     pub whitespace_indicator_fg: Color,
+    pub trailing_whitespace_fg: Color,
+
+    // Indent guide colors
+    pub indent_guide_fg: Color,
+    pub indent_guide_active_fg: Color,
 
     // Diff highlighting colors
     pub diff_add_bg: Color,
@@ -1127,6 +1156,9 @@ pub struct Theme {
     pub syntax_operator: Color,
     pub syntax_punctuation_bracket: Color,
     pub syntax_punctuation_delimiter: Color,
+
+    // Per-scope overrides (see `Theme::scope_color`)
+    pub scopes: HashMap<String, Color>,
 }
 
 impl From<ThemeFile> for Theme {
@@ -1151,6 +1183,9 @@ impl From<ThemeFile> for Theme {
                 .unwrap_or_else(|| shade_toward_contrast(file.editor.bg.clone().into(), 10)),
             ruler_bg: file.editor.ruler_bg.into(),
             whitespace_indicator_fg: file.editor.whitespace_indicator_fg.into(),
+            trailing_whitespace_fg: file.editor.trailing_whitespace_fg.into(),
+            indent_guide_fg: file.editor.indent_guide_fg.into(),
+            indent_guide_active_fg: file.editor.indent_guide_active_fg.into(),
             diff_add_bg: file.editor.diff_add_bg.clone().into(),
             diff_remove_bg: file.editor.diff_remove_bg.clone().into(),
             diff_modify_bg: file.editor.diff_modify_bg.into(),
@@ -1316,6 +1351,11 @@ impl From<ThemeFile> for Theme {
             syntax_operator: file.syntax.operator.into(),
             syntax_punctuation_bracket: file.syntax.punctuation_bracket.into(),
             syntax_punctuation_delimiter: file.syntax.punctuation_delimiter.into(),
+            scopes: file
+                .scopes
+                .into_iter()
+                .map(|(scope, color)| (scope, color.into()))
+                .collect(),
         }
     }
 }
@@ -1340,6 +1380,9 @@ impl From<Theme> for ThemeFile {
                 diff_modify_bg: theme.diff_modify_bg.into(),
                 ruler_bg: theme.ruler_bg.into(),
                 whitespace_indicator_fg: theme.whitespace_indicator_fg.into(),
+                trailing_whitespace_fg: theme.trailing_whitespace_fg.into(),
+                indent_guide_fg: theme.indent_guide_fg.into(),
+                indent_guide_active_fg: theme.indent_guide_active_fg.into(),
                 after_eof_bg: Some(theme.after_eof_bg.into()),
             },
             ui: UiColors {
@@ -1447,6 +1490,11 @@ impl From<Theme> for ThemeFile {
                 punctuation_bracket: theme.syntax_punctuation_bracket.into(),
                 punctuation_delimiter: theme.syntax_punctuation_delimiter.into(),
             },
+            scopes: theme
+                .scopes
+                .into_iter()
+                .map(|(scope, color)| (scope, color.into()))
+                .collect(),
         }
     }
 }
@@ -1469,6 +1517,20 @@ impl Theme {
         }
     }
 
+    /// Resolve a highlight scope (a tree-sitter category such as
+    /// `keyword`, or an LSP semantic token type such as `macro`) to its
+    /// override color. A `language.scope` entry wins over a plain `scope`
+    /// one; `None` means the base theme color applies.
+    pub fn scope_color(&self, language: &str, scope: &str) -> Option<Color> {
+        if self.scopes.is_empty() {
+            return None;
+        }
+        self.scopes
+            .get(&format!("{}.{}", language, scope))
+            .or_else(|| self.scopes.get(scope))
+            .copied()
+    }
+
     /// Load a builtin theme by name (no I/O, uses embedded JSON).
     pub fn load_builtin(name: &str) -> Option<Self> {
         BUILTIN_THEMES
@@ -1519,6 +1581,9 @@ impl Theme {
                 "diff_modify_bg" => Some(self.diff_modify_bg),
                 "ruler_bg" => Some(self.ruler_bg),
                 "whitespace_indicator_fg" => Some(self.whitespace_indicator_fg),
+                "trailing_whitespace_fg" => Some(self.trailing_whitespace_fg),
+                "indent_guide_fg" => Some(self.indent_guide_fg),
+                "indent_guide_active_fg" => Some(self.indent_guide_active_fg),
                 _ => None,
             },
             "ui" => match field {
@@ -1620,6 +1685,9 @@ impl Theme {
                 "diff_modify_bg" => Some(&mut self.diff_modify_bg),
                 "ruler_bg" => Some(&mut self.ruler_bg),
                 "whitespace_indicator_fg" => Some(&mut self.whitespace_indicator_fg),
+                "trailing_whitespace_fg" => Some(&mut self.trailing_whitespace_fg),
+                "indent_guide_fg" => Some(&mut self.indent_guide_fg),
+                "indent_guide_active_fg" => Some(&mut self.indent_guide_active_fg),
                 _ => None,
             },
             "ui" => match field {
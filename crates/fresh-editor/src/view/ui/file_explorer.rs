@@ -110,9 +110,11 @@ impl FileExplorerRenderer {
             .map(|kb| format!(" ({})", kb))
             .unwrap_or_default();
 
-        // Show search query in title when search is active
+        // Show search or filter query in title when either is active
         let title = if search_active {
             format!(" /{} ", view.search_query())
+        } else if view.is_filter_active() {
+            format!(" Filter: {} ", view.filter_query())
         } else if let Some(host) = remote_connection {
             // Extract just the hostname from "user@host" or "user@host:port"
             let hostname = host
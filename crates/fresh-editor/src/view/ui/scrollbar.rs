@@ -236,6 +236,34 @@ impl ScrollbarMouse {
     }
 }
 
+/// Row ↔ document mapping for a scrollbar minimap. Each track row covers
+/// a fixed band of `units_per_row` units (lines, or bytes for buffers
+/// without a line index) counted from the top of the document, so short
+/// documents get one line per row and long ones are condensed to fit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MinimapScale {
+    pub units_per_row: usize,
+}
+
+impl MinimapScale {
+    /// Scale that fits `total_units` into a track of `track_height` rows.
+    pub fn new(total_units: usize, track_height: usize) -> Self {
+        Self {
+            units_per_row: total_units.div_ceil(track_height.max(1)).max(1),
+        }
+    }
+
+    /// Units covered by the track row `row`.
+    pub fn row_units(&self, row: usize) -> std::ops::Range<usize> {
+        row * self.units_per_row..(row + 1) * self.units_per_row
+    }
+
+    /// Track row that shows `unit`.
+    pub fn row_for_unit(&self, unit: usize) -> usize {
+        unit / self.units_per_row
+    }
+}
+
 /// Colors for the scrollbar
 #[derive(Debug, Clone, Copy)]
 pub struct ScrollbarColors {
@@ -620,4 +648,19 @@ mod tests {
         mouse.release();
         assert!(mouse.drag.is_none());
     }
+
+    #[test]
+    fn test_minimap_scale_condenses_long_documents() {
+        // Short documents map one line per row
+        let scale = MinimapScale::new(10, 20);
+        assert_eq!(scale.units_per_row, 1);
+        assert_eq!(scale.row_units(3), 3..4);
+
+        // Long documents round up so every line lands on the track
+        let scale = MinimapScale::new(1000, 30);
+        assert_eq!(scale.units_per_row, 34);
+        assert_eq!(scale.row_for_unit(999), 29);
+        assert_eq!(scale.row_units(2), 68..102);
+        assert_eq!(scale.row_for_unit(scale.row_units(2).start), 2);
+    }
 }
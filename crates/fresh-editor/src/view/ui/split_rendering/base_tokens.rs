@@ -7,6 +7,7 @@
 use super::MAX_SAFE_LINE_WIDTH;
 use crate::model::buffer::{Buffer, LineEnding};
 use fresh_core::api::{ViewTokenWire, ViewTokenWireKind};
+use fresh_core::hooks::ViewLineTokens;
 
 /// Build tokens from a text buffer starting at `top_byte`, stopping roughly
 /// after `visible_count` visual lines. Honors CRLF / LF line endings and
@@ -305,6 +306,46 @@ pub(super) fn build_base_tokens_binary(
     tokens
 }
 
+/// Group a flat base-token stream into per-source-line chunks for the
+/// `view_transform_request` hook. Each chunk ends with its `Newline` token
+/// (the last line may have none); injected `Break` tokens stay with the
+/// line they split. `line_start` falls back to `viewport_start` for the
+/// first line and to the byte after the previous newline otherwise.
+pub(crate) fn group_tokens_by_line(
+    tokens: &[ViewTokenWire],
+    viewport_start: usize,
+) -> Vec<ViewLineTokens> {
+    let mut lines: Vec<ViewLineTokens> = Vec::new();
+    let mut current: Vec<ViewTokenWire> = Vec::new();
+    let mut next_line_start = viewport_start;
+
+    for token in tokens {
+        if current.is_empty() {
+            if let Some(offset) = token.source_offset {
+                next_line_start = offset;
+            }
+        }
+        current.push(token.clone());
+        if let ViewTokenWireKind::Newline = token.kind {
+            let line_start = next_line_start;
+            next_line_start = token.source_offset.map_or(line_start, |o| o + 1);
+            lines.push(ViewLineTokens {
+                line_start,
+                tokens: std::mem::take(&mut current),
+            });
+        }
+    }
+
+    if !current.is_empty() {
+        lines.push(ViewLineTokens {
+            line_start: next_line_start,
+            tokens: current,
+        });
+    }
+
+    lines
+}
+
 /// Check if a byte should be displayed as `<XX>` in binary mode.
 /// Returns true for:
 /// - Control characters (0x00-0x1F) except tab and newline
@@ -328,7 +369,7 @@ fn is_binary_unprintable(b: u8) -> bool {
 
 /// Check if a character is a control character that should be rendered as `<XX>`.
 /// This applies to ALL files (binary and non-binary) to prevent terminal corruption.
-fn is_control_char(ch: char) -> bool {
+pub(crate) fn is_control_char(ch: char) -> bool {
     let code = ch as u32;
     if code >= 128 {
         return false;
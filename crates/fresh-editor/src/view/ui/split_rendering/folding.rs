@@ -201,7 +201,7 @@ pub(super) fn fold_indicators_for_viewport(
     } else {
         // Indent-based fold detection on viewport bytes — key by absolute byte offset
         use crate::view::folding::indent_folding;
-        let tab_size = state.buffer_settings.tab_size;
+        let indent_size = state.buffer_settings.indent_size();
         let max_lookahead = crate::config::INDENT_FOLD_INDICATOR_MAX_SCAN;
 
         for (i, view_line) in view_lines.iter().enumerate() {
@@ -216,7 +216,7 @@ pub(super) fn fold_indicators_for_viewport(
                     subsequent_lines.push(view_lines[j].text.as_bytes());
                 }
 
-                if indent_folding::is_line_foldable_in_bytes(&subsequent_lines, tab_size) {
+                if indent_folding::is_line_foldable_in_bytes(&subsequent_lines, indent_size) {
                     indicators
                         .entry(line_start_byte)
                         .or_insert(FoldIndicator { collapsed: false });
@@ -35,6 +35,8 @@ pub(super) struct LeftMarginContext<'a> {
     pub line_indicators: &'a BTreeMap<usize, LineIndicator>,
     pub fold_indicators: &'a BTreeMap<usize, FoldIndicator>,
     pub cursor_line_start_byte: usize,
+    /// The line holds a secondary cursor and gets the cursor-line highlight
+    pub on_secondary_cursor_line: bool,
     pub cursor_line_number: usize,
     pub relative_line_numbers: bool,
     pub show_line_numbers: bool,
@@ -56,7 +58,8 @@ pub(super) fn render_left_margin(
 
     let lookup_key = ctx.line_start_byte;
     // Pre-compute indicator bg for cursor line highlighting
-    let indicator_is_cursor_line = lookup_key.is_some_and(|k| k == ctx.cursor_line_start_byte);
+    let indicator_is_cursor_line =
+        ctx.on_secondary_cursor_line || lookup_key.is_some_and(|k| k == ctx.cursor_line_start_byte);
     let indicator_bg = if indicator_is_cursor_line && ctx.highlight_current_line && ctx.is_active {
         Some(ctx.theme.current_line_bg)
     } else {
@@ -108,7 +111,9 @@ pub(super) fn render_left_margin(
     }
 
     let is_cursor_line = lookup_key.is_some_and(|k| k == ctx.cursor_line_start_byte);
-    let use_cursor_line_bg = is_cursor_line && ctx.highlight_current_line && ctx.is_active;
+    let use_cursor_line_bg = (is_cursor_line || ctx.on_secondary_cursor_line)
+        && ctx.highlight_current_line
+        && ctx.is_active;
 
     if ctx.is_continuation {
         let blank = " ".repeat(ctx.state.margins.left_config.width);
@@ -0,0 +1,153 @@
+//! Indent guide placement for the split renderer.
+//!
+//! Guides are drawn on leading whitespace at every indent level (the
+//! buffer's indent size), so a line indented by two levels shows two guides
+//! whether the indentation is spaces or tabs (tabs are already expanded in
+//! the view text). The guide of the block around the cursor — the "active
+//! scope" — is found here so the render loop can give it its own color.
+//!
+//! This module has no dependency on any shared render-time "mega struct".
+
+use crate::view::ui::view_pipeline::{LineStart, ViewLine};
+use std::ops::RangeInclusive;
+
+/// The guide of the block containing the cursor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct ActiveGuide {
+    /// Visual column of the guide.
+    pub column: usize,
+    /// View line indices the guide spans.
+    pub lines: RangeInclusive<usize>,
+}
+
+impl ActiveGuide {
+    pub(super) fn covers(&self, view_line_idx: usize, column: usize) -> bool {
+        column == self.column && self.lines.contains(&view_line_idx)
+    }
+}
+
+/// Whether a leading-whitespace cell at visual `column` carries a guide.
+pub(super) fn is_guide_column(column: usize, indent_size: usize) -> bool {
+    indent_size > 0 && column % indent_size == 0
+}
+
+/// Visual width of a view line's indentation, or `None` for a line that is
+/// blank or only whitespace.
+fn indent_width(line: &ViewLine) -> Option<usize> {
+    line.text
+        .chars()
+        .position(|c| !c.is_whitespace())
+        .map(|idx| line.visual_col_at_char(idx))
+}
+
+/// Find the guide of the block containing view line `cursor_idx`: the
+/// deepest guide on the cursor's line, extended up and down over every line
+/// indented past it. Blank lines do not end a block, but are not part of
+/// it at its edges either.
+pub(super) fn active_guide(
+    view_lines: &[ViewLine],
+    cursor_idx: usize,
+    indent_size: usize,
+) -> Option<ActiveGuide> {
+    if indent_size == 0 {
+        return None;
+    }
+    // A wrapped continuation row belongs to the line it continues
+    let mut cursor_idx = cursor_idx;
+    while cursor_idx > 0 && view_lines.get(cursor_idx)?.line_start.is_continuation() {
+        cursor_idx -= 1;
+    }
+    let indent = indent_width(view_lines.get(cursor_idx)?)?;
+    if indent == 0 {
+        return None;
+    }
+    let column = (indent - 1) / indent_size * indent_size;
+
+    let in_block = |line: &ViewLine| {
+        line.line_start == LineStart::AfterBreak || indent_width(line).is_none_or(|w| w > column)
+    };
+    let is_blank = |line: &ViewLine| indent_width(line).is_none();
+
+    let mut first = cursor_idx;
+    while first > 0 && in_block(&view_lines[first - 1]) {
+        first -= 1;
+    }
+    let mut last = cursor_idx;
+    while last + 1 < view_lines.len() && in_block(&view_lines[last + 1]) {
+        last += 1;
+    }
+    while first < cursor_idx && is_blank(&view_lines[first]) {
+        first += 1;
+    }
+    while last > cursor_idx && is_blank(&view_lines[last]) {
+        last -= 1;
+    }
+    Some(ActiveGuide {
+        column,
+        lines: first..=last,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    fn line(text: &str) -> ViewLine {
+        ViewLine {
+            text: text.to_string(),
+            source_start_byte: None,
+            char_source_bytes: vec![None; text.chars().count()],
+            char_styles: vec![None; text.chars().count()],
+            char_visual_cols: (0..text.chars().count()).collect(),
+            visual_to_char: (0..text.chars().count()).collect(),
+            tab_starts: HashSet::new(),
+            line_start: LineStart::AfterSourceNewline,
+            ends_with_newline: true,
+        }
+    }
+
+    #[test]
+    fn guides_sit_on_tab_stops() {
+        assert!(is_guide_column(0, 4));
+        assert!(is_guide_column(8, 4));
+        assert!(!is_guide_column(2, 4));
+        assert!(!is_guide_column(0, 0));
+    }
+
+    #[test]
+    fn active_guide_spans_the_cursor_block() {
+        let lines: Vec<ViewLine> = [
+            "fn a() {",
+            "    if x {",
+            "        y();",
+            "",
+            "        z();",
+            "    }",
+            "}",
+        ]
+        .into_iter()
+        .map(line)
+        .collect();
+
+        // Inside the `if` body the guide at column 4 spans the whole body,
+        // across the blank line
+        assert_eq!(
+            active_guide(&lines, 2, 4),
+            Some(ActiveGuide {
+                column: 4,
+                lines: 2..=4
+            })
+        );
+        // On the `if` line itself the active block is the function body
+        assert_eq!(
+            active_guide(&lines, 1, 4),
+            Some(ActiveGuide {
+                column: 0,
+                lines: 1..=5
+            })
+        );
+        // Top-level lines have no guide
+        assert_eq!(active_guide(&lines, 0, 4), None);
+    }
+}
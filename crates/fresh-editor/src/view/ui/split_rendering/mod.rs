@@ -4,7 +4,7 @@
 //!
 //! - **Self-contained leaves** (`spans`, `style`, `char_style`, `base_tokens`,
 //!   `transforms`, `view_data`, `folding`, `scrollbar`, `layout`, `gutter`,
-//!   `post_pass`) — none of these depend on any shared render-time carrier.
+//!   `indent_guides`, `post_pass`) — none of these depend on any shared render-time carrier.
 //! - **Orchestration** (`orchestration::*`) — the only files that share
 //!   `SelectionContext` / `DecorationContext`. Quarantined in a subdirectory
 //!   so the coupling is visible from `ls` alone.
@@ -16,6 +16,7 @@ pub(crate) mod base_tokens;
 mod char_style;
 mod folding;
 mod gutter;
+mod indent_guides;
 mod layout;
 mod orchestration;
 mod post_pass;
@@ -87,6 +88,7 @@ impl SplitRenderer {
         software_cursor_only: bool,
         show_vertical_scrollbar: bool,
         show_horizontal_scrollbar: bool,
+        scrollbar_minimap: bool,
         diagnostics_inline_text: bool,
         show_tilde: bool,
         highlight_current_column: bool,
@@ -139,6 +141,7 @@ impl SplitRenderer {
             software_cursor_only,
             show_vertical_scrollbar,
             show_horizontal_scrollbar,
+            scrollbar_minimap,
             diagnostics_inline_text,
             show_tilde,
             highlight_current_column,
@@ -311,6 +314,7 @@ mod tests {
     use super::view_data::build_view_data;
     use super::*;
 
+    use crate::config::WrapMode;
     use crate::model::buffer::{Buffer, LineEnding};
     use crate::model::filesystem::StdFileSystem;
     use crate::primitives::display_width::str_width;
@@ -1535,7 +1539,8 @@ mod tests {
         ];
 
         // Apply wrapping with MAX_SAFE_LINE_WIDTH (simulating line_wrap disabled)
-        let wrapped = apply_wrapping_transform(tokens, MAX_SAFE_LINE_WIDTH, 0, false);
+        let wrapped =
+            apply_wrapping_transform(tokens, MAX_SAFE_LINE_WIDTH, 0, false, WrapMode::Word, 0);
 
         // Count Break tokens - should have at least 2 breaks for 25K chars at 10K width
         let break_count = wrapped
@@ -1564,6 +1569,52 @@ mod tests {
         );
     }
 
+    /// End-of-line virtual text that doesn't fit after the last word
+    /// moves the line terminator, and the text with it, to its own row.
+    #[test]
+    fn test_reflow_inline_virtual_text_moves_end_of_line_text() {
+        use super::transforms::reflow_inline_virtual_text;
+        use fresh_core::api::{ViewTokenWire, ViewTokenWireKind};
+
+        let token = |kind, offset| ViewTokenWire {
+            kind,
+            source_offset: Some(offset),
+            style: None,
+        };
+        // "hello world\n" fills 11 of 15 columns
+        let tokens = vec![
+            token(ViewTokenWireKind::Text("hello".to_string()), 0),
+            token(ViewTokenWireKind::Space, 5),
+            token(ViewTokenWireKind::Text("world".to_string()), 6),
+            token(ViewTokenWireKind::Newline, 11),
+        ];
+        let kinds = |tokens: &[ViewTokenWire]| -> Vec<String> {
+            tokens.iter().map(|t| format!("{:?}", t.kind)).collect()
+        };
+
+        // Four columns after the text: fits
+        let widths = std::collections::BTreeMap::from([(11, 4)]);
+        let reflowed = reflow_inline_virtual_text(tokens.clone(), &widths, 15, 0);
+        assert_eq!(kinds(&reflowed), kinds(&tokens));
+
+        // Seven columns: the terminator moves to a row of its own
+        let widths = std::collections::BTreeMap::from([(11, 7)]);
+        let reflowed = reflow_inline_virtual_text(tokens, &widths, 15, 0);
+        assert!(matches!(reflowed[3].kind, ViewTokenWireKind::Break));
+        assert!(matches!(reflowed[4].kind, ViewTokenWireKind::Newline));
+
+        // Inline text inside a word carries the word to the next row
+        let tokens = vec![
+            token(ViewTokenWireKind::Text("hello".to_string()), 0),
+            token(ViewTokenWireKind::Space, 5),
+            token(ViewTokenWireKind::Text("world".to_string()), 6),
+        ];
+        let widths = std::collections::BTreeMap::from([(8, 6)]);
+        let reflowed = reflow_inline_virtual_text(tokens, &widths, 15, 0);
+        assert!(matches!(reflowed[2].kind, ViewTokenWireKind::Break));
+        assert_eq!(reflowed[3].source_offset, Some(6));
+    }
+
     /// Property test encoding the wrap-boundary invariant that the
     /// char-split path of [`apply_wrapping_transform`] must satisfy.
     ///
@@ -1592,6 +1643,7 @@ mod tests {
     #[cfg(test)]
     mod wrap_boundary_property {
         use super::apply_wrapping_transform;
+        use crate::config::WrapMode;
         use fresh_core::api::{ViewTokenWire, ViewTokenWireKind};
         use proptest::prelude::*;
         use unicode_segmentation::UnicodeSegmentation;
@@ -1669,6 +1721,30 @@ mod tests {
             rows
         }
 
+        #[test]
+        fn test_wrap_mode_word_breaks_at_space_char_at_column() {
+            let input = "Lorem ipsum dolor sit amet consectetur";
+            let wrap = |mode| {
+                visual_rows(&apply_wrapping_transform(
+                    tokens_from_input(input),
+                    20,
+                    0,
+                    false,
+                    mode,
+                    0,
+                ))
+            };
+
+            assert_eq!(
+                wrap(WrapMode::Word),
+                vec!["Lorem ipsum dolor ", "sit amet consectetur"]
+            );
+            assert_eq!(
+                wrap(WrapMode::Char),
+                vec!["Lorem ipsum dolor si", "t amet consectetur"]
+            );
+        }
+
         proptest! {
             // A handful of cases per run is plenty — wrapping is
             // deterministic, but the input space is large and we want
@@ -1688,7 +1764,7 @@ mod tests {
                 // Hanging indent off and gutter 0 — we want to isolate
                 // the Text char-split logic from the indent path.
                 let tokens = tokens_from_input(&input);
-                let wrapped = apply_wrapping_transform(tokens, content_width, 0, false);
+                let wrapped = apply_wrapping_transform(tokens, content_width, 0, false, WrapMode::Word, 0);
                 let rows = visual_rows(&wrapped);
 
                 // Invariant 1: no row exceeds content_width.
@@ -1833,7 +1909,8 @@ mod tests {
         ];
 
         // Apply wrapping with MAX_SAFE_LINE_WIDTH (simulating line_wrap disabled)
-        let wrapped = apply_wrapping_transform(tokens, MAX_SAFE_LINE_WIDTH, 0, false);
+        let wrapped =
+            apply_wrapping_transform(tokens, MAX_SAFE_LINE_WIDTH, 0, false, WrapMode::Word, 0);
 
         // Should have no Break tokens for short lines
         let break_count = wrapped
@@ -1890,7 +1967,8 @@ mod tests {
         ];
 
         // Apply safety wrapping (simulating line_wrap=false with MAX_SAFE_LINE_WIDTH)
-        let wrapped = apply_wrapping_transform(tokens, MAX_SAFE_LINE_WIDTH, 0, false);
+        let wrapped =
+            apply_wrapping_transform(tokens, MAX_SAFE_LINE_WIDTH, 0, false, WrapMode::Word, 0);
 
         // Convert to ViewLines
         let view_lines: Vec<_> = ViewLineIterator::new(&wrapped, false, false, 4, false).collect();
@@ -2310,7 +2388,7 @@ mod tests {
                 source_offset: Some(0),
                 style: None,
             }];
-            let wrapped = apply_wrapping_transform(tokens, wrap_width, 0, false);
+            let wrapped = apply_wrapping_transform(tokens, wrap_width, 0, false, WrapMode::Word, 0);
 
             // Reconstruct the chunks the transform emitted by walking
             // its output: each Text token is one chunk; Break tokens
@@ -23,7 +23,7 @@ use super::layout::{
 };
 use super::scrollbar::{
     compute_max_line_length, render_composite_scrollbar, render_horizontal_scrollbar,
-    render_scrollbar, scrollbar_line_counts,
+    render_minimap_scrollbar, render_scrollbar, scrollbar_line_counts,
 };
 use crate::app::types::ViewLineMapping;
 use crate::app::BufferMetadata;
@@ -84,6 +84,7 @@ pub(crate) fn render_content(
     software_cursor_only: bool,
     show_vertical_scrollbar: bool,
     show_horizontal_scrollbar: bool,
+    scrollbar_minimap: bool,
     diagnostics_inline_text: bool,
     show_tilde: bool,
     highlight_current_column: bool,
@@ -619,21 +620,31 @@ pub(crate) fn render_content(
             };
 
             // Render vertical scrollbar for this split and get thumb position
-            let (thumb_start, thumb_end) = if show_vertical_scrollbar && !is_non_scrollable {
-                render_scrollbar(
-                    frame,
-                    state,
-                    &viewport,
-                    layout.scrollbar_rect,
-                    is_active,
-                    theme,
-                    large_file_threshold_bytes,
-                    total_lines,
-                    top_line,
-                )
-            } else {
-                (0, 0)
-            };
+            let (thumb_start, thumb_end) =
+                if show_vertical_scrollbar && !is_non_scrollable && scrollbar_minimap {
+                    render_minimap_scrollbar(
+                        frame,
+                        state,
+                        &viewport,
+                        layout.scrollbar_rect,
+                        theme,
+                        large_file_threshold_bytes,
+                    )
+                } else if show_vertical_scrollbar && !is_non_scrollable {
+                    render_scrollbar(
+                        frame,
+                        state,
+                        &viewport,
+                        layout.scrollbar_rect,
+                        is_active,
+                        theme,
+                        large_file_threshold_bytes,
+                        total_lines,
+                        top_line,
+                    )
+                } else {
+                    (0, 0)
+                };
 
             // Compute the actual max line length for horizontal scrollbar
             let max_content_width = if show_horizontal_scrollbar && !viewport.line_wrap_enabled {
@@ -106,7 +106,7 @@ pub(crate) fn decoration_context(
         .saturating_add(viewport_size)
         .min(state.buffer.len());
 
-    let highlight_spans = state.highlighter.highlight_viewport(
+    let mut highlight_spans = state.highlighter.highlight_viewport(
         &state.buffer,
         highlight_start,
         highlight_end,
@@ -114,6 +114,19 @@ pub(crate) fn decoration_context(
         highlight_context_bytes,
     );
 
+    // Theme scope overrides replace the category color. Semantic token
+    // spans (built below) still win over these where both apply.
+    if !theme.scopes.is_empty() {
+        for span in &mut highlight_spans {
+            let scope_color = span
+                .category
+                .and_then(|category| theme.scope_color(&state.language, category.scope_name()));
+            if let Some(color) = scope_color {
+                span.color = color;
+            }
+        }
+    }
+
     // Update reference highlight overlays (debounced; creates overlays that
     // auto-adjust).
     state.reference_highlight_overlay.update(
@@ -128,7 +141,22 @@ pub(crate) fn decoration_context(
         theme.semantic_highlight_bg,
     );
 
-    // Update bracket highlight overlays.
+    // Update bracket highlight overlays, ignoring brackets inside strings and
+    // comments where the highlighter knows about them.
+    use crate::primitives::highlight_types::HighlightCategory;
+    let string_and_comment_ranges = highlight_spans
+        .iter()
+        .filter(|span| {
+            matches!(
+                span.category,
+                Some(HighlightCategory::String | HighlightCategory::Comment)
+            )
+        })
+        .map(|span| span.range.clone())
+        .collect();
+    state
+        .bracket_highlight_overlay
+        .set_skipped_ranges(string_and_comment_ranges);
     state.bracket_highlight_overlay.update(
         &state.buffer,
         &mut state.overlays,
@@ -223,7 +251,9 @@ pub(crate) fn decoration_context(
 
     let virtual_text_lookup: HashMap<usize, Vec<crate::view::virtual_text::VirtualText>> = state
         .virtual_texts
-        .build_lookup(&state.marker_list, viewport_start, viewport_end)
+        // Inclusive end: end-of-line text on a last line without a
+        // terminator is anchored at the buffer end
+        .build_lookup(&state.marker_list, viewport_start, viewport_end + 1)
         .into_iter()
         .map(|(position, texts)| (position, texts.into_iter().cloned().collect()))
         .collect();
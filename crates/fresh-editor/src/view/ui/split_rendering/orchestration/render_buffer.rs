@@ -501,7 +501,7 @@ pub(crate) fn draw_buffer_in_split(
             render_area,
             gutter_width,
             layout_output.render_output.content_lines_rendered,
-            0,
+            layout_output.left_column,
         );
     }
 
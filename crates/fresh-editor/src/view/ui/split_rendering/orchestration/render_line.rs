@@ -9,6 +9,7 @@
 
 use super::super::char_style::{compute_char_style, CharStyleContext, CharStyleOutput};
 use super::super::gutter::{render_left_margin, LeftMarginContext};
+use super::super::indent_guides;
 use super::super::layout::ViewAnchor;
 use super::super::spans::{
     push_debug_tag, push_span_with_map, span_color_at, span_info_at, DebugSpanTracker,
@@ -28,6 +29,7 @@ use ratatui::layout::Rect;
 use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use std::collections::HashSet;
+use std::ops::Range;
 
 pub(crate) struct LineRenderOutput {
     pub lines: Vec<Line<'static>>,
@@ -158,6 +160,42 @@ pub(crate) fn render_view_lines(input: LineRenderInput<'_>) -> LineRenderOutput
     let cursor_line_end_byte =
         indent_folding::find_line_end_byte(&state.buffer, primary_cursor_position);
 
+    // Lines of the other cursors, highlighted like the primary cursor's line
+    // when `highlight_all_cursor_lines` is on.
+    let secondary_cursor_lines: Vec<Range<usize>> =
+        if highlight_current_line && state.buffer_settings.highlight_all_cursor_lines {
+            cursor_positions
+                .iter()
+                .filter(|&&pos| pos != primary_cursor_position)
+                .map(|&pos| {
+                    indent_folding::find_line_start_byte(&state.buffer, pos)
+                        ..indent_folding::find_line_end_byte(&state.buffer, pos)
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+    // Indent guides: the guide of the block around the cursor gets its own
+    // color, so locate that block once for the whole viewport.
+    let indent_guides_enabled = state.buffer_settings.indent_guides;
+    let indent_size = state.buffer_settings.indent_size();
+    let active_guide = if indent_guides_enabled {
+        view_lines
+            .iter()
+            .position(|line| {
+                !line.line_start.is_continuation()
+                    && line
+                        .char_source_bytes
+                        .iter()
+                        .find_map(|b| *b)
+                        .is_some_and(|b| b >= cursor_line_start_byte && b < cursor_line_end_byte)
+            })
+            .and_then(|idx| indent_guides::active_guide(view_lines, idx, indent_size))
+    } else {
+        None
+    };
+
     let highlight_spans = &decorations.highlight_spans;
     let semantic_token_spans = &decorations.semantic_token_spans;
     let viewport_overlays = &decorations.viewport_overlays;
@@ -274,6 +312,19 @@ pub(crate) fn render_view_lines(input: LineRenderInput<'_>) -> LineRenderOutput
                     last = Some(i);
                 }
             }
+            // Under line wrap a view line is only one segment of its source
+            // line. Whitespace at the start of a continuation row or at the
+            // end of a row that continues below is interior to the source
+            // line, so classify it as inner rather than leading/trailing.
+            if matches!(current_view_line.line_start, LineStart::AfterBreak) {
+                first = Some(0);
+            }
+            if view_lines
+                .get(view_iter_idx + 1)
+                .is_some_and(|next| matches!(next.line_start, LineStart::AfterBreak))
+            {
+                last = Some(usize::MAX);
+            }
             (first, last)
         };
 
@@ -283,6 +334,7 @@ pub(crate) fn render_view_lines(input: LineRenderInput<'_>) -> LineRenderOutput
             line_char_source_bytes.get(char_idx).copied().flatten()
         };
 
+        let view_line_idx = view_iter_idx;
         view_iter_idx += 1;
 
         if lines_rendered >= visible_line_count {
@@ -322,9 +374,13 @@ pub(crate) fn render_view_lines(input: LineRenderInput<'_>) -> LineRenderOutput
         // so plugin-injected soft-break sub-rows — whose first source byte lands
         // mid-line — are still recognised as belonging to the cursor's logical
         // line (issue #1790).
+        let on_secondary_cursor_line = !is_continuation
+            && line_start_byte
+                .is_some_and(|b| secondary_cursor_lines.iter().any(|r| r.contains(&b)));
         if !is_continuation {
-            is_on_cursor_line = line_start_byte
-                .is_some_and(|b| b >= cursor_line_start_byte && b < cursor_line_end_byte);
+            is_on_cursor_line = on_secondary_cursor_line
+                || line_start_byte
+                    .is_some_and(|b| b >= cursor_line_start_byte && b < cursor_line_end_byte);
         }
 
         // Gutter display number — line number for small files, byte offset for large files
@@ -373,6 +429,7 @@ pub(crate) fn render_view_lines(input: LineRenderInput<'_>) -> LineRenderOutput
                 line_indicators,
                 fold_indicators: &decorations.fold_indicators,
                 cursor_line_start_byte,
+                on_secondary_cursor_line,
                 cursor_line_number: state.primary_cursor_line_number.value(),
                 relative_line_numbers,
                 show_line_numbers,
@@ -384,6 +441,10 @@ pub(crate) fn render_view_lines(input: LineRenderInput<'_>) -> LineRenderOutput
             &mut line_view_map,
         );
 
+        // Whether this line's newline is selected, i.e. a selection carries
+        // on to the next line and should paint the rest of this row
+        let mut newline_selected = false;
+
         // Check if this line has any selected text
         let mut byte_index = 0; // Byte offset in line_content string
         let mut display_char_idx = 0usize; // Character index in text (for char_source_bytes)
@@ -600,6 +661,9 @@ pub(crate) fn render_view_lines(input: LineRenderInput<'_>) -> LineRenderOutput
                             .take_while(|r| r.start <= bp)
                             .any(|r| r.end > bp)
                     }) || is_in_block_selection);
+                if ch == '\n' && is_selected {
+                    newline_selected = true;
+                }
 
                 // Compute character style using helper function
                 // char_styles is indexed by character position, not visual column
@@ -713,6 +777,15 @@ pub(crate) fn render_view_lines(input: LineRenderInput<'_>) -> LineRenderOutput
                     }
                 };
 
+                // Indent guide: a leading space (tabs are expanded to spaces)
+                // on a tab stop. Wrapped continuation rows are skipped: their
+                // leading spaces are the hanging indent, not indentation.
+                let is_indent_guide = indent_guides_enabled
+                    && !current_view_line.line_start.is_continuation()
+                    && ch == ' '
+                    && first_non_ws_idx.is_none_or(|first| display_char_idx < first)
+                    && indent_guides::is_guide_column(col_offset, indent_size);
+
                 let display_char: &str = if is_cursor && lsp_waiting && is_active {
                     "⋯"
                 } else if debug_tracker.is_some() && ch == '\r' {
@@ -723,6 +796,8 @@ pub(crate) fn render_view_lines(input: LineRenderInput<'_>) -> LineRenderOutput
                     "\\n"
                 } else if ch == '\n' {
                     ""
+                } else if is_indent_guide {
+                    '│'.encode_utf8(&mut indicator_buf)
                 } else if ws_show_tab {
                     // Visual indicator for tab: show → at the first position
                     is_whitespace_indicator = true;
@@ -735,9 +810,27 @@ pub(crate) fn render_view_lines(input: LineRenderInput<'_>) -> LineRenderOutput
                     ch.encode_utf8(&mut indicator_buf)
                 };
 
-                // Apply subdued whitespace indicator color from theme
+                // Apply subdued whitespace indicator color from theme; trailing
+                // whitespace gets its own color so it stands out
                 if is_whitespace_indicator && !is_cursor && !is_selected {
-                    style = style.fg(theme.whitespace_indicator_fg);
+                    // A whitespace-only line has no non-whitespace char, so all of
+                    // it is trailing
+                    let is_trailing = last_non_ws_idx.is_none_or(|last| display_char_idx > last);
+                    style = style.fg(if is_trailing {
+                        theme.trailing_whitespace_fg
+                    } else {
+                        theme.whitespace_indicator_fg
+                    });
+                }
+                if is_indent_guide && !is_cursor && !is_selected {
+                    let is_active_guide = active_guide
+                        .as_ref()
+                        .is_some_and(|guide| guide.covers(view_line_idx, col_offset));
+                    style = style.fg(if is_active_guide {
+                        theme.indent_guide_active_fg
+                    } else {
+                        theme.indent_guide_fg
+                    });
                 }
 
                 if let Some(bp) = byte_pos {
@@ -821,10 +914,14 @@ pub(crate) fn render_view_lines(input: LineRenderInput<'_>) -> LineRenderOutput
 
                 if let Some(bp) = byte_pos {
                     if let Some(vtexts) = virtual_text_lookup.get(&bp) {
-                        for vtext in vtexts
-                            .iter()
-                            .filter(|v| v.position == VirtualTextPosition::AfterChar)
-                        {
+                        // End-of-line text is anchored on the line
+                        // terminator, so it follows the line's content
+                        for vtext in vtexts.iter().filter(|v| {
+                            matches!(
+                                v.position,
+                                VirtualTextPosition::AfterChar | VirtualTextPosition::EndOfLine
+                            )
+                        }) {
                             // Flush the accumulated text so the virtual
                             // text is placed *after* the current char
                             // rather than sneaking in front of the next
@@ -991,6 +1088,32 @@ pub(crate) fn render_view_lines(input: LineRenderInput<'_>) -> LineRenderOutput
                     );
                 }
             }
+
+            // A last line without a terminator has its end-of-line text
+            // anchored at the buffer end, which no character maps to
+            let buffer_len = state.buffer.len();
+            let reaches_buffer_end = after_last_char_buf_pos.is_none()
+                && line_content
+                    .chars()
+                    .last()
+                    .zip(last_char_buf_pos)
+                    .is_some_and(|(c, bp)| bp + c.len_utf8() == buffer_len);
+            if reaches_buffer_end {
+                if let Some(vtexts) = virtual_text_lookup.get(&buffer_len) {
+                    for vtext in vtexts
+                        .iter()
+                        .filter(|v| v.position == VirtualTextPosition::EndOfLine)
+                    {
+                        push_span_with_map(
+                            &mut line_spans,
+                            &mut line_view_map,
+                            format!(" {}", vtext.text),
+                            vtext.resolved_style(theme),
+                            None,
+                        );
+                    }
+                }
+            }
         }
 
         // ViewLines are already wrapped (Break tokens became newlines in ViewLineIterator)
@@ -1183,6 +1306,13 @@ pub(crate) fn render_view_lines(input: LineRenderInput<'_>) -> LineRenderOutput
                     Some(ratatui::style::Style::default().fg(bg).bg(bg))
                 });
 
+                // A selection running past the line end wins over overlays
+                let fill_style = if newline_selected {
+                    Some(Style::default().bg(theme.selection_bg))
+                } else {
+                    fill_style
+                };
+
                 if let Some(fill_bg) = fill_style {
                     let fill_text = " ".repeat(remaining_cols);
                     // Source byte for the fill cells. Virtual lines
@@ -4,8 +4,13 @@
 //! parameters. They have no dependency on any shared render-time "mega
 //! struct".
 
+use crate::services::lsp::diagnostics::{
+    lsp_diagnostic_namespace, ERROR_PRIORITY, WARNING_PRIORITY,
+};
 use crate::state::EditorState;
+use crate::view::overlay::OverlayNamespace;
 use crate::view::theme::Theme;
+use crate::view::ui::scrollbar::MinimapScale;
 use crate::view::viewport::Viewport;
 use ratatui::layout::Rect;
 use ratatui::style::Style;
@@ -74,12 +79,16 @@ pub(super) fn scrollbar_visual_row_counts(
         gutter_width,
         true,
         viewport.wrap_indent,
+        viewport.wrap_mode,
+        viewport.wrap_indent_extra,
     );
     let effective_width = wrap_config
         .first_line_width
         .saturating_add(gutter_width)
         .max(2);
     let hanging_indent = wrap_config.hanging_indent;
+    let wrap_mode = wrap_config.wrap_mode;
+    let wrap_indent_extra = wrap_config.wrap_indent_extra;
     let pipeline_inputs_ver = pipeline_inputs_version(
         state.buffer.version(),
         state.soft_breaks.version(),
@@ -94,6 +103,8 @@ pub(super) fn scrollbar_visual_row_counts(
         gutter_width: gutter_width as u16,
         wrap_column: None,
         hanging_indent,
+        wrap_mode,
+        wrap_indent_extra,
         line_wrap_enabled: viewport.line_wrap_enabled,
     };
     ensure_built(state, &key);
@@ -222,6 +233,152 @@ pub(super) fn render_scrollbar(
     (thumb_start, thumb_end)
 }
 
+/// Lines sampled per minimap row when measuring its density.
+const MINIMAP_LINE_SAMPLES: usize = 4;
+
+/// Bytes read for a minimap row's density sample in large files.
+const MINIMAP_BYTE_SAMPLE: usize = 256;
+
+/// Colored marks on the minimap, lowest precedence first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum MinimapTick {
+    SearchMatch,
+    Warning,
+    Error,
+}
+
+/// Render the vertical scrollbar as a minimap: each row condenses a band
+/// of lines into a density glyph, with diagnostics and search matches
+/// drawn as colored ticks and the visible range highlighted.
+///
+/// Small files map logical lines onto rows. Large files have no line
+/// index, so rows map byte ranges and density is read from one sample
+/// per row instead of every line.
+/// Returns (thumb_start, thumb_end) covering the visible range.
+pub(super) fn render_minimap_scrollbar(
+    frame: &mut Frame,
+    state: &mut EditorState,
+    viewport: &Viewport,
+    scrollbar_rect: Rect,
+    theme: &Theme,
+    large_file_threshold_bytes: u64,
+) -> (usize, usize) {
+    let height = scrollbar_rect.height as usize;
+    if height == 0 {
+        return (0, 0);
+    }
+
+    let buffer_len = state.buffer.len();
+    let by_bytes = buffer_len > large_file_threshold_bytes as usize;
+    let last_byte = buffer_len.saturating_sub(1);
+    let unit_of = |state: &EditorState, byte: usize| {
+        if by_bytes {
+            byte.min(last_byte)
+        } else {
+            state.buffer.get_line_number(byte.min(last_byte))
+        }
+    };
+    let total_units = if buffer_len == 0 {
+        1
+    } else if by_bytes {
+        buffer_len
+    } else {
+        unit_of(state, last_byte) + 1
+    };
+    let scale = MinimapScale::new(total_units, height);
+
+    let top_unit = unit_of(state, viewport.top_byte);
+    let thumb_start = scale.row_for_unit(top_unit).min(height - 1);
+    let thumb_end = if by_bytes {
+        thumb_start + 1
+    } else {
+        let bottom_unit = top_unit + (viewport.height as usize).max(1) - 1;
+        (scale.row_for_unit(bottom_unit.min(total_units - 1)) + 1).min(height)
+    };
+
+    let diagnostic_ns = lsp_diagnostic_namespace();
+    let search_ns = OverlayNamespace::from_string("search".to_string());
+    let mut ticks: Vec<Option<MinimapTick>> = vec![None; height];
+    for overlay in state.overlays.all() {
+        let tick = match &overlay.namespace {
+            Some(ns) if *ns == diagnostic_ns => match overlay.priority {
+                ERROR_PRIORITY => MinimapTick::Error,
+                WARNING_PRIORITY => MinimapTick::Warning,
+                _ => continue,
+            },
+            Some(ns) if *ns == search_ns => MinimapTick::SearchMatch,
+            _ => continue,
+        };
+        let start = overlay.range(&state.marker_list).start;
+        let row = scale.row_for_unit(unit_of(state, start));
+        if let Some(slot) = ticks.get_mut(row) {
+            *slot = (*slot).max(Some(tick));
+        }
+    }
+
+    for (row, tick) in ticks.into_iter().enumerate() {
+        let units = scale.row_units(row);
+        let (glyph, fg) = match tick {
+            Some(MinimapTick::Error) => ('■', theme.diagnostic_error_fg),
+            Some(MinimapTick::Warning) => ('■', theme.diagnostic_warning_fg),
+            Some(MinimapTick::SearchMatch) => ('■', theme.search_match_bg),
+            None if units.start >= total_units || buffer_len == 0 => (' ', theme.editor_fg),
+            None => {
+                let filled = minimap_density(state, units, total_units, by_bytes);
+                (density_glyph(filled), theme.editor_fg)
+            }
+        };
+        let bg = if row >= thumb_start && row < thumb_end {
+            theme.scrollbar_thumb_fg
+        } else {
+            theme.scrollbar_track_fg
+        };
+
+        let cell_area = Rect::new(scrollbar_rect.x, scrollbar_rect.y + row as u16, 1, 1);
+        let paragraph = Paragraph::new(glyph.to_string()).style(Style::default().fg(fg).bg(bg));
+        frame.render_widget(paragraph, cell_area);
+    }
+
+    (thumb_start, thumb_end)
+}
+
+/// Average count of non-whitespace bytes per sampled line in `units`.
+fn minimap_density(
+    state: &mut EditorState,
+    units: std::ops::Range<usize>,
+    total_units: usize,
+    by_bytes: bool,
+) -> usize {
+    let end = units.end.min(total_units);
+    if by_bytes {
+        let sample_end = end.min(units.start + MINIMAP_BYTE_SAMPLE);
+        let text = state.get_text_range(units.start, sample_end);
+        let line = text.split('\n').find(|l| !l.is_empty()).unwrap_or_default();
+        return line.bytes().filter(|b| !b.is_ascii_whitespace()).count();
+    }
+
+    let step = ((end - units.start) / MINIMAP_LINE_SAMPLES).max(1);
+    let mut filled = 0;
+    let mut samples = 0;
+    for line in (units.start..end).step_by(step).take(MINIMAP_LINE_SAMPLES) {
+        if let Some(bytes) = state.buffer.get_line(line) {
+            filled += bytes.iter().filter(|b| !b.is_ascii_whitespace()).count();
+            samples += 1;
+        }
+    }
+    filled / samples.max(1)
+}
+
+/// Shade glyph for a minimap row with `filled` non-whitespace bytes per line.
+fn density_glyph(filled: usize) -> char {
+    match filled {
+        0 => ' ',
+        1..=15 => '░',
+        16..=47 => '▒',
+        _ => '▓',
+    }
+}
+
 /// Render a horizontal scrollbar for a split.
 /// `max_content_width` should be the actual max line length
 /// (from [`compute_max_line_length`]).
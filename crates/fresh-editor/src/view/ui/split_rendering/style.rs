@@ -4,6 +4,7 @@
 
 use crate::primitives::display_width::char_width;
 use crate::primitives::visual_layout::wrap_str_to_width;
+use crate::services::lsp::diagnostics::{ERROR_PRIORITY, INFO_PRIORITY, WARNING_PRIORITY};
 use crate::view::theme::{color_to_rgb, Theme};
 use crate::view::ui::view_pipeline::{LineStart, ViewLine};
 use fresh_core::api::ViewTokenStyle;
@@ -11,12 +12,11 @@ use ratatui::style::{Color, Modifier, Style};
 use std::collections::HashSet;
 
 /// Style for inline diagnostic text, selected from overlay priority (severity).
-/// Priority values: 100=error, 50=warning, 30=info, 10=hint.
 pub(super) fn inline_diagnostic_style(priority: i32, theme: &Theme) -> Style {
     match priority {
-        100 => Style::default().fg(theme.diagnostic_error_fg),
-        50 => Style::default().fg(theme.diagnostic_warning_fg),
-        30 => Style::default().fg(theme.diagnostic_info_fg),
+        ERROR_PRIORITY => Style::default().fg(theme.diagnostic_error_fg),
+        WARNING_PRIORITY => Style::default().fg(theme.diagnostic_warning_fg),
+        INFO_PRIORITY => Style::default().fg(theme.diagnostic_info_fg),
         _ => Style::default().fg(theme.diagnostic_hint_fg),
     }
 }
@@ -1,7 +1,9 @@
 //! Token / line stream transforms used by the view pipeline.
 //!
-//! This module contains four independent passes:
+//! This module contains five independent passes:
 //! - `apply_wrapping_transform` — hard + soft wrap by display width
+//! - `reflow_inline_virtual_text` — extra wrap breaks so inline virtual
+//!   text fits on its row
 //! - `apply_soft_breaks` — inject breaks at plugin-requested positions
 //! - `apply_conceal_ranges` — conceal or replace byte ranges in Text tokens
 //! - `inject_virtual_lines` — inject `LineAbove` / `LineBelow` virtual text
@@ -9,13 +11,14 @@
 //! None of these depend on any shared render-time "mega struct".
 
 use super::style::create_wrapped_virtual_lines;
+use crate::config::WrapMode;
 use crate::primitives::{ansi, display_width, visual_layout};
 use crate::state::EditorState;
 use crate::view::theme::Theme;
 use crate::view::ui::view_pipeline::ViewLine;
 use crate::view::virtual_text::VirtualTextPosition;
 use fresh_core::api::{ViewTokenWire, ViewTokenWireKind};
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 
 /// Wrap tokens to fit within `content_width` columns (accounting for a
 /// leading gutter on the first visual line). Emits `Break` tokens where
@@ -38,6 +41,12 @@ use std::collections::HashSet;
 ///      post-condition, that no row is ever emitted wider than
 ///      `eff_width`.
 ///
+/// With `hanging_indent`, continuation rows are indented to the line's
+/// leading whitespace plus `wrap_indent_extra` columns.
+///
+/// With [`WrapMode::Char`] steps 1 and 3 are skipped: every row is
+/// filled to `eff_width` and breaks land wherever the column runs out.
+///
 /// The grapheme-split + word-boundary algorithm in step 3/4 mirrors the
 /// standalone [`crate::primitives::visual_layout::wrap_str_to_width`]
 /// helper — used by the virtual-line path
@@ -53,6 +62,8 @@ pub(crate) fn apply_wrapping_transform(
     content_width: usize,
     gutter_width: usize,
     hanging_indent: bool,
+    wrap_mode: WrapMode,
+    wrap_indent_extra: usize,
 ) -> Vec<ViewTokenWire> {
     use visual_layout::visual_width;
     // Single source of truth for the lookback window — keeps the
@@ -152,7 +163,9 @@ pub(crate) fn apply_wrapping_transform(
                     if ws_char_count == text.chars().count() {
                         line_indent += ws_visual_width;
                     } else {
-                        line_indent += ws_visual_width;
+                        // The first content character fixes the indent;
+                        // continuation rows sit `wrap_indent_extra` past it
+                        line_indent += ws_visual_width + wrap_indent_extra;
                         measuring_indent = false;
                     }
                     if line_indent + MIN_CONTINUATION_CONTENT_WIDTH > available_width {
@@ -180,9 +193,13 @@ pub(crate) fn apply_wrapping_transform(
                 //       reach `eff_width`.  When the row is still below
                 //       the floor, don't break: the grapheme-split path
                 //       below will fill the remaining columns.
+                // Char mode leaves splitting to that path, except for ANSI
+                // text which it can't split.
                 let fresh_line_capacity = eff_width.saturating_sub(line_indent);
                 let row_floor = eff_width.saturating_sub(MAX_LOOKBACK).max(eff_width / 2);
-                if current_line_width > 0
+                let word_wrap = wrap_mode == WrapMode::Word || ansi::contains_ansi_codes(text);
+                if word_wrap
+                    && current_line_width > 0
                     && current_line_width + text_visual_width > eff_width
                     && (text_visual_width <= fresh_line_capacity || current_line_width >= row_floor)
                 {
@@ -309,7 +326,7 @@ pub(crate) fn apply_wrapping_transform(
                         // we freed and undo the shrink.  Falls back to
                         // the hard cap when no boundary qualifies.
                         let mut force_break_after_push = false;
-                        if chunk_grapheme_count > 1 {
+                        if chunk_grapheme_count > 1 && wrap_mode == WrapMode::Word {
                             let slice_start = graphemes[grapheme_idx].0;
                             let slice_end_hard =
                                 if grapheme_idx + chunk_grapheme_count < graphemes.len() {
@@ -498,6 +515,76 @@ pub(crate) fn apply_wrapping_transform(
     wrapped
 }
 
+/// Insert wrap breaks so inline virtual text fits on the row it is drawn on.
+///
+/// `apply_wrapping_transform` only measures buffer text, so a row it fills
+/// up would overflow once the renderer draws inline virtual text into it.
+/// `widths` maps a byte offset to the columns of the virtual text drawn at
+/// it (end-of-line text sits on the line terminator). A token that no
+/// longer fits once that text is counted moves to a fresh row, taking the
+/// text with it. Rows are only ever split, so no row gets wider than the
+/// wrapping transform made it, apart from a single token whose virtual
+/// text alone exceeds the width.
+pub(crate) fn reflow_inline_virtual_text(
+    tokens: Vec<ViewTokenWire>,
+    widths: &BTreeMap<usize, usize>,
+    content_width: usize,
+    gutter_width: usize,
+) -> Vec<ViewTokenWire> {
+    let available_width = content_width.saturating_sub(gutter_width);
+    if widths.is_empty() || available_width < 2 {
+        return tokens;
+    }
+
+    let extra_width = |token: &ViewTokenWire, len: usize| -> usize {
+        token
+            .source_offset
+            .map(|start| {
+                widths
+                    .range(start..start + len.max(1))
+                    .map(|(_, w)| w)
+                    .sum()
+            })
+            .unwrap_or(0)
+    };
+
+    let mut output = Vec::with_capacity(tokens.len());
+    let mut row_width = 0usize;
+    for token in tokens {
+        let (width, extra) = match &token.kind {
+            ViewTokenWireKind::Break => {
+                output.push(token);
+                row_width = 0;
+                continue;
+            }
+            // The terminator itself takes no column on screen
+            ViewTokenWireKind::Newline => (0, extra_width(&token, 1)),
+            ViewTokenWireKind::Text(text) => (
+                visual_layout::visual_width(text, row_width),
+                extra_width(&token, text.len()),
+            ),
+            ViewTokenWireKind::Space => (1, extra_width(&token, 1)),
+            ViewTokenWireKind::BinaryByte(_) => (4, extra_width(&token, 1)),
+        };
+        if row_width > 0 && row_width + width + extra > available_width {
+            output.push(ViewTokenWire {
+                source_offset: None,
+                kind: ViewTokenWireKind::Break,
+                style: None,
+            });
+            row_width = 0;
+        }
+        let is_newline = matches!(token.kind, ViewTokenWireKind::Newline);
+        output.push(token);
+        row_width = if is_newline {
+            0
+        } else {
+            row_width + width + extra
+        };
+    }
+    output
+}
+
 /// Apply soft breaks to a token stream.
 ///
 /// Walks tokens with a sorted break list `[(position, indent)]`. When a
@@ -10,6 +10,7 @@ use super::folding::{apply_folding, fold_adjusted_visible_count, fold_skip_set};
 use super::style::fold_placeholder_style;
 use super::transforms::{
     apply_conceal_ranges, apply_soft_breaks, apply_wrapping_transform, inject_virtual_lines,
+    reflow_inline_virtual_text,
 };
 use super::MAX_SAFE_LINE_WIDTH;
 use crate::state::{EditorState, ViewMode};
@@ -144,7 +145,34 @@ pub(super) fn build_view_data(
         MAX_SAFE_LINE_WIDTH
     };
     let hanging_indent = line_wrap_enabled && viewport.wrap_indent;
-    tokens = apply_wrapping_transform(tokens, effective_width, gutter_width, hanging_indent);
+    let wrap_mode = viewport.wrap_mode;
+    let wrap_indent_extra = viewport.wrap_indent_extra;
+    tokens = apply_wrapping_transform(
+        tokens,
+        effective_width,
+        gutter_width,
+        hanging_indent,
+        wrap_mode,
+        wrap_indent_extra,
+    );
+
+    // Make room for plugin virtual text, which the wrapping transform
+    // doesn't see, by moving whatever it would push past the edge onto
+    // the next row.
+    if line_wrap_enabled && !state.virtual_texts.is_empty() {
+        let viewport_end = tokens
+            .iter()
+            .filter_map(|t| t.source_offset)
+            .next_back()
+            .unwrap_or(viewport.top_byte)
+            + 1;
+        let widths = state.virtual_texts.reflow_widths_in_range(
+            &state.marker_list,
+            viewport.top_byte,
+            viewport_end,
+        );
+        tokens = reflow_inline_virtual_text(tokens, &widths, effective_width, gutter_width);
+    }
 
     // Convert tokens to display lines using the view pipeline.
     let is_binary = state.buffer.is_binary();
@@ -226,6 +254,8 @@ pub(super) fn build_view_data(
             gutter_width: gutter_width as u16,
             wrap_column: viewport.wrap_column.map(|c| c as u32),
             hanging_indent,
+            wrap_mode,
+            wrap_indent_extra,
             line_wrap_enabled: true,
         };
 
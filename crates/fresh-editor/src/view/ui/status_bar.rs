@@ -3,7 +3,7 @@
 use std::path::Path;
 
 use crate::app::WarningLevel;
-use crate::config::{StatusBarConfig, StatusBarElement};
+use crate::config::{StatusBarConfig, StatusBarElement, StatusBarFormat, StatusBarSegment};
 use crate::primitives::display_width::{char_width, str_width};
 use crate::state::EditorState;
 use crate::view::prompt::Prompt;
@@ -240,6 +240,8 @@ pub struct StatusBarContext<'a> {
     /// is redundant; when it's not, the filename keeps the prefix
     /// so users still see the connection at a glance.
     pub remote_indicator_on_bar: bool,
+    /// Active editor mode for the `{mode}` element, if any.
+    pub editor_mode: Option<&'a str>,
 }
 
 /// Layout information returned from status bar rendering for mouse click detection
@@ -525,8 +527,9 @@ impl StatusBarRenderer {
         area: Rect,
         ctx: &mut StatusBarContext<'_>,
         config: &StatusBarConfig,
+        format: Option<&StatusBarFormat>,
     ) -> StatusBarLayout {
-        Self::render_status(frame, area, ctx, config)
+        Self::render_status(frame, area, ctx, config, format)
     }
 
     /// Render the prompt/minibuffer
@@ -708,10 +711,15 @@ impl StatusBarRenderer {
                     | StatusBarElement::Cursor
                     | StatusBarElement::CursorCompact
                     | StatusBarElement::CursorCount
+                    | StatusBarElement::Selection
                     | StatusBarElement::Diagnostics
                     | StatusBarElement::LineEnding
                     | StatusBarElement::Encoding
                     | StatusBarElement::Language
+                    | StatusBarElement::File
+                    | StatusBarElement::Modified
+                    | StatusBarElement::Line
+                    | StatusBarElement::Column
             )
         {
             return None;
@@ -724,6 +732,13 @@ impl StatusBarRenderer {
                     ""
                 };
                 let read_only_indicator = if ctx.read_only { " [RO]" } else { "" };
+                // Large-file mode: no line index yet, so positions are byte
+                // offsets until the user runs Scan Line Index.
+                let large_file_indicator = if ctx.state.buffer.line_count().is_none() {
+                    " [Large]"
+                } else {
+                    ""
+                };
                 let remote_disconnected = ctx
                     .remote_connection
                     .map(|conn| conn.contains("(Disconnected)"))
@@ -753,7 +768,7 @@ impl StatusBarRenderer {
                     .unwrap_or_default();
                 let display_name = ctx.display_name;
                 let text = format!(
-                    "{session_prefix}{remote_prefix}{display_name}{modified}{read_only_indicator}"
+                    "{session_prefix}{remote_prefix}{display_name}{modified}{read_only_indicator}{large_file_indicator}"
                 );
                 let kind = if remote_disconnected {
                     ElementKind::RemoteDisconnected
@@ -802,17 +817,64 @@ impl StatusBarRenderer {
                     kind: ElementKind::Normal,
                 })
             }
+            StatusBarElement::File => Some(RenderedElement {
+                text: ctx.display_name.to_string(),
+                kind: ElementKind::Normal,
+            }),
+            StatusBarElement::Modified => {
+                if !ctx.state.buffer.is_modified() {
+                    return None;
+                }
+                Some(RenderedElement {
+                    text: "[+]".to_string(),
+                    kind: ElementKind::Normal,
+                })
+            }
+            StatusBarElement::Line | StatusBarElement::Column => {
+                if !ctx.state.show_cursors {
+                    return None;
+                }
+                let cursor = *ctx.cursors.primary();
+                let value = if ctx.state.buffer.line_count().is_none() {
+                    // Large-file mode has no line index; fall back to bytes
+                    if *element == StatusBarElement::Line {
+                        cursor.position
+                    } else {
+                        return None;
+                    }
+                } else if *element == StatusBarElement::Line {
+                    ctx.state.primary_cursor_line_number.value() + 1
+                } else {
+                    let line_start = ctx
+                        .state
+                        .buffer
+                        .line_iterator(cursor.position, 80)
+                        .current_position();
+                    cursor.position.saturating_sub(line_start) + 1
+                };
+                Some(RenderedElement {
+                    text: value.to_string(),
+                    kind: ElementKind::Normal,
+                })
+            }
+            StatusBarElement::Mode => Some(RenderedElement {
+                text: ctx.editor_mode?.to_string(),
+                kind: ElementKind::Normal,
+            }),
             StatusBarElement::Diagnostics => {
                 let diagnostics = ctx.state.overlays.all();
                 let mut error_count = 0usize;
                 let mut warning_count = 0usize;
                 let mut info_count = 0usize;
-                let diagnostic_ns = crate::services::lsp::diagnostics::lsp_diagnostic_namespace();
+                use crate::services::lsp::diagnostics::{
+                    lsp_diagnostic_namespace, ERROR_PRIORITY, WARNING_PRIORITY,
+                };
+                let diagnostic_ns = lsp_diagnostic_namespace();
                 for overlay in diagnostics {
                     if overlay.namespace.as_ref() == Some(&diagnostic_ns) {
                         match overlay.priority {
-                            100 => error_count += 1,
-                            50 => warning_count += 1,
+                            ERROR_PRIORITY => error_count += 1,
+                            WARNING_PRIORITY => warning_count += 1,
                             _ => info_count += 1,
                         }
                     }
@@ -844,6 +906,33 @@ impl StatusBarRenderer {
                     kind: ElementKind::Normal,
                 })
             }
+            StatusBarElement::Selection => {
+                if !ctx.state.show_cursors {
+                    return None;
+                }
+                let mut ranges: Vec<std::ops::Range<usize>> = ctx
+                    .cursors
+                    .iter()
+                    .filter_map(|(_, cursor)| cursor.selection_range())
+                    .filter(|range| !range.is_empty())
+                    .collect();
+                if ranges.is_empty() {
+                    return None;
+                }
+                ranges.sort_by_key(|range| range.start);
+                let state = &mut *ctx.state;
+                let stats = state.selection_stats.get(&mut state.buffer, &ranges);
+                Some(RenderedElement {
+                    text: t!(
+                        "status.selection",
+                        chars = stats.chars,
+                        lines = stats.lines,
+                        words = stats.words
+                    )
+                    .to_string(),
+                    kind: ElementKind::Normal,
+                })
+            }
             StatusBarElement::Messages => {
                 let mut parts: Vec<&str> = Vec::new();
                 if let Some(msg) = ctx.status_message {
@@ -881,10 +970,18 @@ impl StatusBarRenderer {
                     kind: ElementKind::Normal,
                 })
             }
-            StatusBarElement::LineEnding => Some(RenderedElement {
-                text: format!(" {} ", ctx.state.buffer.line_ending().display_name()),
-                kind: ElementKind::LineEnding,
-            }),
+            StatusBarElement::LineEnding => {
+                let buffer = &ctx.state.buffer;
+                let text = if buffer.has_mixed_line_endings() {
+                    format!(" Mixed ({}) ", buffer.line_ending().display_name())
+                } else {
+                    format!(" {} ", buffer.line_ending().display_name())
+                };
+                Some(RenderedElement {
+                    text,
+                    kind: ElementKind::LineEnding,
+                })
+            }
             StatusBarElement::Encoding => Some(RenderedElement {
                 text: format!(" {} ", ctx.state.buffer.encoding().display_name()),
                 kind: ElementKind::Encoding,
@@ -1205,12 +1302,18 @@ impl StatusBarRenderer {
 
     /// Render a configured side (left/right) into styled per-element groups.
     fn render_side(
-        config_side: &[StatusBarElement],
+        segments: &[StatusBarSegment],
         ctx: &mut StatusBarContext<'_>,
     ) -> Vec<(Vec<Span<'static>>, usize, ElementKind)> {
-        let rendered: Vec<RenderedElement> = config_side
+        let rendered: Vec<RenderedElement> = segments
             .iter()
-            .filter_map(|elem| Self::render_element(elem, ctx))
+            .filter_map(|segment| match segment {
+                StatusBarSegment::Element(elem) => Self::render_element(elem, ctx),
+                StatusBarSegment::Text(text) => Some(RenderedElement {
+                    text: text.clone(),
+                    kind: ElementKind::Normal,
+                }),
+            })
             .filter(|e| !e.text.is_empty())
             .collect();
 
@@ -1235,6 +1338,7 @@ impl StatusBarRenderer {
         area: Rect,
         ctx: &mut StatusBarContext<'_>,
         config: &StatusBarConfig,
+        format: Option<&StatusBarFormat>,
     ) -> StatusBarLayout {
         let mut layout = StatusBarLayout::default();
         let base_style = Style::default()
@@ -1250,17 +1354,40 @@ impl StatusBarRenderer {
         // RemoteIndicator is on the bar so the Filename branch
         // can drop its now-redundant `[Container:<id>] ` /
         // SSH prefix.
-        ctx.remote_indicator_on_bar = config
-            .left
-            .iter()
-            .chain(config.right.iter())
-            .any(|e| matches!(e, StatusBarElement::RemoteIndicator));
-
-        let left_items = Self::render_side(&config.left, ctx);
-        let mut right_items = Self::render_side(&config.right, ctx);
+        ctx.remote_indicator_on_bar = match format {
+            Some(format) => format
+                .elements()
+                .any(|e| matches!(e, StatusBarElement::RemoteIndicator)),
+            None => config
+                .left
+                .iter()
+                .chain(config.right.iter())
+                .any(|e| matches!(e, StatusBarElement::RemoteIndicator)),
+        };
 
-        const SEPARATOR: &str = " | ";
-        let separator_width = str_width(SEPARATOR);
+        // A format template spells out its own spacing; the element lists
+        // are joined with a separator
+        let (left_items, mut right_items, separator) = match format {
+            Some(format) => (
+                Self::render_side(&format.left, ctx),
+                Self::render_side(&format.right, ctx),
+                "",
+            ),
+            None => {
+                let segments = |side: &[StatusBarElement]| -> Vec<StatusBarSegment> {
+                    side.iter()
+                        .cloned()
+                        .map(StatusBarSegment::Element)
+                        .collect()
+                };
+                (
+                    Self::render_side(&segments(&config.left), ctx),
+                    Self::render_side(&segments(&config.right), ctx),
+                    " | ",
+                )
+            }
+        };
+        let separator_width = str_width(separator);
 
         // Reserve a sane minimum for the left side so the buffer name and
         // cursor position aren't truncated to a single character on narrow
@@ -1310,7 +1437,7 @@ impl StatusBarRenderer {
                 break;
             }
             if sep_width > 0 {
-                spans.push(Span::styled(SEPARATOR, base_style));
+                spans.push(Span::styled(separator, base_style));
                 used_left += sep_width;
             }
 
@@ -12,6 +12,33 @@ use ratatui::Frame;
 /// Renders the autocomplete suggestions popup
 pub struct SuggestionsRenderer;
 
+/// Split `text` into spans, bolding and underlining the chars at
+/// `match_positions` (char indices, sorted).
+fn highlight_matches(
+    text: &str,
+    match_positions: &[usize],
+    base_style: Style,
+) -> Vec<Span<'static>> {
+    let match_style = base_style.add_modifier(Modifier::BOLD | Modifier::UNDERLINED);
+    let mut spans = Vec::new();
+    let mut run = String::new();
+    let mut run_matched = false;
+    for (idx, ch) in text.chars().enumerate() {
+        let matched = match_positions.binary_search(&idx).is_ok();
+        if matched != run_matched && !run.is_empty() {
+            let style = if run_matched { match_style } else { base_style };
+            spans.push(Span::styled(std::mem::take(&mut run), style));
+        }
+        run_matched = matched;
+        run.push(ch);
+    }
+    if !run.is_empty() {
+        let style = if run_matched { match_style } else { base_style };
+        spans.push(Span::styled(run, style));
+    }
+    spans
+}
+
 impl SuggestionsRenderer {
     /// Render the suggestions popup (autocomplete/command palette)
     ///
@@ -210,7 +237,17 @@ impl SuggestionsRenderer {
             } else {
                 name.clone()
             };
-            spans.push(Span::styled(name_text.clone(), base_style));
+            // Highlight the characters the query matched. Front-truncated
+            // paths no longer line up with the match positions.
+            if suggestion.match_positions.is_empty() || name_text.starts_with('…') {
+                spans.push(Span::styled(name_text.clone(), base_style));
+            } else {
+                spans.extend(highlight_matches(
+                    &name_text,
+                    &suggestion.match_positions,
+                    base_style,
+                ));
+            }
             let name_display_width = str_width(&name_text);
             let name_padding = name_column_width.saturating_sub(name_display_width);
             if name_padding > 0 {
@@ -446,6 +483,16 @@ mod tests {
     use ratatui::backend::TestBackend;
     use ratatui::Terminal;
 
+    #[test]
+    fn test_highlight_matches_groups_runs() {
+        let base = Style::default();
+        let spans = highlight_matches("Open File", &[0, 1, 5], base);
+        let texts: Vec<&str> = spans.iter().map(|s| s.content.as_ref()).collect();
+        assert_eq!(texts, vec!["Op", "en ", "F", "ile"]);
+        assert!(spans[0].style.add_modifier.contains(Modifier::BOLD));
+        assert!(!spans[1].style.add_modifier.contains(Modifier::BOLD));
+    }
+
     /// Regression test for UTF-8 truncation bug.
     ///
     /// The bug occurred when truncating a description containing multi-byte
@@ -1,8 +1,21 @@
+use crate::config::WrapMode;
 use crate::model::buffer::Buffer;
 use crate::model::cursor::Cursor;
 use crate::primitives::display_width::{char_width, str_width};
 use crate::primitives::line_wrapping::WrapConfig;
 use crate::view::ui::view_pipeline::{LineStart, ViewLine};
+
+/// Which viewport row [`Viewport::place_cursor`] scrolls the cursor to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CursorPlacement {
+    /// First row of the viewport (vim `zt`)
+    Top,
+    /// Middle row of the viewport (vim `zz`)
+    Center,
+    /// Last row of the viewport (vim `zb`)
+    Bottom,
+}
+
 /// The viewport - what portion of the buffer is visible
 #[derive(Debug, Clone)]
 pub struct Viewport {
@@ -31,6 +44,11 @@ pub struct Viewport {
     /// Horizontal scroll offset (columns to keep visible left/right of cursor)
     pub horizontal_scroll_offset: usize,
 
+    /// Whether the viewport may scroll past the end of the document, up to
+    /// the point where the last line sits at the top row. Ignored for
+    /// documents that fit entirely in the viewport.
+    pub overscroll: bool,
+
     /// Whether line wrapping is enabled
     /// When true, horizontal scrolling is disabled
     pub line_wrap_enabled: bool,
@@ -38,6 +56,12 @@ pub struct Viewport {
     /// Whether wrapped continuation lines should be indented to match leading whitespace
     pub wrap_indent: bool,
 
+    /// Whether wrapped lines break at word boundaries or at the exact column
+    pub wrap_mode: WrapMode,
+
+    /// Extra columns added to the hanging indent of wrapped continuation lines
+    pub wrap_indent_extra: usize,
+
     /// Column at which to wrap lines (None = viewport width)
     pub wrap_column: Option<usize>,
 
@@ -118,6 +142,12 @@ pub struct Viewport {
     /// count depends only on buffer content + geometry, which is what
     /// this cache covers.
     pub(crate) wrap_row_cache: crate::view::line_wrap_cache::LineWrapCache,
+
+    /// Whether the whole document fits in the viewport, keyed on buffer
+    /// version, viewport height, width and wrap state. Overscroll checks
+    /// consult this on every frame near the end of the buffer, so it is
+    /// only recomputed when the buffer or geometry changes.
+    document_fits_cache: Option<((u64, usize, u16, bool), bool)>,
 }
 
 impl Viewport {
@@ -131,8 +161,11 @@ impl Viewport {
             height,
             scroll_offset: 3,
             horizontal_scroll_offset: 5,
+            overscroll: false,
             line_wrap_enabled: false,
             wrap_indent: true,
+            wrap_mode: WrapMode::default(),
+            wrap_indent_extra: 0,
             wrap_column: None,
             compose_width: None,
             show_line_numbers: true,
@@ -148,6 +181,7 @@ impl Viewport {
             wrap_row_cache: crate::view::line_wrap_cache::LineWrapCache::with_byte_budget(
                 512 * 1024,
             ),
+            document_fits_cache: None,
         }
     }
 
@@ -300,6 +334,9 @@ impl Viewport {
                 effective_width,
                 wrap_config.gutter_width,
                 wrap_config.hanging_indent,
+                wrap_config.wrap_mode,
+                wrap_config.wrap_indent_extra,
+                &std::collections::BTreeMap::new(),
             ) as usize
                 + extra_virtual_rows;
         }
@@ -342,6 +379,8 @@ impl Viewport {
                     effective_width,
                     wrap_config.gutter_width,
                     wrap_config.hanging_indent,
+                    wrap_config.wrap_mode,
+                    wrap_config.wrap_indent_extra,
                 );
                 crate::view::line_wrap_cache::placeholder_layout_for_row_count(n)
             };
@@ -355,6 +394,8 @@ impl Viewport {
                     gutter_width: wrap_config.gutter_width as u16,
                     wrap_column: None,
                     hanging_indent: wrap_config.hanging_indent,
+                    wrap_mode: wrap_config.wrap_mode,
+                    wrap_indent_extra: wrap_config.wrap_indent_extra,
                     line_wrap_enabled: true,
                 };
                 return cache.get_or_insert_with(key, compute).len() + extra_virtual_rows;
@@ -364,6 +405,19 @@ impl Viewport {
     }
 }
 
+/// Visual width of `text` with each tab expanded to the next multiple of
+/// `tab_size`, as the renderer draws it.
+fn tab_expanded_width(text: &str, tab_size: usize) -> usize {
+    let tab_size = tab_size.max(1);
+    text.chars().fold(0, |col, ch| {
+        if ch == '\t' {
+            col + tab_size - col % tab_size
+        } else {
+            col + char_width(ch)
+        }
+    })
+}
+
 /// Compute the visual-row count for a single line's text by running
 /// `apply_wrapping_transform` on a single-Text-token input and walking
 /// the output token stream. Module-private helper shared by the cache
@@ -373,6 +427,8 @@ fn compute_wrap_row_count_for_text(
     effective_width: usize,
     gutter_width: usize,
     hanging_indent: bool,
+    wrap_mode: WrapMode,
+    wrap_indent_extra: usize,
 ) -> u32 {
     use crate::view::ui::split_rendering::transforms::apply_wrapping_transform;
     use fresh_core::api::{ViewTokenWire, ViewTokenWireKind};
@@ -382,7 +438,14 @@ fn compute_wrap_row_count_for_text(
         kind: ViewTokenWireKind::Text(line_text.to_string()),
         style: None,
     }];
-    let wrapped = apply_wrapping_transform(tokens, effective_width, gutter_width, hanging_indent);
+    let wrapped = apply_wrapping_transform(
+        tokens,
+        effective_width,
+        gutter_width,
+        hanging_indent,
+        wrap_mode,
+        wrap_indent_extra,
+    );
     // Count non-empty visual rows.  `apply_wrapping_transform` can emit a
     // *trailing* `Break` when the last chunk fills `effective_width` exactly
     // — that Break is width-triggered and is followed by nothing, so it
@@ -489,6 +552,8 @@ impl Viewport {
             gutter_width,
             true,
             self.wrap_indent,
+            self.wrap_mode,
+            self.wrap_indent_extra,
         );
 
         // We need to move backwards through visual rows
@@ -577,6 +642,8 @@ impl Viewport {
             gutter_width,
             true,
             self.wrap_indent,
+            self.wrap_mode,
+            self.wrap_indent_extra,
         );
         let buffer_len = buffer.len();
 
@@ -741,6 +808,12 @@ impl Viewport {
         // If we don't have enough rows to fill viewport, find the max scroll position
         // and set it directly (instead of calling scroll_up_visual which can be jumpy)
         if visual_rows_remaining < viewport_height {
+            if self.overscroll
+                && visual_rows_remaining > 0
+                && !self.document_fits(buffer, soft_breaks, virtual_lines, Some(wrap_config))
+            {
+                return;
+            }
             // Find the max scroll position by scanning from the beginning
             let (max_byte, max_offset) = self.find_max_visual_scroll_position(
                 buffer,
@@ -1101,7 +1174,15 @@ impl Viewport {
         } else {
             0
         };
-        let max_top = view_lines.len().saturating_sub(viewport_height);
+        // `max_top` keeps a full viewport's worth of rows below the top.
+        // Overscroll relaxes it so the last view line may reach the top row,
+        // but only when the view lines overflow the viewport — short
+        // documents stay pinned at the top.
+        let max_top = if self.overscroll && view_lines.len() > viewport_height {
+            view_lines.len() - 1
+        } else {
+            view_lines.len().saturating_sub(viewport_height)
+        };
 
         // Cursor is in the top margin zone when it sits within
         // `effective_offset` rows of the top of the viewport.  When
@@ -1139,8 +1220,7 @@ impl Viewport {
                 (cursor_view_line + effective_offset + 1).saturating_sub(viewport_height)
             };
 
-            // Clamp to valid range. `max_top` is the largest top that still
-            // keeps a full viewport's worth of rows below it.
+            // Clamp to valid range.
             let new_offset = target_top.min(max_top);
 
             // Only actually scroll if that moves the viewport. If the
@@ -1329,6 +1409,33 @@ impl Viewport {
         }
     }
 
+    /// Largest `left_column` that still shows the end of the longest line
+    /// on screen, with tabs expanded to multiples of `tab_size`. Measured
+    /// from the buffer rather than `max_line_length_seen`, which lags a
+    /// render behind. Unbounded for large files without line metadata; the
+    /// overshoot clips at render.
+    pub fn max_left_column(&self, buffer: &Buffer, tab_size: usize) -> usize {
+        if buffer.line_count().is_none() {
+            return usize::MAX;
+        }
+
+        let scrollbar_width = 1;
+        let visible_width = (self.width as usize)
+            .saturating_sub(self.gutter_width(buffer))
+            .saturating_sub(scrollbar_width);
+
+        let top_line = buffer.get_line_number(self.top_byte);
+        let longest = (top_line..top_line + self.height as usize)
+            .map_while(|line| buffer.get_line(line))
+            .map(|bytes| {
+                let text = String::from_utf8_lossy(&bytes);
+                tab_expanded_width(text.trim_end_matches(['\n', '\r']), tab_size)
+            })
+            .max()
+            .unwrap_or(0);
+        longest.saturating_sub(visible_width.saturating_sub(1))
+    }
+
     /// Set top_byte with automatic scroll limit enforcement
     /// This prevents scrolling past the end of the buffer by ensuring
     /// the viewport can be filled from the proposed position
@@ -1368,6 +1475,8 @@ impl Viewport {
                 gutter_width,
                 true,
                 self.wrap_indent,
+                self.wrap_mode,
+                self.wrap_indent_extra,
             );
 
             let mut iter = buffer.line_iterator(proposed_top_byte, 80);
@@ -1396,6 +1505,15 @@ impl Viewport {
                 return;
             }
 
+            // With overscroll, any position that still shows a row is valid
+            // once the document overflows the viewport.
+            if self.overscroll
+                && visual_rows > 0
+                && !self.document_fits(buffer, soft_breaks, virtual_lines, Some(&wrap_config))
+            {
+                self.top_byte = proposed_top_byte;
+                return;
+            }
             // Not enough visual rows to fill viewport from proposed position.
             // Use find_max_visual_scroll_position which correctly counts wrapped rows.
             let (max_byte, max_offset) = self.find_max_visual_scroll_position(
@@ -1450,6 +1568,13 @@ impl Viewport {
             return;
         }
 
+        // With overscroll, the last line may scroll up to the top row as long
+        // as the document is taller than the viewport.
+        if self.overscroll && lines_visible > 0 && !self.document_fits(buffer, &[], &[], None) {
+            self.top_byte = proposed_top_byte;
+            return;
+        }
+
         // We don't have enough lines to fill the viewport from proposed_top_byte
         // Calculate how many lines we're short and scroll back
         let lines_short = viewport_height - lines_visible;
@@ -1486,6 +1611,126 @@ impl Viewport {
         self.top_byte = final_top_byte;
     }
 
+    /// Scroll so the cursor's row sits at the top, middle, or bottom of the
+    /// viewport. Rows are visual rows when line wrap is enabled. The result
+    /// is clamped to the document boundaries, so a cursor near the start or
+    /// end of the buffer may land elsewhere.
+    pub fn place_cursor(
+        &mut self,
+        buffer: &mut Buffer,
+        cursor: &Cursor,
+        placement: CursorPlacement,
+    ) {
+        let viewport_height = self.visible_line_count();
+        if viewport_height == 0 {
+            return;
+        }
+        let rows_above_cursor = match placement {
+            CursorPlacement::Top => 0,
+            CursorPlacement::Center => viewport_height / 2,
+            CursorPlacement::Bottom => viewport_height - 1,
+        };
+
+        let mut iter = buffer.line_iterator(cursor.position, 80);
+        let cursor_line_start = iter.current_position();
+
+        if !self.line_wrap_enabled {
+            self.top_view_line_offset = 0;
+            for _ in 0..rows_above_cursor {
+                if iter.prev().is_none() {
+                    break;
+                }
+            }
+            let new_top_byte = iter.current_position();
+            self.set_top_byte_with_limit(buffer, &[], &[], new_top_byte);
+            return;
+        }
+
+        // Start with the cursor's own wrap segment as the top row, then walk
+        // back the requested number of visual rows.
+        let gutter_width = self.gutter_width(buffer);
+        let wrap_config = WrapConfig::new(
+            self.effective_width() as usize,
+            gutter_width,
+            true,
+            self.wrap_indent,
+            self.wrap_mode,
+            self.wrap_indent_extra,
+        );
+        let cursor_segment = match iter.next_line() {
+            Some((_, line_content)) => {
+                let line_text = line_content.trim_end_matches(['\n', '\r']);
+                let effective_width = wrap_config
+                    .first_line_width
+                    .saturating_add(wrap_config.gutter_width)
+                    .max(2);
+                let layout = crate::view::line_wrap_cache::layout_for_plain_text(
+                    line_text,
+                    effective_width,
+                    wrap_config.gutter_width,
+                    wrap_config.hanging_indent,
+                    wrap_config.wrap_mode,
+                    wrap_config.wrap_indent_extra,
+                    4,
+                );
+                let cursor_column = cursor.position.saturating_sub(cursor_line_start);
+                crate::view::line_wrap_cache::char_position_in_layout(&layout, cursor_column).0
+            }
+            None => 0,
+        };
+
+        self.top_byte = cursor_line_start;
+        self.top_view_line_offset = cursor_segment;
+        self.scroll_up_visual(buffer, &[], &[], rows_above_cursor);
+        self.apply_visual_scroll_limit(buffer, &[], &[], &wrap_config);
+    }
+
+    /// Whether the whole document fits in the viewport: in visual rows
+    /// when `wrap_config` is given, otherwise in logical lines. Cached in
+    /// `document_fits_cache`.
+    fn document_fits(
+        &mut self,
+        buffer: &mut Buffer,
+        soft_breaks: &[(usize, u16)],
+        virtual_lines: &[usize],
+        wrap_config: Option<&WrapConfig>,
+    ) -> bool {
+        let viewport_height = self.visible_line_count();
+        let key = (
+            buffer.version(),
+            viewport_height,
+            self.effective_width(),
+            wrap_config.is_some(),
+        );
+        if let Some((cached_key, fits)) = self.document_fits_cache {
+            if cached_key == key {
+                return fits;
+            }
+        }
+
+        let fits = match wrap_config {
+            Some(wrap_config) => {
+                self.find_max_visual_scroll_position(
+                    buffer,
+                    soft_breaks,
+                    virtual_lines,
+                    wrap_config,
+                    viewport_height,
+                ) == (0, 0)
+            }
+            None => {
+                let mut iter = buffer.line_iterator(0, 80);
+                let mut lines = 0;
+                while lines <= viewport_height && iter.next_line().is_some() {
+                    lines += 1;
+                }
+                lines <= viewport_height
+            }
+        };
+        self.document_fits_cache = Some((key, fits));
+        fits
+    }
+
     /// Scroll to a specific line (byte-based)
     /// This seeks from the beginning to find the byte position of the line
     pub fn scroll_to(&mut self, buffer: &mut Buffer, line: usize) {
@@ -1690,6 +1935,8 @@ impl Viewport {
                 gutter_width,
                 true,
                 self.wrap_indent,
+                self.wrap_mode,
+                self.wrap_indent_extra,
             );
 
             let mut iter = buffer.line_iterator(self.top_byte, 80);
@@ -1727,6 +1974,8 @@ impl Viewport {
                             effective_width,
                             wrap_config.gutter_width,
                             wrap_config.hanging_indent,
+                            wrap_config.wrap_mode,
+                            wrap_config.wrap_indent_extra,
                             4,
                         );
                         let segments_count = layout.len().max(1); // empty line = 1 row
@@ -1786,6 +2035,8 @@ impl Viewport {
                         effective_width,
                         wrap_config.gutter_width,
                         wrap_config.hanging_indent,
+                        wrap_config.wrap_mode,
+                        wrap_config.wrap_indent_extra,
                         4,
                     );
                     visual_rows += layout.len();
@@ -1880,6 +2131,8 @@ impl Viewport {
                     gutter_width,
                     true,
                     self.wrap_indent,
+                    self.wrap_mode,
+                    self.wrap_indent_extra,
                 );
 
                 let mut iter = buffer.line_iterator(cursor_line_start, 80);
@@ -1913,6 +2166,8 @@ impl Viewport {
                         effective_width,
                         wrap_config.gutter_width,
                         wrap_config.hanging_indent,
+                        wrap_config.wrap_mode,
+                        wrap_config.wrap_indent_extra,
                         4,
                     );
                     let cursor_column = cursor.position.saturating_sub(cursor_line_start);
@@ -1987,6 +2242,8 @@ impl Viewport {
                             effective_width,
                             wrap_config.gutter_width,
                             wrap_config.hanging_indent,
+                            wrap_config.wrap_mode,
+                            wrap_config.wrap_indent_extra,
                             4,
                         );
                         let added = layout.len().max(1);
@@ -2269,6 +2526,8 @@ impl Viewport {
                 gutter_width,
                 true,
                 self.wrap_indent,
+                self.wrap_mode,
+                self.wrap_indent_extra,
             ))
         } else {
             None
@@ -2326,6 +2585,8 @@ impl Viewport {
                 effective_width,
                 config.gutter_width,
                 config.hanging_indent,
+                config.wrap_mode,
+                config.wrap_indent_extra,
                 4,
             );
 
@@ -2364,6 +2625,18 @@ mod tests {
         assert_eq!(vp.top_byte, 0);
     }
 
+    #[test]
+    fn test_document_fits_is_recomputed_when_buffer_changes() {
+        let mut buffer = Buffer::from_str_test("a\nb\nc");
+        let mut vp = Viewport::new(80, 10);
+        assert!(vp.document_fits(&mut buffer, &[], &[], None));
+
+        let more_lines: String = (0..30).map(|i| format!("line{i}\n")).collect();
+        let len = buffer.len();
+        buffer.insert(len, &more_lines);
+        assert!(!vp.document_fits(&mut buffer, &[], &[], None));
+    }
+
     #[test]
     fn test_scroll_up_down() {
         // Create a buffer with more lines than the viewport to make scrolling possible
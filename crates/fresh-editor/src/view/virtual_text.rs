@@ -5,6 +5,7 @@
 //!
 //! Two types of virtual text are supported:
 //! - **Inline**: Text inserted before/after a character (e.g., `: i32` type hints)
+//!   or after the end of a line (e.g., plugin annotations)
 //! - **Line**: Full lines inserted above/below a position (e.g., git blame headers)
 //!
 //! Virtual text is rendered during the render phase by reading from VirtualTextManager.
@@ -20,9 +21,10 @@
 //! This ensures frame coherence: render always sees a consistent snapshot of virtual text.
 
 use ratatui::style::Style;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use crate::model::marker::{MarkerId, MarkerList};
+use crate::primitives::display_width::str_width;
 
 /// Position relative to the character at the marker position
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -32,6 +34,9 @@ pub enum VirtualTextPosition {
     BeforeChar,
     /// Render after the character (e.g., type hints: `x: i32`)
     AfterChar,
+    /// Render after the line's content. The marker sits on the line
+    /// terminator (or at the buffer end for a last line without one).
+    EndOfLine,
 
     // ─── Line positions (full lines) ───
     /// Render as a full line ABOVE the line containing this position
@@ -50,9 +55,9 @@ impl VirtualTextPosition {
         matches!(self, Self::LineAbove | Self::LineBelow)
     }
 
-    /// Returns true if this is an inline position (BeforeChar/AfterChar)
+    /// Returns true if this is an inline position (BeforeChar/AfterChar/EndOfLine)
     pub fn is_inline(&self) -> bool {
-        matches!(self, Self::BeforeChar | Self::AfterChar)
+        matches!(self, Self::BeforeChar | Self::AfterChar | Self::EndOfLine)
     }
 }
 
@@ -99,6 +104,10 @@ pub struct VirtualText {
     pub string_id: Option<String>,
     /// Optional namespace for bulk removal (like Overlay's namespace)
     pub namespace: Option<VirtualTextNamespace>,
+    /// Whether wrapped lines make room for this text by moving what
+    /// follows it to the next row (plugin `setVirtualText`). Other inline
+    /// text, such as inlay hints, doesn't change the line's layout.
+    pub reflow: bool,
 }
 
 impl VirtualText {
@@ -208,6 +217,7 @@ impl VirtualTextManager {
                 priority,
                 string_id: None,
                 namespace: None,
+                reflow: false,
             },
         );
         self.bump_version();
@@ -236,7 +246,7 @@ impl VirtualTextManager {
     ) -> VirtualTextId {
         debug_assert!(
             vtext_position.is_inline(),
-            "add_with_theme_keys requires BeforeChar or AfterChar"
+            "add_with_theme_keys requires an inline position"
         );
 
         let marker_id = marker_list.create(position, false);
@@ -256,6 +266,7 @@ impl VirtualTextManager {
                 priority,
                 string_id: None,
                 namespace: None,
+                reflow: false,
             },
         );
         self.bump_version();
@@ -294,6 +305,7 @@ impl VirtualTextManager {
                 priority,
                 string_id: Some(string_id),
                 namespace: None,
+                reflow: false,
             },
         );
         self.bump_version();
@@ -318,7 +330,7 @@ impl VirtualTextManager {
     ) -> VirtualTextId {
         debug_assert!(
             vtext_position.is_inline(),
-            "add_with_id_and_theme_keys requires BeforeChar or AfterChar"
+            "add_with_id_and_theme_keys requires an inline position"
         );
 
         let marker_id = marker_list.create(position, false);
@@ -338,8 +350,10 @@ impl VirtualTextManager {
                 priority,
                 string_id: Some(string_id),
                 namespace: None,
+                reflow: false,
             },
         );
+        self.bump_version();
 
         id
     }
@@ -422,6 +436,7 @@ impl VirtualTextManager {
                 priority,
                 string_id: None,
                 namespace: Some(namespace),
+                reflow: false,
             },
         );
         self.bump_version();
@@ -457,6 +472,16 @@ impl VirtualTextManager {
         removed
     }
 
+    /// Set whether a virtual text entry reflows wrapped lines around it
+    pub fn set_reflow(&mut self, id: VirtualTextId, reflow: bool) {
+        if let Some(vtext) = self.texts.get_mut(&id) {
+            if vtext.reflow != reflow {
+                vtext.reflow = reflow;
+                self.bump_version();
+            }
+        }
+    }
+
     /// Remove all virtual text entries whose string_id starts with the given prefix
     pub fn remove_by_prefix(&mut self, marker_list: &mut MarkerList, prefix: &str) {
         // Collect markers to delete
@@ -687,7 +712,7 @@ impl VirtualTextManager {
         results
     }
 
-    /// Query only INLINE virtual texts (BeforeChar/AfterChar) in a byte range
+    /// Query only INLINE virtual texts (BeforeChar/AfterChar/EndOfLine) in a byte range
     ///
     /// Used by the render pipeline to inject inline hints.
     pub fn query_inline_in_range(
@@ -716,6 +741,30 @@ impl VirtualTextManager {
         results
     }
 
+    /// Columns taken by inline virtual text that reflows wrapped lines, keyed
+    /// by the byte position it is drawn at, for positions in `[start, end)`.
+    ///
+    /// Each entry counts the separating space the renderer draws before
+    /// the text. Used by the renderer and the scroll math so both wrap
+    /// lines the same way.
+    pub fn reflow_widths_in_range(
+        &self,
+        marker_list: &MarkerList,
+        start: usize,
+        end: usize,
+    ) -> BTreeMap<usize, usize> {
+        let mut widths = BTreeMap::new();
+        for vtext in self.texts.values().filter(|v| v.reflow) {
+            let Some(pos) = marker_list.get_position(vtext.marker_id) else {
+                continue;
+            };
+            if pos >= start && pos < end {
+                *widths.entry(pos).or_insert(0) += str_width(&vtext.text) + 1;
+            }
+        }
+        widths
+    }
+
     /// Build a lookup map for virtual LINES, keyed by the line's anchor byte position
     ///
     /// For each source line, the renderer can quickly check if there are
@@ -813,6 +862,47 @@ mod tests {
         assert_eq!(marker_list.marker_count(), 0);
     }
 
+    #[test]
+    fn test_reflow_widths_only_include_reflowing_text() {
+        let mut marker_list = MarkerList::new();
+        let mut manager = VirtualTextManager::new();
+
+        // An inlay hint doesn't change the layout
+        manager.add(
+            &mut marker_list,
+            5,
+            ": i32".to_string(),
+            hint_style(),
+            VirtualTextPosition::AfterChar,
+            0,
+        );
+        assert!(manager
+            .reflow_widths_in_range(&marker_list, 0, 20)
+            .is_empty());
+
+        let id = manager.add_with_id_and_theme_keys(
+            &mut marker_list,
+            12,
+            "note".to_string(),
+            hint_style(),
+            None,
+            None,
+            VirtualTextPosition::EndOfLine,
+            0,
+            "plugin-note".to_string(),
+        );
+        let version = manager.version();
+        manager.set_reflow(id, true);
+        assert_ne!(manager.version(), version);
+
+        // The text plus the space separating it from the buffer text
+        let widths = manager.reflow_widths_in_range(&marker_list, 0, 20);
+        assert_eq!(widths.into_iter().collect::<Vec<_>>(), vec![(12, 5)]);
+        assert!(manager
+            .reflow_widths_in_range(&marker_list, 0, 12)
+            .is_empty());
+    }
+
     #[test]
     fn test_remove_nonexistent() {
         let mut marker_list = MarkerList::new();
@@ -35,11 +35,13 @@
 //!   * scrollbar render's `scrollbar_visual_row_counts` (per frame)
 //!   * `ensure_visible` wrapped scroll-up walk (per keystroke)
 
+use crate::config::WrapMode;
 use crate::state::EditorState;
 use crate::view::line_wrap_cache::{
     count_visual_rows_for_text, count_visual_rows_for_text_with_soft_breaks,
     pipeline_inputs_version, CacheViewMode, LineWrapKey, WrapGeometry,
 };
+use std::collections::BTreeMap;
 
 /// All inputs that determine the per-line visual row counts a buffer
 /// produces.  Identical to `LineWrapKey`'s geometry-related fields
@@ -53,6 +55,8 @@ pub struct VisualRowIndexKey {
     pub gutter_width: u16,
     pub wrap_column: Option<u32>,
     pub hanging_indent: bool,
+    pub wrap_mode: WrapMode,
+    pub wrap_indent_extra: usize,
     pub line_wrap_enabled: bool,
 }
 
@@ -67,6 +71,8 @@ impl VisualRowIndexKey {
             gutter_width: self.gutter_width,
             wrap_column: self.wrap_column,
             hanging_indent: self.hanging_indent,
+            wrap_mode: self.wrap_mode,
+            wrap_indent_extra: self.wrap_indent_extra,
             line_wrap_enabled: self.line_wrap_enabled,
         }
     }
@@ -207,6 +213,8 @@ pub fn ensure_built(state: &mut EditorState, key: &VisualRowIndexKey) {
     let effective_width = key.effective_width as usize;
     let gutter_width = key.gutter_width as usize;
     let hanging_indent = key.hanging_indent;
+    let wrap_mode = key.wrap_mode;
+    let wrap_indent_extra = key.wrap_indent_extra;
 
     // Pre-fetch the buffer-wide soft breaks and virtual lines once,
     // then per-line we slice into them with `partition_point`.  Each
@@ -238,6 +246,15 @@ pub fn ensure_built(state: &mut EditorState, key: &VisualRowIndexKey) {
         v.sort_unstable();
         v
     };
+    //   * reflowing inline virtual text (plugin `setVirtualText`) pushes
+    //     the rest of its row onto the next one when it doesn't fit.
+    let reflow_widths: BTreeMap<usize, usize> = if state.virtual_texts.is_empty() {
+        BTreeMap::new()
+    } else {
+        state
+            .virtual_texts
+            .reflow_widths_in_range(&state.marker_list, 0, buffer_len + 1)
+    };
 
     // Build into local Vecs first so we don't fight the borrow checker
     // when re-borrowing `state` per line.
@@ -293,8 +310,27 @@ pub fn ensure_built(state: &mut EditorState, key: &VisualRowIndexKey) {
             };
             let line_content = String::from_utf8_lossy(&bytes);
             let trimmed = line_content.trim_end_matches('\n').trim_end_matches('\r');
+            // Reflowing virtual text, re-keyed to the trimmed line; text
+            // on the terminator (or the buffer end) lands at `trimmed.len()`
+            let widths_end = if line_idx + 1 < line_count {
+                line_end
+            } else {
+                buffer_len + 1
+            };
+            let virtual_text_widths: BTreeMap<usize, usize> = reflow_widths
+                .range(line_start..widths_end)
+                .map(|(&pos, &width)| ((pos - line_start).min(trimmed.len()), width))
+                .collect();
             if line_breaks.is_empty() {
-                count_visual_rows_for_text(trimmed, effective_width, gutter_width, hanging_indent)
+                count_visual_rows_for_text(
+                    trimmed,
+                    effective_width,
+                    gutter_width,
+                    hanging_indent,
+                    wrap_mode,
+                    wrap_indent_extra,
+                    &virtual_text_widths,
+                )
             } else {
                 count_visual_rows_for_text_with_soft_breaks(
                     trimmed,
@@ -303,6 +339,9 @@ pub fn ensure_built(state: &mut EditorState, key: &VisualRowIndexKey) {
                     effective_width,
                     gutter_width,
                     hanging_indent,
+                    wrap_mode,
+                    wrap_indent_extra,
+                    &virtual_text_widths,
                 )
             }
         };
@@ -349,6 +388,8 @@ pub fn ensure_built_from_geom(state: &mut EditorState, geom: &WrapGeometry) {
         gutter_width: geom.gutter_width as u16,
         wrap_column: geom.wrap_column,
         hanging_indent: geom.hanging_indent,
+        wrap_mode: geom.wrap_mode,
+        wrap_indent_extra: geom.wrap_indent_extra,
         line_wrap_enabled: geom.line_wrap_enabled,
     };
     ensure_built(state, &key);
@@ -367,6 +408,8 @@ mod tests {
                 gutter_width: 6,
                 wrap_column: None,
                 hanging_indent: false,
+                wrap_mode: WrapMode::Word,
+                wrap_indent_extra: 0,
                 line_wrap_enabled: true,
             }),
             prefix_sums: prefix,
@@ -32,7 +32,9 @@ use std::time::{SystemTime, UNIX_EPOCH};
 use crate::input::input_history::get_data_dir;
 
 /// Current workspace file format version
-pub const WORKSPACE_VERSION: u32 = 1;
+///
+/// Bump this together with a new step in `WORKSPACE_MIGRATIONS`.
+pub const WORKSPACE_VERSION: u32 = 2;
 
 /// Current per-file workspace version
 pub const FILE_WORKSPACE_VERSION: u32 = 1;
@@ -52,6 +54,10 @@ pub struct Workspace {
     /// Active split ID
     pub active_split_id: usize,
 
+    /// Split maximized with Zoom Split, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub maximized_split_id: Option<usize>,
+
     /// Per-split view states (keyed by split_id)
     pub split_states: HashMap<usize, SerializedSplitViewState>,
 
@@ -74,6 +80,15 @@ pub struct Workspace {
     #[serde(default)]
     pub bookmarks: HashMap<char, SerializedBookmark>,
 
+    /// Anonymous line bookmarks (no register key)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub anonymous_bookmarks: Vec<SerializedBookmark>,
+
+    /// Named clipboard registers (only saved when `editor.persist_registers`
+    /// is enabled)
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub registers: HashMap<char, String>,
+
     /// Open terminal workspaces (for restoration)
     #[serde(default)]
     pub terminals: Vec<SerializedTerminalWorkspace>,
@@ -291,6 +306,11 @@ pub struct WorkspaceConfigOverrides {
     pub enable_inlay_hints: Option<bool>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub mouse_enabled: Option<bool>,
+    /// Theme key the user switched to while this workspace was active
+    /// (absent when they kept the configured theme). A theme that no
+    /// longer exists is ignored on restore.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub theme: Option<String>,
     /// Legacy: menu bar visibility was once stored as a per-workspace
     /// override here. It is now a global preference (`editor.show_menu_bar`),
     /// so this field is no longer written and is ignored on restore. Kept
@@ -548,90 +568,103 @@ pub fn get_workspaces_dir() -> io::Result<PathBuf> {
     Ok(get_data_dir()?.join("workspaces"))
 }
 
+/// Encoding of the filesystem root, which has no components to encode.
+/// `/` is always written as `_` elsewhere, so `%2F` cannot occur otherwise.
+const ROOT_PATH_ENCODING: &str = "%2F";
+
+/// Marker prepended to the encoding of relative paths, so that `a/b` and
+/// `/a/b` map to different names. `~` is percent-encoded everywhere else.
+const RELATIVE_PATH_MARKER: char = '~';
+
 /// Encode a path into a filesystem-safe filename using percent encoding
 ///
-/// Keeps alphanumeric chars, `-`, `.`, `_` as-is.
-/// Replaces `/` with `_` for readability.
-/// Percent-encodes other special characters as %XX.
+/// Keeps alphanumeric chars, `-`, `.` as-is.
+/// Replaces path separators with `_` for readability.
+/// Percent-encodes other special characters (including `_`) as %XX.
+/// The leading `/` of an absolute Unix path is dropped.
+///
+/// The encoding is injective: `decode_filename_to_path` recovers the
+/// original path for every UTF-8 input, so two different directories can
+/// never share a workspace file.
 ///
 /// Example: `/home/user/my project` -> `home_user_my%20project`
 pub fn encode_path_for_filename(path: &Path) -> String {
     let path_str = path.to_string_lossy();
+
     let mut result = String::with_capacity(path_str.len() * 2);
+    let body = if !path.is_absolute() {
+        result.push(RELATIVE_PATH_MARKER);
+        &path_str[..]
+    } else if cfg!(windows) {
+        // Absolute Windows paths start with a drive or UNC prefix, which is
+        // encoded like any other text.
+        &path_str[..]
+    } else {
+        // Absolute Unix paths all start with exactly one root separator
+        &path_str[1..]
+    };
+
+    if body.is_empty() && path.is_absolute() {
+        return ROOT_PATH_ENCODING.to_string();
+    }
 
-    for c in path_str.chars() {
+    for c in body.chars() {
         match c {
             // Path separators become underscores for readability
-            '/' | '\\' => result.push('_'),
+            c if std::path::is_separator(c) => result.push('_'),
             // Safe chars pass through
             c if c.is_ascii_alphanumeric() => result.push(c),
             '-' | '.' => result.push(c),
-            // Underscore needs special handling to avoid collision with /
-            '_' => result.push_str("%5F"),
-            // Everything else gets percent-encoded
+            // Everything else gets percent-encoded, including `_` (which
+            // would otherwise be read back as a separator)
             c => {
-                for byte in c.to_string().as_bytes() {
+                let mut buf = [0; 4];
+                for byte in c.encode_utf8(&mut buf).as_bytes() {
                     result.push_str(&format!("%{:02X}", byte));
                 }
             }
         }
     }
 
-    // Remove leading underscores (from leading /)
-    let result = result.trim_start_matches('_').to_string();
-
-    // Collapse multiple underscores
-    let mut final_result = String::with_capacity(result.len());
-    let mut last_was_underscore = false;
-    for c in result.chars() {
-        if c == '_' {
-            if !last_was_underscore {
-                final_result.push(c);
-            }
-            last_was_underscore = true;
-        } else {
-            final_result.push(c);
-            last_was_underscore = false;
-        }
-    }
-
-    if final_result.is_empty() {
-        final_result = "root".to_string();
-    }
-
-    final_result
+    result
 }
 
 /// Decode a filename back to the original path (for debugging/tooling)
+///
+/// Inverse of `encode_path_for_filename`. Returns None if `encoded` is not
+/// a valid encoding.
 #[allow(dead_code)]
 pub fn decode_filename_to_path(encoded: &str) -> Option<PathBuf> {
-    if encoded == "root" {
+    if encoded == ROOT_PATH_ENCODING {
         return Some(PathBuf::from("/"));
     }
 
-    let mut result = String::with_capacity(encoded.len() + 1);
-    // Re-add leading slash that was stripped during encoding
-    result.push('/');
-
-    let mut chars = encoded.chars().peekable();
-
-    while let Some(c) = chars.next() {
-        if c == '%' {
-            // Read two hex digits
-            let hex: String = chars.by_ref().take(2).collect();
-            if hex.len() == 2 {
-                if let Ok(byte) = u8::from_str_radix(&hex, 16) {
-                    result.push(byte as char);
-                }
+    let mut bytes = Vec::with_capacity(encoded.len() + 1);
+    let body = match encoded.strip_prefix(RELATIVE_PATH_MARKER) {
+        Some(rest) => rest,
+        None => {
+            // Re-add the root separator that was stripped during encoding
+            if !cfg!(windows) {
+                bytes.push(b'/');
             }
-        } else if c == '_' {
-            result.push('/');
-        } else {
-            result.push(c);
+            encoded
+        }
+    };
+
+    let mut input = body.bytes();
+    while let Some(b) = input.next() {
+        match b {
+            b'%' => {
+                let hex = [input.next()?, input.next()?];
+                let hex = std::str::from_utf8(&hex).ok()?;
+                bytes.push(u8::from_str_radix(hex, 16).ok()?);
+            }
+            b'_' => bytes.extend_from_slice(std::path::MAIN_SEPARATOR_STR.as_bytes()),
+            b => bytes.push(b),
         }
     }
 
-    Some(PathBuf::from(result))
+    String::from_utf8(bytes).ok().map(PathBuf::from)
 }
 
 /// Get the workspace file path for a working directory
@@ -729,8 +762,119 @@ impl From<serde_json::Error> for WorkspaceError {
     }
 }
 
+// ============================================================================
+// Version migrations
+// ============================================================================
+
+/// A migration step that upgrades workspace JSON by exactly one version.
+///
+/// Steps edit the raw JSON in place, so fields a step doesn't touch —
+/// including ones this build no longer knows about — pass through as-is.
+type WorkspaceMigration = fn(&mut serde_json::Value);
+
+/// Ordered migration steps: entry `i` upgrades version `i + 1` to `i + 2`.
+const WORKSPACE_MIGRATIONS: &[WorkspaceMigration] = &[migrate_v1_to_v2];
+
+/// Upgrade workspace JSON saved at version `from` to the current
+/// `WORKSPACE_VERSION` and deserialize it.
+///
+/// Returns `VersionTooNew` if `from` is newer than this build supports.
+pub fn migrate(
+    mut workspace_value: serde_json::Value,
+    from: u32,
+) -> Result<Workspace, WorkspaceError> {
+    if from > WORKSPACE_VERSION {
+        return Err(WorkspaceError::VersionTooNew {
+            version: from,
+            max_supported: WORKSPACE_VERSION,
+        });
+    }
+
+    // Version 0 never existed; treat it like the first format
+    for version in from.max(1)..WORKSPACE_VERSION {
+        tracing::debug!("Migrating workspace from version {}", version);
+        WORKSPACE_MIGRATIONS[(version - 1) as usize](&mut workspace_value);
+    }
+
+    if let Some(obj) = workspace_value.as_object_mut() {
+        obj.insert("version".to_string(), WORKSPACE_VERSION.into());
+    }
+
+    Ok(serde_json::from_value(workspace_value)?)
+}
+
+/// Version 1 -> 2: splits saved before tabs were tracked only have the
+/// deprecated `open_files`/`active_file_index` pair. Fill in `open_tabs`
+/// and `active_tab_index` from it. The old fields are kept.
+fn migrate_v1_to_v2(workspace: &mut serde_json::Value) {
+    let Some(split_states) = workspace
+        .get_mut("split_states")
+        .and_then(|v| v.as_object_mut())
+    else {
+        return;
+    };
+
+    for split_state in split_states.values_mut() {
+        let Some(state) = split_state.as_object_mut() else {
+            continue;
+        };
+        let has_tabs = state
+            .get("open_tabs")
+            .and_then(|v| v.as_array())
+            .is_some_and(|tabs| !tabs.is_empty());
+        if has_tabs {
+            continue;
+        }
+        let Some(open_files) = state.get("open_files").and_then(|v| v.as_array()) else {
+            continue;
+        };
+
+        let open_tabs: Vec<serde_json::Value> = open_files
+            .iter()
+            .map(|path| serde_json::json!({ "File": path }))
+            .collect();
+        let active_file_index = state
+            .get("active_file_index")
+            .and_then(|v| v.as_u64())
+            .unwrap_or(0);
+        let active_tab_index = if (active_file_index as usize) < open_tabs.len() {
+            serde_json::Value::from(active_file_index)
+        } else {
+            serde_json::Value::Null
+        };
+
+        state.insert("open_tabs".to_string(), open_tabs.into());
+        state.insert("active_tab_index".to_string(), active_tab_index);
+    }
+}
+
+/// Parse workspace JSON, migrating it from older versions.
+///
+/// Returns the workspace and whether a migration was applied (so the caller
+/// can re-save it in the current format).
+fn parse_workspace(content: &str) -> Result<(Workspace, bool), WorkspaceError> {
+    let value: serde_json::Value = serde_json::from_str(content)?;
+    let version = value
+        .get("version")
+        .and_then(|v| v.as_u64())
+        .map(|v| u32::try_from(v).unwrap_or(u32::MAX))
+        .unwrap_or(1);
+
+    if version > WORKSPACE_VERSION {
+        tracing::warn!(
+            "Workspace version {} is newer than supported {}",
+            version,
+            WORKSPACE_VERSION
+        );
+    }
+    let workspace = migrate(value, version)?;
+    Ok((workspace, version < WORKSPACE_VERSION))
+}
+
 impl Workspace {
     /// Load workspace for a working directory (if exists)
+    ///
+    /// Workspaces saved by older versions are migrated and re-saved.
     pub fn load(working_dir: &Path) -> Result<Option<Workspace>, WorkspaceError> {
         let path = get_workspace_path(working_dir)?;
         tracing::debug!("Looking for workspace at {:?}", path);
@@ -742,7 +886,7 @@ impl Workspace {
 
         tracing::debug!("Loading workspace from {:?}", path);
         let content = std::fs::read_to_string(&path)?;
-        let workspace: Workspace = serde_json::from_str(&content)?;
+        let (workspace, migrated) = parse_workspace(&content)?;
 
         tracing::debug!(
             "Loaded workspace: version={}, split_states={}, active_split={}",
@@ -769,17 +913,10 @@ impl Workspace {
             return Err(WorkspaceError::WorkdirMismatch { expected, found });
         }
 
-        // Check version compatibility
-        if workspace.version > WORKSPACE_VERSION {
-            tracing::warn!(
-                "Workspace version {} is newer than supported {}",
-                workspace.version,
-                WORKSPACE_VERSION
-            );
-            return Err(WorkspaceError::VersionTooNew {
-                version: workspace.version,
-                max_supported: WORKSPACE_VERSION,
-            });
+        if migrated {
+            if let Err(e) = workspace.save() {
+                tracing::warn!("Failed to re-save migrated workspace: {}", e);
+            }
         }
 
         Ok(Some(workspace))
@@ -834,16 +971,15 @@ impl Workspace {
         }
 
         let content = std::fs::read_to_string(&path)?;
-        let workspace: Workspace = serde_json::from_str(&content)?;
+        let (workspace, migrated) = parse_workspace(&content)?;
+        if migrated {
+            if let Err(e) = workspace.save_session(session_name) {
+                tracing::warn!("Failed to re-save migrated session workspace: {}", e);
+            }
+        }
 
         // For session workspaces, skip working_dir validation — the session
         // always restores its own workspace regardless of CWD.
-        if workspace.version > WORKSPACE_VERSION {
-            return Err(WorkspaceError::VersionTooNew {
-                version: workspace.version,
-                max_supported: WORKSPACE_VERSION,
-            });
-        }
 
         // If working_dir changed, log but still load (session owns its layout)
         let found = workspace
@@ -908,12 +1044,15 @@ impl Workspace {
                 role: None,
             },
             active_split_id: 0,
+            maximized_split_id: None,
             split_states: HashMap::new(),
             config_overrides: WorkspaceConfigOverrides::default(),
             file_explorer: FileExplorerState::default(),
             histories: WorkspaceHistories::default(),
             search_options: SearchOptions::default(),
             bookmarks: HashMap::new(),
+            anonymous_bookmarks: Vec::new(),
+            registers: HashMap::new(),
             terminals: Vec::new(),
             external_files: Vec::new(),
             read_only_files: Vec::new(),
@@ -983,9 +1122,146 @@ mod tests {
         let decoded = decode_filename_to_path(&encoded).unwrap();
         assert_eq!(decoded, PathBuf::from("/home/user/my_project"));
 
-        // Root path
+        // Root path (distinct from `/root`)
         let encoded = encode_path_for_filename(Path::new("/"));
-        assert_eq!(encoded, "root");
+        assert_eq!(encoded, "%2F");
+        assert_eq!(
+            decode_filename_to_path(&encoded).unwrap(),
+            PathBuf::from("/")
+        );
+        assert_eq!(encode_path_for_filename(Path::new("/root")), "root");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_percent_encoding_keeps_empty_and_trailing_components() {
+        let paths = ["/a/b", "/a//b", "/a/b/", "//a/b", "a/b", "/a_b"];
+        let encoded: Vec<String> = paths
+            .iter()
+            .map(|p| encode_path_for_filename(Path::new(p)))
+            .collect();
+        for (i, a) in encoded.iter().enumerate() {
+            for b in &encoded[i + 1..] {
+                assert_ne!(a, b, "encodings collide: {:?}", encoded);
+            }
+        }
+    }
+
+    #[cfg(unix)]
+    mod proptests {
+        use super::*;
+        use proptest::prelude::*;
+
+        /// Paths built from possibly-empty components (so `//` and trailing
+        /// separators occur), optionally absolute, with Unicode and the
+        /// characters the encoding treats specially.
+        fn path_strategy() -> impl Strategy<Value = String> {
+            (
+                any::<bool>(),
+                prop::collection::vec(r"[a-zA-Z0-9_.%~ é中\\-]{0,4}", 0..6),
+            )
+                .prop_map(|(absolute, components)| {
+                    let joined = components.join("/");
+                    if absolute {
+                        format!("/{}", joined)
+                    } else {
+                        joined
+                    }
+                })
+        }
+
+        proptest! {
+            #[test]
+            fn prop_encode_decode_round_trip(path in path_strategy()) {
+                let encoded = encode_path_for_filename(Path::new(&path));
+                prop_assert!(!encoded.contains('/'));
+                let decoded = decode_filename_to_path(&encoded).unwrap();
+                // Compare as strings: `Path` equality ignores repeated and
+                // trailing separators, which is exactly what must survive.
+                prop_assert_eq!(decoded.to_str().unwrap(), path.as_str());
+            }
+        }
+    }
+
+    #[test]
+    fn test_migrate_v1_workspace_without_data_loss() {
+        // A workspace as written by version 1, before tabs were tracked
+        let v1 = r#"{
+            "version": 1,
+            "working_dir": "/home/user/project",
+            "split_layout": {"Leaf": {"file_path": "src/main.rs", "split_id": 0}},
+            "active_split_id": 0,
+            "split_states": {
+                "0": {
+                    "open_files": ["src/lib.rs", "src/main.rs"],
+                    "active_file_index": 1,
+                    "file_states": {
+                        "src/main.rs": {
+                            "cursor": {"position": 42, "anchor": 40},
+                            "scroll": {"top_byte": 10},
+                            "folds": [{"header_line": 3, "end_line": 7}]
+                        }
+                    }
+                }
+            },
+            "config_overrides": {"line_wrap": false},
+            "file_explorer": {"visible": true, "expanded_dirs": ["src"]},
+            "bookmarks": {"a": {"file_path": "src/lib.rs", "position": 5}},
+            "external_files": ["/etc/hosts"],
+            "saved_at": 1700000000
+        }"#;
+
+        let (workspace, migrated) = parse_workspace(v1).unwrap();
+        assert!(migrated);
+        assert_eq!(workspace.version, WORKSPACE_VERSION);
+
+        // Tabs are filled in from the deprecated open_files list
+        let split = &workspace.split_states[&0];
+        let tabs: Vec<&Path> = split
+            .open_tabs
+            .iter()
+            .map(|tab| match tab {
+                SerializedTabRef::File(path) => path.as_path(),
+                other => panic!("unexpected tab {:?}", other),
+            })
+            .collect();
+        assert_eq!(
+            tabs,
+            vec![Path::new("src/lib.rs"), Path::new("src/main.rs")]
+        );
+        assert_eq!(split.active_tab_index, Some(1));
+        assert_eq!(split.open_files.len(), 2);
+
+        // Everything else survives unchanged
+        let file_state = &split.file_states[Path::new("src/main.rs")];
+        assert_eq!(file_state.cursor.position, 42);
+        assert_eq!(file_state.cursor.anchor, Some(40));
+        assert_eq!(file_state.scroll.top_byte, 10);
+        assert_eq!(file_state.folds.len(), 1);
+        assert_eq!(file_state.folds[0].end_line, 7);
+        assert_eq!(workspace.config_overrides.line_wrap, Some(false));
+        assert!(workspace.file_explorer.visible);
+        assert_eq!(
+            workspace.file_explorer.expanded_dirs,
+            vec![PathBuf::from("src")]
+        );
+        assert_eq!(workspace.bookmarks[&'a'].position, 5);
+        assert_eq!(workspace.external_files, vec![PathBuf::from("/etc/hosts")]);
+        assert_eq!(workspace.saved_at, 1700000000);
+
+        // Re-serialized form is current and parses without migrating again
+        let json = serde_json::to_string(&workspace).unwrap();
+        let (_, migrated_again) = parse_workspace(&json).unwrap();
+        assert!(!migrated_again);
+    }
+
+    #[test]
+    fn test_migrate_rejects_newer_version() {
+        let value = serde_json::json!({ "version": WORKSPACE_VERSION + 1 });
+        assert!(matches!(
+            migrate(value, WORKSPACE_VERSION + 1),
+            Err(WorkspaceError::VersionTooNew { .. })
+        ));
     }
 
     #[test]
@@ -1019,6 +1295,22 @@ mod tests {
         assert!(!json.contains("line_numbers")); // None values skipped
     }
 
+    #[test]
+    fn test_workspace_config_overrides_theme_round_trip() {
+        let overrides = WorkspaceConfigOverrides {
+            theme: Some("builtin://nord".to_string()),
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&overrides).unwrap();
+        let restored: WorkspaceConfigOverrides = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.theme.as_deref(), Some("builtin://nord"));
+
+        // Workspaces saved before the field existed have no theme override
+        let legacy: WorkspaceConfigOverrides =
+            serde_json::from_str(r#"{"line_wrap":true}"#).unwrap();
+        assert!(legacy.theme.is_none());
+    }
+
     #[test]
     fn test_split_layout_serialization() {
         // Create a nested split layout
@@ -1168,6 +1460,7 @@ mod tests {
             split_id: 0,
         };
         workspace.active_split_id = 1;
+        workspace.maximized_split_id = Some(1);
 
         // Add split state
         workspace.split_states.insert(
@@ -1208,6 +1501,7 @@ mod tests {
         assert_eq!(restored.version, WORKSPACE_VERSION);
         assert_eq!(restored.working_dir, PathBuf::from("/home/user/myproject"));
         assert_eq!(restored.active_split_id, 1);
+        assert_eq!(restored.maximized_split_id, Some(1));
         assert!(restored.bookmarks.contains_key(&'m'));
         assert!(restored.search_options.case_sensitive);
         assert!(restored.search_options.use_regex);
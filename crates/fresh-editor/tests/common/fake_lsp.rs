@@ -2235,6 +2235,503 @@ done
         dir.join("fake_lsp_server_drops_semantic_tokens.sh")
     }
 
+    /// Spawn a fake LSP server that answers `textDocument/signatureHelp`
+    /// with a two-parameter signature, `add(a: i32, b: i32)`. The active
+    /// parameter is the number of commas in the last synced document text
+    /// (capped at 1), so typing a `,` inside the call advances it.
+    pub fn spawn_with_signature_help(dir: &std::path::Path) -> anyhow::Result<Self> {
+        let (stop_tx, stop_rx) = mpsc::channel();
+
+        let script = r#"#!/bin/bash
+
+read_message() {
+    local content_length=0
+    while IFS= read -r line; do
+        line="${line%$'\r'}"
+        if [ -z "$line" ]; then
+            break
+        fi
+        case "$line" in
+            Content-Length:*)
+                content_length="${line#Content-Length:}"
+                content_length="${content_length// /}"
+                ;;
+        esac
+    done
+    if [ "$content_length" -gt 0 ] 2>/dev/null; then
+        dd bs=1 count="$content_length" 2>/dev/null
+    fi
+}
+
+send_message() {
+    local message="$1"
+    local length=${#message}
+    printf "Content-Length: %d\r\n\r\n%s" "$length" "$message"
+}
+
+doc_text=""
+
+while true; do
+    msg=$(read_message)
+    if [ -z "$msg" ]; then break; fi
+
+    method=$(echo "$msg" | grep -o '"method":"[^"]*"' | cut -d'"' -f4)
+    msg_id=$(echo "$msg" | grep -o '"id":[0-9]*' | head -1 | cut -d':' -f2)
+
+case "$method" in
+    "initialize")
+        send_message '{"jsonrpc":"2.0","id":'$msg_id',"result":{"capabilities":{"textDocumentSync":1,"signatureHelpProvider":{"triggerCharacters":["(",","]}}}}'
+        ;;
+    "textDocument/didOpen"|"textDocument/didChange")
+        # Full sync: remember the latest text
+        doc_text=$(echo "$msg" | grep -o '"text":"[^"]*"' | head -1)
+        ;;
+    "textDocument/signatureHelp")
+        commas=$(printf '%s' "$doc_text" | tr -cd ',' | wc -c)
+        active=$(( commas > 1 ? 1 : commas ))
+        send_message '{"jsonrpc":"2.0","id":'$msg_id',"result":{"signatures":[{"label":"add(a: i32, b: i32)","parameters":[{"label":"a: i32"},{"label":"b: i32"}]}],"activeSignature":0,"activeParameter":'$active'}}'
+        ;;
+    "textDocument/didSave"|"textDocument/didClose"|"initialized"|"$/cancelRequest")
+        # Notifications: no response.
+        ;;
+    "shutdown")
+        send_message '{"jsonrpc":"2.0","id":'$msg_id',"result":null}'
+        break
+        ;;
+    *)
+        if [ -n "$msg_id" ]; then
+            send_message '{"jsonrpc":"2.0","id":'$msg_id',"result":null}'
+        fi
+        ;;
+esac
+done
+"#;
+
+        let script_path = Self::signature_help_script_path(dir);
+        std::fs::write(&script_path, script)?;
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mut perms = std::fs::metadata(&script_path)?.permissions();
+            perms.set_mode(0o755);
+            std::fs::set_permissions(&script_path, perms)?;
+        }
+
+        let handle = Some(thread::spawn(move || {
+            let _ = stop_rx.recv();
+        }));
+
+        Ok(Self { handle, stop_tx })
+    }
+
+    /// Path to the signature-help fake LSP server script.
+    pub fn signature_help_script_path(dir: &std::path::Path) -> std::path::PathBuf {
+        dir.join("fake_lsp_server_signature_help.sh")
+    }
+
+    /// Spawn a fake LSP server whose completion items carry no documentation
+    /// until they are resolved with completionItem/resolve.
+    ///
+    /// Completion returns `alpha_fn`, `beta_fn` and `gamma_fn`. Resolving
+    /// `alpha_fn` or `beta_fn` adds a one-line markdown description; resolving
+    /// `gamma_fn` returns it unchanged. The `alpha_fn` response is delayed and
+    /// also carries an additional text edit inserting `use alpha;` at the top
+    /// of the file.
+    pub fn spawn_with_completion_resolve(dir: &std::path::Path) -> anyhow::Result<Self> {
+        let (stop_tx, stop_rx) = mpsc::channel();
+
+        let script = r#"#!/bin/bash
+
+read_message() {
+    local content_length=0
+    while IFS= read -r line; do
+        line="${line%$'\r'}"
+        if [ -z "$line" ]; then
+            break
+        fi
+        case "$line" in
+            Content-Length:*)
+                content_length="${line#Content-Length:}"
+                content_length="${content_length// /}"
+                ;;
+        esac
+    done
+    if [ "$content_length" -gt 0 ] 2>/dev/null; then
+        dd bs=1 count="$content_length" 2>/dev/null
+    fi
+}
+
+send_message() {
+    local message="$1"
+    local length=${#message}
+    printf "Content-Length: %d\r\n\r\n%s" "$length" "$message"
+}
+
+while true; do
+    msg=$(read_message)
+    if [ -z "$msg" ]; then break; fi
+
+    method=$(echo "$msg" | grep -o '"method":"[^"]*"' | cut -d'"' -f4)
+    msg_id=$(echo "$msg" | grep -o '"id":[0-9]*' | head -1 | cut -d':' -f2)
+
+case "$method" in
+    "initialize")
+        send_message '{"jsonrpc":"2.0","id":'$msg_id',"result":{"capabilities":{"textDocumentSync":1,"completionProvider":{"resolveProvider":true}}}}'
+        ;;
+    "textDocument/completion")
+        send_message '{"jsonrpc":"2.0","id":'$msg_id',"result":{"isIncomplete":false,"items":[{"label":"alpha_fn","kind":3},{"label":"beta_fn","kind":3},{"label":"gamma_fn","kind":3}]}}'
+        ;;
+    "completionItem/resolve")
+        label=$(echo "$msg" | grep -o '"label":"[^"]*"' | head -1 | cut -d'"' -f4)
+        case "$label" in
+            "alpha_fn")
+                sleep 0.3
+                send_message '{"jsonrpc":"2.0","id":'$msg_id',"result":{"label":"alpha_fn","kind":3,"documentation":{"kind":"markdown","value":"Alpha returns the first letter"},"additionalTextEdits":[{"range":{"start":{"line":0,"character":0},"end":{"line":0,"character":0}},"newText":"use alpha;\n"}]}}'
+                ;;
+            "beta_fn")
+                send_message '{"jsonrpc":"2.0","id":'$msg_id',"result":{"label":"beta_fn","kind":3,"documentation":{"kind":"markdown","value":"Beta returns the second letter"}}}'
+                ;;
+            *)
+                send_message '{"jsonrpc":"2.0","id":'$msg_id',"result":{"label":"'$label'","kind":3}}'
+                ;;
+        esac
+        ;;
+    "textDocument/didOpen"|"textDocument/didChange"|"textDocument/didSave"|"textDocument/didClose"|"initialized"|"$/cancelRequest")
+        # Notifications: no response.
+        ;;
+    "shutdown")
+        send_message '{"jsonrpc":"2.0","id":'$msg_id',"result":null}'
+        break
+        ;;
+    *)
+        if [ -n "$msg_id" ]; then
+            send_message '{"jsonrpc":"2.0","id":'$msg_id',"result":null}'
+        fi
+        ;;
+esac
+done
+"#;
+
+        let script_path = Self::completion_resolve_script_path(dir);
+        std::fs::write(&script_path, script)?;
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mut perms = std::fs::metadata(&script_path)?.permissions();
+            perms.set_mode(0o755);
+            std::fs::set_permissions(&script_path, perms)?;
+        }
+
+        let handle = Some(thread::spawn(move || {
+            let _ = stop_rx.recv();
+        }));
+
+        Ok(Self { handle, stop_tx })
+    }
+
+    /// Path to the completion-resolve fake LSP server script.
+    pub fn completion_resolve_script_path(dir: &std::path::Path) -> std::path::PathBuf {
+        dir.join("fake_lsp_server_completion_resolve.sh")
+    }
+
+    /// Spawn a fake LSP server whose rename edits span two files.
+    ///
+    /// `textDocument/rename` renames `helper` in the requested document (on
+    /// line 1, `    helper();`) and in the sibling `lib.rs` (on line 0,
+    /// `pub fn helper() {}`).
+    pub fn spawn_with_multi_file_rename(dir: &std::path::Path) -> anyhow::Result<Self> {
+        let (stop_tx, stop_rx) = mpsc::channel();
+
+        let script = r#"#!/bin/bash
+
+read_message() {
+    local content_length=0
+    while IFS= read -r line; do
+        line="${line%$'\r'}"
+        if [ -z "$line" ]; then
+            break
+        fi
+        case "$line" in
+            Content-Length:*)
+                content_length="${line#Content-Length:}"
+                content_length="${content_length// /}"
+                ;;
+        esac
+    done
+    if [ "$content_length" -gt 0 ] 2>/dev/null; then
+        dd bs=1 count="$content_length" 2>/dev/null
+    fi
+}
+
+send_message() {
+    local message="$1"
+    local length=${#message}
+    printf "Content-Length: %d\r\n\r\n%s" "$length" "$message"
+}
+
+while true; do
+    msg=$(read_message)
+    if [ -z "$msg" ]; then break; fi
+
+    method=$(echo "$msg" | grep -o '"method":"[^"]*"' | cut -d'"' -f4)
+    msg_id=$(echo "$msg" | grep -o '"id":[0-9]*' | head -1 | cut -d':' -f2)
+
+case "$method" in
+    "initialize")
+        send_message '{"jsonrpc":"2.0","id":'$msg_id',"result":{"capabilities":{"textDocumentSync":1,"renameProvider":true}}}'
+        ;;
+    "textDocument/rename")
+        uri=$(echo "$msg" | grep -o '"uri":"[^"]*"' | head -1 | cut -d'"' -f4)
+        new_name=$(echo "$msg" | grep -o '"newName":"[^"]*"' | head -1 | cut -d'"' -f4)
+        lib_uri="${uri%/*}/lib.rs"
+        send_message '{"jsonrpc":"2.0","id":'$msg_id',"result":{"changes":{"'$uri'":[{"range":{"start":{"line":1,"character":4},"end":{"line":1,"character":10}},"newText":"'$new_name'"}],"'$lib_uri'":[{"range":{"start":{"line":0,"character":7},"end":{"line":0,"character":13}},"newText":"'$new_name'"}]}}}'
+        ;;
+    "textDocument/didOpen"|"textDocument/didChange"|"textDocument/didSave"|"textDocument/didClose"|"initialized"|"$/cancelRequest")
+        # Notifications: no response.
+        ;;
+    "shutdown")
+        send_message '{"jsonrpc":"2.0","id":'$msg_id',"result":null}'
+        break
+        ;;
+    *)
+        if [ -n "$msg_id" ]; then
+            send_message '{"jsonrpc":"2.0","id":'$msg_id',"result":null}'
+        fi
+        ;;
+esac
+done
+"#;
+
+        let script_path = Self::multi_file_rename_script_path(dir);
+        std::fs::write(&script_path, script)?;
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mut perms = std::fs::metadata(&script_path)?.permissions();
+            perms.set_mode(0o755);
+            std::fs::set_permissions(&script_path, perms)?;
+        }
+
+        let handle = Some(thread::spawn(move || {
+            let _ = stop_rx.recv();
+        }));
+
+        Ok(Self { handle, stop_tx })
+    }
+
+    /// Path to the multi-file rename fake LSP server script.
+    pub fn multi_file_rename_script_path(dir: &std::path::Path) -> std::path::PathBuf {
+        dir.join("fake_lsp_server_multi_file_rename.sh")
+    }
+
+    /// Spawn a fake LSP server that publishes a fixed set of diagnostics for
+    /// every opened document.
+    ///
+    /// `diagnostics` is the JSON array sent as `publishDiagnostics` params.
+    /// Each `name` gets its own script, so several of these servers can be
+    /// configured for one language.
+    pub fn spawn_publishing_diagnostics(
+        dir: &std::path::Path,
+        name: &str,
+        diagnostics: &str,
+    ) -> anyhow::Result<Self> {
+        let (stop_tx, stop_rx) = mpsc::channel();
+
+        let script = format!(
+            r#"#!/bin/bash
+
+read_message() {{
+    local content_length=0
+    while IFS= read -r line; do
+        line="${{line%$'\r'}}"
+        if [ -z "$line" ]; then
+            break
+        fi
+        case "$line" in
+            Content-Length:*)
+                content_length="${{line#Content-Length:}}"
+                content_length="${{content_length// /}}"
+                ;;
+        esac
+    done
+    if [ "$content_length" -gt 0 ] 2>/dev/null; then
+        dd bs=1 count="$content_length" 2>/dev/null
+    fi
+}}
+
+send_message() {{
+    local message="$1"
+    local length=${{#message}}
+    printf "Content-Length: %d\r\n\r\n%s" "$length" "$message"
+}}
+
+while true; do
+    msg=$(read_message)
+    if [ -z "$msg" ]; then break; fi
+
+    method=$(echo "$msg" | grep -o '"method":"[^"]*"' | cut -d'"' -f4)
+    msg_id=$(echo "$msg" | grep -o '"id":[0-9]*' | head -1 | cut -d':' -f2)
+
+case "$method" in
+    "initialize")
+        send_message '{{"jsonrpc":"2.0","id":'$msg_id',"result":{{"capabilities":{{"textDocumentSync":1}}}}}}'
+        ;;
+    "textDocument/didOpen")
+        uri=$(echo "$msg" | grep -o '"uri":"[^"]*"' | head -1 | cut -d'"' -f4)
+        send_message '{{"jsonrpc":"2.0","method":"textDocument/publishDiagnostics","params":{{"uri":"'$uri'","diagnostics":{diagnostics}}}}}'
+        ;;
+    "textDocument/didChange"|"textDocument/didSave"|"textDocument/didClose"|"initialized"|"$/cancelRequest")
+        # Notifications: no response.
+        ;;
+    "shutdown")
+        send_message '{{"jsonrpc":"2.0","id":'$msg_id',"result":null}}'
+        break
+        ;;
+    *)
+        if [ -n "$msg_id" ]; then
+            send_message '{{"jsonrpc":"2.0","id":'$msg_id',"result":null}}'
+        fi
+        ;;
+esac
+done
+"#
+        );
+
+        let script_path = Self::publishing_diagnostics_script_path(dir, name);
+        std::fs::write(&script_path, script)?;
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mut perms = std::fs::metadata(&script_path)?.permissions();
+            perms.set_mode(0o755);
+            std::fs::set_permissions(&script_path, perms)?;
+        }
+
+        let handle = Some(thread::spawn(move || {
+            let _ = stop_rx.recv();
+        }));
+
+        Ok(Self { handle, stop_tx })
+    }
+
+    /// Path to the diagnostics-publishing fake LSP server script for `name`.
+    pub fn publishing_diagnostics_script_path(
+        dir: &std::path::Path,
+        name: &str,
+    ) -> std::path::PathBuf {
+        dir.join(format!("fake_lsp_server_diagnostics_{name}.sh"))
+    }
+
+    /// Spawn a fake LSP server that answers every completion request with
+    /// `completions` and every code action request with `code_actions`.
+    ///
+    /// Both are JSON arrays (of `CompletionItem`s and `CodeAction`s).
+    /// Resolving a completion item adds the documentation "Resolved by
+    /// `name`" and an additional text edit inserting `// from <name>` at the
+    /// top of the file, so tests can tell which server resolved it. Each
+    /// `name` gets its own script, so several of these servers can be
+    /// configured for one language.
+    pub fn spawn_offering(
+        dir: &std::path::Path,
+        name: &str,
+        completions: &str,
+        code_actions: &str,
+    ) -> anyhow::Result<Self> {
+        let (stop_tx, stop_rx) = mpsc::channel();
+
+        let script = format!(
+            r#"#!/bin/bash
+
+read_message() {{
+    local content_length=0
+    while IFS= read -r line; do
+        line="${{line%$'\r'}}"
+        if [ -z "$line" ]; then
+            break
+        fi
+        case "$line" in
+            Content-Length:*)
+                content_length="${{line#Content-Length:}}"
+                content_length="${{content_length// /}}"
+                ;;
+        esac
+    done
+    if [ "$content_length" -gt 0 ] 2>/dev/null; then
+        dd bs=1 count="$content_length" 2>/dev/null
+    fi
+}}
+
+send_message() {{
+    local message="$1"
+    local length=${{#message}}
+    printf "Content-Length: %d\r\n\r\n%s" "$length" "$message"
+}}
+
+while true; do
+    msg=$(read_message)
+    if [ -z "$msg" ]; then break; fi
+
+    method=$(echo "$msg" | grep -o '"method":"[^"]*"' | cut -d'"' -f4)
+    msg_id=$(echo "$msg" | grep -o '"id":[0-9]*' | head -1 | cut -d':' -f2)
+
+case "$method" in
+    "initialize")
+        send_message '{{"jsonrpc":"2.0","id":'$msg_id',"result":{{"capabilities":{{"textDocumentSync":1,"completionProvider":{{"resolveProvider":true}},"codeActionProvider":true}}}}}}'
+        ;;
+    "textDocument/completion")
+        send_message '{{"jsonrpc":"2.0","id":'$msg_id',"result":{{"isIncomplete":false,"items":{completions}}}}}'
+        ;;
+    "completionItem/resolve")
+        label=$(echo "$msg" | grep -o '"label":"[^"]*"' | head -1 | cut -d'"' -f4)
+        send_message '{{"jsonrpc":"2.0","id":'$msg_id',"result":{{"label":"'$label'","documentation":"Resolved by {name}","additionalTextEdits":[{{"range":{{"start":{{"line":0,"character":0}},"end":{{"line":0,"character":0}}}},"newText":"// from {name}\n"}}]}}}}'
+        ;;
+    "textDocument/codeAction")
+        send_message '{{"jsonrpc":"2.0","id":'$msg_id',"result":{code_actions}}}'
+        ;;
+    "textDocument/didOpen"|"textDocument/didChange"|"textDocument/didSave"|"textDocument/didClose"|"initialized"|"$/cancelRequest")
+        # Notifications: no response.
+        ;;
+    "shutdown")
+        send_message '{{"jsonrpc":"2.0","id":'$msg_id',"result":null}}'
+        break
+        ;;
+    *)
+        if [ -n "$msg_id" ]; then
+            send_message '{{"jsonrpc":"2.0","id":'$msg_id',"result":null}}'
+        fi
+        ;;
+esac
+done
+"#
+        );
+
+        let script_path = Self::offering_script_path(dir, name);
+        std::fs::write(&script_path, script)?;
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mut perms = std::fs::metadata(&script_path)?.permissions();
+            perms.set_mode(0o755);
+            std::fs::set_permissions(&script_path, perms)?;
+        }
+
+        let handle = Some(thread::spawn(move || {
+            let _ = stop_rx.recv();
+        }));
+
+        Ok(Self { handle, stop_tx })
+    }
+
+    /// Path to the completion/code-action fake LSP server script for `name`.
+    pub fn offering_script_path(dir: &std::path::Path, name: &str) -> std::path::PathBuf {
+        dir.join(format!("fake_lsp_server_offering_{name}.sh"))
+    }
+
     /// Stop the server
     pub fn stop(&mut self) {
         let _ = self.stop_tx.send(());
@@ -0,0 +1,218 @@
+//! Filesystem wrapper that injects faults into selected operations.
+//!
+//! Wraps another `FileSystem` (usually `StdFileSystem`) and delegates to it
+//! until a fault is armed, so tests can reproduce failures that are hard to
+//! trigger on a real disk.
+
+use fresh::model::filesystem::{
+    DirEntry, FileMetadata, FilePermissions, FileReader, FileSystem, FileWriter,
+};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+pub struct FaultInjectingFileSystem {
+    inner: Arc<dyn FileSystem>,
+    /// When true, rename() returns PermissionDenied (a non-EXDEV error) so
+    /// we can observe whether the caller incorrectly falls back to
+    /// copy+delete.
+    fail_rename_with_eacces: AtomicBool,
+    /// When true, rename() returns CrossesDevices (EXDEV) so the caller
+    /// exercises the cross-fs copy+delete fallback path.
+    fail_rename_with_exdev: AtomicBool,
+    /// When set, copy() returns PermissionDenied whenever the *destination*
+    /// path's file name contains this substring. Lets tests simulate a
+    /// recursive copy that succeeds for some children and fails for others.
+    poison_copy_substring: std::sync::Mutex<Option<String>>,
+    /// When true, remove_file / remove_dir return PermissionDenied — used
+    /// to exercise the "copy succeeded but source could not be removed"
+    /// edge case of the cross-fs cut fallback.
+    fail_remove_with_eacces: AtomicBool,
+    /// When true, write_file() writes half the data to the temp file it
+    /// would rename into place and then fails, as if the process died or
+    /// the disk filled up mid-write.
+    interrupt_write_file: AtomicBool,
+}
+
+impl FaultInjectingFileSystem {
+    pub fn new(inner: Arc<dyn FileSystem>) -> Self {
+        Self {
+            inner,
+            fail_rename_with_eacces: AtomicBool::new(false),
+            fail_rename_with_exdev: AtomicBool::new(false),
+            poison_copy_substring: std::sync::Mutex::new(None),
+            fail_remove_with_eacces: AtomicBool::new(false),
+            interrupt_write_file: AtomicBool::new(false),
+        }
+    }
+
+    pub fn arm_rename_eacces(&self) {
+        self.fail_rename_with_eacces.store(true, Ordering::SeqCst);
+    }
+
+    pub fn arm_rename_exdev(&self) {
+        self.fail_rename_with_exdev.store(true, Ordering::SeqCst);
+    }
+
+    pub fn arm_remove_eacces(&self) {
+        self.fail_remove_with_eacces.store(true, Ordering::SeqCst);
+    }
+
+    pub fn arm_interrupted_write(&self) {
+        self.interrupt_write_file.store(true, Ordering::SeqCst);
+    }
+
+    pub fn disarm_interrupted_write(&self) {
+        self.interrupt_write_file.store(false, Ordering::SeqCst);
+    }
+
+    pub fn arm_copy_poison(&self, substring: &str) {
+        *self.poison_copy_substring.lock().unwrap() = Some(substring.to_string());
+    }
+
+    fn poison_match(&self, dst: &Path) -> bool {
+        let Some(name) = dst.file_name().and_then(|n| n.to_str()) else {
+            return false;
+        };
+        match &*self.poison_copy_substring.lock().unwrap() {
+            Some(s) => name.contains(s),
+            None => false,
+        }
+    }
+}
+
+impl FileSystem for FaultInjectingFileSystem {
+    fn rename(&self, from: &Path, to: &Path) -> io::Result<()> {
+        if self.fail_rename_with_eacces.load(Ordering::SeqCst) {
+            return Err(io::Error::new(
+                io::ErrorKind::PermissionDenied,
+                "fault-injected: rename not permitted (EACCES)",
+            ));
+        }
+        if self.fail_rename_with_exdev.load(Ordering::SeqCst) {
+            return Err(io::Error::new(
+                io::ErrorKind::CrossesDevices,
+                "fault-injected: rename across filesystems (EXDEV)",
+            ));
+        }
+        self.inner.rename(from, to)
+    }
+
+    fn copy(&self, from: &Path, to: &Path) -> io::Result<u64> {
+        if self.poison_match(to) {
+            return Err(io::Error::new(
+                io::ErrorKind::PermissionDenied,
+                "fault-injected: copy of poisoned name refused",
+            ));
+        }
+        self.inner.copy(from, to)
+    }
+
+    // ---- boilerplate delegation to the inner filesystem ----
+    fn read_file(&self, path: &Path) -> io::Result<Vec<u8>> {
+        self.inner.read_file(path)
+    }
+    fn read_range(&self, path: &Path, offset: u64, len: usize) -> io::Result<Vec<u8>> {
+        self.inner.read_range(path, offset, len)
+    }
+    fn write_file(&self, path: &Path, data: &[u8]) -> io::Result<()> {
+        if self.interrupt_write_file.load(Ordering::SeqCst) {
+            let mut partial = self.inner.create_file(&self.temp_path_for(path))?;
+            partial.write_all(&data[..data.len() / 2])?;
+            return Err(io::Error::other("fault-injected: write interrupted"));
+        }
+        self.inner.write_file(path, data)
+    }
+    fn create_file(&self, path: &Path) -> io::Result<Box<dyn FileWriter>> {
+        self.inner.create_file(path)
+    }
+    fn open_file(&self, path: &Path) -> io::Result<Box<dyn FileReader>> {
+        self.inner.open_file(path)
+    }
+    fn open_file_for_write(&self, path: &Path) -> io::Result<Box<dyn FileWriter>> {
+        self.inner.open_file_for_write(path)
+    }
+    fn open_file_for_append(&self, path: &Path) -> io::Result<Box<dyn FileWriter>> {
+        self.inner.open_file_for_append(path)
+    }
+    fn set_file_length(&self, path: &Path, len: u64) -> io::Result<()> {
+        self.inner.set_file_length(path, len)
+    }
+    fn remove_file(&self, path: &Path) -> io::Result<()> {
+        if self.fail_remove_with_eacces.load(Ordering::SeqCst) {
+            return Err(io::Error::new(
+                io::ErrorKind::PermissionDenied,
+                "fault-injected: remove_file not permitted",
+            ));
+        }
+        self.inner.remove_file(path)
+    }
+    fn remove_dir(&self, path: &Path) -> io::Result<()> {
+        if self.fail_remove_with_eacces.load(Ordering::SeqCst) {
+            return Err(io::Error::new(
+                io::ErrorKind::PermissionDenied,
+                "fault-injected: remove_dir not permitted",
+            ));
+        }
+        self.inner.remove_dir(path)
+    }
+    fn metadata(&self, path: &Path) -> io::Result<FileMetadata> {
+        self.inner.metadata(path)
+    }
+    fn symlink_metadata(&self, path: &Path) -> io::Result<FileMetadata> {
+        self.inner.symlink_metadata(path)
+    }
+    fn is_dir(&self, path: &Path) -> io::Result<bool> {
+        self.inner.is_dir(path)
+    }
+    fn is_file(&self, path: &Path) -> io::Result<bool> {
+        self.inner.is_file(path)
+    }
+    fn set_permissions(&self, path: &Path, permissions: &FilePermissions) -> io::Result<()> {
+        self.inner.set_permissions(path, permissions)
+    }
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<DirEntry>> {
+        self.inner.read_dir(path)
+    }
+    fn create_dir(&self, path: &Path) -> io::Result<()> {
+        self.inner.create_dir(path)
+    }
+    fn create_dir_all(&self, path: &Path) -> io::Result<()> {
+        self.inner.create_dir_all(path)
+    }
+    fn canonicalize(&self, path: &Path) -> io::Result<PathBuf> {
+        self.inner.canonicalize(path)
+    }
+    fn current_uid(&self) -> u32 {
+        self.inner.current_uid()
+    }
+    fn sudo_write(
+        &self,
+        path: &Path,
+        data: &[u8],
+        mode: u32,
+        uid: u32,
+        gid: u32,
+    ) -> io::Result<()> {
+        self.inner.sudo_write(path, data, mode, uid, gid)
+    }
+    fn search_file(
+        &self,
+        path: &Path,
+        pattern: &str,
+        opts: &fresh::model::filesystem::FileSearchOptions,
+        cursor: &mut fresh::model::filesystem::FileSearchCursor,
+    ) -> io::Result<Vec<fresh::model::filesystem::SearchMatch>> {
+        fresh::model::filesystem::default_search_file(&*self.inner, path, pattern, opts, cursor)
+    }
+    fn walk_files(
+        &self,
+        root: &Path,
+        skip_dirs: &[&str],
+        cancel: &std::sync::atomic::AtomicBool,
+        on_file: &mut dyn FnMut(&Path, &str) -> bool,
+    ) -> io::Result<()> {
+        self.inner.walk_files(root, skip_dirs, cancel, on_file)
+    }
+}
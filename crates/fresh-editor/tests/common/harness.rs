@@ -1153,6 +1153,26 @@ impl EditorTestHarness {
         Ok(())
     }
 
+    /// Simulate a middle-click at specific coordinates
+    pub fn mouse_middle_click(&mut self, col: u16, row: u16) -> anyhow::Result<()> {
+        let mouse_down = MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Middle),
+            column: col,
+            row,
+            modifiers: KeyModifiers::empty(),
+        };
+        self.send_mouse(mouse_down)?;
+        let mouse_up = MouseEvent {
+            kind: MouseEventKind::Up(MouseButton::Middle),
+            column: col,
+            row,
+            modifiers: KeyModifiers::empty(),
+        };
+        self.send_mouse(mouse_up)?;
+        self.render()?;
+        Ok(())
+    }
+
     /// Simulate a mouse move (hover) at specific coordinates
     pub fn mouse_move(&mut self, col: u16, row: u16) -> anyhow::Result<()> {
         let mouse_event = MouseEvent {
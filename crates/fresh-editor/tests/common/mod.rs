@@ -8,6 +8,9 @@ pub mod blog_showcase;
 pub mod fake_lsp;
 #[cfg(test)]
 #[allow(dead_code)]
+pub mod fault_filesystem;
+#[cfg(test)]
+#[allow(dead_code)]
 pub mod fixtures;
 #[cfg(test)]
 #[allow(dead_code)]
@@ -178,6 +178,89 @@ fn test_fallback_copies_previous_indent() {
     );
 }
 
+/// Test that auto-inserted indentation is trimmed from a line left blank
+#[test]
+fn test_blank_auto_indent_trimmed_on_second_enter() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.txt");
+    std::fs::write(&file_path, "").unwrap();
+
+    let mut harness = harness_with_auto_indent();
+    harness.open_file(&file_path).unwrap();
+
+    harness.type_text("    indented line").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+
+    // The intermediate blank line loses its indent, the new line keeps it
+    let content = harness.get_buffer_content().unwrap();
+    assert_eq!(content, "    indented line\n\n    ");
+    assert_eq!(harness.cursor_position(), content.len());
+
+    // Leaving the auto-indented line by moving up trims it too
+    harness.send_key(KeyCode::Up, KeyModifiers::NONE).unwrap();
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "    indented line\n\n"
+    );
+}
+
+/// Test that the trim is undone together with the Enter that triggered it
+#[test]
+fn test_blank_auto_indent_trim_undoes_with_enter() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.txt");
+    std::fs::write(&file_path, "").unwrap();
+
+    let mut harness = harness_with_auto_indent();
+    harness.open_file(&file_path).unwrap();
+
+    harness.type_text("    indented line").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "    indented line\n\n    "
+    );
+
+    // A single undo brings back the trimmed indent and removes the newline
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "    indented line\n    "
+    );
+}
+
+/// Test that indentation typed by the user on a blank line is kept
+#[test]
+fn test_typed_blank_indent_not_trimmed() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.txt");
+    std::fs::write(&file_path, "").unwrap();
+
+    let mut harness = harness_with_auto_indent();
+    harness.open_file(&file_path).unwrap();
+
+    harness.type_text("line").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.type_text("  ").unwrap();
+    harness.send_key(KeyCode::Up, KeyModifiers::NONE).unwrap();
+
+    assert_eq!(harness.get_buffer_content().unwrap(), "line\n  ");
+}
+
 /// Test auto-indent with multi-cursor
 #[test]
 fn test_auto_indent_with_multi_cursor() {
@@ -247,6 +330,51 @@ fn test_auto_indent_disabled_by_config() {
     );
 }
 
+/// Test that a language's `auto_indent: false` overrides the global flag
+/// for that language only, while other languages keep smart indent.
+#[test]
+fn test_auto_indent_disabled_per_language() {
+    let temp_dir = TempDir::new().unwrap();
+    let rust_path = temp_dir.path().join("test.rs");
+    let python_path = temp_dir.path().join("test.py");
+    std::fs::write(&rust_path, "fn main() {").unwrap();
+    std::fs::write(&python_path, "def foo():").unwrap();
+
+    let mut config = Config::default();
+    config.editor.auto_indent = true;
+    config.languages.get_mut("rust").unwrap().auto_indent = false;
+    let mut harness = EditorTestHarness::create(
+        80,
+        24,
+        HarnessOptions::new()
+            .with_config(config)
+            .without_empty_plugins_dir(),
+    )
+    .unwrap();
+
+    // Rust opted out: Enter after `{` inserts a bare newline
+    harness.open_file(&rust_path).unwrap();
+    harness
+        .send_key(KeyCode::End, KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_buffer_content("fn main() {\n");
+
+    // Python still follows the global flag: Enter after `:` indents
+    harness.open_file(&python_path).unwrap();
+    harness
+        .send_key(KeyCode::End, KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_buffer_content("def foo():\n    ");
+}
+
 /// Test TypeScript indent with interface
 #[test]
 fn test_typescript_interface_indent() {
@@ -0,0 +1,164 @@
+//! E2E tests for bookmarks: the list prompt (entries show file, line and a
+//! preview; Enter jumps to the selection and Delete removes it) and
+//! anonymous line bookmarks cycled with next/prev.
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::input::keybindings::Action;
+
+const CONTENT: &str = "alpha\nbravo\ncharlie\ndelta\n";
+
+/// Bookmark the first and third lines, then return the cursor to the top.
+fn set_two_bookmarks(harness: &mut EditorTestHarness) {
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::SetBookmark('1'));
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::SetBookmark('2'));
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+        .unwrap();
+    assert_eq!(harness.cursor_position(), 0);
+}
+
+#[test]
+fn test_list_bookmarks_and_jump_to_second() {
+    let mut harness = EditorTestHarness::new(100, 24).unwrap();
+    let _fixture = harness.load_buffer_from_text(CONTENT).unwrap();
+    set_two_bookmarks(&mut harness);
+
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::ListBookmarks);
+    harness.render().unwrap();
+    assert!(harness.editor().is_prompting());
+    harness.assert_screen_contains("'1'  test_buffer.txt:1");
+    harness.assert_screen_contains("'2'  test_buffer.txt:3");
+    harness.assert_screen_contains("charlie");
+
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+
+    assert!(!harness.editor().is_prompting());
+    // "alpha\nbravo\n" is 12 bytes, so line 3 starts at 12
+    assert_eq!(harness.cursor_position(), 12);
+}
+
+#[test]
+fn test_list_bookmarks_delete_removes_entry() {
+    let mut harness = EditorTestHarness::new(100, 24).unwrap();
+    let _fixture = harness.load_buffer_from_text(CONTENT).unwrap();
+    set_two_bookmarks(&mut harness);
+
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::ListBookmarks);
+    harness
+        .send_key(KeyCode::Delete, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+
+    assert!(harness.editor().is_prompting());
+    harness.assert_screen_not_contains("'1'  test_buffer.txt:1");
+    harness.assert_screen_contains("'2'  test_buffer.txt:3");
+
+    // Removing the last bookmark closes the list
+    harness
+        .send_key(KeyCode::Delete, KeyModifiers::NONE)
+        .unwrap();
+    assert!(!harness.editor().is_prompting());
+
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::JumpToBookmark('2'));
+    assert_eq!(harness.cursor_position(), 0);
+}
+
+#[test]
+fn test_toggle_anonymous_bookmarks_and_cycle() {
+    let mut harness = EditorTestHarness::new(100, 24).unwrap();
+    let _fixture = harness.load_buffer_from_text(CONTENT).unwrap();
+
+    // Mark "bravo" (6), "charlie" (12) and "delta" (20), toggling from
+    // mid-line so the marks land on the line starts
+    for _ in 0..3 {
+        harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+        harness
+            .send_key(KeyCode::Right, KeyModifiers::NONE)
+            .unwrap();
+        harness
+            .editor_mut()
+            .dispatch_action_for_tests(Action::ToggleBookmark);
+        harness.send_key(KeyCode::Home, KeyModifiers::NONE).unwrap();
+    }
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+        .unwrap();
+
+    let mut visited = Vec::new();
+    for _ in 0..4 {
+        harness
+            .editor_mut()
+            .dispatch_action_for_tests(Action::NextBookmark);
+        visited.push(harness.cursor_position());
+    }
+    assert_eq!(visited, vec![6, 12, 20, 6], "next wraps to the first mark");
+
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::PrevBookmark);
+    assert_eq!(harness.cursor_position(), 20, "prev wraps to the last mark");
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::PrevBookmark);
+    assert_eq!(harness.cursor_position(), 12);
+
+    // Toggling again on "charlie" removes its mark
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::ToggleBookmark);
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::NextBookmark);
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::NextBookmark);
+    assert_eq!(harness.cursor_position(), 20);
+}
+
+#[test]
+fn test_anonymous_bookmark_follows_edits() {
+    let mut harness = EditorTestHarness::new(100, 24).unwrap();
+    let _fixture = harness.load_buffer_from_text(CONTENT).unwrap();
+
+    // Mark "charlie", then insert a line above it
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::ToggleBookmark);
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("new").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::NextBookmark);
+    // "new\nalpha\nbravo\n" is 16 bytes
+    assert_eq!(harness.cursor_position(), 16);
+}
@@ -4,6 +4,7 @@
 //! - Toggle Tab Indicators
 //! - Toggle Line Numbers
 //! - Reset Buffer Settings
+//! - Show Indentation (detected on open)
 
 use crate::common::harness::EditorTestHarness;
 use crossterm::event::{KeyCode, KeyModifiers};
@@ -209,6 +210,102 @@ fn test_set_tab_size_command() {
     );
 }
 
+/// Test that whitespace indicators render tabs as `→` and interior spaces as
+/// `·` without touching the buffer, and that trailing whitespace gets its own
+/// theme color.
+#[test]
+fn test_whitespace_indicators_render_tabs_and_spaces() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.txt");
+    std::fs::write(&file_path, "a\tb c  \n").unwrap();
+
+    let mut config = Config::default();
+    config.editor.whitespace_spaces_inner = true;
+    config.editor.whitespace_spaces_trailing = true;
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    let screen = harness.screen_to_string();
+    let (row, line) = screen
+        .lines()
+        .enumerate()
+        .find(|(_, line)| line.contains("b·c··"))
+        .unwrap_or_else(|| panic!("expected b·c·· on screen. Screen:\n{}", screen));
+    assert!(line.contains("a→"), "tab should render as →: {}", line);
+    harness.assert_buffer_content("a\tb c  \n");
+
+    // The interior dot and the trailing dots use different colors
+    let chars: Vec<char> = line.chars().collect();
+    let b_col = chars.iter().position(|&c| c == 'b').unwrap();
+    let inner = harness
+        .get_cell_style((b_col + 1) as u16, row as u16)
+        .unwrap();
+    let trailing = harness
+        .get_cell_style((b_col + 3) as u16, row as u16)
+        .unwrap();
+    assert_ne!(
+        inner.fg, trailing.fg,
+        "trailing whitespace should be rendered distinctly"
+    );
+
+    run_command(&mut harness, "Toggle Whitespace Indicators");
+    let screen = harness.screen_to_string();
+    assert!(
+        !screen.contains('→') && !screen.contains("b·c"),
+        "toggling off should hide all indicators. Screen:\n{}",
+        screen
+    );
+}
+
+/// Test that a whitespace-only line is colored as trailing whitespace
+#[test]
+fn test_whitespace_only_line_uses_trailing_color() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.txt");
+    std::fs::write(&file_path, "b c  \n   \n").unwrap();
+
+    let mut config = Config::default();
+    config.editor.whitespace_spaces_inner = true;
+    config.editor.whitespace_spaces_trailing = true;
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    let screen = harness.screen_to_string();
+    let lines: Vec<&str> = screen.lines().collect();
+    let text_row = lines
+        .iter()
+        .position(|line| line.contains("b·c··"))
+        .unwrap_or_else(|| panic!("expected b·c·· on screen. Screen:\n{}", screen));
+    let chars: Vec<char> = lines[text_row].chars().collect();
+    let b_col = chars.iter().position(|&c| c == 'b').unwrap();
+    let blank_chars: Vec<char> = lines[text_row + 1].chars().collect();
+    assert_eq!(
+        &blank_chars[b_col..b_col + 3],
+        &['·', '·', '·'],
+        "whitespace-only line should show its spaces. Screen:\n{}",
+        screen
+    );
+
+    let inner = harness
+        .get_cell_style((b_col + 1) as u16, text_row as u16)
+        .unwrap();
+    let trailing = harness
+        .get_cell_style((b_col + 3) as u16, text_row as u16)
+        .unwrap();
+    for col in b_col..b_col + 3 {
+        let blank = harness
+            .get_cell_style(col as u16, (text_row + 1) as u16)
+            .unwrap();
+        assert_eq!(
+            blank.fg, trailing.fg,
+            "whitespace-only line should use the trailing whitespace color"
+        );
+        assert_ne!(blank.fg, inner.fg);
+    }
+}
+
 /// Test that "Reset Buffer Settings" restores config defaults
 #[test]
 fn test_reset_buffer_settings_command() {
@@ -416,3 +513,50 @@ fn test_toggle_line_numbers_persists_across_file_changes() {
     // Verify the edited content is still visible
     harness.assert_screen_contains("Edited line 5");
 }
+
+/// Indentation detected from a 2-space file overrides a tabs default, drives
+/// Tab, and is reported by "Show Indentation"
+#[test]
+fn test_detected_indentation_overrides_config() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.js");
+    std::fs::write(&file_path, "function a() {\n  if (x) {\n    y();\n  }\n}\n").unwrap();
+
+    let mut config = Config::default();
+    config.editor.use_tabs = true;
+    config.editor.tab_size = 8;
+    config.editor.detect_indentation = true;
+    let mut harness = EditorTestHarness::with_config(120, 24, config).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    run_command(&mut harness, "Show Indentation");
+    harness.assert_screen_contains("Indentation: spaces, width 2 (detected from file)");
+
+    // Tab indents by the detected two spaces
+    harness
+        .send_key(KeyCode::End, KeyModifiers::CONTROL)
+        .unwrap();
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE).unwrap();
+    harness.assert_buffer_content("function a() {\n  if (x) {\n    y();\n  }\n}\n  ");
+
+    // Without detection the configured tabs apply
+    let mut config = Config::default();
+    config.editor.use_tabs = true;
+    config.editor.detect_indentation = false;
+    let mut harness = EditorTestHarness::with_config(120, 24, config).unwrap();
+    harness.open_file(&file_path).unwrap();
+    run_command(&mut harness, "Show Indentation");
+    harness.assert_screen_contains("Indentation: tabs");
+    harness.assert_screen_contains("(from settings)");
+
+    // A language that sets use_tabs itself keeps it: Makefiles need tabs
+    let makefile = temp_dir.path().join("Makefile");
+    std::fs::write(&makefile, "all:\n  echo a\n  echo b\n    echo c\n").unwrap();
+    let mut config = Config::default();
+    config.editor.detect_indentation = true;
+    let mut harness = EditorTestHarness::with_config(120, 24, config).unwrap();
+    harness.open_file(&makefile).unwrap();
+    run_command(&mut harness, "Show Indentation");
+    harness.assert_screen_contains("Indentation: tabs");
+}
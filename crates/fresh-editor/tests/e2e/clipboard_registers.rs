@@ -0,0 +1,171 @@
+//! E2E tests for named clipboard registers.
+//!
+//! Copying into a named register (`"a`) leaves the clipboard (the unnamed
+//! register) alone, so a later plain copy does not lose the register's text.
+//! Cycle Paste swaps a fresh paste for older entries of the yank ring.
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::Config;
+use fresh::input::keybindings::Action;
+
+/// Select the text of the cursor's line (without the newline).
+fn select_line(harness: &mut EditorTestHarness) {
+    harness.send_key(KeyCode::Home, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::End, KeyModifiers::SHIFT).unwrap();
+}
+
+#[test]
+fn test_named_register_survives_plain_copy() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    // Keep the system clipboard out of the test
+    harness.editor_mut().set_clipboard_for_test(String::new());
+
+    harness.type_text("alpha\nbeta").unwrap();
+
+    // "ay on the first line
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+        .unwrap();
+    select_line(&mut harness);
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::CopyToRegister('a'));
+    assert_eq!(harness.editor().clipboard_content_for_test(), "");
+
+    // A plain copy of the second line overwrites the unnamed register only
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    select_line(&mut harness);
+    harness.editor_mut().dispatch_action_for_tests(Action::Copy);
+    assert_eq!(harness.editor().clipboard_content_for_test(), "beta");
+
+    // "ap pastes the register's text, not the clipboard's
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+    harness.type_text(" ").unwrap();
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::PasteFromRegister('a'));
+    harness.assert_buffer_content("alpha\nbeta alpha");
+}
+
+#[test]
+fn test_paste_from_register_picker_shows_previews() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.editor_mut().set_clipboard_for_test(String::new());
+
+    harness.type_text("first line\nsecond line").unwrap();
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+        .unwrap();
+    select_line(&mut harness);
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::CopyToRegister('q'));
+
+    harness
+        .send_key(KeyCode::End, KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text(" ").unwrap();
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::PromptPasteFromRegister);
+    harness.render().unwrap();
+
+    // The named register and its yank-ring copy are listed with a preview
+    harness.assert_screen_contains("'q'");
+    harness.assert_screen_contains("'1'");
+    harness.assert_screen_contains("first line");
+
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.assert_buffer_content("first line\nsecond line first line");
+}
+
+/// Copy each line of `alpha`, `beta`, `gamma` so the yank ring holds all
+/// three (newest first), then open an empty line at the end.
+fn fill_yank_ring(harness: &mut EditorTestHarness) {
+    harness.editor_mut().set_clipboard_for_test(String::new());
+    harness.type_text("alpha\nbeta\ngamma").unwrap();
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+        .unwrap();
+    for _ in 0..3 {
+        select_line(harness);
+        harness.editor_mut().dispatch_action_for_tests(Action::Copy);
+        harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    }
+    harness
+        .send_key(KeyCode::End, KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+}
+
+#[test]
+fn test_cycle_paste_walks_the_yank_ring() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    fill_yank_ring(&mut harness);
+
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::Paste);
+    harness.assert_buffer_content("alpha\nbeta\ngamma\ngamma");
+
+    // Each cycle swaps the pasted text for the next older copy, wrapping
+    for expected in ["beta", "alpha", "gamma"] {
+        harness
+            .editor_mut()
+            .dispatch_action_for_tests(Action::CyclePasteForward);
+        harness.assert_buffer_content(&format!("alpha\nbeta\ngamma\n{expected}"));
+    }
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::CyclePasteBackward);
+    harness.assert_buffer_content("alpha\nbeta\ngamma\nalpha");
+
+    // However often it was cycled, the paste is one undo step
+    harness.editor_mut().dispatch_action_for_tests(Action::Undo);
+    harness.assert_buffer_content("alpha\nbeta\ngamma\n");
+}
+
+#[test]
+fn test_cycle_paste_ends_after_another_edit() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    fill_yank_ring(&mut harness);
+
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::Paste);
+    harness.type_text("!").unwrap();
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::CyclePasteForward);
+    harness.assert_buffer_content("alpha\nbeta\ngamma\ngamma!");
+}
+
+/// With the undo history at its cap every edit trims the oldest group, so
+/// the event log index no longer moves. Cycling must still work right after
+/// a paste and must not undo an unrelated edit made after it.
+#[test]
+fn test_cycle_paste_with_trimmed_undo_history() {
+    let mut config = Config::default();
+    config.editor.undo_history_max_groups = 2;
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    fill_yank_ring(&mut harness);
+
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::Paste);
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::CyclePasteForward);
+    harness.assert_buffer_content("alpha\nbeta\ngamma\nbeta");
+
+    harness.type_text("!").unwrap();
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::CyclePasteForward);
+    harness.assert_buffer_content("alpha\nbeta\ngamma\nbeta!");
+}
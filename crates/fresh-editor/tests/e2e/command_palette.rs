@@ -215,6 +215,55 @@ fn test_quick_open_file_path_line_only() {
         .expect("Cursor should jump to Ln 3, Col 1 after Quick Open");
 }
 
+/// Test Quick Open file mode finds a nested file by fuzzy query, skips
+/// gitignored files, and opens the selection
+#[test]
+fn test_quick_open_fuzzy_finds_nested_file() {
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    let mut harness =
+        EditorTestHarness::with_temp_project_and_config(100, 24, Default::default()).unwrap();
+    let project_root = harness.project_dir().unwrap();
+
+    fs::create_dir_all(project_root.join("src").join("widgets")).unwrap();
+    fs::write(
+        project_root
+            .join("src")
+            .join("widgets")
+            .join("fancy_button.rs"),
+        "// FANCY_MARKER\n",
+    )
+    .unwrap();
+    fs::create_dir(project_root.join("generated")).unwrap();
+    fs::write(
+        project_root.join("generated").join("fancy_button_gen.rs"),
+        "",
+    )
+    .unwrap();
+    fs::write(project_root.join(".gitignore"), "generated/\n").unwrap();
+
+    // Open Quick Open (command mode), then switch to file mode
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Backspace, KeyModifiers::NONE)
+        .unwrap();
+
+    harness.type_text("fncybtn").unwrap();
+    harness
+        .wait_until(|h| h.screen_to_string().contains("src/widgets/fancy_button.rs"))
+        .expect("Fuzzy query should surface the nested file");
+    harness.assert_screen_not_contains("fancy_button_gen.rs");
+
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .wait_until(|h| h.screen_to_string().contains("FANCY_MARKER"))
+        .expect("Selecting the suggestion should open the file");
+}
+
 /// Helper: write a fixture file with `n` lines of the form `LINEn\n`.
 ///
 /// Tests here use long files so the live-preview jump scrolls the viewport
@@ -897,6 +946,106 @@ fn test_goto_line_prompt_signed_input_is_relative_without_setting() {
         .expect("`-3` should be a relative jump regardless of the setting");
 }
 
+/// `line:column` jumps to a column on that line and `:column` moves within
+/// the current line. Columns are visual (tabs expanded) and clamp to the end
+/// of the line.
+#[test]
+fn test_goto_line_prompt_line_and_column() {
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    let mut harness =
+        EditorTestHarness::with_temp_project_and_config(100, 24, Default::default()).unwrap();
+    let project_root = harness.project_dir().unwrap();
+
+    let jump_path = project_root.join("jump.txt");
+    let mut content = "0123456789\n".repeat(10);
+    content.push_str("\tabc\n");
+    fs::write(&jump_path, content).unwrap();
+
+    harness.open_file(&jump_path).unwrap();
+    harness.render().unwrap();
+
+    let goto = |harness: &mut EditorTestHarness, input: &str, expected: &str| {
+        harness
+            .send_key(KeyCode::Char('g'), KeyModifiers::CONTROL)
+            .unwrap();
+        harness.type_text(input).unwrap();
+        harness
+            .send_key(KeyCode::Enter, KeyModifiers::NONE)
+            .unwrap();
+        harness
+            .wait_until(|h| h.screen_to_string().contains(expected))
+            .unwrap_or_else(|_| panic!("`{input}` should land on {expected}"));
+    };
+
+    goto(&mut harness, "10:5", "Ln 10, Col 5");
+    goto(&mut harness, ":3", "Ln 10, Col 3");
+    goto(&mut harness, ":99", "Ln 10, Col 11");
+    // The status message reports the clamped column, not the requested one
+    harness.assert_screen_contains("Jumped to line 10, column 11");
+    // Visual column 6 of "\tabc" (4-wide tab) is the 'b'
+    goto(&mut harness, "11:6", "Ln 11, Col 3");
+
+    // An invalid column is rejected like an invalid line
+    harness
+        .send_key(KeyCode::Char('g'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("10:x").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("Invalid line number: 10:x");
+    harness.assert_screen_contains("Ln 11, Col 3");
+}
+
+/// `+N`, `N%` and `$` jump relative to the cursor, through the file and to
+/// its last line, and each jump can be undone with Navigate Back.
+#[test]
+fn test_goto_line_prompt_percent_and_last_line() {
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    let mut harness =
+        EditorTestHarness::with_temp_project_and_config(100, 24, Default::default()).unwrap();
+    let project_root = harness.project_dir().unwrap();
+
+    let jump_path = project_root.join("jump.txt");
+    let content: Vec<String> = (1..=50).map(|i| format!("LINE{i}")).collect();
+    fs::write(&jump_path, content.join("\n")).unwrap();
+
+    harness.open_file(&jump_path).unwrap();
+    harness.render().unwrap();
+
+    let goto = |harness: &mut EditorTestHarness, input: &str, expected: &str| {
+        harness
+            .send_key(KeyCode::Char('g'), KeyModifiers::CONTROL)
+            .unwrap();
+        harness.type_text(input).unwrap();
+        harness
+            .send_key(KeyCode::Enter, KeyModifiers::NONE)
+            .unwrap();
+        harness
+            .wait_until(|h| h.screen_to_string().contains(expected))
+            .unwrap_or_else(|_| panic!("`{input}` should land on {expected}"));
+    };
+
+    goto(&mut harness, "10", "Ln 10,");
+    goto(&mut harness, "+5", "Ln 15,");
+    goto(&mut harness, "100%", "Ln 50,");
+    goto(&mut harness, "50%", "Ln 25,");
+    goto(&mut harness, "$", "Ln 50,");
+
+    // Navigate Back retraces the jumps
+    harness.send_key(KeyCode::Left, KeyModifiers::ALT).unwrap();
+    harness
+        .wait_until(|h| h.screen_to_string().contains("Ln 25,"))
+        .expect("Navigate Back should return to where `$` jumped from");
+    harness.send_key(KeyCode::Left, KeyModifiers::ALT).unwrap();
+    harness
+        .wait_until(|h| h.screen_to_string().contains("Ln 50,"))
+        .expect("Navigate Back should return to where `50%` jumped from");
+}
+
 /// Issue #1750: an unsigned line number is always absolute, even when the
 /// `relative_line_numbers` display setting is enabled.
 #[test]
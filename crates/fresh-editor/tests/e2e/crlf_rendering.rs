@@ -776,3 +776,41 @@ fn test_set_line_ending_converts_on_save_crlf_to_lf() {
         "All line endings should be converted to LF"
     );
 }
+
+/// Test that a file mixing CRLF and LF reports "Mixed" in the status bar,
+/// and that Normalize Line Endings rewrites it to the dominant format as a
+/// single undoable edit.
+#[test]
+fn test_normalize_mixed_line_endings_is_one_undo_step() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("mixed.txt");
+
+    let content = "Line 1\r\nLine 2\nLine 3\r\n";
+    std::fs::write(&file_path, content).unwrap();
+
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    harness.assert_screen_contains("Mixed (CRLF)");
+
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.wait_for_prompt().unwrap();
+    harness.type_text("Normalize Line Endings").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+
+    harness.assert_buffer_content("Line 1\r\nLine 2\r\nLine 3\r\n");
+    harness.assert_screen_not_contains("Mixed");
+
+    // One undo brings back the original mixed content
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_buffer_content(content);
+}
@@ -208,3 +208,108 @@ fn test_duplicate_line_undo() {
         "Undo should restore original content"
     );
 }
+
+/// Test duplicating a line down repeatedly, undoing one copy at a time
+#[test]
+fn test_duplicate_selection_down_repeated_and_undo() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+
+    harness.type_text("    hello").unwrap();
+    harness.render().unwrap();
+
+    for _ in 0..3 {
+        harness
+            .editor_mut()
+            .dispatch_action_for_tests(fresh::input::keybindings::Action::DuplicateSelectionDown);
+    }
+    harness.render().unwrap();
+
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "    hello\n    hello\n    hello\n    hello"
+    );
+    // Cursor follows the copies onto the last line, same column
+    assert_eq!(harness.cursor_position(), 39);
+
+    // Each duplicate is a single undo step
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "    hello\n    hello\n    hello"
+    );
+    for _ in 0..2 {
+        harness
+            .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+            .unwrap();
+    }
+    assert_eq!(harness.get_buffer_content().unwrap(), "    hello");
+}
+
+/// Test duplicating selected lines up leaves the selection on the copy
+#[test]
+fn test_duplicate_selection_up_keeps_selection_on_copy() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+
+    harness.type_text("one\ntwo\nthree").unwrap();
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+        .unwrap();
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness
+        .send_key(KeyCode::Down, KeyModifiers::SHIFT)
+        .unwrap();
+    harness.send_key(KeyCode::End, KeyModifiers::SHIFT).unwrap();
+    assert_eq!(harness.get_selected_text(), "two\nthree");
+
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(fresh::input::keybindings::Action::DuplicateSelectionUp);
+    harness.render().unwrap();
+
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "one\ntwo\nthree\ntwo\nthree"
+    );
+    assert_eq!(harness.get_selection_range(), Some(4..13));
+}
+
+/// Test duplicating with several cursors: each cursor's line is copied and
+/// every cursor moves onto its own copy
+#[test]
+fn test_duplicate_selection_down_multi_cursor() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+
+    harness.type_text("a1\nb2\nc3").unwrap();
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Right, KeyModifiers::NONE)
+        .unwrap();
+    harness.editor_mut().add_cursor_below();
+    assert_eq!(harness.cursor_count(), 2);
+
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(fresh::input::keybindings::Action::DuplicateSelectionDown);
+    harness.render().unwrap();
+
+    assert_eq!(harness.get_buffer_content().unwrap(), "a1\na1\nb2\nb2\nc3");
+    // Both cursors keep their column on the copy below their line
+    let mut positions: Vec<usize> = harness
+        .editor()
+        .active_cursors()
+        .iter()
+        .map(|(_, cursor)| cursor.position)
+        .collect();
+    positions.sort_unstable();
+    assert_eq!(positions, vec![4, 10]);
+
+    // The whole multi-cursor duplicate undoes in one step
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), "a1\nb2\nc3");
+}
@@ -0,0 +1,188 @@
+//! E2E tests for exporting an event log and replaying it into a fresh editor
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::Config;
+use fresh::model::event_stream::{EventStreamReader, RecordKind, EVENT_STREAM_VERSION};
+use tempfile::TempDir;
+
+/// Test that replaying an exported edit session reproduces the buffer
+#[test]
+fn test_replay_exported_event_log_reproduces_buffer() {
+    let temp_dir = TempDir::new().unwrap();
+    let file = temp_dir.path().join("notes.txt");
+    std::fs::write(&file, "first line\nsecond line\n").unwrap();
+    let log_path = temp_dir.path().join("session.jsonl");
+
+    let recorded = {
+        let mut harness = EditorTestHarness::with_config_and_working_dir(
+            80,
+            24,
+            Config::default(),
+            temp_dir.path().to_path_buf(),
+        )
+        .unwrap();
+        harness.open_file(&file).unwrap();
+
+        harness.type_text("new ").unwrap();
+        harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+        harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+        harness.type_text("!!").unwrap();
+        harness
+            .send_key(KeyCode::Backspace, KeyModifiers::NONE)
+            .unwrap();
+        let content = harness.get_buffer_content().unwrap();
+        assert_eq!(content, "new first line\nsecond line!\n");
+
+        let count = harness.editor_mut().export_event_log(&log_path).unwrap();
+        assert!(count > 0, "Export should write the recorded events");
+        content
+    };
+
+    // The file on disk is untouched; a fresh editor rebuilds the edits
+    let mut harness = EditorTestHarness::with_config_and_working_dir(
+        80,
+        24,
+        Config::default(),
+        temp_dir.path().to_path_buf(),
+    )
+    .unwrap();
+    let summary = harness.editor_mut().replay_event_log(&log_path).unwrap();
+    assert_eq!(summary.skipped, 0, "No event should be skipped");
+    assert!(summary.missing_files.is_empty());
+    harness.render().unwrap();
+
+    assert_eq!(harness.get_buffer_content().unwrap(), recorded);
+    assert_eq!(
+        harness.editor().active_state().buffer.file_path(),
+        Some(file.as_path()),
+        "Replay should open the recorded file"
+    );
+}
+
+/// Test that multi-cursor edits, recorded as batches of inserts and
+/// deletes, replay instead of being skipped, and that the export starts
+/// from the saved file rather than from when it was opened
+#[test]
+fn test_replay_multi_cursor_batch_since_save() {
+    let temp_dir = TempDir::new().unwrap();
+    let file = temp_dir.path().join("list.txt");
+    std::fs::write(&file, "one\ntwo\nthree\n").unwrap();
+    let log_path = temp_dir.path().join("session.jsonl");
+
+    let recorded = {
+        let mut harness = EditorTestHarness::with_config_and_working_dir(
+            80,
+            24,
+            Config::default(),
+            temp_dir.path().to_path_buf(),
+        )
+        .unwrap();
+        harness.open_file(&file).unwrap();
+
+        // Saved before the multi-cursor edit, so only the batch is exported
+        harness.type_text("- ").unwrap();
+        harness.editor_mut().save().unwrap();
+        harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+        harness.send_key(KeyCode::Home, KeyModifiers::NONE).unwrap();
+        harness.editor_mut().add_cursor_below();
+        harness.type_text("* ").unwrap();
+        harness
+            .send_key(KeyCode::Backspace, KeyModifiers::NONE)
+            .unwrap();
+        let content = harness.get_buffer_content().unwrap();
+        assert_eq!(content, "- one\n*two\n*three\n");
+
+        harness.editor_mut().export_event_log(&log_path).unwrap();
+        content
+    };
+    assert_eq!(
+        std::fs::read_to_string(&file).unwrap(),
+        "- one\ntwo\nthree\n"
+    );
+
+    let mut harness = EditorTestHarness::with_config_and_working_dir(
+        80,
+        24,
+        Config::default(),
+        temp_dir.path().to_path_buf(),
+    )
+    .unwrap();
+    let summary = harness.editor_mut().replay_event_log(&log_path).unwrap();
+    assert_eq!(summary.skipped, 0, "Batches should replay, not be skipped");
+    assert!(summary.applied > 0);
+    harness.render().unwrap();
+
+    assert_eq!(harness.get_buffer_content().unwrap(), recorded);
+}
+
+/// Test that events for a file that no longer exists are skipped instead
+/// of being applied to whatever buffer is open
+#[test]
+fn test_replay_skips_events_for_missing_file() {
+    let temp_dir = TempDir::new().unwrap();
+    let log_path = temp_dir.path().join("session.jsonl");
+    let missing = temp_dir.path().join("gone.txt");
+    let log = format!(
+        "{}\n{}\n",
+        serde_json::json!({ "type": "open_file", "path": missing }),
+        r#"{"event":{"Insert":{"position":0,"text":"hello","cursor_id":0}},"timestamp":0,"description":null}"#
+    );
+    std::fs::write(&log_path, log).unwrap();
+
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    let summary = harness.editor_mut().replay_event_log(&log_path).unwrap();
+
+    assert_eq!(summary.applied, 0);
+    assert_eq!(summary.skipped, 1);
+    assert_eq!(summary.missing_files, vec![missing]);
+    harness.assert_buffer_content("");
+}
+
+/// Test that keystrokes with streaming enabled produce a versioned stream of
+/// parseable JSON lines with strictly increasing timestamps
+#[test]
+fn test_event_stream_records_keystrokes_with_increasing_timestamps() {
+    let temp_dir = TempDir::new().unwrap();
+    let stream_path = temp_dir.path().join("stream.jsonl");
+
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness
+        .editor_mut()
+        .enable_event_streaming(&stream_path)
+        .unwrap();
+    harness.type_text("abc").unwrap();
+    harness.send_key(KeyCode::Left, KeyModifiers::NONE).unwrap();
+    let active = harness.editor().active_buffer();
+
+    let text = std::fs::read_to_string(&stream_path).unwrap();
+    for line in text.lines() {
+        serde_json::from_str::<serde_json::Value>(line)
+            .unwrap_or_else(|e| panic!("Line is not JSON ({e}): {line}"));
+    }
+
+    let records: Vec<_> = EventStreamReader::open(&stream_path)
+        .unwrap()
+        .collect::<std::io::Result<_>>()
+        .unwrap();
+    assert!(
+        matches!(
+            records[0].kind,
+            RecordKind::Header {
+                version: EVENT_STREAM_VERSION,
+                ..
+            }
+        ),
+        "The stream should start with a versioned header"
+    );
+    assert!(
+        records.windows(2).all(|w| w[0].time_us < w[1].time_us),
+        "Timestamps should strictly increase"
+    );
+
+    let count = |f: fn(&RecordKind) -> bool| records.iter().filter(|r| f(&r.kind)).count();
+    assert_eq!(count(|k| matches!(k, RecordKind::Keystroke { .. })), 4);
+    assert!(count(|k| matches!(k, RecordKind::Action { .. })) >= 4);
+    assert!(count(|k| matches!(k, RecordKind::Event { .. })) >= 4);
+    assert!(records[1..].iter().all(|r| r.buffer_id == Some(active)));
+}
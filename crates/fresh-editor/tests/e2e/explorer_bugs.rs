@@ -20,211 +20,15 @@
 //!    left on disk. The user sees an error but half of the source tree
 //!    now exists at the destination.
 
+use crate::common::fault_filesystem::FaultInjectingFileSystem;
 use crate::common::harness::{EditorTestHarness, HarnessOptions};
 use crossterm::event::{KeyCode, KeyModifiers};
 use fresh::config::Config;
-use fresh::model::filesystem::{
-    DirEntry, FileMetadata, FilePermissions, FileReader, FileSystem, FileWriter, StdFileSystem,
-};
+use fresh::model::filesystem::StdFileSystem;
 use std::fs;
-use std::io;
-use std::path::{Path, PathBuf};
-use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
 
-// ---------------------------------------------------------------------------
-// Test filesystem: wraps StdFileSystem and can be armed to inject faults
-// into rename() / copy() to reproduce bugs #2 and #3.
-// ---------------------------------------------------------------------------
-
-struct FaultInjectingFileSystem {
-    inner: Arc<dyn FileSystem>,
-    /// When true, rename() returns PermissionDenied (a non-EXDEV error) so
-    /// we can observe whether the caller incorrectly falls back to
-    /// copy+delete.
-    fail_rename_with_eacces: AtomicBool,
-    /// When true, rename() returns CrossesDevices (EXDEV) so the caller
-    /// exercises the cross-fs copy+delete fallback path.
-    fail_rename_with_exdev: AtomicBool,
-    /// When set, copy() returns PermissionDenied whenever the *destination*
-    /// path's file name contains this substring. Lets tests simulate a
-    /// recursive copy that succeeds for some children and fails for others.
-    poison_copy_substring: std::sync::Mutex<Option<String>>,
-    /// When true, remove_file / remove_dir return PermissionDenied — used
-    /// to exercise the "copy succeeded but source could not be removed"
-    /// edge case of the cross-fs cut fallback.
-    fail_remove_with_eacces: AtomicBool,
-}
-
-impl FaultInjectingFileSystem {
-    fn new(inner: Arc<dyn FileSystem>) -> Self {
-        Self {
-            inner,
-            fail_rename_with_eacces: AtomicBool::new(false),
-            fail_rename_with_exdev: AtomicBool::new(false),
-            poison_copy_substring: std::sync::Mutex::new(None),
-            fail_remove_with_eacces: AtomicBool::new(false),
-        }
-    }
-
-    fn arm_rename_eacces(&self) {
-        self.fail_rename_with_eacces.store(true, Ordering::SeqCst);
-    }
-
-    fn arm_rename_exdev(&self) {
-        self.fail_rename_with_exdev.store(true, Ordering::SeqCst);
-    }
-
-    fn arm_remove_eacces(&self) {
-        self.fail_remove_with_eacces.store(true, Ordering::SeqCst);
-    }
-
-    fn arm_copy_poison(&self, substring: &str) {
-        *self.poison_copy_substring.lock().unwrap() = Some(substring.to_string());
-    }
-
-    fn poison_match(&self, dst: &Path) -> bool {
-        let Some(name) = dst.file_name().and_then(|n| n.to_str()) else {
-            return false;
-        };
-        match &*self.poison_copy_substring.lock().unwrap() {
-            Some(s) => name.contains(s),
-            None => false,
-        }
-    }
-}
-
-impl FileSystem for FaultInjectingFileSystem {
-    fn rename(&self, from: &Path, to: &Path) -> io::Result<()> {
-        if self.fail_rename_with_eacces.load(Ordering::SeqCst) {
-            return Err(io::Error::new(
-                io::ErrorKind::PermissionDenied,
-                "fault-injected: rename not permitted (EACCES)",
-            ));
-        }
-        if self.fail_rename_with_exdev.load(Ordering::SeqCst) {
-            return Err(io::Error::new(
-                io::ErrorKind::CrossesDevices,
-                "fault-injected: rename across filesystems (EXDEV)",
-            ));
-        }
-        self.inner.rename(from, to)
-    }
-
-    fn copy(&self, from: &Path, to: &Path) -> io::Result<u64> {
-        if self.poison_match(to) {
-            return Err(io::Error::new(
-                io::ErrorKind::PermissionDenied,
-                "fault-injected: copy of poisoned name refused",
-            ));
-        }
-        self.inner.copy(from, to)
-    }
-
-    // ---- boilerplate delegation to the inner filesystem ----
-    fn read_file(&self, path: &Path) -> io::Result<Vec<u8>> {
-        self.inner.read_file(path)
-    }
-    fn read_range(&self, path: &Path, offset: u64, len: usize) -> io::Result<Vec<u8>> {
-        self.inner.read_range(path, offset, len)
-    }
-    fn write_file(&self, path: &Path, data: &[u8]) -> io::Result<()> {
-        self.inner.write_file(path, data)
-    }
-    fn create_file(&self, path: &Path) -> io::Result<Box<dyn FileWriter>> {
-        self.inner.create_file(path)
-    }
-    fn open_file(&self, path: &Path) -> io::Result<Box<dyn FileReader>> {
-        self.inner.open_file(path)
-    }
-    fn open_file_for_write(&self, path: &Path) -> io::Result<Box<dyn FileWriter>> {
-        self.inner.open_file_for_write(path)
-    }
-    fn open_file_for_append(&self, path: &Path) -> io::Result<Box<dyn FileWriter>> {
-        self.inner.open_file_for_append(path)
-    }
-    fn set_file_length(&self, path: &Path, len: u64) -> io::Result<()> {
-        self.inner.set_file_length(path, len)
-    }
-    fn remove_file(&self, path: &Path) -> io::Result<()> {
-        if self.fail_remove_with_eacces.load(Ordering::SeqCst) {
-            return Err(io::Error::new(
-                io::ErrorKind::PermissionDenied,
-                "fault-injected: remove_file not permitted",
-            ));
-        }
-        self.inner.remove_file(path)
-    }
-    fn remove_dir(&self, path: &Path) -> io::Result<()> {
-        if self.fail_remove_with_eacces.load(Ordering::SeqCst) {
-            return Err(io::Error::new(
-                io::ErrorKind::PermissionDenied,
-                "fault-injected: remove_dir not permitted",
-            ));
-        }
-        self.inner.remove_dir(path)
-    }
-    fn metadata(&self, path: &Path) -> io::Result<FileMetadata> {
-        self.inner.metadata(path)
-    }
-    fn symlink_metadata(&self, path: &Path) -> io::Result<FileMetadata> {
-        self.inner.symlink_metadata(path)
-    }
-    fn is_dir(&self, path: &Path) -> io::Result<bool> {
-        self.inner.is_dir(path)
-    }
-    fn is_file(&self, path: &Path) -> io::Result<bool> {
-        self.inner.is_file(path)
-    }
-    fn set_permissions(&self, path: &Path, permissions: &FilePermissions) -> io::Result<()> {
-        self.inner.set_permissions(path, permissions)
-    }
-    fn read_dir(&self, path: &Path) -> io::Result<Vec<DirEntry>> {
-        self.inner.read_dir(path)
-    }
-    fn create_dir(&self, path: &Path) -> io::Result<()> {
-        self.inner.create_dir(path)
-    }
-    fn create_dir_all(&self, path: &Path) -> io::Result<()> {
-        self.inner.create_dir_all(path)
-    }
-    fn canonicalize(&self, path: &Path) -> io::Result<PathBuf> {
-        self.inner.canonicalize(path)
-    }
-    fn current_uid(&self) -> u32 {
-        self.inner.current_uid()
-    }
-    fn sudo_write(
-        &self,
-        path: &Path,
-        data: &[u8],
-        mode: u32,
-        uid: u32,
-        gid: u32,
-    ) -> io::Result<()> {
-        self.inner.sudo_write(path, data, mode, uid, gid)
-    }
-    fn search_file(
-        &self,
-        path: &Path,
-        pattern: &str,
-        opts: &fresh::model::filesystem::FileSearchOptions,
-        cursor: &mut fresh::model::filesystem::FileSearchCursor,
-    ) -> io::Result<Vec<fresh::model::filesystem::SearchMatch>> {
-        fresh::model::filesystem::default_search_file(&*self.inner, path, pattern, opts, cursor)
-    }
-    fn walk_files(
-        &self,
-        root: &Path,
-        skip_dirs: &[&str],
-        cancel: &std::sync::atomic::AtomicBool,
-        on_file: &mut dyn FnMut(&Path, &str) -> bool,
-    ) -> io::Result<()> {
-        self.inner.walk_files(root, skip_dirs, cancel, on_file)
-    }
-}
-
 // ---------------------------------------------------------------------------
 // Bug #1: stale NodeIds in multi_selection after cut+paste
 // ---------------------------------------------------------------------------
@@ -1405,6 +1209,173 @@ fn test_delete_closes_open_buffer_for_deleted_file() {
     );
 }
 
+/// Deleting a file force-closes its buffer, so the confirmation prompt
+/// must warn when that buffer has unsaved edits.
+#[test]
+fn test_delete_prompt_warns_about_unsaved_changes() {
+    let mut harness = EditorTestHarness::with_temp_project(120, 40).unwrap();
+    let project_root = harness.project_dir().unwrap();
+    fs::write(project_root.join("victim.txt"), "v").unwrap();
+
+    harness
+        .send_key(KeyCode::Char('e'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.wait_for_file_explorer().unwrap();
+    harness.wait_for_file_explorer_item("victim.txt").unwrap();
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap(); // victim.txt
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.type_text("edited ").unwrap();
+
+    harness
+        .send_key(KeyCode::Char('e'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.wait_for_file_explorer_item("victim.txt").unwrap();
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap(); // victim.txt
+    harness
+        .send_key(KeyCode::Delete, KeyModifiers::NONE)
+        .unwrap();
+    harness.wait_for_prompt().unwrap();
+    harness.assert_screen_contains("Unsaved changes will be lost.");
+
+    // Declining keeps both the file and the edited buffer
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.wait_for_prompt_closed().unwrap();
+    assert!(project_root.join("victim.txt").exists());
+    assert_eq!(harness.get_buffer_content().unwrap(), "edited v");
+}
+
+/// Creating a file with a directory selected puts it inside that
+/// directory under the name typed at the prompt, and the buffer it opens
+/// saves to that file.
+#[test]
+fn test_new_file_is_created_in_selected_directory() {
+    let mut harness = EditorTestHarness::with_temp_project(120, 40).unwrap();
+    let project_root = harness.project_dir().unwrap();
+    fs::create_dir(project_root.join("subdir")).unwrap();
+
+    harness
+        .send_key(KeyCode::Char('e'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.wait_for_file_explorer().unwrap();
+    harness.wait_for_file_explorer_item("subdir").unwrap();
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap(); // subdir
+    harness
+        .send_key(KeyCode::Char('n'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.wait_for_prompt().unwrap();
+    harness.type_text("created.txt").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.wait_for_prompt_closed().unwrap();
+
+    let created = project_root.join("subdir").join("created.txt");
+    assert!(created.exists(), "new file must land in the selected dir");
+    assert!(
+        !project_root.join("created.txt").exists(),
+        "new file must not be created next to the selected dir"
+    );
+    let leftovers: Vec<_> = fs::read_dir(project_root.join("subdir"))
+        .unwrap()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_name().to_string_lossy().starts_with("untitled_"))
+        .collect();
+    assert!(
+        leftovers.is_empty(),
+        "placeholder file must be renamed, found {:?}",
+        leftovers
+    );
+
+    // Focus is on the new buffer; saving writes the renamed file.
+    harness.type_text("SENTINEL").unwrap();
+    harness
+        .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .wait_until(|_| {
+            fs::read_to_string(&created)
+                .map(|s| s.contains("SENTINEL"))
+                .unwrap_or(false)
+        })
+        .unwrap();
+}
+
+/// Renaming an open file in the explorer moves its buffer to the new path,
+/// so a later save writes the renamed file instead of recreating the old
+/// one.
+#[test]
+fn test_rename_open_file_redirects_save_to_new_path() {
+    let mut harness = EditorTestHarness::with_temp_project(120, 40).unwrap();
+    let project_root = harness.project_dir().unwrap();
+    fs::write(project_root.join("before.txt"), "orig\n").unwrap();
+
+    // Open before.txt as a permanent tab (Enter also focuses the editor).
+    harness
+        .send_key(KeyCode::Char('e'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.wait_for_file_explorer().unwrap();
+    harness.wait_for_file_explorer_item("before.txt").unwrap();
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap(); // before.txt
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+
+    // Back to the explorer, F2 to rename.
+    harness
+        .send_key(KeyCode::Char('e'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.wait_for_file_explorer_item("before.txt").unwrap();
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap(); // before.txt
+    harness.send_key(KeyCode::F(2), KeyModifiers::NONE).unwrap();
+    harness.wait_for_prompt().unwrap();
+    // Clear the default-filled name and type the new one.
+    for _ in 0..16 {
+        harness
+            .send_key(KeyCode::Backspace, KeyModifiers::NONE)
+            .unwrap();
+    }
+    harness.type_text("after.txt").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.wait_for_prompt_closed().unwrap();
+
+    let before = project_root.join("before.txt");
+    let after = project_root.join("after.txt");
+    assert!(after.exists(), "rename must have landed on disk");
+    assert!(!before.exists(), "old file must be gone after rename");
+    harness.wait_for_file_explorer_item("after.txt").unwrap();
+
+    // Back to the editor, append a sentinel, Ctrl+S to save.
+    harness
+        .send_key(KeyCode::Char('e'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .send_key(KeyCode::End, KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("SENTINEL").unwrap();
+    harness
+        .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        .unwrap();
+
+    harness
+        .wait_until(|_| {
+            fs::read_to_string(&after)
+                .map(|s| s == "orig\nSENTINEL")
+                .unwrap_or(false)
+        })
+        .unwrap();
+    assert!(
+        !before.exists(),
+        "save must not recreate the file at the old path: {:?}",
+        before
+    );
+}
+
 /// Renaming a directory in the explorer used to leave any buffer for a
 /// file *under* that directory still pointing at the old path — so a
 /// subsequent Ctrl+S wrote to the old (now-gone) location, recreating
@@ -3350,6 +3350,120 @@ fn test_file_explorer_git_markers_clear_after_external_commit() {
     );
 }
 
+/// Reveal in File Explorer opens the hidden explorer, expands the tree to
+/// the active file and selects it.
+#[test]
+fn test_reveal_in_explorer_selects_nested_file() {
+    let mut harness = EditorTestHarness::with_temp_project(120, 40).unwrap();
+    let project_root = harness.project_dir().unwrap();
+    fs::create_dir_all(project_root.join("src/deep")).unwrap();
+    let nested = project_root.join("src/deep/target.rs");
+    fs::write(&nested, "fn main() {}\n").unwrap();
+
+    harness.open_file(&nested).unwrap();
+    assert!(!harness.editor().file_explorer_visible());
+
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("Reveal in File Explorer").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+
+    harness
+        .wait_until(|h| {
+            h.editor()
+                .file_explorer()
+                .and_then(|explorer| explorer.get_selected_entry())
+                .is_some_and(|entry| entry.path == nested)
+        })
+        .unwrap();
+    assert!(harness.editor().file_explorer_visible());
+    harness.assert_screen_contains("target.rs");
+}
+
+/// Files outside the working directory can't be revealed; the action says
+/// so instead of failing.
+#[test]
+fn test_reveal_in_explorer_outside_working_dir() {
+    let mut harness = EditorTestHarness::with_temp_project(120, 40).unwrap();
+    let outside_dir = tempfile::tempdir().unwrap();
+    let outside = outside_dir.path().join("elsewhere.txt");
+    fs::write(&outside, "x").unwrap();
+
+    harness.open_file(&outside).unwrap();
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("Reveal in File Explorer").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+
+    harness.assert_screen_contains("elsewhere.txt is outside the working directory");
+    assert!(!harness.editor().file_explorer_visible());
+}
+
+/// Saving a tracked file from the editor refreshes the git status markers,
+/// even when the save lands while an earlier refresh is still running.
+#[test]
+#[cfg_attr(windows, ignore)]
+fn test_file_explorer_git_marker_appears_after_save() {
+    let repo = GitTestRepo::new();
+    repo.setup_git_explorer_plugin();
+    repo.create_file("notes.txt", "clean\n");
+    repo.git_add_all();
+    repo.git_commit("Initial commit");
+
+    let mut harness = EditorTestHarness::with_working_dir(120, 40, repo.path.clone()).unwrap();
+    harness.open_file(&repo.path.join("notes.txt")).unwrap();
+    harness.type_text("dirty ").unwrap();
+    harness
+        .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        .unwrap();
+
+    harness.editor_mut().toggle_file_explorer();
+    harness
+        .wait_until(|h| {
+            h.screen_to_string()
+                .lines()
+                .any(|line| line.contains("notes.txt") && line.contains("M"))
+        })
+        .unwrap();
+}
+
+/// Outside a git repository the plugin has nothing to report and must not
+/// leave any status markers behind.
+#[test]
+#[cfg_attr(windows, ignore)]
+fn test_file_explorer_no_git_markers_outside_repo() {
+    let repo = GitTestRepo::new();
+    repo.setup_git_explorer_plugin();
+    fs::remove_dir_all(repo.path.join(".git")).unwrap();
+    repo.create_file("loose.txt", "text");
+
+    let mut harness = EditorTestHarness::with_working_dir(120, 40, repo.path.clone()).unwrap();
+    harness.editor_mut().toggle_file_explorer();
+    harness.wait_for_file_explorer_item("loose.txt").unwrap();
+
+    // Give the plugin's git invocations time to finish
+    for _ in 0..10 {
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        harness.process_async_and_render().unwrap();
+    }
+
+    let screen = harness.screen_to_string();
+    assert!(
+        !screen
+            .lines()
+            .any(|line| line.contains("loose.txt") && line.contains(" U")),
+        "no untracked marker expected outside a git repo:\n{}",
+        screen
+    );
+}
+
 /// The rendered file explorer panel has a hard minimum width of 5 columns,
 /// independent of whatever the config or the restored workspace tries to
 /// set it to. A 0/1/2-column explorer has no room for a readable tree and
@@ -150,3 +150,79 @@ fn test_goto_matching_bracket_from_inside_outer_of_nested() {
         "From inside outer parens, should jump to nearest closing ')'"
     );
 }
+
+// =============================================================================
+// Highlighting the pair, and brackets next to (not under) the cursor
+// =============================================================================
+
+/// Foreground color of the first on-screen occurrence of `text`, offset by
+/// `column` characters.
+fn fg_at(harness: &EditorTestHarness, text: &str, column: u16) -> Option<ratatui::style::Color> {
+    let (x, y) = harness.find_text_on_screen(text).unwrap();
+    harness.get_cell_style(x + column, y).and_then(|s| s.fg)
+}
+
+#[test]
+fn test_cursor_on_paren_highlights_match_and_jumps_there() {
+    // Content: foo(bar) baz
+    let (mut harness, _tmp) = setup("foo(bar) baz");
+    let plain = fg_at(&harness, "foo(bar)", 7);
+
+    move_cursor_to(&mut harness, 3);
+    let gold = Some(fresh::view::bracket_highlight_overlay::DEFAULT_BRACKET_COLORS[0]);
+    assert_eq!(
+        fg_at(&harness, "foo(bar)", 7),
+        gold,
+        "')' should be highlighted"
+    );
+    assert_ne!(plain, gold);
+
+    goto_matching_bracket(&mut harness);
+    assert_eq!(harness.cursor_position(), 7);
+
+    // Moving away from the pair clears the highlight
+    move_cursor_to(&mut harness, 10);
+    assert_ne!(fg_at(&harness, "foo(bar)", 7), gold);
+}
+
+#[test]
+fn test_goto_matching_bracket_from_just_after_closing_paren() {
+    // Content: foo(bar) baz
+    // Cursor at position 8 (right after ')'), should jump to '(' at position 3
+    let (mut harness, _tmp) = setup("foo(bar) baz");
+    move_cursor_to(&mut harness, 8);
+
+    goto_matching_bracket(&mut harness);
+    assert_eq!(harness.cursor_position(), 3);
+}
+
+#[test]
+fn test_matching_skips_brackets_in_strings() {
+    // Content: g(")");  — the ')' at 3 is inside a string literal
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.rs");
+    std::fs::write(&file_path, "g(\")\");\n").unwrap();
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    move_cursor_to(&mut harness, 1);
+    goto_matching_bracket(&mut harness);
+    assert_eq!(harness.cursor_position(), 5);
+}
+
+#[test]
+fn test_bracket_highlight_can_be_disabled() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.txt");
+    std::fs::write(&file_path, "foo(bar) baz").unwrap();
+    let mut config = fresh::config::Config::default();
+    config.editor.highlight_matching_brackets = false;
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    move_cursor_to(&mut harness, 3);
+    let gold = Some(fresh::view::bracket_highlight_overlay::DEFAULT_BRACKET_COLORS[0]);
+    assert_ne!(fg_at(&harness, "foo(bar)", 7), gold);
+}
@@ -104,6 +104,64 @@ fn test_hanging_wrap_indent_disabled() {
     );
 }
 
+/// Test that `wrap_indent_extra` indents continuation lines past the original
+/// indentation, and that clicking a continuation line still lands on the
+/// character drawn under the mouse
+#[test]
+fn test_hanging_wrap_indent_extra() {
+    let config = Config {
+        editor: fresh::config::EditorConfig {
+            line_wrap: true,
+            wrap_indent_extra: 2,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let mut harness = EditorTestHarness::with_config(60, 24, config).unwrap();
+
+    let text = "    This is a long indented line that will wrap around because it is too long to fit in a single visual line in the editor.";
+    harness.type_text(text).unwrap();
+    harness.render().unwrap();
+
+    let screen = harness.screen_to_string();
+    let content_lines: Vec<(usize, &str)> = screen
+        .lines()
+        .enumerate()
+        .filter(|(_, l)| l.contains('│'))
+        .collect();
+    assert!(content_lines.len() >= 2, "Should wrap. Screen:\n{}", screen);
+
+    let split_content = |line: &str| {
+        let bar_pos = line.find('│').unwrap();
+        (
+            line[..bar_pos].chars().count() + 1,
+            line[bar_pos + '│'.len_utf8()..].to_string(),
+        )
+    };
+    let (_, first_content) = split_content(content_lines[0].1);
+    let (content_col, second_content) = split_content(content_lines[1].1);
+    let first_leading = first_content.chars().take_while(|c| *c == ' ').count();
+    let second_leading = second_content.chars().take_while(|c| *c == ' ').count();
+
+    // Continuation text starts two columns past the first non-whitespace char
+    assert_eq!(
+        second_leading,
+        first_leading + 2,
+        "Continuation should be indented 2 columns past the original indent.\nScreen:\n{}",
+        screen
+    );
+
+    // Clicking the continuation's first character puts the cursor on it
+    let first_row_text = first_content.trim();
+    let first_row_end = text.find(first_row_text).unwrap() + first_row_text.len();
+    let expected = text.len() - text[first_row_end..].trim_start().len();
+    let row = content_lines[1].0 as u16;
+    harness
+        .mouse_click((content_col + second_leading) as u16, row)
+        .unwrap();
+    assert_eq!(harness.cursor_position(), expected);
+}
+
 /// Test that wrapped continuation lines are indented when the original line uses tab indentation.
 /// This is the same as test_hanging_wrap_indent_basic but with tabs instead of spaces.
 #[test]
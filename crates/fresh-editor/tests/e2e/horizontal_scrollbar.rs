@@ -238,3 +238,96 @@ fn test_horizontal_scrollbar_in_split_view() {
     // Both splits should show their content
     harness.assert_screen_contains("Line 0:");
 }
+
+/// Shift+wheel scrolls horizontally, shifting the content left, and stops
+/// once the end of the longest visible line is on screen
+#[test]
+fn test_shift_wheel_scrolls_horizontally() {
+    use crossterm::event::{MouseEvent, MouseEventKind};
+
+    let mut config = Config::default();
+    config.editor.line_wrap = false;
+
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    let content = (0..20)
+        .map(|i| format!("Line {i}: {}END", "X".repeat(110)))
+        .collect::<Vec<_>>()
+        .join("\n");
+    harness.load_buffer_from_text(&content).unwrap();
+    harness.render().unwrap();
+    assert_eq!(harness.editor().active_viewport().left_column, 0);
+    harness.assert_screen_contains("Line 0:");
+
+    let (first_content_row, _) = harness.content_area_rows();
+    let shift_scroll_right = MouseEvent {
+        kind: MouseEventKind::ScrollDown,
+        column: 40,
+        row: first_content_row as u16,
+        modifiers: KeyModifiers::SHIFT,
+    };
+    harness.send_mouse(shift_scroll_right).unwrap();
+    harness.render().unwrap();
+
+    let left_column = harness.editor().active_viewport().left_column;
+    assert!(left_column > 0, "Shift+wheel should scroll right");
+    // The cursor follows into the visible columns instead of being left
+    // off-screen on the left
+    assert_eq!(harness.cursor_position(), left_column);
+    harness.assert_screen_not_contains("Line 0:");
+    harness.assert_screen_contains("e 0:");
+
+    for _ in 0..50 {
+        harness.send_mouse(shift_scroll_right).unwrap();
+    }
+    harness.render().unwrap();
+
+    let left_column = harness.editor().active_viewport().left_column;
+    assert!(
+        left_column < 120,
+        "Scrolling should stop at the longest line, left_column = {left_column}"
+    );
+    harness.assert_screen_contains("XEND");
+}
+
+/// Horizontal wheel scrolling measures lines with tabs expanded, so a line
+/// whose tabs run past the window can be scrolled to its end
+#[test]
+fn test_shift_wheel_scrolls_tab_indented_lines() {
+    use crossterm::event::{MouseEvent, MouseEventKind};
+
+    let mut config = Config::default();
+    config.editor.line_wrap = false;
+    config.editor.tab_size = 4;
+
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    // 30 tabs are 120 columns wide but only 30 bytes long
+    let content = (0..20)
+        .map(|i| format!("{}END{i}", "\t".repeat(30)))
+        .collect::<Vec<_>>()
+        .join("\n");
+    harness.load_buffer_from_text(&content).unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("END0");
+
+    let (first_content_row, _) = harness.content_area_rows();
+    let shift_scroll_right = MouseEvent {
+        kind: MouseEventKind::ScrollDown,
+        column: 40,
+        row: first_content_row as u16,
+        modifiers: KeyModifiers::SHIFT,
+    };
+    for _ in 0..50 {
+        harness.send_mouse(shift_scroll_right).unwrap();
+    }
+    harness.render().unwrap();
+
+    let left_column = harness.editor().active_viewport().left_column;
+    assert!(left_column > 0, "Shift+wheel should scroll past the tabs");
+    assert!(
+        left_column < 124,
+        "Scrolling should stop at the longest line, left_column = {left_column}"
+    );
+    harness.assert_screen_contains("END0");
+    // The cursor follows onto the first visible tab
+    assert_eq!(harness.cursor_position(), left_column / 4);
+}
@@ -946,6 +946,109 @@ fn test_smart_backspace_partial_indent() {
     );
 }
 
+/// Test that Backspace in 4-space indentation removes a whole indent level,
+/// while Backspace after a space in the middle of a line removes just one,
+/// and that the unindent is a single undo step
+#[test]
+fn test_smart_backspace_indent_level_vs_mid_line() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.rs");
+    std::fs::write(&file_path, "        let a =  1;\n").unwrap();
+
+    let mut harness = harness_with_spaces();
+    harness.open_file(&file_path).unwrap();
+
+    // Cursor after the 8 leading spaces
+    for _ in 0..8 {
+        harness
+            .send_key(KeyCode::Right, KeyModifiers::NONE)
+            .unwrap();
+    }
+    harness
+        .send_key(KeyCode::Backspace, KeyModifiers::NONE)
+        .unwrap();
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "    let a =  1;\n",
+        "Backspace in leading spaces should remove one indent level"
+    );
+
+    // Undo restores the whole indent level at once
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "        let a =  1;\n"
+    );
+
+    // Cursor after the two spaces before "1", in the middle of the line
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+    for _ in 0.."1;".len() {
+        harness.send_key(KeyCode::Left, KeyModifiers::NONE).unwrap();
+    }
+    harness
+        .send_key(KeyCode::Backspace, KeyModifiers::NONE)
+        .unwrap();
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "        let a = 1;\n",
+        "Backspace after a space mid-line should remove only that space"
+    );
+}
+
+/// Test that Backspace in a misaligned indent deletes back to the previous
+/// tab stop rather than a full indent width
+#[test]
+fn test_smart_backspace_snaps_to_previous_tab_stop() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.rs");
+    std::fs::write(&file_path, "      x\n").unwrap();
+
+    let mut harness = harness_with_spaces();
+    harness.open_file(&file_path).unwrap();
+
+    for _ in 0..6 {
+        harness
+            .send_key(KeyCode::Right, KeyModifiers::NONE)
+            .unwrap();
+    }
+    harness
+        .send_key(KeyCode::Backspace, KeyModifiers::NONE)
+        .unwrap();
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "    x\n",
+        "Backspace at column 6 should delete back to the tab stop at column 4"
+    );
+}
+
+/// Test that disabling `backspace_unindent` makes Backspace in leading
+/// whitespace delete a single space
+#[test]
+fn test_smart_backspace_disabled_deletes_one_space() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.rs");
+    std::fs::write(&file_path, "        x\n").unwrap();
+
+    let mut config = Config::default();
+    config.editor.tab_size = 4;
+    config.editor.backspace_unindent = false;
+    let mut harness =
+        EditorTestHarness::create(80, 24, HarnessOptions::new().with_config(config)).unwrap();
+    harness.open_file(&file_path).unwrap();
+
+    for _ in 0..8 {
+        harness
+            .send_key(KeyCode::Right, KeyModifiers::NONE)
+            .unwrap();
+    }
+    harness
+        .send_key(KeyCode::Backspace, KeyModifiers::NONE)
+        .unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), "       x\n");
+}
+
 // =============================================================================
 // Selection Boundary Tests (Issue #1304)
 // =============================================================================
@@ -1032,3 +1135,62 @@ fn test_shift_tab_dedent_does_not_dedent_line_at_selection_boundary() {
         "Shift+Tab should not dedent line 4 when selection ends at its start (issue #1304)"
     );
 }
+
+// =============================================================================
+// Retab Tests
+// =============================================================================
+
+/// Run the "Retab Buffer" command from the command palette
+fn run_retab(harness: &mut EditorTestHarness) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("Retab Buffer").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+}
+
+/// Test Retab converts a mixed-indent file to spaces
+#[test]
+fn test_retab_mixed_indent_to_spaces() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.txt");
+    std::fs::write(&file_path, "a {\n\tb\n    c\n  \td\n}\n").unwrap();
+
+    let mut harness = harness_with_spaces();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    run_retab(&mut harness);
+
+    harness.assert_buffer_content("a {\n    b\n    c\n    d\n}\n");
+    harness.assert_screen_contains("Converted indentation to spaces");
+}
+
+/// Test Retab converts a mixed-indent file to tabs when use_tabs is set
+#[test]
+fn test_retab_mixed_indent_to_tabs() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.txt");
+    std::fs::write(&file_path, "a {\n\tb\n        c\n      d\n}\n").unwrap();
+
+    let mut config = Config::default();
+    config.editor.tab_size = 4;
+    config.editor.use_tabs = true;
+    let mut harness =
+        EditorTestHarness::create(80, 24, HarnessOptions::new().with_config(config)).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    run_retab(&mut harness);
+
+    harness.assert_buffer_content("a {\n\tb\n\t\tc\n\t  d\n}\n");
+
+    // Retab is a single undoable edit
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.assert_buffer_content("a {\n\tb\n        c\n      d\n}\n");
+}
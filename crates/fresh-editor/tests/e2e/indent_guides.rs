@@ -0,0 +1,110 @@
+//! E2E tests for indent guides.
+//!
+//! With `editor.indent_guides` on, leading whitespace shows a `│` at every
+//! indentation level, and the guide of the block holding the cursor is drawn
+//! in `indent_guide_active_fg`.
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::Config;
+use tempfile::TempDir;
+
+fn setup(content: &str, indent_guides: bool) -> (EditorTestHarness, TempDir) {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.txt");
+    std::fs::write(&file_path, content).unwrap();
+
+    let mut config = Config::default();
+    config.editor.indent_guides = indent_guides;
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+    (harness, temp_dir)
+}
+
+/// The characters drawn in the `width` cells before `text` on screen.
+fn indent_before(harness: &EditorTestHarness, text: &str, width: u16) -> String {
+    let (x, y) = harness.find_text_on_screen(text).unwrap();
+    (x - width..x)
+        .map(|col| harness.get_cell(col, y).unwrap())
+        .collect()
+}
+
+#[test]
+fn test_indent_guides_render_at_each_level() {
+    let (harness, _tmp) = setup("fn a() {\n    if x {\n        y();\n    }\n}\n", true);
+
+    assert_eq!(indent_before(&harness, "if x", 4), "│   ");
+    assert_eq!(indent_before(&harness, "y();", 8), "│   │   ");
+}
+
+#[test]
+fn test_indent_guides_one_per_tab() {
+    let (harness, _tmp) = setup("fn a() {\n\tif x {\n\t\ty();\n\t}\n}\n", true);
+
+    // Each tab is one level: a guide in place of the tab arrow, then blanks
+    assert_eq!(indent_before(&harness, "y();", 8), "│   │   ");
+}
+
+#[test]
+fn test_indent_guides_follow_detected_indent_width() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.txt");
+    std::fs::write(&file_path, "fn a() {\n  if x {\n    y();\n  }\n}\n").unwrap();
+
+    // Two-space indentation is detected although the tab size is four
+    let mut config = Config::default();
+    config.editor.indent_guides = true;
+    config.editor.detect_indentation = true;
+    config.editor.tab_size = 4;
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    assert_eq!(indent_before(&harness, "y();", 4), "│ │ ");
+}
+
+#[test]
+fn test_indent_guides_skip_wrapped_continuation_rows() {
+    let call = format!("y_start({});", "argument, ".repeat(12));
+    let (harness, _tmp) = setup(
+        &format!("fn a() {{\n    if x {{\n        {call}\n    }}\n}}\n"),
+        true,
+    );
+
+    assert_eq!(indent_before(&harness, "y_start", 8), "│   │   ");
+
+    // The continuation row's hanging indent lines up with the text above
+    // but draws no guides
+    let (x, y) = harness.find_text_on_screen("y_start").unwrap();
+    let hanging: String = (x - 8..x)
+        .map(|col| harness.get_cell(col, y + 1).unwrap())
+        .collect();
+    assert_eq!(hanging, "        ");
+    assert!(harness.get_cell(x, y + 1).unwrap() != " ");
+}
+
+#[test]
+fn test_indent_guides_hidden_when_disabled() {
+    let (harness, _tmp) = setup("fn a() {\n    y();\n}\n", false);
+
+    assert_eq!(indent_before(&harness, "y();", 4), "    ");
+}
+
+#[test]
+fn test_indent_guide_of_cursor_block_is_highlighted() {
+    let (mut harness, _tmp) = setup("fn a() {\n    if x {\n        y();\n    }\n}\n", true);
+    for _ in 0..2 {
+        harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    }
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+
+    let theme = harness.editor().theme().clone();
+    let (x, y) = harness.find_text_on_screen("y();").unwrap();
+    let fg = |col: u16| harness.get_cell_style(col, y).and_then(|s| s.fg);
+    // The cursor sits in the `if` body: its guide is active, the function
+    // body's guide is not
+    assert_eq!(fg(x - 4), Some(theme.indent_guide_active_fg));
+    assert_eq!(fg(x - 8), Some(theme.indent_guide_fg));
+}
@@ -769,6 +769,34 @@ fn test_byte_offset_gutter_and_scan() {
         screen
     );
 
+    // A signed offset moves relative to the cursor, and Navigate Back
+    // returns to where the jump started
+    harness
+        .send_key(KeyCode::Char('g'), KeyModifiers::CONTROL)
+        .unwrap();
+    let _ = harness.type_text("n");
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    let _ = harness.type_text(&format!("-{}", line_len));
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    let screen = harness.screen_to_string();
+    assert!(
+        screen.contains(&format!("Byte {}", target_byte - line_len)),
+        "`-{}` should move back one line.\nScreen:\n{}",
+        line_len,
+        screen
+    );
+    harness.send_key(KeyCode::Left, KeyModifiers::ALT).unwrap();
+    harness
+        .wait_until(|h| {
+            h.screen_to_string()
+                .contains(&format!("Byte {}", target_byte))
+        })
+        .expect("Navigate Back should return to where the relative jump started");
+
     // === Test 4: Answer "y" to scan, gutter switches to line numbers ===
     harness
         .send_key(KeyCode::Char('g'), KeyModifiers::CONTROL)
@@ -1769,3 +1797,53 @@ fn test_large_file_gutter_indicators_viewport_filtering() {
         screen_back_eof
     );
 }
+
+/// Test that a file above the configured threshold shows the `[Large]`
+/// status-bar badge, and that running Scan Line Index from the command
+/// palette computes the line count and clears the badge.
+#[test]
+fn test_large_file_badge_and_scan_line_index_command() {
+    let content: String = (0..200).map(|i| format!("line {i}\n")).collect();
+
+    let mut config = fresh::config::Config::default();
+    config.editor.large_file_threshold_bytes = 100;
+
+    let fixture = TestFixture::new("badge.txt", &content).unwrap();
+    let mut harness = EditorTestHarness::with_config(100, 24, config).unwrap();
+    harness.open_file(&fixture.path).unwrap();
+    harness.render().unwrap();
+
+    assert!(harness
+        .editor()
+        .active_state()
+        .buffer
+        .line_count()
+        .is_none());
+    let status = harness.get_status_bar();
+    assert!(
+        status.contains("[Large]"),
+        "Status bar should show the large-file badge.\nStatus bar: '{}'",
+        status
+    );
+
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("Scan Line Index").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    while harness.editor_mut().process_line_scan() {}
+    harness.render().unwrap();
+
+    assert_eq!(
+        harness.editor().active_state().buffer.line_count(),
+        Some(201)
+    );
+    let status = harness.get_status_bar();
+    assert!(
+        !status.contains("[Large]"),
+        "Badge should clear once the line index is built.\nStatus bar: '{}'",
+        status
+    );
+}
@@ -112,3 +112,75 @@ fn test_relative_line_numbers_show_correct_distances() {
         "Line 5 should show relative distance 2 from cursor on line 3, screen:\n{screen}"
     );
 }
+
+/// Gutter labels (text before the separator) for the first `count` content rows.
+fn gutter_labels(harness: &EditorTestHarness, count: usize) -> Vec<String> {
+    let (first_row, _) = harness.content_area_rows();
+    (first_row..first_row + count)
+        .map(|row| {
+            let text = harness.get_screen_row(row);
+            text.split('│').next().unwrap_or("").trim().to_string()
+        })
+        .collect()
+}
+
+/// Relative numbers follow the primary cursor: adding a cursor below makes
+/// it primary, so distances are re-measured from the new cursor's line.
+#[test]
+fn test_relative_line_numbers_follow_new_primary_cursor() {
+    let mut config = fresh::config::Config::default();
+    config.editor.relative_line_numbers = true;
+
+    let mut harness = EditorTestHarness::with_temp_project_and_config(120, 24, config).unwrap();
+    let project_dir = harness.project_dir().unwrap();
+    let file_path = project_dir.join("test.txt");
+    std::fs::write(&file_path, "line1\nline2\nline3\nline4\nline5\n").unwrap();
+
+    harness.open_file(&file_path).unwrap();
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+    assert_eq!(gutter_labels(&harness, 5), ["1", "2", "1", "2", "3"]);
+
+    // The added cursor on line 3 becomes the primary
+    harness
+        .send_key(KeyCode::Down, KeyModifiers::CONTROL | KeyModifiers::ALT)
+        .unwrap();
+    harness.render().unwrap();
+    assert_eq!(gutter_labels(&harness, 5), ["2", "1", "3", "1", "2"]);
+}
+
+/// Only the first visual row of a wrapped line gets a relative number;
+/// continuation rows leave the gutter blank.
+#[test]
+fn test_relative_line_numbers_skip_wrapped_continuations() {
+    let mut config = fresh::config::Config::default();
+    config.editor.relative_line_numbers = true;
+    config.editor.line_wrap = true;
+
+    let mut harness = EditorTestHarness::with_temp_project_and_config(40, 24, config).unwrap();
+    let project_dir = harness.project_dir().unwrap();
+    let file_path = project_dir.join("test.txt");
+    let long_line = "word ".repeat(14);
+    std::fs::write(&file_path, format!("line1\n{long_line}\nline3\nline4\n")).unwrap();
+
+    harness.open_file(&file_path).unwrap();
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+
+    let labels = gutter_labels(&harness, 8);
+    let line3_row = (0..labels.len())
+        .find(|&i| {
+            let (first_row, _) = harness.content_area_rows();
+            harness.get_screen_row(first_row + i).contains("line3")
+        })
+        .expect("line3 should be visible");
+    assert!(line3_row > 2, "long line should wrap onto several rows");
+    assert_eq!(labels[0], "2");
+    assert_eq!(labels[1], "1");
+    for label in &labels[2..line3_row] {
+        assert_eq!(label, "", "wrapped continuation rows must not be numbered");
+    }
+    assert_eq!(labels[line3_row], "3");
+    assert_eq!(labels[line3_row + 1], "1");
+}
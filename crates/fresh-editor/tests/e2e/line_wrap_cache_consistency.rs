@@ -30,6 +30,7 @@ use fresh::config::Config;
 use fresh::view::line_wrap_cache::{
     count_visual_rows_for_text, pipeline_inputs_version, CacheViewMode, LineWrapKey,
 };
+use std::collections::BTreeMap;
 
 const TERMINAL_HEIGHT: u16 = 24;
 
@@ -70,7 +71,7 @@ fn mixed_buffer() -> String {
 /// build from the harness's current state.  These match the key the
 /// renderer's writeback in `view_data::build_view_data` constructs.
 fn current_keys(harness: &EditorTestHarness, line_start: usize) -> (LineWrapKey, LineWrapKey) {
-    let (effective_width, gutter_width, hanging_indent, wrap_column) = {
+    let (effective_width, gutter_width, hanging_indent, wrap_mode, wrap_indent_extra, wrap_column) = {
         let editor = harness.editor();
         let viewport = editor.active_viewport();
         let state = editor.active_state();
@@ -83,7 +84,14 @@ fn current_keys(harness: &EditorTestHarness, line_start: usize) -> (LineWrapKey,
         let content_width = viewport.width as usize;
         let effective = content_width.saturating_sub(1).max(1);
         let wrap_col = viewport.wrap_column.map(|c| c as u32);
-        (effective as u32, gutter, viewport.wrap_indent, wrap_col)
+        (
+            effective as u32,
+            gutter,
+            viewport.wrap_indent,
+            viewport.wrap_mode,
+            viewport.wrap_indent_extra,
+            wrap_col,
+        )
     };
     let pipeline_ver = {
         let editor = harness.editor();
@@ -103,6 +111,8 @@ fn current_keys(harness: &EditorTestHarness, line_start: usize) -> (LineWrapKey,
         gutter_width,
         wrap_column,
         hanging_indent,
+        wrap_mode,
+        wrap_indent_extra,
         line_wrap_enabled: true,
     };
     let source = LineWrapKey {
@@ -193,6 +203,9 @@ fn render_writeback_values_match_fresh_recompute() {
                 compose_key.effective_width as usize,
                 compose_key.gutter_width as usize,
                 compose_key.hanging_indent,
+                compose_key.wrap_mode,
+                compose_key.wrap_indent_extra,
+                &BTreeMap::new(),
             );
             if let Some(v) = cached_compose {
                 assert_eq!(
@@ -274,6 +287,9 @@ fn scroll_math_miss_handler_matches_fresh_recompute() {
                 source_key.effective_width as usize,
                 source_key.gutter_width as usize,
                 source_key.hanging_indent,
+                source_key.wrap_mode,
+                source_key.wrap_indent_extra,
+                &BTreeMap::new(),
             );
             if let Some(v) = cached_source {
                 assert_eq!(
@@ -356,6 +372,9 @@ fn resize_produces_fresh_cache_entries_at_new_width() {
             source_after.effective_width as usize,
             source_after.gutter_width as usize,
             source_after.hanging_indent,
+            source_after.wrap_mode,
+            source_after.wrap_indent_extra,
+            &BTreeMap::new(),
         );
         assert_eq!(v, fresh, "post-resize entry disagrees with fresh recompute");
     }
@@ -395,6 +414,9 @@ fn repeated_edits_keep_cache_consistent() {
                     source_key.effective_width as usize,
                     source_key.gutter_width as usize,
                     source_key.hanging_indent,
+                    source_key.wrap_mode,
+                    source_key.wrap_indent_extra,
+                    &BTreeMap::new(),
                 );
                 assert_eq!(
                     v, fresh,
@@ -446,6 +468,9 @@ fn edit_invalidates_cache_visibly() {
                 source_key.effective_width as usize,
                 source_key.gutter_width as usize,
                 source_key.hanging_indent,
+                source_key.wrap_mode,
+                source_key.wrap_indent_extra,
+                &BTreeMap::new(),
             );
             assert_eq!(
                 v, fresh,
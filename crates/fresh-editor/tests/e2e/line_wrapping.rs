@@ -2477,3 +2477,65 @@ fn test_page_view_default_width_80() {
         screen
     );
 }
+
+/// Test that compose guides set from the command palette are drawn at
+/// their columns relative to the centered page, not the split's edge
+#[test]
+fn test_page_view_compose_guides_rendered() {
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    let mut harness = EditorTestHarness::new(160, 24).unwrap();
+    let content = "Hello from compose guides test";
+    let fixture =
+        crate::common::fixtures::TestFixture::new("test_compose_guides.txt", content).unwrap();
+    harness.open_file(&fixture.path).unwrap();
+    harness.render().unwrap();
+
+    for (command, input) in [
+        ("Toggle Page View", None),
+        ("Set Compose Guides", Some("40, 72")),
+    ] {
+        harness
+            .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+            .unwrap();
+        harness.type_text(command).unwrap();
+        harness
+            .send_key(KeyCode::Enter, KeyModifiers::NONE)
+            .unwrap();
+        if let Some(input) = input {
+            harness.type_text(input).unwrap();
+            harness
+                .send_key(KeyCode::Enter, KeyModifiers::NONE)
+                .unwrap();
+        }
+    }
+    harness.render().unwrap();
+
+    let screen = harness.screen_to_string();
+    let (text_x, text_y) = harness
+        .find_text_on_screen("Hello from")
+        .unwrap_or_else(|| panic!("Content should be visible\nScreen:\n{}", screen));
+    assert!(
+        text_x >= 30,
+        "Page view content should be centered, found at x={}\nScreen:\n{}",
+        text_x,
+        screen
+    );
+
+    for col in [40u16, 72] {
+        assert_eq!(
+            harness.get_cell(text_x + col, text_y).as_deref(),
+            Some("│"),
+            "Expected a compose guide at column {} (x={})\nScreen:\n{}",
+            col,
+            text_x + col,
+            screen
+        );
+    }
+    assert_ne!(
+        harness.get_cell(text_x + 41, text_y).as_deref(),
+        Some("│"),
+        "Only the configured columns should have guides\nScreen:\n{}",
+        screen
+    );
+}
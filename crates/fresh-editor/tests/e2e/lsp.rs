@@ -3362,6 +3362,7 @@ fn test_pull_diagnostics_message_handling() -> anyhow::Result<()> {
             result_id: Some("test-result-id-123".to_string()),
             diagnostics: vec![diagnostic],
             unchanged: false,
+            server_name: "rust-analyzer".to_string(),
         });
     }
 
@@ -3405,6 +3406,7 @@ fn test_pull_diagnostics_unchanged_response() -> anyhow::Result<()> {
             result_id: Some("test-result-id-456".to_string()),
             diagnostics: Vec::new(), // Empty when unchanged
             unchanged: true,
+            server_name: "rust-analyzer".to_string(),
         });
     }
 
@@ -6239,6 +6241,75 @@ fn test_completion_triggered_on_trigger_character() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Test that typing `foo.` opens the completion popup without an explicit
+/// request, and that the popup goes away once the typed prefix stops
+/// matching any item
+#[test]
+#[cfg_attr(target_os = "windows", ignore)] // Uses Bash-based fake LSP server
+fn test_completion_popup_auto_opens_after_trigger_character() -> anyhow::Result<()> {
+    let temp_dir = tempfile::tempdir()?;
+
+    let _fake_server = FakeLspServer::spawn_with_logging(temp_dir.path())?;
+
+    let log_file = temp_dir.path().join("auto_completion_test_log.txt");
+    let test_file = temp_dir.path().join("test.rs");
+    std::fs::write(&test_file, "fn main() {\n    \n}\n")?;
+
+    // Word characters alone shouldn't open it: "foo" is below the threshold
+    let mut config = fresh::config::Config::default();
+    config.editor.completion_popup_auto_show = true;
+    config.editor.quick_suggestions_min_chars = 4;
+    config.lsp.insert(
+        "rust".to_string(),
+        fresh::types::LspLanguageConfig::Multi(vec![fresh::services::lsp::LspServerConfig {
+            command: FakeLspServer::logging_script_path(temp_dir.path())
+                .to_string_lossy()
+                .to_string(),
+            args: vec![log_file.to_string_lossy().to_string()],
+            enabled: true,
+            auto_start: true,
+            process_limits: fresh::services::process_limits::ProcessLimits::default(),
+            initialization_options: None,
+            env: Default::default(),
+            language_id_overrides: Default::default(),
+            root_markers: Default::default(),
+            name: None,
+            only_features: None,
+            except_features: None,
+        }]),
+    );
+
+    let mut harness = EditorTestHarness::with_config_and_working_dir(
+        120,
+        30,
+        config,
+        temp_dir.path().to_path_buf(),
+    )?;
+
+    harness.open_file(&test_file)?;
+    harness.render()?;
+    harness.wait_until(|_| {
+        let log_content = std::fs::read_to_string(&log_file).unwrap_or_default();
+        log_content.contains("textDocument/didOpen")
+    })?;
+
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE)?;
+    harness.send_key(KeyCode::End, KeyModifiers::NONE)?;
+    harness.type_text("foo.")?;
+
+    harness.wait_until(|h| h.screen_to_string().contains("test_function"))?;
+    assert!(harness.editor().active_state().popups.is_visible());
+
+    // No item starts with "z", so the popup is dismissed
+    harness.type_text("z")?;
+    harness.render()?;
+    assert!(!harness.editor().active_state().popups.is_visible());
+    harness.assert_screen_not_contains("test_function");
+    harness.assert_buffer_content("fn main() {\n    foo.z\n}\n");
+
+    Ok(())
+}
+
 /// Test that completion is triggered on word characters when quick_suggestions is enabled
 ///
 /// This test verifies VS Code-like behavior where typing word characters (letters, numbers, _)
@@ -6325,6 +6396,86 @@ fn test_completion_triggered_on_word_char_with_quick_suggestions() -> anyhow::Re
     Ok(())
 }
 
+/// Test that quick suggestions wait for `quick_suggestions_min_chars` word
+/// characters: no request below the threshold, a request once it is reached
+#[test]
+#[cfg_attr(target_os = "windows", ignore)] // Uses Bash-based fake LSP server
+fn test_quick_suggestions_respect_min_chars() -> anyhow::Result<()> {
+    let temp_dir = tempfile::tempdir()?;
+
+    let _fake_server = FakeLspServer::spawn_with_logging(temp_dir.path())?;
+
+    let log_file = temp_dir.path().join("quick_suggestions_min_chars_log.txt");
+    let test_file = temp_dir.path().join("test.rs");
+    std::fs::write(&test_file, "fn main() {\n    \n}\n")?;
+
+    let mut config = fresh::config::Config::default();
+    config.editor.completion_popup_auto_show = true;
+    config.editor.quick_suggestions = true;
+    config.editor.quick_suggestions_delay_ms = 10;
+    config.editor.quick_suggestions_min_chars = 3;
+    config.lsp.insert(
+        "rust".to_string(),
+        fresh::types::LspLanguageConfig::Multi(vec![fresh::services::lsp::LspServerConfig {
+            command: FakeLspServer::logging_script_path(temp_dir.path())
+                .to_string_lossy()
+                .to_string(),
+            args: vec![log_file.to_string_lossy().to_string()],
+            enabled: true,
+            auto_start: true,
+            process_limits: fresh::services::process_limits::ProcessLimits::default(),
+            initialization_options: None,
+            env: Default::default(),
+            language_id_overrides: Default::default(),
+            root_markers: Default::default(),
+            name: None,
+            only_features: None,
+            except_features: None,
+        }]),
+    );
+
+    let mut harness = EditorTestHarness::with_config_and_working_dir(
+        120,
+        30,
+        config,
+        temp_dir.path().to_path_buf(),
+    )?;
+
+    harness.open_file(&test_file)?;
+    harness.render()?;
+    harness.wait_until(|_| {
+        let log_content = std::fs::read_to_string(&log_file).unwrap_or_default();
+        log_content.contains("textDocument/didOpen")
+    })?;
+    std::fs::write(&log_file, "")?;
+
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE)?;
+    harness.send_key(KeyCode::End, KeyModifiers::NONE)?;
+
+    // Two characters: below the threshold, nothing is requested even after
+    // the delay has passed
+    harness.type_text("pr")?;
+    for _ in 0..10 {
+        harness.process_async_and_render()?;
+        harness.sleep(std::time::Duration::from_millis(50));
+    }
+    let log_content = std::fs::read_to_string(&log_file)?;
+    assert!(
+        !log_content.contains("textDocument/completion"),
+        "Expected no completion below quick_suggestions_min_chars. Log: {}",
+        log_content
+    );
+
+    // The third character reaches the threshold
+    harness.type_text("i")?;
+    harness.wait_until(|_| {
+        let log_content = std::fs::read_to_string(&log_file).unwrap_or_default();
+        log_content.contains("textDocument/completion")
+    })?;
+
+    Ok(())
+}
+
 /// Test that completion is NOT triggered on word characters when quick_suggestions is disabled
 ///
 /// This test verifies that when quick_suggestions is disabled, only LSP trigger characters
@@ -9731,6 +9882,70 @@ fn test_restart_lsp_prompt_restarts_stopped_server() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Test that restarting a running server re-initializes it against a fresh
+/// process, and that the status popup then reports the new process as ready
+#[test]
+#[cfg_attr(windows, ignore = "Uses bash script for fake LSP server")]
+fn test_restart_lsp_reinitializes_and_status_reports_running() -> anyhow::Result<()> {
+    let temp_dir = tempfile::tempdir()?;
+    let _fake_server = FakeLspServer::spawn(temp_dir.path())?;
+
+    let test_file = temp_dir.path().join("test.rs");
+    std::fs::write(&test_file, "fn main() {}\n")?;
+
+    let mut config = fresh::config::Config::default();
+    config.lsp.insert(
+        "rust".to_string(),
+        fresh::types::LspLanguageConfig::Multi(vec![fresh::services::lsp::LspServerConfig {
+            command: FakeLspServer::script_path(temp_dir.path())
+                .to_string_lossy()
+                .to_string(),
+            args: vec![],
+            enabled: true,
+            auto_start: true,
+            process_limits: fresh::services::process_limits::ProcessLimits::default(),
+            initialization_options: None,
+            env: Default::default(),
+            language_id_overrides: Default::default(),
+            root_markers: Default::default(),
+            name: Some("fake-ls".to_string()),
+            only_features: None,
+            except_features: None,
+        }]),
+    );
+
+    let mut harness = EditorTestHarness::with_config_and_working_dir(
+        120,
+        30,
+        config,
+        temp_dir.path().to_path_buf(),
+    )?;
+
+    harness.open_file(&test_file)?;
+    harness.render()?;
+
+    harness.wait_until(|h| h.editor().initialized_lsp_server_count("rust") == 1)?;
+    let original_pids = harness.editor().lsp_server_process_ids("rust");
+    assert_eq!(original_pids.len(), 1, "expected one spawned server");
+
+    // With a single server, restart kills and respawns it immediately
+    harness.editor_mut().handle_lsp_restart();
+    harness.wait_until(|h| {
+        h.editor().initialized_lsp_server_count("rust") == 1
+            && h.editor()
+                .lsp_server_process_ids("rust")
+                .iter()
+                .any(|pid| !original_pids.contains(pid))
+    })?;
+    let new_pid = harness.editor().lsp_server_process_ids("rust")[0];
+
+    harness.editor_mut().show_lsp_status_popup();
+    harness.wait_for_screen_contains("fake-ls (ready)")?;
+    harness.assert_screen_contains(&format!("pid {}", new_pid));
+
+    Ok(())
+}
+
 /// Test that "stop lsp" via prompt sends didClose before removing handles
 ///
 /// This is a regression test: previously, `disable_lsp_for_buffer` was called AFTER
@@ -9915,3 +10130,317 @@ fn lsp_spawn_failure_writes_stub_log_for_view_log_popup() -> anyhow::Result<()>
 
     Ok(())
 }
+
+/// Test that signature help stays open while the call's arguments are
+/// typed, moves to the next parameter after a comma, and closes with the
+/// call
+#[test]
+#[cfg_attr(target_os = "windows", ignore)] // Uses Bash-based fake LSP server
+fn test_signature_help_updates_while_typing_arguments() -> anyhow::Result<()> {
+    let temp_dir = tempfile::tempdir()?;
+
+    let _fake_server = FakeLspServer::spawn_with_signature_help(temp_dir.path())?;
+
+    let test_file = temp_dir.path().join("test.rs");
+    std::fs::write(&test_file, "fn main() {\n    \n}\n")?;
+
+    let mut config = fresh::config::Config::default();
+    config.lsp.insert(
+        "rust".to_string(),
+        fresh::types::LspLanguageConfig::Multi(vec![fresh::services::lsp::LspServerConfig {
+            command: FakeLspServer::signature_help_script_path(temp_dir.path())
+                .to_string_lossy()
+                .to_string(),
+            args: vec![],
+            enabled: true,
+            auto_start: true,
+            process_limits: fresh::services::process_limits::ProcessLimits::default(),
+            initialization_options: None,
+            env: Default::default(),
+            language_id_overrides: Default::default(),
+            root_markers: Default::default(),
+            name: None,
+            only_features: None,
+            except_features: None,
+        }]),
+    );
+
+    let mut harness = EditorTestHarness::with_config_and_working_dir(
+        120,
+        30,
+        config,
+        temp_dir.path().to_path_buf(),
+    )?;
+
+    harness.open_file(&test_file)?;
+    harness.render()?;
+    harness.wait_until(|h| h.editor().initialized_lsp_server_count("rust") > 0)?;
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE)?;
+    harness.send_key(KeyCode::End, KeyModifiers::NONE)?;
+
+    // The popup lists the active parameter on a line of its own
+    let shows_param = |screen: &str, param: &str| {
+        screen
+            .lines()
+            .any(|line| line.split('│').any(|cell| cell.trim() == param))
+    };
+
+    // Opening the call shows the first parameter
+    harness.type_text("add(")?;
+    harness.wait_until(|h| shows_param(&h.screen_to_string(), "a: i32"))?;
+
+    // Typing the first argument keeps the popup on the first parameter
+    harness.type_text("1")?;
+    harness.render()?;
+    assert!(harness.editor().active_state().popups.is_visible());
+
+    // A comma moves it on to the second parameter
+    harness.type_text(", 2")?;
+    harness.wait_until(|h| shows_param(&h.screen_to_string(), "b: i32"))?;
+    assert!(!shows_param(&harness.screen_to_string(), "a: i32"));
+    assert!(harness.editor().active_state().popups.is_visible());
+
+    // Closing the call dismisses it
+    harness.type_text(")")?;
+    harness.render()?;
+    assert!(!harness.editor().active_state().popups.is_visible());
+    harness.assert_buffer_content("fn main() {\n    add(1, 2)\n}\n");
+
+    Ok(())
+}
+
+/// Moving the selection in the completion popup shows the selected item's
+/// documentation, fetched with completionItem/resolve, in a preview pane.
+#[test]
+#[cfg_attr(
+    target_os = "windows",
+    ignore = "FakeLspServer uses a Bash script which is not available on Windows"
+)]
+fn test_completion_preview_shows_resolved_documentation() -> anyhow::Result<()> {
+    let temp_dir = tempfile::tempdir()?;
+
+    let _fake_server = FakeLspServer::spawn_with_completion_resolve(temp_dir.path())?;
+
+    let test_file = temp_dir.path().join("test.rs");
+    std::fs::write(&test_file, "fn main() {\n    \n}\n")?;
+
+    let mut config = fresh::config::Config::default();
+    config.lsp.insert(
+        "rust".to_string(),
+        fresh::types::LspLanguageConfig::Multi(vec![fresh::services::lsp::LspServerConfig {
+            command: FakeLspServer::completion_resolve_script_path(temp_dir.path())
+                .to_string_lossy()
+                .to_string(),
+            args: vec![],
+            enabled: true,
+            auto_start: true,
+            process_limits: fresh::services::process_limits::ProcessLimits::default(),
+            initialization_options: None,
+            env: Default::default(),
+            language_id_overrides: Default::default(),
+            root_markers: Default::default(),
+            name: None,
+            only_features: None,
+            except_features: None,
+        }]),
+    );
+
+    let mut harness = EditorTestHarness::with_config_and_working_dir(
+        120,
+        30,
+        config,
+        temp_dir.path().to_path_buf(),
+    )?;
+
+    harness.open_file(&test_file)?;
+    harness.render()?;
+    harness.wait_until(|h| h.editor().initialized_lsp_server_count("rust") > 0)?;
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE)?;
+    harness.send_key(KeyCode::End, KeyModifiers::NONE)?;
+
+    // The first item is selected when the popup opens
+    harness.send_key(KeyCode::Char(' '), KeyModifiers::CONTROL)?;
+    harness.wait_until(|h| {
+        h.screen_to_string()
+            .contains("Alpha returns the first letter")
+    })?;
+
+    // Moving the selection swaps the preview for the next item's docs
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE)?;
+    harness.wait_until(|h| {
+        h.screen_to_string()
+            .contains("Beta returns the second letter")
+    })?;
+    harness.assert_screen_not_contains("Alpha returns the first letter");
+
+    // An item that resolves without documentation hides the preview
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE)?;
+    harness.wait_until(|h| {
+        !h.screen_to_string()
+            .contains("Beta returns the second letter")
+    })?;
+    harness.assert_screen_contains("gamma_fn");
+    harness.assert_screen_not_contains("Alpha returns the first letter");
+
+    // Going back shows the cached docs again
+    harness.send_key(KeyCode::Up, KeyModifiers::NONE)?;
+    harness.wait_until(|h| {
+        h.screen_to_string()
+            .contains("Beta returns the second letter")
+    })?;
+    harness.assert_buffer_content("fn main() {\n    \n}\n");
+
+    Ok(())
+}
+
+/// A rename whose edits span two files is listed in a preview popup and only
+/// applied once confirmed, as one undo step per buffer.
+#[test]
+#[cfg_attr(
+    target_os = "windows",
+    ignore = "FakeLspServer uses a Bash script which is not available on Windows"
+)]
+fn test_multi_file_rename_preview_lists_locations_and_applies_on_confirm() -> anyhow::Result<()> {
+    let temp_dir = tempfile::tempdir()?;
+
+    let _fake_server = FakeLspServer::spawn_with_multi_file_rename(temp_dir.path())?;
+
+    let main_file = temp_dir.path().join("main.rs");
+    let lib_file = temp_dir.path().join("lib.rs");
+    std::fs::write(&main_file, "fn main() {\n    helper();\n}\n")?;
+    std::fs::write(&lib_file, "pub fn helper() {}\n")?;
+
+    let mut config = fresh::config::Config::default();
+    config.lsp.insert(
+        "rust".to_string(),
+        fresh::types::LspLanguageConfig::Multi(vec![fresh::services::lsp::LspServerConfig {
+            command: FakeLspServer::multi_file_rename_script_path(temp_dir.path())
+                .to_string_lossy()
+                .to_string(),
+            args: vec![],
+            enabled: true,
+            auto_start: true,
+            process_limits: fresh::services::process_limits::ProcessLimits::default(),
+            initialization_options: None,
+            env: Default::default(),
+            language_id_overrides: Default::default(),
+            root_markers: Default::default(),
+            name: None,
+            only_features: None,
+            except_features: None,
+        }]),
+    );
+
+    let mut harness = EditorTestHarness::with_config_and_working_dir(
+        120,
+        30,
+        config,
+        temp_dir.path().to_path_buf(),
+    )?;
+
+    harness.open_file(&main_file)?;
+    harness.render()?;
+    harness.wait_until(|h| h.editor().initialized_lsp_server_count("rust") > 0)?;
+
+    // Put the cursor on `helper` and rename it
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE)?;
+    for _ in 0..5 {
+        harness.send_key(KeyCode::Right, KeyModifiers::NONE)?;
+    }
+    harness.send_key(KeyCode::F(2), KeyModifiers::NONE)?;
+    for _ in 0.."helper".len() {
+        harness.send_key(KeyCode::Backspace, KeyModifiers::NONE)?;
+    }
+    harness.type_text("assist")?;
+    harness.send_key(KeyCode::Enter, KeyModifiers::NONE)?;
+
+    // The preview lists both locations; nothing is applied yet
+    harness.wait_until(|h| {
+        h.screen_to_string()
+            .contains("lib.rs:1: pub fn helper() {}")
+    })?;
+    harness.assert_screen_contains("main.rs:2: helper();");
+    harness.assert_buffer_content("fn main() {\n    helper();\n}\n");
+
+    // Confirming applies the edits in both files and keeps main.rs active
+    harness.send_key(KeyCode::Enter, KeyModifiers::NONE)?;
+    harness.render()?;
+    assert!(!harness.editor().active_state().popups.is_visible());
+    harness.assert_buffer_content("fn main() {\n    assist();\n}\n");
+
+    // One undo reverts main.rs without touching lib.rs
+    harness.send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)?;
+    harness.assert_buffer_content("fn main() {\n    helper();\n}\n");
+
+    harness.open_file(&lib_file)?;
+    harness.assert_buffer_content("pub fn assist() {}\n");
+
+    Ok(())
+}
+
+/// A preview resolve that answers after the selection moved on must not be
+/// mistaken for an accepted item: its additional_text_edits are dropped.
+#[test]
+#[cfg_attr(
+    target_os = "windows",
+    ignore = "FakeLspServer uses a Bash script which is not available on Windows"
+)]
+fn test_completion_preview_ignores_resolve_after_selection_moves() -> anyhow::Result<()> {
+    let temp_dir = tempfile::tempdir()?;
+
+    let _fake_server = FakeLspServer::spawn_with_completion_resolve(temp_dir.path())?;
+
+    let test_file = temp_dir.path().join("test.rs");
+    std::fs::write(&test_file, "fn main() {\n    \n}\n")?;
+
+    let mut config = fresh::config::Config::default();
+    config.lsp.insert(
+        "rust".to_string(),
+        fresh::types::LspLanguageConfig::Multi(vec![fresh::services::lsp::LspServerConfig {
+            command: FakeLspServer::completion_resolve_script_path(temp_dir.path())
+                .to_string_lossy()
+                .to_string(),
+            args: vec![],
+            enabled: true,
+            auto_start: true,
+            process_limits: fresh::services::process_limits::ProcessLimits::default(),
+            initialization_options: None,
+            env: Default::default(),
+            language_id_overrides: Default::default(),
+            root_markers: Default::default(),
+            name: None,
+            only_features: None,
+            except_features: None,
+        }]),
+    );
+
+    let mut harness = EditorTestHarness::with_config_and_working_dir(
+        120,
+        30,
+        config,
+        temp_dir.path().to_path_buf(),
+    )?;
+
+    harness.open_file(&test_file)?;
+    harness.render()?;
+    harness.wait_until(|h| h.editor().initialized_lsp_server_count("rust") > 0)?;
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE)?;
+    harness.send_key(KeyCode::End, KeyModifiers::NONE)?;
+
+    // Opening the popup resolves alpha_fn, whose answer is delayed; move on
+    // to beta_fn before it arrives
+    harness.send_key(KeyCode::Char(' '), KeyModifiers::CONTROL)?;
+    harness.wait_until(|h| h.screen_to_string().contains("beta_fn"))?;
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE)?;
+
+    // The server answers in order, so alpha_fn's response has been handled
+    // once beta_fn's documentation shows
+    harness.wait_until(|h| {
+        h.screen_to_string()
+            .contains("Beta returns the second letter")
+    })?;
+    harness.assert_screen_not_contains("Alpha returns the first letter");
+    harness.assert_buffer_content("fn main() {\n    \n}\n");
+
+    Ok(())
+}
@@ -0,0 +1,154 @@
+//! E2E test: code-action requests carry the diagnostics under the cursor.
+//!
+//! Quick fixes like "Remove unused import" are only offered by servers when
+//! the diagnostic they fix is in `CodeActionContext.diagnostics`.
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+
+/// Create a fake LSP server that publishes an "unused import" warning on
+/// didOpen and offers the matching quick fix only when the codeAction request
+/// includes that diagnostic in its context.
+fn create_quick_fix_lsp_script(dir: &std::path::Path) -> std::path::PathBuf {
+    let script = r##"#!/bin/bash
+
+read_message() {
+    local content_length=0
+    while IFS=: read -r key value; do
+        key=$(echo "$key" | tr -d '\r\n')
+        value=$(echo "$value" | tr -d '\r\n ')
+        if [ "$key" = "Content-Length" ]; then
+            content_length=$value
+        fi
+        if [ -z "$key" ]; then
+            break
+        fi
+    done
+    if [ $content_length -gt 0 ]; then
+        dd bs=1 count=$content_length 2>/dev/null
+    fi
+}
+
+send_message() {
+    local message="$1"
+    local length=${#message}
+    printf "Content-Length: $length\r\n\r\n%s" "$message"
+}
+
+DOC_URI=""
+
+while true; do
+    msg=$(read_message)
+    if [ -z "$msg" ]; then break; fi
+
+    method=$(echo "$msg" | grep -o '"method":"[^"]*"' | cut -d'"' -f4)
+    msg_id=$(echo "$msg" | grep -o '"id":[0-9]*' | cut -d':' -f2)
+
+    case "$method" in
+        "initialize")
+            send_message '{"jsonrpc":"2.0","id":'$msg_id',"result":{"capabilities":{"textDocumentSync":1,"codeActionProvider":true}}}'
+            ;;
+        "textDocument/didOpen")
+            DOC_URI=$(echo "$msg" | grep -o '"uri":"[^"]*"' | head -1 | cut -d'"' -f4)
+            send_message '{"jsonrpc":"2.0","method":"textDocument/publishDiagnostics","params":{"uri":"'$DOC_URI'","diagnostics":[{"range":{"start":{"line":0,"character":4},"end":{"line":0,"character":11}},"severity":2,"message":"unused import: `std::io`"}]}}'
+            ;;
+        "textDocument/codeAction")
+            if echo "$msg" | grep -q 'unused import'; then
+                send_message '{"jsonrpc":"2.0","id":'$msg_id',"result":[{"title":"Remove unused import","kind":"quickfix","edit":{"changes":{"'$DOC_URI'":[{"range":{"start":{"line":0,"character":0},"end":{"line":1,"character":0}},"newText":""}]}}}]}'
+            else
+                send_message '{"jsonrpc":"2.0","id":'$msg_id',"result":[]}'
+            fi
+            ;;
+        "shutdown")
+            send_message '{"jsonrpc":"2.0","id":'$msg_id',"result":null}'
+            break
+            ;;
+        *)
+            if [ -n "$msg_id" ]; then
+                send_message '{"jsonrpc":"2.0","id":'$msg_id',"result":null}'
+            fi
+            ;;
+    esac
+done
+"##;
+
+    let script_path = dir.join("fake_lsp_quick_fix.sh");
+    std::fs::write(&script_path, script).expect("Failed to write fake LSP script");
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mut perms = std::fs::metadata(&script_path).unwrap().permissions();
+        perms.set_mode(0o755);
+        std::fs::set_permissions(&script_path, perms).unwrap();
+    }
+
+    script_path
+}
+
+/// Test: with the cursor on an unused import, the quick fix is offered and
+/// choosing it deletes the line; undo brings it back.
+#[test]
+#[cfg_attr(
+    target_os = "windows",
+    ignore = "FakeLspServer uses a Bash script which is not available on Windows"
+)]
+fn test_code_action_quick_fix_for_diagnostic_under_cursor() -> anyhow::Result<()> {
+    let temp_dir = tempfile::tempdir()?;
+    let script_path = create_quick_fix_lsp_script(temp_dir.path());
+    let test_file = temp_dir.path().join("test.rs");
+    let original = "use std::io;\nfn main() {}\n";
+    std::fs::write(&test_file, original)?;
+
+    let mut config = fresh::config::Config::default();
+    config.lsp.insert(
+        "rust".to_string(),
+        fresh::types::LspLanguageConfig::Multi(vec![fresh::services::lsp::LspServerConfig {
+            command: script_path.to_string_lossy().to_string(),
+            args: vec![],
+            enabled: true,
+            auto_start: true,
+            process_limits: fresh::services::process_limits::ProcessLimits::default(),
+            initialization_options: None,
+            env: Default::default(),
+            language_id_overrides: Default::default(),
+            root_markers: Default::default(),
+            name: None,
+            only_features: None,
+            except_features: None,
+        }]),
+    );
+
+    let mut harness = EditorTestHarness::create(
+        80,
+        24,
+        crate::common::harness::HarnessOptions::new()
+            .with_config(config)
+            .with_working_dir(temp_dir.path().to_path_buf()),
+    )?;
+    harness.open_file(&test_file)?;
+    harness.render()?;
+
+    // Wait for the server's warning to arrive
+    harness.wait_until(|h| !h.editor().get_stored_diagnostics().is_empty())?;
+
+    // Put the cursor inside `std::io`
+    for _ in 0..5 {
+        harness.send_key(KeyCode::Right, KeyModifiers::NONE)?;
+    }
+    harness.send_key(KeyCode::Char('.'), KeyModifiers::ALT)?;
+    harness.render()?;
+
+    harness.wait_for_screen_contains("Remove unused import")?;
+    harness.send_key(KeyCode::Enter, KeyModifiers::NONE)?;
+    harness.render()?;
+
+    harness.wait_until(|h| h.get_buffer_content().as_deref() == Some("fn main() {}\n"))?;
+
+    // The edit went through the bulk-edit path, so one undo restores it
+    harness.send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)?;
+    harness.render()?;
+    harness.assert_buffer_content(original);
+
+    Ok(())
+}
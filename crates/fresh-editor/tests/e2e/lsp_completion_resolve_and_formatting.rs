@@ -109,6 +109,24 @@ fn setup_editor(
     initial_content: &str,
     file_ext: &str,
     language: &str,
+) -> anyhow::Result<(EditorTestHarness, std::path::PathBuf)> {
+    setup_editor_with_config(
+        temp_dir,
+        log_file,
+        initial_content,
+        file_ext,
+        language,
+        |_| {},
+    )
+}
+
+fn setup_editor_with_config(
+    temp_dir: &tempfile::TempDir,
+    log_file: &std::path::Path,
+    initial_content: &str,
+    file_ext: &str,
+    language: &str,
+    configure: impl FnOnce(&mut fresh::config::Config),
 ) -> anyhow::Result<(EditorTestHarness, std::path::PathBuf)> {
     let script_path = create_completion_and_formatting_lsp_script(temp_dir.path());
     let test_file = temp_dir.path().join(format!("test.{}", file_ext));
@@ -132,6 +150,7 @@ fn setup_editor(
             except_features: None,
         }]),
     );
+    configure(&mut config);
 
     let mut harness = EditorTestHarness::create(
         120,
@@ -250,3 +269,79 @@ fn test_format_buffer_via_lsp() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+/// Test: the "Format Document (LSP)" command formats via the server even
+/// for a language that has an external formatter configured.
+#[test]
+#[cfg_attr(target_os = "windows", ignore)]
+fn test_lsp_format_document_command() -> anyhow::Result<()> {
+    let temp_dir = tempfile::tempdir()?;
+    let log_file = temp_dir.path().join("lsp_format_cmd_log.txt");
+    // Rust has rustfmt configured; the LSP command must bypass it
+    let (mut harness, _test_file) = setup_editor(
+        &temp_dir,
+        &log_file,
+        "fn main() {\n    let x = 5;\n}\n",
+        "rs",
+        "rust",
+    )?;
+
+    harness.wait_for_screen_contains("LSP (on)")?;
+
+    harness.send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)?;
+    harness.render()?;
+    harness.type_text("Format Document (LSP)")?;
+    harness.render()?;
+    harness.send_key(KeyCode::Enter, KeyModifiers::NONE)?;
+    harness.render()?;
+
+    harness.wait_until(|_| {
+        let log = std::fs::read_to_string(&log_file).unwrap_or_default();
+        log.contains("METHOD:textDocument/formatting")
+    })?;
+    harness.wait_for_screen_contains("Formatted (1 edits)")?;
+    harness.assert_buffer_content("fn main() {\n    let x = 5;\n}\n");
+
+    Ok(())
+}
+
+/// Test: format_on_save with no external formatter requests LSP formatting
+/// and re-saves the buffer once the edits are applied.
+#[test]
+#[cfg_attr(target_os = "windows", ignore)]
+fn test_format_on_save_via_lsp() -> anyhow::Result<()> {
+    let temp_dir = tempfile::tempdir()?;
+    let log_file = temp_dir.path().join("lsp_format_on_save_log.txt");
+    let (mut harness, test_file) = setup_editor_with_config(
+        &temp_dir,
+        &log_file,
+        "function main()\n    local x = 5\nend\n",
+        "lua",
+        "lua",
+        |config| {
+            let lua = config.languages.get_mut("lua").unwrap();
+            lua.formatter = None;
+            lua.format_on_save = true;
+        },
+    )?;
+
+    harness.wait_for_screen_contains("LSP (on)")?;
+
+    // Dirty the buffer so the save has something to write
+    harness.send_key(KeyCode::End, KeyModifiers::NONE)?;
+    harness.type_text(" -- entry")?;
+    harness.send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)?;
+    harness.render()?;
+
+    harness.wait_until(|_| {
+        let log = std::fs::read_to_string(&log_file).unwrap_or_default();
+        log.contains("METHOD:textDocument/formatting")
+    })?;
+    harness.wait_for_screen_contains("Formatted (1 edits)")?;
+
+    let on_disk = std::fs::read_to_string(&test_file)?;
+    assert_eq!(on_disk, "function main() -- entry\n    local x = 5\nend\n");
+    assert!(!harness.editor().active_state().buffer.is_modified());
+
+    Ok(())
+}
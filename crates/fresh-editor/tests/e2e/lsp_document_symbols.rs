@@ -0,0 +1,142 @@
+//! E2E tests for the textDocument/documentSymbol outline popup.
+
+use crate::common::harness::{EditorTestHarness, HarnessOptions};
+use crossterm::event::{KeyCode, KeyModifiers};
+
+/// Create a fake LSP server whose documentSymbol response lists two
+/// top-level functions (hierarchical form), the second with a nested local.
+fn create_document_symbols_lsp_script(dir: &std::path::Path) -> std::path::PathBuf {
+    let script = r##"#!/bin/bash
+
+read_message() {
+    local content_length=0
+    while IFS=: read -r key value; do
+        key=$(echo "$key" | tr -d '\r\n')
+        value=$(echo "$value" | tr -d '\r\n ')
+        if [ "$key" = "Content-Length" ]; then
+            content_length=$value
+        fi
+        if [ -z "$key" ]; then
+            break
+        fi
+    done
+    if [ $content_length -gt 0 ]; then
+        dd bs=1 count=$content_length 2>/dev/null
+    fi
+}
+
+send_message() {
+    local message="$1"
+    local length=${#message}
+    printf "Content-Length: $length\r\n\r\n%s" "$message"
+}
+
+while true; do
+    msg=$(read_message)
+    if [ -z "$msg" ]; then break; fi
+
+    method=$(echo "$msg" | grep -o '"method":"[^"]*"' | cut -d'"' -f4)
+    msg_id=$(echo "$msg" | grep -o '"id":[0-9]*' | cut -d':' -f2)
+
+    case "$method" in
+        "initialize")
+            send_message '{"jsonrpc":"2.0","id":'$msg_id',"result":{"capabilities":{"textDocumentSync":1,"documentSymbolProvider":true}}}'
+            ;;
+        "textDocument/documentSymbol")
+            send_message '{"jsonrpc":"2.0","id":'$msg_id',"result":[{"name":"first","kind":12,"range":{"start":{"line":0,"character":0},"end":{"line":1,"character":1}},"selectionRange":{"start":{"line":0,"character":3},"end":{"line":0,"character":8}}},{"name":"second","kind":12,"range":{"start":{"line":3,"character":0},"end":{"line":5,"character":1}},"selectionRange":{"start":{"line":3,"character":3},"end":{"line":3,"character":9}},"children":[{"name":"inner_value","kind":13,"range":{"start":{"line":4,"character":4},"end":{"line":4,"character":24}},"selectionRange":{"start":{"line":4,"character":8},"end":{"line":4,"character":19}}}]}]}'
+            ;;
+        "shutdown")
+            send_message '{"jsonrpc":"2.0","id":'$msg_id',"result":null}'
+            break
+            ;;
+        *)
+            if [ -n "$msg_id" ]; then
+                send_message '{"jsonrpc":"2.0","id":'$msg_id',"result":null}'
+            fi
+            ;;
+    esac
+done
+"##;
+
+    let script_path = dir.join("fake_lsp_document_symbols.sh");
+    std::fs::write(&script_path, script).expect("Failed to write fake LSP script");
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mut perms = std::fs::metadata(&script_path).unwrap().permissions();
+        perms.set_mode(0o755);
+        std::fs::set_permissions(&script_path, perms).unwrap();
+    }
+
+    script_path
+}
+
+/// Test: selecting the second function in the outline popup moves the
+/// cursor to its name, and nested symbols are listed indented.
+#[test]
+#[cfg_attr(
+    target_os = "windows",
+    ignore = "FakeLspServer uses a Bash script which is not available on Windows"
+)]
+fn test_document_symbols_popup_jumps_to_selection() -> anyhow::Result<()> {
+    let temp_dir = tempfile::tempdir()?;
+    let script_path = create_document_symbols_lsp_script(temp_dir.path());
+    let test_file = temp_dir.path().join("test.rs");
+    std::fs::write(
+        &test_file,
+        "fn first() {\n}\n\nfn second() {\n    let inner_value = 1;\n}\n",
+    )?;
+
+    let mut config = fresh::config::Config::default();
+    config.lsp.insert(
+        "rust".to_string(),
+        fresh::types::LspLanguageConfig::Multi(vec![fresh::services::lsp::LspServerConfig {
+            command: script_path.to_string_lossy().to_string(),
+            args: vec![],
+            enabled: true,
+            auto_start: true,
+            process_limits: fresh::services::process_limits::ProcessLimits::default(),
+            initialization_options: None,
+            env: Default::default(),
+            language_id_overrides: Default::default(),
+            root_markers: Default::default(),
+            name: None,
+            only_features: None,
+            except_features: None,
+        }]),
+    );
+
+    let mut harness = EditorTestHarness::create(
+        120,
+        30,
+        HarnessOptions::new()
+            .with_config(config)
+            .with_working_dir(temp_dir.path().to_path_buf()),
+    )?;
+    harness.open_file(&test_file)?;
+    harness.render()?;
+    harness.wait_for_screen_contains("LSP (on)")?;
+
+    harness.send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)?;
+    harness.type_text("Document Symbols")?;
+    harness.send_key(KeyCode::Enter, KeyModifiers::NONE)?;
+    harness.render()?;
+
+    // Children are indented beneath their parent
+    harness.wait_for_screen_contains("  inner_value")?;
+    harness.assert_screen_contains("first");
+    harness.assert_screen_contains("second");
+
+    // Select "second" (one below the initially selected "first")
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE)?;
+    harness.send_key(KeyCode::Enter, KeyModifiers::NONE)?;
+    harness.render()?;
+
+    // Line 3, character 3: the start of "second"
+    let expected = "fn first() {\n}\n\nfn ".len();
+    assert_eq!(harness.cursor_position(), expected);
+    harness.assert_screen_not_contains("Document Symbols");
+
+    Ok(())
+}
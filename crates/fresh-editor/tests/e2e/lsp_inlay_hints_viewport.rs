@@ -0,0 +1,239 @@
+//! E2E tests: inlay hints are requested for the visible region and
+//! re-requested when scrolling moves past the lines already hinted.
+
+use crate::common::harness::{EditorTestHarness, HarnessOptions};
+use crossterm::event::{KeyCode, KeyModifiers};
+
+/// Fake LSP that logs each inlayHint request's line range (and file name)
+/// and returns a hint on line 390 only when the requested range includes
+/// that line.
+fn create_ranged_inlay_hints_lsp_script(dir: &std::path::Path) -> std::path::PathBuf {
+    let script = r##"#!/bin/bash
+
+LOG_FILE="$1"
+> "$LOG_FILE"
+
+read_message() {
+    local content_length=0
+    while IFS=: read -r key value; do
+        key=$(echo "$key" | tr -d '\r\n')
+        value=$(echo "$value" | tr -d '\r\n ')
+        if [ "$key" = "Content-Length" ]; then
+            content_length=$value
+        fi
+        if [ -z "$key" ]; then
+            break
+        fi
+    done
+    if [ $content_length -gt 0 ]; then
+        dd bs=1 count=$content_length 2>/dev/null
+    fi
+}
+
+send_message() {
+    local message="$1"
+    local length=${#message}
+    printf "Content-Length: $length\r\n\r\n%s" "$message"
+}
+
+while true; do
+    msg=$(read_message)
+    if [ -z "$msg" ]; then break; fi
+
+    method=$(echo "$msg" | grep -o '"method":"[^"]*"' | cut -d'"' -f4)
+    msg_id=$(echo "$msg" | grep -o '"id":[0-9]*' | cut -d':' -f2)
+
+    case "$method" in
+        "initialize")
+            send_message '{"jsonrpc":"2.0","id":'$msg_id',"result":{"capabilities":{"textDocumentSync":1,"inlayHintProvider":true}}}'
+            ;;
+        "textDocument/inlayHint")
+            start=$(echo "$msg" | grep -o '"start":{"line":[0-9]*' | grep -o '[0-9]*$')
+            end=$(echo "$msg" | grep -o '"end":{"line":[0-9]*' | grep -o '[0-9]*$')
+            uri=$(echo "$msg" | grep -o '"uri":"[^"]*"' | head -1 | cut -d'"' -f4)
+            echo "RANGE:$start-$end" >> "$LOG_FILE"
+            echo "FILE:${uri##*/}:$start-$end" >> "$LOG_FILE"
+            if [ "$start" -le 390 ] && [ "$end" -ge 390 ]; then
+                send_message '{"jsonrpc":"2.0","id":'$msg_id',"result":[{"position":{"line":390,"character":8},"label":": i32","kind":1}]}'
+            else
+                send_message '{"jsonrpc":"2.0","id":'$msg_id',"result":[]}'
+            fi
+            ;;
+        "shutdown")
+            send_message '{"jsonrpc":"2.0","id":'$msg_id',"result":null}'
+            break
+            ;;
+        *)
+            if [ -n "$msg_id" ]; then
+                send_message '{"jsonrpc":"2.0","id":'$msg_id',"result":null}'
+            fi
+            ;;
+    esac
+done
+"##;
+
+    let script_path = dir.join("fake_lsp_ranged_inlay_hints.sh");
+    std::fs::write(&script_path, script).expect("Failed to write fake LSP script");
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mut perms = std::fs::metadata(&script_path).unwrap().permissions();
+        perms.set_mode(0o755);
+        std::fs::set_permissions(&script_path, perms).unwrap();
+    }
+
+    script_path
+}
+
+fn requested_ranges(log_file: &std::path::Path) -> Vec<(u32, u32)> {
+    std::fs::read_to_string(log_file)
+        .unwrap_or_default()
+        .lines()
+        .filter_map(|l| l.strip_prefix("RANGE:"))
+        .filter_map(|r| {
+            let (start, end) = r.split_once('-')?;
+            Some((start.parse().ok()?, end.parse().ok()?))
+        })
+        .collect()
+}
+
+/// Number of inlayHint requests logged for `file_name`.
+fn request_count(log_file: &std::path::Path, file_name: &str) -> usize {
+    let prefix = format!("FILE:{file_name}:");
+    std::fs::read_to_string(log_file)
+        .unwrap_or_default()
+        .lines()
+        .filter(|l| l.starts_with(&prefix))
+        .count()
+}
+
+fn ranged_inlay_hints_config(
+    script_path: &std::path::Path,
+    log_file: &std::path::Path,
+) -> fresh::config::Config {
+    let mut config = fresh::config::Config::default();
+    config.editor.enable_inlay_hints = true;
+    config.lsp.insert(
+        "rust".to_string(),
+        fresh::types::LspLanguageConfig::Multi(vec![fresh::services::lsp::LspServerConfig {
+            command: script_path.to_string_lossy().to_string(),
+            args: vec![log_file.to_string_lossy().to_string()],
+            enabled: true,
+            auto_start: true,
+            process_limits: fresh::services::process_limits::ProcessLimits::default(),
+            initialization_options: None,
+            env: Default::default(),
+            language_id_overrides: Default::default(),
+            root_markers: Default::default(),
+            name: None,
+            only_features: None,
+            except_features: None,
+        }]),
+    );
+    config
+}
+
+/// Test: the first request covers only the region around the viewport, so
+/// the hint near the end of the file shows up only after scrolling to it.
+#[test]
+#[cfg_attr(
+    target_os = "windows",
+    ignore = "FakeLspServer uses a Bash script which is not available on Windows"
+)]
+fn test_inlay_hints_requested_after_scrolling_into_view() -> anyhow::Result<()> {
+    let temp_dir = tempfile::tempdir()?;
+    let log_file = temp_dir.path().join("inlay_ranges.txt");
+    let script_path = create_ranged_inlay_hints_lsp_script(temp_dir.path());
+
+    let test_file = temp_dir.path().join("test.rs");
+    let content: String = (0..400).map(|i| format!("let v{i} = {i};\n")).collect();
+    std::fs::write(&test_file, content)?;
+
+    let config = ranged_inlay_hints_config(&script_path, &log_file);
+
+    let mut harness = EditorTestHarness::create(
+        80,
+        24,
+        HarnessOptions::new()
+            .with_config(config)
+            .with_working_dir(temp_dir.path().to_path_buf()),
+    )?;
+    harness.open_file(&test_file)?;
+    // Render before the server initializes so the first request is scoped
+    // to the viewport rather than the whole document.
+    harness.render()?;
+
+    harness.wait_until(|_| !requested_ranges(&log_file).is_empty())?;
+    let (first_start, first_end) = requested_ranges(&log_file)[0];
+    assert_eq!(first_start, 0);
+    assert!(
+        first_end < 390,
+        "first request should cover the padded viewport only, got 0-{}",
+        first_end
+    );
+
+    // Jump to the end of the file; the scroll schedules a new request
+    harness.send_key(KeyCode::End, KeyModifiers::CONTROL)?;
+    harness.render()?;
+
+    harness.wait_for_screen_contains(": i32")?;
+    let ranges = requested_ranges(&log_file);
+    assert!(
+        ranges.iter().any(|&(start, end)| start > 0 && end >= 390),
+        "expected a request for the scrolled-to region, got {:?}",
+        ranges
+    );
+
+    Ok(())
+}
+
+/// Test: editing two buffers within one debounce window re-requests hints
+/// for both, rather than the second edit's refresh replacing the first's.
+#[test]
+#[cfg_attr(
+    target_os = "windows",
+    ignore = "FakeLspServer uses a Bash script which is not available on Windows"
+)]
+fn test_inlay_hints_refresh_scheduled_per_buffer() -> anyhow::Result<()> {
+    let temp_dir = tempfile::tempdir()?;
+    let log_file = temp_dir.path().join("inlay_ranges.txt");
+    let script_path = create_ranged_inlay_hints_lsp_script(temp_dir.path());
+
+    let file_a = temp_dir.path().join("a.rs");
+    let file_b = temp_dir.path().join("b.rs");
+    std::fs::write(&file_a, "let a = 1;\n")?;
+    std::fs::write(&file_b, "let b = 2;\n")?;
+
+    let config = ranged_inlay_hints_config(&script_path, &log_file);
+    let mut harness = EditorTestHarness::create(
+        80,
+        24,
+        HarnessOptions::new()
+            .with_config(config)
+            .with_working_dir(temp_dir.path().to_path_buf()),
+    )?;
+
+    harness.open_file(&file_a)?;
+    harness.render()?;
+    harness.wait_until(|_| request_count(&log_file, "a.rs") > 0)?;
+    harness.open_file(&file_b)?;
+    harness.render()?;
+    harness.wait_until(|_| request_count(&log_file, "b.rs") > 0)?;
+    let (before_a, before_b) = (
+        request_count(&log_file, "a.rs"),
+        request_count(&log_file, "b.rs"),
+    );
+
+    // Edit both buffers before either debounce fires
+    harness.open_file(&file_a)?;
+    harness.type_text("x")?;
+    harness.open_file(&file_b)?;
+    harness.type_text("x")?;
+
+    harness.wait_until(|_| {
+        request_count(&log_file, "a.rs") > before_a && request_count(&log_file, "b.rs") > before_b
+    })?;
+
+    Ok(())
+}
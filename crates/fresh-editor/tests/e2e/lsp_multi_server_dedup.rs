@@ -0,0 +1,207 @@
+//! E2E tests for completions and code actions merged from several LSP servers.
+//!
+//! Each server's entries are tagged with the server's name, and an entry both
+//! servers offer is listed once. Completion items are resolved by the server
+//! that sent them.
+
+use crate::common::fake_lsp::FakeLspServer;
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+
+fn server_config(dir: &std::path::Path, name: &str) -> fresh::services::lsp::LspServerConfig {
+    fresh::services::lsp::LspServerConfig {
+        command: FakeLspServer::offering_script_path(dir, name)
+            .to_string_lossy()
+            .to_string(),
+        args: vec![],
+        enabled: true,
+        auto_start: true,
+        process_limits: fresh::services::process_limits::ProcessLimits::default(),
+        initialization_options: None,
+        env: Default::default(),
+        language_id_overrides: Default::default(),
+        root_markers: Default::default(),
+        name: Some(name.to_string()),
+        only_features: None,
+        except_features: None,
+    }
+}
+
+/// Start the "alpha" and "beta" servers with the given completions and code
+/// actions, open a Rust file and wait for both servers to initialize.
+fn harness_with_two_servers(
+    temp_dir: &std::path::Path,
+    completions: [&str; 2],
+    code_actions: [&str; 2],
+) -> anyhow::Result<(EditorTestHarness, Vec<FakeLspServer>)> {
+    let servers = vec![
+        FakeLspServer::spawn_offering(temp_dir, "alpha", completions[0], code_actions[0])?,
+        FakeLspServer::spawn_offering(temp_dir, "beta", completions[1], code_actions[1])?,
+    ];
+
+    let test_file = temp_dir.join("test.rs");
+    std::fs::write(&test_file, "fn main() {\n    \n    \n    \n    \n}\n")?;
+
+    // Keep stray input from firing extra completion requests
+    let mut config = fresh::config::Config::default();
+    config.editor.quick_suggestions = false;
+    config.lsp.insert(
+        "rust".to_string(),
+        fresh::types::LspLanguageConfig::Multi(vec![
+            server_config(temp_dir, "alpha"),
+            server_config(temp_dir, "beta"),
+        ]),
+    );
+
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(120, 30, config, temp_dir.to_path_buf())?;
+    harness.open_file(&test_file)?;
+    harness.render()?;
+    harness.wait_until(|h| h.editor().initialized_lsp_server_count("rust") >= 2)?;
+
+    Ok((harness, servers))
+}
+
+/// A completion both servers offer is listed once, and the detail of each
+/// item names the server it came from.
+#[test]
+#[cfg_attr(
+    target_os = "windows",
+    ignore = "FakeLspServer uses a Bash script which is not available on Windows"
+)]
+fn test_completions_merged_from_two_servers() -> anyhow::Result<()> {
+    let temp_dir = tempfile::tempdir()?;
+    let shared = r#"{"label":"shared_item","kind":3,"insertText":"shared_item"}"#;
+    let (mut harness, _servers) = harness_with_two_servers(
+        temp_dir.path(),
+        [
+            &format!(r#"[{shared},{{"label":"alpha_item","kind":6,"detail":"a"}}]"#),
+            &format!(r#"[{shared},{{"label":"beta_item","kind":6,"detail":"b"}}]"#),
+        ],
+        ["[]", "[]"],
+    )?;
+
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE)?;
+    harness.send_key(KeyCode::End, KeyModifiers::NONE)?;
+    harness.send_key(KeyCode::Char(' '), KeyModifiers::CONTROL)?;
+
+    harness.wait_until(|h| {
+        let screen = h.screen_to_string();
+        screen.contains("alpha_item") && screen.contains("beta_item")
+    })?;
+
+    assert_eq!(harness.editor().completion_items_count(), 3);
+    let screen = harness.screen_to_string();
+    assert_eq!(
+        screen.matches("shared_item").count(),
+        1,
+        "shared completion should be listed once. Screen:\n{screen}"
+    );
+    assert!(screen.contains("[alpha] a"), "Screen:\n{screen}");
+    assert!(screen.contains("[beta] b"), "Screen:\n{screen}");
+
+    Ok(())
+}
+
+/// Two servers offering items with the same label each resolve their own
+/// item, both for the documentation preview and when the item is accepted.
+#[test]
+#[cfg_attr(
+    target_os = "windows",
+    ignore = "FakeLspServer uses a Bash script which is not available on Windows"
+)]
+fn test_completion_resolved_by_originating_server() -> anyhow::Result<()> {
+    let temp_dir = tempfile::tempdir()?;
+    let (mut harness, _servers) = harness_with_two_servers(
+        temp_dir.path(),
+        [
+            r#"[{"label":"shared_item","kind":3,"insertText":"shared_alpha"}]"#,
+            r#"[{"label":"shared_item","kind":3,"insertText":"shared_beta"}]"#,
+        ],
+        ["[]", "[]"],
+    )?;
+
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE)?;
+    harness.send_key(KeyCode::End, KeyModifiers::NONE)?;
+    harness.send_key(KeyCode::Char(' '), KeyModifiers::CONTROL)?;
+
+    harness.wait_until(|h| {
+        let screen = h.screen_to_string();
+        screen.contains("[alpha]") && screen.contains("[beta]")
+    })?;
+
+    // The first row's preview is resolved by the server that row came from
+    let screen = harness.screen_to_string();
+    let (first, second) = if screen.find("[alpha]") < screen.find("[beta]") {
+        ("alpha", "beta")
+    } else {
+        ("beta", "alpha")
+    };
+    harness.wait_until(|h| {
+        h.screen_to_string()
+            .contains(&format!("Resolved by {first}"))
+    })?;
+
+    // Accepting the second row applies the edits its own server resolved
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE)?;
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE)?;
+    harness.wait_until(|h| {
+        h.get_buffer_content()
+            .is_some_and(|content| content.contains("// from "))
+    })?;
+
+    let content = harness.get_buffer_content().unwrap_or_default();
+    assert!(
+        content.contains(&format!("shared_{second}")),
+        "Content:\n{content}"
+    );
+    assert!(
+        content.starts_with(&format!("// from {second}\n")),
+        "Content:\n{content}"
+    );
+    assert_eq!(
+        content.matches("// from ").count(),
+        1,
+        "Content:\n{content}"
+    );
+
+    Ok(())
+}
+
+/// A code action both servers offer (same title and kind) is listed once.
+#[test]
+#[cfg_attr(
+    target_os = "windows",
+    ignore = "FakeLspServer uses a Bash script which is not available on Windows"
+)]
+fn test_code_actions_deduplicated_across_servers() -> anyhow::Result<()> {
+    let temp_dir = tempfile::tempdir()?;
+    let shared = r#"{"title":"Organize imports","kind":"source.organizeImports"}"#;
+    let (mut harness, _servers) = harness_with_two_servers(
+        temp_dir.path(),
+        ["[]", "[]"],
+        [
+            &format!(r#"[{shared},{{"title":"Alpha fix","kind":"quickfix"}}]"#),
+            &format!(r#"[{shared},{{"title":"Beta fix","kind":"quickfix"}}]"#),
+        ],
+    )?;
+
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE)?;
+    harness.send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)?;
+    harness.type_text("Code Actions")?;
+    harness.send_key(KeyCode::Enter, KeyModifiers::NONE)?;
+
+    harness.wait_until(|h| {
+        let screen = h.screen_to_string();
+        screen.contains("Alpha fix") && screen.contains("Beta fix")
+    })?;
+
+    let screen = harness.screen_to_string();
+    assert_eq!(
+        screen.matches("Organize imports").count(),
+        1,
+        "shared code action should be listed once. Screen:\n{screen}"
+    );
+
+    Ok(())
+}
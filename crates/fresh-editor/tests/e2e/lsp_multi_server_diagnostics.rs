@@ -0,0 +1,191 @@
+//! E2E tests for diagnostics merged from several LSP servers.
+//!
+//! When two servers are configured for one language (e.g. a type checker and
+//! a linter), the buffer shows the diagnostics of both, each tagged with the
+//! server it came from, and a problem reported by both appears once.
+
+use crate::common::fake_lsp::FakeLspServer;
+use crate::common::harness::EditorTestHarness;
+
+fn server_config(dir: &std::path::Path, name: &str) -> fresh::services::lsp::LspServerConfig {
+    fresh::services::lsp::LspServerConfig {
+        command: FakeLspServer::publishing_diagnostics_script_path(dir, name)
+            .to_string_lossy()
+            .to_string(),
+        args: vec![],
+        enabled: true,
+        auto_start: true,
+        process_limits: fresh::services::process_limits::ProcessLimits::default(),
+        initialization_options: None,
+        env: Default::default(),
+        language_id_overrides: Default::default(),
+        root_markers: Default::default(),
+        name: Some(name.to_string()),
+        only_features: None,
+        except_features: None,
+    }
+}
+
+/// Diagnostics published by two servers for the same file are merged:
+/// both servers' problems are stored, untagged ones get the server's name as
+/// their source, and the problem both servers report is kept once.
+#[test]
+#[cfg_attr(
+    target_os = "windows",
+    ignore = "FakeLspServer uses a Bash script which is not available on Windows"
+)]
+fn test_diagnostics_merged_from_two_servers() -> anyhow::Result<()> {
+    let temp_dir = tempfile::tempdir()?;
+
+    let shared = r#"{"range":{"start":{"line":1,"character":8},"end":{"line":1,"character":9}},"severity":1,"message":"mismatched types"}"#;
+    let _checker = FakeLspServer::spawn_publishing_diagnostics(
+        temp_dir.path(),
+        "checker",
+        &format!("[{shared}]"),
+    )?;
+    let _linter = FakeLspServer::spawn_publishing_diagnostics(
+        temp_dir.path(),
+        "linter",
+        &format!(
+            r#"[{shared},{{"range":{{"start":{{"line":1,"character":8}},"end":{{"line":1,"character":9}}}},"severity":2,"source":"lint","message":"unused variable"}}]"#
+        ),
+    )?;
+
+    let test_file = temp_dir.path().join("test.rs");
+    std::fs::write(&test_file, "fn main() {\n    let x: u8 = \"\";\n}\n")?;
+
+    let mut config = fresh::config::Config::default();
+    config.lsp.insert(
+        "rust".to_string(),
+        fresh::types::LspLanguageConfig::Multi(vec![
+            server_config(temp_dir.path(), "checker"),
+            server_config(temp_dir.path(), "linter"),
+        ]),
+    );
+
+    let mut harness = EditorTestHarness::with_config_and_working_dir(
+        120,
+        24,
+        config,
+        temp_dir.path().to_path_buf(),
+    )?;
+
+    harness.open_file(&test_file)?;
+    harness.render()?;
+
+    let stored = |h: &EditorTestHarness| -> Vec<(Option<String>, String)> {
+        let mut diagnostics: Vec<_> = h
+            .editor()
+            .get_stored_diagnostics()
+            .values()
+            .flatten()
+            .map(|d| (d.source.clone(), d.message.clone()))
+            .collect();
+        diagnostics.sort();
+        diagnostics
+    };
+
+    // Until the checker has published, the shared problem is tagged with the
+    // linter, so waiting for the merged result also waits for both servers
+    let expected = vec![
+        (Some("checker".to_string()), "mismatched types".to_string()),
+        (Some("lint".to_string()), "unused variable".to_string()),
+    ];
+    harness.wait_until(|h| stored(h) == expected)?;
+
+    Ok(())
+}
+
+/// Pulled diagnostics are merged with pushed ones the same way: they are
+/// tagged with the server that reported them, and a problem another server
+/// already pushed is kept once.
+#[test]
+#[cfg_attr(
+    target_os = "windows",
+    ignore = "FakeLspServer uses a Bash script which is not available on Windows"
+)]
+fn test_pulled_diagnostics_merged_with_pushed() -> anyhow::Result<()> {
+    use fresh::services::async_bridge::AsyncMessage;
+
+    let temp_dir = tempfile::tempdir()?;
+
+    let shared = r#"{"range":{"start":{"line":1,"character":8},"end":{"line":1,"character":9}},"severity":1,"message":"mismatched types"}"#;
+    let _checker = FakeLspServer::spawn_publishing_diagnostics(
+        temp_dir.path(),
+        "checker",
+        &format!("[{shared}]"),
+    )?;
+    let _linter = FakeLspServer::spawn_publishing_diagnostics(temp_dir.path(), "linter", "[]")?;
+
+    let test_file = temp_dir.path().join("test.rs");
+    std::fs::write(&test_file, "fn main() {\n    let x: u8 = \"\";\n}\n")?;
+
+    let mut config = fresh::config::Config::default();
+    config.lsp.insert(
+        "rust".to_string(),
+        fresh::types::LspLanguageConfig::Multi(vec![
+            server_config(temp_dir.path(), "checker"),
+            server_config(temp_dir.path(), "linter"),
+        ]),
+    );
+
+    let mut harness = EditorTestHarness::with_config_and_working_dir(
+        120,
+        24,
+        config,
+        temp_dir.path().to_path_buf(),
+    )?;
+
+    harness.open_file(&test_file)?;
+    harness.render()?;
+    harness.wait_until(|h| h.editor().initialized_lsp_server_count("rust") >= 2)?;
+
+    let stored = |h: &EditorTestHarness| -> Vec<(Option<String>, String)> {
+        let mut diagnostics: Vec<_> = h
+            .editor()
+            .get_stored_diagnostics()
+            .values()
+            .flatten()
+            .map(|d| (d.source.clone(), d.message.clone()))
+            .collect();
+        diagnostics.sort();
+        diagnostics
+    };
+    harness.wait_until(|h| {
+        stored(h) == vec![(Some("checker".to_string()), "mismatched types".to_string())]
+    })?;
+
+    let diagnostic = |line: u32, message: &str| {
+        let mut diagnostic = lsp_types::Diagnostic::new_simple(
+            lsp_types::Range {
+                start: lsp_types::Position { line, character: 8 },
+                end: lsp_types::Position { line, character: 9 },
+            },
+            message.to_string(),
+        );
+        diagnostic.severity = Some(lsp_types::DiagnosticSeverity::ERROR);
+        diagnostic
+    };
+    let uri = fresh_core::file_uri::path_to_lsp_uri(&test_file).expect("Should create URI");
+    if let Some(bridge) = harness.editor().async_bridge() {
+        let _ = bridge.sender().send(AsyncMessage::LspPulledDiagnostics {
+            request_id: 1,
+            uri: uri.as_str().to_string(),
+            result_id: None,
+            diagnostics: vec![
+                diagnostic(1, "mismatched types"),
+                diagnostic(2, "pulled problem"),
+            ],
+            unchanged: false,
+            server_name: "linter".to_string(),
+        });
+    }
+
+    let expected = vec![
+        (Some("checker".to_string()), "mismatched types".to_string()),
+        (Some("linter".to_string()), "pulled problem".to_string()),
+    ];
+    harness.wait_until(|h| stored(h) == expected)?;
+
+    Ok(())
+}
@@ -0,0 +1,165 @@
+//! E2E tests for the workspace/symbol search prompt.
+
+use crate::common::harness::{EditorTestHarness, HarnessOptions};
+use crossterm::event::{KeyCode, KeyModifiers};
+
+/// Create a fake LSP server whose workspace/symbol response lists one
+/// symbol in `a.rs` and one in `b.rs` (both under the directory passed as
+/// the first argument). Each query is logged so the test can check that an
+/// empty query is never sent.
+fn create_workspace_symbols_lsp_script(dir: &std::path::Path) -> std::path::PathBuf {
+    let script = r##"#!/bin/bash
+
+ROOT="$1"
+LOG_FILE="$2"
+> "$LOG_FILE"
+
+read_message() {
+    local content_length=0
+    while IFS=: read -r key value; do
+        key=$(echo "$key" | tr -d '\r\n')
+        value=$(echo "$value" | tr -d '\r\n ')
+        if [ "$key" = "Content-Length" ]; then
+            content_length=$value
+        fi
+        if [ -z "$key" ]; then
+            break
+        fi
+    done
+    if [ $content_length -gt 0 ]; then
+        dd bs=1 count=$content_length 2>/dev/null
+    fi
+}
+
+send_message() {
+    local message="$1"
+    local length=${#message}
+    printf "Content-Length: $length\r\n\r\n%s" "$message"
+}
+
+while true; do
+    msg=$(read_message)
+    if [ -z "$msg" ]; then break; fi
+
+    method=$(echo "$msg" | grep -o '"method":"[^"]*"' | cut -d'"' -f4)
+    msg_id=$(echo "$msg" | grep -o '"id":[0-9]*' | cut -d':' -f2)
+
+    case "$method" in
+        "initialize")
+            send_message '{"jsonrpc":"2.0","id":'$msg_id',"result":{"capabilities":{"textDocumentSync":1,"workspaceSymbolProvider":true}}}'
+            ;;
+        "workspace/symbol")
+            query=$(echo "$msg" | grep -o '"query":"[^"]*"' | cut -d'"' -f4)
+            echo "QUERY:$query" >> "$LOG_FILE"
+            send_message '{"jsonrpc":"2.0","id":'$msg_id',"result":[{"name":"Widget","kind":23,"location":{"uri":"file://'$ROOT'/a.rs","range":{"start":{"line":0,"character":11},"end":{"line":0,"character":17}}}},{"name":"make_widget","kind":12,"location":{"uri":"file://'$ROOT'/b.rs","range":{"start":{"line":2,"character":3},"end":{"line":2,"character":14}}}}]}'
+            ;;
+        "shutdown")
+            send_message '{"jsonrpc":"2.0","id":'$msg_id',"result":null}'
+            break
+            ;;
+        *)
+            if [ -n "$msg_id" ]; then
+                send_message '{"jsonrpc":"2.0","id":'$msg_id',"result":null}'
+            fi
+            ;;
+    esac
+done
+"##;
+
+    let script_path = dir.join("fake_lsp_workspace_symbols.sh");
+    std::fs::write(&script_path, script).expect("Failed to write fake LSP script");
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mut perms = std::fs::metadata(&script_path).unwrap().permissions();
+        perms.set_mode(0o755);
+        std::fs::set_permissions(&script_path, perms).unwrap();
+    }
+
+    script_path
+}
+
+/// Test: typing a query lists matches from both files, and selecting the
+/// one in the unopened `b.rs` opens it with the cursor on the symbol.
+#[test]
+#[cfg_attr(
+    target_os = "windows",
+    ignore = "FakeLspServer uses a Bash script which is not available on Windows"
+)]
+fn test_workspace_symbols_opens_selected_file() -> anyhow::Result<()> {
+    let temp_dir = tempfile::tempdir()?;
+    // Canonicalize so the URIs the server builds match the opened paths
+    let root = temp_dir.path().canonicalize()?;
+    let log_file = root.join("queries.txt");
+    let script_path = create_workspace_symbols_lsp_script(&root);
+
+    let a_file = root.join("a.rs");
+    let b_content = "use crate::Widget;\n\nfn make_widget() -> Widget {\n    Widget\n}\n";
+    std::fs::write(&a_file, "pub struct Widget;\n")?;
+    std::fs::write(root.join("b.rs"), b_content)?;
+
+    let mut config = fresh::config::Config::default();
+    config.lsp.insert(
+        "rust".to_string(),
+        fresh::types::LspLanguageConfig::Multi(vec![fresh::services::lsp::LspServerConfig {
+            command: script_path.to_string_lossy().to_string(),
+            args: vec![
+                root.to_string_lossy().to_string(),
+                log_file.to_string_lossy().to_string(),
+            ],
+            enabled: true,
+            auto_start: true,
+            process_limits: fresh::services::process_limits::ProcessLimits::default(),
+            initialization_options: None,
+            env: Default::default(),
+            language_id_overrides: Default::default(),
+            root_markers: Default::default(),
+            name: None,
+            only_features: None,
+            except_features: None,
+        }]),
+    );
+
+    let mut harness = EditorTestHarness::create(
+        120,
+        30,
+        HarnessOptions::new()
+            .with_config(config)
+            .with_working_dir(root.clone()),
+    )?;
+    harness.open_file(&a_file)?;
+    harness.render()?;
+    harness.wait_for_screen_contains("LSP (on)")?;
+
+    harness.send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)?;
+    harness.type_text("Workspace Symbols")?;
+    harness.send_key(KeyCode::Enter, KeyModifiers::NONE)?;
+    harness.render()?;
+
+    // Nothing is sent until there is a query to send
+    harness.assert_screen_contains("Type a symbol name to search the workspace");
+
+    harness.type_text("widget")?;
+    harness.wait_for_screen_contains("make_widget")?;
+    harness.assert_screen_contains("b.rs:3");
+
+    let queries = std::fs::read_to_string(&log_file)?;
+    assert!(
+        queries.lines().all(|l| l != "QUERY:"),
+        "empty query was sent: {:?}",
+        queries
+    );
+
+    // Select "make_widget" (one below "Widget")
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE)?;
+    harness.send_key(KeyCode::Enter, KeyModifiers::NONE)?;
+    harness.render()?;
+
+    harness.assert_buffer_content(b_content);
+    // Line 2, character 3: the start of "make_widget"
+    let expected = "use crate::Widget;\n\nfn ".len();
+    assert_eq!(harness.cursor_position(), expected);
+
+    Ok(())
+}
@@ -9,11 +9,13 @@ pub mod basic;
 pub mod binary_file;
 pub mod block_selection;
 pub mod blog_showcases;
+pub mod bookmarks;
 pub mod buffer_groups;
 pub mod buffer_lifecycle;
 pub mod buffer_settings_commands;
 pub mod capslock_shortcuts;
 pub mod case_conversion;
+pub mod clipboard_registers;
 pub mod command_palette;
 pub mod config_language_selector;
 pub mod copy_buffer_path;
@@ -29,6 +31,7 @@ pub mod document_model;
 pub mod duplicate_line;
 pub mod emacs_actions;
 pub mod encoding;
+pub mod event_replay;
 pub mod explorer_bugs;
 pub mod explorer_context_menu;
 pub mod explorer_menu;
@@ -47,6 +50,7 @@ pub mod horizontal_scrollbar;
 pub mod hot_exit_flows;
 pub mod hot_exit_recovery_lsp_sync;
 pub mod indent_dedent;
+pub mod indent_guides;
 pub mod inline_diagnostics;
 pub mod issue_1147_wrapped_line_nav;
 pub mod issue_1288_word_select_whitespace;
@@ -87,6 +91,7 @@ pub mod locale;
 pub mod lsp;
 pub mod lsp_autostart_selective;
 pub mod lsp_bulk_edit_undo_desync;
+pub mod lsp_code_action_diagnostic_context;
 pub mod lsp_code_action_modal;
 pub mod lsp_code_action_resolve_and_commands;
 pub mod lsp_completion_duplicate_entries_1514;
@@ -97,13 +102,17 @@ pub mod lsp_config;
 pub mod lsp_crash_loop;
 pub mod lsp_cross_language_diagnostic_pull;
 pub mod lsp_diagnostic_flow;
+pub mod lsp_document_symbols;
 pub mod lsp_env;
 pub mod lsp_goto_definition_readonly;
 pub mod lsp_indicator_click_to_open;
 pub mod lsp_inlay_hints_capability;
+pub mod lsp_inlay_hints_viewport;
 pub mod lsp_lifecycle_visibility;
 pub mod lsp_missing_binary_and_dismiss;
 pub mod lsp_multi_semantic_tokens;
+pub mod lsp_multi_server_dedup;
+pub mod lsp_multi_server_diagnostics;
 pub mod lsp_no_config;
 pub mod lsp_order;
 pub mod lsp_popup_focus_keybinding;
@@ -113,6 +122,7 @@ pub mod lsp_stop_stale_indicator;
 pub mod lsp_toggle_desync;
 pub mod lsp_unified_code_actions;
 pub mod lsp_unresponsive_capability_does_not_block;
+pub mod lsp_workspace_symbols;
 pub mod macros;
 pub mod margin;
 pub mod markdown_compose;
@@ -143,6 +153,7 @@ pub mod preview_lsp_popup_focus;
 pub mod preview_tabs;
 pub mod prompt;
 pub mod prompt_editing;
+pub mod read_only_mode;
 pub mod recovery;
 pub mod remote_fs_test;
 pub mod remote_indicator_popup;
@@ -191,6 +202,7 @@ pub mod status_bar_config;
 pub mod status_bar_message_click;
 pub mod stdin_input;
 pub mod sudo_save_prompt;
+pub mod surround;
 #[cfg(unix)]
 pub mod symlinks;
 pub mod syntax_highlighting_coverage;
@@ -109,6 +109,39 @@ fn test_scrollbar_click_jump() {
     );
 }
 
+/// Test that clicking a row of the scrollbar minimap scrolls to the lines it condenses
+#[test]
+fn test_scrollbar_minimap_click_jumps_to_row() {
+    let mut config = fresh::config::Config::default();
+    config.editor.scrollbar_minimap = true;
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+
+    let content: String = (1..=1000).map(|i| format!("Line {i}\n")).collect();
+    let _fixture = harness.load_buffer_from_text(&content).unwrap();
+    harness.render().unwrap();
+    assert_eq!(harness.top_line_number(), 0);
+
+    let (content_first_row, content_last_row) = harness.content_area_rows();
+    let track_height = content_last_row - content_first_row + 1;
+    let lines_per_row = 1000usize.div_ceil(track_height);
+    let click_offset = track_height / 2;
+
+    harness
+        .mouse_click(79, (content_first_row + click_offset) as u16)
+        .unwrap();
+    harness.render().unwrap();
+
+    // The clicked row's lines are centred in the viewport
+    let row_centre = click_offset * lines_per_row + lines_per_row / 2;
+    let expected_top = row_centre - harness.viewport_height() / 2;
+    let top_line = harness.top_line_number();
+    assert!(
+        top_line.abs_diff(expected_top) <= lines_per_row,
+        "Minimap click should scroll near line {expected_top}, got {top_line}"
+    );
+    harness.assert_screen_contains(&format!("Line {} ", row_centre + 1));
+}
+
 /// Test dragging scrollbar to scroll
 #[test]
 fn test_scrollbar_drag() {
@@ -2373,3 +2406,151 @@ fn test_scrollbar_track_hover_then_click_clears_highlight() {
         click_row, post_click_style.bg
     );
 }
+
+/// Test middle-click pastes the clipboard at the clicked position
+#[test]
+fn test_middle_click_pastes_at_click_position() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.type_text("hello world").unwrap();
+    harness
+        .editor_mut()
+        .set_clipboard_for_test("big ".to_string());
+    harness.render().unwrap();
+
+    let (col, row) = harness.find_text_on_screen("world").unwrap();
+    harness.mouse_middle_click(col, row).unwrap();
+
+    harness.assert_buffer_content("hello big world");
+    assert_eq!(harness.cursor_position(), "hello big ".len());
+}
+
+/// Test middle-click pastes the active selection rather than the clipboard
+#[test]
+fn test_middle_click_pastes_active_selection() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.type_text("hello world").unwrap();
+    harness
+        .editor_mut()
+        .set_clipboard_for_test("big ".to_string());
+    harness.send_key(KeyCode::Home, KeyModifiers::NONE).unwrap();
+    for _ in 0.."hello".len() {
+        harness
+            .send_key(KeyCode::Right, KeyModifiers::SHIFT)
+            .unwrap();
+    }
+    harness.render().unwrap();
+
+    let (col, row) = harness.find_text_on_screen("world").unwrap();
+    harness
+        .mouse_middle_click(col + "world".len() as u16, row)
+        .unwrap();
+
+    harness.assert_buffer_content("hello worldhello");
+    assert_eq!(harness.cursor_position(), "hello worldhello".len());
+    assert!(
+        harness
+            .editor()
+            .active_cursors()
+            .primary()
+            .selection_range()
+            .is_none(),
+        "The click should replace the selection with a plain cursor"
+    );
+}
+
+/// Test middle-click leaves a read-only buffer untouched
+#[test]
+fn test_middle_click_respects_read_only() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.type_text("hello world").unwrap();
+    harness
+        .editor_mut()
+        .set_clipboard_for_test("big ".to_string());
+    let buffer_id = harness.editor().active_buffer();
+    harness.editor_mut().mark_buffer_read_only(buffer_id, true);
+    harness.render().unwrap();
+
+    let (col, row) = harness.find_text_on_screen("world").unwrap();
+    harness.mouse_middle_click(col, row).unwrap();
+
+    harness.assert_buffer_content("hello world");
+}
+
+/// Test dragging past the bottom edge scrolls the view and keeps extending
+/// the selection
+#[test]
+fn test_drag_past_bottom_edge_autoscrolls() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    let content: String = (1..=100).map(|i| format!("line {i}\n")).collect();
+    let _fixture = harness.load_buffer_from_text(&content).unwrap();
+    harness.render().unwrap();
+
+    let (first_row, last_row) = harness.content_area_rows();
+    let (start_col, start_row) = harness.find_text_on_screen("line 1").unwrap();
+    harness
+        .send_mouse(MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column: start_col,
+            row: start_row,
+            modifiers: KeyModifiers::empty(),
+        })
+        .unwrap();
+    for _ in 0..5 {
+        harness
+            .send_mouse(MouseEvent {
+                kind: MouseEventKind::Drag(MouseButton::Left),
+                column: start_col,
+                row: last_row as u16 + 1,
+                modifiers: KeyModifiers::empty(),
+            })
+            .unwrap();
+        harness.render().unwrap();
+    }
+    harness
+        .send_mouse(MouseEvent {
+            kind: MouseEventKind::Up(MouseButton::Left),
+            column: start_col,
+            row: last_row as u16 + 1,
+            modifiers: KeyModifiers::empty(),
+        })
+        .unwrap();
+    harness.render().unwrap();
+
+    assert!(
+        harness.editor().active_viewport().top_byte > 0,
+        "Dragging past the bottom edge should scroll the view"
+    );
+    let selected = harness.get_selected_text();
+    assert!(selected.starts_with("line 1\n"), "Selected: {selected:?}");
+    assert!(
+        selected.lines().count() > last_row - first_row + 1,
+        "Selection should extend past the initially visible lines: {selected:?}"
+    );
+}
+
+/// Test turning mouse capture off tells the terminal to stop reporting the
+/// mouse and ignores events still delivered
+#[test]
+fn test_toggle_mouse_capture_off_ignores_mouse_events() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.editor_mut().set_session_mode(true);
+    harness.type_text("hello world").unwrap();
+    harness.render().unwrap();
+    let (col, row) = harness.find_text_on_screen("hello").unwrap();
+
+    harness.editor_mut().toggle_mouse_capture();
+    assert!(!harness.editor().is_mouse_enabled());
+    let sequences = harness.editor_mut().take_pending_escape_sequences();
+    assert!(String::from_utf8_lossy(&sequences).contains("\x1b[?1000l"));
+
+    harness.mouse_click(col, row).unwrap();
+    assert_eq!(harness.cursor_position(), "hello world".len());
+
+    harness.editor_mut().toggle_mouse_capture();
+    assert!(harness.editor().is_mouse_enabled());
+    let sequences = harness.editor_mut().take_pending_escape_sequences();
+    assert!(String::from_utf8_lossy(&sequences).contains("\x1b[?1000h"));
+
+    harness.mouse_click(col, row).unwrap();
+    assert_eq!(harness.cursor_position(), 0);
+}
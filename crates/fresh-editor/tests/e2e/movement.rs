@@ -130,6 +130,69 @@ fn test_move_line_up_down_shortcuts() {
     harness.assert_buffer_content("A\nB\nC");
 }
 
+/// Test moving a middle line up and down with Alt+Up/Down, then undoing
+#[test]
+fn test_move_middle_line_up_down_and_undo() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.type_text("one\ntwo\nthree").unwrap();
+
+    // Cursor on "two", column 1
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+        .unwrap();
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness
+        .send_key(KeyCode::Right, KeyModifiers::NONE)
+        .unwrap();
+
+    harness.send_key(KeyCode::Up, KeyModifiers::ALT).unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), "two\none\nthree");
+    assert_eq!(harness.cursor_position(), 1, "cursor stays on moved text");
+
+    // Already at the top: nothing changes
+    harness.send_key(KeyCode::Up, KeyModifiers::ALT).unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), "two\none\nthree");
+
+    harness.send_key(KeyCode::Down, KeyModifiers::ALT).unwrap();
+    harness.send_key(KeyCode::Down, KeyModifiers::ALT).unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), "one\nthree\ntwo");
+    assert_eq!(harness.cursor_position(), 11);
+
+    // Each move is a single undo step
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), "one\ntwo\nthree");
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), "two\none\nthree");
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), "one\ntwo\nthree");
+}
+
+/// Test moving a multi-line selection as a block keeps it selected
+#[test]
+fn test_move_selected_block_down() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.type_text("a\nb\nc\nd").unwrap();
+
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Down, KeyModifiers::SHIFT)
+        .unwrap();
+    harness.send_key(KeyCode::End, KeyModifiers::SHIFT).unwrap();
+    assert_eq!(harness.get_selected_text(), "a\nb");
+
+    harness.send_key(KeyCode::Down, KeyModifiers::ALT).unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), "c\na\nb\nd");
+    assert_eq!(harness.get_selected_text(), "a\nb");
+}
+
 /// Test basic editing operations: insert, modify, delete, newline
 /// This test verifies both buffer state and rendered screen output
 #[test]
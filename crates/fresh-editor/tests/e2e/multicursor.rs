@@ -2069,3 +2069,174 @@ fn test_multi_cursor_enter_markdown_bullet_list() {
         x_count, content_after
     );
 }
+
+/// Test that Select All Matches puts a selection on each match of the
+/// current search, so typing replaces all of them
+#[test]
+fn test_select_all_matches_from_search() {
+    use crossterm::event::{KeyCode, KeyModifiers};
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+
+    harness.type_text("foo bar foo baz foo").unwrap();
+
+    harness
+        .send_key(KeyCode::Char('f'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("foo").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("Select All Matches").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+
+    let mut selections = harness.editor().active_cursors().selections();
+    selections.sort_by_key(|r| r.start);
+    assert_eq!(selections, vec![0..3, 8..11, 16..19]);
+
+    harness.type_text("qux").unwrap();
+    harness.assert_buffer_content("qux bar qux baz qux");
+}
+
+/// Test adding next occurrences one at a time while skipping one of them
+#[test]
+fn test_skip_last_selection_match() {
+    use crossterm::event::{KeyCode, KeyModifiers};
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+
+    harness.type_text("foo bar foo baz foo qux foo").unwrap();
+    harness.send_key(KeyCode::Home, KeyModifiers::NONE).unwrap();
+
+    // First press selects the word under the cursor
+    harness.editor_mut().add_cursor_at_next_match();
+    // Add the second "foo", then move that selection on to the third
+    harness.editor_mut().add_cursor_at_next_match();
+    harness.editor_mut().skip_last_selection_match();
+    // Add the fourth
+    harness.editor_mut().add_cursor_at_next_match();
+    harness.render().unwrap();
+
+    let mut selections = harness.editor().active_cursors().selections();
+    selections.sort_by_key(|r| r.start);
+    assert_eq!(selections, vec![0..3, 16..19, 24..27]);
+
+    harness.type_text("X").unwrap();
+    harness.assert_buffer_content("X bar foo baz X qux X");
+}
+
+/// Align Cursors on `=` pads each assignment so the `=` line up, leaves a
+/// line without one alone, and undoes in one step
+#[test]
+fn test_align_cursors_on_delimiter() {
+    use crossterm::event::{KeyCode, KeyModifiers};
+    use fresh::input::keybindings::Action;
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+
+    let original = "let x = 1;\nlet long = 2;\nlet mid = 3;\nfoo();";
+    harness.type_text(original).unwrap();
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+        .unwrap();
+    for _ in 0..3 {
+        harness.editor_mut().add_cursor_below();
+    }
+
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::AlignCursors("=".to_string()));
+    harness.assert_buffer_content("let x    = 1;\nlet long = 2;\nlet mid  = 3;\nfoo();");
+
+    // The cursors stay at the start of their lines
+    let mut positions = harness.editor().active_cursors().positions();
+    positions.sort();
+    assert_eq!(positions, vec![0, 14, 28, 42]);
+
+    harness.editor_mut().dispatch_action_for_tests(Action::Undo);
+    harness.assert_buffer_content(original);
+}
+
+/// With no delimiter the cursors themselves are lined up
+#[test]
+fn test_align_cursors_to_one_column() {
+    use crossterm::event::{KeyCode, KeyModifiers};
+    use fresh::input::keybindings::Action;
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+
+    harness.type_text("ab\nabcd\na").unwrap();
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+        .unwrap();
+    harness.editor_mut().add_cursor_below();
+    harness.editor_mut().add_cursor_below();
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::AlignCursors(String::new()));
+    harness.type_text("|").unwrap();
+    harness.assert_buffer_content("ab  |\nabcd|\na   |");
+}
+
+/// Rotate Primary Cursor walks the primary through the cursors in buffer
+/// order, and Collapse to Primary Cursor keeps only that one and its
+/// selection
+#[test]
+fn test_rotate_primary_then_collapse() {
+    use crossterm::event::{KeyCode, KeyModifiers};
+    use fresh::input::keybindings::Action;
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+
+    harness.type_text("foo bar foo baz foo").unwrap();
+    harness.send_key(KeyCode::Home, KeyModifiers::NONE).unwrap();
+    for _ in 0..3 {
+        harness.editor_mut().add_cursor_at_next_match();
+    }
+    assert_eq!(harness.editor().active_cursors().count(), 3);
+    assert_eq!(
+        harness
+            .editor()
+            .active_cursors()
+            .primary()
+            .selection_range(),
+        Some(16..19)
+    );
+
+    // The last cursor is primary, so rotating wraps to the first
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::RotatePrimaryCursor);
+    assert_eq!(
+        harness
+            .editor()
+            .active_cursors()
+            .primary()
+            .selection_range(),
+        Some(0..3)
+    );
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::RotatePrimaryCursor);
+    assert_eq!(
+        harness
+            .editor()
+            .active_cursors()
+            .primary()
+            .selection_range(),
+        Some(8..11)
+    );
+
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::CollapseToSinglePrimary);
+    assert_eq!(harness.editor().active_cursors().count(), 1);
+    assert_eq!(harness.editor().active_cursors().selections(), vec![8..11]);
+
+    harness.type_text("X").unwrap();
+    harness.assert_buffer_content("foo bar X baz foo");
+}
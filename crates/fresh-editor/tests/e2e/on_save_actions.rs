@@ -52,10 +52,13 @@ fn test_format_on_save() {
             page_width: None,
             use_tabs: None,
             tab_size: None,
+            indent_style: None,
+            indent_width: None,
             formatter: Some(formatter),
             format_on_save: true,
             on_save: vec![],
             word_characters: None,
+            run_command: None,
         },
     );
 
@@ -121,10 +124,13 @@ fn test_on_save_linter_style() {
             page_width: None,
             use_tabs: None,
             tab_size: None,
+            indent_style: None,
+            indent_width: None,
             formatter: None,
             format_on_save: false,
             on_save: vec![action],
             word_characters: None,
+            run_command: None,
         },
     );
 
@@ -190,10 +196,13 @@ fn test_on_save_action_failure() {
             page_width: None,
             use_tabs: None,
             tab_size: None,
+            indent_style: None,
+            indent_width: None,
             formatter: None,
             format_on_save: false,
             on_save: vec![action],
             word_characters: None,
+            run_command: None,
         },
     );
 
@@ -268,10 +277,13 @@ fn test_on_save_file_placeholder() {
             page_width: None,
             use_tabs: None,
             tab_size: None,
+            indent_style: None,
+            indent_width: None,
             formatter: None,
             format_on_save: false,
             on_save: vec![action],
             word_characters: None,
+            run_command: None,
         },
     );
 
@@ -340,10 +352,13 @@ fn test_formatter_stdin_mode() {
             page_width: None,
             use_tabs: None,
             tab_size: None,
+            indent_style: None,
+            indent_width: None,
             formatter: Some(formatter),
             format_on_save: true,
             on_save: vec![],
             word_characters: None,
+            run_command: None,
         },
     );
 
@@ -366,6 +381,84 @@ fn test_formatter_stdin_mode() {
     harness.assert_buffer_content("HELLO WORLD\n");
 }
 
+/// Test that a stdin formatter runs before the file is written, so the
+/// formatted text is saved in a single write
+#[test]
+#[cfg_attr(not(unix), ignore = "On-save actions require Unix-like environment")]
+fn test_stdin_formatter_runs_before_write() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("project");
+    std::fs::create_dir(&project_dir).unwrap();
+
+    let file_path = project_dir.join("uppercase.txt");
+    std::fs::write(&file_path, "original\n").unwrap();
+
+    // Uppercase stdin, but only while the file on disk is still unsaved
+    let formatter = FormatterConfig {
+        command: "grep".to_string(),
+        args: vec![
+            "-q".to_string(),
+            "original".to_string(),
+            "$FILE".to_string(),
+            "&&".to_string(),
+            "tr".to_string(),
+            "a-z".to_string(),
+            "A-Z".to_string(),
+        ],
+        stdin: true,
+        timeout_ms: 5000,
+    };
+
+    let mut config = Config::default();
+    config.languages.insert(
+        "plaintext".to_string(),
+        LanguageConfig {
+            extensions: vec!["txt".to_string()],
+            filenames: vec![],
+            grammar: "plaintext".to_string(),
+            comment_prefix: None,
+            auto_indent: false,
+            auto_close: None,
+            auto_surround: None,
+            textmate_grammar: None,
+            show_whitespace_tabs: true,
+            line_wrap: None,
+            wrap_column: None,
+            page_view: None,
+            page_width: None,
+            use_tabs: None,
+            tab_size: None,
+            indent_style: None,
+            indent_width: None,
+            formatter: Some(formatter),
+            format_on_save: true,
+            on_save: vec![],
+            word_characters: None,
+            run_command: None,
+        },
+    );
+
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(100, 24, config, project_dir).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness
+        .send_key(KeyCode::Char('a'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("edited\n").unwrap();
+
+    harness
+        .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+
+    harness.assert_buffer_content("EDITED\n");
+    assert_eq!(std::fs::read_to_string(&file_path).unwrap(), "EDITED\n");
+    assert!(
+        !harness.editor().active_state().buffer.is_modified(),
+        "The formatted text should be what was saved"
+    );
+}
+
 /// Test that on-save action failure stops subsequent actions
 #[test]
 #[cfg_attr(not(unix), ignore = "On-save actions require Unix-like environment")]
@@ -419,10 +512,13 @@ fn test_on_save_stops_on_failure() {
             page_width: None,
             use_tabs: None,
             tab_size: None,
+            indent_style: None,
+            indent_width: None,
             formatter: None,
             format_on_save: false,
             on_save: vec![action1, action2],
             word_characters: None,
+            run_command: None,
         },
     );
 
@@ -519,10 +615,13 @@ fn test_formatter_not_found_shows_message() {
             page_width: None,
             use_tabs: None,
             tab_size: None,
+            indent_style: None,
+            indent_width: None,
             formatter: Some(formatter),
             format_on_save: true,
             on_save: vec![],
             word_characters: None,
+            run_command: None,
         },
     );
 
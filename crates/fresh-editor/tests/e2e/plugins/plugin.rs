@@ -1435,3 +1435,852 @@ editor.setStatus("Uppercase plugin loaded");
         screen
     );
 }
+
+/// Test that a `before_file_save` handler returning false cancels the save
+/// and leaves the file on disk untouched
+#[test]
+fn test_plugin_before_file_save_vetoes_save() {
+    init_tracing_from_env();
+
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let project_root = temp_dir.path().join("project_root");
+    fs::create_dir(&project_root).unwrap();
+    let plugins_dir = project_root.join("plugins");
+    fs::create_dir(&plugins_dir).unwrap();
+    copy_plugin_lib(&plugins_dir);
+
+    let test_plugin = r#"
+const editor = getEditor();
+
+editor.on("before_file_save", (args) => {
+    return args.text === null || !args.text.includes("FORBIDDEN");
+});
+
+editor.setStatus("Save guard loaded");
+"#;
+    fs::write(plugins_dir.join("save_guard.ts"), test_plugin).unwrap();
+
+    let file_path = project_root.join("guarded.txt");
+    fs::write(&file_path, "clean\n").unwrap();
+
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(80, 24, Default::default(), project_root)
+            .unwrap();
+    harness
+        .wait_until(|h| h.screen_to_string().contains("Save guard loaded"))
+        .unwrap();
+    harness.open_file(&file_path).unwrap();
+
+    harness.type_text("FORBIDDEN ").unwrap();
+    harness
+        .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+
+    assert_eq!(
+        fs::read_to_string(&file_path).unwrap(),
+        "clean\n",
+        "The vetoed save must not write the file"
+    );
+    harness.assert_screen_contains("blocked by a plugin");
+    assert!(harness.editor().active_state().buffer.is_modified());
+
+    // Without the token the same handler lets the save through
+    harness
+        .send_key(KeyCode::Char('a'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("allowed\n").unwrap();
+    harness
+        .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+
+    assert_eq!(fs::read_to_string(&file_path).unwrap(), "allowed\n");
+}
+
+/// Test that a vetoed save leaves the buffer as it was: the pre-save
+/// cleanup only runs once plugins allow the save
+#[test]
+fn test_plugin_before_file_save_veto_skips_pre_save_actions() {
+    init_tracing_from_env();
+
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let project_root = temp_dir.path().join("project_root");
+    fs::create_dir(&project_root).unwrap();
+    let plugins_dir = project_root.join("plugins");
+    fs::create_dir(&plugins_dir).unwrap();
+    copy_plugin_lib(&plugins_dir);
+
+    let test_plugin = r#"
+const editor = getEditor();
+
+editor.on("before_file_save", (args) => {
+    return args.text === null || !args.text.includes("FORBIDDEN");
+});
+
+editor.setStatus("Save guard loaded");
+"#;
+    fs::write(plugins_dir.join("save_guard.ts"), test_plugin).unwrap();
+
+    let file_path = project_root.join("guarded.txt");
+    fs::write(&file_path, "clean\n").unwrap();
+
+    let mut config = fresh::config::Config::default();
+    config.editor.trim_trailing_whitespace_on_save = true;
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(80, 24, config, project_root).unwrap();
+    harness
+        .wait_until(|h| h.screen_to_string().contains("Save guard loaded"))
+        .unwrap();
+    harness.open_file(&file_path).unwrap();
+
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+    harness.type_text(" FORBIDDEN   ").unwrap();
+    harness
+        .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+
+    harness.assert_screen_contains("blocked by a plugin");
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "clean FORBIDDEN   \n",
+        "The vetoed save must not trim the buffer"
+    );
+    assert_eq!(fs::read_to_string(&file_path).unwrap(), "clean\n");
+
+    // An allowed save is trimmed and says so
+    harness
+        .send_key(KeyCode::Char('a'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("allowed   \n").unwrap();
+    harness
+        .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+
+    assert_eq!(fs::read_to_string(&file_path).unwrap(), "allowed\n");
+    harness.assert_screen_contains("with on-save actions");
+}
+
+/// Test that auto-save and save-on-exit ask `before_file_save` handlers too
+#[test]
+fn test_plugin_before_file_save_vetoes_auto_save() {
+    init_tracing_from_env();
+
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let project_root = temp_dir.path().join("project_root");
+    fs::create_dir(&project_root).unwrap();
+    let plugins_dir = project_root.join("plugins");
+    fs::create_dir(&plugins_dir).unwrap();
+    copy_plugin_lib(&plugins_dir);
+
+    let test_plugin = r#"
+const editor = getEditor();
+
+editor.on("before_file_save", (args) => {
+    return args.text === null || !args.text.includes("FORBIDDEN");
+});
+
+editor.setStatus("Save guard loaded");
+"#;
+    fs::write(plugins_dir.join("save_guard.ts"), test_plugin).unwrap();
+
+    let file_path = project_root.join("guarded.txt");
+    fs::write(&file_path, "clean\n").unwrap();
+
+    let mut config = Config::default();
+    config.editor.auto_save_enabled = true;
+    config.editor.auto_save_interval_secs = 1;
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(80, 24, config, project_root).unwrap();
+    harness
+        .wait_until(|h| h.screen_to_string().contains("Save guard loaded"))
+        .unwrap();
+    harness.open_file(&file_path).unwrap();
+
+    harness.type_text("FORBIDDEN ").unwrap();
+    harness.advance_time(std::time::Duration::from_millis(1100));
+
+    let saved = harness.editor_mut().auto_save_persistent_buffers().unwrap();
+    assert_eq!(saved, 0, "The vetoed auto-save must not count as a save");
+    let saved = harness.editor_mut().save_all_on_exit().unwrap();
+    assert_eq!(saved, 0, "The vetoed save on exit must not count as a save");
+
+    assert_eq!(fs::read_to_string(&file_path).unwrap(), "clean\n");
+    assert!(harness.editor().active_state().buffer.is_modified());
+}
+
+/// Test that a hung `before_file_save` handler is aborted after
+/// `plugin_handler_timeout_ms`: the save goes through, the plugin is
+/// disabled, and other plugins keep working
+#[test]
+fn test_plugin_hung_handler_times_out() {
+    init_tracing_from_env();
+
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let project_root = temp_dir.path().join("project_root");
+    fs::create_dir(&project_root).unwrap();
+    let plugins_dir = project_root.join("plugins");
+    fs::create_dir(&plugins_dir).unwrap();
+    copy_plugin_lib(&plugins_dir);
+
+    let hung_plugin = r#"
+const editor = getEditor();
+
+editor.on("before_file_save", (args) => {
+    const start = Date.now();
+    while (Date.now() - start < 60000) {}
+    return false;
+});
+
+editor.setStatus("Hung plugin loaded");
+"#;
+    fs::write(plugins_dir.join("hung.ts"), hung_plugin).unwrap();
+
+    let ping_plugin = r#"
+const editor = getEditor();
+
+globalThis.ping = function(): void {
+    editor.setStatus("pong");
+};
+
+editor.registerCommand("Test: Ping", "Reply with pong", "ping", "normal");
+"#;
+    fs::write(plugins_dir.join("ping.ts"), ping_plugin).unwrap();
+
+    let file_path = project_root.join("notes.txt");
+    fs::write(&file_path, "old\n").unwrap();
+
+    let mut config = Config::default();
+    config.editor.plugin_handler_timeout_ms = 200;
+    config.editor.plugin_disable_after_timeouts = 1;
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(80, 24, config, project_root).unwrap();
+    harness
+        .wait_until(|h| h.screen_to_string().contains("Hung plugin loaded"))
+        .unwrap();
+    harness.open_file(&file_path).unwrap();
+
+    harness
+        .send_key(KeyCode::Char('a'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("new\n").unwrap();
+    let save_started = std::time::Instant::now();
+    harness
+        .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        .unwrap();
+    let save_took = save_started.elapsed();
+
+    // The aborted handler counts as allowing the save
+    assert_eq!(fs::read_to_string(&file_path).unwrap(), "new\n");
+    assert!(
+        save_took < std::time::Duration::from_secs(2),
+        "save should only wait for the handler timeout, took {save_took:?}"
+    );
+    harness
+        .wait_until(|h| h.screen_to_string().contains("Plugin 'hung' disabled"))
+        .unwrap();
+
+    // The plugin thread is free to serve other plugins
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("Test: Ping").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .wait_until(|h| h.screen_to_string().contains("pong"))
+        .unwrap();
+}
+
+/// Test that a plugin whose manifest requests an ungranted permission is
+/// not loaded, and that a granted plugin can't call APIs outside the
+/// permissions it declared
+#[test]
+fn test_plugin_manifest_permissions() {
+    init_tracing_from_env();
+
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let project_root = temp_dir.path().join("project_root");
+    fs::create_dir(&project_root).unwrap();
+    let plugins_dir = project_root.join("plugins");
+    fs::create_dir(&plugins_dir).unwrap();
+    copy_plugin_lib(&plugins_dir);
+
+    let spawner_plugin = r#"
+const editor = getEditor();
+
+globalThis.spawner_run = function(): void {
+    editor.setStatus("spawner ran");
+};
+
+editor.registerCommand("Test: Spawner Run", "Run the spawner", "spawner_run", "normal");
+"#;
+    fs::write(plugins_dir.join("spawner.ts"), spawner_plugin).unwrap();
+    fs::write(
+        plugins_dir.join("spawner.manifest.json"),
+        r#"{"name": "spawner", "version": "1.0.0", "permissions": ["process"]}"#,
+    )
+    .unwrap();
+
+    let reader_plugin = r#"
+const editor = getEditor();
+
+globalThis.reader_probe = function(): void {
+    let spawn = "allowed";
+    try {
+        editor.spawnProcess("true", []);
+    } catch (e) {
+        spawn = "denied";
+    }
+    const exists = editor.fileExists(editor.getCwd());
+    editor.setStatus(`reader: spawn ${spawn}, fs ${exists ? "ok" : "missing"}`);
+};
+
+editor.registerCommand("Test: Reader Probe", "Probe the reader's permissions", "reader_probe", "normal");
+"#;
+    fs::write(plugins_dir.join("reader.ts"), reader_plugin).unwrap();
+    fs::write(
+        plugins_dir.join("reader.manifest.json"),
+        r#"{"name": "reader", "apis": ["fileExists"], "permissions": ["filesystem"]}"#,
+    )
+    .unwrap();
+
+    let mut config = Config::default();
+    config.plugins.insert(
+        "reader".to_string(),
+        fresh::config::PluginConfig {
+            permissions: vec![fresh_core::config::PluginPermission::Filesystem],
+            ..Default::default()
+        },
+    );
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(120, 24, config, project_root).unwrap();
+    harness
+        .wait_until(|h| {
+            h.screen_to_string()
+                .contains("Plugin 'spawner' not loaded: permissions not granted: process")
+        })
+        .unwrap();
+
+    // The refused plugin registered nothing
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("Test: Spawner Run").unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("Run the spawner");
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("Test: Reader Probe").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .wait_until(|h| h.screen_to_string().contains("reader: spawn denied, fs ok"))
+        .unwrap();
+}
+
+/// Test that a restricted plugin can't reach gated calls through the
+/// write/exec APIs or another plugin's exported API, and that a plugin
+/// without a manifest stays unrestricted
+#[test]
+fn test_plugin_sandbox_closes_bypasses() {
+    init_tracing_from_env();
+
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let project_root = temp_dir.path().join("project_root");
+    fs::create_dir(&project_root).unwrap();
+    let plugins_dir = project_root.join("plugins");
+    fs::create_dir(&plugins_dir).unwrap();
+    copy_plugin_lib(&plugins_dir);
+
+    let exporter_plugin = r#"
+const editor = getEditor();
+
+editor.exportPluginApi("exporter", {
+    run(): string {
+        editor.spawnProcess("true", []);
+        return "ran";
+    },
+});
+"#;
+    fs::write(plugins_dir.join("exporter.ts"), exporter_plugin).unwrap();
+    fs::write(
+        plugins_dir.join("exporter.manifest.json"),
+        r#"{"permissions": ["process"]}"#,
+    )
+    .unwrap();
+
+    let borrower_plugin = r#"
+const editor = getEditor();
+
+function attempt(call: () => unknown): string {
+    try {
+        call();
+        return "allowed";
+    } catch (e) {
+        return "denied";
+    }
+}
+
+globalThis.borrower_probe = function(): void {
+    const cwd = editor.getCwd();
+    const results = [
+        attempt(() => editor.copyPath(cwd + "/a", cwd + "/b")),
+        attempt(() => editor.saveBufferToPath(editor.getActiveBufferId(), cwd + "/c")),
+        attempt(() => editor.replaceInFile(cwd + "/a", [])),
+        attempt(() => editor.saveThemeFile("stolen", "{}")),
+        attempt(() => editor.sendTerminalInput(0, "id\n")),
+        attempt(() => (editor.getPluginApi("exporter") as { run(): string }).run()),
+    ];
+    editor.setStatus(`borrower: ${results.join(" ")}`);
+};
+
+editor.registerCommand("Test: Borrower Probe", "Probe the borrower", "borrower_probe", "normal");
+"#;
+    fs::write(plugins_dir.join("borrower.ts"), borrower_plugin).unwrap();
+    fs::write(
+        plugins_dir.join("borrower.manifest.json"),
+        r#"{"permissions": []}"#,
+    )
+    .unwrap();
+
+    // A plugin without a manifest and without grants
+    let bare_plugin = r#"
+const editor = getEditor();
+
+globalThis.bare_probe = function(): void {
+    let fs = "ok";
+    try {
+        editor.fileExists(editor.getCwd());
+    } catch (e) {
+        fs = "denied";
+    }
+    editor.setStatus(`bare: fs ${fs}`);
+};
+
+editor.registerCommand("Test: Probe bare", "Probe bare", "bare_probe", "normal");
+"#;
+    fs::write(plugins_dir.join("bare.ts"), bare_plugin).unwrap();
+
+    let mut config = Config::default();
+    config.plugins.insert(
+        "exporter".to_string(),
+        fresh::config::PluginConfig {
+            permissions: vec![fresh_core::config::PluginPermission::Process],
+            ..Default::default()
+        },
+    );
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(120, 24, config, project_root).unwrap();
+
+    for (command, expected) in [
+        (
+            "Test: Borrower Probe",
+            "borrower: denied denied denied denied denied denied",
+        ),
+        ("Test: Probe bare", "bare: fs ok"),
+    ] {
+        harness
+            .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+            .unwrap();
+        harness.type_text(command).unwrap();
+        harness
+            .wait_until(|h| h.screen_to_string().contains(command))
+            .unwrap();
+        harness
+            .send_key(KeyCode::Enter, KeyModifiers::NONE)
+            .unwrap();
+        harness
+            .wait_until(|h| h.screen_to_string().contains(expected))
+            .unwrap();
+    }
+}
+
+/// Test a plugin that uppercases the selection through `getText` and
+/// `replaceText`, and that the replacement undoes in one step
+#[test]
+fn test_plugin_get_text_replace_text_uppercases_selection() {
+    init_tracing_from_env();
+
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let project_root = temp_dir.path().join("project_root");
+    fs::create_dir(&project_root).unwrap();
+    let plugins_dir = project_root.join("plugins");
+    fs::create_dir(&plugins_dir).unwrap();
+    copy_plugin_lib(&plugins_dir);
+
+    let test_plugin = r#"
+const editor = getEditor();
+
+globalThis.uppercase_selection = async function(): Promise<void> {
+    const cursor = editor.getPrimaryCursor();
+    if (!cursor || !cursor.selection) {
+        editor.setStatus("No selection");
+        return;
+    }
+    const { start, end } = cursor.selection;
+    const text = await editor.getText(start, end);
+    await editor.replaceText(start, end, text.toUpperCase());
+    editor.setStatus("Uppercased selection");
+};
+
+editor.registerCommand(
+    "Test: Uppercase Selection",
+    "Uppercase the selected text",
+    "uppercase_selection",
+    "normal"
+);
+
+editor.setStatus("Uppercase plugin loaded");
+"#;
+    fs::write(plugins_dir.join("uppercase.ts"), test_plugin).unwrap();
+
+    let file_path = project_root.join("words.txt");
+    fs::write(&file_path, "hello world\n").unwrap();
+
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(80, 24, Default::default(), project_root)
+            .unwrap();
+    harness
+        .wait_until(|h| h.screen_to_string().contains("Uppercase plugin loaded"))
+        .unwrap();
+    harness.open_file(&file_path).unwrap();
+
+    for _ in 0..5 {
+        harness
+            .send_key(KeyCode::Right, KeyModifiers::SHIFT)
+            .unwrap();
+    }
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("Test: Uppercase Selection").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .wait_until(|h| h.get_buffer_content().unwrap() == "HELLO world\n")
+        .unwrap();
+
+    // The delete + insert pair is one undo step
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.assert_buffer_content("hello world\n");
+}
+
+/// Test that end-of-line virtual text set by a plugin renders after the
+/// line's content and stays there as the line grows
+#[test]
+fn test_plugin_set_virtual_text_renders_after_line_content() {
+    init_tracing_from_env();
+
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let project_root = temp_dir.path().join("project_root");
+    fs::create_dir(&project_root).unwrap();
+    let plugins_dir = project_root.join("plugins");
+    fs::create_dir(&plugins_dir).unwrap();
+    copy_plugin_lib(&plugins_dir);
+
+    let test_plugin = r#"
+const editor = getEditor();
+
+globalThis.annotate_line = function(): void {
+    const cursor = editor.getPrimaryCursor();
+    if (!cursor) {
+        return;
+    }
+    editor.setVirtualText(editor.getActiveBufferId(), "marker", cursor.position, "← here", {
+        anchor: "endOfLine",
+        italic: true,
+    });
+    editor.setStatus("Annotated line");
+};
+
+editor.registerCommand(
+    "Test: Annotate Line",
+    "Mark the cursor's line",
+    "annotate_line",
+    "normal"
+);
+
+editor.setStatus("Annotate plugin loaded");
+"#;
+    fs::write(plugins_dir.join("annotate.ts"), test_plugin).unwrap();
+
+    let file_path = project_root.join("lines.txt");
+    fs::write(&file_path, "first line\nsecond line\n").unwrap();
+
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(80, 24, Default::default(), project_root)
+            .unwrap();
+    harness
+        .wait_until(|h| h.screen_to_string().contains("Annotate plugin loaded"))
+        .unwrap();
+    harness.open_file(&file_path).unwrap();
+
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("Test: Annotate Line").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .wait_until(|h| h.screen_to_string().contains("first line ← here"))
+        .unwrap();
+
+    let screen = harness.screen_to_string();
+    let second = screen
+        .lines()
+        .find(|line| line.contains("second line"))
+        .expect("second line should be visible");
+    assert!(
+        !second.contains("← here"),
+        "Only the annotated line should carry the text: {second:?}"
+    );
+    // The text isn't part of the buffer
+    harness.assert_buffer_content("first line\nsecond line\n");
+
+    // Typing at the end of the line keeps the text after the content
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+    harness.type_text("!").unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("first line! ← here");
+}
+
+/// A plugin opens a scratch buffer with known content, streams more text
+/// into it, and the modified buffer closes without a save prompt
+#[test]
+fn test_plugin_open_scratch_buffer() {
+    init_tracing_from_env();
+
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let project_root = temp_dir.path().join("project_root");
+    fs::create_dir(&project_root).unwrap();
+    let plugins_dir = project_root.join("plugins");
+    fs::create_dir(&plugins_dir).unwrap();
+    copy_plugin_lib(&plugins_dir);
+
+    let test_plugin = r#"
+const editor = getEditor();
+
+globalThis.open_scratch = async function(): Promise<void> {
+    const id = await editor.openScratchBuffer("*Scratch Output*", "build started\n", "text");
+    editor.appendToBuffer(id, "build finished\n");
+    editor.setStatus("Scratch opened");
+};
+
+editor.registerCommand(
+    "Test: Open Scratch",
+    "Open a scratch buffer",
+    "open_scratch",
+    "normal"
+);
+
+editor.setStatus("Scratch plugin loaded");
+"#;
+    fs::write(plugins_dir.join("scratch.ts"), test_plugin).unwrap();
+
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(80, 24, Default::default(), project_root)
+            .unwrap();
+    harness
+        .wait_until(|h| h.screen_to_string().contains("Scratch plugin loaded"))
+        .unwrap();
+
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("Test: Open Scratch").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .wait_until(|h| h.screen_to_string().contains("build finished"))
+        .unwrap();
+
+    harness.assert_buffer_content("build started\nbuild finished\n");
+    harness.assert_screen_contains("*Scratch Output*");
+    let scratch_id = harness.editor().active_buffer();
+    assert!(harness.editor().is_scratch_buffer(scratch_id));
+
+    // Edit it, then close: no save prompt
+    harness.type_text("edited").unwrap();
+    harness.editor_mut().close_tab();
+    harness.render().unwrap();
+    assert!(
+        !harness.editor().is_prompting(),
+        "Closing a scratch buffer should not prompt to save"
+    );
+    assert_ne!(harness.editor().active_buffer(), scratch_id);
+    harness.assert_screen_not_contains("*Scratch Output*");
+}
+
+/// Typing quickly into a plugin prompt coalesces `prompt_changed`: the
+/// plugin sees far fewer events than keystrokes, and the last one carries
+/// the full input
+#[test]
+fn test_plugin_prompt_changed_is_debounced() {
+    init_tracing_from_env();
+
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let project_root = temp_dir.path().join("project_root");
+    fs::create_dir(&project_root).unwrap();
+    let plugins_dir = project_root.join("plugins");
+    fs::create_dir(&plugins_dir).unwrap();
+    copy_plugin_lib(&plugins_dir);
+
+    let test_plugin = r#"
+const editor = getEditor();
+
+let changes = 0;
+
+globalThis.open_count_prompt = function(): void {
+    editor.startPrompt("Count: ", "count-prompt");
+};
+
+globalThis.on_count_prompt_changed = function(args: { prompt_type: string; input: string }): void {
+    if (args.prompt_type !== "count-prompt") {
+        return;
+    }
+    changes += 1;
+    editor.setStatus(`changes=${changes} last=${args.input}.`);
+};
+
+editor.on("prompt_changed", "on_count_prompt_changed");
+editor.registerCommand(
+    "Test: Count Prompt Changes",
+    "Open a prompt that counts prompt_changed events",
+    "open_count_prompt",
+    "normal"
+);
+
+editor.setStatus("Counter plugin loaded");
+"#;
+    fs::write(plugins_dir.join("counter.ts"), test_plugin).unwrap();
+
+    let mut config = Config::default();
+    config.editor.plugin_hook_debounce_ms = 100;
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(100, 24, config, project_root).unwrap();
+    harness
+        .wait_until(|h| h.screen_to_string().contains("Counter plugin loaded"))
+        .unwrap();
+
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("Test: Count Prompt Changes").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .wait_until(|h| h.screen_to_string().contains("Count: "))
+        .unwrap();
+
+    harness.type_text("abcdefghij").unwrap();
+    harness
+        .wait_until(|h| h.screen_to_string().contains("last=abcdefghij."))
+        .unwrap();
+
+    let screen = harness.screen_to_string();
+    let changes: usize = screen
+        .split("changes=")
+        .nth(1)
+        .and_then(|rest| rest.split(' ').next())
+        .and_then(|n| n.parse().ok())
+        .expect("status should report the change count");
+    // The prompt's initial event, at most one immediate keystroke, and
+    // the coalesced final input
+    assert!(
+        changes <= 3,
+        "10 keystrokes should be coalesced, plugin saw {changes} events"
+    );
+}
+
+/// Test that a view_transform_request handler can conceal Markdown heading
+/// markers using the per-line tokens while cursor placement and clicks
+/// still map to the right source bytes
+#[test]
+fn test_view_transform_conceals_heading_markers() {
+    init_tracing_from_env();
+
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let project_root = temp_dir.path().join("project_root");
+    fs::create_dir(&project_root).unwrap();
+    let plugins_dir = project_root.join("plugins");
+    fs::create_dir(&plugins_dir).unwrap();
+    copy_plugin_lib(&plugins_dir);
+
+    let test_plugin = r###"
+const editor = getEditor();
+
+globalThis.onViewTransform = function(data: {
+    buffer_id: number;
+    split_id: number;
+    viewport_start: number;
+    viewport_end: number;
+    lines: { line_start: number; tokens: ViewTokenWire[] }[];
+}): void {
+    const tokens: ViewTokenWire[] = [];
+    for (const line of data.lines) {
+        const [first, second] = line.tokens;
+        const isHeading = first && second
+            && typeof first.kind === "object" && "Text" in first.kind
+            && first.kind.Text === "#" && second.kind === "Space";
+        tokens.push(...(isHeading ? line.tokens.slice(2) : line.tokens));
+    }
+    editor.submitViewTransform(data.buffer_id, data.split_id,
+        data.viewport_start, data.viewport_end, tokens);
+};
+
+editor.on("view_transform_request", "onViewTransform");
+editor.setStatus("Conceal plugin loaded");
+"###;
+    fs::write(plugins_dir.join("conceal_headings.ts"), test_plugin).unwrap();
+
+    let file_path = project_root.join("notes.md");
+    fs::write(&file_path, "# Title\nbody text\n").unwrap();
+
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(80, 24, Default::default(), project_root)
+            .unwrap();
+    harness
+        .wait_until(|h| h.screen_to_string().contains("Conceal plugin loaded"))
+        .unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness
+        .wait_until(|h| !h.screen_to_string().contains("# Title"))
+        .unwrap();
+
+    let (title_col, title_row) = harness
+        .find_text_on_screen("Title")
+        .expect("heading text should be visible");
+    let (body_col, _) = harness
+        .find_text_on_screen("body text")
+        .expect("body line should be visible");
+    assert_eq!(
+        title_col, body_col,
+        "Concealed heading should start in the text column"
+    );
+    // The markers are only hidden, not removed
+    harness.assert_buffer_content("# Title\nbody text\n");
+
+    // End of the heading maps to the byte after "Title"
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+    assert_eq!(harness.cursor_position(), 7);
+    assert_eq!(harness.screen_cursor_position(), (title_col + 5, title_row));
+
+    // Clicking the "T" lands on its source byte, past the hidden marker
+    harness.mouse_click(title_col, title_row).unwrap();
+    assert_eq!(harness.cursor_position(), 2);
+}
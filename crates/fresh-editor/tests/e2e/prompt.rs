@@ -946,3 +946,49 @@ fn test_open_file_prompt_shows_completions_immediately() {
     let screen = harness.screen_to_string();
     println!("Screen after opening prompt:\n{}", screen);
 }
+
+/// Test that the built-in file browser lists completions with no plugins
+/// loaded, marks directories with a trailing `/`, and follows a typed
+/// directory path
+#[test]
+fn test_open_file_prompt_completions_without_plugins() {
+    use crossterm::event::{KeyCode, KeyModifiers};
+    use std::fs;
+
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let project_root = temp_dir.path().to_path_buf();
+    fs::write(project_root.join("alpha.txt"), "alpha content").unwrap();
+    fs::create_dir(project_root.join("nested")).unwrap();
+    fs::write(project_root.join("nested").join("inner.txt"), "inner").unwrap();
+
+    // No plugins directory in the working dir, so nothing provides completions
+    // except the core
+    let mut harness = EditorTestHarness::with_config_and_working_dir(
+        80,
+        24,
+        Default::default(),
+        project_root.clone(),
+    )
+    .unwrap();
+    harness.render().unwrap();
+
+    harness
+        .send_key(KeyCode::Char('o'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.assert_screen_contains("Open file:");
+
+    harness
+        .wait_until(|h| {
+            let screen = h.screen_to_string();
+            screen.contains("alpha.txt") && screen.contains("nested/")
+        })
+        .expect("Completions should appear immediately without plugins");
+
+    // Typing a directory path lists that directory and leaves only the
+    // filename part in the prompt
+    harness.type_text("nested/in").unwrap();
+    harness
+        .wait_until(|h| h.editor().prompt_input() == Some("in"))
+        .expect("Prompt should navigate into the typed directory");
+    harness.wait_for_screen_contains("inner.txt").unwrap();
+}
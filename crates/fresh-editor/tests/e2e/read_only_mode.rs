@@ -0,0 +1,75 @@
+//! E2E tests for read-only buffer mode: Toggle Read-Only Mode and files
+//! that open read-only because they lack write permission.
+
+use crate::common::fixtures::TestFixture;
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+
+fn run_command(harness: &mut EditorTestHarness, name: &str) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.wait_for_prompt().unwrap();
+    harness.type_text(name).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+}
+
+/// Test: typing is a no-op while read-only mode is on, the status bar
+/// shows `[RO]`, and toggling the mode off re-enables editing.
+#[test]
+fn test_toggle_read_only_blocks_and_restores_editing() {
+    let fixture = TestFixture::new("notes.txt", "hello\n").unwrap();
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.open_file(&fixture.path).unwrap();
+    harness.render().unwrap();
+
+    run_command(&mut harness, "Toggle Read-Only Mode");
+    assert!(harness.editor().is_editing_disabled());
+    assert!(harness.get_status_bar().contains("[RO]"));
+
+    harness.type_text("x").unwrap();
+    harness.render().unwrap();
+    harness.assert_buffer_content("hello\n");
+    assert!(!harness.editor().active_state().buffer.is_modified());
+
+    // Navigation still works
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+    assert_eq!(harness.cursor_position(), 5);
+
+    run_command(&mut harness, "Toggle Read-Only Mode");
+    assert!(!harness.editor().is_editing_disabled());
+    assert!(!harness.get_status_bar().contains("[RO]"));
+
+    harness.type_text("!").unwrap();
+    harness.render().unwrap();
+    harness.assert_buffer_content("hello!\n");
+}
+
+/// Test: a file without write permission opens read-only.
+#[test]
+#[cfg(unix)]
+fn test_unwritable_file_opens_read_only() {
+    use std::os::unix::fs::PermissionsExt;
+
+    // Root (uid 0) bypasses Unix file permission checks
+    if unsafe { libc::getuid() } == 0 {
+        eprintln!("Skipping test: root bypasses file permission checks");
+        return;
+    }
+
+    let fixture = TestFixture::new("locked.txt", "locked\n").unwrap();
+    std::fs::set_permissions(&fixture.path, std::fs::Permissions::from_mode(0o444)).unwrap();
+
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.open_file(&fixture.path).unwrap();
+    harness.render().unwrap();
+
+    assert!(harness.editor().is_editing_disabled());
+    assert!(harness.get_status_bar().contains("[RO]"));
+
+    harness.type_text("x").unwrap();
+    harness.assert_buffer_content("locked\n");
+}
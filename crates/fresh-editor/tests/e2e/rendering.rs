@@ -768,6 +768,57 @@ fn test_current_line_highlight_follows_cursor_movement() {
     );
 }
 
+/// With several cursors only the primary cursor's line is highlighted,
+/// unless `highlight_all_cursor_lines` is on.
+#[test]
+fn test_current_line_highlight_with_multiple_cursors() {
+    use fresh::config::Config;
+    use ratatui::style::Color;
+
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("highlight_test.txt");
+    std::fs::write(&file_path, "abc\ndef\nghi\n").unwrap();
+
+    let current_line_bg = Color::Rgb(40, 40, 40);
+    let gutter_width: u16 = 8;
+
+    for all_lines in [false, true] {
+        let config = Config {
+            theme: "dark".into(),
+            editor: fresh::config::EditorConfig {
+                highlight_all_cursor_lines: all_lines,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+        harness.open_file(&file_path).unwrap();
+        harness.editor_mut().add_cursor_below();
+        harness.render().unwrap();
+
+        let (content_row, _) = harness.content_area_rows();
+        let highlighted: Vec<bool> = (0..3)
+            .map(|line| {
+                harness
+                    .get_cell_style(gutter_width + 20, (content_row + line) as u16)
+                    .unwrap()
+                    .bg
+                    == Some(current_line_bg)
+            })
+            .collect();
+
+        if all_lines {
+            assert_eq!(highlighted, vec![true, true, false]);
+        } else {
+            assert_eq!(
+                highlighted.iter().filter(|&&h| h).count(),
+                1,
+                "only the primary cursor's line should be highlighted: {highlighted:?}"
+            );
+        }
+    }
+}
+
 /// `highlight_current_column` paints the cursor's column (across the full
 /// viewport height) with the same tint as the current line.
 #[test]
@@ -2906,3 +2906,113 @@ fn test_page_up_changes_view_until_top() {
         );
     }
 }
+
+/// With `overscroll` enabled, jumping to the last line keeps `scroll_offset`
+/// blank rows below it instead of pinning it to the bottom row.
+#[test]
+fn test_overscroll_keeps_scroll_offset_below_last_line() {
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    let mut config = fresh::config::Config::default();
+    config.editor.scroll_offset = 3;
+    config.editor.overscroll = true;
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+
+    let total_lines = 60;
+    let content = (0..total_lines)
+        .map(|i| format!("Line {i:03}"))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let _fixture = harness.load_buffer_from_text(&content).unwrap();
+    harness.render().unwrap();
+
+    harness
+        .send_key(KeyCode::End, KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+
+    let viewport_height = harness.viewport_height();
+    assert_eq!(
+        harness.top_line_number(),
+        total_lines - viewport_height + 3,
+        "last line should sit scroll_offset rows above the bottom"
+    );
+
+    let (_, last_row) = harness.content_area_rows();
+    for row in last_row - 2..=last_row {
+        assert!(
+            !harness.get_screen_row(row).contains("Line "),
+            "row {row} below the last line should be blank"
+        );
+    }
+    harness.assert_screen_contains("Line 059");
+}
+
+/// Overscroll never applies to a document that fits in the viewport.
+#[test]
+fn test_overscroll_ignored_for_short_documents() {
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    let mut config = fresh::config::Config::default();
+    config.editor.overscroll = true;
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+
+    let content = (0..10)
+        .map(|i| format!("Line {i:03}"))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let _fixture = harness.load_buffer_from_text(&content).unwrap();
+    harness.render().unwrap();
+
+    harness
+        .send_key(KeyCode::End, KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+
+    assert_eq!(harness.top_line_number(), 0);
+    harness.assert_screen_contains("Line 000");
+}
+
+/// Recenter, Cursor to Top, and Cursor to Bottom place the cursor's
+/// line in the middle, first, and last rows of an 80x24 window.
+#[test]
+fn test_center_top_bottom_cursor_commands() {
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    fn run_command(harness: &mut EditorTestHarness, name: &str) {
+        harness
+            .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+            .unwrap();
+        harness.wait_for_prompt().unwrap();
+        harness.type_text(name).unwrap();
+        harness
+            .send_key(KeyCode::Enter, KeyModifiers::NONE)
+            .unwrap();
+        harness.render().unwrap();
+    }
+
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    let content = (0..100)
+        .map(|i| format!("Line {i:03}"))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let _fixture = harness.load_buffer_from_text(&content).unwrap();
+    harness.render().unwrap();
+
+    let cursor_line = 50;
+    for _ in 0..cursor_line {
+        harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    }
+    harness.render().unwrap();
+    let viewport_height = harness.viewport_height();
+
+    run_command(&mut harness, "Recenter");
+    assert_eq!(harness.top_line_number(), cursor_line - viewport_height / 2);
+
+    run_command(&mut harness, "Cursor to Top");
+    assert_eq!(harness.top_line_number(), cursor_line);
+
+    run_command(&mut harness, "Cursor to Bottom");
+    assert_eq!(harness.top_line_number(), cursor_line + 1 - viewport_height);
+    assert_eq!(harness.top_view_line_offset(), 0);
+}
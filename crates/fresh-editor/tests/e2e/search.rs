@@ -2843,3 +2843,141 @@ fn test_search_status_bar_line_number_updates_on_f3() {
 
     harness.assert_screen_contains("Ln 5,");
 }
+
+/// Test that every visible match of a confirmed search is highlighted, that
+/// Whole Word is respected, and that Esc clears the highlights
+#[test]
+fn test_all_visible_matches_are_highlighted() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.txt");
+    std::fs::write(&file_path, "first alpha\nalphabet then alpha\n").unwrap();
+
+    let mut harness = EditorTestHarness::new(100, 24).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+    let match_bg = harness.editor().theme().search_match_bg;
+
+    // Background of the second character of the last `needle` on the row
+    // containing `row_text` (the first character may be under the cursor)
+    let bg_in = |harness: &EditorTestHarness, row_text: &str, needle: &str| {
+        let screen = harness.screen_to_string();
+        let (row, line) = screen
+            .lines()
+            .enumerate()
+            .find(|(_, line)| line.contains(row_text))
+            .expect("row should be on screen");
+        let byte_col = line.rfind(needle).expect("needle should be on the row");
+        let col = line[..byte_col].chars().count() + 1;
+        harness
+            .get_cell_style(col as u16, row as u16)
+            .and_then(|style| style.bg)
+    };
+
+    harness
+        .send_key(KeyCode::Char('f'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Char('w'), KeyModifiers::ALT)
+        .unwrap();
+    harness.type_text("alpha").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+
+    assert_eq!(bg_in(&harness, "first alpha", "alpha"), Some(match_bg));
+    assert_eq!(bg_in(&harness, "then alpha", "alpha"), Some(match_bg));
+    assert_ne!(
+        bg_in(&harness, "alphabet", "alphabet"),
+        Some(match_bg),
+        "Whole Word search must not highlight inside 'alphabet'"
+    );
+
+    // Esc clears the highlights
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+    assert_ne!(bg_in(&harness, "first alpha", "alpha"), Some(match_bg));
+    assert_ne!(bg_in(&harness, "then alpha", "alpha"), Some(match_bg));
+}
+
+/// Test that typing in the search prompt moves the cursor to the nearest
+/// match, narrowing as the query grows, and that Escape returns the cursor
+#[test]
+fn test_incremental_search_previews_match_and_escape_restores() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.txt");
+    std::fs::write(&file_path, "apple banana\nberry band\nbandana\n").unwrap();
+
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+    assert_eq!(harness.cursor_position(), 0);
+
+    harness
+        .send_key(KeyCode::Char('f'), KeyModifiers::CONTROL)
+        .unwrap();
+
+    // Each keystroke jumps to the first match at or after the start position
+    harness.type_text("ban").unwrap();
+    assert_eq!(harness.cursor_position(), 6, "'ban' previews 'banana'");
+    harness.type_text("d").unwrap();
+    assert_eq!(harness.cursor_position(), 19, "'band' previews 'band'");
+    harness.type_text("a").unwrap();
+    assert_eq!(harness.cursor_position(), 24, "'banda' previews 'bandana'");
+
+    // No match: the cursor stays on the last preview
+    harness.type_text("x").unwrap();
+    assert_eq!(harness.cursor_position(), 24);
+
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+    assert_eq!(
+        harness.cursor_position(),
+        0,
+        "Escape should return to where the search started"
+    );
+
+    // Enter confirms the previewed match
+    harness
+        .send_key(KeyCode::Char('f'), KeyModifiers::CONTROL)
+        .unwrap();
+    // Clear any query pre-filled from history
+    for _ in 0..10 {
+        harness
+            .send_key(KeyCode::Backspace, KeyModifiers::NONE)
+            .unwrap();
+    }
+    harness.type_text("band").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    assert_eq!(harness.cursor_position(), 19);
+}
+
+/// Test that cancelling an incremental search also restores the scroll
+/// position after previewing an off-screen match
+#[test]
+fn test_incremental_search_escape_restores_viewport() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.txt");
+    let mut content: String = (0..200).map(|i| format!("line {i}\n")).collect();
+    content.push_str("needle\n");
+    std::fs::write(&file_path, content).unwrap();
+
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    harness
+        .send_key(KeyCode::Char('f'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("needle").unwrap();
+    harness.render().unwrap();
+    assert!(harness.top_byte() > 0, "preview should scroll to the match");
+
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+    assert_eq!(harness.top_byte(), 0);
+    assert_eq!(harness.cursor_position(), 0);
+}
@@ -407,6 +407,52 @@ fn test_search_replace_executes_replacement() {
     assert_eq!(gamma, "nothing relevant\njust filler\n");
 }
 
+/// Files matched by `.gitignore` are neither searched nor replaced.
+#[test]
+fn test_search_replace_skips_gitignored_files() {
+    let (_temp_dir, project_root) = setup_search_replace_project();
+    create_test_files(&project_root);
+    fs::create_dir(project_root.join("build")).unwrap();
+    fs::write(project_root.join("build").join("out.txt"), "hello build\n").unwrap();
+    fs::write(project_root.join(".gitignore"), "build/\n").unwrap();
+
+    let start_file = project_root.join("gamma.txt");
+    let mut harness = EditorTestHarness::with_config_and_working_dir(
+        120,
+        30,
+        Default::default(),
+        project_root.clone(),
+    )
+    .unwrap();
+    harness.open_file(&start_file).unwrap();
+    harness.render().unwrap();
+
+    open_search_replace_via_palette(&mut harness);
+    enter_search_and_replace(&mut harness, "hello", "goodbye");
+    harness
+        .wait_until_stable(|h| {
+            let s = h.screen_to_string();
+            s.contains("matches") && s.contains("[v]")
+        })
+        .unwrap();
+    harness.assert_screen_not_contains("out.txt");
+
+    confirm_replace_all(&mut harness);
+
+    let alpha = fs::read_to_string(project_root.join("alpha.txt")).unwrap();
+    assert!(
+        !alpha.contains("hello"),
+        "alpha.txt not replaced:\n{}",
+        alpha
+    );
+    let beta = fs::read_to_string(project_root.join("beta.txt")).unwrap();
+    assert!(!beta.contains("hello"), "beta.txt not replaced:\n{}", beta);
+    assert_eq!(
+        fs::read_to_string(project_root.join("build").join("out.txt")).unwrap(),
+        "hello build\n"
+    );
+}
+
 /// Replacing with an empty string deletes the matched text.
 #[test]
 fn test_search_replace_delete_pattern() {
@@ -100,6 +100,43 @@ fn test_selection_visual_rendering() {
     );
 }
 
+/// Test that a selection spanning lines paints the rest of every row whose
+/// newline it covers, but not past the end of its last line
+#[test]
+fn test_multiline_selection_highlights_to_end_of_line() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.type_text("one\ntwo\nthree").unwrap();
+
+    // Select from "o|ne" down to "th|ree"
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Right, KeyModifiers::NONE)
+        .unwrap();
+    for _ in 0..2 {
+        harness
+            .send_key(KeyCode::Down, KeyModifiers::SHIFT)
+            .unwrap();
+    }
+    harness.render().unwrap();
+
+    let selection_bg = harness.editor().theme().selection_bg;
+    let bg_after = |text: &str, offset: u16| {
+        let (x, y) = harness.find_text_on_screen(text).unwrap();
+        harness.get_cell_style(x + offset, y).and_then(|s| s.bg)
+    };
+
+    // The first and interior lines are painted well past their text
+    assert_eq!(bg_after("one", 1), Some(selection_bg));
+    assert_eq!(bg_after("one", 20), Some(selection_bg));
+    assert_eq!(bg_after("two", 20), Some(selection_bg));
+    // The last line is selected only up to the cursor
+    assert_eq!(bg_after("three", 0), Some(selection_bg));
+    assert_ne!(bg_after("three", 1), Some(selection_bg));
+    assert_ne!(bg_after("three", 20), Some(selection_bg));
+}
+
 /// Test select word functionality (Ctrl+W)
 #[test]
 fn test_select_word() {
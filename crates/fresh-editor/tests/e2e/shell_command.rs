@@ -474,3 +474,179 @@ fn test_shell_command_replace_clamps_cursor_when_buffer_shrinks() {
         "Cursor should be clamped to new buffer length"
     );
 }
+
+/// Test that a command from the `commands` config section shows up in the
+/// command palette and streams its output into a buffer
+#[test]
+#[cfg_attr(not(unix), ignore = "Shell commands require Unix-like environment")]
+fn test_custom_config_command_output_in_buffer() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut config = fresh::config::Config::default();
+    config.commands.insert(
+        "Greet Project".to_string(),
+        fresh::config::CustomCommandConfig {
+            command: "echo hello-from-config; echo warning-on-stderr >&2".to_string(),
+            cwd: None,
+            process_limits: Default::default(),
+        },
+    );
+
+    let mut harness = EditorTestHarness::create(
+        100,
+        24,
+        crate::common::harness::HarnessOptions::new()
+            .with_config(config)
+            .with_working_dir(temp_dir.path().to_path_buf()),
+    )
+    .unwrap();
+    harness.render().unwrap();
+
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.wait_for_prompt().unwrap();
+    harness.type_text("Greet Project").unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("Greet Project");
+
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.wait_for_prompt_closed().unwrap();
+
+    harness
+        .wait_until(|h| {
+            h.get_buffer_content().is_some_and(|content| {
+                content.contains("hello-from-config\n") && content.contains("warning-on-stderr\n")
+            })
+        })
+        .unwrap();
+    harness.assert_screen_contains("*Greet Project*");
+}
+
+/// Running a custom command again stops the run still in progress, and
+/// each run's output is one undo step
+#[test]
+#[cfg_attr(not(unix), ignore = "Shell commands require Unix-like environment")]
+fn test_rerunning_custom_command_stops_previous_run() {
+    use fresh::input::keybindings::Action;
+
+    let temp_dir = TempDir::new().unwrap();
+    let mut config = fresh::config::Config::default();
+    config.commands.insert(
+        "Count Runs".to_string(),
+        fresh::config::CustomCommandConfig {
+            command: "n=$(($(cat count 2>/dev/null || echo 0) + 1)); echo $n > count; \
+                      echo run-$n; sleep 1; echo done-$n"
+                .to_string(),
+            cwd: None,
+            process_limits: Default::default(),
+        },
+    );
+    let mut harness = EditorTestHarness::create(
+        100,
+        24,
+        crate::common::harness::HarnessOptions::new()
+            .with_config(config)
+            .with_working_dir(temp_dir.path().to_path_buf()),
+    )
+    .unwrap();
+    let run = Action::RunCustomCommand("Count Runs".to_string());
+
+    harness.editor_mut().dispatch_action_for_tests(run.clone());
+    harness
+        .wait_until(|h| {
+            h.get_buffer_content()
+                .is_some_and(|content| content.contains("run-1"))
+        })
+        .unwrap();
+    harness.editor_mut().dispatch_action_for_tests(run);
+    harness
+        .wait_until(|h| h.screen_to_string().contains("Count Runs finished"))
+        .unwrap();
+
+    let content = harness.get_buffer_content().unwrap();
+    assert_eq!(
+        content, "run-2\ndone-2\n",
+        "The first run should be stopped and its output replaced"
+    );
+    assert_eq!(
+        harness.editor().active_event_log().undo_group_count(),
+        2,
+        "Each run's output should be a single undo step"
+    );
+}
+
+/// Run Current File saves the dirty file, runs it with the language's
+/// `run_command` and streams the output into a buffer with the exit code
+/// in the status bar
+#[test]
+#[cfg_attr(not(unix), ignore = "Shell commands require Unix-like environment")]
+fn test_run_current_file_uses_language_template() {
+    use fresh::input::keybindings::Action;
+
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("hello.sh");
+    std::fs::write(&file_path, "echo from-script\n").unwrap();
+
+    let mut config = fresh::config::Config::default();
+    config.languages.get_mut("bash").unwrap().run_command =
+        Some("echo running {file}; sh {file}".to_string());
+
+    let mut harness = EditorTestHarness::create(
+        100,
+        24,
+        crate::common::harness::HarnessOptions::new()
+            .with_config(config)
+            .with_working_dir(temp_dir.path().to_path_buf()),
+    )
+    .unwrap();
+    harness.open_file(&file_path).unwrap();
+
+    // Leave an unsaved edit so the run has to save first
+    harness
+        .send_key(KeyCode::End, KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("echo edited\n").unwrap();
+
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::RunCurrentFile);
+    harness
+        .wait_until(|h| {
+            h.get_buffer_content()
+                .is_some_and(|content| content.contains("from-script\nedited\n"))
+        })
+        .unwrap();
+    let content = harness.get_buffer_content().unwrap();
+    assert!(content.contains("running "), "output: {content}");
+    assert!(content.contains("hello.sh"), "output: {content}");
+    assert_eq!(
+        std::fs::read_to_string(&file_path).unwrap(),
+        "echo from-script\necho edited\n"
+    );
+
+    harness
+        .wait_until(|h| h.get_status_bar().contains("exit code 0"))
+        .unwrap();
+    harness.assert_screen_contains("*Run: hello.sh*");
+}
+
+/// A language without a `run_command` reports it instead of running
+#[test]
+fn test_run_current_file_without_template() {
+    use fresh::input::keybindings::Action;
+
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("notes.txt");
+    std::fs::write(&file_path, "plain text\n").unwrap();
+
+    let mut harness = EditorTestHarness::new(100, 24).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::RunCurrentFile);
+    harness.render().unwrap();
+    harness.assert_screen_contains("No run_command configured");
+    harness.assert_buffer_content("plain text\n");
+}
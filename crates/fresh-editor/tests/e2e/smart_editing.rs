@@ -1118,6 +1118,41 @@ fn test_jump_through_multiple_errors() {
     );
 }
 
+/// Test that a diagnostic jump records the departure point in position
+/// history, so Navigate Back (Alt+Left) returns to it
+#[test]
+fn test_jump_to_error_records_position_history() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.rs");
+    std::fs::write(&file_path, "line1\nline2\nline3\nline4\nline5").unwrap();
+
+    let mut harness = harness_with_auto_indent();
+    harness.open_file(&file_path).unwrap();
+
+    let diagnostics = vec![create_diagnostic(3, 0, 3, 5, "Error on line 4")];
+    apply_test_diagnostics(&mut harness, diagnostics);
+
+    // Start mid-way through line 1
+    harness
+        .send_key(KeyCode::Right, KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Right, KeyModifiers::NONE)
+        .unwrap();
+
+    harness.send_key(KeyCode::F(8), KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+    assert_eq!(harness.editor().active_cursors().primary().position, 18);
+
+    harness.send_key(KeyCode::Left, KeyModifiers::ALT).unwrap();
+    harness.render().unwrap();
+    assert_eq!(
+        harness.editor().active_cursors().primary().position,
+        2,
+        "Navigate Back should return to where the jump started"
+    );
+}
+
 // =============================================================================
 // Block/Rectangular Selection Tests
 // =============================================================================
@@ -818,3 +818,136 @@ fn test_close_tab_with_multiple_tabs_removes_tab_only() {
         screen_after_close
     );
 }
+
+fn run_command(harness: &mut EditorTestHarness, name: &str) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.wait_for_prompt().unwrap();
+    harness.type_text(name).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+}
+
+/// Open file1.txt..file4.txt in one split and switch back to file2.txt
+fn open_four_files_on_second(temp_dir: &TempDir) -> EditorTestHarness {
+    let mut harness = EditorTestHarness::new(100, 24).unwrap();
+    for i in 1..=4 {
+        let path = temp_dir.path().join(format!("file{i}.txt"));
+        std::fs::write(&path, format!("Content {i}")).unwrap();
+        harness.open_file(&path).unwrap();
+    }
+    for _ in 0..2 {
+        harness
+            .send_key(KeyCode::PageUp, KeyModifiers::CONTROL)
+            .unwrap();
+    }
+    harness.render().unwrap();
+    harness.assert_buffer_content("Content 2");
+    harness
+}
+
+/// Test that Close Other Tabs leaves only the active tab
+#[test]
+fn test_close_other_tabs_keeps_only_active() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut harness = open_four_files_on_second(&temp_dir);
+
+    run_command(&mut harness, "Close Other Tabs");
+
+    harness.assert_screen_contains("file2.txt");
+    for name in ["file1.txt", "file3.txt", "file4.txt"] {
+        harness.assert_screen_not_contains(name);
+    }
+    harness.assert_buffer_content("Content 2");
+}
+
+/// Test that Close Tabs to the Right asks once about modified tabs and
+/// discards them on request
+#[test]
+fn test_close_tabs_to_right_prompts_once_for_modified() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut harness = open_four_files_on_second(&temp_dir);
+
+    // Modify file3 and file4, then come back to file2
+    for _ in 0..2 {
+        harness
+            .send_key(KeyCode::PageDown, KeyModifiers::CONTROL)
+            .unwrap();
+        harness.type_text("EDIT").unwrap();
+    }
+    for _ in 0..2 {
+        harness
+            .send_key(KeyCode::PageUp, KeyModifiers::CONTROL)
+            .unwrap();
+    }
+    harness.render().unwrap();
+    harness.assert_buffer_content("Content 2");
+
+    run_command(&mut harness, "Close Tabs to the Right");
+    harness.assert_screen_contains("2 tab(s) modified");
+
+    harness
+        .send_key(KeyCode::Char('d'), KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+
+    harness.assert_screen_contains("file1.txt");
+    harness.assert_screen_contains("file2.txt");
+    harness.assert_screen_not_contains("file3.txt");
+    harness.assert_screen_not_contains("file4.txt");
+    harness.assert_buffer_content("Content 2");
+    assert_eq!(
+        std::fs::read_to_string(temp_dir.path().join("file4.txt")).unwrap(),
+        "Content 4"
+    );
+}
+
+/// Test that Close Others from the tab context menu asks about modified
+/// tabs instead of silently keeping them
+#[test]
+fn test_tab_menu_close_others_prompts_for_modified() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut harness = open_four_files_on_second(&temp_dir);
+
+    // Modify file1, then come back to file2
+    harness
+        .send_key(KeyCode::PageUp, KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("EDIT").unwrap();
+    harness
+        .send_key(KeyCode::PageDown, KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_buffer_content("Content 2");
+
+    let (col, row) = harness.find_text_on_screen("file2.txt").unwrap();
+    harness.mouse_right_click(col, row).unwrap();
+    harness.render().unwrap();
+    let (col, row) = harness.find_text_on_screen("Close Others").unwrap();
+    harness.mouse_click(col, row).unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("1 tab(s) modified");
+
+    harness
+        .send_key(KeyCode::Char('d'), KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+
+    harness.assert_screen_contains("file2.txt");
+    for name in ["file1.txt", "file3.txt", "file4.txt"] {
+        harness.assert_screen_not_contains(name);
+    }
+    assert_eq!(
+        std::fs::read_to_string(temp_dir.path().join("file1.txt")).unwrap(),
+        "Content 1"
+    );
+}
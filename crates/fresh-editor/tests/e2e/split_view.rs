@@ -381,6 +381,176 @@ fn test_cannot_toggle_maximize_single_split() {
     harness.assert_screen_contains("Cannot maximize");
 }
 
+/// Test moving a buffer into the next split updates both tab lists, keeps
+/// the buffer's cursor, and closes a split whose last tab moves out
+#[test]
+fn test_move_buffer_to_next_split() {
+    use fresh::input::keybindings::Action;
+
+    let mut harness = EditorTestHarness::with_temp_project(120, 40).unwrap();
+    let project_dir = harness.project_dir().unwrap();
+    let file1 = project_dir.join("file1.txt");
+    let file2 = project_dir.join("file2.txt");
+    let file3 = project_dir.join("file3.txt");
+    std::fs::write(&file1, "File 1 content").unwrap();
+    std::fs::write(&file2, "File 2 content").unwrap();
+    std::fs::write(&file3, "File 3 content").unwrap();
+
+    // Left split: file1, file3. Right split: file1, file2.
+    harness.open_file(&file1).unwrap();
+    let left = harness.editor().get_active_split();
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::SplitVertical);
+    let right = harness.editor().get_active_split();
+    harness.open_file(&file2).unwrap();
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::NextSplit);
+    assert_eq!(harness.editor().get_active_split(), left);
+    harness.open_file(&file3).unwrap();
+    let file3_id = harness.editor().active_buffer();
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+    assert_eq!(harness.cursor_position(), 14);
+
+    // Move file3 right
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::MoveBufferToNextSplit);
+    harness.render().unwrap();
+
+    assert!(!harness.editor().get_split_tabs(left).contains(&file3_id));
+    assert_eq!(
+        harness.editor().get_split_tabs(right).last(),
+        Some(&file3_id)
+    );
+    assert_eq!(harness.editor().get_active_split(), right);
+    assert_eq!(harness.editor().active_buffer(), file3_id);
+    assert_eq!(
+        harness.cursor_position(),
+        14,
+        "cursor should move with the tab"
+    );
+    harness.assert_screen_contains("File 3 content");
+
+    // Moving the left split's last tab out closes the split
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::NextSplit);
+    assert_eq!(harness.editor().get_active_split(), left);
+    assert_eq!(harness.editor().get_split_tabs(left).len(), 1);
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::MoveBufferToNextSplit);
+    assert_eq!(harness.editor().get_split_count(), 1);
+    assert_eq!(harness.editor().get_active_split(), right);
+}
+
+/// Test swapping exchanges which buffer each split shows and keeps focus
+/// on the content that was active
+#[test]
+fn test_swap_splits() {
+    use fresh::input::keybindings::Action;
+
+    let mut harness = EditorTestHarness::with_temp_project(120, 40).unwrap();
+    let project_dir = harness.project_dir().unwrap();
+    let file1 = project_dir.join("file1.txt");
+    let file2 = project_dir.join("file2.txt");
+    std::fs::write(&file1, "File 1 content").unwrap();
+    std::fs::write(&file2, "File 2 content").unwrap();
+
+    harness.open_file(&file1).unwrap();
+    let left = harness.editor().get_active_split();
+    let file1_id = harness.editor().active_buffer();
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::SplitVertical);
+    let right = harness.editor().get_active_split();
+    harness.open_file(&file2).unwrap();
+    let file2_id = harness.editor().active_buffer();
+    let right_tabs = harness.editor().get_split_tabs(right);
+
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::SwapSplits);
+    harness.render().unwrap();
+
+    assert_eq!(
+        harness.editor().get_split_buffer(left.into()),
+        Some(file2_id)
+    );
+    assert_eq!(
+        harness.editor().get_split_buffer(right.into()),
+        Some(file1_id)
+    );
+    assert_eq!(harness.editor().get_split_tabs(left), right_tabs);
+    assert_eq!(harness.editor().get_active_split(), left);
+    assert_eq!(harness.editor().active_buffer(), file2_id);
+}
+
+/// Ratio of the root split container (the only one after a single split)
+fn root_split_ratio(harness: &EditorTestHarness) -> f32 {
+    let manager = harness.editor().split_manager_for_tests();
+    manager
+        .get_ratio(manager.root().id())
+        .expect("root should be a split container")
+}
+
+/// Test that equalizing resets a resized two-way split to 0.5 and that
+/// zoom/unzoom restores the ratio it had before zooming
+#[test]
+fn test_equalize_and_zoom_splits() {
+    use fresh::input::keybindings::Action;
+
+    let mut harness = EditorTestHarness::with_temp_project(120, 40).unwrap();
+    let project_dir = harness.project_dir().unwrap();
+    let file1 = project_dir.join("file1.txt");
+    let file2 = project_dir.join("file2.txt");
+    std::fs::write(&file1, "File 1 content").unwrap();
+    std::fs::write(&file2, "File 2 content").unwrap();
+
+    harness.open_file(&file1).unwrap();
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::SplitVertical);
+    harness.open_file(&file2).unwrap();
+
+    // Grow the active split, then equalize
+    for _ in 0..2 {
+        harness
+            .editor_mut()
+            .dispatch_action_for_tests(Action::IncreaseSplitSize);
+    }
+    assert!((root_split_ratio(&harness) - 0.5).abs() > 0.05);
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::EqualizeSplits);
+    assert!((root_split_ratio(&harness) - 0.5).abs() < f32::EPSILON);
+
+    // Resize again; zooming hides the other split without touching the ratio
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::DecreaseSplitSize);
+    let before_zoom = root_split_ratio(&harness);
+
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::ToggleMaximizeSplit);
+    harness.render().unwrap();
+    assert!(harness.editor().split_manager_for_tests().is_maximized());
+    harness.assert_screen_contains("File 2 content");
+    harness.assert_screen_not_contains("File 1 content");
+
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::UnzoomSplit);
+    harness.render().unwrap();
+    assert!(!harness.editor().split_manager_for_tests().is_maximized());
+    harness.assert_screen_contains("File 1 content");
+    harness.assert_screen_contains("File 2 content");
+    assert!((root_split_ratio(&harness) - before_zoom).abs() < f32::EPSILON);
+}
+
 /// Test that closing the last buffer in a split closes the split (if other splits exist)
 #[test]
 fn test_close_last_buffer_in_split_closes_split() {
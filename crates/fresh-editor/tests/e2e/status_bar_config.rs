@@ -4,13 +4,18 @@
 //! control which elements appear (and don't appear) in the rendered status bar.
 
 use crate::common::harness::{EditorTestHarness, HarnessOptions};
+use crossterm::event::{KeyCode, KeyModifiers};
 use fresh::config::{Config, StatusBarConfig, StatusBarElement};
 use std::fs;
 
 /// Helper: create a config with the given status bar elements.
 fn config_with_status_bar(left: Vec<StatusBarElement>, right: Vec<StatusBarElement>) -> Config {
     let mut config = Config::default();
-    config.editor.status_bar = StatusBarConfig { left, right };
+    config.editor.status_bar = StatusBarConfig {
+        left,
+        right,
+        format: None,
+    };
     config
 }
 
@@ -241,3 +246,85 @@ fn test_both_sides_empty() {
         "No cursor info expected.\nStatus bar: {status}"
     );
 }
+
+/// A `format` template replaces the element lists: placeholders can be
+/// reordered around literal text, and unknown ones show up as-is.
+#[test]
+fn test_format_template_reorders_cursor_before_filename() {
+    let mut config = Config::default();
+    config.editor.status_bar.format = Some("<{line}:{col}> {file}{modified} {nope}".to_string());
+
+    let mut harness = EditorTestHarness::with_temp_project_and_config(120, 30, config).unwrap();
+
+    let dir = harness.project_dir().unwrap();
+    let file = dir.join("notes.txt");
+    fs::write(&file, "alpha\nbeta\n").unwrap();
+    harness.open_file(&file).unwrap();
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness
+        .send_key(KeyCode::Right, KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Right, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+
+    let screen = harness.screen_to_string();
+    let status = harness.get_status_bar();
+    assert!(
+        screen.contains("<2:3> notes.txt {nope}"),
+        "Template should put line:col before the file name.\nStatus bar: {status}"
+    );
+    // The default element lists are no longer used
+    assert!(
+        !status.contains("UTF-8"),
+        "Encoding isn't in the template.\nStatus bar: {status}"
+    );
+}
+
+/// `{selection}` (on by default) counts the selected characters, lines and
+/// words, sums them over all cursors, and hides without a selection.
+#[test]
+fn test_selection_stats_in_status_bar() {
+    let mut harness =
+        EditorTestHarness::with_temp_project_and_config(140, 30, Config::default()).unwrap();
+
+    let dir = harness.project_dir().unwrap();
+    let file = dir.join("notes.txt");
+    fs::write(&file, "hello world\nfoo bar\nfoo\n").unwrap();
+    harness.open_file(&file).unwrap();
+    harness.render().unwrap();
+    assert!(
+        !harness.get_status_bar().contains("selected"),
+        "No selection, no segment.\nStatus bar: {}",
+        harness.get_status_bar()
+    );
+
+    // Select the first two lines
+    for _ in 0..2 {
+        harness
+            .send_key(KeyCode::Down, KeyModifiers::SHIFT)
+            .unwrap();
+    }
+    harness.render().unwrap();
+    let status = harness.get_status_bar();
+    assert!(
+        status.contains("20 chars, 2 lines, 4 words selected"),
+        "Status bar: {status}"
+    );
+
+    // Select "foo" on the second line, then its next match: both count
+    harness.send_key(KeyCode::Up, KeyModifiers::NONE).unwrap();
+    for _ in 0..3 {
+        harness
+            .send_key(KeyCode::Right, KeyModifiers::SHIFT)
+            .unwrap();
+    }
+    harness.editor_mut().add_cursor_at_next_match();
+    harness.render().unwrap();
+    let status = harness.get_status_bar();
+    assert!(
+        status.contains("6 chars, 2 lines, 2 words selected"),
+        "Status bar: {status}"
+    );
+}
@@ -0,0 +1,116 @@
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::input::keybindings::Action;
+
+fn select_first_word(harness: &mut EditorTestHarness) {
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+        .unwrap();
+    for _ in 0..5 {
+        harness
+            .send_key(KeyCode::Right, KeyModifiers::SHIFT)
+            .unwrap();
+    }
+    assert_eq!(harness.get_selected_text(), "hello");
+}
+
+/// Surround a word with quotes from the prompt, then unwrap it again
+#[test]
+fn test_surround_with_quote_and_unwrap() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.type_text("hello world").unwrap();
+    select_first_word(&mut harness);
+
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::Surround(String::new()));
+    harness.render().unwrap();
+    harness.assert_screen_contains("Surround with");
+    harness.type_text("\"").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+
+    assert_eq!(harness.get_buffer_content().unwrap(), "\"hello\" world");
+    // The selection stays on the wrapped text
+    assert_eq!(harness.get_selected_text(), "hello");
+
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::Surround("\"".to_string()));
+    assert_eq!(harness.get_buffer_content().unwrap(), "hello world");
+    assert_eq!(harness.get_selected_text(), "hello");
+
+    // Undo restores the quotes in one step
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), "\"hello\" world");
+}
+
+/// Typing `t` at the surround prompt asks for a tag name
+#[test]
+fn test_surround_with_tag_and_unwrap() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.type_text("hello world").unwrap();
+    select_first_word(&mut harness);
+
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::Surround(String::new()));
+    harness.type_text("t").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("Tag:");
+    harness.type_text("div").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "<div>hello</div> world"
+    );
+    assert_eq!(harness.get_selected_text(), "hello");
+
+    // Selecting the whole element unwraps it too
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+        .unwrap();
+    for _ in 0.."<div>hello</div>".len() {
+        harness
+            .send_key(KeyCode::Right, KeyModifiers::SHIFT)
+            .unwrap();
+    }
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::Surround("<div>".to_string()));
+    assert_eq!(harness.get_buffer_content().unwrap(), "hello world");
+    assert_eq!(harness.get_selected_text(), "hello");
+}
+
+/// Each cursor's selection is wrapped independently
+#[test]
+fn test_surround_multi_cursor() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.type_text("foo\nfoo").unwrap();
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+        .unwrap();
+    harness.send_key(KeyCode::End, KeyModifiers::SHIFT).unwrap();
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::AddCursorNextMatch);
+
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::Surround("(".to_string()));
+    assert_eq!(harness.get_buffer_content().unwrap(), "(foo)\n(foo)");
+
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::Surround(")".to_string()));
+    assert_eq!(harness.get_buffer_content().unwrap(), "foo\nfoo");
+}
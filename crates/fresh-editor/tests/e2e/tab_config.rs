@@ -1,10 +1,11 @@
 //! Tests for per-language tab configuration options:
 //! - `show_whitespace_tabs`: Whether to display tab indicators (→) in the editor
 //! - `use_tabs`: Whether pressing Tab inserts a tab character or spaces
+//! - `indent_style` / `indent_width`: Override `use_tabs` / `tab_size`
 
 use crate::common::harness::{EditorTestHarness, HarnessOptions};
 use crossterm::event::{KeyCode, KeyModifiers};
-use fresh::config::Config;
+use fresh::config::{Config, IndentStyle};
 use tempfile::TempDir;
 
 // =============================================================================
@@ -968,3 +969,71 @@ fn test_issue_1068_skip_over_with_dedent_uses_tabs_in_go() {
         );
     }
 }
+
+// =============================================================================
+// indent_style / indent_width Tests
+// =============================================================================
+
+/// Test that `indent_width` sets how many spaces Tab inserts
+#[test]
+fn test_indent_width_sets_tab_key_spaces() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.txt");
+    std::fs::write(&file_path, "").unwrap();
+
+    let mut config = Config::default();
+    config.editor.indent_width = Some(2);
+
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE).unwrap();
+
+    assert_eq!(harness.get_buffer_content().unwrap(), "  ");
+}
+
+/// Test that a language's `indent_style` wins over its `use_tabs`
+#[test]
+fn test_language_indent_style_overrides_use_tabs() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.rs");
+    std::fs::write(&file_path, "").unwrap();
+
+    let mut config = Config::default();
+    let rust_config = config.languages.get_mut("rust").unwrap();
+    rust_config.use_tabs = Some(false);
+    rust_config.indent_style = Some(IndentStyle::Tabs);
+
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE).unwrap();
+
+    assert_eq!(harness.get_buffer_content().unwrap(), "\t");
+}
+
+/// Test that tabs are drawn `indent_width` wide and that clicking maps
+/// screen columns back through that same width
+#[test]
+fn test_indent_width_sets_tab_display_and_click_columns() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.txt");
+    std::fs::write(&file_path, "\tX\nabcdef").unwrap();
+
+    let mut config = Config::default();
+    config.editor.tab_size = 8;
+    config.editor.indent_width = Some(3);
+
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    let (x_col, x_row) = harness.find_text_on_screen("X").unwrap();
+    let (arrow_col, _) = harness.find_text_on_screen("→").unwrap();
+    assert_eq!(x_col - arrow_col, 3, "A tab should be drawn 3 columns wide");
+
+    harness.mouse_click(x_col, x_row).unwrap();
+    assert_eq!(
+        harness.cursor_position(),
+        1,
+        "Clicking the X should put the cursor right after the tab"
+    );
+}
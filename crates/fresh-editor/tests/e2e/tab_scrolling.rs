@@ -215,3 +215,38 @@ fn test_tab_scroll_button_click() {
         harness.render().unwrap();
     }
 }
+
+/// Test that the "Switch to Tab by Name" picker reaches every tab, including
+/// ones scrolled out of the tab bar
+#[test]
+fn test_switch_to_tab_picker_lists_hidden_tabs() {
+    let temp_dir = TempDir::new().unwrap();
+    let files = create_dummy_files(&temp_dir);
+
+    let mut harness = EditorTestHarness::new(NARROW_WIDTH, TEST_HEIGHT).unwrap();
+    for file_path in &files {
+        harness.open_file(file_path).unwrap();
+    }
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("long_file_name_number_00.txt");
+
+    for i in 0..NUM_FILES {
+        harness
+            .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+            .unwrap();
+        harness.wait_for_prompt().unwrap();
+        harness.type_text("Switch to Tab by Name").unwrap();
+        harness
+            .send_key(KeyCode::Enter, KeyModifiers::NONE)
+            .unwrap();
+        harness.wait_for_prompt().unwrap();
+        harness.type_text(&format!("number_{:02}", i)).unwrap();
+        harness
+            .send_key(KeyCode::Enter, KeyModifiers::NONE)
+            .unwrap();
+        harness.render().unwrap();
+
+        harness.assert_screen_contains(&format!("Content for file {}", i));
+        harness.assert_screen_contains(files[i].file_name().unwrap().to_str().unwrap());
+    }
+}
@@ -2402,6 +2402,177 @@ fn test_scrollback_captured_after_session_restore() {
     }
 }
 
+/// Open a terminal in a fresh workspace, save it, and return the terminal's
+/// backing file path. Returns `None` when no PTY is available.
+#[cfg(not(windows))]
+fn save_workspace_with_terminal(
+    project_dir: &std::path::Path,
+    dir_context: &fresh::config_io::DirectoryContext,
+) -> Option<std::path::PathBuf> {
+    if native_pty_system()
+        .openpty(PtySize {
+            rows: 1,
+            cols: 1,
+            pixel_width: 0,
+            pixel_height: 0,
+        })
+        .is_err()
+    {
+        eprintln!("Skipping terminal session test: PTY not available");
+        return None;
+    }
+
+    let mut harness = EditorTestHarness::with_shared_dir_context(
+        80,
+        24,
+        Config::default(),
+        project_dir.to_path_buf(),
+        dir_context.clone(),
+    )
+    .unwrap();
+    harness.editor_mut().open_terminal();
+    harness.render().unwrap();
+    harness.editor_mut().save_workspace().unwrap();
+
+    let buffer_id = harness.editor().active_buffer_id();
+    let terminal_id = harness.editor().get_terminal_id(buffer_id).unwrap();
+    harness
+        .editor()
+        .terminal_backing_files()
+        .get(&terminal_id)
+        .cloned()
+}
+
+/// Test that restore replays only the last `restore_scrollback_lines` lines
+/// of the backing file into the terminal buffer.
+#[test]
+#[cfg(not(windows))] // Uses Unix shell
+fn test_session_restore_trims_scrollback_to_line_cap() {
+    use fresh::config_io::DirectoryContext;
+    use tempfile::TempDir;
+
+    let data_temp_dir = TempDir::new().unwrap();
+    let project_temp_dir = TempDir::new().unwrap();
+    let project_dir = project_temp_dir.path().join("project");
+    std::fs::create_dir(&project_dir).unwrap();
+    let dir_context = DirectoryContext::for_testing(data_temp_dir.path());
+
+    let Some(backing_path) = save_workspace_with_terminal(&project_dir, &dir_context) else {
+        return;
+    };
+
+    // Replace whatever the first session wrote with known content
+    let content: String = (0..50)
+        .map(|i| format!("RESTORED_LINE_{:02}\n", i))
+        .collect();
+    std::fs::write(&backing_path, content).unwrap();
+
+    let mut config = Config::default();
+    config.terminal.restore_scrollback_lines = 20;
+    let mut harness = EditorTestHarness::with_shared_dir_context(
+        80,
+        24,
+        config,
+        project_dir.clone(),
+        dir_context.clone(),
+    )
+    .unwrap();
+    let restored = harness.editor_mut().try_restore_workspace().unwrap();
+    assert!(restored, "Session should have been restored");
+    harness.render().unwrap();
+
+    let buffer_id = harness.editor().active_buffer_id();
+    assert!(harness.editor().is_terminal_buffer(buffer_id));
+    let content = harness
+        .editor()
+        .get_buffer_content(buffer_id)
+        .unwrap_or_default();
+    assert!(
+        content.contains("RESTORED_LINE_30") && content.contains("RESTORED_LINE_49"),
+        "The last 20 lines should be restored. Content:\n{}",
+        content
+    );
+    assert!(
+        !content.contains("RESTORED_LINE_29"),
+        "Lines beyond the cap should be dropped. Content:\n{}",
+        content
+    );
+}
+
+/// Test that scrollback that isn't valid UTF-8 is restored with its bytes
+/// intact, and that a missing backing file restores an empty terminal instead
+/// of failing the restore.
+#[test]
+#[cfg(not(windows))] // Uses Unix shell
+fn test_session_restore_keeps_non_utf8_scrollback() {
+    use fresh::config_io::DirectoryContext;
+    use tempfile::TempDir;
+
+    let data_temp_dir = TempDir::new().unwrap();
+    let project_temp_dir = TempDir::new().unwrap();
+    let project_dir = project_temp_dir.path().join("project");
+    std::fs::create_dir(&project_dir).unwrap();
+    let dir_context = DirectoryContext::for_testing(data_temp_dir.path());
+
+    let Some(backing_path) = save_workspace_with_terminal(&project_dir, &dir_context) else {
+        return;
+    };
+
+    // A stray invalid byte, then output cut off in the middle of a
+    // multi-byte character
+    let scrollback: &[u8] = b"PRIOR_OUTPUT \xff\n\xe2\x82";
+    std::fs::write(&backing_path, scrollback).unwrap();
+    {
+        let mut harness = EditorTestHarness::with_shared_dir_context(
+            80,
+            24,
+            Config::default(),
+            project_dir.clone(),
+            dir_context.clone(),
+        )
+        .unwrap();
+        let restored = harness.editor_mut().try_restore_workspace().unwrap();
+        assert!(restored, "Session should have been restored");
+        harness.render().unwrap();
+
+        let buffer_id = harness.editor().active_buffer_id();
+        assert!(harness.editor().is_terminal_buffer(buffer_id));
+        let content = harness
+            .editor()
+            .get_buffer_content(buffer_id)
+            .unwrap_or_default();
+        assert!(
+            content.contains("PRIOR_OUTPUT"),
+            "Non-UTF-8 scrollback should be replayed. Content:\n{}",
+            content
+        );
+
+        let on_disk = std::fs::read(&backing_path).unwrap();
+        assert!(
+            on_disk.starts_with(scrollback),
+            "Backing file bytes should be preserved, got {:?}",
+            on_disk
+        );
+    }
+
+    // No backing file at all
+    std::fs::remove_file(&backing_path).unwrap();
+    let mut harness = EditorTestHarness::with_shared_dir_context(
+        80,
+        24,
+        Config::default(),
+        project_dir.clone(),
+        dir_context.clone(),
+    )
+    .unwrap();
+    let restored = harness.editor_mut().try_restore_workspace().unwrap();
+    assert!(restored, "Session should have been restored");
+    harness.render().unwrap();
+    assert!(harness
+        .editor()
+        .is_terminal_buffer(harness.editor().active_buffer_id()));
+}
+
 /// Test that scrollback content is stable and accessible after repeated mode toggles.
 ///
 /// This test verifies:
@@ -3325,3 +3496,51 @@ fn test_hidden_terminal_resyncs_pty_size_when_revealed() {
         rows_after
     );
 }
+
+/// Test that the saved workspace records a terminal's current directory,
+/// not the directory it was opened in
+#[test]
+fn test_terminal_cwd_saved_in_workspace() {
+    use fresh::workspace::Workspace;
+    use tempfile::TempDir;
+
+    if harness_or_skip(1, 1).is_none() {
+        return;
+    }
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("project");
+    let sub_dir = project_dir.join("sub");
+    std::fs::create_dir_all(&sub_dir).unwrap();
+
+    let mut harness = EditorTestHarness::with_config_and_working_dir(
+        100,
+        30,
+        Config::default(),
+        project_dir.clone(),
+    )
+    .unwrap();
+    harness.editor_mut().open_terminal();
+    harness.render().unwrap();
+
+    // Change directory and report it the way OSC 7 aware shells do
+    harness.editor_mut().send_terminal_input(
+        b"cd sub && printf '\\033]7;file://localhost%s\\007' \"$PWD\" && echo CWD_$((40+2))_DONE\n",
+    );
+    harness
+        .wait_until(|h| h.screen_to_string().contains("CWD_42_DONE"))
+        .expect("Shell should change directory");
+
+    harness.editor_mut().save_workspace().unwrap();
+    let workspace = Workspace::load(&project_dir)
+        .unwrap()
+        .expect("Workspace should have been saved");
+    let saved_cwd = workspace.terminals[0]
+        .cwd
+        .clone()
+        .expect("Terminal cwd should be saved");
+    assert_eq!(
+        saved_cwd.canonicalize().unwrap(),
+        sub_dir.canonicalize().unwrap(),
+        "Saved terminal cwd should follow the shell's cd"
+    );
+}
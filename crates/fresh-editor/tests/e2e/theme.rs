@@ -784,3 +784,191 @@ fn test_theme_selector_strips_url_scheme_from_display() {
 
     drop(temp_dir);
 }
+
+/// A user theme with a distinctive gutter (line number) color.
+fn gutter_theme_json(line_number_fg: [u8; 3]) -> String {
+    format!(
+        r#"{{
+        "name": "Gutter Preview",
+        "editor": {{ "line_number_fg": {:?} }},
+        "ui": {{}},
+        "search": {{}},
+        "diagnostic": {{}},
+        "syntax": {{}}
+    }}"#,
+        line_number_fg
+    )
+}
+
+/// Foreground color of the first line's line number in the gutter.
+fn gutter_fg(harness: &EditorTestHarness) -> Option<Color> {
+    let (start_row, _) = harness.content_area_rows();
+    let row = start_row as u16;
+    let col = harness.get_row_text(row).find('1')? as u16;
+    harness.get_cell_style(col, row)?.fg
+}
+
+/// Open the theme switcher and move the selection onto the theme matching
+/// `filter`, which previews it.
+fn select_theme_in_switcher(harness: &mut EditorTestHarness, filter: &str) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.wait_for_prompt().unwrap();
+    harness.type_text("Select Theme").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.wait_for_screen_contains("Select theme").unwrap();
+
+    for _ in 0..20 {
+        harness
+            .send_key(KeyCode::Backspace, KeyModifiers::NONE)
+            .unwrap();
+    }
+    harness.type_text(filter).unwrap();
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+}
+
+fn harness_with_gutter_theme(temp_dir: &TempDir) -> EditorTestHarness {
+    let dir_context = DirectoryContext::for_testing(temp_dir.path());
+    let themes_dir = temp_dir.path().join("config").join("themes");
+    fs::create_dir_all(&themes_dir).unwrap();
+    fs::write(
+        themes_dir.join("gutter-preview.json"),
+        gutter_theme_json([255, 0, 255]),
+    )
+    .unwrap();
+
+    let project_root = temp_dir.path().join("project_root");
+    fs::create_dir_all(project_root.join("plugins")).unwrap();
+
+    let mut config = Config::default();
+    config.theme = "dark".into();
+    let mut harness = EditorTestHarness::create(
+        80,
+        24,
+        HarnessOptions::new()
+            .with_config(config)
+            .with_working_dir(project_root)
+            .with_shared_dir_context(dir_context)
+            .without_empty_plugins_dir(),
+    )
+    .unwrap();
+    harness.type_text("hello").unwrap();
+    harness
+}
+
+/// Moving the selection in the theme switcher previews the theme live, and
+/// Escape restores the theme that was active before the switcher opened.
+#[test]
+fn test_theme_switcher_previews_selection_and_escape_restores() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut harness = harness_with_gutter_theme(&temp_dir);
+    let original_fg = gutter_fg(&harness);
+    assert_ne!(original_fg, Some(Color::Rgb(255, 0, 255)));
+
+    select_theme_in_switcher(&mut harness, "gutter-preview");
+    assert_eq!(gutter_fg(&harness), Some(Color::Rgb(255, 0, 255)));
+
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+    assert_eq!(harness.editor().theme().name, "dark");
+    assert_eq!(gutter_fg(&harness), original_fg);
+}
+
+/// Editing a theme file while the switcher is open reloads it, keeping the
+/// selected theme previewed.
+#[test]
+fn test_theme_switcher_hot_reloads_changed_theme_file() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut harness = harness_with_gutter_theme(&temp_dir);
+
+    select_theme_in_switcher(&mut harness, "gutter-preview");
+    assert_eq!(gutter_fg(&harness), Some(Color::Rgb(255, 0, 255)));
+
+    // Make sure the rewrite gets a distinct modification time
+    std::thread::sleep(std::time::Duration::from_millis(50));
+    fs::write(
+        temp_dir
+            .path()
+            .join("config")
+            .join("themes")
+            .join("gutter-preview.json"),
+        gutter_theme_json([0, 255, 0]),
+    )
+    .unwrap();
+
+    harness
+        .wait_until(|h| gutter_fg(h) == Some(Color::Rgb(0, 255, 0)))
+        .unwrap();
+    assert!(harness.screen_to_string().contains("Select theme"));
+}
+
+/// A theme scope override for `macro` recolors an LSP semantic token of
+/// that type in the rendered buffer.
+#[test]
+fn test_theme_scope_override_colors_semantic_macro_token() {
+    let temp_dir = TempDir::new().unwrap();
+    let dir_context = DirectoryContext::for_testing(temp_dir.path());
+    let themes_dir = temp_dir.path().join("config").join("themes");
+    fs::create_dir_all(&themes_dir).unwrap();
+    fs::write(
+        themes_dir.join("macro-theme.json"),
+        r#"{
+        "name": "Macro Theme",
+        "editor": {},
+        "ui": {},
+        "search": {},
+        "diagnostic": {},
+        "syntax": {},
+        "scopes": { "rust.macro": [255, 0, 128] }
+    }"#,
+    )
+    .unwrap();
+
+    let project_root = temp_dir.path().join("project_root");
+    fs::create_dir_all(project_root.join("plugins")).unwrap();
+    let test_file = project_root.join("demo.rs");
+    fs::write(&test_file, "fn main() {\n    println!(\"hi\");\n}\n").unwrap();
+
+    let mut config = Config::default();
+    config.theme = "macro-theme.json".into();
+    let mut harness = EditorTestHarness::create(
+        80,
+        24,
+        HarnessOptions::new()
+            .with_config(config)
+            .with_working_dir(project_root)
+            .with_shared_dir_context(dir_context),
+    )
+    .unwrap();
+    harness.open_file(&test_file).unwrap();
+    assert_eq!(harness.editor().theme().name, "Macro Theme");
+
+    let theme = harness.editor().theme().clone();
+    let tokens = [
+        fresh::state::SemanticTokenSpan {
+            range: 3..7, // "main"
+            token_type: "function".to_string(),
+            modifiers: Vec::new(),
+        },
+        fresh::state::SemanticTokenSpan {
+            range: 16..23, // "println"
+            token_type: "macro".to_string(),
+            modifiers: Vec::new(),
+        },
+    ];
+    fresh::services::lsp::semantic_tokens::apply_semantic_tokens_to_state(
+        harness.editor_mut().active_state_mut(),
+        &tokens,
+        &theme,
+    );
+    harness.render().unwrap();
+
+    let fg_of = |harness: &EditorTestHarness, text: &str| {
+        let (x, y) = harness.find_text_on_screen(text).unwrap();
+        harness.get_cell_style(x, y).unwrap().fg
+    };
+    assert_eq!(fg_of(&harness, "println"), Some(Color::Rgb(255, 0, 128)));
+    assert_eq!(fg_of(&harness, "main"), Some(theme.syntax_function));
+}
@@ -9,6 +9,7 @@
 use crate::common::harness::EditorTestHarness;
 use crossterm::event::{KeyCode, KeyModifiers};
 use fresh::config::Config;
+use fresh::input::keybindings::Action;
 use ratatui::style::Color;
 use tempfile::TempDir;
 
@@ -485,3 +486,97 @@ fn test_add_ruler_zero_column() {
         );
     }
 }
+
+/// Test that an argument typed after the command name confirms the prompt
+/// the command opens, without asking again.
+#[test]
+fn test_add_ruler_command_with_inline_argument() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    let _fixture = harness.load_buffer_from_text(&"X".repeat(60)).unwrap();
+    harness.render().unwrap();
+
+    let (content_first_row, _) = harness.content_area_rows();
+    let row = content_first_row as u16;
+    let ruler_x = gutter_width(&harness) + 40;
+
+    run_command(&mut harness, "Add Ruler 40");
+
+    assert!(
+        !harness.editor().is_prompting(),
+        "No follow-up prompt should open when the argument is given inline"
+    );
+    assert!(
+        has_ruler_bg(&harness, ruler_x, row),
+        "Ruler should appear at column 40 after `Add Ruler 40`"
+    );
+}
+
+/// Test that a malformed inline argument leaves the prompt open with the
+/// text filled in instead of failing.
+#[test]
+fn test_add_ruler_command_with_invalid_inline_argument() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    let _fixture = harness.load_buffer_from_text(&"X".repeat(60)).unwrap();
+    harness.render().unwrap();
+
+    run_command(&mut harness, "Add Ruler abc");
+
+    assert_eq!(
+        harness.editor().prompt_input(),
+        Some("abc"),
+        "Add Ruler prompt should stay open with the malformed argument"
+    );
+}
+
+/// Test that an argument for a prompt whose input isn't checked up front is
+/// filled in for the user to confirm rather than acted on.
+#[test]
+fn test_inline_argument_for_unchecked_prompt_waits_for_confirmation() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    let _fixture = harness.load_buffer_from_text("content").unwrap();
+    harness.render().unwrap();
+
+    run_command(&mut harness, "Save File As notes.txt");
+
+    assert_eq!(
+        harness.editor().prompt_input(),
+        Some("notes.txt"),
+        "Save As prompt should wait for confirmation with the argument filled in"
+    );
+}
+
+/// Test that Repeat Last Command re-runs the last palette command with the
+/// same inline argument.
+#[test]
+fn test_repeat_last_command_reuses_arguments() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    let _fixture = harness.load_buffer_from_text(&"X".repeat(60)).unwrap();
+    harness.render().unwrap();
+
+    let (content_first_row, _) = harness.content_area_rows();
+    let row = content_first_row as u16;
+    let ruler_x = gutter_width(&harness) + 30;
+
+    run_command(&mut harness, "Add Ruler 30");
+    assert!(has_ruler_bg(&harness, ruler_x, row));
+
+    // Remove it outside the palette so the last palette command is unchanged
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::RemoveRuler);
+    harness.type_text("30").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    assert!(!has_ruler_bg(&harness, ruler_x, row));
+
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::RepeatLastCommand);
+    harness.render().unwrap();
+    assert!(
+        has_ruler_bg(&harness, ruler_x, row),
+        "Repeat Last Command should add the ruler at column 30 again"
+    );
+}
@@ -2344,3 +2344,102 @@ fn test_restore_flag_overrides_disabled_config() {
         harness.assert_screen_contains("workspace content");
     }
 }
+
+/// Test that the theme chosen while a workspace is open comes back when the
+/// workspace is reopened, even though the base config names another theme
+#[test]
+fn test_session_restores_selected_theme() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("project");
+    std::fs::create_dir(&project_dir).unwrap();
+    let dir_context = DirectoryContext::for_testing(temp_dir.path());
+
+    // First session: switch to nord through the theme picker and save
+    {
+        let mut harness = EditorTestHarness::create(
+            80,
+            24,
+            HarnessOptions::new()
+                .with_config(Config::default())
+                .with_working_dir(project_dir.clone())
+                .with_shared_dir_context(dir_context.clone())
+                .without_empty_plugins_dir(),
+        )
+        .unwrap();
+        assert_eq!(harness.editor().theme().name, "high-contrast");
+
+        harness
+            .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+            .unwrap();
+        harness.wait_for_prompt().unwrap();
+        harness.type_text("Select Theme").unwrap();
+        harness
+            .send_key(KeyCode::Enter, KeyModifiers::NONE)
+            .unwrap();
+        harness.wait_for_screen_contains("Select theme").unwrap();
+        for _ in 0..20 {
+            harness
+                .send_key(KeyCode::Backspace, KeyModifiers::NONE)
+                .unwrap();
+        }
+        harness.type_text("nord").unwrap();
+        harness
+            .send_key(KeyCode::Enter, KeyModifiers::NONE)
+            .unwrap();
+        harness.wait_for_prompt_closed().unwrap();
+        assert_eq!(harness.editor().theme().name, "nord");
+
+        harness.editor_mut().save_workspace().unwrap();
+    }
+
+    // Second session: the base config still says high-contrast
+    {
+        let mut harness = EditorTestHarness::create(
+            80,
+            24,
+            HarnessOptions::new()
+                .with_config(Config::default())
+                .with_working_dir(project_dir.clone())
+                .with_shared_dir_context(dir_context.clone())
+                .without_empty_plugins_dir(),
+        )
+        .unwrap();
+        assert_eq!(harness.editor().theme().name, "high-contrast");
+
+        let restored = harness.editor_mut().try_restore_workspace().unwrap();
+        assert!(restored, "Session should have been restored");
+        assert_eq!(
+            harness.editor().theme().name,
+            "nord",
+            "Reopening the workspace should restore the theme chosen in it"
+        );
+        assert_eq!(
+            harness.editor().config().theme,
+            Config::default().theme,
+            "The workspace theme must not be written into the config"
+        );
+
+        // The override is kept for the next session
+        let workspace = harness.editor().capture_workspace();
+        assert_eq!(
+            workspace.config_overrides.theme.as_deref(),
+            Some("builtin://nord")
+        );
+    }
+
+    // A workspace where the theme was never switched stores no override
+    let other_dir = temp_dir.path().join("other");
+    std::fs::create_dir(&other_dir).unwrap();
+    let harness = EditorTestHarness::create(
+        80,
+        24,
+        HarnessOptions::new()
+            .with_config(Config::default())
+            .with_working_dir(other_dir)
+            .with_shared_dir_context(dir_context)
+            .without_empty_plugins_dir(),
+    )
+    .unwrap();
+    let workspace = harness.editor().capture_workspace();
+    assert!(workspace.config_overrides.theme.is_none());
+}
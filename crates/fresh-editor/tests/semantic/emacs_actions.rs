@@ -79,6 +79,111 @@ fn theorem_transpose_chars_at_end_is_noop() {
     });
 }
 
+#[test]
+fn theorem_transpose_chars_respects_multibyte_boundaries() {
+    // The swap works on whole characters, not bytes: "aé|b" becomes
+    // "abé" with the cursor after both.
+    assert_buffer_scenario(BufferScenario {
+        description: "TransposeChars swaps multi-byte characters whole".into(),
+        initial_text: "aéb".into(),
+        actions: vec![
+            Action::MoveDocumentEnd,
+            Action::MoveLeft,
+            Action::TransposeChars,
+        ],
+        expected_text: "abé".into(),
+        expected_primary: CursorExpect::at(4),
+        expected_extra_cursors: vec![],
+        expected_selection_text: None,
+        ..Default::default()
+    });
+}
+
+#[test]
+fn theorem_transpose_chars_at_line_end_is_noop() {
+    // The char at the cursor is the newline; swapping it would merge
+    // lines, so TransposeChars leaves the text alone.
+    assert_buffer_scenario(BufferScenario {
+        description: "TransposeChars at end of line is a no-op".into(),
+        initial_text: "ab\ncd".into(),
+        actions: vec![
+            Action::MoveDocumentStart,
+            Action::MoveLineEnd,
+            Action::TransposeChars,
+        ],
+        expected_text: "ab\ncd".into(),
+        expected_primary: CursorExpect::at(2),
+        expected_extra_cursors: vec![],
+        expected_selection_text: None,
+        ..Default::default()
+    });
+}
+
+// ─────────────────────────────────────────────────────────────────────────
+// TransposeWords (Emacs M-t)
+// ─────────────────────────────────────────────────────────────────────────
+
+#[test]
+fn theorem_transpose_words_between_words() {
+    // "foo |bar baz": the word before the cursor swaps with the one
+    // after it, and the cursor ends up after both.
+    assert_buffer_scenario(BufferScenario {
+        description: "TransposeWords swaps the words around the cursor".into(),
+        initial_text: "foo bar baz".into(),
+        actions: vec![
+            Action::MoveDocumentStart,
+            Action::MoveRight,
+            Action::MoveRight,
+            Action::MoveRight,
+            Action::MoveRight,
+            Action::TransposeWords,
+        ],
+        expected_text: "bar foo baz".into(),
+        expected_primary: CursorExpect::at(7),
+        expected_extra_cursors: vec![],
+        expected_selection_text: None,
+        ..Default::default()
+    });
+}
+
+#[test]
+fn theorem_transpose_words_inside_word_swaps_with_next() {
+    // "foo ba|r baz": a cursor inside a word swaps that word with the
+    // following one.
+    assert_buffer_scenario(BufferScenario {
+        description: "TransposeWords inside a word swaps it with the next word".into(),
+        initial_text: "foo bar baz".into(),
+        actions: vec![
+            Action::MoveDocumentEnd,
+            Action::MoveLeft,
+            Action::MoveLeft,
+            Action::MoveLeft,
+            Action::MoveLeft,
+            Action::MoveLeft,
+            Action::TransposeWords,
+        ],
+        expected_text: "foo baz bar".into(),
+        expected_primary: CursorExpect::at(11),
+        expected_extra_cursors: vec![],
+        expected_selection_text: None,
+        ..Default::default()
+    });
+}
+
+#[test]
+fn theorem_transpose_words_without_following_word_is_noop() {
+    assert_buffer_scenario(BufferScenario {
+        description: "TransposeWords after the last word is a no-op".into(),
+        initial_text: "foo bar".into(),
+        actions: vec![Action::MoveDocumentEnd, Action::TransposeWords],
+        expected_text: "foo bar".into(),
+        expected_primary: CursorExpect::at(7),
+        expected_extra_cursors: vec![],
+        expected_selection_text: None,
+        ..Default::default()
+    });
+}
+
 // ─────────────────────────────────────────────────────────────────────────
 // OpenLine (Emacs C-o)
 // ─────────────────────────────────────────────────────────────────────────
@@ -54,6 +54,7 @@ pub mod migrated_undo_redo_full;
 pub mod migrated_unicode;
 pub mod migrated_workspace;
 pub mod multicursor;
+pub mod number_increment;
 pub mod phase_proofs;
 pub mod properties;
 pub mod regressions;
@@ -0,0 +1,82 @@
+//! IncrementNumber / DecrementNumber (vim Ctrl-A / Ctrl-X) as theorems.
+//!
+//! The number under or after the cursor on its line changes in place;
+//! zero padding, sign and hex notation survive, and the cursor lands
+//! just after the updated number.
+
+use crate::common::scenario::buffer_scenario::{
+    assert_buffer_scenario, BufferScenario, CursorExpect,
+};
+use fresh::test_api::Action;
+
+#[test]
+fn theorem_increment_number_after_cursor_carries() {
+    assert_buffer_scenario(BufferScenario {
+        description: "IncrementNumber turns v9 into v10".into(),
+        initial_text: "v9".into(),
+        actions: vec![Action::IncrementNumber],
+        expected_text: "v10".into(),
+        expected_primary: CursorExpect::at(3),
+        expected_extra_cursors: vec![],
+        expected_selection_text: None,
+        ..Default::default()
+    });
+}
+
+#[test]
+fn theorem_decrement_number_keeps_zero_padding() {
+    assert_buffer_scenario(BufferScenario {
+        description: "DecrementNumber turns 007 into 006".into(),
+        initial_text: "007".into(),
+        actions: vec![Action::DecrementNumber],
+        expected_text: "006".into(),
+        expected_primary: CursorExpect::at(3),
+        expected_extra_cursors: vec![],
+        expected_selection_text: None,
+        ..Default::default()
+    });
+}
+
+#[test]
+fn theorem_decrement_number_crosses_zero() {
+    // A leading '-' not attached to a word is the number's sign
+    assert_buffer_scenario(BufferScenario {
+        description: "DecrementNumber takes 0 to -1 and -1 to -2".into(),
+        initial_text: "x = 0".into(),
+        actions: vec![Action::DecrementNumber, Action::DecrementNumber],
+        expected_text: "x = -2".into(),
+        expected_primary: CursorExpect::at(6),
+        expected_extra_cursors: vec![],
+        expected_selection_text: None,
+        ..Default::default()
+    });
+}
+
+#[test]
+fn theorem_increment_number_hex() {
+    assert_buffer_scenario(BufferScenario {
+        description: "IncrementNumber keeps hex notation and digit case".into(),
+        initial_text: "mask 0x0F".into(),
+        actions: vec![Action::IncrementNumber],
+        expected_text: "mask 0x10".into(),
+        expected_primary: CursorExpect::at(9),
+        expected_extra_cursors: vec![],
+        expected_selection_text: None,
+        ..Default::default()
+    });
+}
+
+#[test]
+fn theorem_increment_number_per_cursor() {
+    // Each cursor changes its own line's number independently
+    assert_buffer_scenario(BufferScenario {
+        description: "IncrementNumber applies to every cursor's number".into(),
+        initial_text: "a1\nb9".into(),
+        actions: vec![Action::AddCursorBelow, Action::IncrementNumber],
+        expected_text: "a2\nb10".into(),
+        expected_primary: CursorExpect::at(6),
+        expected_extra_cursors: vec![CursorExpect::at(2)],
+        expected_selection_text: None,
+        ..Default::default()
+    });
+}
@@ -1,8 +1,12 @@
 mod common;
 
-use common::harness::EditorTestHarness;
+use common::fault_filesystem::FaultInjectingFileSystem;
+use common::harness::{EditorTestHarness, HarnessOptions};
 use fresh::config::Config;
+use fresh::config_io::DirectoryContext;
+use fresh::model::filesystem::StdFileSystem;
 use std::fs;
+use std::sync::Arc;
 use std::time::Duration;
 
 /// Helper to create an auto-save-enabled config with a short interval for testing
@@ -104,6 +108,39 @@ fn test_persistent_auto_save_fires_after_interval() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_persistent_auto_save_waits_for_inactivity() -> anyhow::Result<()> {
+    let config = auto_save_config(5);
+
+    let mut harness = EditorTestHarness::with_temp_project_and_config(80, 24, config)?;
+    let temp_dir = harness.project_dir().unwrap();
+    let file_path = temp_dir.join("test_inactivity.txt");
+    fs::write(&file_path, "Original")?;
+
+    harness.open_file(&file_path)?;
+    harness.type_text("A")?;
+
+    // Keep editing: each edit restarts the countdown, so 6s after the first
+    // edit but only 3s after the last one nothing is written yet
+    harness.advance_time(Duration::from_millis(3000));
+    harness.type_text("B")?;
+    harness.advance_time(Duration::from_millis(3000));
+    let saved = harness.editor_mut().auto_save_persistent_buffers()?;
+    assert_eq!(saved, 0, "Should not save while the user is still editing");
+    assert_eq!(fs::read_to_string(&file_path)?, "Original");
+
+    // 5.1s of inactivity after the last edit
+    harness.advance_time(Duration::from_millis(2100));
+    let saved = harness.editor_mut().auto_save_persistent_buffers()?;
+    assert_eq!(saved, 1, "Should save once editing has been idle");
+
+    let content = fs::read_to_string(&file_path)?;
+    assert!(content.contains("AB"), "File should contain both edits");
+    assert!(!harness.editor().active_state().buffer.is_modified());
+
+    Ok(())
+}
+
 #[test]
 fn test_auto_recovery_save_throttled_before_interval() -> anyhow::Result<()> {
     let mut config = Config::default();
@@ -129,3 +166,115 @@ fn test_auto_recovery_save_throttled_before_interval() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+/// An auto-save interrupted mid-write must leave the original file intact
+/// (the partial data only ever lands in the temp file that `write_file`
+/// renames into place), and the unsaved edit must still be recoverable in
+/// the next session.
+#[test]
+fn test_interrupted_auto_save_recovers_from_snapshot() -> anyhow::Result<()> {
+    let temp_dir = tempfile::TempDir::new()?;
+    let project_dir = temp_dir.path().join("project");
+    fs::create_dir(&project_dir)?;
+    let file_path = project_dir.join("notes.txt");
+    fs::write(&file_path, "Original")?;
+    let dir_context = DirectoryContext::for_testing(temp_dir.path());
+
+    let mut config = auto_save_config(1);
+    config.editor.auto_recovery_save_interval_secs = 1;
+    config.editor.hot_exit = true;
+
+    // Session 1: edit, snapshot for recovery, then have auto-save die
+    // partway through writing. The harness is dropped without a clean
+    // shutdown, as if the editor had crashed.
+    {
+        let fault_fs = Arc::new(FaultInjectingFileSystem::new(Arc::new(StdFileSystem)));
+        let mut harness = EditorTestHarness::create(
+            80,
+            24,
+            HarnessOptions::new()
+                .with_config(config.clone())
+                .with_working_dir(project_dir.clone())
+                .with_shared_dir_context(dir_context.clone())
+                .with_filesystem(fault_fs.clone()),
+        )?;
+        harness.startup(true, &[])?;
+
+        harness.open_file(&file_path)?;
+        harness.type_text("Edited ")?;
+        harness.advance_time(Duration::from_millis(2100));
+
+        let snapshots = harness.editor_mut().auto_recovery_save_dirty_buffers()?;
+        assert_eq!(snapshots, 1, "Dirty buffer should be snapshotted");
+
+        fault_fs.arm_interrupted_write();
+        let saved = harness.editor_mut().auto_save_persistent_buffers()?;
+        assert_eq!(saved, 0, "Interrupted auto-save should not count as saved");
+
+        assert_eq!(
+            fs::read_to_string(&file_path)?,
+            "Original",
+            "Interrupted write must not touch the original file"
+        );
+        assert!(harness.editor().active_state().buffer.is_modified());
+    }
+
+    // Session 2: reopening the file brings back the unsaved edit.
+    let mut harness = EditorTestHarness::create(
+        80,
+        24,
+        HarnessOptions::new()
+            .with_config(config)
+            .with_working_dir(project_dir.clone())
+            .with_shared_dir_context(dir_context),
+    )?;
+    harness.startup(true, &[file_path.clone()])?;
+
+    assert_eq!(harness.get_buffer_content().unwrap(), "Edited Original");
+    assert!(
+        harness.editor().active_state().buffer.is_modified(),
+        "Recovered edit should still be unsaved"
+    );
+    assert_eq!(fs::read_to_string(&file_path)?, "Original");
+
+    Ok(())
+}
+
+#[test]
+fn test_persistent_auto_save_backs_off_after_failure() -> anyhow::Result<()> {
+    let temp_dir = tempfile::TempDir::new()?;
+    let file_path = temp_dir.path().join("backoff.txt");
+    fs::write(&file_path, "Original")?;
+
+    let fault_fs = Arc::new(FaultInjectingFileSystem::new(Arc::new(StdFileSystem)));
+    let mut harness = EditorTestHarness::create(
+        80,
+        24,
+        HarnessOptions::new()
+            .with_config(auto_save_config(2))
+            .with_working_dir(temp_dir.path().to_path_buf())
+            .with_filesystem(fault_fs.clone()),
+    )?;
+
+    harness.open_file(&file_path)?;
+    harness.type_text("X")?;
+    harness.advance_time(Duration::from_millis(2100));
+
+    fault_fs.arm_interrupted_write();
+    let saved = harness.editor_mut().auto_save_persistent_buffers()?;
+    assert_eq!(saved, 0, "Interrupted write should fail");
+    fault_fs.disarm_interrupted_write();
+
+    // One failure doubles the wait to 4s: no retry after another 2.1s
+    harness.advance_time(Duration::from_millis(2100));
+    let saved = harness.editor_mut().auto_save_persistent_buffers()?;
+    assert_eq!(saved, 0, "Should not retry before the backoff elapses");
+    assert_eq!(fs::read_to_string(&file_path)?, "Original");
+
+    harness.advance_time(Duration::from_millis(2000));
+    let saved = harness.editor_mut().auto_save_persistent_buffers()?;
+    assert_eq!(saved, 1, "Should retry once the backoff elapses");
+    assert_eq!(fs::read_to_string(&file_path)?, "XOriginal");
+
+    Ok(())
+}
@@ -93,6 +93,7 @@ use fresh_core::api::{
     PluginCommand, PluginResponse,
 };
 use fresh_core::command::Command;
+use fresh_core::config::PluginPermission;
 use fresh_core::overlay::OverlayNamespace;
 use fresh_core::text_property::TextPropertyEntry;
 use fresh_core::{BufferId, SplitId};
@@ -102,11 +103,12 @@ use fresh_parser_js::{
 };
 use fresh_plugin_api_macros::{plugin_api, plugin_api_impl};
 use rquickjs::{Context, Function, Object, Runtime, Value};
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::rc::Rc;
 use std::sync::{mpsc, Arc, RwLock};
+use std::time::{Duration, Instant};
 
 /// Recursively copy a directory and all its contents.
 fn copy_dir_recursive(src: &Path, dst: &Path) -> std::io::Result<()> {
@@ -229,26 +231,41 @@ fn json_to_js_value<'js>(
 
 /// Call a JS handler function directly with structured data, bypassing JSON
 /// string serialization and JS-side `JSON.parse()` + source re-parsing.
-fn call_handler(ctx: &rquickjs::Ctx<'_>, handler_name: &str, event_data: &serde_json::Value) {
+///
+/// Returns false only when the handler synchronously returned `false`;
+/// missing handlers, errors and Promises count as true.
+fn call_handler(
+    ctx: &rquickjs::Ctx<'_>,
+    handler_name: &str,
+    event_data: &serde_json::Value,
+) -> bool {
     let js_data = match json_to_js_value(ctx, event_data) {
         Ok(v) => v,
         Err(e) => {
             log_js_error(ctx, e, &format!("handler {} data conversion", handler_name));
-            return;
+            return true;
         }
     };
 
     let globals = ctx.globals();
     let Ok(func) = globals.get::<_, rquickjs::Function>(handler_name) else {
-        return;
+        return true;
     };
 
-    match func.call::<_, rquickjs::Value>((js_data,)) {
-        Ok(result) => attach_promise_catch(ctx, &globals, handler_name, result),
-        Err(e) => log_js_error(ctx, e, &format!("handler {}", handler_name)),
-    }
+    let allowed = match func.call::<_, rquickjs::Value>((js_data,)) {
+        Ok(result) => {
+            let allowed = result.as_bool() != Some(false);
+            attach_promise_catch(ctx, &globals, handler_name, result);
+            allowed
+        }
+        Err(e) => {
+            log_js_error(ctx, e, &format!("handler {}", handler_name));
+            true
+        }
+    };
 
     run_pending_jobs_checked(ctx, &format!("emit handler {}", handler_name));
+    allowed
 }
 
 /// If `result` is a thenable (Promise), attach `.catch()` to surface async rejections.
@@ -443,6 +460,11 @@ fn format_js_error(
 /// If panic_on_js_errors is enabled, this will panic to surface JS errors immediately
 fn log_js_error(ctx: &rquickjs::Ctx<'_>, err: rquickjs::Error, context: &str) {
     let error = format_js_error(ctx, err, context);
+    if handler_interrupted() {
+        // Reported once by the backend, naming the plugin
+        tracing::debug!("{}", error);
+        return;
+    }
     tracing::error!("{}", error);
 
     // When enabled, panic on JS errors to make them visible and fail fast
@@ -465,6 +487,18 @@ fn should_panic_on_js_errors() -> bool {
     PANIC_ON_JS_ERRORS.load(std::sync::atomic::Ordering::SeqCst)
 }
 
+thread_local! {
+    /// Set when the runtime's interrupt handler aborts a hook handler or
+    /// action that ran past its deadline. The errors that follow are the
+    /// timeout itself, not bugs in the plugin.
+    static HANDLER_INTERRUPTED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Whether the running handler was aborted for exceeding its deadline
+fn handler_interrupted() -> bool {
+    HANDLER_INTERRUPTED.with(Cell::get)
+}
+
 /// Global flag indicating a fatal JS error occurred that should terminate the plugin thread.
 /// This is used because panicking inside rquickjs callbacks (FFI boundary) gets caught by
 /// rquickjs's catch_unwind, so we need an alternative mechanism to signal errors.
@@ -509,7 +543,7 @@ fn run_pending_jobs_checked(ctx: &rquickjs::Ctx<'_>, context: &str) -> usize {
         // Check for unhandled exception before running more jobs
         let exc: rquickjs::Value = ctx.catch();
         // Only treat it as an exception if it's actually an Error object
-        if exc.is_exception() {
+        if exc.is_exception() && !handler_interrupted() {
             let error_msg = if let Some(err) = exc.as_exception() {
                 format!(
                     "{}: {}",
@@ -533,7 +567,7 @@ fn run_pending_jobs_checked(ctx: &rquickjs::Ctx<'_>, context: &str) -> usize {
 
     // Final check for exceptions after all jobs completed
     let exc: rquickjs::Value = ctx.catch();
-    if exc.is_exception() {
+    if exc.is_exception() && !handler_interrupted() {
         let error_msg = if let Some(err) = exc.as_exception() {
             format!(
                 "{}: {}",
@@ -666,6 +700,10 @@ pub struct PluginTrackedState {
 /// Shared between QuickJsBackend (plugin thread) and PluginThreadHandle (main thread).
 pub type AsyncResourceOwners = Arc<std::sync::Mutex<HashMap<u64, String>>>;
 
+/// Restricted plugins and the permissions each may use. Plugins missing
+/// from the map are unrestricted.
+type PluginSandboxes = Rc<RefCell<HashMap<String, Vec<PluginPermission>>>>;
+
 #[derive(Debug, Clone)]
 pub struct PluginHandler {
     pub plugin_name: String,
@@ -759,6 +797,10 @@ pub struct JsEditorApi {
     #[qjs(skip_trace)]
     plugin_api_exports:
         Rc<RefCell<HashMap<String, (String, rquickjs::Persistent<rquickjs::Object<'static>>)>>>,
+    /// Restricted plugins: name -> permissions they may use. Consulted
+    /// when one plugin asks for another's exported API.
+    #[qjs(skip_trace)]
+    plugin_sandboxes: PluginSandboxes,
     pub plugin_name: String,
 }
 
@@ -827,13 +869,33 @@ impl JsEditorApi {
         ctx: rquickjs::Ctx<'js>,
         name: String,
     ) -> rquickjs::Result<rquickjs::Value<'js>> {
-        let persistent = self
+        let export = self
             .plugin_api_exports
             .borrow()
             .get(&name)
-            .map(|(_exporter, p)| p.clone());
-        match persistent {
-            Some(p) => {
+            .map(|(exporter, p)| (exporter.clone(), p.clone()));
+        match export {
+            Some((exporter, p)) => {
+                let withheld = {
+                    let sandboxes = self.plugin_sandboxes.borrow();
+                    crate::manifest::withheld_permissions(
+                        sandboxes.get(&self.plugin_name).map(Vec::as_slice),
+                        sandboxes.get(&exporter).map(Vec::as_slice),
+                    )
+                };
+                if !withheld.is_empty() {
+                    let names: Vec<&str> = withheld.iter().map(|p| p.as_str()).collect();
+                    let msg = rquickjs::String::from_str(
+                        ctx.clone(),
+                        &format!(
+                            "Plugin '{}' has no permission to use the API of '{}' (needs {})",
+                            self.plugin_name,
+                            exporter,
+                            names.join(", ")
+                        ),
+                    )?;
+                    return Err(ctx.throw(msg.into_value()));
+                }
                 let restored = p.restore(&ctx)?;
                 Ok(restored.into_value())
             }
@@ -2942,6 +3004,94 @@ impl JsEditorApi {
         Ok(true)
     }
 
+    /// Add or replace the virtual text `virtualTextId`. `options.anchor`
+    /// places it relative to `position`: `"before"` or `"after"` the
+    /// character there, or `"endOfLine"` (the default) after the content
+    /// of its line. `fg`/`bg`/`bold`/`italic` style it as in
+    /// `addVirtualTextStyled`. The text reflows with its line when the
+    /// line wraps. Remove it with `removeVirtualText`.
+    pub fn set_virtual_text<'js>(
+        &self,
+        _ctx: rquickjs::Ctx<'js>,
+        buffer_id: u32,
+        virtual_text_id: String,
+        position: u32,
+        text: String,
+        options: rquickjs::function::Opt<rquickjs::Object<'js>>,
+    ) -> rquickjs::Result<bool> {
+        use fresh_core::api::{OverlayColorSpec, VirtualTextAnchor, VirtualTextStyle};
+
+        fn parse_color_spec(key: &str, obj: &rquickjs::Object<'_>) -> Option<OverlayColorSpec> {
+            if let Ok(theme_key) = obj.get::<_, String>(key) {
+                if !theme_key.is_empty() {
+                    return Some(OverlayColorSpec::ThemeKey(theme_key));
+                }
+            }
+            if let Ok(arr) = obj.get::<_, Vec<u8>>(key) {
+                if arr.len() >= 3 {
+                    return Some(OverlayColorSpec::Rgb(arr[0], arr[1], arr[2]));
+                }
+            }
+            None
+        }
+
+        let position = position as usize;
+        let (anchor, style) = match options.0 {
+            Some(options) => {
+                let anchor = match options
+                    .get::<_, Option<String>>("anchor")
+                    .ok()
+                    .flatten()
+                    .as_deref()
+                {
+                    None | Some("endOfLine") => VirtualTextAnchor::EndOfLine { position },
+                    Some("before") => VirtualTextAnchor::Before { position },
+                    Some("after") => VirtualTextAnchor::After { position },
+                    Some(other) => {
+                        return Err(rquickjs::Error::new_from_js_message(
+                            "string",
+                            "VirtualTextAnchor",
+                            format!(
+                            "unknown anchor '{}' (expected \"before\", \"after\" or \"endOfLine\")",
+                            other
+                        ),
+                        ))
+                    }
+                };
+                let style = VirtualTextStyle {
+                    fg: parse_color_spec("fg", &options),
+                    bg: parse_color_spec("bg", &options),
+                    bold: options.get("bold").unwrap_or(false),
+                    italic: options.get("italic").unwrap_or(false),
+                };
+                (anchor, style)
+            }
+            None => (
+                VirtualTextAnchor::EndOfLine { position },
+                VirtualTextStyle::default(),
+            ),
+        };
+
+        // Track virtual text ID for cleanup on unload.
+        self.plugin_tracked_state
+            .borrow_mut()
+            .entry(self.plugin_name.clone())
+            .or_default()
+            .virtual_text_ids
+            .push((BufferId(buffer_id as usize), virtual_text_id.clone()));
+
+        Ok(self
+            .command_sender
+            .send(PluginCommand::SetVirtualText {
+                buffer_id: BufferId(buffer_id as usize),
+                anchor,
+                text,
+                style,
+                id: virtual_text_id,
+            })
+            .is_ok())
+    }
+
     /// Remove virtual texts whose ID starts with the given prefix
     pub fn remove_virtual_texts_by_prefix(&self, buffer_id: u32, prefix: String) -> bool {
         self.command_sender
@@ -4008,6 +4158,40 @@ impl JsEditorApi {
             .is_ok())
     }
 
+    /// Open an editable scratch buffer (no file, closed without a save
+    /// prompt) as a new tab and switch to it (async, returns the buffer
+    /// ID). `language` sets the highlighting; it defaults to detection
+    /// from `name`.
+    #[plugin_api(async_promise, js_name = "openScratchBuffer", ts_return = "number")]
+    #[qjs(rename = "_openScratchBufferStart")]
+    pub fn open_scratch_buffer_start(
+        &self,
+        _ctx: rquickjs::Ctx<'_>,
+        name: String,
+        content: String,
+        language: rquickjs::function::Opt<String>,
+    ) -> u64 {
+        let id = self.alloc_request_id();
+        let _ = self.command_sender.send(PluginCommand::OpenScratchBuffer {
+            name,
+            content,
+            language: language.0,
+            request_id: Some(id),
+        });
+        id
+    }
+
+    /// Append text to the end of a buffer (e.g. streaming output into a
+    /// scratch buffer)
+    pub fn append_to_buffer(&self, buffer_id: u32, text: String) -> bool {
+        self.command_sender
+            .send(PluginCommand::AppendToBuffer {
+                buffer_id: BufferId(buffer_id as usize),
+                text,
+            })
+            .is_ok()
+    }
+
     /// Get text properties at cursor position (returns JS array)
     pub fn get_text_properties_at_cursor(
         &self,
@@ -4207,6 +4391,54 @@ impl JsEditorApi {
         id
     }
 
+    /// Shorthand for `getBufferText` with the buffer last (async).
+    /// `bufferId` defaults to the active buffer. Rejects if the range lies
+    /// outside the buffer; offsets inside a character are widened to
+    /// include the whole character.
+    #[plugin_api(async_promise, js_name = "getText", ts_return = "string")]
+    #[qjs(rename = "_getTextStart")]
+    pub fn get_text_start(
+        &self,
+        _ctx: rquickjs::Ctx<'_>,
+        start: u32,
+        end: u32,
+        buffer_id: rquickjs::function::Opt<u32>,
+    ) -> u64 {
+        let id = self.alloc_request_id();
+        let _ = self.command_sender.send(PluginCommand::GetBufferText {
+            buffer_id: BufferId(buffer_id.0.unwrap_or(0) as usize),
+            start: start as usize,
+            end: end as usize,
+            request_id: id,
+        });
+        id
+    }
+
+    /// Replace the text between byte offsets `start` and `end` with `text`
+    /// as one undoable edit (async, resolves to true once applied).
+    /// `bufferId` defaults to the active buffer; range rules match
+    /// `getText`.
+    #[plugin_api(async_promise, js_name = "replaceText", ts_return = "boolean")]
+    #[qjs(rename = "_replaceTextStart")]
+    pub fn replace_text_start(
+        &self,
+        _ctx: rquickjs::Ctx<'_>,
+        start: u32,
+        end: u32,
+        text: String,
+        buffer_id: rquickjs::function::Opt<u32>,
+    ) -> u64 {
+        let id = self.alloc_request_id();
+        let _ = self.command_sender.send(PluginCommand::ReplaceRange {
+            buffer_id: BufferId(buffer_id.0.unwrap_or(0) as usize),
+            start: start as usize,
+            end: end as usize,
+            text,
+            request_id: id,
+        });
+        id
+    }
+
     /// Delay/sleep (async, returns request_id)
     #[plugin_api(async_promise, js_name = "delay", ts_return = "void")]
     #[qjs(rename = "_delayStart")]
@@ -4673,6 +4905,12 @@ fn parse_view_token_style(
     }))
 }
 
+/// How long a single hook handler or action may run before it is aborted
+pub const DEFAULT_HANDLER_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Timeouts after which a plugin is unloaded
+pub const DEFAULT_MAX_HANDLER_TIMEOUTS: u32 = 3;
+
 /// QuickJS-based JavaScript runtime for plugins
 pub struct QuickJsBackend {
     runtime: Runtime,
@@ -4715,6 +4953,25 @@ pub struct QuickJsBackend {
     /// Runtime.
     plugin_api_exports:
         Rc<RefCell<HashMap<String, (String, rquickjs::Persistent<rquickjs::Object<'static>>)>>>,
+    /// Deadline of the handler or action currently running; the runtime's
+    /// interrupt handler aborts JS execution once it has passed
+    handler_deadline: Rc<Cell<Option<Instant>>>,
+    /// How long one handler or action may run (zero: no limit)
+    handler_timeout: Duration,
+    /// When the editor stops waiting for the blocking hook being emitted;
+    /// its handlers are aborted then even if `handler_timeout` is zero
+    veto_deadline: Option<Instant>,
+    /// Consecutive timeouts after which a plugin is disabled (0: never)
+    max_handler_timeouts: u32,
+    /// Number of consecutive timed-out handlers per plugin
+    handler_timeouts: HashMap<String, u32>,
+    /// Plugins that reached `max_handler_timeouts`, waiting to be unloaded
+    plugins_to_disable: Vec<String>,
+    /// Permissions the user granted each plugin (from its config)
+    granted_permissions: HashMap<String, Vec<PluginPermission>>,
+    /// Restricted plugins: name -> permissions they may use. Gated APIs
+    /// outside them are replaced with stubs that throw.
+    plugin_sandboxes: PluginSandboxes,
 }
 
 impl Drop for QuickJsBackend {
@@ -4782,7 +5039,7 @@ impl QuickJsBackend {
         // Set up promise rejection tracker to catch unhandled rejections
         runtime.set_host_promise_rejection_tracker(Some(Box::new(
             |_ctx, _promise, reason, is_handled| {
-                if !is_handled {
+                if !is_handled && !handler_interrupted() {
                     // Format the rejection reason
                     let error_msg = if let Some(exc) = reason.as_exception() {
                         format!(
@@ -4806,6 +5063,18 @@ impl QuickJsBackend {
             },
         )));
 
+        // Abort any JS that runs past the current handler's deadline. The
+        // interrupt exception can't be caught by the plugin.
+        let handler_deadline: Rc<Cell<Option<Instant>>> = Rc::new(Cell::new(None));
+        let deadline = Rc::clone(&handler_deadline);
+        runtime.set_interrupt_handler(Some(Box::new(move || {
+            let expired = deadline.get().is_some_and(|d| Instant::now() >= d);
+            if expired {
+                HANDLER_INTERRUPTED.with(|flag| flag.set(true));
+            }
+            expired
+        })));
+
         let main_context = Context::full(&runtime)
             .map_err(|e| anyhow!("Failed to create QuickJS context: {}", e))?;
 
@@ -4840,6 +5109,14 @@ impl QuickJsBackend {
             registered_language_configs,
             registered_lsp_servers,
             plugin_api_exports,
+            handler_deadline,
+            handler_timeout: DEFAULT_HANDLER_TIMEOUT,
+            veto_deadline: None,
+            max_handler_timeouts: DEFAULT_MAX_HANDLER_TIMEOUTS,
+            handler_timeouts: HashMap::new(),
+            plugins_to_disable: Vec::new(),
+            granted_permissions: HashMap::new(),
+            plugin_sandboxes: Rc::new(RefCell::new(HashMap::new())),
         };
 
         // Initialize main context (for internal utilities if needed)
@@ -4861,6 +5138,12 @@ impl QuickJsBackend {
         let registered_language_configs = Rc::clone(&self.registered_language_configs);
         let registered_lsp_servers = Rc::clone(&self.registered_lsp_servers);
         let plugin_api_exports = Rc::clone(&self.plugin_api_exports);
+        let denied_apis = self
+            .plugin_sandboxes
+            .borrow()
+            .get(plugin_name)
+            .map(|allowed| crate::manifest::denied_apis(allowed))
+            .unwrap_or_default();
 
         context.with(|ctx| {
             let globals = ctx.globals();
@@ -4885,6 +5168,7 @@ impl QuickJsBackend {
                 registered_language_configs: Rc::clone(&registered_language_configs),
                 registered_lsp_servers: Rc::clone(&registered_lsp_servers),
                 plugin_api_exports: Rc::clone(&plugin_api_exports),
+                plugin_sandboxes: Rc::clone(&self.plugin_sandboxes),
                 plugin_name: plugin_name.to_string(),
             };
             let editor = rquickjs::Class::<JsEditorApi>::instance(ctx.clone(), js_api)?;
@@ -5103,6 +5387,9 @@ impl QuickJsBackend {
                 editor.spawnBackgroundProcess = _wrapAsyncThenable("_spawnBackgroundProcessStart", "spawnBackgroundProcess");
                 editor.spawnProcessWait = _wrapAsync("_spawnProcessWaitStart", "spawnProcessWait");
                 editor.getBufferText = _wrapAsync("_getBufferTextStart", "getBufferText");
+                editor.getText = _wrapAsync("_getTextStart", "getText");
+                editor.replaceText = _wrapAsync("_replaceTextStart", "replaceText");
+                editor.openScratchBuffer = _wrapAsync("_openScratchBufferStart", "openScratchBuffer");
                 editor.createCompositeBuffer = _wrapAsync("_createCompositeBufferStart", "createCompositeBuffer");
                 editor.getHighlights = _wrapAsync("_getHighlightsStart", "getHighlights");
                 editor.loadPlugin = _wrapAsync("_loadPluginStart", "loadPlugin");
@@ -5170,6 +5457,28 @@ impl QuickJsBackend {
                 };
             "#.as_bytes())?;
 
+            // A plugin with a manifest only gets the capabilities it declared
+            if !denied_apis.is_empty() {
+                let sandbox = format!(
+                    r#"
+                    (function(names, plugin) {{
+                        const proto = Object.getPrototypeOf(editor);
+                        for (const name of names) {{
+                            const denied = function() {{
+                                throw new Error("Plugin '" + plugin + "' has no permission to call editor." + name);
+                            }};
+                            for (const target of [editor, proto]) {{
+                                Object.defineProperty(target, name, {{ value: denied, writable: false, configurable: false }});
+                            }}
+                        }}
+                    }})({}, {});
+                    "#,
+                    serde_json::to_string(&denied_apis).unwrap_or_else(|_| "[]".to_string()),
+                    serde_json::to_string(plugin_name).unwrap_or_else(|_| "\"\"".to_string())
+                );
+                ctx.eval::<(), _>(sandbox.as_bytes())?;
+            }
+
             Ok::<_, rquickjs::Error>(())
         }).map_err(|e| anyhow!("Failed to set up global API: {}", e))?;
 
@@ -5514,12 +5823,18 @@ impl QuickJsBackend {
     }
 
     /// Emit an event to all registered handlers
+    ///
+    /// Every handler runs; the result is true only if none of them returned
+    /// `false`, which lets `before_*` hooks veto the operation.
     pub async fn emit(&mut self, event_name: &str, event_data: &serde_json::Value) -> Result<bool> {
         tracing::trace!("emit: event '{}' with data: {:?}", event_name, event_data);
 
         self.services
             .set_js_execution_state(format!("hook '{}'", event_name));
 
+        let mut all_true = true;
+        let mut timed_out = Vec::new();
+        let mut finished = Vec::new();
         let handlers = self.event_handlers.borrow().get(event_name).cloned();
         if let Some(handler_pairs) = handlers {
             let plugin_contexts = self.plugin_contexts.borrow();
@@ -5527,14 +5842,151 @@ impl QuickJsBackend {
                 let Some(context) = plugin_contexts.get(&handler.plugin_name) else {
                     continue;
                 };
-                context.with(|ctx| {
-                    call_handler(&ctx, &handler.handler_name, event_data);
+                let (allowed, expired) = self.with_handler_deadline(|| {
+                    context.with(|ctx| call_handler(&ctx, &handler.handler_name, event_data))
                 });
+                // A handler that timed out counts as having returned nothing
+                if expired {
+                    timed_out.push(handler.plugin_name.clone());
+                } else {
+                    finished.push(handler.plugin_name.clone());
+                    if !allowed {
+                        all_true = false;
+                    }
+                }
             }
         }
+        for plugin_name in finished {
+            self.handler_timeouts.remove(&plugin_name);
+        }
+        for plugin_name in timed_out {
+            self.record_handler_timeout(&plugin_name, &format!("hook '{}'", event_name));
+        }
 
         self.services.clear_js_execution_state();
-        Ok(true)
+        Ok(all_true)
+    }
+
+    /// Emit a blocking hook whose caller stops waiting at `deadline`.
+    /// Handlers still running then are aborted, whatever the handler
+    /// timeout.
+    pub async fn emit_blocking(
+        &mut self,
+        event_name: &str,
+        event_data: &serde_json::Value,
+        deadline: Instant,
+    ) -> Result<bool> {
+        self.veto_deadline = Some(deadline);
+        let result = self.emit(event_name, event_data).await;
+        self.veto_deadline = None;
+        result
+    }
+
+    /// Limit how long a single hook handler or action may run, and how
+    /// many times in a row a plugin may time out before it is disabled
+    /// (0: never). A zero `timeout` removes the limit.
+    pub fn set_handler_limits(&mut self, timeout: Duration, max_timeouts: u32) {
+        self.handler_timeout = timeout;
+        self.max_handler_timeouts = max_timeouts;
+    }
+
+    /// Record the permissions granted to each plugin in its config
+    pub fn set_granted_permissions(&mut self, grants: HashMap<String, Vec<PluginPermission>>) {
+        self.granted_permissions = grants;
+    }
+
+    /// Permissions granted to `plugin_name`
+    pub fn granted_permissions(&self, plugin_name: &str) -> &[PluginPermission] {
+        self.granted_permissions
+            .get(plugin_name)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Confine `plugin_name` to the APIs behind `allowed` when its context
+    /// is created, or lift the restriction with `None`.
+    pub fn set_plugin_sandbox(
+        &mut self,
+        plugin_name: &str,
+        allowed: Option<Vec<PluginPermission>>,
+    ) {
+        let mut sandboxes = self.plugin_sandboxes.borrow_mut();
+        match allowed {
+            Some(allowed) => {
+                sandboxes.insert(plugin_name.to_string(), allowed);
+            }
+            None => {
+                sandboxes.remove(plugin_name);
+            }
+        }
+    }
+
+    /// The names in `apis` that aren't functions on the `editor` object
+    pub fn missing_apis(&self, apis: &[String]) -> Vec<String> {
+        self.main_context.with(|ctx| {
+            let editor = ctx.globals().get::<_, Object>("editor").ok();
+            apis.iter()
+                .filter(|api| {
+                    !editor
+                        .as_ref()
+                        .and_then(|editor| editor.get::<_, Value>(api.as_str()).ok())
+                        .is_some_and(|value| value.is_function())
+                })
+                .cloned()
+                .collect()
+        })
+    }
+
+    /// Take the plugins that timed out too often since the last call.
+    /// The caller is expected to unload them.
+    pub fn take_plugins_to_disable(&mut self) -> Vec<String> {
+        let plugins = std::mem::take(&mut self.plugins_to_disable);
+        for name in &plugins {
+            self.handler_timeouts.remove(name);
+        }
+        plugins
+    }
+
+    /// Run `f` with the handler deadline armed. Returns its result and
+    /// whether it was aborted for running past the deadline.
+    fn with_handler_deadline<R>(&self, f: impl FnOnce() -> R) -> (R, bool) {
+        HANDLER_INTERRUPTED.with(|flag| flag.set(false));
+        let timeout_deadline =
+            (!self.handler_timeout.is_zero()).then(|| Instant::now() + self.handler_timeout);
+        let deadline = match (timeout_deadline, self.veto_deadline) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+        self.handler_deadline.set(deadline);
+        let result = f();
+        self.handler_deadline.set(None);
+        (result, HANDLER_INTERRUPTED.with(|flag| flag.replace(false)))
+    }
+
+    /// Note that one of `plugin_name`'s handlers timed out, queueing the
+    /// plugin for unloading once it has done so `max_handler_timeouts`
+    /// times in a row. A handler that finishes in time resets the count.
+    fn record_handler_timeout(&mut self, plugin_name: &str, what: &str) {
+        let count = self
+            .handler_timeouts
+            .entry(plugin_name.to_string())
+            .or_default();
+        *count += 1;
+        tracing::warn!(
+            "Plugin '{}' timed out in {} ({} time(s) in a row)",
+            plugin_name,
+            what,
+            count
+        );
+        if self.max_handler_timeouts > 0
+            && *count >= self.max_handler_timeouts
+            && !self
+                .plugins_to_disable
+                .iter()
+                .any(|name| name == plugin_name)
+        {
+            self.plugins_to_disable.push(plugin_name.to_string());
+        }
     }
 
     /// Check if any handlers are registered for an event
@@ -5612,15 +6064,24 @@ impl QuickJsBackend {
         );
 
         tracing::info!("start_action: evaluating JS code");
-        context.with(|ctx| {
-            if let Err(e) = ctx.eval::<rquickjs::Value, _>(code.as_bytes()) {
-                log_js_error(&ctx, e, &format!("action {}", action_name));
-            }
-            tracing::info!("start_action: running pending microtasks");
-            // Run any immediate microtasks
-            let count = run_pending_jobs_checked(&ctx, &format!("start_action {}", action_name));
-            tracing::info!("start_action: executed {} pending jobs", count);
+        let ((), expired) = self.with_handler_deadline(|| {
+            context.with(|ctx| {
+                if let Err(e) = ctx.eval::<rquickjs::Value, _>(code.as_bytes()) {
+                    log_js_error(&ctx, e, &format!("action {}", action_name));
+                }
+                tracing::info!("start_action: running pending microtasks");
+                // Run any immediate microtasks
+                let count =
+                    run_pending_jobs_checked(&ctx, &format!("start_action {}", action_name));
+                tracing::info!("start_action: executed {} pending jobs", count);
+            })
         });
+        drop(plugin_contexts);
+        if expired {
+            self.record_handler_timeout(&plugin_name, &format!("action '{}'", action_name));
+        } else {
+            self.handler_timeouts.remove(&plugin_name);
+        }
 
         tracing::info!("start_action: END '{}'", action_name);
 
@@ -6456,6 +6917,139 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_emit_returns_false_when_a_handler_vetoes() {
+        let (mut backend, _rx) = create_test_backend();
+
+        backend
+            .execute_js(
+                r#"
+            const editor = getEditor();
+            globalThis.allowHandler = function(data) { return true; };
+            globalThis.silentHandler = function(data) {};
+            globalThis.vetoHandler = function(data) { return data.path !== "/blocked.txt"; };
+            editor.on("before_file_save", "allowHandler");
+            editor.on("before_file_save", "silentHandler");
+            editor.on("before_file_save", "vetoHandler");
+        "#,
+                "test.js",
+            )
+            .unwrap();
+
+        let allowed = serde_json::json!({"path": "/ok.txt"});
+        assert!(backend.emit("before_file_save", &allowed).await.unwrap());
+        let blocked = serde_json::json!({"path": "/blocked.txt"});
+        assert!(!backend.emit("before_file_save", &blocked).await.unwrap());
+        assert!(backend.emit("no_handlers", &blocked).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_hung_handler_times_out_and_plugin_is_disabled() {
+        let (mut backend, rx) = create_test_backend();
+        backend.set_handler_limits(Duration::from_millis(50), 2);
+
+        backend
+            .execute_js(
+                r#"
+            const editor = getEditor();
+            globalThis.hungHandler = function(data) {
+                try { while (true) {} } catch (e) {}
+                return false;
+            };
+            editor.on("before_file_save", "hungHandler");
+        "#,
+                "hung.js",
+            )
+            .unwrap();
+        backend
+            .execute_js(
+                r#"
+            const editor = getEditor();
+            globalThis.okHandler = function(data) { editor.setStatus("ok: " + data.path); };
+            editor.on("before_file_save", "okHandler");
+        "#,
+                "other.js",
+            )
+            .unwrap();
+        while rx.try_recv().is_ok() {}
+
+        let data = serde_json::json!({"path": "/a.txt"});
+        // The hung handler is aborted and doesn't veto; later handlers still run
+        assert!(backend.emit("before_file_save", &data).await.unwrap());
+        assert!(matches!(
+            rx.try_recv(),
+            Ok(PluginCommand::SetStatus { message }) if message == "ok: /a.txt"
+        ));
+        assert!(backend.take_plugins_to_disable().is_empty());
+
+        assert!(backend.emit("before_file_save", &data).await.unwrap());
+        assert_eq!(backend.take_plugins_to_disable(), ["hung"]);
+        assert!(backend.take_plugins_to_disable().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_handler_timeout_count_resets_after_success() {
+        let (mut backend, _rx) = create_test_backend();
+        backend.set_handler_limits(Duration::from_millis(50), 2);
+
+        backend
+            .execute_js(
+                r#"
+            const editor = getEditor();
+            globalThis.flakyHandler = function(data) {
+                if (data.hang) { try { while (true) {} } catch (e) {} }
+                return true;
+            };
+            editor.on("before_file_save", "flakyHandler");
+        "#,
+                "flaky.js",
+            )
+            .unwrap();
+
+        let hang = serde_json::json!({"hang": true});
+        let ok = serde_json::json!({"hang": false});
+        // Timeouts separated by successful runs never add up to the limit
+        for _ in 0..3 {
+            assert!(backend.emit("before_file_save", &hang).await.unwrap());
+            assert!(backend.emit("before_file_save", &ok).await.unwrap());
+        }
+        assert!(backend.take_plugins_to_disable().is_empty());
+
+        assert!(backend.emit("before_file_save", &hang).await.unwrap());
+        assert!(backend.emit("before_file_save", &hang).await.unwrap());
+        assert_eq!(backend.take_plugins_to_disable(), ["flaky"]);
+    }
+
+    #[tokio::test]
+    async fn test_blocking_hook_aborted_without_handler_timeout() {
+        let (mut backend, _rx) = create_test_backend();
+        backend.set_handler_limits(Duration::ZERO, 0);
+
+        backend
+            .execute_js(
+                r#"
+            const editor = getEditor();
+            globalThis.hungHandler = function(data) {
+                try { while (true) {} } catch (e) {}
+                return false;
+            };
+            editor.on("before_file_save", "hungHandler");
+        "#,
+                "hung.js",
+            )
+            .unwrap();
+
+        // With the general limit off, a blocking hook's handlers are still
+        // aborted once the editor stops waiting
+        let data = serde_json::json!({"path": "/a.txt"});
+        let deadline = Instant::now() + Duration::from_millis(50);
+        assert!(backend
+            .emit_blocking("before_file_save", &data, deadline)
+            .await
+            .unwrap());
+        assert!(backend.take_plugins_to_disable().is_empty());
+    }
+
     #[test]
     fn test_api_copy_to_clipboard() {
         let (mut backend, rx) = create_test_backend();
@@ -6878,6 +7472,134 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_api_get_text_and_replace_text_send_commands() {
+        let (mut backend, rx) = create_test_backend();
+
+        backend
+            .execute_js(
+                r#"
+            const editor = getEditor();
+            globalThis._textPromise = editor.getText(2, 5);
+            globalThis._replacePromise = editor.replaceText(2, 5, "XYZ", 7);
+        "#,
+                "test.js",
+            )
+            .unwrap();
+
+        match rx.try_recv().unwrap() {
+            PluginCommand::GetBufferText {
+                buffer_id,
+                start,
+                end,
+                ..
+            } => {
+                assert_eq!(
+                    buffer_id.0, 0,
+                    "bufferId should default to the active buffer"
+                );
+                assert_eq!((start, end), (2, 5));
+            }
+            cmd => panic!("Expected GetBufferText, got {:?}", cmd),
+        }
+        match rx.try_recv().unwrap() {
+            PluginCommand::ReplaceRange {
+                buffer_id,
+                start,
+                end,
+                text,
+                ..
+            } => {
+                assert_eq!(buffer_id.0, 7);
+                assert_eq!((start, end), (2, 5));
+                assert_eq!(text, "XYZ");
+            }
+            cmd => panic!("Expected ReplaceRange, got {:?}", cmd),
+        }
+    }
+
+    #[test]
+    fn test_api_set_virtual_text_sends_command() {
+        use fresh_core::api::{OverlayColorSpec, VirtualTextAnchor};
+
+        let (mut backend, rx) = create_test_backend();
+
+        backend
+            .execute_js(
+                r#"
+            const editor = getEditor();
+            editor.setVirtualText(1, "note", 4, "<- here");
+            editor.setVirtualText(1, "hint", 2, ": i32", { anchor: "after", fg: "ui.muted", italic: true });
+        "#,
+                "test.js",
+            )
+            .unwrap();
+
+        match rx.try_recv().unwrap() {
+            PluginCommand::SetVirtualText {
+                buffer_id,
+                anchor,
+                text,
+                id,
+                ..
+            } => {
+                assert_eq!(buffer_id.0, 1);
+                assert_eq!(anchor, VirtualTextAnchor::EndOfLine { position: 4 });
+                assert_eq!(text, "<- here");
+                assert_eq!(id, "note");
+            }
+            cmd => panic!("Expected SetVirtualText, got {:?}", cmd),
+        }
+        match rx.try_recv().unwrap() {
+            PluginCommand::SetVirtualText { anchor, style, .. } => {
+                assert_eq!(anchor, VirtualTextAnchor::After { position: 2 });
+                assert!(
+                    matches!(style.fg, Some(OverlayColorSpec::ThemeKey(ref k)) if k == "ui.muted")
+                );
+                assert!(style.italic && !style.bold);
+            }
+            cmd => panic!("Expected SetVirtualText, got {:?}", cmd),
+        }
+    }
+
+    #[test]
+    fn test_api_open_scratch_buffer_and_append() {
+        let (mut backend, rx) = create_test_backend();
+
+        backend
+            .execute_js(
+                r#"
+            const editor = getEditor();
+            editor.openScratchBuffer("*Output*", "line 1\n", "rust");
+            editor.appendToBuffer(3, "line 2\n");
+        "#,
+                "test.js",
+            )
+            .unwrap();
+
+        match rx.try_recv().unwrap() {
+            PluginCommand::OpenScratchBuffer {
+                name,
+                content,
+                language,
+                request_id,
+            } => {
+                assert_eq!(name, "*Output*");
+                assert_eq!(content, "line 1\n");
+                assert_eq!(language.as_deref(), Some("rust"));
+                assert!(request_id.is_some());
+            }
+            cmd => panic!("Expected OpenScratchBuffer, got {:?}", cmd),
+        }
+        match rx.try_recv().unwrap() {
+            PluginCommand::AppendToBuffer { buffer_id, text } => {
+                assert_eq!(buffer_id.0, 3);
+                assert_eq!(text, "line 2\n");
+            }
+            cmd => panic!("Expected AppendToBuffer, got {:?}", cmd),
+        }
+    }
+
     #[test]
     fn test_api_get_buffer_text_resolves_callback() {
         let (mut backend, rx) = create_test_backend();
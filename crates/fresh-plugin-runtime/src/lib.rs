@@ -1,4 +1,5 @@
 pub mod backend;
+pub mod manifest;
 pub mod process;
 pub mod thread;
 pub mod ts_export;
@@ -0,0 +1,209 @@
+//! Plugin manifests.
+//!
+//! A plugin may ship `<name>.manifest.json` next to its source, declaring
+//! what it is and what it needs:
+//!
+//! ```json
+//! {
+//!   "name": "git-tools",
+//!   "version": "1.2.0",
+//!   "apis": ["spawnProcess", "readFile"],
+//!   "permissions": ["process", "filesystem"]
+//! }
+//! ```
+//!
+//! Before loading a plugin with a manifest the editor checks that every
+//! permission it requests is granted in `plugins.<name>.permissions` and
+//! that every API it lists exists; otherwise the plugin isn't loaded. A
+//! loaded plugin can then only call the APIs behind the permissions it
+//! declared. Plugins without a manifest are loaded as before, without
+//! restrictions.
+//!
+//! A plugin can't borrow capabilities through another plugin's exported
+//! API either: `getPluginApi` refuses an exporter holding permissions the
+//! caller doesn't have.
+
+use anyhow::{anyhow, Result};
+use fresh_core::config::PluginPermission;
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+
+/// Contents of a plugin's `.manifest.json`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct PluginManifest {
+    pub name: Option<String>,
+    pub version: Option<String>,
+    /// Editor APIs the plugin needs (`editor.<api>`)
+    pub apis: Vec<String>,
+    /// Capabilities the plugin needs
+    pub permissions: Vec<PluginPermission>,
+}
+
+impl PluginManifest {
+    /// Where the manifest of the plugin at `plugin_path` lives
+    pub fn path_for(plugin_path: &Path) -> PathBuf {
+        plugin_path.with_extension("manifest.json")
+    }
+
+    /// Read the manifest of the plugin at `plugin_path`, if it has one.
+    pub fn load_for(plugin_path: &Path) -> Result<Option<Self>> {
+        let path = Self::path_for(plugin_path);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let content = std::fs::read_to_string(&path)
+            .map_err(|e| anyhow!("Failed to read {}: {}", path.display(), e))?;
+        let manifest = serde_json::from_str(&content)
+            .map_err(|e| anyhow!("Invalid manifest {}: {}", path.display(), e))?;
+        Ok(Some(manifest))
+    }
+
+    /// The requested permissions missing from `granted`
+    pub fn ungranted(&self, granted: &[PluginPermission]) -> Vec<PluginPermission> {
+        let mut missing: Vec<PluginPermission> = Vec::new();
+        for permission in &self.permissions {
+            if !granted.contains(permission) && !missing.contains(permission) {
+                missing.push(*permission);
+            }
+        }
+        missing
+    }
+}
+
+/// The editor APIs behind each permission, including the internal entry
+/// points their JS wrappers call.
+pub fn gated_apis(permission: PluginPermission) -> &'static [&'static str] {
+    match permission {
+        PluginPermission::Filesystem => &[
+            "fileExists",
+            "fileStat",
+            "readFile",
+            "writeFile",
+            "readDir",
+            "createDir",
+            "removePath",
+            "renamePath",
+            "copyPath",
+            "saveBufferToPath",
+            "replaceInFile",
+            "_replaceInFileStart",
+            "grepProject",
+            "_grepProjectStart",
+            "grepProjectStreaming",
+            "_grepProjectStreamingStart",
+            "saveThemeFile",
+            "deleteTheme",
+            "_deleteThemeSync",
+        ],
+        PluginPermission::Process => &[
+            "spawnProcess",
+            "_spawnProcessStart",
+            "spawnProcessWait",
+            "_spawnProcessWaitStart",
+            "spawnHostProcess",
+            "_spawnHostProcessStart",
+            "_killHostProcess",
+            "spawnBackgroundProcess",
+            "_spawnBackgroundProcessStart",
+            "killBackgroundProcess",
+            "killProcess",
+            "createTerminal",
+            "_createTerminalStart",
+            "sendTerminalInput",
+        ],
+        PluginPermission::Network => &[],
+    }
+}
+
+/// The gated APIs a plugin that was allowed `allowed` may not call
+pub fn denied_apis(allowed: &[PluginPermission]) -> Vec<&'static str> {
+    PluginPermission::ALL
+        .into_iter()
+        .filter(|permission| !allowed.contains(permission))
+        .flat_map(gated_apis)
+        .copied()
+        .collect()
+}
+
+/// Permissions with gated APIs that `exporter` holds and `caller` doesn't.
+/// `None` stands for an unrestricted plugin.
+///
+/// A caller may only use another plugin's exported API when this is empty,
+/// so it can't reach gated calls through the exporter.
+pub fn withheld_permissions(
+    caller: Option<&[PluginPermission]>,
+    exporter: Option<&[PluginPermission]>,
+) -> Vec<PluginPermission> {
+    let Some(caller) = caller else {
+        return Vec::new();
+    };
+    let exporter = exporter.unwrap_or(&PluginPermission::ALL);
+    exporter
+        .iter()
+        .copied()
+        .filter(|permission| !caller.contains(permission) && !gated_apis(*permission).is_empty())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_manifest_parses_and_reports_ungranted() {
+        let manifest: PluginManifest = serde_json::from_str(
+            r#"{"name": "tools", "version": "1.0.0", "permissions": ["process", "filesystem"]}"#,
+        )
+        .unwrap();
+        assert_eq!(manifest.version.as_deref(), Some("1.0.0"));
+        assert!(manifest.apis.is_empty());
+        assert_eq!(
+            manifest.ungranted(&[PluginPermission::Filesystem]),
+            [PluginPermission::Process]
+        );
+        assert!(manifest
+            .ungranted(&[PluginPermission::Process, PluginPermission::Filesystem])
+            .is_empty());
+        assert_eq!(
+            PluginManifest::path_for(Path::new("/plugins/tools.ts")),
+            Path::new("/plugins/tools.manifest.json")
+        );
+    }
+
+    #[test]
+    fn test_denied_apis_cover_ungranted_permissions() {
+        let denied = denied_apis(&[PluginPermission::Filesystem]);
+        assert!(denied.contains(&"spawnProcess"));
+        assert!(denied.contains(&"_spawnProcessStart"));
+        assert!(denied.contains(&"sendTerminalInput"));
+        assert!(!denied.contains(&"readFile"));
+
+        let denied = denied_apis(&[PluginPermission::Process]);
+        for api in [
+            "copyPath",
+            "saveBufferToPath",
+            "replaceInFile",
+            "_replaceInFileStart",
+            "saveThemeFile",
+        ] {
+            assert!(denied.contains(&api), "{api} should need filesystem");
+        }
+    }
+
+    #[test]
+    fn test_withheld_permissions_follow_the_caller() {
+        let fs = [PluginPermission::Filesystem];
+        // An unrestricted exporter holds everything a restricted caller lacks
+        assert_eq!(
+            withheld_permissions(Some(&fs), None),
+            [PluginPermission::Process]
+        );
+        assert_eq!(
+            withheld_permissions(Some(&[]), Some(&fs)),
+            [PluginPermission::Filesystem]
+        );
+        assert!(withheld_permissions(Some(&fs), Some(&fs)).is_empty());
+        assert!(withheld_permissions(None, None).is_empty());
+    }
+}
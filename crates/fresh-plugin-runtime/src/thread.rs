@@ -11,6 +11,7 @@
 
 use crate::backend::quickjs_backend::{AsyncResourceOwners, PendingResponses, TsPluginInfo};
 use crate::backend::QuickJsBackend;
+use crate::manifest::PluginManifest;
 use anyhow::{anyhow, Result};
 use fresh_core::api::{EditorStateSnapshot, JsCallbackId, PluginCommand};
 use fresh_core::hooks::HookArgs;
@@ -112,6 +113,15 @@ pub enum PluginRequest {
     /// Run a hook (fire-and-forget, no response needed)
     RunHook { hook_name: String, args: HookArgs },
 
+    /// Run a hook and report whether every handler allowed it
+    RunHookBlocking {
+        hook_name: String,
+        args: HookArgs,
+        /// When the caller stops waiting; handlers still running are aborted
+        deadline: std::time::Instant,
+        response: oneshot::Sender<bool>,
+    },
+
     /// Check if any handlers are registered for a hook
     HasHookHandlers {
         hook_name: String,
@@ -123,6 +133,13 @@ pub enum PluginRequest {
         response: oneshot::Sender<Vec<TsPluginInfo>>,
     },
 
+    /// Change how long hook handlers and actions may run, and after how
+    /// many timeouts a plugin is disabled
+    SetHandlerLimits {
+        timeout: Duration,
+        max_timeouts: u32,
+    },
+
     /// Track an async resource (buffer/terminal) that was just created.
     /// Sent by deliver_response when the editor confirms resource creation.
     TrackAsyncResource {
@@ -627,6 +644,84 @@ impl PluginThreadHandle {
         }
     }
 
+    /// Limit how long a single hook handler or action may run. A handler
+    /// that runs longer is aborted and treated as having returned nothing;
+    /// a plugin that times out `max_timeouts` times is unloaded (0: never).
+    /// A zero `timeout` removes the limit.
+    pub fn set_handler_limits(&self, timeout: Duration, max_timeouts: u32) {
+        if let Some(sender) = self.request_sender.as_ref() {
+            fire_and_forget(sender.send(PluginRequest::SetHandlerLimits {
+                timeout,
+                max_timeouts,
+            }));
+        }
+    }
+
+    /// Run a hook and wait for its handlers (blocking)
+    ///
+    /// Returns false if any handler returned `false`. Handlers only get
+    /// `timeout` to answer; a plugin that doesn't, or a plugin thread that
+    /// is gone, counts as allowing the operation so a stuck plugin can't
+    /// block the editor.
+    pub fn run_hook_blocking(
+        &self,
+        hook_name: &str,
+        args: HookArgs,
+        timeout: std::time::Duration,
+    ) -> bool {
+        self.run_hooks_blocking(hook_name, vec![args], timeout)
+            .pop()
+            .unwrap_or(true)
+    }
+
+    /// Run a hook once per entry of `args` and wait for every verdict
+    /// (blocking)
+    ///
+    /// All runs are queued up front and share one `timeout`, so the wait
+    /// doesn't grow with the number of runs. Verdicts are returned in the
+    /// order of `args`, with the same rules as [`Self::run_hook_blocking`].
+    pub fn run_hooks_blocking(
+        &self,
+        hook_name: &str,
+        args: Vec<HookArgs>,
+        timeout: std::time::Duration,
+    ) -> Vec<bool> {
+        let deadline = std::time::Instant::now() + timeout;
+        let receivers: Vec<_> = args
+            .into_iter()
+            .map(|args| {
+                let (tx, rx) = oneshot::channel();
+                let sender = self.request_sender.as_ref()?;
+                sender
+                    .send(PluginRequest::RunHookBlocking {
+                        hook_name: hook_name.to_string(),
+                        args,
+                        deadline,
+                        response: tx,
+                    })
+                    .ok()?;
+                Some(rx)
+            })
+            .collect();
+
+        receivers
+            .into_iter()
+            .map(|rx| {
+                let Some(rx) = rx else {
+                    return true;
+                };
+                let remaining = deadline.saturating_duration_since(std::time::Instant::now());
+                match rx.recv_timeout(remaining) {
+                    Ok(allowed) => allowed,
+                    Err(_) => {
+                        tracing::warn!("Hook '{}' timed out, allowing the operation", hook_name);
+                        true
+                    }
+                }
+            })
+            .collect()
+    }
+
     /// Check if any handlers are registered for a hook (blocking)
     pub fn has_hook_handlers(&self, hook_name: &str) -> bool {
         let (tx, rx) = oneshot::channel();
@@ -1026,6 +1121,26 @@ async fn plugin_thread_loop(
                 has_pending_work = runtime.borrow_mut().poll_event_loop_once();
             }
         }
+
+        disable_timed_out_plugins(&runtime, plugins);
+    }
+}
+
+/// Unload the plugins whose handlers kept running past their deadline
+fn disable_timed_out_plugins(
+    runtime: &Rc<RefCell<QuickJsBackend>>,
+    plugins: &mut HashMap<String, TsPluginInfo>,
+) {
+    let to_disable = runtime.borrow_mut().take_plugins_to_disable();
+    for name in to_disable {
+        tracing::error!("Disabling plugin '{}': its handlers keep timing out", name);
+        if let Err(e) = unload_plugin_internal(Rc::clone(runtime), plugins, &name) {
+            tracing::warn!("Failed to unload plugin '{}': {}", name, e);
+        }
+        runtime.borrow().send_status(format!(
+            "Plugin '{}' disabled: its handlers keep timing out",
+            name
+        ));
     }
 }
 
@@ -1041,7 +1156,8 @@ async fn run_hook_internal_rc(
     runtime: Rc<RefCell<QuickJsBackend>>,
     hook_name: &str,
     args: &HookArgs,
-) -> Result<()> {
+    deadline: Option<std::time::Instant>,
+) -> Result<bool> {
     // Convert HookArgs to serde_json::Value using hook_args_to_json which produces flat JSON
     // (not enum-tagged JSON from serde's default Serialize)
     let json_start = std::time::Instant::now();
@@ -1054,14 +1170,22 @@ async fn run_hook_internal_rc(
 
     // Emit to TypeScript handlers
     let emit_start = std::time::Instant::now();
-    runtime.borrow_mut().emit(hook_name, &json_data).await?;
+    let all_true = match deadline {
+        Some(deadline) => {
+            runtime
+                .borrow_mut()
+                .emit_blocking(hook_name, &json_data, deadline)
+                .await?
+        }
+        None => runtime.borrow_mut().emit(hook_name, &json_data).await?,
+    };
     tracing::trace!(
         hook = hook_name,
         emit_ms = emit_start.elapsed().as_millis(),
         "emit completed"
     );
 
-    Ok(())
+    Ok(all_true)
 }
 
 /// Handle a single request in the plugin thread
@@ -1147,7 +1271,8 @@ async fn handle_request(
             } else {
                 tracing::trace!(hook = %hook_name, "RunHook request received");
             }
-            if let Err(e) = run_hook_internal_rc(Rc::clone(&runtime), &hook_name, &args).await {
+            if let Err(e) = run_hook_internal_rc(Rc::clone(&runtime), &hook_name, &args, None).await
+            {
                 let error_msg = format!("Plugin error in '{}': {}", hook_name, e);
                 tracing::error!("{}", error_msg);
                 // Surface the error to the UI
@@ -1171,6 +1296,27 @@ async fn handle_request(
             }
         }
 
+        PluginRequest::RunHookBlocking {
+            hook_name,
+            args,
+            deadline,
+            response,
+        } => {
+            let allowed =
+                match run_hook_internal_rc(Rc::clone(&runtime), &hook_name, &args, Some(deadline))
+                    .await
+                {
+                    Ok(allowed) => allowed,
+                    Err(e) => {
+                        let error_msg = format!("Plugin error in '{}': {}", hook_name, e);
+                        tracing::error!("{}", error_msg);
+                        runtime.borrow_mut().send_status(error_msg);
+                        true
+                    }
+                };
+            fire_and_forget(response.send(allowed));
+        }
+
         PluginRequest::HasHookHandlers {
             hook_name,
             response,
@@ -1184,6 +1330,15 @@ async fn handle_request(
             fire_and_forget(response.send(plugin_list));
         }
 
+        PluginRequest::SetHandlerLimits {
+            timeout,
+            max_timeouts,
+        } => {
+            runtime
+                .borrow_mut()
+                .set_handler_limits(timeout, max_timeouts);
+        }
+
         PluginRequest::ResolveCallback {
             callback_id,
             result_json,
@@ -1263,6 +1418,8 @@ struct PreparedPlugin {
     /// isolated-declarations emit failed outright — the plugin still
     /// loads at runtime.
     declarations: Option<String>,
+    /// The plugin's `.manifest.json`, if it ships one
+    manifest: Option<PluginManifest>,
 }
 
 /// Prepare a plugin for execution: read source, transpile, extract dependencies.
@@ -1278,6 +1435,7 @@ fn prepare_plugin(path: &Path) -> Result<PreparedPlugin> {
 
     let source = std::fs::read_to_string(path)
         .map_err(|e| anyhow!("Failed to read plugin {}: {}", path.display(), e))?;
+    let manifest = PluginManifest::load_for(path)?;
 
     let filename = path
         .file_name()
@@ -1352,9 +1510,61 @@ fn prepare_plugin(path: &Path) -> Result<PreparedPlugin> {
         i18n,
         dependencies,
         declarations,
+        manifest,
     })
 }
 
+/// Decide whether a plugin may load, given its manifest if it has one.
+///
+/// A plugin whose manifest requests permissions its config doesn't grant,
+/// or lists APIs this editor doesn't provide, is refused: the user is told
+/// why and false is returned. An admitted plugin with a manifest is
+/// confined to the permissions it declared. One without a manifest isn't
+/// restricted; it is noted in the log so users can spot plugins that
+/// haven't declared what they use.
+fn admit_plugin(
+    runtime: &Rc<RefCell<QuickJsBackend>>,
+    plugin_name: &str,
+    manifest: Option<&PluginManifest>,
+) -> bool {
+    let Some(manifest) = manifest else {
+        tracing::info!(
+            "Plugin '{}' has no manifest and loads without restrictions",
+            plugin_name
+        );
+        runtime.borrow_mut().set_plugin_sandbox(plugin_name, None);
+        return true;
+    };
+
+    let ungranted = manifest.ungranted(runtime.borrow().granted_permissions(plugin_name));
+    let missing_apis = runtime.borrow().missing_apis(&manifest.apis);
+    let refusal = if !ungranted.is_empty() {
+        let names: Vec<&str> = ungranted.iter().map(|p| p.as_str()).collect();
+        Some(format!("permissions not granted: {}", names.join(", ")))
+    } else if !missing_apis.is_empty() {
+        Some(format!("missing editor APIs: {}", missing_apis.join(", ")))
+    } else {
+        None
+    };
+    if let Some(reason) = refusal {
+        let message = format!("Plugin '{}' not loaded: {}", plugin_name, reason);
+        tracing::warn!("{}", message);
+        runtime.borrow().send_status(message);
+        return false;
+    }
+
+    tracing::debug!(
+        "Plugin '{}' {} loads with permissions {:?}",
+        plugin_name,
+        manifest.version.as_deref().unwrap_or("(unversioned)"),
+        manifest.permissions
+    );
+    runtime
+        .borrow_mut()
+        .set_plugin_sandbox(plugin_name, Some(manifest.permissions.clone()));
+    true
+}
+
 /// Execute a pre-prepared plugin in QuickJS. This is the serial phase —
 /// must run on the plugin thread.
 fn execute_prepared_plugin(
@@ -1414,6 +1624,10 @@ async fn load_plugin_internal(
         .to_string();
 
     tracing::info!("Loading TypeScript plugin: {} from {:?}", plugin_name, path);
+    let manifest = PluginManifest::load_for(path)?;
+    if !admit_plugin(&runtime, &plugin_name, manifest.as_ref()) {
+        return Ok(());
+    }
     tracing::debug!(
         "load_plugin_internal: starting module load for plugin '{}'",
         plugin_name
@@ -1545,6 +1759,12 @@ async fn load_plugins_from_dir_with_config_internal(
     );
     let mut errors = Vec::new();
     let mut discovered_plugins: HashMap<String, PluginConfig> = HashMap::new();
+    runtime.borrow_mut().set_granted_permissions(
+        plugin_configs
+            .iter()
+            .map(|(name, config)| (name.clone(), config.permissions.clone()))
+            .collect(),
+    );
 
     if !dir.exists() {
         tracing::warn!("Plugin directory does not exist: {:?}", dir);
@@ -1590,6 +1810,7 @@ async fn load_plugins_from_dir_with_config_internal(
             PluginConfig {
                 enabled: existing_config.enabled,
                 path: Some(path.clone()),
+                permissions: existing_config.permissions.clone(),
             }
         } else {
             // Create new config with default enabled = true
@@ -1689,6 +1910,9 @@ async fn load_plugins_from_dir_with_config_internal(
     let exec_start = std::time::Instant::now();
     for plugin_name in load_order {
         if let Some(prepared) = prepared_map.get(&plugin_name) {
+            if !admit_plugin(&runtime, &plugin_name, prepared.manifest.as_ref()) {
+                continue;
+            }
             tracing::debug!(
                 "load_plugins_from_dir_with_config_internal: executing plugin '{}'",
                 plugin_name
@@ -349,6 +349,9 @@ interface EditorAPI {
  * `action` in `pre_command`/`post_command` is the serde JSON of the `Action`
  * enum: unit variants serialize as a plain string (e.g. `"MoveLeft"`),
  * tuple variants as a single-key object (e.g. `{"InsertChar": "a"}`).
+ *
+ * A `before_file_open` or `before_file_save` handler that returns `false`
+ * (synchronously) cancels the open or save.
  */
 interface HookEventMap {
   // ── lifecycle ────────────────────────────────────────────────────────────
@@ -366,7 +369,7 @@ interface HookEventMap {
   // ── file I/O ─────────────────────────────────────────────────────────────
   before_file_open: { path: string };
   after_file_open: { path: string; buffer_id: number };
-  before_file_save: { path: string; buffer_id: number };
+  before_file_save: { path: string; buffer_id: number; text: string | null };
   after_file_save: { path: string; buffer_id: number };
 
   // ── text edits ───────────────────────────────────────────────────────────
@@ -431,6 +434,7 @@ interface HookEventMap {
     viewport_start: number;
     viewport_end: number;
     tokens: ViewTokenWire[];
+    lines: { line_start: number; tokens: ViewTokenWire[] }[];
     cursor_positions: number[];
   };
 